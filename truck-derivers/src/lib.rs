@@ -886,6 +886,12 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
                     hint: H,
                     trials: usize,
                 ) -> Option<f64>,
+                fn try_search_parameter<H: Into<SPHint1D>>(
+                    &self,
+                    pt: Self::Point,
+                    hint: H,
+                    trials: usize,
+                ) -> Result<f64, truck_geotrait::SearchError<f64>>,
             );
             quote! {
                 #[automatically_derived]
@@ -920,6 +926,14 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
                     ) -> Option<f64> {
                         self.0.search_parameter(pt, hint, trials)
                     }
+                    fn try_search_parameter<H: Into<SPHint1D>>(
+                        &self,
+                        pt: Self::Point,
+                        hint: H,
+                        trials: usize,
+                    ) -> Result<f64, truck_geotrait::SearchError<f64>> {
+                        self.0.try_search_parameter(pt, hint, trials)
+                    }
                 }
             }
         }
@@ -951,6 +965,12 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
                     hint: H,
                     trials: usize,
                 ) -> Option<(f64, f64)>,
+                fn try_search_parameter<H: Into<SPHint2D>>(
+                    &self,
+                    pt: Self::Point,
+                    hint: H,
+                    trials: usize,
+                ) -> Result<(f64, f64), truck_geotrait::SearchError<(f64, f64)>>,
             );
             quote! {
                 #[automatically_derived]
@@ -984,6 +1004,14 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
                     ) -> Option<(f64, f64)> {
                         self.0.search_parameter(pt, hint, trials)
                     }
+                    fn try_search_parameter<H: Into<SPHint2D>>(
+                        &self,
+                        pt: Self::Point,
+                        hint: H,
+                        trials: usize,
+                    ) -> Result<(f64, f64), truck_geotrait::SearchError<(f64, f64)>> {
+                        self.0.try_search_parameter(pt, hint, trials)
+                    }
                 }
             }
         }
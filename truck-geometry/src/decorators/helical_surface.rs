@@ -0,0 +1,397 @@
+use super::*;
+use std::f64::consts::PI;
+
+impl<C> HelicalSurface<C> {
+    /// Creates a surface by sweeping `curve` along the helix around the axis through `origin`
+    /// with the direction `axis`. The curve advances `pitch` along the axis per turn, and the
+    /// sweep rotates by `angle`, which is the range of the parameter `v`.
+    #[inline(always)]
+    pub fn by_helix(curve: C, origin: Point3, axis: Vector3, pitch: f64, angle: f64) -> Self {
+        HelicalSurface {
+            curve,
+            origin,
+            axis: axis.normalize(),
+            pitch,
+            vrange: (f64::min(0.0, angle), f64::max(0.0, angle)),
+        }
+    }
+    /// Returns the curve before swept.
+    #[inline(always)]
+    pub const fn entity_curve(&self) -> &C { &self.curve }
+    /// Into the curve before swept.
+    #[inline(always)]
+    pub fn into_entity_curve(self) -> C { self.curve }
+    /// Returns the origin of the axis
+    #[inline(always)]
+    pub const fn origin(&self) -> Point3 { self.origin }
+    /// Returns the direction of the axis
+    #[inline(always)]
+    pub const fn axis(&self) -> Vector3 { self.axis }
+    /// Returns the advance along the axis per turn
+    #[inline(always)]
+    pub const fn pitch(&self) -> f64 { self.pitch }
+    /// Returns the transformation moving the curve to the `v`-parameter curve, the rotation by
+    /// the angle `v` and the translation along the axis.
+    #[inline(always)]
+    pub fn screw_matrix(&self, v: f64) -> Matrix4 {
+        Matrix4::from_translation(self.origin.to_vec() + self.axis * self.advance(v))
+            * Matrix4::from_axis_angle(self.axis, Rad(v))
+            * Matrix4::from_translation(-self.origin.to_vec())
+    }
+    #[inline(always)]
+    fn advance(&self, v: f64) -> f64 { self.pitch * v / (2.0 * PI) }
+    #[inline(always)]
+    fn rotate(&self, v: f64, vec: Vector3) -> Vector3 {
+        Matrix3::from_axis_angle(self.axis, Rad(v)) * vec
+    }
+}
+
+impl<C: ParametricCurve3D> ParametricSurface for HelicalSurface<C> {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        self.screw_matrix(v).transform_point(self.curve.subs(u))
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Vector3 { self.rotate(v, self.curve.der(u)) }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        let radius = self.rotate(v, self.curve.subs(u) - self.origin);
+        self.axis.cross(radius) + self.axis * (self.pitch / (2.0 * PI))
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { self.rotate(v, self.curve.der2(u)) }
+    #[inline(always)]
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        self.axis.cross(self.rotate(v, self.curve.der(u)))
+    }
+    #[inline(always)]
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        let radius = self.rotate(v, self.curve.subs(u) - self.origin);
+        self.axis.cross(self.axis.cross(radius))
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        let (v0, v1) = self.vrange;
+        (
+            self.curve.parameter_range(),
+            (Bound::Included(v0), Bound::Included(v1)),
+        )
+    }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> { self.curve.period() }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> BoundedSurface for HelicalSurface<C> {}
+
+impl<C: ParametricCurve3D> ParametricSurface3D for HelicalSurface<C> {
+    #[inline(always)]
+    fn normal(&self, u: f64, v: f64) -> Vector3 {
+        self.uder(u, v).cross(self.vder(u, v)).normalize()
+    }
+}
+
+impl<C: Clone> Invertible for HelicalSurface<C> {
+    #[inline(always)]
+    fn invert(&mut self) { *self = self.inverse() }
+    #[inline(always)]
+    fn inverse(&self) -> Self {
+        // The screw motion around the opposite axis by `-v` is the one around the axis by `v`.
+        HelicalSurface {
+            curve: self.curve.clone(),
+            origin: self.origin,
+            axis: -self.axis,
+            pitch: self.pitch,
+            vrange: (-self.vrange.1, -self.vrange.0),
+        }
+    }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> HelicalSurface<C> {
+    /// Returns the range of `v` such that `subs(u, v)` has the same height as `point` along the
+    /// axis for some `u`.
+    fn vrange_at(&self, point: Point3) -> (f64, f64) {
+        let height = |p: Point3| (p - self.origin).dot(self.axis);
+        let (t0, t1) = self.curve.range_tuple();
+        let init = (f64::INFINITY, f64::NEG_INFINITY);
+        let (min, max) = (0..=PRESEARCH_DIVISION).fold(init, |(min, max), i| {
+            let t = t0 + (t1 - t0) * i as f64 / PRESEARCH_DIVISION as f64;
+            let h = height(self.curve.subs(t));
+            (f64::min(min, h), f64::max(max, h))
+        });
+        let k = self.pitch / (2.0 * PI);
+        if k.so_small() {
+            (0.0, 2.0 * PI)
+        } else {
+            let (v0, v1) = ((height(point) - max) / k, (height(point) - min) / k);
+            (f64::min(v0, v1) - PI, f64::max(v0, v1) + PI)
+        }
+    }
+    fn presearch_hint(&self, point: Point3, hint: SPHint2D) -> (f64, f64) {
+        match hint {
+            SPHint2D::Parameter(u, v) => (u, v),
+            SPHint2D::Range(x, y) => {
+                algo::surface::presearch(self, point, (x, y), PRESEARCH_DIVISION)
+            }
+            SPHint2D::None => {
+                let range = (self.curve.range_tuple(), self.vrange_at(point));
+                algo::surface::presearch(self, point, range, PRESEARCH_DIVISION)
+            }
+        }
+    }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> HelicalSurface<C> {
+    /// Returns the cubic B-spline surface approximating the whole surface within `tol`.
+    ///
+    /// The parameters of the approximation are those of the curve and the range of `v`
+    /// normalized to `[0, 1]`. The sampling is refined until the errors at the middle points are
+    /// less than `tol`, up to the division limit. If the samples cannot be interpolated, the
+    /// piecewise bilinear surface through them is returned instead.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)],
+    /// );
+    /// // one turn
+    /// let helicoid =
+    ///     HelicalSurface::by_helix(line, Point3::origin(), Vector3::unit_z(), 3.0, 2.0 * PI);
+    /// let bspsurface = helicoid.approximation(1.0e-4);
+    /// for i in 0..=10 {
+    ///     for j in 0..=10 {
+    ///         let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+    ///         let pt = helicoid.subs(u, 2.0 * PI * v);
+    ///         assert!(bspsurface.subs(u, v).distance(pt) < 1.0e-4);
+    ///     }
+    /// }
+    /// ```
+    pub fn approximation(&self, tol: f64) -> BSplineSurface<Point3> {
+        const MAX_DIVISION: usize = 1024;
+        let ((t0, t1), (v0, v1)) = (self.curve.range_tuple(), self.vrange);
+        let param = |i: usize, division: usize, (s0, s1): (f64, f64)| {
+            s0 + (s1 - s0) * i as f64 / division as f64
+        };
+        // The sections are the interpolations of the points at the parameters of the division,
+        // and the odd points of the finer division are used to estimate the errors.
+        let (mut udiv, mut vdiv) = (4, usize::max(4, ((v1 - v0) / (PI / 4.0)).ceil() as usize));
+        loop {
+            let sections = (0..=vdiv)
+                .map(|j| {
+                    let v = param(j, vdiv, (v0, v1));
+                    let points: Vec<Point3> = (0..=udiv)
+                        .map(|i| self.subs(param(i, udiv, (t0, t1)), v))
+                        .collect();
+                    let ends = EndCondition::Clamped(
+                        self.uder(t0, v) * (t1 - t0),
+                        self.uder(t1, v) * (t1 - t0),
+                    );
+                    BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, ends).ok()
+                })
+                .collect::<Option<Vec<_>>>();
+            let surface = sections
+                .as_ref()
+                .and_then(|sections| BSplineSurface::loft(sections, 3).ok());
+            let (sections, surface) = match (sections, surface) {
+                (Some(sections), Some(surface)) => (sections, surface),
+                _ => {
+                    let control_points = (0..=udiv)
+                        .map(|i| {
+                            let u = param(i, udiv, (t0, t1));
+                            (0..=vdiv)
+                                .map(|j| self.subs(u, param(j, vdiv, (v0, v1))))
+                                .collect()
+                        })
+                        .collect();
+                    let knot_vecs = (
+                        KnotVec::uniform_knot(1, udiv),
+                        KnotVec::uniform_knot(1, vdiv),
+                    );
+                    return BSplineSurface::new(knot_vecs, control_points);
+                }
+            };
+            let uerror = sections
+                .iter()
+                .enumerate()
+                .fold(0.0, |error, (j, section)| {
+                    let v = param(j, vdiv, (v0, v1));
+                    (0..udiv).fold(error, |error, i| {
+                        let pt = self.subs(param(2 * i + 1, 2 * udiv, (t0, t1)), v);
+                        let dist = section
+                            .subs(param(2 * i + 1, 2 * udiv, (0.0, 1.0)))
+                            .distance(pt);
+                        f64::max(error, dist)
+                    })
+                });
+            let verror = (0..vdiv).fold(0.0, |error, j| {
+                let v = param(2 * j + 1, 2 * vdiv, (v0, v1));
+                let w = param(2 * j + 1, 2 * vdiv, (0.0, 1.0));
+                (0..=udiv).fold(error, |error, i| {
+                    let pt = self.subs(param(i, udiv, (t0, t1)), v);
+                    let dist = surface.subs(param(i, udiv, (0.0, 1.0)), w).distance(pt);
+                    f64::max(error, dist)
+                })
+            });
+            if uerror < tol && verror < tol {
+                return surface;
+            }
+            if uerror >= tol {
+                udiv *= 2;
+            }
+            if verror >= tol {
+                vdiv *= 2;
+            }
+            if udiv > MAX_DIVISION || vdiv > MAX_DIVISION {
+                return surface;
+            }
+        }
+    }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> Processor<HelicalSurface<C>, Matrix4> {
+    /// Returns the cubic B-spline surface approximating the whole surface within `tol`,
+    /// cf. [`HelicalSurface::approximation`].
+    pub fn approximation(&self, tol: f64) -> BSplineSurface<Point3> {
+        let matrix = *self.transform();
+        // The transformation may scale the errors.
+        let scale = f64::cbrt(matrix.determinant().abs());
+        let tol = if scale.so_small() { tol } else { tol / scale };
+        let mut surface = self.entity().approximation(tol);
+        surface.transform_control_points(|pt| *pt = matrix.transform_point(*pt));
+        if !self.orientation() {
+            surface.swap_axes();
+        }
+        surface
+    }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> SearchParameter<D2> for HelicalSurface<C> {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = self.presearch_hint(point, hint.into());
+        algo::surface::search_parameter3d(self, point, hint, trials)
+    }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> SearchNearestParameter<D2> for HelicalSurface<C> {
+    type Point = Point3;
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = self.presearch_hint(point, hint.into());
+        algo::surface::search_nearest_parameter(self, point, hint, trials)
+    }
+}
+
+impl<C, D> IncludeCurve<D> for HelicalSurface<C>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    D: ParametricCurve3D + BoundedCurve,
+{
+    fn include(&self, curve: &D) -> bool {
+        let (t0, t1) = curve.range_tuple();
+        let mut hint = match self.search_parameter(curve.front(), None, INCLUDE_CURVE_TRIALS) {
+            Some(hint) => hint,
+            None => return false,
+        };
+        (1..=PRESEARCH_DIVISION).all(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / PRESEARCH_DIVISION as f64;
+            let pt = curve.subs(t);
+            self.search_parameter(pt, Some(hint), INCLUDE_CURVE_TRIALS)
+                .or_else(|| self.search_parameter(pt, None, INCLUDE_CURVE_TRIALS))
+                .map(|res| hint = res)
+                .is_some()
+        })
+    }
+}
+
+impl<C> ParameterDivision2D for HelicalSurface<C>
+where C: ParametricCurve3D + ParameterDivision1D<Point = Point3>
+{
+    fn parameter_division(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let curve_division = self.curve.parameter_division(urange, tol);
+        let max = curve_division.1.into_iter().fold(0.0, |max, pt| {
+            let radius = pt - self.origin;
+            f64::max(max, radius.cross(self.axis).magnitude())
+        });
+        let acos = f64::acos(f64::max(1.0 - tol / max, -1.0));
+        let div: usize = 1 + ((vrange.1 - vrange.0).abs() / acos).floor() as usize;
+        let helix_division = (0..=div)
+            .map(|j| vrange.0 + (vrange.1 - vrange.0) * j as f64 / div as f64)
+            .collect();
+        (curve_division.0, helix_division)
+    }
+}
+
+#[test]
+fn helical_derivation_test() {
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(2.0, 0.0, 1.0),
+            Point3::new(1.5, 0.5, 2.0),
+        ],
+    );
+    let surface = HelicalSurface::by_helix(
+        curve,
+        Point3::new(0.1, -0.2, 0.3),
+        Vector3::new(1.0, 2.0, 2.0),
+        1.5,
+        6.0 * PI,
+    );
+    const EPS: f64 = 1.0e-4;
+    for i in 0..=10 {
+        for j in 0..=10 {
+            let (u, v) = (i as f64 / 10.0, 6.0 * PI * j as f64 / 10.0);
+            let uder = (surface.subs(u + EPS, v) - surface.subs(u - EPS, v)) / (2.0 * EPS);
+            assert!((surface.uder(u, v) - uder).magnitude() < 1.0e-6);
+            let vder = (surface.subs(u, v + EPS) - surface.subs(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.vder(u, v) - vder).magnitude() < 1.0e-6);
+            let uuder = (surface.uder(u + EPS, v) - surface.uder(u - EPS, v)) / (2.0 * EPS);
+            assert!((surface.uuder(u, v) - uuder).magnitude() < 1.0e-6);
+            let uvder = (surface.uder(u, v + EPS) - surface.uder(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.uvder(u, v) - uvder).magnitude() < 1.0e-6);
+            let vvder = (surface.vder(u, v + EPS) - surface.vder(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.vvder(u, v) - vvder).magnitude() < 1.0e-6);
+        }
+    }
+    // one turn advances the pitch along the axis
+    let axis = surface.axis();
+    let (p, q) = (surface.subs(0.3, 0.0), surface.subs(0.3, 2.0 * PI));
+    assert_near!(q, p + 1.5 * axis);
+}
+
+#[test]
+fn helical_search_parameter_test() {
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.5)],
+    );
+    let surface =
+        HelicalSurface::by_helix(curve, Point3::origin(), Vector3::unit_z(), 2.0, 6.0 * PI);
+    for (u, v) in [(0.3, 1.0), (0.8, 7.5), (0.5, 14.0)] {
+        let pt = surface.subs(u, v);
+        let (u0, v0) = surface.search_parameter(pt, None, 100).unwrap();
+        assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+        let pt = pt + 0.05 * surface.normal(u, v);
+        assert!(surface.search_parameter(pt, None, 100).is_none());
+        let (u0, v0) = surface.search_nearest_parameter(pt, None, 100).unwrap();
+        assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+    }
+}
@@ -24,15 +24,20 @@ where
         let pt0 = surface0.subs(uv0.0, uv0.1);
         uv1 = surface1.search_nearest_parameter(point, Some(uv1), 10)?;
         let pt1 = surface1.subs(uv1.0, uv1.1);
-        if point.near(&pt0) && point.near(&pt1) && pt0.near(&pt1) {
-            return Some((point, Point2::from(uv0), Point2::from(uv1)));
-        } else {
-            let n0 = surface0.normal(uv0.0, uv0.1);
-            let n1 = surface1.normal(uv1.0, uv1.1);
-            let mat = Matrix3::from_cols(n0, n1, normal).transpose();
-            let inv = mat.invert()?;
+        let converged = point.near(&pt0) && point.near(&pt1) && pt0.near(&pt1);
+        let n0 = surface0.normal(uv0.0, uv0.1);
+        let n1 = surface1.normal(uv1.0, uv1.1);
+        let mat = Matrix3::from_cols(n0, n1, normal).transpose();
+        let newton = mat.invert().map(|inv| {
             let pt = inv * Vector3::new(pt0.dot(n0), pt1.dot(n1), point.dot(normal));
-            point = Point3::from_vec(pt);
+            Point3::from_vec(pt)
+        });
+        match (converged, newton) {
+            // The last Newton step makes the point precise far beyond the tolerance.
+            (true, Some(pt)) => return Some((pt, Point2::from(uv0), Point2::from(uv1))),
+            (true, None) => return Some((point, Point2::from(uv0), Point2::from(uv1))),
+            (false, Some(pt)) => point = pt,
+            (false, None) => return None,
         }
     }
     #[cfg(all(test, debug_assertions))]
@@ -121,6 +126,46 @@ where
             100,
         )
     }
+
+    /// the derivation at `t`, where `p0` and `p1` are the uv coordinates given by `search_triple`
+    fn der_by_triple(&self, t: f64, p0: Point2, p1: Point2) -> Vector3 {
+        let n = self.leader.der(t);
+        let d = self
+            .surface0
+            .normal(p0.x, p0.y)
+            .cross(self.surface1.normal(p1.x, p1.y))
+            .normalize();
+        d * (n.dot(n) / d.dot(n))
+    }
+
+    /// Refines the parameter `t` to the foot of the perpendicular from `point` by Newton's method.
+    fn refine_nearest_parameter(&self, point: Point3, mut t: f64, trials: usize) -> Option<f64> {
+        let (t0, t1) = self.leader.parameter_range();
+        let clamp = move |t: f64| {
+            let t = match t0 {
+                Bound::Included(t0) | Bound::Excluded(t0) => f64::max(t, t0),
+                Bound::Unbounded => t,
+            };
+            match t1 {
+                Bound::Included(t1) | Bound::Excluded(t1) => f64::min(t, t1),
+                Bound::Unbounded => t,
+            }
+        };
+        for _ in 0..trials {
+            let (pt, p0, p1) = self.search_triple(t)?;
+            let der = self.der_by_triple(t, p0, p1);
+            if der.so_small() {
+                return None;
+            }
+            let dt = (pt - point).dot(der) / der.magnitude2();
+            let s = clamp(t - dt);
+            if (s - t).so_small() {
+                return Some(s);
+            }
+            t = s;
+        }
+        None
+    }
 }
 
 impl<C, S> ParametricCurve for IntersectionCurve<C, S>
@@ -132,14 +177,8 @@ where
     type Vector = Vector3;
     fn subs(&self, t: f64) -> Point3 { self.search_triple(t).unwrap().0 }
     fn der(&self, t: f64) -> Vector3 {
-        let n = self.leader.der(t);
         let (_, p0, p1) = self.search_triple(t).unwrap();
-        let d = self
-            .surface0
-            .normal(p0.x, p0.y)
-            .cross(self.surface1.normal(p1.x, p1.y))
-            .normalize();
-        d * (n.dot(n) / d.dot(n))
+        self.der_by_triple(t, p0, p1)
     }
     /// This method is unimplemented! Should panic!!
     #[inline(always)]
@@ -201,11 +240,8 @@ where
         hint: H,
         trials: usize,
     ) -> Option<f64> {
-        let t = self
-            .leader()
-            .search_nearest_parameter(point, hint, trials)
-            .unwrap();
-        let pt = self.subs(t);
+        let t = self.search_nearest_parameter(point, hint, trials)?;
+        let (pt, _, _) = self.search_triple(t)?;
         match pt.near(&point) {
             true => Some(t),
             false => None,
@@ -213,7 +249,8 @@ where
     }
 }
 
-/// Only derive from leading curve. Not precise.
+/// The parameter is derived from the leading curve, and refined by Newton's method on this curve.
+/// If the refinement does not converge, returns the parameter derived from the leading curve.
 impl<C, S> SearchNearestParameter<D1> for IntersectionCurve<C, S>
 where
     C: ParametricCurve3D + SearchNearestParameter<D1, Point = Point3>,
@@ -226,7 +263,10 @@ where
         hint: H,
         trials: usize,
     ) -> Option<f64> {
-        self.leader().search_nearest_parameter(point, hint, trials)
+        let t = self
+            .leader()
+            .search_nearest_parameter(point, hint, trials)?;
+        Some(self.refine_nearest_parameter(point, t, trials).unwrap_or(t))
     }
 }
 
@@ -251,3 +291,63 @@ impl<C: BoundedCurve> IntersectionCurve<C, Plane> {
         Line(self.leader.subs(s), self.leader.subs(t))
     }
 }
+
+#[test]
+fn cylinder_plane_intersection() {
+    use std::f64::consts::PI;
+    let line = |p: Point3, q: Point3| BSplineCurve::new(KnotVec::bezier_knot(1), vec![p, q]);
+    let revolution = |curve: BSplineCurve<Point3>| {
+        let surface = RevolutedCurve::by_revolution(curve, Point3::origin(), Vector3::unit_z());
+        Processor::<_, Matrix4>::new(surface)
+    };
+    let cylinder = revolution(line(
+        Point3::new(1.0, 0.0, -2.0),
+        Point3::new(1.0, 0.0, 2.0),
+    ));
+    // the plane z = 0 rotated around the x-axis
+    let mut plane = revolution(line(Point3::new(0.1, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0)));
+    plane.transform_by(Matrix4::from_angle_x(Rad(PI / 6.0)));
+    let plane_normal = Vector3::new(0.0, -f64::sin(PI / 6.0), f64::cos(PI / 6.0));
+
+    // the seed polyline on the ellipse, parametrized by the chord length
+    const N: usize = 16;
+    let points: Vec<Point3> = (0..=N)
+        .map(|i| {
+            let theta = 2.0 * PI * i as f64 / N as f64;
+            let (s, c) = theta.sin_cos();
+            Point3::new(c, s, s * f64::tan(PI / 6.0))
+        })
+        .collect();
+    let mut knots = vec![0.0, 0.0];
+    points.windows(2).for_each(|p| {
+        let last = *knots.last().unwrap();
+        knots.push(last + p[0].distance(p[1]));
+    });
+    knots.push(*knots.last().unwrap());
+    let leader = BSplineCurve::new(KnotVec::from(knots.clone()), points);
+    let curve = IntersectionCurve::new_unchecked(Box::new(cylinder), Box::new(plane), leader, 0.05);
+
+    for i in 1..=N {
+        // between the seed samples
+        let t = (knots[i] + knots[i + 1]) / 2.0;
+        let pt = curve.subs(t);
+        let on_cylinder = f64::abs(Vector2::new(pt.x, pt.y).magnitude() - 1.0);
+        assert!(on_cylinder < 1.0e-9, "{on_cylinder}");
+        let on_plane = f64::abs(pt.to_vec().dot(plane_normal));
+        assert!(on_plane < 1.0e-9, "{on_plane}");
+        // the chord of the seed is perpendicular to the plane fixing the parameter
+        let chord = curve.leader().subs(t);
+        assert!((pt - chord).dot(curve.leader().der(t)).so_small());
+        // the derivation is tangent to both surfaces
+        let der = curve.der(t);
+        assert!(der.dot(plane_normal).so_small());
+        assert!(der.dot(Vector3::new(pt.x, pt.y, 0.0)).so_small());
+
+        let s = curve.search_parameter(pt, None, 100).unwrap();
+        assert_near!(curve.subs(s), pt);
+        let s = curve.search_parameter(pt, t + 0.05, 100).unwrap();
+        assert_near!(s, t);
+        let far = pt + Vector3::new(pt.x, pt.y, 0.0) * 0.1;
+        assert!(curve.search_parameter(far, t, 100).is_none());
+    }
+}
@@ -43,6 +43,112 @@ pub struct RevolutedCurve<C> {
     revolution: Revolution,
 }
 
+/// surface constructed by sweeping a curve along a helix
+///
+/// The curve moves by the screw motion, the rotation around the axis and the translation along
+/// it, so the curve is fixed relative to the Frenet frame of the helix. The parameter `v` is the
+/// angle of the rotation, and its range is that of the sweep.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+/// let line = BSplineCurve::new(
+///     KnotVec::bezier_knot(1),
+///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)],
+/// );
+/// // helicoid advancing 3.0 per turn, with 2 turns
+/// let helicoid =
+///     HelicalSurface::by_helix(line, Point3::origin(), Vector3::unit_z(), 3.0, 4.0 * PI);
+/// assert_eq!(helicoid.range_tuple(), ((0.0, 1.0), (0.0, 4.0 * PI)));
+/// const N: usize = 30;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         let u = i as f64 / N as f64;
+///         let v = 4.0 * PI * j as f64 / N as f64;
+///         let r = 1.0 + u;
+///         let pt = Point3::new(r * f64::cos(v), r * f64::sin(v), 3.0 * v / (2.0 * PI));
+///         assert_near!(helicoid.subs(u, v), pt);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HelicalSurface<C> {
+    curve: C,
+    origin: Point3,
+    axis: Vector3,
+    pitch: f64,
+    vrange: (f64, f64),
+}
+
+/// The offset of a surface along its unit normal
+///
+/// The point at `(u, v)` is `surface.subs(u, v) + distance * surface.normal(u, v)`, and the
+/// derivations are computed from the derivation of the unit normal by the Weingarten map.
+/// The second derivations require the third derivations of `surface` given by
+/// [`ParametricSurface::der_mn`].
+///
+/// If the normal of `surface` is not defined at `(u, v)`, i.e. the first fundamental form
+/// degenerates and `surface.normal(u, v)` is not finite, the point of `surface` is returned.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+/// let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 1.0);
+/// let offset = OffsetSurface::new(sphere, 0.5);
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         let (u, v) = (PI * i as f64 / N as f64, 2.0 * PI * j as f64 / N as f64);
+///         assert_near!(offset.subs(u, v).distance(Point3::new(1.0, 2.0, 3.0)), 1.5);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OffsetSurface<S> {
+    surface: S,
+    distance: f64,
+}
+
+/// The surface whose parameters are transformed by affine maps and, optionally, swapped
+///
+/// The parameter `(u, v)` of the entity surface corresponds to
+/// `(u_scalar * u + u_move, v_scalar * v + v_move)`, whose components are swapped if `swap` is
+/// `true`. The derivations are given by the chain rule, and the hints of the parameter search
+/// are transformed into the ones of the entity surface.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+/// let line = BSplineCurve::new(
+///     KnotVec::bezier_knot(1),
+///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0)],
+/// );
+/// let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+/// let surface = ParameterTransformedSurface::new(cylinder, (2.0, 0.0), (1.0, -PI), true);
+/// assert_eq!(surface.try_range_tuple(), (Some((-PI, PI)), Some((0.0, 2.0))));
+///
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         let (u, v) = (i as f64 / N as f64, 2.0 * PI * j as f64 / N as f64);
+///         assert_near!(cylinder.subs(u, v), surface.subs(v - PI, 2.0 * u));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParameterTransformedSurface<S> {
+    surface: S,
+    transforms: (ParameterAffinity, ParameterAffinity),
+    swap: bool,
+}
+
+/// Affine transformation `t -> scalar * t + move` of a parameter
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+struct ParameterAffinity {
+    scalar: f64,
+    r#move: f64,
+}
+
 /// Linearly extruded curve
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExtrudedCurve<C, V> {
@@ -123,6 +229,12 @@ pub struct PCurve<C, S> {
 }
 
 /// Intersection curve between two surfaces.
+///
+/// The curve is evaluated lazily: the point at `t` is the intersection of the two surfaces and
+/// the plane through `leader.subs(t)` perpendicular to `leader.der(t)`, found by Newton's method.
+/// Hence, the parameterization is the one of the leading curve, e.g. the chord length if the leader
+/// is a polyline whose knots are the cumulative chord lengths, and the points can be evaluated at
+/// any parameter without re-tracing the intersection.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IntersectionCurve<C, S> {
     // Considering rotational surfaces, we can consider the case
@@ -142,8 +254,13 @@ pub struct TrimmedCurve<C> {
 
 mod curve_on_surface;
 mod extruded_curve;
+mod helical_surface;
 mod intersection_curve;
+mod offset_curve;
+mod offset_surface;
+mod parameter_transformed_surface;
 mod processor;
 mod revolved_curve;
 mod trimmied_curve;
 pub use intersection_curve::double_projection;
+pub use offset_curve::planar_offset;
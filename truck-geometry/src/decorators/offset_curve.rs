@@ -0,0 +1,225 @@
+use super::*;
+
+/// The maximum depth of the bisections in the cubic approximation of the offset.
+const OFFSET_APPROXIMATION_TRIALS: usize = 20;
+/// The number of the bisections searching the ends of the cusp regions.
+const CUSP_BISECTION_TRIALS: usize = 60;
+/// The step of the central difference approximating the second derivation of the offset.
+const DIFFERENCE_STEP: f64 = 1.0e-4;
+
+/// The exact offset of a planar curve, used as the target of the approximation.
+#[derive(Clone, Copy, Debug)]
+struct PlanarOffset<'a, C> {
+    curve: &'a C,
+    normal: Vector3,
+    distance: f64,
+}
+
+impl<'a, C: ParametricCurve3D> PlanarOffset<'a, C> {
+    /// Returns the in-plane unit normal of the curve and its derivation.
+    fn unit_normal(&self, t: f64) -> (Vector3, Vector3) {
+        let w = self.normal.cross(self.curve.der(t));
+        let dw = self.normal.cross(self.curve.der2(t));
+        let mag2 = w.magnitude2();
+        if mag2.so_small2() {
+            return (Vector3::zero(), Vector3::zero());
+        }
+        let mag = f64::sqrt(mag2);
+        (w / mag, (dw * mag2 - w * w.dot(dw)) / (mag2 * mag))
+    }
+    /// Returns whether the offset runs forward along the curve or not.
+    /// The offset runs backward on the cusp regions.
+    #[inline(always)]
+    fn is_forward(&self, t: f64) -> bool { self.der(t).dot(self.curve.der(t)) >= 0.0 }
+    /// Returns the parameter at which `is_forward` changes in `(t0, t1)`.
+    fn bisect(&self, (mut t0, mut t1): (f64, f64)) -> f64 {
+        let forward = self.is_forward(t0);
+        for _ in 0..CUSP_BISECTION_TRIALS {
+            let t = (t0 + t1) / 2.0;
+            match self.is_forward(t) == forward {
+                true => t0 = t,
+                false => t1 = t,
+            }
+        }
+        (t0 + t1) / 2.0
+    }
+}
+
+impl<'a, C: ParametricCurve3D> ParametricCurve for PlanarOffset<'a, C> {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Point3 { self.curve.subs(t) + self.unit_normal(t).0 * self.distance }
+    #[inline(always)]
+    fn der(&self, t: f64) -> Vector3 { self.curve.der(t) + self.unit_normal(t).1 * self.distance }
+    /// Approximated by the central difference, since the third derivation is not given.
+    #[inline(always)]
+    fn der2(&self, t: f64) -> Vector3 {
+        let h = DIFFERENCE_STEP;
+        (self.der(t + h) - self.der(t - h)) / (2.0 * h)
+    }
+}
+
+/// Approximates the offset of a planar curve by a cubic B-spline curve.
+///
+/// The offset is `curve.subs(t) + n(t) * distance`, where `n(t)` is the unit vector of
+/// `plane_normal.cross(curve.der(t))`, and the returned curve is within `tol` of it.
+/// `range` must satisfy `range.0 < range.1`.
+///
+/// # Cusps
+///
+/// If `distance` exceeds the radius of curvature on the side of the offset, the exact offset
+/// has two cusps and a loop between them. Such a cusp region is detected as the parameters
+/// on which the offset runs backward along `curve`, and the loop is trimmed at the
+/// self-intersection of the offset around the region. Hence, the returned curve has a corner
+/// at the self-intersection, and its parameter is not the same as the one of `curve` there.
+///
+/// Returns `None` if a cusp region has no self-intersection, e.g. the region reaches an end of
+/// `range` or the offset collapses entirely, or if the approximation fails.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+///
+/// let circle = UnitCircle::<Point3>::new();
+/// let range = (0.0, 2.0 * PI);
+/// let offset = planar_offset(&circle, range, -0.5, Vector3::unit_z(), 1.0e-3).unwrap();
+///
+/// let (t0, t1) = offset.range_tuple();
+/// const N: usize = 100;
+/// for i in 0..=N {
+///     let t = t0 + (t1 - t0) * i as f64 / N as f64;
+///     assert!(f64::abs(offset.subs(t).to_vec().magnitude() - 1.5) < 1.0e-3);
+/// }
+/// ```
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn planar_offset<C: ParametricCurve3D>(
+    curve: &C,
+    range: (f64, f64),
+    distance: f64,
+    plane_normal: Vector3,
+    tol: f64,
+) -> Option<BSplineCurve<Point3>> {
+    nonpositive_tolerance!(tol);
+    let offset = PlanarOffset {
+        curve,
+        normal: plane_normal.normalize(),
+        distance,
+    };
+    let (params, _) = algo::curve::parameter_division(&offset, range, tol);
+    let mut regions = Vec::new();
+    let mut start = (!offset.is_forward(range.0)).then_some(range.0);
+    params.windows(2).for_each(|t| {
+        if offset.is_forward(t[0]) == offset.is_forward(t[1]) {
+            return;
+        }
+        match start.take() {
+            Some(a) => regions.push((a, offset.bisect((t[0], t[1])))),
+            None => start = Some(offset.bisect((t[0], t[1]))),
+        }
+    });
+    if let Some(a) = start {
+        regions.push((a, range.1));
+    }
+
+    let mut pieces = Vec::new();
+    let mut t0 = range.0;
+    for (a, b) in regions {
+        if b <= t0 {
+            continue;
+        }
+        if a <= t0 || range.1 <= b {
+            return None;
+        }
+        let hits = algo::curve::intersections(&offset, (t0, a), &offset, (b, range.1), tol);
+        let gap = |(t, s, _): &(f64, f64, Point3)| (a - t) + (s - b);
+        let (t, s, _) = hits.into_iter().min_by(|x, y| gap(x).total_cmp(&gap(y)))?;
+        pieces.push((t0, t));
+        t0 = s;
+    }
+    pieces.push((t0, range.1));
+
+    let d_tol = f64::sqrt(tol);
+    let mut pieces = pieces.into_iter().map(|piece| {
+        let trials = OFFSET_APPROXIMATION_TRIALS;
+        BSplineCurve::cubic_approximation(&offset, piece, tol / 2.0, d_tol, trials)
+    });
+    let mut res = pieces.next()??;
+    for piece in pieces {
+        let mut piece = piece?;
+        piece.knot_translate(res.range_tuple().1 - piece.range_tuple().0);
+        res = res.try_concat(&piece).ok()?;
+    }
+    Some(res)
+}
+
+#[test]
+fn offset_circle() {
+    use std::f64::consts::PI;
+    let circle = UnitCircle::<Point3>::new();
+    let range = (0.0, 2.0 * PI);
+    for distance in [0.3, -0.3] {
+        let offset = planar_offset(&circle, range, distance, Vector3::unit_z(), 1.0e-3).unwrap();
+        let (t0, t1) = offset.range_tuple();
+        assert_near!(offset.front(), Point3::new(1.0 - distance, 0.0, 0.0));
+        const N: usize = 1000;
+        for i in 0..=N {
+            let t = t0 + (t1 - t0) * i as f64 / N as f64;
+            let pt = offset.subs(t);
+            assert!(f64::abs(pt.to_vec().magnitude() - (1.0 - distance)) < 1.0e-3);
+            assert_near!(pt.z, 0.0);
+        }
+    }
+    // the inner offset collapses
+    assert!(planar_offset(&circle, range, 1.5, Vector3::unit_z(), 1.0e-3).is_none());
+}
+
+#[test]
+fn offset_ellipse() {
+    use std::f64::consts::PI;
+    // the ellipse with the semi-axes 2 and 1
+    let ellipse = Processor::<_, Matrix4>::new(UnitCircle::<Point3>::new())
+        .transformed(Matrix4::from_nonuniform_scale(2.0, 1.0, 1.0));
+    let range = (-PI / 2.0, 3.0 * PI / 2.0);
+    // the exact offset sampled analytically
+    let exact = |t: f64, d: f64| {
+        let normal = Vector3::new(-f64::cos(t), -2.0 * f64::sin(t), 0.0).normalize();
+        Point3::new(2.0 * f64::cos(t), f64::sin(t), 0.0) + normal * d
+    };
+    let tol = 1.0e-3;
+
+    let offset = planar_offset(&ellipse, range, -0.4, Vector3::unit_z(), tol).unwrap();
+    const N: usize = 200;
+    for i in 0..=N {
+        let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+        let pt = exact(t, -0.4);
+        let s = offset
+            .search_nearest_parameter(pt, SPHint1D::None, 100)
+            .unwrap();
+        assert!(offset.subs(s).distance(pt) < tol);
+    }
+
+    // the radius of curvature is 0.5 at the ends of the major axis
+    let offset = planar_offset(&ellipse, range, 0.8, Vector3::unit_z(), tol).unwrap();
+    let (t0, t1) = offset.range_tuple();
+    let pts = (0..=N)
+        .map(|i| offset.subs(t0 + (t1 - t0) * i as f64 / N as f64))
+        .collect::<Vec<_>>();
+    let max_x = pts.iter().map(|pt| pt.x.abs()).fold(0.0, f64::max);
+    // the tips of the loops are at `x = 1.2`, and the self-intersections are at `x = 1.5 cos t`,
+    // where `sin^2 t = 0.52`.
+    assert!(max_x < 1.1);
+    // the points on the loops are nearer to the ellipse than `0.8`.
+    let samples = (0..=2000)
+        .map(|i| ellipse.subs(2.0 * PI * i as f64 / 2000.0))
+        .collect::<Vec<_>>();
+    pts.iter().for_each(|pt| {
+        let dist = samples
+            .iter()
+            .map(|q| q.distance(*pt))
+            .fold(f64::INFINITY, f64::min);
+        assert!(f64::abs(dist - 0.8) < 2.0 * tol);
+    });
+}
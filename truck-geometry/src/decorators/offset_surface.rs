@@ -0,0 +1,341 @@
+use super::*;
+
+/// The unit normal of the base surface and its derivations.
+#[derive(Clone, Copy, Debug)]
+struct NormalDerivations {
+    normal: Vector3,
+    uder: Vector3,
+    vder: Vector3,
+}
+
+impl<S> OffsetSurface<S> {
+    /// Creates the offset of `surface` by `distance` along its normal.
+    #[inline(always)]
+    pub const fn new(surface: S, distance: f64) -> Self { Self { surface, distance } }
+    /// Returns the base surface.
+    #[inline(always)]
+    pub const fn entity_surface(&self) -> &S { &self.surface }
+    /// Returns the base surface.
+    #[inline(always)]
+    pub fn into_entity_surface(self) -> S { self.surface }
+    /// Returns the offset distance.
+    #[inline(always)]
+    pub const fn distance(&self) -> f64 { self.distance }
+}
+
+impl<S: ParametricSurface3D> OffsetSurface<S> {
+    /// Returns the unit normal and its derivations by the Weingarten map, or `None` if the
+    /// first fundamental form degenerates.
+    fn normal_derivations(&self, u: f64, v: f64) -> Option<NormalDerivations> {
+        let surface = &self.surface;
+        let (su, sv) = (surface.uder(u, v), surface.vder(u, v));
+        // the first fundamental form
+        let (e, f, g) = (su.dot(su), su.dot(sv), sv.dot(sv));
+        let det = e * g - f * f;
+        if det.so_small2() {
+            return None;
+        }
+        let normal = su.cross(sv) / f64::sqrt(det);
+        // the second fundamental form
+        let l = surface.uuder(u, v).dot(normal);
+        let m = surface.uvder(u, v).dot(normal);
+        let n = surface.vvder(u, v).dot(normal);
+        Some(NormalDerivations {
+            normal,
+            uder: (su * (m * f - l * g) + sv * (l * f - m * e)) / det,
+            vder: (su * (n * f - m * g) + sv * (m * f - n * e)) / det,
+        })
+    }
+
+    /// Returns the second derivations of the unit normal, by `u` twice, `u` and `v`, and `v`
+    /// twice. The third derivations of the base surface are given by `der_mn`.
+    fn normal_second_derivations(&self, u: f64, v: f64) -> Option<[Vector3; 3]> {
+        let NormalDerivations { normal, uder, vder } = self.normal_derivations(u, v)?;
+        let der = |m: usize, n: usize| self.surface.der_mn(m, n, u, v);
+        let (su, sv) = (der(1, 0), der(0, 1));
+        let (suu, suv, svv) = (der(2, 0), der(1, 1), der(0, 2));
+        // the derivations of the non-normalized normal `su.cross(sv)`
+        let mag = su.cross(sv).magnitude();
+        let cross_u = suu.cross(sv) + su.cross(suv);
+        let cross_v = suv.cross(sv) + su.cross(svv);
+        let cross_uu = der(3, 0).cross(sv) + 2.0 * suu.cross(suv) + su.cross(der(2, 1));
+        let cross_uv = der(2, 1).cross(sv) + suu.cross(svv) + su.cross(der(1, 2));
+        let cross_vv = der(1, 2).cross(sv) + 2.0 * suv.cross(svv) + su.cross(der(0, 3));
+        // differentiates `uder` or `vder`, i.e. `(cross_a - normal * normal.dot(cross_a)) / mag`
+        let second = |cross_ab: Vector3, a: (Vector3, Vector3), b: (Vector3, Vector3)| {
+            let ((na, cross_a), (nb, cross_b)) = (a, b);
+            (cross_ab
+                - na * normal.dot(cross_b)
+                - nb * normal.dot(cross_a)
+                - normal * (mag * na.dot(nb) + normal.dot(cross_ab)))
+                / mag
+        };
+        Some([
+            second(cross_uu, (uder, cross_u), (uder, cross_u)),
+            second(cross_uv, (uder, cross_u), (vder, cross_v)),
+            second(cross_vv, (vder, cross_v), (vder, cross_v)),
+        ])
+    }
+}
+
+impl<S: ParametricSurface3D> ParametricSurface for OffsetSurface<S> {
+    type Point = Point3;
+    type Vector = Vector3;
+    /// Returns the point of the base surface moved by the distance along the normal.
+    /// If the normal is not defined, i.e. the normal of the base surface is not finite, returns
+    /// the point of the base surface.
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        let point = self.surface.subs(u, v);
+        let normal = self.surface.normal(u, v);
+        match normal.x.is_finite() && normal.y.is_finite() && normal.z.is_finite() {
+            true => point + self.distance * normal,
+            false => point,
+        }
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        let uder = self.surface.uder(u, v);
+        match self.normal_derivations(u, v) {
+            Some(ders) => uder + self.distance * ders.uder,
+            None => uder,
+        }
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        let vder = self.surface.vder(u, v);
+        match self.normal_derivations(u, v) {
+            Some(ders) => vder + self.distance * ders.vder,
+            None => vder,
+        }
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        let uuder = self.surface.uuder(u, v);
+        match self.normal_second_derivations(u, v) {
+            Some([nuu, _, _]) => uuder + self.distance * nuu,
+            None => uuder,
+        }
+    }
+    #[inline(always)]
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        let uvder = self.surface.uvder(u, v);
+        match self.normal_second_derivations(u, v) {
+            Some([_, nuv, _]) => uvder + self.distance * nuv,
+            None => uvder,
+        }
+    }
+    #[inline(always)]
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        let vvder = self.surface.vvder(u, v);
+        match self.normal_second_derivations(u, v) {
+            Some([_, _, nvv]) => vvder + self.distance * nvv,
+            None => vvder,
+        }
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) { self.surface.parameter_range() }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> { self.surface.u_period() }
+    #[inline(always)]
+    fn v_period(&self) -> Option<f64> { self.surface.v_period() }
+}
+
+impl<S: ParametricSurface3D> ParametricSurface3D for OffsetSurface<S> {
+    /// Returns the normal of the base surface, which is the normal of the offset unless the
+    /// offset passes through the center of curvature.
+    #[inline(always)]
+    fn normal(&self, u: f64, v: f64) -> Vector3 { self.surface.normal(u, v) }
+}
+
+impl<S: ParametricSurface3D + BoundedSurface> BoundedSurface for OffsetSurface<S> {}
+
+impl<S: Invertible> Invertible for OffsetSurface<S> {
+    /// Inverts the base surface and the sign of the distance, so the offset is the same set.
+    #[inline(always)]
+    fn invert(&mut self) {
+        self.surface.invert();
+        self.distance = -self.distance;
+    }
+    #[inline(always)]
+    fn inverse(&self) -> Self {
+        OffsetSurface {
+            surface: self.surface.inverse(),
+            distance: -self.distance,
+        }
+    }
+}
+
+impl<S> OffsetSurface<S>
+where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>
+{
+    /// The nearest point of the base surface has the same parameter as the one of the offset.
+    fn presearch_hint(&self, point: Point3, hint: SPHint2D, trials: usize) -> Option<(f64, f64)> {
+        match hint {
+            SPHint2D::Parameter(u, v) => Some((u, v)),
+            SPHint2D::Range(x, y) => Some(algo::surface::presearch(
+                self,
+                point,
+                (x, y),
+                PRESEARCH_DIVISION,
+            )),
+            SPHint2D::None => self.surface.search_nearest_parameter(point, None, trials),
+        }
+    }
+}
+
+impl<S> SearchParameter<D2> for OffsetSurface<S>
+where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>
+{
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = self.presearch_hint(point, hint.into(), trials)?;
+        algo::surface::search_parameter3d(self, point, hint, trials)
+    }
+}
+
+impl<S> SearchNearestParameter<D2> for OffsetSurface<S>
+where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>
+{
+    type Point = Point3;
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = self.presearch_hint(point, hint.into(), trials)?;
+        algo::surface::search_nearest_parameter(self, point, hint, trials)
+    }
+}
+
+impl<S, D> IncludeCurve<D> for OffsetSurface<S>
+where
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+    D: ParametricCurve3D + BoundedCurve,
+{
+    fn include(&self, curve: &D) -> bool {
+        let (t0, t1) = curve.range_tuple();
+        let mut hint = match self.search_parameter(curve.front(), None, INCLUDE_CURVE_TRIALS) {
+            Some(hint) => hint,
+            None => return false,
+        };
+        (1..=PRESEARCH_DIVISION).all(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / PRESEARCH_DIVISION as f64;
+            let pt = curve.subs(t);
+            self.search_parameter(pt, Some(hint), INCLUDE_CURVE_TRIALS)
+                .or_else(|| self.search_parameter(pt, None, INCLUDE_CURVE_TRIALS))
+                .map(|res| hint = res)
+                .is_some()
+        })
+    }
+}
+
+impl<S: ParametricSurface3D> ParameterDivision2D for OffsetSurface<S> {
+    #[inline(always)]
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        algo::surface::parameter_division(self, range, tol)
+    }
+}
+
+#[test]
+fn offset_derivation_test() {
+    let surface = BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
+        vec![
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(0.0, 0.5, 0.3),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            vec![
+                Point3::new(0.5, 0.0, 0.4),
+                Point3::new(0.5, 0.5, 1.0),
+                Point3::new(0.5, 1.0, -0.2),
+            ],
+            vec![
+                Point3::new(1.0, 0.0, 0.1),
+                Point3::new(1.0, 0.5, 0.2),
+                Point3::new(1.0, 1.0, 0.0),
+            ],
+        ],
+    );
+    let offset = OffsetSurface::new(surface, 0.3);
+    const EPS: f64 = 1.0e-4;
+    for i in 0..=10 {
+        for j in 0..=10 {
+            let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+            let uder = (offset.subs(u + EPS, v) - offset.subs(u - EPS, v)) / (2.0 * EPS);
+            assert!((offset.uder(u, v) - uder).magnitude() < 1.0e-6);
+            let vder = (offset.subs(u, v + EPS) - offset.subs(u, v - EPS)) / (2.0 * EPS);
+            assert!((offset.vder(u, v) - vder).magnitude() < 1.0e-6);
+            let uuder = (offset.uder(u + EPS, v) - offset.uder(u - EPS, v)) / (2.0 * EPS);
+            assert!((offset.uuder(u, v) - uuder).magnitude() < 1.0e-5);
+            let uvder = (offset.uder(u, v + EPS) - offset.uder(u, v - EPS)) / (2.0 * EPS);
+            assert!((offset.uvder(u, v) - uvder).magnitude() < 1.0e-5);
+            let vvder = (offset.vder(u, v + EPS) - offset.vder(u, v - EPS)) / (2.0 * EPS);
+            assert!((offset.vvder(u, v) - vvder).magnitude() < 1.0e-5);
+        }
+    }
+}
+
+#[test]
+fn offset_sphere_test() {
+    use std::f64::consts::PI;
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let sphere = Sphere::new(center, 1.5);
+    for distance in [0.5, -0.7] {
+        let offset = OffsetSurface::new(sphere, distance);
+        let answer = Sphere::new(center, 1.5 + distance);
+        const N: usize = 20;
+        for i in 0..=N {
+            for j in 0..=N {
+                let u = PI * i as f64 / N as f64;
+                let v = 2.0 * PI * j as f64 / N as f64;
+                assert_near!(offset.subs(u, v), answer.subs(u, v));
+                assert_near!(offset.normal(u, v), answer.normal(u, v));
+                if 0 < i && i < N {
+                    assert_near!(offset.uder(u, v), answer.uder(u, v));
+                    assert_near!(offset.vder(u, v), answer.vder(u, v));
+                }
+            }
+        }
+        let pt = answer.subs(1.0, 2.0);
+        let (u, v) = offset.search_parameter(pt, None, 100).unwrap();
+        assert_near!(Vector2::new(u, v), Vector2::new(1.0, 2.0));
+    }
+}
+
+#[test]
+fn offset_plane_test() {
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(2.0, 0.0, 1.0),
+        Point3::new(0.0, 3.0, 1.0),
+    );
+    let offset = OffsetSurface::new(plane, 0.25);
+    let vector = Vector3::new(0.0, 0.0, 0.25);
+    for (u, v) in [(0.0, 0.0), (0.3, 0.8), (-1.5, 2.0)] {
+        assert_near!(offset.subs(u, v), plane.subs(u, v) + vector);
+        assert_near!(offset.uder(u, v), plane.uder(u, v));
+        assert_near!(offset.vder(u, v), plane.vder(u, v));
+        assert!(offset.uuder(u, v).so_small());
+        assert!(offset.uvder(u, v).so_small());
+        assert!(offset.vvder(u, v).so_small());
+        assert_near!(offset.normal(u, v), Vector3::unit_z());
+    }
+    // degenerate surface: the base point is returned
+    let point = Plane::new(Point3::origin(), Point3::origin(), Point3::origin());
+    let offset = OffsetSurface::new(point, 1.0);
+    assert_near!(offset.subs(0.5, 0.5), Point3::origin());
+    assert_near!(offset.uder(0.5, 0.5), Vector3::zero());
+}
@@ -0,0 +1,358 @@
+use super::*;
+
+impl ParameterAffinity {
+    #[inline(always)]
+    fn new((scalar, r#move): (f64, f64)) -> Self {
+        assert!(scalar > 0.0, "The scalar {scalar} is not positive.");
+        Self { scalar, r#move }
+    }
+    #[inline(always)]
+    fn apply(self, t: f64) -> f64 { self.scalar * t + self.r#move }
+    #[inline(always)]
+    fn inverse_apply(self, t: f64) -> f64 { (t - self.r#move) / self.scalar }
+    #[inline(always)]
+    fn apply_bound(self, bound: Bound<f64>) -> Bound<f64> {
+        match bound {
+            Bound::Included(t) => Bound::Included(self.apply(t)),
+            Bound::Excluded(t) => Bound::Excluded(self.apply(t)),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+    #[inline(always)]
+    fn inverse_apply_range(self, (t0, t1): (f64, f64)) -> (f64, f64) {
+        (self.inverse_apply(t0), self.inverse_apply(t1))
+    }
+}
+
+impl<S> ParameterTransformedSurface<S> {
+    /// Creates the surface whose parameter is transformed from the one of `surface`.
+    ///
+    /// The parameter `(u, v)` of `surface` corresponds to
+    /// `(u_scalar * u + u_move, v_scalar * v + v_move)` of the returned surface, and the two
+    /// components are swapped if `swap` is `true`.
+    /// # Panics
+    /// Panic occurs if `u_scalar` or `v_scalar` is not positive.
+    #[inline(always)]
+    pub fn new(
+        surface: S,
+        (u_scalar, u_move): (f64, f64),
+        (v_scalar, v_move): (f64, f64),
+        swap: bool,
+    ) -> Self {
+        Self {
+            surface,
+            transforms: (
+                ParameterAffinity::new((u_scalar, u_move)),
+                ParameterAffinity::new((v_scalar, v_move)),
+            ),
+            swap,
+        }
+    }
+    /// Returns the surface before the transformation.
+    #[inline(always)]
+    pub const fn entity_surface(&self) -> &S { &self.surface }
+    /// Returns the surface before the transformation.
+    #[inline(always)]
+    pub fn into_entity_surface(self) -> S { self.surface }
+    /// Returns whether the parameters are swapped or not.
+    #[inline(always)]
+    pub const fn swapped(&self) -> bool { self.swap }
+
+    #[inline(always)]
+    fn swap<T>(&self, (u, v): (T, T)) -> (T, T) {
+        match self.swap {
+            true => (v, u),
+            false => (u, v),
+        }
+    }
+    /// Returns the parameter of `self` corresponding to the parameter `(u, v)` of the entity
+    /// surface.
+    #[inline(always)]
+    pub fn parameter_from_entity(&self, (u, v): (f64, f64)) -> (f64, f64) {
+        let (u_trans, v_trans) = self.transforms;
+        self.swap((u_trans.apply(u), v_trans.apply(v)))
+    }
+    /// Returns the parameter of the entity surface corresponding to the parameter `(u, v)` of
+    /// `self`.
+    #[inline(always)]
+    pub fn parameter_to_entity(&self, (u, v): (f64, f64)) -> (f64, f64) {
+        let (u_trans, v_trans) = self.transforms;
+        let (u, v) = self.swap((u, v));
+        (u_trans.inverse_apply(u), v_trans.inverse_apply(v))
+    }
+    /// Transforms the hint for `self` into the one for the entity surface.
+    fn hint_to_entity(&self, hint: SPHint2D) -> SPHint2D {
+        let (u_trans, v_trans) = self.transforms;
+        match hint {
+            SPHint2D::Parameter(u, v) => self.parameter_to_entity((u, v)).into(),
+            SPHint2D::Range(urange, vrange) => {
+                let (urange, vrange) = self.swap((urange, vrange));
+                SPHint2D::Range(
+                    u_trans.inverse_apply_range(urange),
+                    v_trans.inverse_apply_range(vrange),
+                )
+            }
+            SPHint2D::None => SPHint2D::None,
+        }
+    }
+    /// Returns the derivation of the entity parameter by the parameter of `self`.
+    #[inline(always)]
+    fn entity_parameter_derivation(&self) -> (f64, f64) {
+        let (u_trans, v_trans) = self.transforms;
+        (1.0 / u_trans.scalar, 1.0 / v_trans.scalar)
+    }
+}
+
+impl<S: ParametricSurface> ParametricSurface for ParameterTransformedSurface<S>
+where S::Vector: VectorSpace<Scalar = f64>
+{
+    type Point = S::Point;
+    type Vector = S::Vector;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Self::Point {
+        let (u, v) = self.parameter_to_entity((u, v));
+        self.surface.subs(u, v)
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Self::Vector {
+        let (s, t) = self.parameter_to_entity((u, v));
+        let (du, dv) = self.entity_parameter_derivation();
+        match self.swap {
+            true => self.surface.vder(s, t) * dv,
+            false => self.surface.uder(s, t) * du,
+        }
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> Self::Vector {
+        let (s, t) = self.parameter_to_entity((u, v));
+        let (du, dv) = self.entity_parameter_derivation();
+        match self.swap {
+            true => self.surface.uder(s, t) * du,
+            false => self.surface.vder(s, t) * dv,
+        }
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> Self::Vector {
+        let (s, t) = self.parameter_to_entity((u, v));
+        let (du, dv) = self.entity_parameter_derivation();
+        match self.swap {
+            true => self.surface.vvder(s, t) * (dv * dv),
+            false => self.surface.uuder(s, t) * (du * du),
+        }
+    }
+    #[inline(always)]
+    fn uvder(&self, u: f64, v: f64) -> Self::Vector {
+        let (s, t) = self.parameter_to_entity((u, v));
+        let (du, dv) = self.entity_parameter_derivation();
+        self.surface.uvder(s, t) * (du * dv)
+    }
+    #[inline(always)]
+    fn vvder(&self, u: f64, v: f64) -> Self::Vector {
+        let (s, t) = self.parameter_to_entity((u, v));
+        let (du, dv) = self.entity_parameter_derivation();
+        match self.swap {
+            true => self.surface.uuder(s, t) * (du * du),
+            false => self.surface.vvder(s, t) * (dv * dv),
+        }
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        let ((u0, u1), (v0, v1)) = self.surface.parameter_range();
+        let (u_trans, v_trans) = self.transforms;
+        self.swap((
+            (u_trans.apply_bound(u0), u_trans.apply_bound(u1)),
+            (v_trans.apply_bound(v0), v_trans.apply_bound(v1)),
+        ))
+    }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> {
+        let (u_trans, v_trans) = self.transforms;
+        match self.swap {
+            true => self.surface.v_period().map(|p| p * v_trans.scalar),
+            false => self.surface.u_period().map(|p| p * u_trans.scalar),
+        }
+    }
+    #[inline(always)]
+    fn v_period(&self) -> Option<f64> {
+        let (u_trans, v_trans) = self.transforms;
+        match self.swap {
+            true => self.surface.u_period().map(|p| p * u_trans.scalar),
+            false => self.surface.v_period().map(|p| p * v_trans.scalar),
+        }
+    }
+}
+
+impl<S: ParametricSurface3D> ParametricSurface3D for ParameterTransformedSurface<S> {
+    /// The normal is inverted if the parameters are swapped.
+    #[inline(always)]
+    fn normal(&self, u: f64, v: f64) -> Vector3 {
+        let (s, t) = self.parameter_to_entity((u, v));
+        match self.swap {
+            true => -self.surface.normal(s, t),
+            false => self.surface.normal(s, t),
+        }
+    }
+}
+
+impl<S: BoundedSurface> BoundedSurface for ParameterTransformedSurface<S> where S::Vector: VectorSpace<Scalar = f64> {}
+
+impl<S: Clone> Invertible for ParameterTransformedSurface<S> {
+    /// Swaps the parameters of `self`.
+    #[inline(always)]
+    fn invert(&mut self) { self.swap = !self.swap }
+    #[inline(always)]
+    fn inverse(&self) -> Self {
+        Self {
+            surface: self.surface.clone(),
+            transforms: self.transforms,
+            swap: !self.swap,
+        }
+    }
+}
+
+impl<S: Transformed<T>, T> Transformed<T> for ParameterTransformedSurface<S> {
+    #[inline(always)]
+    fn transform_by(&mut self, trans: T) { self.surface.transform_by(trans) }
+    #[inline(always)]
+    fn transformed(&self, trans: T) -> Self {
+        Self {
+            surface: self.surface.transformed(trans),
+            transforms: self.transforms,
+            swap: self.swap,
+        }
+    }
+}
+
+impl<S: SearchParameter<D2>> SearchParameter<D2> for ParameterTransformedSurface<S> {
+    type Point = S::Point;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: S::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = self.hint_to_entity(hint.into());
+        let res = self.surface.search_parameter(point, hint, trials)?;
+        Some(self.parameter_from_entity(res))
+    }
+    fn try_search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: S::Point,
+        hint: H,
+        trials: usize,
+    ) -> Result<(f64, f64), SearchError<(f64, f64)>> {
+        let hint = self.hint_to_entity(hint.into());
+        let from_entity = |x| self.parameter_from_entity(x);
+        self.surface
+            .try_search_parameter(point, hint, trials)
+            .map(from_entity)
+            .map_err(|e| e.map(from_entity))
+    }
+}
+
+impl<S: SearchNearestParameter<D2>> SearchNearestParameter<D2> for ParameterTransformedSurface<S> {
+    type Point = S::Point;
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: S::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = self.hint_to_entity(hint.into());
+        let res = self.surface.search_nearest_parameter(point, hint, trials)?;
+        Some(self.parameter_from_entity(res))
+    }
+}
+
+impl<S: IncludeCurve<C>, C: ParametricCurve> IncludeCurve<C> for ParameterTransformedSurface<S> {
+    #[inline(always)]
+    fn include(&self, curve: &C) -> bool { self.surface.include(curve) }
+}
+
+impl<S: ParameterDivision2D> ParameterDivision2D for ParameterTransformedSurface<S> {
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let (u_trans, v_trans) = self.transforms;
+        let (urange, vrange) = self.swap(range);
+        let range = (
+            u_trans.inverse_apply_range(urange),
+            v_trans.inverse_apply_range(vrange),
+        );
+        let (mut udiv, mut vdiv) = self.surface.parameter_division(range, tol);
+        udiv.iter_mut().for_each(|u| *u = u_trans.apply(*u));
+        vdiv.iter_mut().for_each(|v| *v = v_trans.apply(*v));
+        self.swap((udiv, vdiv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn cylinder() -> RevolutedCurve<BSplineCurve<Point3>> {
+        let line = BSplineCurve::new(
+            KnotVec::bezier_knot(1),
+            vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 2.0)],
+        );
+        RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z())
+    }
+
+    #[test]
+    fn transformed_cylinder() {
+        let cylinder = cylinder();
+        // (u, v) of the cylinder -> (2 * v + 1, 3 * u - 1)
+        let surface =
+            ParameterTransformedSurface::new(cylinder.clone(), (3.0, -1.0), (2.0, 1.0), true);
+        let (urange, vrange) = surface.try_range_tuple();
+        assert_eq!(urange, Some((1.0, 1.0 + 4.0 * PI)));
+        assert_eq!(vrange, Some((-1.0, 2.0)));
+        assert_eq!(surface.u_period(), Some(4.0 * PI));
+        assert_eq!(surface.v_period(), None);
+
+        const EPS: f64 = 1.0e-4;
+        for (s, t) in [(0.0, 0.5), (0.3, 1.0), (0.8, 4.0), (1.0, 6.0)] {
+            let (u, v) = (2.0 * t + 1.0, 3.0 * s - 1.0);
+            assert_near!(surface.subs(u, v), cylinder.subs(s, t));
+            let z = 2.0 * s;
+            let answer = Point3::new(f64::cos(t), f64::sin(t), z);
+            assert_near!(surface.subs(u, v), answer);
+            // swapped parameters invert the normal
+            assert_near!(surface.normal(u, v), -cylinder.normal(s, t));
+            let normal = surface.uder(u, v).cross(surface.vder(u, v)).normalize();
+            assert_near!(surface.normal(u, v), normal);
+
+            let uder = (surface.subs(u + EPS, v) - surface.subs(u - EPS, v)) / (2.0 * EPS);
+            assert!((surface.uder(u, v) - uder).magnitude() < 1.0e-6);
+            let vder = (surface.subs(u, v + EPS) - surface.subs(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.vder(u, v) - vder).magnitude() < 1.0e-6);
+            let uuder = (surface.uder(u + EPS, v) - surface.uder(u - EPS, v)) / (2.0 * EPS);
+            assert!((surface.uuder(u, v) - uuder).magnitude() < 1.0e-6);
+            let uvder = (surface.uder(u, v + EPS) - surface.uder(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.uvder(u, v) - uvder).magnitude() < 1.0e-6);
+            let vvder = (surface.vder(u, v + EPS) - surface.vder(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.vvder(u, v) - vvder).magnitude() < 1.0e-6);
+
+            // round trip of the parameter search
+            let hint = (u + 0.05, v - 0.05);
+            let (u0, v0) = surface.search_parameter(answer, hint, 100).unwrap();
+            assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+            let pt = answer + Vector3::new(answer.x, answer.y, 0.0) * 0.1;
+            assert!(surface.search_parameter(pt, hint, 100).is_none());
+            let (u0, v0) = surface.search_nearest_parameter(pt, hint, 100).unwrap();
+            assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+        }
+
+        // The division is the one of the cylinder.
+        let (udiv, vdiv) = surface.parameter_division(surface.range_tuple(), 0.01);
+        let (sdiv, tdiv) = cylinder.parameter_division(cylinder.range_tuple(), 0.01);
+        assert_eq!(udiv.len(), tdiv.len());
+        assert_eq!(vdiv.len(), sdiv.len());
+        udiv.iter()
+            .zip(&tdiv)
+            .for_each(|(u, t)| assert_near!(*u, 2.0 * t + 1.0));
+    }
+}
@@ -430,6 +430,18 @@ where
             .search_parameter(inv.transform_point(point), hint, trials)?;
         Some(self.get_curve_parameter(t))
     }
+    fn try_search_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: <E as SearchParameter<D1>>::Point,
+        hint: H,
+        trials: usize,
+    ) -> Result<f64, SearchError<f64>> {
+        let inv = self.transform.inverse_transform().unwrap();
+        self.entity
+            .try_search_parameter(inv.transform_point(point), hint, trials)
+            .map(|t| self.get_curve_parameter(t))
+            .map_err(|e| e.map(|t| self.get_curve_parameter(t)))
+    }
 }
 
 impl<E, T> SearchParameter<D2> for Processor<E, T>
@@ -454,6 +466,23 @@ where
             false => Some((v, u)),
         }
     }
+    fn try_search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: E::Point,
+        hint: H,
+        trials: usize,
+    ) -> Result<(f64, f64), SearchError<(f64, f64)>> {
+        let inv = self.transform.inverse_transform().unwrap();
+        let orientation = self.orientation;
+        let swap = move |(u, v): (f64, f64)| match orientation {
+            true => (u, v),
+            false => (v, u),
+        };
+        self.entity
+            .try_search_parameter(inv.transform_point(point), hint, trials)
+            .map(swap)
+            .map_err(|e| e.map(swap))
+    }
 }
 
 impl<P, E, T> SearchNearestParameter<D1> for Processor<E, T>
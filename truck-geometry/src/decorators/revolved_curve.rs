@@ -303,6 +303,83 @@ impl<C: ParametricCurve3D + BoundedCurve> RevolutedCurve<C> {
     pub fn is_back_fixed(&self) -> bool { self.revolution.contains(self.curve.back()) }
 }
 
+impl RevolutedCurve<NurbsCurve<Vector4>> {
+    /// into NURBS surface
+    ///
+    /// The parameter `u` is the one of the curve, and `v` is the one of the NURBS circle, which
+    /// coincides with the angle at the multiples of `PI / 2`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, PI / 2.0));
+    /// let curve = NurbsCurve::<Vector4>::from(arc);
+    /// // hemisphere
+    /// let surface = RevolutedCurve::by_revolution(curve, Point3::origin(), Vector3::unit_x());
+    /// let nurbs = surface.into_nurbs();
+    /// assert_eq!(nurbs.range_tuple(), ((0.0, PI / 2.0), (0.0, 2.0 * PI)));
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let u = PI / 2.0 * i as f64 / N as f64;
+    ///         let v = 2.0 * PI * j as f64 / N as f64;
+    ///         assert_near!(nurbs.subs(u, v).to_vec().magnitude(), 1.0);
+    ///     }
+    /// }
+    /// assert_near!(nurbs.subs(PI / 4.0, PI), surface.subs(PI / 4.0, PI));
+    /// ```
+    pub fn into_nurbs(&self) -> NurbsSurface<Vector4> {
+        let revolution = self.revolution;
+        specifieds::nurbs_revolution(&self.curve, revolution.origin, revolution.axis)
+    }
+}
+
+impl RevolutedCurve<BSplineCurve<Point3>> {
+    /// into NURBS surface
+    ///
+    /// The parameter `u` is the one of the curve, and `v` is the one of the NURBS circle, which
+    /// coincides with the angle at the multiples of `PI / 2`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0)],
+    /// );
+    /// // cone
+    /// let surface = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+    /// let nurbs = surface.into_nurbs();
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let u = i as f64 / N as f64;
+    ///         let v = 2.0 * PI * j as f64 / N as f64;
+    ///         let pt = nurbs.subs(u, v);
+    ///         assert_near!(pt.x * pt.x + pt.y * pt.y, pt.z * pt.z);
+    ///     }
+    /// }
+    /// ```
+    pub fn into_nurbs(&self) -> NurbsSurface<Vector4> {
+        let revolution = self.revolution;
+        let curve = NurbsCurve::from(self.curve.clone());
+        specifieds::nurbs_revolution(&curve, revolution.origin, revolution.axis)
+    }
+}
+
+impl RevolutedCurve<Line<Point3>> {
+    /// into NURBS surface
+    ///
+    /// The parameter `u` is the one of the line, and `v` is the one of the NURBS circle, which
+    /// coincides with the angle at the multiples of `PI / 2`.
+    pub fn into_nurbs(&self) -> NurbsSurface<Vector4> {
+        let revolution = self.revolution;
+        let curve = NurbsCurve::from(self.curve.to_bspline());
+        specifieds::nurbs_revolution(&curve, revolution.origin, revolution.axis)
+    }
+}
+
 impl<C: ParametricCurve3D + BoundedCurve> SearchParameter<D2> for RevolutedCurve<C> {
     type Point = Point3;
     fn search_parameter<H: Into<SPHint2D>>(
@@ -687,3 +764,46 @@ fn include_curve_abnormal1() {
     let line = BSplineCurve::new(KnotVec::bezier_knot(1), vec![pt0, pt1]);
     assert!(!surface.include(&line));
 }
+
+#[test]
+fn into_nurbs_test() {
+    use specifieds::nurbs_circle_parameter;
+    let assert_coincide = |surface: &dyn Fn(f64, f64) -> Point3, nurbs: &NurbsSurface<Vector4>| {
+        const N: usize = 10;
+        for i in 0..N {
+            for j in 0..N {
+                let u = (i as f64 + 0.5) / N as f64;
+                let v = 2.0 * PI * (j as f64 + 0.5) / N as f64;
+                let dist = surface(u, v).distance(nurbs.subs(u, nurbs_circle_parameter(v)));
+                assert!(dist < 1.0e-12, "{dist}");
+            }
+        }
+    };
+    let (origin, axis) = (Point3::new(1.0, 2.0, 3.0), Vector3::new(1.0, -1.0, 2.0));
+
+    // cylinder
+    let p = Point3::new(2.0, 0.0, 0.0);
+    let line = Line(p, p + 2.0 * axis);
+    let cylinder = RevolutedCurve::by_revolution(line, origin, axis);
+    assert_coincide(&|u, v| cylinder.subs(u, v), &cylinder.into_nurbs());
+
+    // cone
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![origin, Point3::new(0.0, 5.0, 1.0)],
+    );
+    let cone = RevolutedCurve::by_revolution(line, origin, axis);
+    assert_coincide(&|u, v| cone.subs(u, v), &cone.into_nurbs());
+
+    // rational profile
+    let curve = NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Vector4::new(0.0, 1.0, 0.0, 1.0),
+            Vector4::new(2.0, 2.0, 1.0, 2.0),
+            Vector4::new(-1.0, 0.5, 3.0, 0.5),
+        ],
+    ));
+    let surface = RevolutedCurve::by_revolution(curve, origin, axis);
+    assert_coincide(&|u, v| surface.subs(u, v), &surface.into_nurbs());
+}
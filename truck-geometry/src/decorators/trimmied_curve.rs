@@ -41,6 +41,7 @@ impl<C: ParametricCurve> Cut for TrimmedCurve<C> {
 
 impl<C: SearchNearestParameter<D1>> SearchNearestParameter<D1> for TrimmedCurve<C> {
     type Point = C::Point;
+    /// If no hint is given, the nearest parameter is searched in the trimmed range.
     #[inline(always)]
     fn search_nearest_parameter<H: Into<SPHint1D>>(
         &self,
@@ -48,6 +49,10 @@ impl<C: SearchNearestParameter<D1>> SearchNearestParameter<D1> for TrimmedCurve<
         hint: H,
         trials: usize,
     ) -> Option<f64> {
+        let hint = match hint.into() {
+            SPHint1D::None => SPHint1D::Range(self.range.0, self.range.1),
+            hint => hint,
+        };
         self.curve.search_nearest_parameter(pt, hint, trials)
     }
 }
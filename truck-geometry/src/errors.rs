@@ -100,6 +100,27 @@ the degree: {1}"
     /// ```
     #[error("The {0}th knot in this knot vector cannot be removed.")]
     CannotRemoveKnot(usize),
+    /// The specified knot cannot be removed without moving the curve more than the tolerance.
+    /// The second field is the gap between the control points solved from both sides of the knot.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(matches!(
+    ///     bspcurve.try_remove_knot_with_tolerance(3, 1.0e-3),
+    ///     Err(Error::CannotRemoveKnotWithinTolerance(3, _)),
+    /// ));
+    /// ```
+    #[error("The {0}th knot cannot be removed within the tolerance: the gap is {1}.")]
+    CannotRemoveKnotWithinTolerance(usize, f64),
     /// Empty vector of points cannot construct B-spline.
     /// # Examples
     /// ```
@@ -169,6 +190,63 @@ the number of control points: {1}"
     /// ```
     #[error("The vector of control points and the one of weights have different length.")]
     DifferentLength,
+    /// The number of points is too few to construct B-spline curves through the points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let points = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(1.0, 1.0)];
+    /// assert!(matches!(
+    ///     BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, EndCondition::Periodic),
+    ///     Err(Error::TooFewPoints(3, 4)),
+    /// ));
+    /// ```
+    #[error(
+        "The number of points is too few.
+the number of points: {0}
+the required number: {1}"
+    )]
+    TooFewPoints(usize, usize),
+    /// The linear system to construct B-spline curves is singular. For example, the system is
+    /// singular if the same points continue under the chord length parametrization.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let points = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    /// ];
+    /// assert!(matches!(
+    ///     BSplineCurve::interpolate(&points, 3, ParamMethod::ChordLength, EndCondition::Natural),
+    ///     Err(Error::SingularMatrix),
+    /// ));
+    /// ```
+    #[error("The linear system is singular.")]
+    SingularMatrix,
+    /// The boundary curves of a Coons patch do not meet at the corner `(u, v)`, where `u` and `v`
+    /// are `0` or `1`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let line = |p: Point2, q: Point2| BSplineCurve::new(KnotVec::bezier_knot(1), vec![p, q]);
+    /// let (p00, p10) = (Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+    /// let (p01, p11) = (Point2::new(0.0, 1.0), Point2::new(1.0, 1.0));
+    /// assert!(matches!(
+    ///     BSplineSurface::coons_patch(
+    ///         &line(p00, p10),
+    ///         &line(p01, Point2::new(1.0, 2.0)),
+    ///         &line(p00, p01),
+    ///         &line(p10, p11),
+    ///     ),
+    ///     Err(Error::MismatchedCorner(1, 1)),
+    /// ));
+    /// ```
+    #[error("The boundary curves do not meet at the corner ({0}, {1}).")]
+    MismatchedCorner(usize, usize),
 }
 
 #[test]
@@ -183,8 +261,13 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::NotSortedVector).unwrap();
     writeln!(stderr, "{}\n", Error::TooLargeDegree(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::CannotRemoveKnot(7)).unwrap();
+    writeln!(stderr, "{}\n", Error::CannotRemoveKnotWithinTolerance(7, 0.5)).unwrap();
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::DifferentLength).unwrap();
+    writeln!(stderr, "{}\n", Error::TooFewPoints(3, 4)).unwrap();
+    writeln!(stderr, "{}\n", Error::SingularMatrix).unwrap();
+    writeln!(stderr, "{}\n", Error::MismatchedCorner(1, 0)).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
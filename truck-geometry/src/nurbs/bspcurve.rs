@@ -159,6 +159,16 @@ impl<P> BSplineCurve<P> {
 }
 
 impl<P: ControlPoint<f64>> BSplineCurve<P> {
+    /// Wraps `t` into the parameter range if the curve is closed and `t` is out of the range.
+    #[inline(always)]
+    fn periodic_parameter(&self, t: f64) -> f64 {
+        let (t0, t1) = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        match (t < t0 || t1 < t, self.period()) {
+            (true, Some(period)) => t0 + f64::rem_euclid(t - t0, period),
+            _ => t,
+        }
+    }
+
     /// Returns the closure of substitution.
     /// # Examples
     /// The following test code is the same test with the one of `BSplineCurve::subs()`.
@@ -295,6 +305,7 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     /// ```
     #[inline(always)]
     fn subs(&self, t: f64) -> P {
+        let t = self.periodic_parameter(t);
         let basis = self
             .knot_vec
             .try_bspline_basis_functions(self.degree(), t)
@@ -321,6 +332,7 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     /// ```
     #[inline(always)]
     fn der(&self, t: f64) -> P::Diff {
+        let t = self.periodic_parameter(t);
         let k = self.degree();
         let knot_vec = self.knot_vec();
         let closure = move |sum: P::Diff, (i, b): (usize, f64)| {
@@ -356,6 +368,7 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     /// ```
     #[inline(always)]
     fn der2(&self, t: f64) -> P::Diff {
+        let t = self.periodic_parameter(t);
         let k = self.degree();
         if k < 2 {
             return P::Diff::zero();
@@ -373,6 +386,33 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
             * k as f64
             * (k - 1) as f64
     }
+    /// Substitutes to the `n`-th ord derived B-spline curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(0.0, 1.0),
+    ///     Vector2::new(1.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // bpscurve = (4t^3 - 6t^2 + 3t, -3t^2 + 3t), derived3 = (24, 0)
+    /// const N : usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let t = 1.0 / (N as f64) * (i as f64);
+    ///     assert_near2!(bspcurve.der_n(3, t), Vector2::new(24.0, 0.0));
+    ///     assert_near2!(bspcurve.der_n(4, t), Vector2::zero());
+    /// }
+    /// ```
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> P::Diff {
+        let t = self.periodic_parameter(t);
+        let coefs = self.control_points.iter().map(|pt| pt.to_vec()).collect();
+        bspline_der_n::<P>(&self.knot_vec, self.degree(), coefs, n, t)
+    }
     #[inline(always)]
     fn parameter_range(&self) -> ParameterRange {
         (
@@ -380,6 +420,42 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
             Bound::Included(self.knot_vec[self.knot_vec.len() - 1]),
         )
     }
+    /// Returns the length of the parameter range if the curve is clamped and closed, i.e. the
+    /// first and the last control points coincide. The closed curve is evaluated periodically
+    /// out of the parameter range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(0.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::uniform_knot(2, 3), ctrl_pts);
+    /// assert_eq!(bspcurve.period(), Some(1.0));
+    /// assert_near!(bspcurve.subs(1.25), bspcurve.subs(0.25));
+    /// assert_near!(bspcurve.der(-0.25), bspcurve.der(0.75));
+    ///
+    /// // the closed curve is searched across the seam.
+    /// let pt = bspcurve.subs(0.98);
+    /// let t = algo::curve::search_parameter(&bspcurve, pt, 0.05, 100).unwrap();
+    /// assert_near!(t, 0.98);
+    ///
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(1), ctrl_pts);
+    /// assert_eq!(bspcurve.period(), None);
+    /// ```
+    #[inline(always)]
+    fn period(&self) -> Option<f64> {
+        let (first, last) = (self.control_points[0], self.control_points.last().unwrap());
+        let period = self.knot_vec.range_length();
+        match (*last - first).is_zero() && self.is_clamped() && period > 0.0 {
+            true => Some(period),
+            false => None,
+        }
+    }
 }
 
 impl<P: ControlPoint<f64>> BoundedCurve for BSplineCurve<P> {}
@@ -557,6 +633,119 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         Ok(self)
     }
 
+    /// Removes the knot corresponding to the indice `idx` once by Tiller's algorithm.
+    ///
+    /// The new control points are solved from both sides of the knot, and the knot is removed
+    /// if the gap between the two solutions is at most `tol`. Unlike `try_remove_knot()`, the
+    /// curve may move slightly, and the gap is an estimation of the movement. If the knot cannot
+    /// be removed, `self` is not changed and returns
+    /// [`Error::CannotRemoveKnot`](./errors/enum.Error.html#variant.CannotRemoveKnot) or
+    /// [`Error::CannotRemoveKnotWithinTolerance`](./errors/enum.Error.html#variant.CannotRemoveKnotWithinTolerance).
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// bspcurve.add_knot(0.5).add_knot(0.5);
+    /// assert!(bspcurve.try_remove_knot_with_tolerance(4, 1.0e-9).is_ok());
+    /// assert!(bspcurve.try_remove_knot_with_tolerance(4, 1.0e-9).is_ok());
+    /// assert_eq!(bspcurve.knot_vec(), org_curve.knot_vec());
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    ///
+    /// // the end knots cannot be removed.
+    /// assert_eq!(
+    ///     bspcurve.try_remove_knot_with_tolerance(0, 1.0),
+    ///     Err(Error::CannotRemoveKnot(0)),
+    /// );
+    /// ```
+    pub fn try_remove_knot_with_tolerance(&mut self, idx: usize, tol: f64) -> Result<&mut Self>
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let degree = self.degree();
+        let (r, control_points, gap) =
+            super::remove_knot_points(&self.knot_vec, degree, &self.control_points, idx)
+                .ok_or(Error::CannotRemoveKnot(idx))?;
+        if gap > tol {
+            return Err(Error::CannotRemoveKnotWithinTolerance(idx, gap));
+        }
+        self.control_points = control_points;
+        self.knot_vec.remove(r);
+        Ok(self)
+    }
+
+    /// Removes knots greedily from the back while the curve stays within `tol` of the original
+    /// curve, and returns the number of the removed knots.
+    ///
+    /// Each removal is tested by `try_remove_knot_with_tolerance()`, and is applied only if the
+    /// maximum deviation from the original curve, sampled on the spans around the knot, is at
+    /// most `tol`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// bspcurve.add_knot(0.2).add_knot(0.5).add_knot(0.5).add_knot(0.7);
+    /// assert_eq!(bspcurve.reduce_knots(1.0e-9), 4);
+    /// assert_eq!(bspcurve.knot_vec(), org_curve.knot_vec());
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// ```
+    pub fn reduce_knots(&mut self, tol: f64) -> usize
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let original = self.clone();
+        let degree = self.degree();
+        let mut count = 0;
+        'removal: loop {
+            for idx in (0..self.knot_vec.len()).rev() {
+                let mut curve = self.clone();
+                if curve.try_remove_knot_with_tolerance(idx, tol).is_err() {
+                    continue;
+                }
+                let (i0, i1) = (idx.saturating_sub(degree), idx + degree);
+                let range = (self.knot_vec[i0], self.knot_vec[i1]);
+                if original.sampled_deviation(&curve, range) <= tol {
+                    *self = curve;
+                    count += 1;
+                    continue 'removal;
+                }
+            }
+            return count;
+        }
+    }
+
+    /// Returns the maximum distance between `self` and `other` sampled on the knot spans of
+    /// `self` in `range`.
+    fn sampled_deviation(&self, other: &Self, (t0, t1): (f64, f64)) -> f64
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let division = 2 * (self.degree() + 1);
+        self.knot_vec
+            .windows(2)
+            .filter(|span| t0 <= span[0] && span[0] < span[1] && span[1] <= t1)
+            .flat_map(|span| {
+                (0..=division).map(move |i| {
+                    let s = i as f64 / division as f64;
+                    span[0] * (1.0 - s) + span[1] * s
+                })
+            })
+            .map(|t| (self.subs(t) - other.subs(t)).magnitude())
+            .fold(0.0, f64::max)
+    }
+
     /// elevate 1 degree for bezier curve.
     fn elevate_degree_bezier(&mut self) -> &mut Self {
         let k = self.degree();
@@ -594,6 +783,164 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Elevates `times` degrees of the Bezier curves decomposed from `self` and concats them.
+    /// The multiplicity of each interior knot becomes the new degree.
+    pub(super) fn elevate_degree_decomposed(&mut self, times: usize) -> &mut Self {
+        let mut result = CurveCollector::Singleton;
+        for mut bezier in self.bezier_decomposition() {
+            for _ in 0..times {
+                bezier.elevate_degree_bezier();
+            }
+            result.concat(&bezier);
+        }
+        *self = result.unwrap();
+        self
+    }
+
+    /// Removes the knots inserted by the Bezier decomposition as far as `self` does not change.
+    /// Each interior knot of `knot_vec`, the knot vector before the decomposition with `degree`,
+    /// is removed until its multiplicity decreases to `mult + self.degree() - degree`, where
+    /// `mult` is the original multiplicity.
+    fn remove_decomposition_knots(&mut self, knot_vec: &KnotVec, degree: usize) {
+        let (knots, mults) = knot_vec.to_single_multi();
+        let len = knots.len();
+        for (knot, mult) in knots.into_iter().zip(mults).take(len - 1).skip(1) {
+            let target = (mult + self.degree()).saturating_sub(degree);
+            let count = self.knot_vec.iter().filter(|t| t.near(&knot)).count();
+            for _ in target..count {
+                // The first knot in the run can be removed by `try_remove_knot`.
+                let idx = self.knot_vec.iter().position(|t| t.near(&knot)).unwrap();
+                if self.try_remove_knot(idx).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Elevates `times` degrees.
+    ///
+    /// The curve is decomposed into Bezier curves, each Bezier curve is elevated, and they are
+    /// recombined. Different from repeating `elevate_degree()`, the multiplicities of the interior
+    /// knots increase only by `times`, which is the least to keep the continuity.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// bspcurve.elevate_degree_by(2);
+    /// assert_eq!(bspcurve.degree(), 4);
+    /// assert_eq!(
+    ///     bspcurve.knot_vec(),
+    ///     &KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0]),
+    /// );
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// ```
+    pub fn elevate_degree_by(&mut self, times: usize) -> &mut Self {
+        if times == 0 {
+            return self;
+        }
+        let (knot_vec, degree) = (self.knot_vec.clone(), self.degree());
+        self.elevate_degree_decomposed(times);
+        self.remove_decomposition_knots(&knot_vec, degree);
+        self
+    }
+
+    /// Reduces 1 degree of the bezier curve. Returns `None` if the control points of the reduced
+    /// curve elevated again differ from the ones of `self` more than `tol`.
+    fn reduce_degree_bezier(&self, tol: f64) -> Option<Self>
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let p = self.degree();
+        let pts = &self.control_points;
+        let alpha = |i: usize| i as f64 / p as f64;
+        // solves the new control points from the both ends.
+        let h = (p - 1) / 2;
+        let mut left = vec![pts[0]];
+        for (i, pt) in pts.iter().enumerate().take(h + 1).skip(1) {
+            let q = *left.last().unwrap();
+            left.push(q + (*pt - q) / (1.0 - alpha(i)));
+        }
+        let mut right = vec![pts[p]];
+        for (i, pt) in pts.iter().enumerate().take(p).skip(h + 2).rev() {
+            let q = *right.last().unwrap();
+            right.push(q + (*pt - q) / alpha(i));
+        }
+        let points: Vec<P> = left.into_iter().chain(right.into_iter().rev()).collect();
+
+        let error = (1..p)
+            .map(|i| {
+                let q = points[i - 1] + (points[i] - points[i - 1]) * (1.0 - alpha(i));
+                (pts[i] - q).magnitude()
+            })
+            .fold(0.0, f64::max);
+        let (t0, t1) = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        let knot_vec = KnotVec::from([vec![t0; p], vec![t1; p]].concat());
+        if error <= tol {
+            Some(BSplineCurve::new(knot_vec, points))
+        } else {
+            None
+        }
+    }
+
+    /// Reduces 1 degree if the curve can be represented by the lower degree within `tol`.
+    /// Returns `false` and does not change `self` if the degree cannot be reduced.
+    ///
+    /// Each Bezier curve decomposed from `self` is reduced and elevated again, and the reduction
+    /// succeeds if the control points of the elevated one are within `tol` from the original
+    /// ones. Since the Bezier curves are bounded by the control points, the reduced curve is also
+    /// within `tol` from `self`. The curve with degree less than 2 is not reduced.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.elevate_degree_by(1);
+    /// assert!(bspcurve.reduce_degree(1.0e-9));
+    /// assert_eq!(bspcurve.knot_vec(), org_curve.knot_vec());
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    ///
+    /// // quadratic curves cannot be represented by lines.
+    /// assert!(!bspcurve.reduce_degree(1.0e-9));
+    /// assert_eq!(bspcurve.degree(), 2);
+    /// ```
+    pub fn reduce_degree(&mut self, tol: f64) -> bool
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let degree = self.degree();
+        if degree < 2 {
+            return false;
+        }
+        let beziers = self
+            .bezier_decomposition()
+            .iter()
+            .map(|bezier| bezier.reduce_degree_bezier(tol))
+            .collect::<Option<Vec<_>>>();
+        let Some(beziers) = beziers else {
+            return false;
+        };
+        let mut result = CurveCollector::Singleton;
+        for bezier in &beziers {
+            result.concat(bezier);
+        }
+        let knot_vec = std::mem::replace(self, result.unwrap()).knot_vec;
+        self.remove_decomposition_knots(&knot_vec, degree);
+        true
+    }
+
     /// Makes the B-spline curve clamped
     /// # Examples
     /// ```
@@ -926,6 +1273,32 @@ fn parameter_transform_random_test() {
     truck_geotrait::parameter_transform_random_test(&curve, 10);
 }
 
+#[test]
+fn der_n_random_test() {
+    let curve = BSplineCurve::new(
+        KnotVec::from(vec![
+            0.0, 0.0, 0.0, 0.0, 0.0, 0.3, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0,
+        ]),
+        (0..8)
+            .map(|_| Vector3::new(rand::random(), rand::random(), rand::random()))
+            .collect(),
+    );
+    const N: usize = 100;
+    for i in 0..N {
+        // the knots are avoided for the finite differences
+        let t = (i as f64 + 0.5) / N as f64;
+        assert_near!(curve.der_n(0, t), curve.subs(t));
+        assert_near!(curve.der_n(1, t), curve.der(t));
+        assert_near!(curve.der_n(2, t), curve.der2(t));
+        let h = 1.0e-5;
+        let der3 = (curve.der2(t + h) - curve.der2(t - h)) / (2.0 * h);
+        assert!((curve.der_n(3, t) - der3).magnitude() < 1.0e-3 * der3.magnitude().max(1.0));
+        let der4 = (curve.der_n(3, t + h) - curve.der_n(3, t - h)) / (2.0 * h);
+        assert!((curve.der_n(4, t) - der4).magnitude() < 1.0e-3 * der4.magnitude().max(1.0));
+        assert_near!(curve.der_n(5, t), Vector3::zero());
+    }
+}
+
 impl<P: ControlPoint<f64> + Tolerance> Cut for BSplineCurve<P> {
     fn cut(&mut self, mut t: f64) -> BSplineCurve<P> {
         let degree = self.degree();
@@ -980,6 +1353,137 @@ fn cut_random_test() {
     truck_geotrait::cut_random_test(&curve, 10);
 }
 
+#[test]
+fn elevate_reduce_degree_random_test() {
+    let org_curve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 4),
+        (0..7)
+            .map(|_| {
+                Point3::new(
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                )
+            })
+            .collect(),
+    );
+    let mut curve = org_curve.clone();
+    curve.elevate_degree_by(2);
+    assert_eq!(curve.degree(), 5);
+    assert!(curve.near2_as_curve(&org_curve));
+    assert!(curve.reduce_degree(1.0e-9));
+    assert!(curve.reduce_degree(1.0e-9));
+    assert!(!curve.reduce_degree(1.0e-9));
+    assert_eq!(curve.degree(), 3);
+    assert_eq!(curve.knot_vec().len(), org_curve.knot_vec().len());
+    assert!(curve.near2_as_curve(&org_curve));
+}
+
+#[test]
+fn reduce_knots_random_test() {
+    let org_curve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 4),
+        (0..7)
+            .map(|_| {
+                Point3::new(
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                )
+            })
+            .collect(),
+    );
+    let mut curve = org_curve.clone();
+    for _ in 0..10 {
+        curve.add_knot(rand::random::<f64>());
+    }
+    assert_eq!(curve.knot_vec().len(), org_curve.knot_vec().len() + 10);
+    assert_eq!(curve.reduce_knots(1.0e-9), 10);
+    assert_eq!(curve.knot_vec().len(), org_curve.knot_vec().len());
+    curve
+        .knot_vec()
+        .iter()
+        .zip(org_curve.knot_vec())
+        .for_each(|(t0, t1)| assert_near!(t0, t1));
+    curve
+        .control_points()
+        .iter()
+        .zip(org_curve.control_points())
+        .for_each(|(pt0, pt1)| assert_near!(pt0, pt1));
+}
+
+impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
+    /// Returns the part of the curve restricted to `(t0, t1)`, clamped at the cut ends.
+    pub(super) fn cut_out(&self, (t0, t1): (f64, f64)) -> BSplineCurve<P> {
+        let (a, b) = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        let mut curve = self.clone();
+        if t0 > a + TOLERANCE {
+            curve = curve.cut(t0);
+        }
+        if t1 < b - TOLERANCE {
+            curve.cut(t1);
+        }
+        curve
+    }
+}
+
+impl<P: ControlPoint<f64> + Tolerance> ControlHull<D1> for BSplineCurve<P> {
+    type Point = P;
+    /// Returns the control points of the part of the curve restricted to `range`.
+    /// If the part is not clamped, the origin is also returned, since the sum of the B-spline
+    /// basis functions is less than one near the ends of non-clamped knot vectors.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)],
+    /// );
+    /// // the control polygon of the whole curve reaches `y = 2`, the curve does `y = 1`.
+    /// assert_near!(curve.hull_bounding_box((0.0, 1.0)).max(), Point2::new(2.0, 2.0));
+    /// // the part is more tightly bounded.
+    /// let bdb = curve.hull_bounding_box((0.0, 0.25));
+    /// assert_near!(bdb.max(), Point2::new(0.5, 0.75));
+    /// ```
+    fn control_hull(&self, range: (f64, f64)) -> Vec<P> {
+        let curve = self.cut_out(range);
+        let clamped = curve.is_clamped();
+        let mut points = curve.control_points;
+        if !clamped {
+            points.push(P::origin());
+        }
+        points
+    }
+}
+
+#[test]
+fn control_hull_random_test() {
+    let curve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 5),
+        (0..8)
+            .map(|_| {
+                Point3::new(
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                )
+            })
+            .collect(),
+    );
+    let (t0, t1) = (
+        0.3 * rand::random::<f64>(),
+        0.7 + 0.3 * rand::random::<f64>(),
+    );
+    let bdb = curve.hull_bounding_box((t0, t1));
+    assert!(bdb.diagonal().magnitude() <= curve.roughly_bounding_box().diagonal().magnitude());
+    for i in 0..=100 {
+        let t = t0 + (t1 - t0) * i as f64 / 100.0;
+        let pt = curve.subs(t);
+        let (min, max) = (bdb.min(), bdb.max());
+        assert!((0..3).all(|i| min[i] - TOLERANCE <= pt[i] && pt[i] <= max[i] + TOLERANCE));
+    }
+}
+
 impl<P: ControlPoint<f64> + Tolerance> Concat<BSplineCurve<P>> for BSplineCurve<P> {
     type Output = BSplineCurve<P>;
     /// Concats two B-spline curves.
@@ -1205,7 +1709,14 @@ where
         let hint = match hint.into() {
             SPHint1D::Parameter(hint) => hint,
             SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
+                let hint = algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION);
+                return algo::curve::search_nearest_parameter_bounded(
+                    self,
+                    point,
+                    hint,
+                    (x, y),
+                    trial,
+                );
             }
             SPHint1D::None => {
                 algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
@@ -1224,6 +1735,14 @@ where
     type Point = P;
     #[inline(always)]
     fn search_parameter<H: Into<SPHint1D>>(&self, point: P, hint: H, trial: usize) -> Option<f64> {
+        self.try_search_parameter(point, hint, trial).ok()
+    }
+    fn try_search_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: P,
+        hint: H,
+        trial: usize,
+    ) -> Result<f64, SearchError<f64>> {
         let hint = match hint.into() {
             SPHint1D::Parameter(hint) => hint,
             SPHint1D::Range(x, y) => {
@@ -1233,7 +1752,30 @@ where
                 algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
-        algo::curve::search_parameter(self, point, hint, trial)
+        algo::curve::try_search_parameter(self, point, hint, trial)
+    }
+}
+
+#[test]
+fn try_search_parameter_test() {
+    let ctrl_pts = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 0.0),
+    ];
+    let curve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    let t = curve
+        .try_search_parameter(curve.subs(0.3), 0.5, 100)
+        .unwrap();
+    assert_near!(t, 0.3);
+
+    let res = curve.try_search_parameter(Point2::new(1.0, 1.5), 0.4, 100);
+    match res {
+        Err(SearchError::OffGeometry { nearest, distance }) => {
+            assert_near!(nearest, 0.5);
+            assert_near!(distance, 1.0);
+        }
+        _ => panic!("unexpected result: {res:?}"),
     }
 }
 
@@ -1355,6 +1897,38 @@ fn test_parameter_division() {
     }
 }
 
+#[test]
+fn test_uniform_arc_length_division() {
+    let knot_vec = KnotVec::uniform_knot(2, 3);
+    let ctrl_pts = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let range = bspcurve.range_tuple();
+    let total = algo::curve::arc_length(&bspcurve, range, TOLERANCE);
+    let n = 20;
+    let (div, pts) = algo::curve::uniform_arc_length_division(&bspcurve, range, n);
+    assert_eq!(div.len(), n + 1);
+    assert_eq!((div[0], div[n]), range);
+    let mut previous = 0.0;
+    for i in 1..=n {
+        assert_eq!(bspcurve.subs(div[i]), pts[i]);
+        let length = algo::curve::arc_length(&bspcurve, (range.0, div[i]), TOLERANCE);
+        assert!(previous < length);
+        assert!(f64::abs(length - total * i as f64 / n as f64) < 10.0 * TOLERANCE);
+        previous = length;
+    }
+
+    let s = total / 3.0;
+    let t = algo::curve::param_at_arc_length(&bspcurve, range, s, TOLERANCE);
+    let length = algo::curve::arc_length(&bspcurve, (range.0, t), TOLERANCE);
+    assert!(f64::abs(length - s) < 10.0 * TOLERANCE);
+}
+
 impl<P> BSplineCurve<P>
 where
     P: ControlPoint<f64>
@@ -838,6 +838,42 @@ impl<P: ControlPoint<f64>> ParametricSurface for BSplineSurface<P> {
             * degree0 as f64
             * degree1 as f64
     }
+    /// Substitutes the B-spline surface derived `m` times by `u` and `n` times by `v`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// // bspsurface: (v, 2v(1 - v)(2u - 1) + u), derived by u once and v twice: (0.0, -8.0)
+    /// const N: usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let u = (i as f64) / (N as f64);
+    ///     for j in 0..=N {
+    ///         let v = (j as f64) / (N as f64);
+    ///         assert_near2!(bspsurface.der_mn(1, 2, u, v), Vector2::new(0.0, -8.0));
+    ///         assert_near2!(bspsurface.der_mn(2, 1, u, v), Vector2::zero());
+    ///     }
+    /// }
+    /// ```
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> P::Diff {
+        let (degree0, degree1) = self.degrees();
+        let (uknot_vec, vknot_vec) = self.knot_vecs();
+        let coefs = self
+            .control_points
+            .iter()
+            .map(|vec| {
+                let coefs = vec.iter().map(|pt| pt.to_vec()).collect();
+                bspline_der_n::<P>(vknot_vec, degree1, coefs, n, v)
+            })
+            .collect();
+        bspline_der_n::<P>(uknot_vec, degree0, coefs, m, u)
+    }
     #[inline(always)]
     fn parameter_range(&self) -> (ParameterRange, ParameterRange) { self.parameter_range() }
 }
@@ -1196,6 +1232,182 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Removes the uknot corresponding to the indice `idx` once by Tiller's algorithm.
+    ///
+    /// The knot is removed if, for every column of control points, the gap between the two
+    /// solutions from both sides of the knot is at most `tol`. See also
+    /// [`BSplineCurve::try_remove_knot_with_tolerance`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 2.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.add_uknot(0.3);
+    /// assert!(bspsurface.try_remove_uknot_with_tolerance(3, 1.0e-9).is_ok());
+    /// assert_eq!(bspsurface.uknot_vec(), org_surface.uknot_vec());
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn try_remove_uknot_with_tolerance(&mut self, idx: usize, tol: f64) -> Result<&mut Self>
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let degree = self.udegree();
+        let (mut r, mut gap) = (idx, 0.0);
+        let columns = (0..self.control_points[0].len())
+            .map(|j| {
+                let points: Vec<P> = self.control_points.iter().map(|vec| vec[j]).collect();
+                let (r0, new_points, gap0) =
+                    super::remove_knot_points(&self.knot_vecs.0, degree, &points, idx)
+                        .ok_or(Error::CannotRemoveKnot(idx))?;
+                r = r0;
+                gap = f64::max(gap, gap0);
+                Ok(new_points)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if gap > tol {
+            return Err(Error::CannotRemoveKnotWithinTolerance(idx, gap));
+        }
+        let n = self.control_points.len() - 1;
+        self.control_points = (0..n)
+            .map(|i| columns.iter().map(|column| column[i]).collect())
+            .collect();
+        self.knot_vecs.0.remove(r);
+        Ok(self)
+    }
+
+    /// Removes the vknot corresponding to the indice `idx` once by Tiller's algorithm.
+    ///
+    /// The knot is removed if, for every row of control points, the gap between the two
+    /// solutions from both sides of the knot is at most `tol`. See also
+    /// [`BSplineCurve::try_remove_knot_with_tolerance`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 2.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.add_vknot(0.3);
+    /// assert!(bspsurface.try_remove_vknot_with_tolerance(3, 1.0e-9).is_ok());
+    /// assert_eq!(bspsurface.vknot_vec(), org_surface.vknot_vec());
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn try_remove_vknot_with_tolerance(&mut self, idx: usize, tol: f64) -> Result<&mut Self>
+    where P::Diff: InnerSpace<Scalar = f64> {
+        self.swap_axes();
+        let res = self.try_remove_uknot_with_tolerance(idx, tol).map(|_| ());
+        self.swap_axes();
+        res.map(move |_| self)
+    }
+
+    /// Removes uknots greedily from the back while the surface stays within `tol` of the
+    /// original surface, and returns the number of the removed knots.
+    /// See also [`BSplineCurve::reduce_knots`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 2.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.add_uknot(0.3).add_uknot(0.6).add_uknot(0.6);
+    /// assert_eq!(bspsurface.reduce_uknots(1.0e-9), 3);
+    /// assert_eq!(bspsurface.uknot_vec(), org_surface.uknot_vec());
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn reduce_uknots(&mut self, tol: f64) -> usize
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let original = self.clone();
+        let degree = self.udegree();
+        let mut count = 0;
+        'removal: loop {
+            for idx in (0..self.knot_vecs.0.len()).rev() {
+                let mut surface = self.clone();
+                if surface.try_remove_uknot_with_tolerance(idx, tol).is_err() {
+                    continue;
+                }
+                let (i0, i1) = (idx.saturating_sub(degree), idx + degree);
+                let range = (self.knot_vecs.0[i0], self.knot_vecs.0[i1]);
+                if original.sampled_udeviation(&surface, range) <= tol {
+                    *self = surface;
+                    count += 1;
+                    continue 'removal;
+                }
+            }
+            return count;
+        }
+    }
+
+    /// Removes vknots greedily from the back while the surface stays within `tol` of the
+    /// original surface, and returns the number of the removed knots.
+    /// See also [`BSplineCurve::reduce_knots`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 2.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.add_vknot(0.3).add_vknot(0.6).add_vknot(0.6);
+    /// assert_eq!(bspsurface.reduce_vknots(1.0e-9), 3);
+    /// assert_eq!(bspsurface.vknot_vec(), org_surface.vknot_vec());
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn reduce_vknots(&mut self, tol: f64) -> usize
+    where P::Diff: InnerSpace<Scalar = f64> {
+        self.swap_axes();
+        let count = self.reduce_uknots(tol);
+        self.swap_axes();
+        count
+    }
+
+    /// Returns the maximum distance between `self` and `other` sampled on the knot spans of
+    /// `self` whose u-parameters are between `u0` and `u1`.
+    fn sampled_udeviation(&self, other: &Self, (u0, u1): (f64, f64)) -> f64
+    where P::Diff: InnerSpace<Scalar = f64> {
+        let sample = |knot_vec: &KnotVec, degree: usize, (t0, t1): (f64, f64)| {
+            let division = 2 * (degree + 1);
+            knot_vec
+                .windows(2)
+                .filter(|span| t0 <= span[0] && span[0] < span[1] && span[1] <= t1)
+                .flat_map(|span| {
+                    (0..=division).map(move |i| {
+                        let s = i as f64 / division as f64;
+                        span[0] * (1.0 - s) + span[1] * s
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let (udegree, vdegree) = self.degrees();
+        let whole = (f64::NEG_INFINITY, f64::INFINITY);
+        let vs = sample(&self.knot_vecs.1, vdegree, whole);
+        sample(&self.knot_vecs.0, udegree, (u0, u1))
+            .into_iter()
+            .flat_map(|u| vs.iter().map(move |v| (u, *v)))
+            .map(|(u, v)| (self.subs(u, v) - other.subs(u, v)).magnitude())
+            .fold(0.0, f64::max)
+    }
+
     /// Elevates the vdegree.
     /// # Examples
     /// ```
@@ -1257,6 +1469,85 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Elevates `times` vdegrees. cf. [`BSplineCurve::elevate_degree_by`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::uniform_knot(2, 2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0), Vector2::new(1.5, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0), Vector2::new(1.5, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 2.0), Vector2::new(1.5, 2.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.elevate_vdegree_by(2);
+    ///
+    /// assert_eq!(bspsurface.udegree(), org_surface.udegree());
+    /// assert_eq!(bspsurface.vdegree(), org_surface.vdegree() + 2);
+    /// // the multiplicity of the interior knot `0.5` increases by 2.
+    /// assert_eq!(bspsurface.vknot_vec().multiplicity(5), 3);
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn elevate_vdegree_by(&mut self, times: usize) -> &mut Self {
+        if times == 0 {
+            return self;
+        }
+        let mut new_knot_vec = KnotVec::new();
+        for (i, vec) in self.control_points.iter_mut().enumerate() {
+            let mut curve = BSplineCurve::new(self.knot_vecs.1.clone(), vec.clone());
+            curve.elevate_degree_decomposed(times);
+            if i == 0 {
+                new_knot_vec = curve.knot_vec().clone();
+            }
+            *vec = curve.control_points;
+        }
+        let (knots, mults) =
+            std::mem::replace(&mut self.knot_vecs.1, new_knot_vec).to_single_multi();
+
+        // removes the knots inserted by the Bezier decomposition.
+        let len = knots.len();
+        for (knot, mult) in knots.into_iter().zip(mults).take(len - 1).skip(1) {
+            let count = self.knot_vecs.1.iter().filter(|t| t.near(&knot)).count();
+            for _ in mult + times..count {
+                let idx = self.knot_vecs.1.iter().position(|t| t.near(&knot)).unwrap();
+                if self.try_remove_vknot(idx).is_err() {
+                    break;
+                }
+            }
+        }
+        self
+    }
+
+    /// Elevates `times` udegrees. cf. [`BSplineCurve::elevate_degree_by`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(0.0, 2.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 2.0)],
+    ///     vec![Vector2::new(0.0, 3.0), Vector2::new(0.5, 3.0), Vector2::new(1.0, 3.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    ///
+    /// bspsurface.elevate_udegree_by(1);
+    ///
+    /// assert_eq!(bspsurface.udegree(), org_surface.udegree() + 1);
+    /// assert_eq!(bspsurface.vdegree(), org_surface.vdegree());
+    /// assert_eq!(bspsurface.uknot_vec().len(), org_surface.uknot_vec().len() + 3);
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn elevate_udegree_by(&mut self, times: usize) -> &mut Self {
+        self.swap_axes();
+        self.elevate_vdegree_by(times);
+        self.swap_axes();
+        self
+    }
+
     /// Aligns the udegree with the same degrees.
     /// # Examples
     /// ```
@@ -1696,6 +1987,103 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         BSplineSurface::new(knot_vecs, control_points)
     }
 
+    /// Creates the bilinearly blended Coons patch with the boundary curves `c0`, `c1`, `d0` and
+    /// `d1`, i.e. `c0` and `c1` are the boundaries at `v = 0` and `v = 1`, and `d0` and `d1` are
+    /// the ones at `u = 0` and `u = 1`.
+    ///
+    /// The opposite curves are made compatible by the degree elevation and the knot insertion, and
+    /// the knot vectors of the patch are normalized.
+    /// # Failures
+    /// If the curves do not meet at a corner, returns [`Error::MismatchedCorner`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let c0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    /// );
+    /// let c1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    /// );
+    /// let d0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    /// );
+    /// let d1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(3),
+    ///     vec![
+    ///         Point3::new(1.0, 0.0, 0.0),
+    ///         Point3::new(1.0, 0.3, -1.0),
+    ///         Point3::new(1.0, 0.6, 1.0),
+    ///         Point3::new(1.0, 1.0, 0.0),
+    ///     ],
+    /// );
+    /// let surface = BSplineSurface::coons_patch(&c0, &c1, &d0, &d1).unwrap();
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_near!(surface.subs(t, 0.0), c0.subs(t));
+    ///     assert_near!(surface.subs(t, 1.0), c1.subs(t));
+    ///     assert_near!(surface.subs(0.0, t), d0.subs(t));
+    ///     assert_near!(surface.subs(1.0, t), d1.subs(t));
+    /// }
+    /// ```
+    pub fn coons_patch(
+        c0: &BSplineCurve<P>,
+        c1: &BSplineCurve<P>,
+        d0: &BSplineCurve<P>,
+        d1: &BSplineCurve<P>,
+    ) -> Result<BSplineSurface<P>> {
+        let corners = [
+            (c0.front(), d0.front(), (0, 0)),
+            (c0.back(), d1.front(), (1, 0)),
+            (c1.front(), d0.back(), (0, 1)),
+            (c1.back(), d1.back(), (1, 1)),
+        ];
+        if let Some((_, _, (u, v))) = corners.iter().find(|(p, q, _)| !p.near(q)) {
+            return Err(Error::MismatchedCorner(*u, *v));
+        }
+        let (mut c0, mut c1) = (c0.clone(), c1.clone());
+        c0.syncro_degree(&mut c1);
+        c0.syncro_knots(&mut c1);
+        let (mut d0, mut d1) = (d0.clone(), d1.clone());
+        d0.syncro_degree(&mut d1);
+        d0.syncro_knots(&mut d1);
+
+        // The linear functions are expressed by the Greville abscissae.
+        let greville = |curve: &BSplineCurve<P>| -> Vec<f64> {
+            let (knot_vec, degree) = (curve.knot_vec(), curve.degree());
+            (0..curve.control_points().len())
+                .map(|i| knot_vec[i + 1..=i + degree].iter().sum::<f64>() / degree as f64)
+                .collect()
+        };
+        let (us, vs) = (greville(&c0), greville(&d0));
+        let (p00, p10) = (c0.front(), c0.back());
+        let (p01, p11) = (c1.front(), c1.back());
+        let control_points = us
+            .iter()
+            .enumerate()
+            .map(|(i, u)| {
+                vs.iter()
+                    .enumerate()
+                    .map(|(j, v)| {
+                        let ruled_c = c0.control_points[i]
+                            + (c1.control_points[i] - c0.control_points[i]) * *v;
+                        let ruled_d = (d0.control_points[j] - P::origin()) * (1.0 - u)
+                            + (d1.control_points[j] - P::origin()) * *u;
+                        let bilinear = (p00 - P::origin()) * ((1.0 - u) * (1.0 - v))
+                            + (p10 - P::origin()) * (u * (1.0 - v))
+                            + (p01 - P::origin()) * ((1.0 - u) * v)
+                            + (p11 - P::origin()) * (u * v);
+                        ruled_c + (ruled_d - bilinear)
+                    })
+                    .collect()
+            })
+            .collect();
+        let knot_vecs = (c0.knot_vec().clone(), d0.knot_vec().clone());
+        Ok(BSplineSurface::new_unchecked(knot_vecs, control_points))
+    }
+
     /// Normalizes the knot vectors
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -1851,6 +2239,45 @@ impl<V: Bounded> BSplineSurface<V> {
     }
 }
 
+impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
+    /// Returns the part of the surface restricted to `(urange, vrange)`, clamped at the cut ends.
+    pub(super) fn cut_out(&self, ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64))) -> Self {
+        let (uknot_vec, vknot_vec) = &self.knot_vecs;
+        let (ua, ub) = (uknot_vec[0], uknot_vec[uknot_vec.len() - 1]);
+        let (va, vb) = (vknot_vec[0], vknot_vec[vknot_vec.len() - 1]);
+        let mut surface = self.clone();
+        if u0 > ua + TOLERANCE {
+            surface = surface.ucut(u0);
+        }
+        if u1 < ub - TOLERANCE {
+            surface.ucut(u1);
+        }
+        if v0 > va + TOLERANCE {
+            surface = surface.vcut(v0);
+        }
+        if v1 < vb - TOLERANCE {
+            surface.vcut(v1);
+        }
+        surface
+    }
+}
+
+impl<P: ControlPoint<f64> + Tolerance> ControlHull<D2> for BSplineSurface<P> {
+    type Point = P;
+    /// Returns the control points of the part of the surface restricted to `range`.
+    /// If the part is not clamped, the origin is also returned, since the sum of the B-spline
+    /// basis functions is less than one near the ends of non-clamped knot vectors.
+    fn control_hull(&self, range: ((f64, f64), (f64, f64))) -> Vec<P> {
+        let surface = self.cut_out(range);
+        let clamped = surface.is_clamped();
+        let mut points = surface.control_points.concat();
+        if !clamped {
+            points.push(P::origin());
+        }
+        points
+    }
+}
+
 impl<P: ControlPoint<f64>> ParameterDivision2D for BSplineSurface<P>
 where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
         + MetricSpace<Metric = f64>
@@ -1877,12 +2304,21 @@ impl<V: Clone> Invertible for BSplineSurface<V> {
 
 impl SearchParameter<D2> for BSplineSurface<Point2> {
     type Point = Point2;
+    #[inline(always)]
     fn search_parameter<H: Into<SPHint2D>>(
         &self,
         point: Point2,
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
+        self.try_search_parameter(point, hint, trials).ok()
+    }
+    fn try_search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point2,
+        hint: H,
+        trials: usize,
+    ) -> Result<(f64, f64), SearchError<(f64, f64)>> {
         let hint = match hint.into() {
             SPHint2D::Parameter(x, y) => (x, y),
             SPHint2D::Range(range0, range1) => {
@@ -1892,18 +2328,27 @@ impl SearchParameter<D2> for BSplineSurface<Point2> {
                 algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
-        algo::surface::search_parameter2d(self, point, hint, trials)
+        algo::surface::try_search_parameter2d(self, point, hint, trials)
     }
 }
 
 impl SearchParameter<D2> for BSplineSurface<Point3> {
     type Point = Point3;
+    #[inline(always)]
     fn search_parameter<H: Into<SPHint2D>>(
         &self,
         point: Point3,
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
+        self.try_search_parameter(point, hint, trials).ok()
+    }
+    fn try_search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Result<(f64, f64), SearchError<(f64, f64)>> {
         let hint = match hint.into() {
             SPHint2D::Parameter(x, y) => (x, y),
             SPHint2D::Range(range0, range1) => {
@@ -1913,7 +2358,7 @@ impl SearchParameter<D2> for BSplineSurface<Point3> {
                 algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
-        algo::surface::search_parameter3d(self, point, hint, trials)
+        algo::surface::try_search_parameter3d(self, point, hint, trials)
     }
 }
 
@@ -2228,3 +2673,113 @@ fn test_include_bspcurve3() {
     *curve.control_point_mut(2) += Vector3::new(0.0, 0.0, 0.001);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn der_mn_random_test() {
+    let knot_vecs = (KnotVec::uniform_knot(3, 2), KnotVec::uniform_knot(2, 3));
+    let ctrl_pts = (0..5)
+        .map(|_| {
+            (0..5)
+                .map(|_| Vector3::new(rand::random(), rand::random(), rand::random()))
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    const N: usize = 10;
+    for (i, j) in (0..N).flat_map(|i| (0..N).map(move |j| (i, j))) {
+        // the knots are avoided for the finite differences
+        let (u, v) = ((i as f64 + 0.5) / N as f64, (j as f64 + 0.5) / N as f64);
+        assert_near!(surface.der_mn(0, 0, u, v), surface.subs(u, v));
+        assert_near!(surface.der_mn(1, 0, u, v), surface.uder(u, v));
+        assert_near!(surface.der_mn(0, 1, u, v), surface.vder(u, v));
+        assert_near!(surface.der_mn(2, 0, u, v), surface.uuder(u, v));
+        assert_near!(surface.der_mn(1, 1, u, v), surface.uvder(u, v));
+        assert_near!(surface.der_mn(0, 2, u, v), surface.vvder(u, v));
+        let h = 1.0e-5;
+        let deltas = [
+            ((3, 0), surface.uuder(u + h, v) - surface.uuder(u - h, v)),
+            ((2, 1), surface.uuder(u, v + h) - surface.uuder(u, v - h)),
+            ((1, 2), surface.vvder(u + h, v) - surface.vvder(u - h, v)),
+            ((0, 3), surface.vvder(u, v + h) - surface.vvder(u, v - h)),
+        ];
+        for ((m, n), delta) in deltas {
+            let der = delta / (2.0 * h);
+            let err = (surface.der_mn(m, n, u, v) - der).magnitude();
+            assert!(err < 1.0e-3 * der.magnitude().max(1.0));
+        }
+        assert_near!(surface.der_mn(4, 0, u, v), Vector3::zero());
+        assert_near!(surface.der_mn(1, 3, u, v), Vector3::zero());
+    }
+}
+
+#[test]
+fn control_hull_random_test() {
+    let knot_vecs = (KnotVec::uniform_knot(2, 3), KnotVec::uniform_knot(3, 2));
+    let control_points = (0..5)
+        .map(|_| {
+            (0..5)
+                .map(|_| {
+                    Point3::new(
+                        rand::random::<f64>(),
+                        rand::random::<f64>(),
+                        rand::random::<f64>(),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(knot_vecs, control_points);
+    let ((u0, u1), (v0, v1)) = ((0.1, 0.8), (0.25, 0.9));
+    let bdb = surface.hull_bounding_box(((u0, u1), (v0, v1)));
+    let (min, max) = (bdb.min(), bdb.max());
+    for i in 0..=20 {
+        for j in 0..=20 {
+            let u = u0 + (u1 - u0) * i as f64 / 20.0;
+            let v = v0 + (v1 - v0) * j as f64 / 20.0;
+            let pt = surface.subs(u, v);
+            assert!((0..3).all(|i| min[i] - TOLERANCE <= pt[i] && pt[i] <= max[i] + TOLERANCE));
+        }
+    }
+}
+
+#[test]
+fn coons_patch_plane_test() {
+    let random_point = || {
+        Point3::new(
+            rand::random::<f64>(),
+            rand::random::<f64>(),
+            rand::random::<f64>(),
+        )
+    };
+    let plane = Plane::new(random_point(), random_point(), random_point());
+    let line = |u0: f64, v0: f64, u1: f64, v1: f64| {
+        BSplineCurve::new(
+            KnotVec::bezier_knot(1),
+            vec![plane.subs(u0, v0), plane.subs(u1, v1)],
+        )
+    };
+    let mut c0 = line(0.0, 0.0, 1.0, 0.0);
+    c0.elevate_degree_by(2);
+    (0..3).for_each(|_| {
+        c0.add_knot(rand::random::<f64>());
+    });
+    let c1 = line(0.0, 1.0, 1.0, 1.0);
+    let mut d0 = line(0.0, 0.0, 0.0, 1.0);
+    d0.elevate_degree_by(1).add_knot(0.3).add_knot(0.6);
+    let d1 = line(1.0, 0.0, 1.0, 1.0);
+
+    let surface = BSplineSurface::coons_patch(&c0, &c1, &d0, &d1).unwrap();
+    assert_eq!((surface.udegree(), surface.vdegree()), (3, 2));
+    for i in 0..=10 {
+        for j in 0..=10 {
+            let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+            assert_near!(surface.subs(u, v), plane.subs(u, v));
+        }
+    }
+
+    let moved = line(0.0, 0.0, 1.0, 0.5);
+    assert!(matches!(
+        BSplineSurface::coons_patch(&moved, &c1, &d0, &d1),
+        Err(Error::MismatchedCorner(1, 0)),
+    ));
+}
@@ -0,0 +1,547 @@
+use super::*;
+use crate::errors::Error;
+
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Constructs the B-spline curve with `degree` passing through `points`.
+    ///
+    /// The parameters of the points are assigned by `param` and normalized to `[0, 1]`, and the
+    /// curve is clamped on `[0, 1]`. Two more control points than `points` are solved with the
+    /// conditions `ends` at the ends, or, in the case of [`EndCondition::Periodic`], the curve is
+    /// closed at the first point.
+    /// # Failures
+    /// - If the number of points is too few for `degree`, returns [`Error::TooFewPoints`].
+    /// - If the linear system is singular, returns [`Error::SingularMatrix`]. The conditions
+    /// [`EndCondition::Natural`] and [`EndCondition::Clamped`] require `degree >= 2`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(3.0, 1.0),
+    /// ];
+    /// let ends = EndCondition::Clamped(Vector2::new(3.0, 3.0), Vector2::new(3.0, 3.0));
+    /// let bspcurve = BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, ends).unwrap();
+    /// assert_near!(bspcurve.subs(0.0), points[0]);
+    /// assert_near!(bspcurve.subs(1.0 / 3.0), points[1]);
+    /// assert_near!(bspcurve.subs(2.0 / 3.0), points[2]);
+    /// assert_near!(bspcurve.subs(1.0), points[3]);
+    /// assert_near!(bspcurve.der(0.0), Vector2::new(3.0, 3.0));
+    /// assert_near!(bspcurve.der(1.0), Vector2::new(3.0, 3.0));
+    /// ```
+    pub fn interpolate(
+        points: &[P],
+        degree: usize,
+        param: ParamMethod,
+        ends: EndCondition<P::Diff>,
+    ) -> Result<Self> {
+        let (d0, d1, order) = match ends {
+            EndCondition::Periodic => return Self::periodic_interpolate(points, degree, param),
+            EndCondition::Natural => (P::Diff::zero(), P::Diff::zero(), 2),
+            EndCondition::Clamped(d0, d1) => (d0, d1, 1),
+        };
+        let m = points.len();
+        let required = usize::max(2, degree.saturating_sub(1));
+        if m < required {
+            return Err(Error::TooFewPoints(m, required));
+        }
+        let params = parameters(points, param, false);
+
+        // the interior knots are the averages of the parameters.
+        let mut knots = vec![0.0; degree + 1];
+        knots.extend(
+            (0..m + 1 - degree).map(|j| params[j..j + degree].iter().sum::<f64>() / degree as f64),
+        );
+        knots.extend(vec![1.0; degree + 1]);
+        let knot_vec = KnotVec::from(knots);
+
+        let mut matrix = Vec::with_capacity(m + 2);
+        let mut rhs = Vec::with_capacity(m + 2);
+        for (i, (t, pt)) in params.iter().zip(points).enumerate() {
+            if i == m - 1 {
+                matrix.push(basis_der_n(&knot_vec, degree, order, 1.0));
+                rhs.push(d1);
+            }
+            matrix.push(knot_vec.bspline_basis_functions(degree, *t));
+            rhs.push(*pt - P::origin());
+            if i == 0 {
+                matrix.push(basis_der_n(&knot_vec, degree, order, 0.0));
+                rhs.push(d0);
+            }
+        }
+        let control_points = solve(matrix, rhs)
+            .ok_or(Error::SingularMatrix)?
+            .into_iter()
+            .map(|vec| P::origin() + vec)
+            .collect();
+        Ok(BSplineCurve::new_unchecked(knot_vec, control_points))
+    }
+
+    fn periodic_interpolate(points: &[P], degree: usize, param: ParamMethod) -> Result<Self> {
+        let m = points.len();
+        let required = usize::max(3, degree + 1);
+        if m < required {
+            return Err(Error::TooFewPoints(m, required));
+        }
+        let params = parameters(points, param, true);
+
+        // The knots are the parameters if `degree` is odd, and the middle points of them if
+        // `degree` is even. Both cases are extended periodically.
+        let base = match degree % 2 {
+            1 => params[..m].to_vec(),
+            _ => params.windows(2).map(|t| (t[0] + t[1]) / 2.0).collect(),
+        };
+        let knot = |k: isize| {
+            let (q, r) = (k.div_euclid(m as isize), k.rem_euclid(m as isize));
+            base[r as usize] + q as f64
+        };
+        let (len, offset) = (m + 2 * degree + 2, degree as isize + 1);
+        let knots: Vec<f64> = (0..len).map(|j| knot(j as isize - offset)).collect();
+        let knot_vec = KnotVec::from(knots);
+
+        // the `j`-th control point coincides with the `(j % m)`-th one.
+        let matrix = params[..m]
+            .iter()
+            .map(|t| {
+                let mut row = vec![0.0; m];
+                let basis = knot_vec.bspline_basis_functions(degree, *t);
+                basis
+                    .into_iter()
+                    .enumerate()
+                    .for_each(|(j, b)| row[j % m] += b);
+                row
+            })
+            .collect();
+        let rhs = points.iter().map(|pt| *pt - P::origin()).collect();
+        let vectors = solve(matrix, rhs).ok_or(Error::SingularMatrix)?;
+        let control_points = (0..len - degree - 1)
+            .map(|j| P::origin() + vectors[j % m])
+            .collect();
+        let mut curve = BSplineCurve::new_unchecked(knot_vec, control_points);
+        let mut curve = curve.cut(0.0);
+        curve.cut(1.0);
+        Ok(curve)
+    }
+
+    /// Constructs the B-spline curve with `degree` and `n_ctrl` control points approximating
+    /// `points` by the least squares method.
+    ///
+    /// The parameters of the points are assigned by `param` and normalized to `[0, 1]`. The curve
+    /// passes through the first and the last points, and minimizes the sum of the squared distances
+    /// to the other points at the parameters.
+    /// # Failures
+    /// - If `n_ctrl` is not more than `degree` or larger than the number of points, returns
+    /// [`Error::TooFewPoints`].
+    /// - If the linear system is singular, returns [`Error::SingularMatrix`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // noisy points on the parabola
+    /// let points: Vec<Point2> = (0..=50)
+    ///     .map(|i| {
+    ///         let t = i as f64 / 50.0;
+    ///         let noise = if i % 2 == 0 { 1.0e-3 } else { -1.0e-3 };
+    ///         Point2::new(t, t * t + noise)
+    ///     })
+    ///     .collect();
+    /// let bspcurve = BSplineCurve::approximate(&points, 3, 6, ParamMethod::Uniform).unwrap();
+    /// assert_eq!(bspcurve.control_points().len(), 6);
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert!(bspcurve.subs(t).distance(Point2::new(t, t * t)) < 2.0e-3);
+    /// }
+    /// ```
+    pub fn approximate(
+        points: &[P],
+        degree: usize,
+        n_ctrl: usize,
+        param: ParamMethod,
+    ) -> Result<Self> {
+        let m = points.len();
+        if n_ctrl <= degree || n_ctrl < 2 || m < n_ctrl {
+            return Err(Error::TooFewPoints(m, usize::max(n_ctrl, degree + 1)));
+        }
+        let params = parameters(points, param, false);
+
+        // the interior knots are distributed so that every span contains parameters.
+        let d = m as f64 / (n_ctrl - degree) as f64;
+        let mut knots = vec![0.0; degree + 1];
+        knots.extend((1..n_ctrl - degree).map(|j| {
+            let x = j as f64 * d;
+            let (i, a) = (x as usize, x.fract());
+            params[i - 1] * (1.0 - a) + params[i] * a
+        }));
+        knots.extend(vec![1.0; degree + 1]);
+        let knot_vec = KnotVec::from(knots);
+
+        // the normal equation for the interior control points
+        let (front, back) = (points[0] - P::origin(), points[m - 1] - P::origin());
+        let rows: Vec<(Vec<f64>, P::Diff)> = params[1..m - 1]
+            .iter()
+            .zip(&points[1..m - 1])
+            .map(|(t, pt)| {
+                let basis = knot_vec.bspline_basis_functions(degree, *t);
+                let residual = (*pt - P::origin()) - front * basis[0] - back * basis[n_ctrl - 1];
+                (basis, residual)
+            })
+            .collect();
+        let n = n_ctrl - 2;
+        let matrix = (1..=n)
+            .map(|i| {
+                (1..=n)
+                    .map(|j| rows.iter().map(|(basis, _)| basis[i] * basis[j]).sum())
+                    .collect()
+            })
+            .collect();
+        let rhs = (1..=n)
+            .map(|i| {
+                rows.iter()
+                    .fold(P::Diff::zero(), |sum, (basis, vec)| sum + *vec * basis[i])
+            })
+            .collect();
+        let vectors = solve(matrix, rhs).ok_or(Error::SingularMatrix)?;
+        let control_points = std::iter::once(front)
+            .chain(vectors)
+            .chain(std::iter::once(back))
+            .map(|vec| P::origin() + vec)
+            .collect();
+        Ok(BSplineCurve::new_unchecked(knot_vec, control_points))
+    }
+}
+
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Constructs the surface lofting `curves` in order.
+    ///
+    /// The curves are made compatible by the degree elevation and the knot insertion, and their
+    /// knot vectors are normalized to the `u`-parameter range `[0, 1]`. The control points are
+    /// interpolated in the `v` direction with the degree `degree_v`, which is lowered to
+    /// `curves.len() - 1` if it is too high, so two curves give a ruled surface. The parameters
+    /// of the curves are the averages of the chord length parameters of the control points, and
+    /// the `v`-knot vector is clamped on `[0, 1]`. If all curves are closed, so is the surface.
+    /// # Failures
+    /// - If less than two curves are given, returns [`Error::TooFewPoints`].
+    /// - If the linear system is singular, returns [`Error::SingularMatrix`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // The sections are equally spaced, so their parameters are `0.0`, `0.5` and `1.0`.
+    /// let curves: Vec<BSplineCurve<Point3>> = [0.0, 1.0, 0.0]
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(i, z)| {
+    ///         let x = i as f64;
+    ///         BSplineCurve::new(
+    ///             KnotVec::bezier_knot(1),
+    ///             vec![Point3::new(x, 0.0, *z), Point3::new(x, 1.0, *z)],
+    ///         )
+    ///     })
+    ///     .collect();
+    /// let surface = BSplineSurface::loft(&curves, 2).unwrap();
+    /// assert_eq!(surface.vdegree(), 2);
+    /// for i in 0..=10 {
+    ///     let u = i as f64 / 10.0;
+    ///     assert_near!(surface.subs(u, 0.0), curves[0].subs(u));
+    ///     assert_near!(surface.subs(u, 0.5), curves[1].subs(u));
+    ///     assert_near!(surface.subs(u, 1.0), curves[2].subs(u));
+    /// }
+    /// ```
+    pub fn loft(curves: &[BSplineCurve<P>], degree_v: usize) -> Result<Self> {
+        let n = curves.len();
+        if n < 2 {
+            return Err(Error::TooFewPoints(n, 2));
+        }
+        let mut curves = curves.to_vec();
+        let degree = curves.iter().map(|curve| curve.degree()).max().unwrap();
+        curves.iter_mut().for_each(|curve| {
+            let times = degree - curve.degree();
+            curve.elevate_degree_by(times);
+        });
+        // The first curve collects all knots, and then gives them back to the others.
+        let (first, others) = curves.split_first_mut().unwrap();
+        for _ in 0..2 {
+            for curve in others.iter_mut() {
+                first.syncro_knots(curve);
+            }
+        }
+
+        let degree_v = usize::min(degree_v, n - 1);
+        let len = curves[0].control_points().len();
+        let columns: Vec<Vec<P>> = (0..len)
+            .map(|i| curves.iter().map(|curve| *curve.control_point(i)).collect())
+            .collect();
+        let mut params = vec![0.0; n];
+        columns.iter().for_each(|column| {
+            let column_params = parameters(column, ParamMethod::ChordLength, false);
+            params
+                .iter_mut()
+                .zip(column_params)
+                .for_each(|(t, s)| *t += s / len as f64);
+        });
+
+        // the interior knots are the averages of the parameters.
+        let mut knots = vec![0.0; degree_v + 1];
+        knots.extend(
+            (1..n - degree_v)
+                .map(|j| params[j..j + degree_v].iter().sum::<f64>() / degree_v as f64),
+        );
+        knots.extend(vec![1.0; degree_v + 1]);
+        let vknot_vec = KnotVec::from(knots);
+        let matrix: Vec<Vec<f64>> = params
+            .iter()
+            .map(|t| vknot_vec.bspline_basis_functions(degree_v, *t))
+            .collect();
+        let control_points = columns
+            .into_iter()
+            .map(|column| {
+                let rhs = column.into_iter().map(|pt| pt - P::origin()).collect();
+                let vectors = solve(matrix.clone(), rhs).ok_or(Error::SingularMatrix)?;
+                Ok(vectors.into_iter().map(|vec| P::origin() + vec).collect())
+            })
+            .collect::<Result<Vec<Vec<P>>>>()?;
+        let knot_vecs = (curves[0].knot_vec().clone(), vknot_vec);
+        Ok(BSplineSurface::new_unchecked(knot_vecs, control_points))
+    }
+}
+
+/// Returns the parameters of `points` normalized to `[0, 1]`. If `closed`, the parameter of the
+/// first point after going around is also returned.
+fn parameters<P>(points: &[P], method: ParamMethod, closed: bool) -> Vec<f64>
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let closing = closed.then(|| (points[points.len() - 1], points[0]));
+    let mut params = vec![0.0];
+    points
+        .windows(2)
+        .map(|pts| (pts[0], pts[1]))
+        .chain(closing)
+        .for_each(|(p, q)| {
+            let delta = match method {
+                ParamMethod::Uniform => 1.0,
+                ParamMethod::ChordLength => (q - p).magnitude(),
+                ParamMethod::Centripetal => (q - p).magnitude().sqrt(),
+            };
+            params.push(params[params.len() - 1] + delta);
+        });
+    let total = params[params.len() - 1];
+    if total.so_small() {
+        let n = params.len() - 1;
+        (0..=n).map(|i| i as f64 / n as f64).collect()
+    } else {
+        params.into_iter().map(|t| t / total).collect()
+    }
+}
+
+/// Returns the `n`-th order derivations of the B-spline basis functions at `t`.
+fn basis_der_n(knot_vec: &KnotVec, degree: usize, n: usize, t: f64) -> Vec<f64> {
+    let len = knot_vec.len() - degree - 1;
+    if n == 0 {
+        return knot_vec.bspline_basis_functions(degree, t);
+    } else if n > degree {
+        return vec![0.0; len];
+    }
+    let lower = basis_der_n(knot_vec, degree - 1, n - 1, t);
+    (0..len)
+        .map(|i| {
+            let a = inv_or_zero(knot_vec[i + degree] - knot_vec[i]);
+            let b = inv_or_zero(knot_vec[i + degree + 1] - knot_vec[i + 1]);
+            degree as f64 * (lower[i] * a - lower[i + 1] * b)
+        })
+        .collect()
+}
+
+/// Solves the linear system `matrix * x = rhs` by the LU decomposition with partial pivoting.
+/// Returns `None` if the matrix is singular.
+fn solve<V>(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<V>) -> Option<Vec<V>>
+where V: VectorSpace<Scalar = f64> {
+    let n = rhs.len();
+    for k in 0..n {
+        let pivot = (k..n).max_by(|i, j| matrix[*i][k].abs().total_cmp(&matrix[*j][k].abs()))?;
+        if matrix[pivot][k].so_small() {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        rhs.swap(k, pivot);
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        let (row_k, vec_k) = (upper.last().unwrap(), rhs[k]);
+        for (row, vec) in lower.iter_mut().zip(&mut rhs[k + 1..]) {
+            let a = row[k] / row_k[k];
+            row.iter_mut()
+                .zip(row_k)
+                .skip(k)
+                .for_each(|(x, y)| *x -= a * y);
+            *vec = *vec - vec_k * a;
+        }
+    }
+    for k in (0..n).rev() {
+        let vec = (k + 1..n).fold(rhs[k], |sum, j| sum - rhs[j] * matrix[k][j]);
+        rhs[k] = vec / matrix[k][k];
+    }
+    Some(rhs)
+}
+
+#[test]
+fn interpolate_cubic_test() {
+    let cubic = BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        (0..4)
+            .map(|_| {
+                Point3::new(
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                )
+            })
+            .collect(),
+    );
+    let points: Vec<Point3> = (0..=10).map(|i| cubic.subs(i as f64 / 10.0)).collect();
+    let ends = EndCondition::Clamped(cubic.der(0.0), cubic.der(1.0));
+    let curve = BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, ends).unwrap();
+    for i in 0..=100 {
+        let t = i as f64 / 100.0;
+        assert_near!(curve.subs(t), cubic.subs(t));
+    }
+}
+
+#[test]
+fn interpolate_natural_test() {
+    let points: Vec<Point3> = (0..8)
+        .map(|_| {
+            Point3::new(
+                rand::random::<f64>(),
+                rand::random::<f64>(),
+                rand::random::<f64>(),
+            )
+        })
+        .collect();
+    for param in [
+        ParamMethod::Uniform,
+        ParamMethod::ChordLength,
+        ParamMethod::Centripetal,
+    ] {
+        let ends = EndCondition::Natural;
+        let curve = BSplineCurve::interpolate(&points, 3, param, ends).unwrap();
+        let params = parameters(&points, param, false);
+        for (t, pt) in params.into_iter().zip(&points) {
+            assert_near!(curve.subs(t), *pt);
+        }
+        assert!(curve.der2(0.0).so_small());
+        assert!(curve.der2(1.0).so_small());
+    }
+}
+
+#[test]
+fn interpolate_periodic_test() {
+    for degree in 2..=4 {
+        let points: Vec<Point3> = (0..8)
+            .map(|i| {
+                let theta = std::f64::consts::PI * i as f64 / 4.0;
+                let r = 1.0 + 0.5 * rand::random::<f64>();
+                Point3::new(r * theta.cos(), r * theta.sin(), rand::random::<f64>())
+            })
+            .collect();
+        let ends = EndCondition::Periodic;
+        let curve = BSplineCurve::interpolate(&points, degree, ParamMethod::Uniform, ends).unwrap();
+        assert_eq!(curve.range_tuple(), (0.0, 1.0));
+        for (i, pt) in points.iter().enumerate() {
+            assert_near!(curve.subs(i as f64 / 8.0), *pt);
+        }
+        // C^2 across the seam
+        assert_near!(curve.subs(0.0), curve.subs(1.0));
+        assert_near!(curve.der(0.0), curve.der(1.0));
+        if degree > 2 {
+            assert!((curve.der2(0.0) - curve.der2(1.0)).magnitude() < 1.0e-4);
+        }
+    }
+}
+
+#[test]
+fn approximate_test() {
+    let cubic = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 2),
+        (0..5)
+            .map(|_| {
+                Point3::new(
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                    rand::random::<f64>(),
+                )
+            })
+            .collect(),
+    );
+    let points: Vec<Point3> = (0..=40).map(|i| cubic.subs(i as f64 / 40.0)).collect();
+    let curve = BSplineCurve::approximate(&points, 3, 5, ParamMethod::Uniform).unwrap();
+    assert_eq!(curve.control_points().len(), 5);
+    assert_near!(curve.front(), points[0]);
+    assert_near!(curve.back(), points[40]);
+    assert!(matches!(
+        BSplineCurve::approximate(&points[..4], 3, 5, ParamMethod::Uniform),
+        Err(Error::TooFewPoints(4, 5)),
+    ));
+}
+
+#[test]
+fn loft_frustum_test() {
+    // the circle with `radius` at the height `z` in the homogeneous coordinate
+    let circle = |radius: f64, z: f64| {
+        let knot_vec = KnotVec::from(vec![
+            0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+        ]);
+        let w = 0.5 * f64::sqrt(2.0);
+        let ctrl_pts = [
+            (1.0, 0.0, 1.0),
+            (1.0, 1.0, w),
+            (0.0, 1.0, 1.0),
+            (-1.0, 1.0, w),
+            (-1.0, 0.0, 1.0),
+            (-1.0, -1.0, w),
+            (0.0, -1.0, 1.0),
+            (1.0, -1.0, w),
+            (1.0, 0.0, 1.0),
+        ]
+        .iter()
+        .map(|(x, y, w)| Vector4::new(radius * x, radius * y, z, 1.0) * *w)
+        .collect();
+        BSplineCurve::new(knot_vec, ctrl_pts)
+    };
+    let mut dense = circle(2.0, 1.0);
+    (1..20).for_each(|i| {
+        dense.add_knot(i as f64 / 20.0);
+    });
+    let check = |surface: &BSplineSurface<Vector4>| {
+        for i in 0..=20 {
+            for j in 0..=20 {
+                let (u, v) = (i as f64 / 20.0, j as f64 / 20.0);
+                let pt = surface.subs(u, v).to_point();
+                let radius = f64::sqrt(pt.x * pt.x + pt.y * pt.y);
+                assert!(f64::abs(radius - (1.0 + pt.z)) < 1.0e-6, "{pt:?}");
+            }
+        }
+    };
+
+    // two sections give a ruled surface
+    let surface = BSplineSurface::loft(&[circle(1.0, 0.0), dense.clone()], 3).unwrap();
+    assert_eq!(surface.vdegree(), 1);
+    check(&surface);
+    assert_near!(surface.subs(0.0, 0.5), surface.subs(1.0, 0.5));
+
+    // three sections with different knot densities
+    let curves = [circle(1.0, 0.0), circle(1.5, 0.5), dense];
+    let surface = BSplineSurface::loft(&curves, 2).unwrap();
+    assert_eq!(surface.vdegree(), 2);
+    check(&surface);
+
+    assert!(matches!(
+        BSplineSurface::loft(&curves[..1], 2),
+        Err(Error::TooFewPoints(1, 2)),
+    ));
+}
@@ -118,12 +118,127 @@ pub struct NurbsCurve<V>(BSplineCurve<V>);
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct NurbsSurface<V>(BSplineSurface<V>);
 
+/// The methods to assign parameters to the points interpolated by B-spline curves.
+/// cf. [`BSplineCurve::interpolate`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParamMethod {
+    /// The parameters are equally spaced.
+    Uniform,
+    /// The differences of parameters are proportional to the distances between the points.
+    ChordLength,
+    /// The differences of parameters are proportional to the square roots of the distances
+    /// between the points.
+    Centripetal,
+}
+
+/// The conditions at the ends of B-spline curves interpolating points.
+/// cf. [`BSplineCurve::interpolate`]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EndCondition<V> {
+    /// The second order derivations vanish at both ends.
+    Natural,
+    /// The derivations at the front and the back are specified. The parameter of the
+    /// interpolating curve is normalized to `[0, 1]`.
+    Clamped(V, V),
+    /// The curve is closed, returning from the last point to the first point, and the derivations
+    /// up to the order `degree - 1` are continuous across the seam.
+    Periodic,
+}
+
 mod bspcurve;
 mod bspsurface;
+mod interpolation;
 mod knot_vec;
 mod nurbscurve;
 mod nurbssurface;
 
+/// Returns the `n`-th order derivation of `sum_i coefs[i] * N_i(t)`, where `N_i` is the
+/// B-spline basis function of `knot_vec` with degree `degree`.
+fn bspline_der_n<P: ControlPoint<f64>>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    mut coefs: Vec<P::Diff>,
+    n: usize,
+    t: f64,
+) -> P::Diff {
+    if n > degree {
+        return P::Diff::zero();
+    }
+    let zero = P::Diff::zero();
+    for r in 1..=n {
+        let k = degree + 1 - r;
+        let len = coefs.len();
+        coefs = (0..=len)
+            .map(|i| {
+                let prev = if i == 0 { zero } else { coefs[i - 1] };
+                let next = if i == len { zero } else { coefs[i] };
+                (next - prev) * (k as f64 * inv_or_zero(knot_vec[i + k] - knot_vec[i]))
+            })
+            .collect();
+    }
+    knot_vec
+        .bspline_basis_functions(degree - n, t)
+        .into_iter()
+        .zip(coefs)
+        .fold(zero, |sum, (b, coef)| sum + coef * b)
+}
+
+/// Removes the knot `knot_vec[idx]` once from the B-spline with the control points `points` by
+/// Tiller's algorithm. The new control points are solved from both sides of the knot, and the gap
+/// between the two solutions is returned with the index of the removed knot and the new points.
+/// Returns `None` if the knot is not interior or its multiplicity is greater than `degree`.
+fn remove_knot_points<P>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    points: &[P],
+    idx: usize,
+) -> Option<(usize, Vec<P>, f64)>
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    let (p, n) = (degree, points.len());
+    let u = *knot_vec.get(idx)?;
+    // `r` is the last index of the knot `u`, and `s` is its multiplicity.
+    let r = idx + knot_vec[idx..].iter().take_while(|t| **t == u).count() - 1;
+    let s = knot_vec[..=r].iter().rev().take_while(|t| **t == u).count();
+    if r < p + 1 || s > p || r + 1 >= n + s {
+        return None;
+    }
+
+    let (first, last) = (r - p, r - s);
+    let alpha = |i: usize| (u - knot_vec[i]) / (knot_vec[i + p + 1] - knot_vec[i]);
+    let mut left = vec![points[first - 1]];
+    let mut right = vec![points[last + 1]];
+    let (mut i, mut j) = (first, last);
+    while i < j {
+        let q = *left.last().unwrap();
+        left.push(q + (points[i] - q) / alpha(i));
+        let q = *right.last().unwrap();
+        right.push(q + (points[j] - q) / (1.0 - alpha(j)));
+        i += 1;
+        j -= 1;
+    }
+
+    let (q0, q1) = (*left.last().unwrap(), *right.last().unwrap());
+    let gap = if i == j {
+        (points[i] - (q0 + (q1 - q0) * alpha(i))).magnitude()
+    } else {
+        // both sides have solved the same control point.
+        *left.last_mut().unwrap() = q0 + (q1 - q0) / 2.0;
+        right.pop();
+        (q1 - q0).magnitude()
+    };
+    let new_points = points[..first]
+        .iter()
+        .copied()
+        .chain(left.into_iter().skip(1))
+        .chain(right.into_iter().skip(1).rev())
+        .chain(points[last + 1..].iter().copied())
+        .collect();
+    Some((r, new_points, gap))
+}
+
 #[doc(hidden)]
 #[inline(always)]
 pub fn inv_or_zero(delta: f64) -> f64 {
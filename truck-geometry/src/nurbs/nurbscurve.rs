@@ -243,6 +243,20 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsCurve<V
         self
     }
 
+    /// Elevates `times` degrees in the homogeneous space. cf.[`BSplineCurve::elevate_degree_by`]
+    pub fn elevate_degree_by(&mut self, times: usize) -> &mut Self {
+        self.0.elevate_degree_by(times);
+        self
+    }
+
+    /// Reduces 1 degree in the homogeneous space if the curve can be represented by the lower
+    /// degree within `tol`. Note that `tol` is the tolerance of the homogeneous coordinates.
+    /// cf.[`BSplineCurve::reduce_degree`]
+    pub fn reduce_degree(&mut self, tol: f64) -> bool
+    where V: InnerSpace<Scalar = f64> {
+        self.0.reduce_degree(tol)
+    }
+
     /// Makes the NURBS curve clamped. cf.[`BSplineCurve::clamp`]
     #[inline(always)]
     pub fn clamp(&mut self) -> &mut Self {
@@ -287,6 +301,38 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> Cut for Nurb
     fn cut(&mut self, t: f64) -> Self { NurbsCurve(self.0.cut(t)) }
 }
 
+impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> ControlHull<D1>
+    for NurbsCurve<V>
+{
+    type Point = V::Point;
+    /// Returns the rationalized control points of the part of the curve restricted to `range`.
+    /// # Remarks
+    /// The weights of the control points must be positive.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the unit quarter circle
+    /// let curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(1.0, 0.0, 1.0),
+    ///         Vector3::new(1.0, 1.0, 1.0) / f64::sqrt(2.0),
+    ///         Vector3::new(0.0, 1.0, 1.0),
+    ///     ],
+    /// ));
+    /// let bdb = curve.hull_bounding_box((0.25, 0.75));
+    /// for i in 0..=10 {
+    ///     let t = 0.25 + 0.05 * i as f64;
+    ///     assert!(bdb.contains(curve.subs(t)));
+    /// }
+    /// assert!(bdb.max().x < 1.0 && bdb.max().y < 1.0);
+    /// ```
+    fn control_hull(&self, range: (f64, f64)) -> Vec<V::Point> {
+        let curve = self.0.cut_out(range);
+        curve.control_points.into_iter().map(V::to_point).collect()
+    }
+}
+
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> Concat<NurbsCurve<V>>
     for NurbsCurve<V>
 where <V as Homogeneous<f64>>::Point: Debug
@@ -330,6 +376,35 @@ fn concat_positive_test() {
     concat_random_test(&part0, &part1, 10);
 }
 
+#[test]
+fn elevate_circle_test() {
+    let w = 0.5 * f64::sqrt(2.0);
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+    ]);
+    let control_points = vec![
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(w, w, w),
+        Vector3::new(0.0, 1.0, 1.0),
+        Vector3::new(-w, w, w),
+        Vector3::new(-1.0, 0.0, 1.0),
+        Vector3::new(-w, -w, w),
+        Vector3::new(0.0, -1.0, 1.0),
+        Vector3::new(w, -w, w),
+        Vector3::new(1.0, 0.0, 1.0),
+    ];
+    let circle = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points));
+    let mut curve = circle.clone();
+    curve.elevate_degree_by(1);
+    assert_eq!(curve.degree(), 3);
+    assert_eq!(curve.knot_vec().len(), 17);
+    for i in 0..=100 {
+        let t = i as f64 / 100.0;
+        assert!(curve.subs(t).distance(circle.subs(t)) < 1.0e-12);
+        assert!((curve.subs(t).to_vec().magnitude() - 1.0).abs() < 1.0e-12);
+    }
+}
+
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsCurve<V>
 where V::Point: Tolerance
 {
@@ -479,7 +554,14 @@ where
         let hint = match hint.into() {
             SPHint1D::Parameter(hint) => hint,
             SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
+                let hint = algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION);
+                return algo::curve::search_nearest_parameter_bounded(
+                    self,
+                    point,
+                    hint,
+                    (x, y),
+                    trial,
+                );
             }
             SPHint1D::None => {
                 algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
@@ -502,6 +584,14 @@ where
         hint: H,
         trial: usize,
     ) -> Option<f64> {
+        self.try_search_parameter(point, hint, trial).ok()
+    }
+    fn try_search_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: V::Point,
+        hint: H,
+        trial: usize,
+    ) -> Result<f64, SearchError<f64>> {
         let hint = match hint.into() {
             SPHint1D::Parameter(hint) => hint,
             SPHint1D::Range(x, y) => {
@@ -511,7 +601,7 @@ where
                 algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
-        algo::curve::search_parameter(self, point, hint, trial)
+        algo::curve::try_search_parameter(self, point, hint, trial)
     }
 }
 
@@ -543,6 +633,23 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricCurve for Nurb
         let der2 = self.0.der2(t);
         pt.rat_der2(der, der2)
     }
+    /// Derived by the Leibniz rule for the product of the curve and the weight.
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        let ders = (0..=n).map(|k| self.0.der_n(k, t)).collect::<Vec<_>>();
+        let weight = ders[0].weight();
+        let mut res = Vec::<Self::Vector>::with_capacity(n + 1);
+        for k in 0..=n {
+            let mut binom = 1.0;
+            let mut sum = ders[k].truncate();
+            for i in 1..=k {
+                binom = binom * (k + 1 - i) as f64 / i as f64;
+                sum = sum - res[k - i] * (binom * ders[i].weight());
+            }
+            res.push(sum / weight);
+        }
+        res[n]
+    }
     #[inline(always)]
     fn parameter_range(&self) -> ParameterRange {
         (
@@ -550,6 +657,8 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricCurve for Nurb
             Bound::Included(self.0.knot_vec[self.0.knot_vec.len() - 1]),
         )
     }
+    #[inline(always)]
+    fn period(&self) -> Option<f64> { self.0.period() }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> BoundedCurve for NurbsCurve<V> {}
@@ -617,3 +726,29 @@ fn test_parameter_division() {
         assert!(dist < tol, "large distance: {dist}");
     }
 }
+
+#[test]
+fn test_der_n() {
+    let knot_vec = KnotVec::uniform_knot(2, 3);
+    let ctrl_pts = vec![
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+        Vector4::new(2.0, 0.0, 0.0, 2.0),
+        Vector4::new(0.0, 3.0, 0.0, 3.0),
+        Vector4::new(0.0, 0.0, 2.0, 2.0),
+        Vector4::new(1.0, 1.0, 1.0, 1.0),
+    ];
+    let curve = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    const N: usize = 30;
+    for i in 0..N {
+        // the knots are avoided for the finite differences
+        let t = (i as f64 + 0.5) / N as f64;
+        assert_near!(curve.der_n(0, t), curve.subs(t).to_vec());
+        assert_near!(curve.der_n(1, t), curve.der(t));
+        assert_near!(curve.der_n(2, t), curve.der2(t));
+        let h = 1.0e-5;
+        let der3 = (curve.der2(t + h) - curve.der2(t - h)) / (2.0 * h);
+        assert!((curve.der_n(3, t) - der3).magnitude() < 1.0e-3 * der3.magnitude().max(1.0));
+        let der4 = (curve.der_n(3, t + h) - curve.der_n(3, t - h)) / (2.0 * h);
+        assert!((curve.der_n(4, t) - der4).magnitude() < 1.0e-3 * der4.magnitude().max(1.0));
+    }
+}
@@ -415,6 +415,18 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsSurface
         self.0.elevate_vdegree();
         self
     }
+    /// Elevates `times` udegrees in the homogeneous space.
+    #[inline(always)]
+    pub fn elevate_udegree_by(&mut self, times: usize) -> &mut Self {
+        self.0.elevate_udegree_by(times);
+        self
+    }
+    /// Elevates `times` vdegrees in the homogeneous space.
+    #[inline(always)]
+    pub fn elevate_vdegree_by(&mut self, times: usize) -> &mut Self {
+        self.0.elevate_vdegree_by(times);
+        self
+    }
     /// Aligns the udegree with the same degrees.
     #[inline(always)]
     pub fn syncro_uvdegrees(&mut self) -> &mut Self {
@@ -612,6 +624,14 @@ impl SearchParameter<D2> for NurbsSurface<Vector3> {
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
+        self.try_search_parameter(point, hint, trials).ok()
+    }
+    fn try_search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point2,
+        hint: H,
+        trials: usize,
+    ) -> Result<(f64, f64), SearchError<(f64, f64)>> {
         let hint = match hint.into() {
             SPHint2D::Parameter(x, y) => (x, y),
             SPHint2D::Range(range0, range1) => {
@@ -621,7 +641,21 @@ impl SearchParameter<D2> for NurbsSurface<Vector3> {
                 algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
-        algo::surface::search_parameter2d(self, point, hint, trials)
+        algo::surface::try_search_parameter2d(self, point, hint, trials)
+    }
+}
+
+impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> ControlHull<D2>
+    for NurbsSurface<V>
+{
+    type Point = V::Point;
+    /// Returns the rationalized control points of the part of the surface restricted to `range`.
+    /// # Remarks
+    /// The weights of the control points must be positive.
+    fn control_hull(&self, range: ((f64, f64), (f64, f64))) -> Vec<V::Point> {
+        let surface = self.0.cut_out(range);
+        let control_points = surface.control_points.into_iter().flatten();
+        control_points.map(V::to_point).collect()
     }
 }
 
@@ -819,12 +853,21 @@ impl SearchParameter<D2> for NurbsSurface<Vector4> {
     /// let (u, v) = surface.search_parameter(pt, Some((0.5, 0.5)), 100).unwrap();
     /// assert_near!(surface.subs(u, v), pt);
     /// ```
+    #[inline(always)]
     fn search_parameter<H: Into<SPHint2D>>(
         &self,
         point: Point3,
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
+        self.try_search_parameter(point, hint, trials).ok()
+    }
+    fn try_search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Result<(f64, f64), SearchError<(f64, f64)>> {
         let hint = match hint.into() {
             SPHint2D::Parameter(x, y) => (x, y),
             SPHint2D::Range(range0, range1) => {
@@ -834,7 +877,7 @@ impl SearchParameter<D2> for NurbsSurface<Vector4> {
                 algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
             }
         };
-        algo::surface::search_parameter3d(self, point, hint, trials)
+        algo::surface::try_search_parameter3d(self, point, hint, trials)
     }
 }
 
@@ -17,6 +17,8 @@ impl ParametricCurve for UnitCircle<Point2> {
     #[inline]
     fn der2(&self, t: f64) -> Self::Vector { Vector2::new(-f64::cos(t), -f64::sin(t)) }
     #[inline]
+    fn period(&self) -> Option<f64> { Some(2.0 * PI) }
+    #[inline]
     fn parameter_range(&self) -> ParameterRange {
         (Bound::Included(0.0), Bound::Excluded(2.0 * PI))
     }
@@ -159,3 +161,111 @@ fn parameter_division() {
         assert!(p.to_vec().magnitude() > 0.95);
     }
 }
+
+impl<V> From<TrimmedCurve<UnitCircle<V::Point>>> for NurbsCurve<V>
+where
+    V: Homogeneous<f64>,
+    UnitCircle<V::Point>: ParametricCurve<Point = V::Point>,
+{
+    /// Converts the arc into the exact NURBS curve consisting of the rational quadratic arcs
+    /// whose central angles are at most `PI / 2`. The parameter is not preserved except for the
+    /// ends of the arcs, where it coincides with the angle.
+    fn from(arc: TrimmedCurve<UnitCircle<V::Point>>) -> Self {
+        let (t0, t1) = arc.range_tuple();
+        let n = usize::max(1, f64::ceil((t1 - t0) / (PI / 2.0) - TOLERANCE) as usize);
+        let knots: Vec<f64> = (0..=n)
+            .map(|i| {
+                let s = i as f64 / n as f64;
+                t0 * (1.0 - s) + t1 * s
+            })
+            .collect();
+        let ends: Vec<V::Point> = knots.iter().map(|t| arc.subs(*t)).collect();
+        let weight = f64::cos((t1 - t0) / (2.0 * n as f64));
+        let middles: Vec<(V::Point, f64)> = knots
+            .windows(2)
+            .map(|a| {
+                let middle = arc.subs((a[0] + a[1]) / 2.0).to_vec() / weight;
+                (V::Point::from_vec(middle), weight)
+            })
+            .collect();
+        rational_quadratic_arcs(&knots, &ends, &middles)
+    }
+}
+
+#[test]
+fn into_nurbs() {
+    const N: usize = 100;
+    for range in [(0.0, 2.0 * PI), (0.3, 2.0), (-1.0, 3.5)] {
+        let arc = TrimmedCurve::new(UnitCircle::<Point2>::new(), range);
+        let nurbs = NurbsCurve::<Vector3>::from(arc);
+        assert_eq!(nurbs.range_tuple(), range);
+        assert_near!(nurbs.front(), arc.front());
+        assert_near!(nurbs.back(), arc.back());
+        for i in 0..=N {
+            let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+            assert_near!(nurbs.subs(t).to_vec().magnitude(), 1.0);
+            // the nurbs curve does not go backward
+            assert!(nurbs.der(t).dot(arc.der(t)) > 0.0);
+        }
+
+        let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), range);
+        let nurbs = NurbsCurve::<Vector4>::from(arc);
+        for i in 0..=N {
+            let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+            let pt = nurbs.subs(t);
+            assert_near!(pt.to_vec().magnitude(), 1.0);
+            assert_near!(pt.z, 0.0);
+        }
+    }
+}
+
+#[test]
+fn ellipse() {
+    // ellipse whose semi-axes are 3 and 1
+    let transform = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 1.0).normalize(), Rad(1.0))
+        * Matrix4::from_nonuniform_scale(3.0, 1.0, 1.0);
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, 2.0 * PI));
+    let mut ellipse = Processor::<_, Matrix4>::new(arc);
+    ellipse.transform_by(transform);
+
+    // curvature at the vertices
+    assert_near!(algo::curve::curvature(&ellipse, 0.0), 3.0);
+    assert_near!(algo::curve::curvature(&ellipse, PI / 2.0), 1.0 / 9.0);
+    assert_near!(algo::curve::curvature(&ellipse, PI), 3.0);
+    assert_near!(algo::curve::curvature(&ellipse, 3.0 * PI / 2.0), 1.0 / 9.0);
+
+    // the nearest point compared with the brute force sampling
+    const N: usize = 10000;
+    let samples: Vec<Point3> = (0..N)
+        .map(|i| ellipse.subs(2.0 * PI * i as f64 / N as f64))
+        .collect();
+    let points = [
+        Point3::new(4.0, 2.0, 0.5),
+        Point3::new(1.0, 2.0, -1.0),
+        Point3::new(-2.0, 0.5, 0.0),
+        Point3::new(0.5, -3.0, 0.3),
+        Point3::new(-5.0, -5.0, 1.0),
+        Point3::new(2.0, 0.5, 0.0),
+    ];
+    for p in points {
+        let p = transform.transform_point(p);
+        let t = ellipse.search_nearest_parameter(p, None, 100).unwrap();
+        let dist = ellipse.subs(t).distance(p);
+        let brute = samples
+            .iter()
+            .map(|q| q.distance(p))
+            .fold(f64::INFINITY, f64::min);
+        assert!(dist <= brute + TOLERANCE, "{dist} {brute}");
+    }
+
+    // exact conversion into NURBS
+    let nurbs = ellipse.map(NurbsCurve::<Vector4>::from).constract();
+    let inv = transform.invert().unwrap();
+    const M: usize = 100;
+    for i in 0..=M {
+        let pt = inv.transform_point(nurbs.subs(2.0 * PI * i as f64 / M as f64));
+        assert_near!(pt.x * pt.x / 9.0 + pt.y * pt.y, 1.0);
+        assert_near!(pt.z, 0.0);
+    }
+}
@@ -0,0 +1,262 @@
+use super::*;
+use std::f64::consts::PI;
+
+impl Cone {
+    /// constructor
+    /// # Panics
+    /// `half_angle` must be in the open interval `(0, PI / 2)`.
+    #[inline(always)]
+    pub fn new(apex: Point3, half_angle: f64) -> Self {
+        if half_angle <= 0.0 || PI / 2.0 <= half_angle {
+            panic!("half angle must be in (0, PI / 2)");
+        }
+        Self { apex, half_angle }
+    }
+
+    /// get apex
+    #[inline(always)]
+    pub const fn apex(&self) -> Point3 { self.apex }
+
+    /// get half angle, the angle between the axis and the generating lines
+    #[inline(always)]
+    pub const fn half_angle(&self) -> f64 { self.half_angle }
+
+    /// Returns whether the point `pt` is on the cone
+    #[inline(always)]
+    pub fn include(&self, pt: Point3) -> bool {
+        match self.search_nearest_parameter(pt, None, 1) {
+            Some((u, v)) => self.subs(u, v).near(&pt),
+            None => false,
+        }
+    }
+
+    /// Returns the angle around the axis in `[0, 2PI)`, or `None` if `pt` is on the axis.
+    #[inline(always)]
+    fn angle(&self, pt: Point3) -> Option<f64> {
+        let r = pt - self.apex;
+        match Vector2::new(r.x, r.y).so_small() {
+            true => None,
+            false => Some(f64::atan2(r.y, r.x).rem_euclid(2.0 * PI)),
+        }
+    }
+}
+
+impl ParametricSurface for Cone {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Point3 { self.apex + v * self.vder(u, v) }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        v * f64::sin(self.half_angle) * Vector3::new(-f64::sin(u), f64::cos(u), 0.0)
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, _: f64) -> Vector3 {
+        let (s, c) = (f64::sin(self.half_angle), f64::cos(self.half_angle));
+        Vector3::new(s * f64::cos(u), s * f64::sin(u), c)
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        -v * f64::sin(self.half_angle) * Vector3::new(f64::cos(u), f64::sin(u), 0.0)
+    }
+    #[inline(always)]
+    fn uvder(&self, u: f64, _: f64) -> Vector3 {
+        f64::sin(self.half_angle) * Vector3::new(-f64::sin(u), f64::cos(u), 0.0)
+    }
+    #[inline(always)]
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Bound::Included(0.0), Bound::Excluded(2.0 * PI)),
+            (Bound::Included(0.0), Bound::Unbounded),
+        )
+    }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+impl ParametricSurface3D for Cone {
+    /// The outer normal, which is well-defined even at the apex.
+    #[inline(always)]
+    fn normal(&self, u: f64, _: f64) -> Vector3 {
+        let (s, c) = (f64::sin(self.half_angle), f64::cos(self.half_angle));
+        Vector3::new(c * f64::cos(u), c * f64::sin(u), -s)
+    }
+}
+
+impl SearchParameter<D2> for Cone {
+    type Point = Point3;
+    #[inline(always)]
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let (u, v) = self.search_nearest_parameter(point, hint, trials)?;
+        match self.subs(u, v).near(&point) {
+            true => Some((u, v)),
+            false => None,
+        }
+    }
+}
+
+impl SearchNearestParameter<D2> for Cone {
+    type Point = Point3;
+    /// Returns the nearest parameter in closed form. If `point` is on the axis, the angle is
+    /// given by the hint, or zero if there is no hint.
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        _: usize,
+    ) -> Option<(f64, f64)> {
+        let u = match (self.angle(point), hint.into()) {
+            (Some(u), _) => u,
+            (None, SPHint2D::Parameter(u, _)) => u,
+            (None, _) => 0.0,
+        };
+        // the generating line at `u` is nearer than the other lines
+        let v = (point - self.apex).dot(self.vder(u, 0.0));
+        Some((u, f64::max(v, 0.0)))
+    }
+}
+
+impl ParameterDivision2D for Cone {
+    fn parameter_division(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        nonpositive_tolerance!(tol);
+        let radius = f64::max(vrange.0.abs(), vrange.1.abs()) * f64::sin(self.half_angle);
+        let circle = UnitCircle::<Point2>::new();
+        let (udiv, _) = circle.parameter_division(urange, tol / radius);
+        // the generating lines are straight
+        (udiv, vec![vrange.0, vrange.1])
+    }
+}
+
+impl IncludeCurve<BSplineCurve<Point3>> for Cone {
+    fn include(&self, curve: &BSplineCurve<Point3>) -> bool {
+        let (knots, _) = curve.knot_vec().to_single_multi();
+        let n = curve.degree() * 2 + 1;
+        include_at_inner_points(&knots, n, |t| self.include(curve.subs(t)))
+    }
+}
+
+impl IncludeCurve<NurbsCurve<Vector4>> for Cone {
+    fn include(&self, curve: &NurbsCurve<Vector4>) -> bool {
+        let (knots, _) = curve.knot_vec().to_single_multi();
+        let n = curve.degree() * 2 + 1;
+        include_at_inner_points(&knots, n, |t| self.include(curve.subs(t)))
+    }
+}
+
+impl IncludeCurve<Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>> for Cone {
+    fn include(&self, curve: &Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>) -> bool {
+        let (t0, t1) = curve.range_tuple();
+        include_at_inner_points(&[t0, t1], 5, |t| self.include(curve.subs(t)))
+    }
+}
+
+#[test]
+fn derivation() {
+    let cone = Cone::new(Point3::new(1.0, 2.0, 3.0), 0.4);
+    const N: usize = 10;
+    const EPS: f64 = 1.0e-4;
+    for i in 0..N {
+        for j in 1..=N {
+            let (u, v) = (2.0 * PI * i as f64 / N as f64, 2.0 * j as f64 / N as f64);
+            let uder = (cone.subs(u + EPS, v) - cone.subs(u - EPS, v)) / (2.0 * EPS);
+            assert!((cone.uder(u, v) - uder).magnitude() < EPS);
+            let vder = (cone.subs(u, v + EPS) - cone.subs(u, v - EPS)) / (2.0 * EPS);
+            assert!((cone.vder(u, v) - vder).magnitude() < EPS);
+            let uuder = (cone.uder(u + EPS, v) - cone.uder(u - EPS, v)) / (2.0 * EPS);
+            assert!((cone.uuder(u, v) - uuder).magnitude() < EPS);
+            let uvder = (cone.vder(u + EPS, v) - cone.vder(u - EPS, v)) / (2.0 * EPS);
+            assert!((cone.uvder(u, v) - uvder).magnitude() < EPS);
+            let normal = cone.uder(u, v).cross(cone.vder(u, v)).normalize();
+            assert_near!(cone.normal(u, v), normal);
+        }
+    }
+}
+
+#[test]
+fn search_parameter_brute_force() {
+    let cone = Cone::new(Point3::new(1.0, 2.0, 3.0), 0.4);
+    const N: usize = 400;
+    let brute_force = |pt: Point3| -> f64 {
+        (0..N)
+            .flat_map(|i| (0..=N).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let (u, v) = (2.0 * PI * i as f64 / N as f64, 4.0 * j as f64 / N as f64);
+                cone.subs(u, v).distance(pt)
+            })
+            .fold(f64::INFINITY, f64::min)
+    };
+    for _ in 0..10 {
+        let u = 2.0 * PI * rand::random::<f64>();
+        let v = 0.5 + 2.0 * rand::random::<f64>();
+        let pt = cone.subs(u, v);
+        let (u0, v0) = cone.search_parameter(pt, None, 1).unwrap();
+        assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+
+        let q = pt + (rand::random::<f64>() - 0.5) * cone.normal(u, v);
+        assert!(cone.search_parameter(q, None, 1).is_none() || q.near(&pt));
+        let (u0, v0) = cone.search_nearest_parameter(q, None, 1).unwrap();
+        let dist = cone.subs(u0, v0).distance(q);
+        let brute = brute_force(q);
+        assert!(
+            dist <= brute + TOLERANCE && brute - dist < 0.02,
+            "{dist} {brute}"
+        );
+    }
+    // the nearest point of a point below the apex is the apex
+    let q = cone.apex() - Vector3::new(0.1, 0.2, 1.0);
+    let (u0, v0) = cone.search_nearest_parameter(q, None, 1).unwrap();
+    assert_near!(cone.subs(u0, v0), cone.apex());
+}
+
+#[test]
+fn normal_consistency() {
+    let apex = Point3::new(1.0, 2.0, 3.0);
+    let cone = Cone::new(apex, 0.4);
+    // the line from the far point to the apex makes the normal of the revolution outer
+    let far = apex + 2.0 * Vector3::new(f64::sin(0.4), 0.0, f64::cos(0.4));
+    let revolved = RevolutedCurve::by_revolution(Line(far, apex), apex, Vector3::unit_z());
+    const N: usize = 10;
+    for i in 0..N {
+        for j in 1..N {
+            let (u, v) = (2.0 * PI * i as f64 / N as f64, 2.0 * j as f64 / N as f64);
+            let pt = cone.subs(u, v);
+            let (s, t) = revolved.search_parameter(pt, None, 100).unwrap();
+            assert_near!(cone.normal(u, v), revolved.normal(s, t));
+            // the normal points outward, away from the axis
+            let radial = pt - Point3::new(apex.x, apex.y, pt.z);
+            assert!(cone.normal(u, v).dot(radial) > 0.0);
+        }
+    }
+}
+
+#[test]
+fn include_circles() {
+    let cone = Cone::new(Point3::new(1.0, 2.0, 3.0), 0.4);
+    // the circle at the height `2.0` with the radius `2.0 * tan(0.4)`
+    let radius = 2.0 * f64::tan(0.4);
+    let mat = Matrix4::from_translation(Vector3::new(1.0, 2.0, 5.0)) * Matrix4::from_scale(radius);
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, 2.0 * PI));
+    let mut circle = Processor::new(arc);
+    circle.transform_by(mat);
+    let mut nurbs = NurbsCurve::<Vector4>::from(arc);
+    nurbs.transform_by(mat);
+    assert!(IncludeCurve::include(&cone, &circle));
+    assert!(IncludeCurve::include(&cone, &nurbs));
+
+    let mat = Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.1));
+    circle.transform_by(mat);
+    nurbs.transform_by(mat);
+    assert!(!IncludeCurve::include(&cone, &circle));
+    assert!(!IncludeCurve::include(&cone, &nurbs));
+}
@@ -123,3 +123,53 @@ fn sp_test() {
     let q = Point2::new(-1.0, 0.0);
     assert!(curve.search_parameter(q, None, 0).is_none());
 }
+
+impl<V> From<TrimmedCurve<UnitHyperbola<V::Point>>> for NurbsCurve<V>
+where
+    V: Homogeneous<f64>,
+    UnitHyperbola<V::Point>: ParametricCurve<Point = V::Point>,
+{
+    /// Converts the arc into the exact NURBS curve, a rational quadratic Bézier curve. The
+    /// parameter is not preserved except for the ends.
+    fn from(arc: TrimmedCurve<UnitHyperbola<V::Point>>) -> Self {
+        let (t0, t1) = arc.range_tuple();
+        let weight = f64::cosh((t1 - t0) / 2.0);
+        // the intersection of the tangent lines at the ends
+        let middle = arc.subs((t0 + t1) / 2.0).to_vec() / weight;
+        rational_quadratic_arcs(
+            &[t0, t1],
+            &[arc.subs(t0), arc.subs(t1)],
+            &[(V::Point::from_vec(middle), weight)],
+        )
+    }
+}
+
+#[test]
+fn into_nurbs() {
+    const N: usize = 100;
+    for range in [(-1.0, 1.0), (0.5, 3.0), (-4.0, -2.5)] {
+        let arc = TrimmedCurve::new(UnitHyperbola::<Point2>::new(), range);
+        let nurbs = NurbsCurve::<Vector3>::from(arc);
+        assert_eq!(nurbs.range_tuple(), range);
+        assert_near!(nurbs.front(), arc.front());
+        assert_near!(nurbs.back(), arc.back());
+        for i in 0..=N {
+            let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+            let pt = nurbs.subs(t);
+            assert_near!(pt.x * pt.x - pt.y * pt.y, 1.0);
+            assert!(pt.x > 0.0);
+        }
+        // the middle point is the one of the hyperbola
+        let t = (range.0 + range.1) / 2.0;
+        assert_near!(nurbs.subs(t), arc.subs(t));
+
+        let arc = TrimmedCurve::new(UnitHyperbola::<Point3>::new(), range);
+        let nurbs = NurbsCurve::<Vector4>::from(arc);
+        for i in 0..=N {
+            let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+            let pt = nurbs.subs(t);
+            assert_near!(pt.x * pt.x - pt.y * pt.y, 1.0);
+            assert_near!(pt.z, 0.0);
+        }
+    }
+}
@@ -11,6 +11,28 @@ use crate::{prelude::*, *};
 pub struct Line<P>(pub P, pub P);
 
 /// unit circle
+///
+/// The ellipses are expressed by the trimmed unit circle transformed by [`Processor`], and
+/// converted into the exact NURBS curves.
+/// # Example
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+/// let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, PI));
+/// let mut ellipse = Processor::<_, Matrix4>::new(arc);
+/// // the semi-axes are 3.0 and 2.0
+/// ellipse.transform_by(Matrix4::from_nonuniform_scale(3.0, 2.0, 1.0));
+/// assert_near!(ellipse.subs(PI / 2.0), Point3::new(0.0, 2.0, 0.0));
+///
+/// let nurbs = ellipse.map(NurbsCurve::<Vector4>::from).constract();
+/// assert_near!(nurbs.front(), Point3::new(3.0, 0.0, 0.0));
+/// assert_near!(nurbs.back(), Point3::new(-3.0, 0.0, 0.0));
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     let pt = nurbs.subs(PI * i as f64 / N as f64);
+///     assert_near!(pt.x * pt.x / 9.0 + pt.y * pt.y / 4.0, 1.0);
+/// }
+/// ```
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct UnitCircle<P>(std::marker::PhantomData<P>);
 
@@ -93,7 +115,42 @@ pub struct Torus {
     small_radius: f64,
 }
 
+/// cone whose axis is parallel to the z-axis
+///
+/// The parameter `u` is the angle around the axis, and `v` is the distance from the apex along
+/// the generating line, so that the surface is the nappe opening in the direction of the z-axis.
+/// Cones with the other axes are given by [`Processor`].
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+///
+/// let apex = Point3::new(1.0, 2.0, 3.0);
+/// let cone = Cone::new(apex, PI / 6.0);
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         let u = 2.0 * PI * i as f64 / N as f64;
+///         let v = 3.0 * j as f64 / N as f64;
+///         let pt = cone.subs(u, v);
+///
+///         // the distance from the apex is v, and the radius is a half of it
+///         assert_near!(pt.distance(apex), v);
+///         assert_near!(Vector2::new(pt.x - apex.x, pt.y - apex.y).magnitude(), v / 2.0);
+///
+///         // the normal is perpendicular to the generating line
+///         assert!(cone.normal(u, v).dot(pt - apex).so_small());
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Cone {
+    apex: Point3,
+    half_angle: f64,
+}
+
 mod circle;
+mod cone;
 mod hyperbola;
 mod line;
 mod parabola;
@@ -113,3 +170,96 @@ macro_rules! always_true {
 always_true!(UnitCircle);
 always_true!(UnitParabola);
 always_true!(UnitHyperbola);
+
+/// NURBS curve consisting of the rational quadratic Bézier arcs. The `i`-th arc is defined on
+/// `[knots[i], knots[i + 1]]`, and its control points are `ends[i]`, `middles[i].0` weighted by
+/// `middles[i].1`, and `ends[i + 1]`.
+fn rational_quadratic_arcs<V: Homogeneous<f64>>(
+    knots: &[f64],
+    ends: &[V::Point],
+    middles: &[(V::Point, f64)],
+) -> NurbsCurve<V> {
+    let mut knot_vec = vec![knots[0]; 3];
+    knots[1..knots.len() - 1]
+        .iter()
+        .for_each(|t| knot_vec.extend([*t, *t]));
+    knot_vec.extend([knots[knots.len() - 1]; 3]);
+    let mut control_points = vec![V::from_point(ends[0])];
+    ends[1..]
+        .iter()
+        .zip(middles)
+        .for_each(|(end, (middle, weight))| {
+            control_points.push(V::from_point_weight(*middle, *weight));
+            control_points.push(V::from_point(*end));
+        });
+    NurbsCurve::new(BSplineCurve::new(KnotVec::from(knot_vec), control_points))
+}
+
+/// Returns whether `include` holds at `n` inner points of each span between `knots`.
+///
+/// Every span of a rational curve of degree `d` meets an algebraic surface of degree `e` at most
+/// `d * e` times unless it lies on the surface, so `n = d * e + 1` points decide the inclusion.
+fn include_at_inner_points(knots: &[f64], n: usize, include: impl Fn(f64) -> bool) -> bool {
+    knots.windows(2).all(|window| {
+        (1..=n).all(|i| {
+            let t = i as f64 / (n + 1) as f64;
+            include(window[0] * (1.0 - t) + window[1] * t)
+        })
+    })
+}
+
+/// The NURBS circle converted from the trimmed [`UnitCircle`] on `[0, 2PI]`, whose knots are
+/// `0, PI / 2, PI, 3PI / 2, 2PI`.
+fn nurbs_circle() -> NurbsCurve<Vector3> {
+    let arc = TrimmedCurve::new(
+        UnitCircle::<Point2>::new(),
+        (0.0, 2.0 * std::f64::consts::PI),
+    );
+    NurbsCurve::from(arc)
+}
+
+/// The NURBS surface swept by rotating `profile` around the axis through `origin`.
+///
+/// The parameter `u` is the one of `profile`, and `v` corresponds to the angle of the rotation
+/// as the parameter of the NURBS circle [`nurbs_circle`]: they coincide at the knots, and the
+/// angle `theta` in the `k`-th quarter corresponds to
+/// `(k + (1 + tan(alpha / 2) / tan(PI / 8)) / 2) * PI / 2`, where `alpha` is the angle from the
+/// middle of the quarter.
+pub(crate) fn nurbs_revolution(
+    profile: &NurbsCurve<Vector4>,
+    origin: Point3,
+    axis: Vector3,
+) -> NurbsSurface<Vector4> {
+    let axis = axis.normalize();
+    let circle = nurbs_circle();
+    let control_points = profile
+        .control_points()
+        .iter()
+        .map(|q| {
+            // all vectors are multiplied by the weight of `q`
+            let w = q.weight();
+            let radial = q.truncate() - origin.to_vec() * w;
+            let along = axis * radial.dot(axis);
+            let radial = radial - along;
+            let binormal = axis.cross(radial);
+            let center = origin.to_vec() * w + along;
+            circle
+                .control_points()
+                .iter()
+                .map(|c| (center * c.z + radial * c.x + binormal * c.y).extend(w * c.z))
+                .collect()
+        })
+        .collect();
+    let knot_vecs = (profile.knot_vec().clone(), circle.knot_vec().clone());
+    NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points))
+}
+
+/// The parameter of [`nurbs_circle`] corresponding to the angle `theta` in `[0, 2PI]`.
+#[cfg(test)]
+pub(crate) fn nurbs_circle_parameter(theta: f64) -> f64 {
+    const QUARTER: f64 = std::f64::consts::PI / 2.0;
+    let k = f64::min(f64::floor(theta / QUARTER), 3.0);
+    let alpha = theta - (k + 0.5) * QUARTER;
+    let t = f64::tan(alpha / 2.0) / f64::tan(QUARTER / 4.0);
+    (k + (1.0 + t) / 2.0) * QUARTER
+}
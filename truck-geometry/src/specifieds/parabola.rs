@@ -135,3 +135,40 @@ fn sp_test() {
     let p = Point2::new(-3.0, 6.0);
     assert!(curve.search_parameter(p, None, 0).is_none());
 }
+
+impl<V> From<TrimmedCurve<UnitParabola<V::Point>>> for NurbsCurve<V>
+where
+    V: Homogeneous<f64>,
+    UnitParabola<V::Point>:
+        ParametricCurve<Point = V::Point, Vector = <V::Point as EuclideanSpace>::Diff>,
+{
+    /// Converts the arc into the exact NURBS curve, a quadratic Bézier curve with the same
+    /// parameter.
+    fn from(arc: TrimmedCurve<UnitParabola<V::Point>>) -> Self {
+        let (t0, t1) = arc.range_tuple();
+        let middle = arc.subs(t0) + arc.der(t0) * ((t1 - t0) / 2.0);
+        rational_quadratic_arcs(&[t0, t1], &[arc.subs(t0), arc.subs(t1)], &[(middle, 1.0)])
+    }
+}
+
+#[test]
+fn into_nurbs() {
+    const N: usize = 100;
+    for range in [(-1.0, 1.0), (0.5, 3.0), (-4.0, -2.5)] {
+        let arc = TrimmedCurve::new(UnitParabola::<Point2>::new(), range);
+        let nurbs = NurbsCurve::<Vector3>::from(arc);
+        assert_eq!(nurbs.range_tuple(), range);
+        for i in 0..=N {
+            let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+            assert_near!(nurbs.subs(t), arc.subs(t));
+            assert_near!(nurbs.der(t), arc.der(t));
+        }
+
+        let arc = TrimmedCurve::new(UnitParabola::<Point3>::new(), range);
+        let nurbs = NurbsCurve::<Vector4>::from(arc);
+        for i in 0..=N {
+            let t = range.0 + (range.1 - range.0) * i as f64 / N as f64;
+            assert_near!(nurbs.subs(t), arc.subs(t));
+        }
+    }
+}
@@ -14,6 +14,43 @@ impl Sphere {
     /// Returns whether the point `pt` is on sphere
     #[inline(always)]
     pub fn include(&self, pt: Point3) -> bool { self.center.distance(pt).near(&self.radius) }
+    /// into NURBS surface
+    ///
+    /// The surface is the exact rational biquadratic surface whose parameter range is the same as
+    /// the sphere. Both parameters are the ones of the NURBS circle, which coincide with the angles
+    /// at the multiples of `PI / 2`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 4.0);
+    /// let surface = sphere.into_nurbs();
+    /// assert_eq!(surface.range_tuple(), ((0.0, PI), (0.0, 2.0 * PI)));
+    /// assert_near!(surface.subs(PI / 2.0, PI / 2.0), sphere.subs(PI / 2.0, PI / 2.0));
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let u = PI * i as f64 / N as f64;
+    ///         let v = 2.0 * PI * j as f64 / N as f64;
+    ///         assert!(sphere.include(surface.subs(u, v)));
+    ///     }
+    /// }
+    /// ```
+    pub fn into_nurbs(&self) -> NurbsSurface<Vector4> {
+        let (c, r) = (self.center, self.radius);
+        // the meridian `c + r * (sin u, 0, cos u)`
+        let meridian =
+            NurbsCurve::<Vector3>::from(TrimmedCurve::new(UnitCircle::<Point2>::new(), (0.0, PI)));
+        let control_points = meridian
+            .control_points()
+            .iter()
+            .map(|p| Vector4::new(p.z * c.x + r * p.y, p.z * c.y, p.z * c.z + r * p.x, p.z))
+            .collect();
+        let knot_vec = meridian.knot_vec().clone();
+        let meridian = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points));
+        nurbs_revolution(&meridian, c, Vector3::unit_z())
+    }
 }
 
 impl ParametricSurface for Sphere {
@@ -217,3 +254,19 @@ fn exec_search_parameter_test() {
 
 #[test]
 fn search_parameter_test() { (0..10).for_each(|_| exec_search_parameter_test()) }
+
+#[test]
+fn into_nurbs_test() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 4.56);
+    let surface = sphere.into_nurbs();
+    const N: usize = 10;
+    for i in 0..N {
+        for j in 0..N {
+            let u = PI * (i as f64 + 0.5) / N as f64;
+            let v = 2.0 * PI * (j as f64 + 0.5) / N as f64;
+            let (s, t) = (nurbs_circle_parameter(u), nurbs_circle_parameter(v));
+            let dist = sphere.subs(u, v).distance(surface.subs(s, t));
+            assert!(dist < 1.0e-12, "{dist}");
+        }
+    }
+}
@@ -26,6 +26,49 @@ impl Torus {
     /// get small radius
     #[inline(always)]
     pub const fn small_radius(&self) -> f64 { self.small_radius }
+
+    /// Returns whether the point `pt` is on the torus
+    #[inline(always)]
+    pub fn include(&self, pt: Point3) -> bool {
+        let r = pt - self.center;
+        let radial = Vector2::new(r.x, r.y).magnitude() - self.large_radius;
+        Vector2::new(radial, r.z)
+            .magnitude()
+            .near(&self.small_radius)
+    }
+
+    /// into NURBS surface
+    ///
+    /// The surface is the exact rational biquadratic surface whose parameter range is the same as
+    /// the torus. Both parameters are the ones of the NURBS circle, which coincide with the angles
+    /// at the multiples of `PI / 2`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let torus = Torus::new(Point3::new(1.0, 2.0, 3.0), 3.0, 1.0);
+    /// let surface = torus.into_nurbs();
+    /// assert_eq!(surface.range_tuple(), ((0.0, 2.0 * PI), (0.0, 2.0 * PI)));
+    /// assert_near!(surface.subs(PI / 2.0, PI), torus.subs(PI / 2.0, PI));
+    /// ```
+    pub fn into_nurbs(&self) -> NurbsSurface<Vector4> {
+        let (c, large, small) = (self.center, self.large_radius, self.small_radius);
+        // the section `c + (large + small * cos v, 0, small * sin v)`
+        let circle = nurbs_circle();
+        let control_points = circle
+            .control_points()
+            .iter()
+            .map(|p| {
+                let x = p.z * (c.x + large) + small * p.x;
+                Vector4::new(x, p.z * c.y, p.z * c.z + small * p.y, p.z)
+            })
+            .collect();
+        let section = NurbsCurve::new(BSplineCurve::new(circle.knot_vec().clone(), control_points));
+        let mut surface = nurbs_revolution(&section, c, Vector3::unit_z());
+        // the parameter `u` of the torus is the angle of the rotation
+        surface.swap_axes();
+        surface
+    }
 }
 
 impl ParametricSurface for Torus {
@@ -166,6 +209,29 @@ impl ParameterDivision2D for Torus {
     }
 }
 
+impl IncludeCurve<BSplineCurve<Point3>> for Torus {
+    fn include(&self, curve: &BSplineCurve<Point3>) -> bool {
+        let (knots, _) = curve.knot_vec().to_single_multi();
+        let n = curve.degree() * 4 + 1;
+        include_at_inner_points(&knots, n, |t| self.include(curve.subs(t)))
+    }
+}
+
+impl IncludeCurve<NurbsCurve<Vector4>> for Torus {
+    fn include(&self, curve: &NurbsCurve<Vector4>) -> bool {
+        let (knots, _) = curve.knot_vec().to_single_multi();
+        let n = curve.degree() * 4 + 1;
+        include_at_inner_points(&knots, n, |t| self.include(curve.subs(t)))
+    }
+}
+
+impl IncludeCurve<Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>> for Torus {
+    fn include(&self, curve: &Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>) -> bool {
+        let (t0, t1) = curve.range_tuple();
+        include_at_inner_points(&[t0, t1], 9, |t| self.include(curve.subs(t)))
+    }
+}
+
 #[test]
 fn surface() {
     use std::ops::RangeBounds;
@@ -236,3 +302,110 @@ fn surface() {
         }
     }
 }
+
+#[test]
+fn into_nurbs_test() {
+    let torus = Torus::new(Point3::new(1.0, 2.0, 3.0), 3.0, 1.2);
+    let surface = torus.into_nurbs();
+    const N: usize = 10;
+    for i in 0..N {
+        for j in 0..N {
+            let u = 2.0 * PI * (i as f64 + 0.5) / N as f64;
+            let v = 2.0 * PI * (j as f64 + 0.5) / N as f64;
+            let (s, t) = (nurbs_circle_parameter(u), nurbs_circle_parameter(v));
+            let dist = torus.subs(u, v).distance(surface.subs(s, t));
+            assert!(dist < 1.0e-12, "{dist}");
+        }
+    }
+}
+
+#[test]
+fn search_parameter_brute_force() {
+    let torus = Torus::new(Point3::new(1.0, 2.0, 3.0), 3.0, 1.2);
+    const N: usize = 400;
+    let angle = |i: usize| 2.0 * PI * i as f64 / N as f64;
+    let brute_force = |pt: Point3| -> f64 {
+        (0..N)
+            .flat_map(|i| (0..N).map(move |j| (i, j)))
+            .map(|(i, j)| torus.subs(angle(i), angle(j)).distance(pt))
+            .fold(f64::INFINITY, f64::min)
+    };
+    for _ in 0..10 {
+        let q = torus.center()
+            + Vector3::new(
+                10.0 * rand::random::<f64>() - 5.0,
+                10.0 * rand::random::<f64>() - 5.0,
+                4.0 * rand::random::<f64>() - 2.0,
+            );
+        let (u0, v0) = torus.search_nearest_parameter(q, None, 1).unwrap();
+        let dist = torus.subs(u0, v0).distance(q);
+        let brute = brute_force(q);
+        assert!(
+            dist <= brute + TOLERANCE && brute - dist < 0.1,
+            "{dist} {brute}"
+        );
+    }
+}
+
+#[test]
+fn normal_consistency() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let torus = Torus::new(center, 3.0, 1.2);
+    // the section circle in the xz-plane, reversed so that the normal of the revolution is outer
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, 2.0 * PI));
+    let mut section = NurbsCurve::<Vector4>::from(arc);
+    section.transform_by(
+        Matrix4::from_translation(center.to_vec() + Vector3::new(3.0, 0.0, 0.0))
+            * Matrix4::from_angle_x(Rad(PI / 2.0))
+            * Matrix4::from_scale(1.2),
+    );
+    section.invert();
+    let revolved = RevolutedCurve::by_revolution(section, center, Vector3::unit_z());
+    const N: usize = 10;
+    let angle = |i: usize| 2.0 * PI * i as f64 / N as f64;
+    for i in 0..N {
+        for j in 0..N {
+            let (u, v) = (angle(i), angle(j));
+            let pt = torus.subs(u, v);
+            let (s, t) = revolved.search_parameter(pt, None, 100).unwrap();
+            assert_near!(torus.normal(u, v), revolved.normal(s, t));
+            // the normal points away from the core circle
+            let core = center + 3.0 * Vector3::new(f64::cos(u), f64::sin(u), 0.0);
+            assert!(torus.normal(u, v).dot(pt - core) > 0.0);
+        }
+    }
+}
+
+#[test]
+fn include_circles() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let torus = Torus::new(center, 3.0, 1.2);
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, 2.0 * PI));
+    let translation = Matrix4::from_translation(center.to_vec());
+    let mats = [
+        // the outer equator
+        translation * Matrix4::from_scale(4.2),
+        // the top circle
+        translation * Matrix4::from_translation(Vector3::new(0.0, 0.0, 1.2)),
+        // the section circle
+        translation
+            * Matrix4::from_angle_z(Rad(1.0))
+            * Matrix4::from_translation(Vector3::new(3.0, 0.0, 0.0))
+            * Matrix4::from_angle_x(Rad(PI / 2.0))
+            * Matrix4::from_scale(1.2),
+    ];
+    mats.iter().for_each(|mat| {
+        let mut circle = Processor::new(arc);
+        circle.transform_by(*mat);
+        let mut nurbs = NurbsCurve::<Vector4>::from(arc);
+        nurbs.transform_by(*mat);
+        assert!(IncludeCurve::include(&torus, &circle));
+        assert!(IncludeCurve::include(&torus, &nurbs));
+
+        let mat = Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.1));
+        circle.transform_by(mat);
+        nurbs.transform_by(mat);
+        assert!(!IncludeCurve::include(&torus, &circle));
+        assert!(!IncludeCurve::include(&torus, &nurbs));
+    });
+}
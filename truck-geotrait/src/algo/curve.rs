@@ -1,7 +1,36 @@
 use super::*;
+use std::ops::Bound;
+use truck_base::bounding_box::{Bounded, BoundingBox};
+
+/// Wraps the parameter `t` of the periodic curve into the canonical range `[t0, t0 + period]`,
+/// where `t0` is the front of the parameter range, or `0.0` if the range is unbounded.
+///
+/// The parameters in the canonical range are not changed, except that the back end is wrapped
+/// to the front if it is excluded from the parameter range. Returns `t` itself if the curve is
+/// not periodic.
+pub fn normalize_parameter<C: ParametricCurve>(curve: &C, t: f64) -> f64 {
+    let Some(period) = curve.period() else {
+        return t;
+    };
+    let (front, back) = curve.parameter_range();
+    let t0 = match front {
+        Bound::Included(t0) | Bound::Excluded(t0) => t0,
+        Bound::Unbounded => 0.0,
+    };
+    let outside = match back {
+        Bound::Excluded(_) => t0 + period <= t,
+        _ => t0 + period < t,
+    };
+    match t < t0 || outside {
+        true => t0 + f64::rem_euclid(t - t0, period),
+        false => t,
+    }
+}
 
 /// Divides the domain into equal parts, examines all the values, and returns `t` such that `curve.subs(t)` is closest to `point`.
 /// This method is useful to get an efficient hint of `search_nearest_parameter`.
+///
+/// The result is wrapped by [`normalize_parameter`] if the curve is periodic.
 pub fn presearch<C>(curve: &C, point: C::Point, range: (f64, f64), division: usize) -> f64
 where
     C: ParametricCurve,
@@ -18,22 +47,50 @@ where
             res = t;
         }
     }
-    res
+    normalize_parameter(curve, res)
 }
 
 /// Searches the nearest parameter by Newton's method.
+///
+/// This is [`try_search_nearest_parameter`] without the reason of the failure.
+#[inline(always)]
 pub fn search_nearest_parameter<C>(
     curve: &C,
     point: C::Point,
-    mut hint: f64,
+    hint: f64,
     trials: usize,
 ) -> Option<f64>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    try_search_nearest_parameter(curve, point, hint, trials).ok()
+}
+
+/// Searches the nearest parameter by Newton's method.
+///
+/// If the curve is periodic, each step is wrapped by [`normalize_parameter`], so the steps
+/// crossing the seam do not fail and the result is in the canonical range.
+///
+/// # Failures
+/// - [`SearchError::VanishingDerivative`] if the derivation vanishes at the iteration and the
+/// point is not there, since Newton's method cannot proceed.
+/// - [`SearchError::NotConverged`] if Newton's method does not converge within `trials`.
+pub fn try_search_nearest_parameter<C>(
+    curve: &C,
+    point: C::Point,
+    mut hint: f64,
+    trials: usize,
+) -> Result<f64, SearchError>
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
     C::Vector: InnerSpace<Scalar = f64> + Tolerance,
 {
     let mut log = NewtonLog::default();
+    hint = normalize_parameter(curve, hint);
+    let mut residual = f64::INFINITY;
     for _ in 0..=trials {
         log.push(hint);
         let pt = curve.subs(hint);
@@ -42,32 +99,201 @@ where
         let f = der.dot(pt - point);
         let fprime = der2.dot(pt - point) + der.magnitude2();
         let dermag = f64::min(der.magnitude(), 1.0);
-        if f64::abs(f) < TOLERANCE * dermag || fprime.so_small() {
-            return Some(hint);
+        residual = f64::abs(f);
+        if residual < TOLERANCE * dermag || (pt - point).so_small() {
+            return Ok(hint);
+        } else if fprime.so_small() {
+            return match der.so_small() {
+                true => Err(SearchError::VanishingDerivative { at: hint }),
+                false => Ok(hint),
+            };
         } else {
-            hint -= f / fprime;
+            hint = normalize_parameter(curve, hint - f / fprime);
         }
     }
     log.print_error();
-    None
+    Err(SearchError::NotConverged {
+        last: hint,
+        residual,
+    })
+}
+
+/// The number of the division of the range in the presearch of the bounded search.
+const BOUNDED_PRESEARCH_DIVISION: usize = 50;
+
+/// The maximum number of the iterations of the golden-section search.
+const GOLDEN_SECTION_MAX_ITERATION: usize = 100;
+
+/// Searches the nearest parameter in `range` by Newton's method.
+///
+/// Each step of Newton's method is clamped into `range`, and an end of `range` is returned
+/// if the squared distance increases toward the inside of `range` there. If Newton's method
+/// does not converge to a local minimum, e.g. it oscillates between the ends of `range`, the
+/// golden-section search is executed on the interval around the result of [`presearch`].
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct Parabola;
+/// impl ParametricCurve for Parabola {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, t * t) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(1.0, 2.0 * t) }
+///     fn der2(&self, _: f64) -> Vector2 { Vector2::new(0.0, 2.0) }
+/// }
+///
+/// let pt = Point2::new(4.0, 0.0);
+/// let t = algo::curve::search_nearest_parameter(&Parabola, pt, 0.5, 100).unwrap();
+/// assert!(t > 1.0);
+/// let t = algo::curve::search_nearest_parameter_bounded(&Parabola, pt, 0.5, (0.0, 1.0), 100);
+/// assert_eq!(t, Some(1.0));
+/// ```
+pub fn search_nearest_parameter_bounded<C>(
+    curve: &C,
+    point: C::Point,
+    hint: f64,
+    range: (f64, f64),
+    trials: usize,
+) -> Option<f64>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector> + MetricSpace<Metric = f64>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let (t0, t1) = match range.0 <= range.1 {
+        true => range,
+        false => (range.1, range.0),
+    };
+    let mut hint = hint.clamp(t0, t1);
+    let mut previous = None;
+    for _ in 0..=trials {
+        let pt = curve.subs(hint);
+        let der = curve.der(hint);
+        let der2 = curve.der2(hint);
+        let f = der.dot(pt - point);
+        let fprime = der2.dot(pt - point) + der.magnitude2();
+        let dermag = f64::min(der.magnitude(), 1.0);
+        if (hint == t0 && f >= 0.0) || (hint == t1 && f <= 0.0) {
+            return Some(hint);
+        } else if fprime <= 0.0 || fprime.so_small() {
+            break;
+        } else if f64::abs(f) < TOLERANCE * dermag {
+            return Some(hint);
+        }
+        let next = f64::clamp(hint - f / fprime, t0, t1);
+        let oscillating = match previous {
+            Some(previous) => previous == next && (next == t0 || next == t1),
+            None => false,
+        };
+        if oscillating {
+            break;
+        }
+        previous = Some(hint);
+        hint = next;
+    }
+
+    let t = presearch(curve, point, (t0, t1), BOUNDED_PRESEARCH_DIVISION);
+    let delta = (t1 - t0) / BOUNDED_PRESEARCH_DIVISION as f64;
+    let (mut a, mut b) = (f64::max(t - delta, t0), f64::min(t + delta, t1));
+    let dist2 = |t: f64| curve.subs(t).distance2(point);
+    let (mut c, mut d) = (
+        b - GOLDEN_RATIO_FRACT * (b - a),
+        a + GOLDEN_RATIO_FRACT * (b - a),
+    );
+    let (mut fc, mut fd) = (dist2(c), dist2(d));
+    for _ in 0..GOLDEN_SECTION_MAX_ITERATION {
+        if b - a < TOLERANCE2 {
+            break;
+        }
+        if fc < fd {
+            (b, d, fd) = (d, c, fc);
+            c = b - GOLDEN_RATIO_FRACT * (b - a);
+            fc = dist2(c);
+        } else {
+            (a, c, fc) = (c, d, fd);
+            d = a + GOLDEN_RATIO_FRACT * (b - a);
+            fd = dist2(d);
+        }
+    }
+    [a, b, (a + b) / 2.0]
+        .into_iter()
+        .min_by(|s, t| dist2(*s).total_cmp(&dist2(*t)))
 }
 
 /// Searches the parameter by Newton's method.
+///
+/// This is [`try_search_parameter`] without the reason of the failure.
+#[inline(always)]
 pub fn search_parameter<C>(curve: &C, point: C::Point, hint: f64, trials: usize) -> Option<f64>
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
     C::Vector: InnerSpace<Scalar = f64> + Tolerance, {
-    search_nearest_parameter(curve, point, hint, trials).and_then(|t| {
-        match point.to_vec().near(&curve.subs(t).to_vec()) {
-            true => Some(t),
-            false => None,
-        }
-    })
+    try_search_parameter(curve, point, hint, trials).ok()
+}
+
+/// Searches the parameter by Newton's method.
+///
+/// The result is wrapped by [`normalize_parameter`] if the curve is periodic.
+///
+/// # Failures
+/// - The failures of [`try_search_nearest_parameter`].
+/// - [`SearchError::OffGeometry`] if the nearest parameter is found but the point is not on
+/// the curve.
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct Parabola;
+/// impl ParametricCurve for Parabola {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, t * t) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(1.0, 2.0 * t) }
+///     fn der2(&self, _: f64) -> Vector2 { Vector2::new(0.0, 2.0) }
+/// }
+///
+/// let pt = Point2::new(2.0, 4.0);
+/// let t = algo::curve::try_search_parameter(&Parabola, pt, 1.0, 100).unwrap();
+/// assert!(f64::abs(t - 2.0) < 1.0e-6);
+///
+/// // the nearest point of `(0, -1)` is the origin.
+/// let pt = Point2::new(0.0, -1.0);
+/// match algo::curve::try_search_parameter(&Parabola, pt, 1.0, 100) {
+///     Err(SearchError::OffGeometry { distance, .. }) => assert!(f64::abs(distance - 1.0) < 1.0e-6),
+///     _ => panic!("the point is not on the parabola."),
+/// }
+/// ```
+pub fn try_search_parameter<C>(
+    curve: &C,
+    point: C::Point,
+    hint: f64,
+    trials: usize,
+) -> Result<f64, SearchError>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let t = try_search_nearest_parameter(curve, point, hint, trials)?;
+    match point.to_vec().near(&curve.subs(t).to_vec()) {
+        true => Ok(t),
+        false => Err(SearchError::OffGeometry {
+            nearest: t,
+            distance: (curve.subs(t) - point).magnitude(),
+        }),
+    }
 }
 
 /// Creates the curve division
 ///
+/// This is [`parameter_division_with_seed`] with the seed `0`.
+///
 /// # Panics
 ///
 /// `tol` must be more than `TOLERANCE`.
@@ -75,54 +301,609 @@ pub fn parameter_division<C>(curve: &C, range: (f64, f64), tol: f64) -> (Vec<f64
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
-    nonpositive_tolerance!(tol);
-    sub_parameter_division(
-        curve,
-        range,
-        (curve.subs(range.0), curve.subs(range.1)),
-        tol,
-        100,
-    )
+    parameter_division_with_seed(curve, range, tol, 0)
 }
 
-fn sub_parameter_division<C>(
+/// The fractional part of the golden ratio, the step of the quasi-random sequence of the seeds.
+const GOLDEN_RATIO_FRACT: f64 = 0.618_033_988_749_894_9;
+
+/// The maximum depth of the bisections in the curve division.
+const DIVISION_MAX_DEPTH: usize = 100;
+
+/// Creates the curve division with the seed of the sample points.
+///
+/// Each piece of the division is bisected while the curve at the middle parameter or at the
+/// perturbed parameter is farther than `tol` from the corresponding point on the chord.
+/// The perturbation is determined by the hash of the ends of the piece shifted by `seed`,
+/// so the division is reproducible. The bisection is done on an explicit stack and stops at
+/// the depth `100` or when the piece cannot be bisected in `f64`, at which the piece is
+/// accepted regardless of `tol`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn parameter_division_with_seed<C>(
     curve: &C,
     range: (f64, f64),
-    ends: (C::Point, C::Point),
     tol: f64,
-    trials: usize,
+    seed: u64,
 ) -> (Vec<f64>, Vec<C::Point>)
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
 {
-    let gen = ends.0.midpoint(ends.1);
-    let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
-    let t = range.0 * (1.0 - p) + range.1 * p;
-    let mid = ends.0 + (ends.1 - ends.0) * p;
-    let dist2 = curve.subs(t).distance2(mid);
-    if dist2 < tol * tol || trials == 0 {
-        (vec![range.0, range.1], vec![ends.0, ends.1])
-    } else {
+    nonpositive_tolerance!(tol);
+    let shift = f64::fract(seed as f64 * GOLDEN_RATIO_FRACT);
+    let ends = (curve.subs(range.0), curve.subs(range.1));
+    let (mut params, mut pts) = (vec![range.0], vec![ends.0]);
+    let mut stack = vec![(range, ends, 0)];
+    while let Some((range, ends, depth)) = stack.pop() {
+        let gen = ends.0.midpoint(ends.1);
+        let p = 0.5 + (0.2 * f64::fract(HashGen::hash1(gen) + shift) - 0.1);
+        let t = range.0 * (1.0 - p) + range.1 * p;
+        let perturbed = ends.0 + (ends.1 - ends.0) * p;
         let mid_param = (range.0 + range.1) / 2.0;
         let mid_value = curve.subs(mid_param);
-        let (mut params, mut pts) = sub_parameter_division(
-            curve,
-            (range.0, mid_param),
-            (ends.0, mid_value),
-            tol,
-            trials - 1,
-        );
-        let _ = (params.pop(), pts.pop());
-        let (new_params, new_pts) = sub_parameter_division(
-            curve,
-            (mid_param, range.1),
-            (mid_value, ends.1),
-            tol,
-            trials - 1,
-        );
-        params.extend(new_params);
-        pts.extend(new_pts);
-        (params, pts)
+        let is_flat =
+            curve.subs(t).distance2(perturbed) < tol * tol && mid_value.distance2(gen) < tol * tol;
+        let is_atomic = mid_param <= range.0 || range.1 <= mid_param;
+        if is_flat || is_atomic || depth == DIVISION_MAX_DEPTH {
+            params.push(range.1);
+            pts.push(ends.1);
+        } else {
+            stack.push(((mid_param, range.1), (mid_value, ends.1), depth + 1));
+            stack.push(((range.0, mid_param), (ends.0, mid_value), depth + 1));
+        }
+    }
+    (params, pts)
+}
+
+/// The nodes and the weights of the 5-point Gauss–Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// The maximum depth of the bisections in the adaptive quadrature.
+const ARC_LENGTH_MAX_DEPTH: usize = 30;
+
+/// The number of the divisions of the range in the presearch of the parameter at an arc length.
+const ARC_LENGTH_PRESEARCH_DIVISION: usize = 16;
+
+fn gauss_legendre_length<C>(curve: &C, (t0, t1): (f64, f64)) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (mid, half) = ((t0 + t1) / 2.0, (t1 - t0) / 2.0);
+    GAUSS_LEGENDRE_5
+        .iter()
+        .map(|(x, w)| w * curve.der(mid + half * x).magnitude())
+        .sum::<f64>()
+        * half
+}
+
+/// Calculates the length of the curve on `range` by the adaptive Gauss–Legendre quadrature
+/// of the norm of the derivation.
+///
+/// The interval is bisected until the difference between the integral on it and the sum of
+/// the integrals on the halves is less than the part of `tol` in proportion to its width.
+/// If `range.0 > range.1`, returns the negative length.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn arc_length<C>(curve: &C, range: (f64, f64), tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    let width = range.1 - range.0;
+    if width == 0.0 {
+        return 0.0;
+    }
+    let mut stack = vec![(range, gauss_legendre_length(curve, range), 0)];
+    let mut res = 0.0;
+    while let Some(((t0, t1), whole, depth)) = stack.pop() {
+        let mid = (t0 + t1) / 2.0;
+        let left = gauss_legendre_length(curve, (t0, mid));
+        let right = gauss_legendre_length(curve, (mid, t1));
+        let local_tol = tol * f64::abs((t1 - t0) / width);
+        if f64::abs(left + right - whole) < local_tol || depth == ARC_LENGTH_MAX_DEPTH {
+            res += left + right;
+        } else {
+            stack.push(((mid, t1), right, depth + 1));
+            stack.push(((t0, mid), left, depth + 1));
+        }
+    }
+    res
+}
+
+/// The cumulative arc lengths at the division points of the range, used as the presearch of
+/// the parameter at an arc length.
+struct ArcLengthTable {
+    params: Vec<f64>,
+    lengths: Vec<f64>,
+}
+
+impl ArcLengthTable {
+    fn new<C>(curve: &C, range: (f64, f64), division: usize, tol: f64) -> Self
+    where
+        C: ParametricCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        let params = (0..=division)
+            .map(|i| {
+                let p = i as f64 / division as f64;
+                range.0 * (1.0 - p) + range.1 * p
+            })
+            .collect::<Vec<_>>();
+        let piece_tol = f64::max(tol / division as f64, TOLERANCE);
+        let mut lengths = vec![0.0];
+        params.windows(2).for_each(|t| {
+            let len = arc_length(curve, (t[0], t[1]), piece_tol);
+            lengths.push(lengths[lengths.len() - 1] + len);
+        });
+        Self { params, lengths }
+    }
+
+    #[inline(always)]
+    fn total(&self) -> f64 { self.lengths[self.lengths.len() - 1] }
+
+    /// Searches the parameter by Newton's method safeguarded by bisection, starting from the
+    /// linear interpolation in the piece of the table containing the arc length `s`.
+    fn param_at<C>(&self, curve: &C, s: f64, tol: f64) -> f64
+    where
+        C: ParametricCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        let s = s.clamp(0.0, self.total());
+        let i = self.lengths[1..].partition_point(|len| *len < s);
+        let i = usize::min(i, self.params.len() - 2);
+        let (mut lo, mut hi) = (self.params[i], self.params[i + 1]);
+        let (start, s) = (lo, s - self.lengths[i]);
+        let piece_length = self.lengths[i + 1] - self.lengths[i];
+        let mut t = match piece_length.so_small() {
+            true => lo,
+            false => lo + (hi - lo) * s / piece_length,
+        };
+        let mut log = NewtonLog::default();
+        for _ in 0..100 {
+            log.push(t);
+            let f = arc_length(curve, (start, t), tol) - s;
+            if f64::abs(f) < tol {
+                return t;
+            }
+            match f > 0.0 {
+                true => hi = t,
+                false => lo = t,
+            }
+            let der = curve.der(t).magnitude();
+            let next = t - f / der;
+            t = match der.so_small() || next <= lo || hi <= next {
+                true => (lo + hi) / 2.0,
+                false => next,
+            };
+        }
+        log.print_error();
+        t
+    }
+}
+
+/// Returns the parameter `t` such that the length of the curve on `(range.0, t)` is `s`.
+///
+/// The parameter is searched by Newton's method seeded by a presearch on the equal division
+/// of `range`, which must satisfy `range.0 < range.1`. `s` is clamped to the interval between
+/// zero and the length of the curve on `range`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn param_at_arc_length<C>(curve: &C, range: (f64, f64), s: f64, tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    ArcLengthTable::new(curve, range, ARC_LENGTH_PRESEARCH_DIVISION, tol).param_at(curve, s, tol)
+}
+
+/// Divides `range` into `n` pieces on which the lengths of the curve are equal.
+///
+/// Returns the `n + 1` parameters, including the ends of `range`, and the points on them.
+/// `range` must satisfy `range.0 < range.1`, and the lengths are calculated with the tolerance
+/// `TOLERANCE`.
+///
+/// # Panics
+///
+/// `n` must be positive.
+pub fn uniform_arc_length_division<C>(
+    curve: &C,
+    range: (f64, f64),
+    n: usize,
+) -> (Vec<f64>, Vec<C::Point>)
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    assert!(n > 0, "the number of the divisions must be positive.");
+    let division = usize::max(n, ARC_LENGTH_PRESEARCH_DIVISION);
+    let table = ArcLengthTable::new(curve, range, division, TOLERANCE);
+    let total = table.total();
+    let params = (0..=n)
+        .map(|i| match i {
+            0 => range.0,
+            _ if i == n => range.1,
+            _ => table.param_at(curve, total * i as f64 / n as f64, TOLERANCE),
+        })
+        .collect::<Vec<_>>();
+    let points = params.iter().map(|t| curve.subs(*t)).collect();
+    (params, points)
+}
+
+/// The maximum number of the iterations of the Gauss-Newton method polishing an intersection.
+const INTERSECTION_NEWTON_TRIALS: usize = 100;
+
+/// Searches the intersection points of two curves.
+///
+/// Returns the vector of `(t, s, point)`, sorted by `t`, such that `curve0.subs(t)` and
+/// `curve1.subs(s)` are within `tol` of each other and `point == curve0.subs(t)`.
+/// # Details
+/// - The polylines given by [`parameter_division`] with `tol` are recursively bisected while
+/// their bounding boxes, widened by `tol`, overlap.
+/// - Each pair of the segments found as above gives the initial guess of the Gauss-Newton
+/// method for `curve0(t) - curve1(s) = 0`, whose steps are clamped into the ranges.
+/// - The solutions such that the curves are within `tol` between them are merged into one,
+/// so a tangential intersection is returned once.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn intersections<C0, C1>(
+    curve0: &C0,
+    range0: (f64, f64),
+    curve1: &C1,
+    range1: (f64, f64),
+    tol: f64,
+) -> Vec<(f64, f64, C0::Point)>
+where
+    C0: ParametricCurve,
+    C1: ParametricCurve<Point = C0::Point, Vector = C0::Vector>,
+    C0::Point: EuclideanSpace<Scalar = f64, Diff = C0::Vector>
+        + Bounded<Scalar = f64>
+        + MetricSpace<Metric = f64>
+        + HashGen<f64>,
+    C0::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    nonpositive_tolerance!(tol);
+    let (params0, pts0) = parameter_division(curve0, range0, tol);
+    let (params1, pts1) = parameter_division(curve1, range1, tol);
+    let mut pairs = Vec::new();
+    segment_pairs((&pts0, 0), (&pts1, 0), tol, &mut pairs);
+    let mut hits = pairs
+        .into_iter()
+        .filter_map(|(i, j)| {
+            let (x, y) = nearest_on_segments((pts0[i], pts0[i + 1]), (pts1[j], pts1[j + 1]));
+            let t = params0[i] * (1.0 - x) + params0[i + 1] * x;
+            let s = params1[j] * (1.0 - y) + params1[j + 1] * y;
+            polish_intersection((curve0, range0), (curve1, range1), (t, s), tol)
+        })
+        .collect::<Vec<_>>();
+    hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut clusters: Vec<Vec<(f64, f64)>> = Vec::new();
+    hits.into_iter().for_each(|(t, s)| {
+        let is_same = clusters
+            .last()
+            .and_then(|cluster| cluster.last())
+            .is_some_and(|x| {
+                let mid0 = curve0.subs((x.0 + t) / 2.0);
+                let mid1 = curve1.subs((x.1 + s) / 2.0);
+                mid0.distance(mid1) < tol
+            });
+        match is_same {
+            true => clusters.last_mut().unwrap().push((t, s)),
+            false => clusters.push(vec![(t, s)]),
+        }
+    });
+    clusters
+        .into_iter()
+        .map(|cluster| {
+            let len = cluster.len() as f64;
+            let (t, s) = cluster
+                .into_iter()
+                .fold((0.0, 0.0), |(t, s), x| (t + x.0, s + x.1));
+            let (t, s) = (t / len, s / len);
+            (t, s, curve0.subs(t))
+        })
+        .collect()
+}
+
+/// Pushes the pairs of the indices of the segments of two polylines whose bounding boxes,
+/// widened by `tol`, overlap.
+fn segment_pairs<P>(
+    (pts0, offset0): (&[P], usize),
+    (pts1, offset1): (&[P], usize),
+    tol: f64,
+    pairs: &mut Vec<(usize, usize)>,
+) where
+    P: EuclideanSpace<Scalar = f64> + Bounded<Scalar = f64>,
+{
+    if pts0.len() < 2 || pts1.len() < 2 {
+        return;
+    }
+    let bdb0 = pts0.iter().collect::<BoundingBox<P>>();
+    let bdb1 = pts1.iter().collect::<BoundingBox<P>>();
+    let (min0, max0, min1, max1) = (bdb0.min(), bdb0.max(), bdb1.min(), bdb1.max());
+    if (0..P::len()).any(|i| min0[i] - tol > max1[i] || min1[i] - tol > max0[i]) {
+        return;
+    }
+    match (pts0.len(), pts1.len()) {
+        (2, 2) => pairs.push((offset0, offset1)),
+        (len0, len1) if len0 >= len1 => {
+            let mid = (len0 - 1) / 2;
+            segment_pairs((&pts0[..=mid], offset0), (pts1, offset1), tol, pairs);
+            segment_pairs((&pts0[mid..], offset0 + mid), (pts1, offset1), tol, pairs);
+        }
+        (_, len1) => {
+            let mid = (len1 - 1) / 2;
+            segment_pairs((pts0, offset0), (&pts1[..=mid], offset1), tol, pairs);
+            segment_pairs((pts0, offset0), (&pts1[mid..], offset1 + mid), tol, pairs);
+        }
+    }
+}
+
+/// Returns the ratios `(x, y)` of the nearest points `p0 + (p1 - p0) * x` and
+/// `q0 + (q1 - q0) * y` on two segments.
+fn nearest_on_segments<P>((p0, p1): (P, P), (q0, q1): (P, P)) -> (f64, f64)
+where
+    P: EuclideanSpace<Scalar = f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let (d0, d1, r) = (p1 - p0, q1 - q0, p0 - q0);
+    let (a, b, e) = (d0.dot(d0), d0.dot(d1), d1.dot(d1));
+    let (c, f) = (d0.dot(r), d1.dot(r));
+    if a.so_small() || e.so_small() {
+        return (0.5, 0.5);
+    }
+    let denom = a * e - b * b;
+    let x = match denom.so_small() {
+        true => 0.5,
+        false => f64::clamp((b * f - c * e) / denom, 0.0, 1.0),
+    };
+    let y = f64::clamp((b * x + f) / e, 0.0, 1.0);
+    let x = f64::clamp((b * y - c) / a, 0.0, 1.0);
+    (x, y)
+}
+
+/// Polishes the intersection of two curves by the Gauss-Newton method.
+///
+/// The normal equation is slightly damped so that the tangential intersection can be solved.
+fn polish_intersection<C0, C1>(
+    (curve0, range0): (&C0, (f64, f64)),
+    (curve1, range1): (&C1, (f64, f64)),
+    (mut t, mut s): (f64, f64),
+    tol: f64,
+) -> Option<(f64, f64)>
+where
+    C0: ParametricCurve,
+    C1: ParametricCurve<Point = C0::Point, Vector = C0::Vector>,
+    C0::Point: EuclideanSpace<Scalar = f64, Diff = C0::Vector>,
+    C0::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let clamp = |t: f64, (t0, t1): (f64, f64)| f64::clamp(t, f64::min(t0, t1), f64::max(t0, t1));
+    for _ in 0..INTERSECTION_NEWTON_TRIALS {
+        let r = curve0.subs(t) - curve1.subs(s);
+        if r.magnitude2() < TOLERANCE2 * TOLERANCE2 {
+            break;
+        }
+        let (a, b) = (curve0.der(t), curve1.der(s));
+        let (aa, ab, bb) = (a.dot(a), a.dot(b), b.dot(b));
+        let damping = TOLERANCE2 * (aa + bb);
+        let mat = Matrix2::new(aa + damping, -ab, -ab, bb + damping);
+        let delta = mat.invert()? * Vector2::new(a.dot(r), -b.dot(r));
+        t = clamp(t - delta.x, range0);
+        s = clamp(s - delta.y, range1);
+    }
+    match curve0.subs(t).distance(curve1.subs(s)) < tol {
+        true => Some((t, s)),
+        false => None,
+    }
+}
+
+/// The number of the division of each range in the presearch of the closest pair.
+const CLOSEST_PAIR_PRESEARCH_DIVISION: usize = 32;
+
+/// Searches the parameters `(t, s)` such that `curve0.subs(t)` and `curve1.subs(s)` are the
+/// closest pair of the points on the curves.
+///
+/// The initial guess is given by the presearch on the grid of the equal divisions of `range0`
+/// and `range1`. Then, Newton's method is applied to the gradient of the squared distance.
+/// Each step is clamped into the ranges, and the parameter at an end of the range is fixed if
+/// the squared distance increases toward the inside of the range there. The Hessian is damped
+/// so that the method does not stop on the degenerated cases, e.g. the parallel segments.
+/// Returns `None` if Newton's method does not converge in `trials` iterations.
+pub fn search_closest_pair<C0, C1>(
+    curve0: &C0,
+    range0: (f64, f64),
+    curve1: &C1,
+    range1: (f64, f64),
+    trials: usize,
+) -> Option<(f64, f64)>
+where
+    C0: ParametricCurve,
+    C1: ParametricCurve<Point = C0::Point, Vector = C0::Vector>,
+    C0::Point: EuclideanSpace<Scalar = f64, Diff = C0::Vector> + MetricSpace<Metric = f64>,
+    C0::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let sort = |(x, y): (f64, f64)| (f64::min(x, y), f64::max(x, y));
+    let ((t0, t1), (s0, s1)) = (sort(range0), sort(range1));
+    let param = |(t0, t1): (f64, f64), i: usize| {
+        let p = i as f64 / CLOSEST_PAIR_PRESEARCH_DIVISION as f64;
+        t0 * (1.0 - p) + t1 * p
+    };
+    let samples0 = (0..=CLOSEST_PAIR_PRESEARCH_DIVISION)
+        .map(|i| param((t0, t1), i))
+        .map(|t| (t, curve0.subs(t)))
+        .collect::<Vec<_>>();
+    let samples1 = (0..=CLOSEST_PAIR_PRESEARCH_DIVISION)
+        .map(|i| param((s0, s1), i))
+        .map(|s| (s, curve1.subs(s)))
+        .collect::<Vec<_>>();
+    let (mut t, mut s) = samples0
+        .iter()
+        .flat_map(|(t, p)| {
+            samples1
+                .iter()
+                .map(move |(s, q)| ((*t, *s), p.distance2(*q)))
+        })
+        .min_by(|x, y| x.1.total_cmp(&y.1))?
+        .0;
+
+    let mut log = NewtonLog::default();
+    for _ in 0..=trials {
+        log.push((t, s));
+        let r = curve0.subs(t) - curve1.subs(s);
+        let (a, b) = (curve0.der(t), curve1.der(s));
+        let (a2, b2) = (curve0.der2(t), curve1.der2(s));
+        let (gt, gs) = (a.dot(r), -b.dot(r));
+        let free_t = !((t == t0 && gt >= 0.0) || (t == t1 && gt <= 0.0));
+        let free_s = !((s == s0 && gs >= 0.0) || (s == s1 && gs <= 0.0));
+        let small_t = f64::abs(gt) < TOLERANCE * f64::min(a.magnitude(), 1.0);
+        let small_s = f64::abs(gs) < TOLERANCE * f64::min(b.magnitude(), 1.0);
+        if (!free_t || small_t) && (!free_s || small_s) {
+            return Some((t, s));
+        }
+        let (htt, hts, hss) = match (free_t, free_s) {
+            (true, true) => (a.dot(a) + a2.dot(r), -a.dot(b), b.dot(b) - b2.dot(r)),
+            (true, false) => (a.dot(a) + a2.dot(r), 0.0, 1.0),
+            (false, _) => (1.0, 0.0, b.dot(b) - b2.dot(r)),
+        };
+        let gt = if free_t { gt } else { 0.0 };
+        let gs = if free_s { gs } else { 0.0 };
+        // shifts the smallest eigenvalue of the Hessian to be positive
+        let min_eigen = (htt + hss) / 2.0 - f64::hypot((htt - hss) / 2.0, hts);
+        let scale = htt.abs() + hss.abs() + 1.0;
+        let damping = f64::max(-min_eigen, 0.0) + TOLERANCE2 * scale;
+        let mat = Matrix2::new(htt + damping, hts, hts, hss + damping);
+        let delta = mat.invert()? * Vector2::new(gt, gs);
+        t = f64::clamp(t - delta.x, t0, t1);
+        s = f64::clamp(s - delta.y, s0, s1);
+    }
+    log.print_error();
+    None
+}
+
+/// Returns the distance between two curves.
+///
+/// This is the distance between the points given by [`search_closest_pair`].
+pub fn distance_between_curves<C0, C1>(
+    curve0: &C0,
+    range0: (f64, f64),
+    curve1: &C1,
+    range1: (f64, f64),
+    trials: usize,
+) -> Option<f64>
+where
+    C0: ParametricCurve,
+    C1: ParametricCurve<Point = C0::Point, Vector = C0::Vector>,
+    C0::Point: EuclideanSpace<Scalar = f64, Diff = C0::Vector> + MetricSpace<Metric = f64>,
+    C0::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let (t, s) = search_closest_pair(curve0, range0, curve1, range1, trials)?;
+    Some(curve0.subs(t).distance(curve1.subs(s)))
+}
+
+/// Returns the curvature of the curve at `t`.
+///
+/// The curvature is `|der x der2| / |der|^3`, calculated by the Gram determinant so that it
+/// can be applied to curves in any dimension. Returns zero if the derivation vanishes.
+pub fn curvature<C>(curve: &C, t: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (der, der2) = (curve.der(t), curve.der2(t));
+    let mag2 = der.magnitude2();
+    if mag2.so_small2() {
+        return 0.0;
+    }
+    let gram = mag2 * der2.magnitude2() - der.dot(der2) * der.dot(der2);
+    f64::sqrt(f64::max(gram, 0.0)) / (mag2 * f64::sqrt(mag2))
+}
+
+/// Returns the torsion of the curve at `t`.
+///
+/// The torsion is `(der x der2) . der3 / |der x der2|^2`, where `der3` is given by
+/// [`ParametricCurve3D::der3`]. Returns zero if the curvature vanishes.
+pub fn torsion<C: ParametricCurve3D>(curve: &C, t: f64) -> f64 {
+    let binormal = curve.der(t).cross(curve.der2(t));
+    let mag2 = binormal.magnitude2();
+    match mag2.so_small2() {
+        true => 0.0,
+        false => binormal.dot(curve.der3(t)) / mag2,
+    }
+}
+
+/// Returns the Frenet frame, the unit tangent, normal, and binormal vectors, of the curve at `t`.
+///
+/// Returns `None` if the derivation or the curvature vanishes.
+pub fn frenet_frame<C: ParametricCurve3D>(
+    curve: &C,
+    t: f64,
+) -> Option<(Vector3, Vector3, Vector3)> {
+    let (der, der2) = (curve.der(t), curve.der2(t));
+    let binormal = der.cross(der2);
+    if der.so_small() || (binormal.magnitude() / der.magnitude().powi(3)).so_small() {
+        return None;
+    }
+    let (tangent, binormal) = (der.normalize(), binormal.normalize());
+    Some((tangent, binormal.cross(tangent), binormal))
+}
+
+/// Returns the bounding box of the curve restricted to `range` without tessellation.
+///
+/// The range is bisected until, for each piece `(t0, t1)`, the deviation of the curve from the
+/// chord, bounded by `(t1 - t0)^2 / 8 * max |der2|`, is at most `tol`. The box of the chords
+/// inflated by the bounds is returned, so it contains the whole curve, e.g. the extrema between
+/// the samples, not only the sampled points. The excess is at most `tol` in each direction.
+///
+/// # Remarks
+///
+/// The maximum of `|der2|` on a piece is estimated by twice the one at the ends and the middle.
+/// Hence, the guarantee requires that the curve does not oscillate faster than the division of
+/// `range` into 16 pieces. For the curves implementing [`ControlHull`], e.g. B-spline curves,
+/// [`ControlHull::hull_bounding_box`] gives a box with no such assumption.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn bounding_box<C: ParametricCurve3D>(
+    curve: &C,
+    (t0, t1): (f64, f64),
+    tol: f64,
+) -> BoundingBox<Point3> {
+    nonpositive_tolerance!(tol);
+    let n = BOUNDING_BOX_DIVISION;
+    let param = |i: usize| t0 + (t1 - t0) * i as f64 / n as f64;
+    let mut stack = (0..n)
+        .map(|i| ((param(i), param(i + 1)), 0))
+        .collect::<Vec<_>>();
+    let mut bdb = BoundingBox::new();
+    while let Some(((a, b), depth)) = stack.pop() {
+        let mid = (a + b) / 2.0;
+        let acc = [a, mid, b]
+            .into_iter()
+            .map(|t| curve.der2(t).magnitude())
+            .fold(0.0, f64::max);
+        let radius = BOUNDING_BOX_SAFETY * acc * (b - a) * (b - a) / 8.0;
+        if radius > tol && depth < BOUNDING_BOX_MAX_DEPTH {
+            stack.push(((a, mid), depth + 1));
+            stack.push(((mid, b), depth + 1));
+        } else {
+            let r = Vector3::from_value(radius);
+            let (p, q) = (curve.subs(a), curve.subs(b));
+            bdb += [p - r, p + r, q - r, q + r]
+                .iter()
+                .collect::<BoundingBox<_>>();
+        }
     }
+    bdb
 }
@@ -0,0 +1,607 @@
+use super::*;
+use thiserror::Error;
+use truck_base::bounding_box::BoundingBox;
+
+/// The maximum number of the iterations of the Newton's methods in this module.
+const NEWTON_TRIALS: usize = 100;
+
+/// If the sine of the angle between the normals of two surfaces is less than this value,
+/// the surfaces are regarded as tangent.
+const TANGENT_SINE: f64 = 1.0e-3;
+
+/// The maximum number of the bisections searching the boundary of the parameter rectangles.
+const BOUNDARY_BISECTION_TRIALS: usize = 50;
+
+/// The maximum number of the points of a traced intersection polyline.
+const MAX_TRACED_POINTS: usize = 1 << 16;
+
+/// An intersection of a curve and a surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveSurfaceHit {
+    /// An isolated intersection point.
+    Point {
+        /// The parameter of the curve.
+        t: f64,
+        /// The parameter of the surface.
+        uv: (f64, f64),
+        /// The intersection point.
+        point: Point3,
+    },
+    /// The curve lies on the surface in the range of the parameter of the curve.
+    OnSurface {
+        /// The range of the parameter of the curve.
+        t_range: (f64, f64),
+    },
+}
+
+impl CurveSurfaceHit {
+    #[inline(always)]
+    fn front_parameter(&self) -> f64 {
+        match self {
+            CurveSurfaceHit::Point { t, .. } => *t,
+            CurveSurfaceHit::OnSurface { t_range } => t_range.0,
+        }
+    }
+}
+
+/// Searches the intersections of a curve and a surface.
+///
+/// Returns the isolated intersection points and the parts of the curve lying on the surface,
+/// sorted by the parameter of the curve.
+/// # Details
+/// - The polyline given by [`curve::parameter_division`] is tested against the coarse mesh
+/// given by [`surface::parameter_division`] by the bounding boxes widened by `tol`.
+/// - Each candidate is polished by the Gauss-Newton method on `curve(t) - surface(u, v) = 0`,
+/// whose steps are clamped into the ranges of the non-periodic directions.
+/// - The intersection points whose parameters `t` are within `tol` of each other are merged.
+/// - A segment of the polyline lies on the surface if its ends and two inner points are within
+/// `tol` from the surface. The consecutive such segments are returned as one
+/// [`CurveSurfaceHit::OnSurface`], and the intersection points in them are omitted.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn curve_surface<C, S>(
+    curve: &C,
+    crange: (f64, f64),
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> Vec<CurveSurfaceHit>
+where
+    C: ParametricCurve3D,
+    S: ParametricSurface3D,
+{
+    nonpositive_tolerance!(tol);
+    let (params, pts) = curve::parameter_division(curve, crange, tol);
+    let (udiv, vdiv) = surface::parameter_division(surface, (urange, vrange), tol);
+    let grid = udiv
+        .iter()
+        .flat_map(|u| vdiv.iter().map(move |v| (*u, *v)))
+        .map(|(u, v)| ((u, v), surface.subs(u, v)))
+        .collect::<Vec<_>>();
+    let cells = surface_cells(surface, (&udiv, &vdiv));
+    let rect = rectangle(surface, (urange, vrange));
+
+    let mut candidates = pts
+        .windows(2)
+        .zip(params.windows(2))
+        .flat_map(|(p, t)| {
+            let bdb = p.iter().collect::<BoundingBox<Point3>>();
+            cells
+                .iter()
+                .filter(move |(cell, _)| overlap(&bdb, cell, tol))
+                .map(move |(_, (u, v))| {
+                    // the nearest point on the segment to the center of the cell
+                    let dir = p[1] - p[0];
+                    let x = match dir.so_small() {
+                        true => 0.5,
+                        false => (surface.subs(*u, *v) - p[0]).dot(dir) / dir.magnitude2(),
+                    };
+                    let x = f64::clamp(x, 0.0, 1.0);
+                    (t[0] * (1.0 - x) + t[1] * x, *u, *v)
+                })
+        })
+        .filter_map(|hint| polish_curve_surface(curve, crange, surface, rect, hint, tol))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut points = Vec::<(f64, (f64, f64), f64)>::new();
+    for hit in candidates {
+        match points.last_mut() {
+            Some(last) if hit.0 - last.0 < tol => {
+                if hit.2 < last.2 {
+                    *last = hit;
+                }
+            }
+            _ => points.push(hit),
+        }
+    }
+
+    let on_surface = |t: f64| {
+        let pt = curve.subs(t);
+        let hint = grid
+            .iter()
+            .min_by(|(_, p), (_, q)| p.distance2(pt).total_cmp(&q.distance2(pt)))
+            .map(|(uv, _)| *uv)
+            .unwrap_or((urange.0, vrange.0));
+        surface::search_nearest_parameter(surface, pt, hint, NEWTON_TRIALS)
+            .is_some_and(|(u, v)| surface.subs(u, v).distance(pt) < tol)
+    };
+    let on_pts = params.iter().map(|t| on_surface(*t)).collect::<Vec<_>>();
+    let mut hits = Vec::<CurveSurfaceHit>::new();
+    params.windows(2).enumerate().for_each(|(i, t)| {
+        let is_on_surface = on_pts[i]
+            && on_pts[i + 1]
+            && [1.0 / 3.0, 2.0 / 3.0]
+                .into_iter()
+                .all(|x| on_surface(t[0] * (1.0 - x) + t[1] * x));
+        if !is_on_surface {
+            return;
+        }
+        match hits.last_mut() {
+            Some(CurveSurfaceHit::OnSurface { t_range }) if t_range.1 == t[0] => t_range.1 = t[1],
+            _ => hits.push(CurveSurfaceHit::OnSurface {
+                t_range: (t[0], t[1]),
+            }),
+        }
+    });
+    let is_isolated = |t: f64| {
+        hits.iter().all(|hit| match hit {
+            CurveSurfaceHit::OnSurface { t_range } => {
+                let (t0, t1) = (
+                    f64::min(t_range.0, t_range.1),
+                    f64::max(t_range.0, t_range.1),
+                );
+                t < t0 - tol || t1 + tol < t
+            }
+            _ => true,
+        })
+    };
+    let points = points
+        .into_iter()
+        .filter(|(t, _, _)| is_isolated(*t))
+        .map(|(t, uv, _)| CurveSurfaceHit::Point {
+            t,
+            uv,
+            point: curve.subs(t),
+        })
+        .collect::<Vec<_>>();
+    hits.extend(points);
+    hits.sort_by(|a, b| a.front_parameter().total_cmp(&b.front_parameter()));
+    hits
+}
+
+/// Returns the bounding boxes of the corners and the center parameters of the cells of the grid.
+fn surface_cells<S: ParametricSurface3D>(
+    surface: &S,
+    (udiv, vdiv): (&[f64], &[f64]),
+) -> Vec<(BoundingBox<Point3>, (f64, f64))> {
+    udiv.windows(2)
+        .flat_map(|u| vdiv.windows(2).map(move |v| (u, v)))
+        .map(|(u, v)| {
+            let bdb = [(0, 0), (0, 1), (1, 0), (1, 1)]
+                .into_iter()
+                .map(|(i, j)| surface.subs(u[i], v[j]))
+                .collect::<BoundingBox<Point3>>();
+            (bdb, ((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0))
+        })
+        .collect()
+}
+
+/// Returns the ranges of the non-periodic directions in the rectangle.
+fn rectangle<S: ParametricSurface>(
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+) -> surface::ParameterRectangle {
+    let sort = |(x, y): (f64, f64)| (f64::min(x, y), f64::max(x, y));
+    (
+        surface.u_period().is_none().then(|| sort(urange)),
+        surface.v_period().is_none().then(|| sort(vrange)),
+    )
+}
+
+/// Whether the bounding boxes widened by `tol` overlap or not.
+fn overlap(bdb0: &BoundingBox<Point3>, bdb1: &BoundingBox<Point3>, tol: f64) -> bool {
+    let (min0, max0, min1, max1) = (bdb0.min(), bdb0.max(), bdb1.min(), bdb1.max());
+    (0..3).all(|i| min0[i] - tol <= max1[i] && min1[i] - tol <= max0[i])
+}
+
+/// Polishes the intersection of a curve and a surface by the Gauss-Newton method.
+///
+/// Returns the parameters and the distance between the points on the curve and the surface.
+fn polish_curve_surface<C, S>(
+    curve: &C,
+    crange: (f64, f64),
+    surface: &S,
+    rect: surface::ParameterRectangle,
+    (mut t, mut u, mut v): (f64, f64, f64),
+    tol: f64,
+) -> Option<(f64, (f64, f64), f64)>
+where
+    C: ParametricCurve3D,
+    S: ParametricSurface3D,
+{
+    let (t0, t1) = (f64::min(crange.0, crange.1), f64::max(crange.0, crange.1));
+    for _ in 0..NEWTON_TRIALS {
+        let r = curve.subs(t) - surface.subs(u, v);
+        if r.magnitude2() < TOLERANCE2 * TOLERANCE2 {
+            break;
+        }
+        let (der, uder, vder) = (curve.der(t), surface.uder(u, v), surface.vder(u, v));
+        let jacobian = Matrix3::from_cols(der, -uder, -vder);
+        let mat = jacobian.transpose() * jacobian;
+        // slightly damped so that the tangential intersection can be solved
+        let damping = TOLERANCE2 * mat.trace();
+        let delta = (mat + Matrix3::identity() * damping).invert()? * (jacobian.transpose() * r);
+        t = f64::clamp(t - delta.x, t0, t1);
+        (u, v) = surface::clamp_parameter((u - delta.y, v - delta.z), rect);
+    }
+    let dist = curve.subs(t).distance(surface.subs(u, v));
+    match dist < tol {
+        true => Some((t, (u, v), dist)),
+        false => None,
+    }
+}
+
+/// A polyline tracing a component of the intersection of two surfaces.
+///
+/// The polyline is closed if and only if the first point is the same as the last point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntersectionPolyline {
+    /// The points on the intersection.
+    pub points: Vec<Point3>,
+    /// The parameters of the points on the first surface.
+    pub params0: Vec<Point2>,
+    /// The parameters of the points on the second surface.
+    pub params1: Vec<Point2>,
+}
+
+impl IntersectionPolyline {
+    /// Returns whether the polyline is closed or not.
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 2 && self.points[0] == self.points[self.points.len() - 1]
+    }
+
+    fn distance(&self, point: Point3) -> f64 {
+        match self.points.len() {
+            0 => f64::INFINITY,
+            1 => self.points[0].distance(point),
+            _ => self
+                .points
+                .windows(2)
+                .map(|p| {
+                    let dir = p[1] - p[0];
+                    let x = match dir.so_small() {
+                        true => 0.0,
+                        false => f64::clamp((point - p[0]).dot(dir) / dir.magnitude2(), 0.0, 1.0),
+                    };
+                    (p[0] + dir * x).distance(point)
+                })
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+/// Error for the intersection of surfaces.
+#[derive(Clone, Copy, PartialEq, Debug, Error)]
+pub enum SurfaceIntersectionError {
+    /// The surfaces are tangent at the point which is not on the traced intersection curves.
+    #[error("The surfaces are tangent at {0:?}.")]
+    TangentialContact(Point3),
+}
+
+/// Traces the intersection of two surfaces.
+///
+/// Returns the polylines tracing the components of the intersection of the parts of the
+/// surfaces on the parameter rectangles `rect0` and `rect1`.
+/// # Details
+/// - The seed points are searched from the pairs of the cells of the coarse meshes given by
+/// [`surface::parameter_division`] whose bounding boxes are within `2 * tol`.
+/// - From each seed point not on the traced polylines, the intersection is traced in the
+/// both directions along `n0 x n1`, where `n0` and `n1` are the normals of the surfaces.
+/// Each point is projected onto both surfaces by Newton's method on the plane perpendicular
+/// to the direction. The step is halved if the estimated sagitta is larger than `tol`.
+/// - A trace ends if it returns to the first point, it exits from the parameter rectangles,
+/// it meets another traced polyline, or the surfaces are tangent.
+///
+/// # Errors
+///
+/// Returns [`SurfaceIntersectionError::TangentialContact`] if the surfaces are tangent at a
+/// seed point which is not on the traced polylines.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn surface_surface<S0, S1>(
+    surface0: &S0,
+    rect0: ((f64, f64), (f64, f64)),
+    surface1: &S1,
+    rect1: ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> Result<Vec<IntersectionPolyline>, SurfaceIntersectionError>
+where
+    S0: ParametricSurface3D,
+    S1: ParametricSurface3D,
+{
+    nonpositive_tolerance!(tol);
+    let (udiv0, vdiv0) = surface::parameter_division(surface0, rect0, tol);
+    let (udiv1, vdiv1) = surface::parameter_division(surface1, rect1, tol);
+    let cells0 = surface_cells(surface0, (&udiv0, &vdiv0));
+    let cells1 = surface_cells(surface1, (&udiv1, &vdiv1));
+    let step = f64::sqrt(8.0 * tol);
+    let tracer = Tracer {
+        surface0,
+        surface1,
+        rect0: rectangle(surface0, rect0),
+        rect1: rectangle(surface1, rect1),
+        tol,
+        initial_step: step,
+        max_step: 16.0 * step,
+    };
+
+    let seeds = cells0.iter().flat_map(|(bdb0, uv0)| {
+        cells1
+            .iter()
+            .filter(move |(bdb1, _)| overlap(bdb0, bdb1, 2.0 * tol))
+            .map(move |(_, uv1)| (*uv0, *uv1))
+    });
+    let mut polylines = Vec::<IntersectionPolyline>::new();
+    let mut tangent_points = Vec::<Point3>::new();
+    for hint in seeds {
+        let Some(seed) = tracer.seed(hint) else {
+            continue;
+        };
+        let is_traced = |point: Point3| {
+            polylines
+                .iter()
+                .any(|poly| poly.distance(point) < 2.0 * tol)
+        };
+        if is_traced(seed.point) || tangent_points.iter().any(|p| p.distance(seed.point) < tol) {
+            continue;
+        }
+        match tracer.trace(seed, &polylines) {
+            Some(poly) => polylines.push(poly),
+            None => tangent_points.push(seed.point),
+        }
+    }
+    let contact = tangent_points
+        .into_iter()
+        .find(|pt| polylines.iter().all(|poly| poly.distance(*pt) >= 2.0 * tol));
+    match contact {
+        Some(pt) => Err(SurfaceIntersectionError::TangentialContact(pt)),
+        None => Ok(polylines),
+    }
+}
+
+/// A point on the intersection of two surfaces.
+#[derive(Clone, Copy, Debug)]
+struct IntersectionPoint {
+    point: Point3,
+    uv0: Point2,
+    uv1: Point2,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Tracer<'a, S0, S1> {
+    surface0: &'a S0,
+    surface1: &'a S1,
+    rect0: surface::ParameterRectangle,
+    rect1: surface::ParameterRectangle,
+    tol: f64,
+    initial_step: f64,
+    max_step: f64,
+}
+
+impl<'a, S0: ParametricSurface3D, S1: ParametricSurface3D> Tracer<'a, S0, S1> {
+    /// Returns the unit tangent vector of the intersection, `None` if the surfaces are tangent.
+    fn direction(&self, p: &IntersectionPoint) -> Option<Vector3> {
+        let n0 = self.surface0.normal(p.uv0.x, p.uv0.y);
+        let n1 = self.surface1.normal(p.uv1.x, p.uv1.y);
+        let dir = n0.cross(n1);
+        match dir.magnitude() < TANGENT_SINE || !dir.magnitude().is_finite() {
+            true => None,
+            false => Some(dir.normalize()),
+        }
+    }
+
+    fn is_inside(&self, p: &IntersectionPoint) -> bool {
+        let inside = |t: f64, range: Option<(f64, f64)>| match range {
+            Some((t0, t1)) => t0 - TOLERANCE <= t && t <= t1 + TOLERANCE,
+            None => true,
+        };
+        inside(p.uv0.x, self.rect0.0)
+            && inside(p.uv0.y, self.rect0.1)
+            && inside(p.uv1.x, self.rect1.0)
+            && inside(p.uv1.y, self.rect1.1)
+    }
+
+    /// Searches a point on the intersection by the minimum-norm Newton's method.
+    fn seed(&self, ((u0, v0), (u1, v1)): ((f64, f64), (f64, f64))) -> Option<IntersectionPoint> {
+        let (mut uv0, mut uv1) = (Point2::new(u0, v0), Point2::new(u1, v1));
+        for _ in 0..NEWTON_TRIALS {
+            let point = self.surface0.subs(uv0.x, uv0.y);
+            let r = point - self.surface1.subs(uv1.x, uv1.y);
+            if r.magnitude2() < TOLERANCE2 * TOLERANCE2 {
+                return Some(IntersectionPoint { point, uv0, uv1 });
+            }
+            let ders = [
+                self.surface0.uder(uv0.x, uv0.y),
+                self.surface0.vder(uv0.x, uv0.y),
+                -self.surface1.uder(uv1.x, uv1.y),
+                -self.surface1.vder(uv1.x, uv1.y),
+            ];
+            let mat = ders.iter().fold(Matrix3::zero(), |mat, a| {
+                mat + Matrix3::from_cols(a * a.x, a * a.y, a * a.z)
+            });
+            // slightly damped so that the tangential contact can be solved
+            let damping = TOLERANCE2 * mat.trace();
+            let w = (mat + Matrix3::identity() * damping).invert()? * r;
+            uv0 -= Vector2::new(ders[0].dot(w), ders[1].dot(w));
+            uv1 -= Vector2::new(ders[2].dot(w), ders[3].dot(w));
+            uv0 = surface::clamp_parameter(uv0.into(), self.rect0).into();
+            uv1 = surface::clamp_parameter(uv1.into(), self.rect1).into();
+        }
+        let point = self.surface0.subs(uv0.x, uv0.y);
+        match point.distance(self.surface1.subs(uv1.x, uv1.y)) < TOLERANCE {
+            true => Some(IntersectionPoint { point, uv0, uv1 }),
+            false => None,
+        }
+    }
+
+    /// Projects `target` onto the intersection on the plane perpendicular to `dir`.
+    fn correct(
+        &self,
+        start: &IntersectionPoint,
+        target: Point3,
+        dir: Vector3,
+    ) -> Option<IntersectionPoint> {
+        let (mut uv0, mut uv1) = (start.uv0, start.uv1);
+        for _ in 0..NEWTON_TRIALS {
+            let point = self.surface0.subs(uv0.x, uv0.y);
+            let r = point - self.surface1.subs(uv1.x, uv1.y);
+            let g = (point - target).dot(dir);
+            if r.magnitude2() < TOLERANCE2 && g.so_small() {
+                return Some(IntersectionPoint { point, uv0, uv1 });
+            }
+            let (a, b) = (
+                self.surface0.uder(uv0.x, uv0.y),
+                self.surface0.vder(uv0.x, uv0.y),
+            );
+            let (c, d) = (
+                self.surface1.uder(uv1.x, uv1.y),
+                self.surface1.vder(uv1.x, uv1.y),
+            );
+            let mat = Matrix4::from_cols(
+                a.extend(a.dot(dir)),
+                b.extend(b.dot(dir)),
+                (-c).extend(0.0),
+                (-d).extend(0.0),
+            );
+            let delta = mat.invert()? * r.extend(g);
+            uv0 -= Vector2::new(delta.x, delta.y);
+            uv1 -= Vector2::new(delta.z, delta.w);
+        }
+        None
+    }
+
+    /// Traces the intersection from `seed` in both directions.
+    ///
+    /// Returns `None` if the surfaces are tangent at `seed`.
+    fn trace(
+        &self,
+        seed: IntersectionPoint,
+        polylines: &[IntersectionPolyline],
+    ) -> Option<IntersectionPolyline> {
+        let dir = self.direction(&seed)?;
+        let (mut forward, closed) = self.march(seed, dir, polylines);
+        if !closed {
+            let (backward, _) = self.march(seed, -dir, polylines);
+            forward = backward
+                .into_iter()
+                .rev()
+                .chain(forward.into_iter().skip(1))
+                .collect();
+        }
+        Some(IntersectionPolyline {
+            points: forward.iter().map(|p| p.point).collect(),
+            params0: forward.iter().map(|p| p.uv0).collect(),
+            params1: forward.iter().map(|p| p.uv1).collect(),
+        })
+    }
+
+    /// Marches on the intersection from `start` toward `dir`.
+    ///
+    /// Returns the traced points and whether the trace returned to `start` or not.
+    fn march(
+        &self,
+        start: IntersectionPoint,
+        mut dir: Vector3,
+        polylines: &[IntersectionPolyline],
+    ) -> (Vec<IntersectionPoint>, bool) {
+        let min_step = TOLERANCE;
+        let mut step = self.initial_step;
+        let mut res = vec![start];
+        while res.len() < MAX_TRACED_POINTS {
+            let current = res[res.len() - 1];
+            let to_start = start.point - current.point;
+            if res.len() > 2 && to_start.magnitude() < step && to_start.dot(dir) > 0.0 {
+                let end = self.correct(&current, start.point, dir).unwrap_or(start);
+                res.push(IntersectionPoint {
+                    point: start.point,
+                    ..end
+                });
+                return (res, true);
+            }
+            let next = match self.correct(&current, current.point + dir * step, dir) {
+                Some(next) => next,
+                None => {
+                    step /= 2.0;
+                    match step < min_step {
+                        true => break,
+                        false => continue,
+                    }
+                }
+            };
+            let Some(next_dir) = self.direction(&next) else {
+                if self.is_inside(&next) {
+                    res.push(next);
+                }
+                break;
+            };
+            let next_dir = match next_dir.dot(dir) < 0.0 {
+                true => -next_dir,
+                false => next_dir,
+            };
+            let sagitta = step * (next_dir - dir).magnitude() / 8.0;
+            if sagitta > self.tol {
+                step /= 2.0;
+                match step < min_step {
+                    true => break,
+                    false => continue,
+                }
+            }
+            if !self.is_inside(&next) {
+                res.extend(self.boundary_point(&current, dir, step));
+                break;
+            }
+            res.push(next);
+            if polylines
+                .iter()
+                .any(|poly| poly.distance(next.point) < self.tol)
+            {
+                break;
+            }
+            dir = next_dir;
+            if sagitta < self.tol / 4.0 {
+                step = f64::min(step * 2.0, self.max_step);
+            }
+        }
+        (res, false)
+    }
+
+    /// Searches the last point in the parameter rectangles from `current` toward `dir`.
+    fn boundary_point(
+        &self,
+        current: &IntersectionPoint,
+        dir: Vector3,
+        step: f64,
+    ) -> Option<IntersectionPoint> {
+        let (mut lo, mut hi) = (0.0, step);
+        let mut res = None;
+        for _ in 0..BOUNDARY_BISECTION_TRIALS {
+            if hi - lo < TOLERANCE {
+                break;
+            }
+            let mid = (lo + hi) / 2.0;
+            match self.correct(current, current.point + dir * mid, dir) {
+                Some(p) if self.is_inside(&p) => {
+                    lo = mid;
+                    res = Some(p);
+                }
+                _ => hi = mid,
+            }
+        }
+        res
+    }
+}
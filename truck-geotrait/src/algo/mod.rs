@@ -1,7 +1,7 @@
 #![allow(clippy::many_single_char_names)]
 
 use crate::traits::*;
-use truck_base::{cgmath64::*, hash::HashGen, tolerance::*};
+use truck_base::{bounding_box::BoundingBox, cgmath64::*, hash::HashGen, tolerance::*};
 
 /// A structure that stores logs for debugging.
 #[doc(hidden)]
@@ -27,7 +27,16 @@ impl<T: std::fmt::Debug> NewtonLog<T> {
     }
 }
 
+/// The number of the initial pieces of each parameter range in the bounding box computation.
+const BOUNDING_BOX_DIVISION: usize = 16;
+/// The ratio of the bound of the 2nd-order derivation to its maximum on the samples.
+const BOUNDING_BOX_SAFETY: f64 = 2.0;
+/// The maximum depth of the bisections in the bounding box computation.
+const BOUNDING_BOX_MAX_DEPTH: usize = 24;
+
 /// curve algorithms
 pub mod curve;
+/// intersection algorithms
+pub mod intersection;
 /// surface algorithms
 pub mod surface;
@@ -31,19 +31,161 @@ where
     res
 }
 
+/// Options of the damped Newton's method in searching parameters of surfaces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NewtonOptions {
+    /// If the condition number of the 2x2 matrix of a Newton step is larger than this value,
+    /// the matrix is damped by Levenberg-Marquardt so that the condition number is this value.
+    pub max_condition: f64,
+    /// The upper bound of the Levenberg-Marquardt damping factor.
+    pub max_damping: f64,
+    /// The maximum number of halving a step in the backtracking line search.
+    pub line_search_trials: usize,
+}
+
+impl Default for NewtonOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            max_condition: 1.0e8,
+            max_damping: 1.0e8,
+            line_search_trials: 20,
+        }
+    }
+}
+
+pub(super) type ParameterRectangle = (Option<(f64, f64)>, Option<(f64, f64)>);
+
+/// Returns the ranges of the non-periodic bounded directions.
+pub(super) fn parameter_rectangle<S: ParametricSurface>(surface: &S) -> ParameterRectangle {
+    let sort = |(x, y): (f64, f64)| (f64::min(x, y), f64::max(x, y));
+    let (urange, vrange) = surface.try_range_tuple();
+    (
+        urange.filter(|_| surface.u_period().is_none()).map(sort),
+        vrange.filter(|_| surface.v_period().is_none()).map(sort),
+    )
+}
+
+pub(super) fn clamp_parameter(
+    (u, v): (f64, f64),
+    (urange, vrange): ParameterRectangle,
+) -> (f64, f64) {
+    let clamp = |t: f64, range: Option<(f64, f64)>| match range {
+        Some((t0, t1)) => f64::clamp(t, t0, t1),
+        None => t,
+    };
+    (clamp(u, urange), clamp(v, vrange))
+}
+
+/// Solves `matrix * x = vector` for the symmetric `matrix` with the Levenberg-Marquardt damping.
+fn damped_solve(matrix: Matrix2, vector: Vector2, options: &NewtonOptions) -> Option<Vector2> {
+    let (a, b, c) = (matrix[0][0], matrix[1][1], matrix[0][1]);
+    let half_trace = (a + b) / 2.0;
+    let disc = f64::sqrt((a - b) * (a - b) / 4.0 + c * c);
+    let (max, min) = (half_trace + disc, half_trace - disc);
+    let kappa = options.max_condition;
+    let lambda = (f64::abs(max) - kappa * min) / (kappa - 1.0);
+    let lambda = f64::clamp(lambda, 0.0, options.max_damping);
+    let res = (matrix + Matrix2::identity() * lambda).invert()? * vector;
+    match res.x.is_finite() && res.y.is_finite() {
+        true => Some(res),
+        false => None,
+    }
+}
+
+/// The minimum of `init` and the squared magnitudes of the non-degenerate derivatives.
+#[inline(always)]
+fn nondegenerate_magnitude2<V: InnerSpace<Scalar = f64>>(init: f64, ders: [V; 2]) -> f64 {
+    ders.into_iter()
+        .map(|der| der.magnitude2())
+        .filter(|mag2| !mag2.so_small())
+        .fold(init, f64::min)
+}
+
 /// Searches the nearest parameter by Newton's method.
+///
+/// This is [`search_nearest_parameter_with_options`] with the default options.
+#[inline(always)]
 pub fn search_nearest_parameter<S>(
     surface: &S,
     point: S::Point,
-    mut hint: (f64, f64),
+    hint: (f64, f64),
+    trials: usize,
+) -> Option<(f64, f64)>
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    search_nearest_parameter_with_options(surface, point, hint, trials, &Default::default())
+}
+
+/// Searches the nearest parameter by the damped Newton's method.
+///
+/// This is [`try_search_nearest_parameter_with_options`] without the reason of the failure.
+#[inline(always)]
+pub fn search_nearest_parameter_with_options<S>(
+    surface: &S,
+    point: S::Point,
+    hint: (f64, f64),
     trials: usize,
+    options: &NewtonOptions,
 ) -> Option<(f64, f64)>
 where
     S: ParametricSurface,
     S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
     S::Vector: InnerSpace<Scalar = f64> + Tolerance,
 {
+    try_search_nearest_parameter_with_options(surface, point, hint, trials, options).ok()
+}
+
+/// Searches the nearest parameter by Newton's method.
+///
+/// This is [`try_search_nearest_parameter_with_options`] with the default options.
+#[inline(always)]
+pub fn try_search_nearest_parameter<S>(
+    surface: &S,
+    point: S::Point,
+    hint: (f64, f64),
+    trials: usize,
+) -> Result<(f64, f64), SearchError<(f64, f64)>>
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    try_search_nearest_parameter_with_options(surface, point, hint, trials, &Default::default())
+}
+
+/// Searches the nearest parameter by the damped Newton's method.
+///
+/// - If the Hessian of the squared distance is ill-conditioned, e.g. at a pole of a sphere,
+/// it is damped according to `options`.
+/// - Each step is shortened by the backtracking line search until the distance decreases.
+/// - The parameters are clamped into the parameter range of the non-periodic directions, and
+/// the minimum at the boundary of the range is also accepted.
+///
+/// # Failures
+/// - [`SearchError::VanishingDerivative`] if the damped Hessian is still singular.
+/// - [`SearchError::NotConverged`] if Newton's method does not converge within `trials`, or the
+/// line search fails.
+pub fn try_search_nearest_parameter_with_options<S>(
+    surface: &S,
+    point: S::Point,
+    hint: (f64, f64),
+    trials: usize,
+    options: &NewtonOptions,
+) -> Result<(f64, f64), SearchError<(f64, f64)>>
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64, Diff = S::Vector>,
+    S::Vector: InnerSpace<Scalar = f64> + Tolerance,
+{
+    let rect = parameter_rectangle(surface);
+    let mut hint = clamp_parameter(hint, rect);
+    let dist2 = |(u, v): (f64, f64)| (surface.subs(u, v) - point).magnitude2();
     let mut log = NewtonLog::default();
+    let mut residual = f64::INFINITY;
     for _ in 0..=trials {
         log.push(hint);
         let (u0, v0) = hint;
@@ -53,48 +195,153 @@ where
         let uud = surface.uuder(u0, v0);
         let uvd = surface.uvder(u0, v0);
         let vvd = surface.vvder(u0, v0);
-        let f = Vector2::new(ud.dot(s - point), vd.dot(s - point));
+        let mut f = Vector2::new(ud.dot(s - point), vd.dot(s - point));
         let a = uud.dot(s - point) + ud.dot(ud);
         let c = uvd.dot(s - point) + ud.dot(vd);
         let b = vvd.dot(s - point) + vd.dot(vd);
         let fprime = Matrix2::new(a, c, c, b);
-        let dermag2 = f64::min(1.0, ud.magnitude2());
-        let dermag2 = f64::min(dermag2, vd.magnitude2());
-        if f.magnitude2() < TOLERANCE2 * dermag2 || fprime.determinant().so_small() {
-            return Some(hint);
-        } else {
-            hint = (Vector2::from(hint) - fprime.invert()? * f).into();
+        // the gradient toward the outside of the range is ignored at the boundary
+        let on_boundary = |t: f64, range: Option<(f64, f64)>, df: f64| match range {
+            Some((t0, t1)) => (t == t0 && df >= 0.0) || (t == t1 && df <= 0.0),
+            None => false,
+        };
+        if on_boundary(u0, rect.0, f.x) {
+            f.x = 0.0;
+        }
+        if on_boundary(v0, rect.1, f.y) {
+            f.y = 0.0;
+        }
+        let dermag2 = nondegenerate_magnitude2(1.0, [ud, vd]);
+        residual = f.magnitude();
+        if f.magnitude2() < TOLERANCE2 * dermag2 {
+            return Ok(hint);
+        }
+        let Some(step) = damped_solve(fprime, f, options) else {
+            return Err(SearchError::VanishingDerivative { at: hint });
+        };
+        let mut step = -step;
+        let current = dist2(hint);
+        let next = (0..=options.line_search_trials).find_map(|_| {
+            let next = clamp_parameter((u0 + step.x, v0 + step.y), rect);
+            step /= 2.0;
+            match dist2(next) <= current {
+                true => Some(next),
+                false => None,
+            }
+        });
+        match next {
+            Some(next) => hint = next,
+            None => break,
         }
     }
     log.print_error();
-    None
+    Err(SearchError::NotConverged {
+        last: hint,
+        residual,
+    })
 }
 
 /// Searches the parameter by Newton's method.
+///
+/// This is [`search_parameter2d_with_options`] with the default options.
 #[inline(always)]
 pub fn search_parameter2d<S: ParametricSurface<Point = Point2, Vector = Vector2>>(
     surface: &S,
     point: Point2,
-    mut hint: (f64, f64),
+    hint: (f64, f64),
+    trials: usize,
+) -> Option<(f64, f64)> {
+    search_parameter2d_with_options(surface, point, hint, trials, &Default::default())
+}
+
+/// Searches the parameter by Newton's method.
+///
+/// This is [`try_search_parameter2d_with_options`] with the default options.
+#[inline(always)]
+pub fn try_search_parameter2d<S: ParametricSurface<Point = Point2, Vector = Vector2>>(
+    surface: &S,
+    point: Point2,
+    hint: (f64, f64),
     trials: usize,
+) -> Result<(f64, f64), SearchError<(f64, f64)>> {
+    try_search_parameter2d_with_options(surface, point, hint, trials, &Default::default())
+}
+
+/// Searches the parameter by the damped Gauss-Newton method.
+///
+/// This is [`try_search_parameter2d_with_options`] without the reason of the failure.
+#[inline(always)]
+pub fn search_parameter2d_with_options<S: ParametricSurface<Point = Point2, Vector = Vector2>>(
+    surface: &S,
+    point: Point2,
+    hint: (f64, f64),
+    trials: usize,
+    options: &NewtonOptions,
 ) -> Option<(f64, f64)> {
+    try_search_parameter2d_with_options(surface, point, hint, trials, options).ok()
+}
+
+/// Searches the parameter by the damped Gauss-Newton method.
+///
+/// - If the normal equation `J^T J dx = -J^T r` is ill-conditioned, e.g. at a pole of a sphere
+/// or an apex of a cone, it is damped according to `options`.
+/// - Each step is shortened by the backtracking line search until the residual decreases.
+/// - The parameters are clamped into the parameter range of the non-periodic directions.
+///
+/// # Failures
+/// - [`SearchError::VanishingDerivative`] if the damped normal matrix is still singular.
+/// - [`SearchError::NotConverged`] if the method does not converge within `trials`, or the line
+/// search fails, e.g. the point is out of the image of the surface.
+pub fn try_search_parameter2d_with_options<
+    S: ParametricSurface<Point = Point2, Vector = Vector2>,
+>(
+    surface: &S,
+    point: Point2,
+    hint: (f64, f64),
+    trials: usize,
+    options: &NewtonOptions,
+) -> Result<(f64, f64), SearchError<(f64, f64)>> {
+    let rect = parameter_rectangle(surface);
+    let mut hint = clamp_parameter(hint, rect);
     let mut log = NewtonLog::default();
+    let mut residual = f64::INFINITY;
     for _ in 0..=trials {
         log.push(hint);
         let (u0, v0) = hint;
         let pt = surface.subs(u0, v0);
         let uder = surface.uder(u0, v0);
         let vder = surface.vder(u0, v0);
-        let dermag2 = f64::min(0.05, uder.magnitude2());
-        let dermag2 = f64::min(dermag2, vder.magnitude2());
-        if pt.distance2(point) < TOLERANCE2 * dermag2 {
-            return Some(hint);
+        let dermag2 = nondegenerate_magnitude2(0.05, [uder, vder]);
+        let current = pt.distance2(point);
+        residual = f64::sqrt(current);
+        if current < TOLERANCE2 * dermag2 {
+            return Ok(hint);
+        }
+        let jacobian = Matrix2::from_cols(uder, vder);
+        let normal_matrix = jacobian.transpose() * jacobian;
+        let vector = jacobian.transpose() * (pt - point);
+        let Some(step) = damped_solve(normal_matrix, vector, options) else {
+            return Err(SearchError::VanishingDerivative { at: hint });
+        };
+        let mut step = -step;
+        let next = (0..=options.line_search_trials).find_map(|_| {
+            let (u, v) = clamp_parameter((u0 + step.x, v0 + step.y), rect);
+            step /= 2.0;
+            match surface.subs(u, v).distance2(point) < current {
+                true => Some((u, v)),
+                false => None,
+            }
+        });
+        match next {
+            Some(next) => hint = next,
+            None => break,
         }
-        let inv = Matrix2::from_cols(uder, vder).invert()?;
-        hint = (Vector2::from(hint) - inv * (pt - point)).into();
     }
     log.print_error();
-    None
+    Err(SearchError::NotConverged {
+        last: hint,
+        residual,
+    })
 }
 
 #[derive(Clone, Debug)]
@@ -107,8 +354,10 @@ struct ProjectedSurface<'a, S> {
 
 impl<'a, S: ParametricSurface3D> ProjectedSurface<'a, S> {
     fn new(surface: &'a S, (u, v): (f64, f64)) -> Self {
-        let origin = surface.subs(u, v);
-        let normal = surface.normal(u, v);
+        Self::with_normal(surface, surface.subs(u, v), surface.normal(u, v))
+    }
+    /// The projection onto the plane through `origin` perpendicular to `normal`.
+    fn with_normal(surface: &'a S, origin: Point3, normal: Vector3) -> Self {
         let tmp = normal.map(f64::abs);
         let max = match (tmp[0] < tmp[1], tmp[1] < tmp[2], tmp[2] < tmp[0]) {
             (false, _, true) => 0,
@@ -155,23 +404,170 @@ impl<'a, S: ParametricSurface3D> ParametricSurface for ProjectedSurface<'a, S> {
     fn uvder(&self, u: f64, v: f64) -> Vector2 { self.vector_proj(self.surface.uvder(u, v)) }
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Vector2 { self.vector_proj(self.surface.vvder(u, v)) }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) { self.surface.parameter_range() }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> { self.surface.u_period() }
+    #[inline(always)]
+    fn v_period(&self) -> Option<f64> { self.surface.v_period() }
 }
 
 /// Searches the parameter by Newton's method.
+///
+/// This is [`search_parameter3d_with_options`] with the default options.
 #[inline(always)]
 pub fn search_parameter3d<S: ParametricSurface3D>(
     surface: &S,
     point: Point3,
-    (u0, v0): (f64, f64),
+    hint: (f64, f64),
+    trials: usize,
+) -> Option<(f64, f64)> {
+    search_parameter3d_with_options(surface, point, hint, trials, &Default::default())
+}
+
+/// Searches the parameter by Newton's method.
+///
+/// This is [`try_search_parameter3d_with_options`] with the default options.
+#[inline(always)]
+pub fn try_search_parameter3d<S: ParametricSurface3D>(
+    surface: &S,
+    point: Point3,
+    hint: (f64, f64),
+    trials: usize,
+) -> Result<(f64, f64), SearchError<(f64, f64)>> {
+    try_search_parameter3d_with_options(surface, point, hint, trials, &Default::default())
+}
+
+/// Searches the parameter by the damped Gauss-Newton method on the tangent plane at `hint`.
+///
+/// This is [`try_search_parameter3d_with_options`] without the reason of the failure.
+#[inline(always)]
+pub fn search_parameter3d_with_options<S: ParametricSurface3D>(
+    surface: &S,
+    point: Point3,
+    hint: (f64, f64),
     trials: usize,
+    options: &NewtonOptions,
 ) -> Option<(f64, f64)> {
+    try_search_parameter3d_with_options(surface, point, hint, trials, options).ok()
+}
+
+/// Searches the parameter by the damped Gauss-Newton method on the tangent plane at `hint`.
+///
+/// See [`try_search_parameter2d_with_options`] for the details.
+///
+/// # Failures
+/// - The failures of [`try_search_parameter2d_with_options`].
+/// - [`SearchError::OffGeometry`] if the parameter of the projection is found but the point is
+/// not on the surface.
+pub fn try_search_parameter3d_with_options<S: ParametricSurface3D>(
+    surface: &S,
+    point: Point3,
+    (u0, v0): (f64, f64),
+    trials: usize,
+    options: &NewtonOptions,
+) -> Result<(f64, f64), SearchError<(f64, f64)>> {
     let proj = ProjectedSurface::new(surface, (u0, v0));
-    search_parameter2d(&proj, proj.point_proj(point), (u0, v0), trials).and_then(|(u, v)| {
-        match surface.subs(u, v).near(&point) {
-            true => Some((u, v)),
+    let pt = proj.point_proj(point);
+    let (u, v) = try_search_parameter2d_with_options(&proj, pt, (u0, v0), trials, options)?;
+    let distance = surface.subs(u, v).distance(point);
+    match surface.subs(u, v).near(&point) {
+        true => Ok((u, v)),
+        false => Err(SearchError::OffGeometry {
+            nearest: (u, v),
+            distance,
+        }),
+    }
+}
+
+/// Options of the projection of a point onto a surface along a direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectionalProjectionOptions {
+    /// If `true`, the hits behind the point, i.e. with negative `s`, are also accepted.
+    pub allow_negative: bool,
+    /// The number of the division of each direction of the parameter range whose nodes are
+    /// used as the initial guesses in addition to the hint. If `0`, only the hint is used.
+    pub presearch_division: usize,
+    /// The options of Newton's method.
+    pub newton: NewtonOptions,
+}
+
+impl Default for DirectionalProjectionOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            allow_negative: false,
+            presearch_division: 8,
+            newton: NewtonOptions::default(),
+        }
+    }
+}
+
+/// Searches the parameter `(u, v)` such that `surface.subs(u, v) == point + dir * s` for some `s`.
+///
+/// This is [`search_parameter_along_direction_with_options`] with the default options.
+#[inline(always)]
+pub fn search_parameter_along_direction<S: ParametricSurface3D>(
+    surface: &S,
+    point: Point3,
+    dir: Vector3,
+    hint: (f64, f64),
+    trials: usize,
+) -> Option<(f64, f64)> {
+    let options = Default::default();
+    search_parameter_along_direction_with_options(surface, point, dir, hint, trials, &options)
+        .map(|(u, v, _)| (u, v))
+}
+
+/// Searches `(u, v, s)` such that `surface.subs(u, v) == point + dir * s`.
+///
+/// - The equation is solved as the search of the parameter of the projection of the surface
+/// onto the plane perpendicular to `dir`, by [`search_parameter2d_with_options`].
+/// - The initial guesses are `hint` and the nodes of the equal division of the parameter range
+/// if it is bounded. Among the solutions, the one with the minimum `|s|` is returned.
+/// - If `options.allow_negative` is `false`, the solutions with `s < 0` are ignored.
+pub fn search_parameter_along_direction_with_options<S: ParametricSurface3D>(
+    surface: &S,
+    point: Point3,
+    dir: Vector3,
+    hint: (f64, f64),
+    trials: usize,
+    options: &DirectionalProjectionOptions,
+) -> Option<(f64, f64, f64)> {
+    if dir.so_small() {
+        return None;
+    }
+    let proj = ProjectedSurface::with_normal(surface, point, dir.normalize());
+    let solve = |hint: (f64, f64)| {
+        let (u, v) = search_parameter2d_with_options(
+            &proj,
+            Point2::origin(),
+            hint,
+            trials,
+            &options.newton,
+        )?;
+        let vec = surface.subs(u, v) - point;
+        let s = vec.dot(dir) / dir.magnitude2();
+        match vec.near(&(dir * s)) && (options.allow_negative || s >= -TOLERANCE) {
+            true => Some((u, v, s)),
             false => None,
         }
-    })
+    };
+    let division = options.presearch_division;
+    let grid = match (surface.try_range_tuple(), division) {
+        (_, 0) | ((None, _), _) | ((_, None), _) => Vec::new(),
+        ((Some((u0, u1)), Some((v0, v1))), _) => (0..=division)
+            .flat_map(|i| (0..=division).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let (p, q) = (i as f64 / division as f64, j as f64 / division as f64);
+                (u0 * (1.0 - p) + u1 * p, v0 * (1.0 - q) + v1 * q)
+            })
+            .collect(),
+    };
+    std::iter::once(hint)
+        .chain(grid)
+        .filter_map(solve)
+        .min_by(|x, y| x.2.abs().total_cmp(&y.2.abs()))
 }
 
 /// Creates the surface division
@@ -195,6 +591,141 @@ where
     (udiv, vdiv)
 }
 
+/// Returns the bounding box of the surface restricted to `(urange, vrange)` without tessellation.
+///
+/// The domain is divided until, for each cell `(u0, u1) x (v0, v1)`, the deviation of the surface
+/// from the bilinear interpolation of the corners, bounded by
+/// `(u1 - u0)^2 / 8 * max |uuder| + (v1 - v0)^2 / 8 * max |vvder|`, is at most `tol`. The box of
+/// the corners inflated by the bounds is returned, so it contains the whole surface, not only the
+/// sampled points. The excess is at most `tol` in each direction.
+///
+/// # Remarks
+///
+/// The maxima of `|uuder|` and `|vvder|` on a cell are estimated by twice the ones at the corners
+/// and the center. Hence, the guarantee requires that the surface does not oscillate faster than
+/// the division of each range into 16 pieces. For the surfaces implementing [`ControlHull`], e.g.
+/// B-spline surfaces, [`ControlHull::hull_bounding_box`] gives a box with no such assumption.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn bounding_box<S: ParametricSurface3D>(
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> BoundingBox<Point3> {
+    nonpositive_tolerance!(tol);
+    let n = BOUNDING_BOX_DIVISION;
+    let piece = |(t0, t1): (f64, f64), i: usize| {
+        let param = |i: usize| t0 + (t1 - t0) * i as f64 / n as f64;
+        (param(i), param(i + 1))
+    };
+    let mut stack = (0..n)
+        .flat_map(|i| (0..n).map(move |j| ((piece(urange, i), piece(vrange, j)), 0)))
+        .collect::<Vec<_>>();
+    let mut bdb = BoundingBox::new();
+    while let Some((((u0, u1), (v0, v1)), depth)) = stack.pop() {
+        let (um, vm) = ((u0 + u1) / 2.0, (v0 + v1) / 2.0);
+        let params = [(u0, v0), (u1, v0), (u0, v1), (u1, v1), (um, vm)];
+        let (uacc, vacc) = params.iter().fold((0.0, 0.0), |(x, y), &(u, v)| {
+            let uuder = surface.uuder(u, v).magnitude();
+            let vvder = surface.vvder(u, v).magnitude();
+            (f64::max(x, uuder), f64::max(y, vvder))
+        });
+        let uerr = BOUNDING_BOX_SAFETY * uacc * (u1 - u0) * (u1 - u0) / 8.0;
+        let verr = BOUNDING_BOX_SAFETY * vacc * (v1 - v0) * (v1 - v0) / 8.0;
+        if uerr + verr > tol && depth < BOUNDING_BOX_MAX_DEPTH {
+            if uerr > verr {
+                stack.push((((u0, um), (v0, v1)), depth + 1));
+                stack.push((((um, u1), (v0, v1)), depth + 1));
+            } else {
+                stack.push((((u0, u1), (v0, vm)), depth + 1));
+                stack.push((((u0, u1), (vm, v1)), depth + 1));
+            }
+        } else {
+            let r = Vector3::from_value(uerr + verr);
+            params[..4].iter().for_each(|&(u, v)| {
+                let p = surface.subs(u, v);
+                bdb += [p - r, p + r].iter().collect::<BoundingBox<_>>();
+            });
+        }
+    }
+    bdb
+}
+
+/// The maximum number of the rounds of the bisections in the adaptive division.
+const ADAPTIVE_DIVISION_MAX_ITERATION: usize = 30;
+
+/// Creates the surface division adaptive to the curvature in each direction.
+///
+/// The returned division is a tensor-product grid, but the u- and v-parameters are divided
+/// independently. An interval of the u-parameters is bisected if, on some v-parameter of the
+/// grid or the midpoints of its intervals, the surface at the middle of the interval is farther
+/// than `tol` from the chord, or the angle between the normals at the ends of the interval is
+/// larger than `angle_tol`. The intervals of the v-parameters are bisected in the same way.
+/// If a cell is not flat although neither of its intervals is bisected, both of them are.
+/// Hence, a cylinder is divided finely only around its axis.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn parameter_division_adaptive<S: ParametricSurface3D>(
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+    tol: f64,
+    angle_tol: f64,
+) -> (Vec<f64>, Vec<f64>) {
+    nonpositive_tolerance!(tol);
+    let cos_tol = f64::cos(angle_tol);
+    let (mut udiv, mut vdiv) = (vec![urange.0, urange.1], vec![vrange.0, vrange.1]);
+    let is_curved = |(p0, p1): ((f64, f64), (f64, f64))| {
+        let mid = ((p0.0 + p1.0) / 2.0, (p0.1 + p1.1) / 2.0);
+        let (pt0, pt1) = (surface.subs(p0.0, p0.1), surface.subs(p1.0, p1.1));
+        let far = surface.subs(mid.0, mid.1).distance2(pt0.midpoint(pt1)) > tol * tol;
+        let (n0, n1) = (surface.normal(p0.0, p0.1), surface.normal(p1.0, p1.1));
+        far || n0.dot(n1) < cos_tol
+    };
+    let samples = |div: &[f64]| {
+        let mids = div.windows(2).map(|t| (t[0] + t[1]) / 2.0);
+        div.iter().copied().chain(mids).collect::<Vec<_>>()
+    };
+    for _ in 0..ADAPTIVE_DIVISION_MAX_ITERATION {
+        let (usamples, vsamples) = (samples(&udiv), samples(&vdiv));
+        let mut uflags = udiv
+            .windows(2)
+            .map(|u| vsamples.iter().any(|v| is_curved(((u[0], *v), (u[1], *v)))))
+            .collect::<Vec<_>>();
+        let mut vflags = vdiv
+            .windows(2)
+            .map(|v| usamples.iter().any(|u| is_curved(((*u, v[0]), (*u, v[1])))))
+            .collect::<Vec<_>>();
+        for (i, u) in udiv.windows(2).enumerate() {
+            for (j, v) in vdiv.windows(2).enumerate() {
+                if !uflags[i] && !vflags[j] && is_curved(((u[0], v[0]), (u[1], v[1]))) {
+                    (uflags[i], vflags[j]) = (true, true);
+                }
+            }
+        }
+        let bisect = |div: &[f64], flags: Vec<bool>| {
+            let mut res = vec![div[0]];
+            div.windows(2).zip(flags).for_each(|(t, flag)| {
+                let mid = (t[0] + t[1]) / 2.0;
+                if flag && t[0] < mid && mid < t[1] {
+                    res.push(mid);
+                }
+                res.push(t[1]);
+            });
+            res
+        };
+        let (new_udiv, new_vdiv) = (bisect(&udiv, uflags), bisect(&vdiv, vflags));
+        if new_udiv.len() == udiv.len() && new_vdiv.len() == vdiv.len() {
+            break;
+        }
+        (udiv, vdiv) = (new_udiv, new_vdiv);
+    }
+    (udiv, vdiv)
+}
+
 fn sub_parameter_division<S>(surface: &S, (udiv, vdiv): (&mut Vec<f64>, &mut Vec<f64>), tol: f64)
 where
     S: ParametricSurface,
@@ -19,6 +19,21 @@ pub trait ParametricCurve: Clone {
     fn der(&self, t: f64) -> Self::Vector;
     /// Returns the 2nd-order derivation.
     fn der2(&self, t: f64) -> Self::Vector;
+    /// Returns the `n`-th order derivation. The 0th order derivation is the position vector.
+    ///
+    /// The default implementation is the central difference of `der2` for `n > 2`.
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector
+    where
+        Self::Point: control_point::ControlPoint<f64, Diff = Self::Vector>,
+        Self::Vector: VectorSpace<Scalar = f64>, {
+        match n {
+            0 => control_point::ControlPoint::to_vec(self.subs(t)),
+            1 => self.der(t),
+            2 => self.der2(t),
+            _ => central_difference(n - 2, t, |t| self.der2(t)),
+        }
+    }
     /// Returns default parameter range
     #[inline(always)]
     fn parameter_range(&self) -> ParameterRange { (Bound::Unbounded, Bound::Unbounded) }
@@ -92,6 +107,13 @@ impl<'a, C: ParametricCurve> ParametricCurve for &'a C {
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { (*self).der2(t) }
     #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector
+    where
+        Self::Point: control_point::ControlPoint<f64, Diff = Self::Vector>,
+        Self::Vector: VectorSpace<Scalar = f64>, {
+        (*self).der_n(n, t)
+    }
+    #[inline(always)]
     fn parameter_range(&self) -> ParameterRange { (*self).parameter_range() }
     #[inline(always)]
     fn period(&self) -> Option<f64> { (*self).period() }
@@ -113,6 +135,13 @@ impl<C: ParametricCurve> ParametricCurve for Box<C> {
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { (**self).der2(t) }
     #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector
+    where
+        Self::Point: control_point::ControlPoint<f64, Diff = Self::Vector>,
+        Self::Vector: VectorSpace<Scalar = f64>, {
+        (**self).der_n(n, t)
+    }
+    #[inline(always)]
     fn parameter_range(&self) -> ParameterRange { (**self).parameter_range() }
     #[inline(always)]
     fn period(&self) -> Option<f64> { (**self).period() }
@@ -134,7 +163,11 @@ impl<C: Cut> Cut for Box<C> {
 pub trait ParametricCurve2D: ParametricCurve<Point = Point2, Vector = Vector2> {}
 impl<C: ParametricCurve<Point = Point2, Vector = Vector2>> ParametricCurve2D for C {}
 /// 3D parametric curve
-pub trait ParametricCurve3D: ParametricCurve<Point = Point3, Vector = Vector3> {}
+pub trait ParametricCurve3D: ParametricCurve<Point = Point3, Vector = Vector3> {
+    /// Returns the 3rd-order derivation, i.e. `der_n(3, t)`.
+    #[inline(always)]
+    fn der3(&self, t: f64) -> Vector3 { self.der_n(3, t) }
+}
 impl<C: ParametricCurve<Point = Point3, Vector = Vector3>> ParametricCurve3D for C {}
 
 /// Dividable curve
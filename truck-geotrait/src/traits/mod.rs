@@ -1,5 +1,5 @@
 use std::ops::Bound;
-use truck_base::cgmath64::*;
+use truck_base::{bounding_box::BoundingBox, cgmath64::*};
 
 mod curve;
 pub use curve::*;
@@ -19,6 +19,19 @@ fn bound2opt<T>(x: Bound<T>) -> Option<T> {
 }
 const UNBOUNDED_ERROR: &str = "Parameter range is unbounded.";
 
+/// The `n`-th order central difference of `f` at `t`.
+fn central_difference<V: VectorSpace<Scalar = f64>>(n: usize, t: f64, f: impl Fn(f64) -> V) -> V {
+    // balances the truncation error `O(h^2)` and the rounding error `O(eps / h^n)`.
+    let h = f64::powf(f64::EPSILON, 1.0 / (n + 2) as f64) * f64::max(t.abs(), 1.0);
+    let (mut sum, mut binom) = (V::zero(), 1.0);
+    for i in 0..=n {
+        let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+        sum = sum + f(t + (n as f64 / 2.0 - i as f64) * h) * (sign * binom);
+        binom = binom * (n - i) as f64 / (i + 1) as f64;
+    }
+    sum / h.powi(n as i32)
+}
+
 /// Oriented and reversible
 pub trait Invertible: Clone {
     /// Inverts `self`
@@ -71,3 +84,24 @@ impl<S: Transformed<T>, T> Transformed<T> for Box<S> {
     #[inline(always)]
     fn transformed(&self, trans: T) -> Self { Box::new((**self).transformed(trans)) }
 }
+
+/// Geometry contained in the convex hull of its control points, e.g. B-spline curves.
+pub trait ControlHull<Dim: SPDimension> {
+    /// The type of the control points.
+    type Point;
+    /// Returns the points whose convex hull contains the geometry restricted to `range`.
+    fn control_hull(&self, range: Dim::Range) -> Vec<Self::Point>;
+    /// Returns the bounding box of `control_hull(range)`, which is guaranteed to contain the
+    /// geometry restricted to `range`.
+    #[inline(always)]
+    fn hull_bounding_box(&self, range: Dim::Range) -> BoundingBox<Self::Point>
+    where Self::Point: truck_base::bounding_box::Bounded {
+        self.control_hull(range).into_iter().collect()
+    }
+}
+
+impl<Dim: SPDimension, T: ControlHull<Dim>> ControlHull<Dim> for Box<T> {
+    type Point = T::Point;
+    #[inline(always)]
+    fn control_hull(&self, range: Dim::Range) -> Vec<Self::Point> { (**self).control_hull(range) }
+}
@@ -1,9 +1,14 @@
+use std::fmt::Debug;
+use thiserror::Error;
+
 /// Dimension for search nearest parameter
 pub trait SPDimension {
     /// dimension
     const DIM: usize;
     /// parameter type, curve => f64, surface => (f64, f64)
-    type Parameter;
+    type Parameter: Debug;
+    /// parameter range type, curve => (f64, f64), surface => ((f64, f64), (f64, f64))
+    type Range;
     /// `SPHintXX`
     type Hint;
 }
@@ -15,6 +20,7 @@ pub enum D1 {}
 impl SPDimension for D1 {
     const DIM: usize = 1;
     type Parameter = f64;
+    type Range = (f64, f64);
     type Hint = SPHint1D;
 }
 
@@ -25,6 +31,7 @@ pub enum D2 {}
 impl SPDimension for D2 {
     const DIM: usize = 2;
     type Parameter = (f64, f64);
+    type Range = ((f64, f64), (f64, f64));
     type Hint = SPHint2D;
 }
 
@@ -85,6 +92,58 @@ impl From<Option<(f64, f64)>> for SPHint2D {
     }
 }
 
+/// Error for searching parameters, `T` is the type of the parameters.
+#[derive(Clone, Copy, PartialEq, Debug, Error)]
+pub enum SearchError<T: Debug = f64> {
+    /// Newton's method did not converge within the trials.
+    #[error("Newton's method did not converge: the last parameter is {last:?} and the residual is {residual}.")]
+    NotConverged {
+        /// the parameter at the last iteration
+        last: T,
+        /// the residual at the last iteration
+        residual: f64,
+    },
+    /// The derivations vanish, so Newton's method could not proceed.
+    #[error("The derivations vanish at the parameter {at:?}.")]
+    VanishingDerivative {
+        /// the parameter where the derivations vanish
+        at: T,
+    },
+    /// The nearest parameter was found, but the point is not on the geometry.
+    #[error("The point is not on the geometry: the nearest parameter is {nearest:?} and the distance is {distance}.")]
+    OffGeometry {
+        /// the nearest parameter
+        nearest: T,
+        /// the distance between the point and the geometry
+        distance: f64,
+    },
+    /// The parameter was not found, and the implementation does not tell the reason.
+    #[error("The parameter was not found.")]
+    NotFound,
+}
+
+impl<T: Debug> SearchError<T> {
+    /// Maps the parameters in the error by `f`.
+    #[inline(always)]
+    pub fn map<U: Debug, F>(self, f: F) -> SearchError<U>
+    where F: Fn(T) -> U {
+        match self {
+            SearchError::NotConverged { last, residual } => SearchError::NotConverged {
+                last: f(last),
+                residual,
+            },
+            SearchError::VanishingDerivative { at } => {
+                SearchError::VanishingDerivative { at: f(at) }
+            }
+            SearchError::OffGeometry { nearest, distance } => SearchError::OffGeometry {
+                nearest: f(nearest),
+                distance,
+            },
+            SearchError::NotFound => SearchError::NotFound,
+        }
+    }
+}
+
 /// Search parameter `t` such that `self.subs(t)` is near point.
 pub trait SearchParameter<Dim: SPDimension> {
     /// point
@@ -97,6 +156,20 @@ pub trait SearchParameter<Dim: SPDimension> {
         hint: H,
         trials: usize,
     ) -> Option<Dim::Parameter>;
+    /// Search parameter `t` such that `self.subs(t)` is near point.  
+    /// Returns the reason if could not find such parameter.
+    ///
+    /// The default implementation returns [`SearchError::NotFound`] if `search_parameter` fails.
+    #[inline(always)]
+    fn try_search_parameter<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Result<Dim::Parameter, SearchError<Dim::Parameter>> {
+        self.search_parameter(point, hint, trials)
+            .ok_or(SearchError::NotFound)
+    }
 }
 
 impl<'a, Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for &'a T {
@@ -109,6 +182,14 @@ impl<'a, Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for &'a
     ) -> Option<Dim::Parameter> {
         T::search_parameter(*self, point, hint, trials)
     }
+    fn try_search_parameter<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Result<Dim::Parameter, SearchError<Dim::Parameter>> {
+        T::try_search_parameter(*self, point, hint, trials)
+    }
 }
 
 impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for Box<T> {
@@ -121,6 +202,14 @@ impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for Box<T>
     ) -> Option<Dim::Parameter> {
         T::search_parameter(&**self, point, hint, trials)
     }
+    fn try_search_parameter<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Result<Dim::Parameter, SearchError<Dim::Parameter>> {
+        T::try_search_parameter(&**self, point, hint, trials)
+    }
 }
 
 /// Search parameter `t` such that `self.subs(t)` is nearest point.
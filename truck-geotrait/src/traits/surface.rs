@@ -19,6 +19,30 @@ pub trait ParametricSurface: Clone {
     fn uvder(&self, u: f64, v: f64) -> Self::Vector;
     /// Returns the 2nd-order derivation by `v`.
     fn vvder(&self, u: f64, v: f64) -> Self::Vector;
+    /// Returns the derivation `m` times by `u` and `n` times by `v`.
+    /// The 0th order derivation is the position vector.
+    ///
+    /// The default implementation is the central difference of the 2nd-order derivations
+    /// for `m + n > 2`.
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector
+    where
+        Self::Point: control_point::ControlPoint<f64, Diff = Self::Vector>,
+        Self::Vector: VectorSpace<Scalar = f64>, {
+        match (m, n) {
+            (0, 0) => control_point::ControlPoint::to_vec(self.subs(u, v)),
+            (1, 0) => self.uder(u, v),
+            (0, 1) => self.vder(u, v),
+            (2, 0) => self.uuder(u, v),
+            (1, 1) => self.uvder(u, v),
+            (0, 2) => self.vvder(u, v),
+            (_, 0) => central_difference(m - 2, u, |u| self.uuder(u, v)),
+            (0, _) => central_difference(n - 2, v, |v| self.vvder(u, v)),
+            _ => central_difference(m - 1, u, |u| {
+                central_difference(n - 1, v, |v| self.uvder(u, v))
+            }),
+        }
+    }
     /// The range of the parameter of the surface.
     #[inline(always)]
     fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
@@ -59,6 +83,13 @@ impl<'a, S: ParametricSurface> ParametricSurface for &'a S {
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Self::Vector { (*self).vvder(u, v) }
     #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector
+    where
+        Self::Point: control_point::ControlPoint<f64, Diff = Self::Vector>,
+        Self::Vector: VectorSpace<Scalar = f64>, {
+        (*self).der_mn(m, n, u, v)
+    }
+    #[inline(always)]
     fn parameter_range(&self) -> (ParameterRange, ParameterRange) { (*self).parameter_range() }
     #[inline(always)]
     fn u_period(&self) -> Option<f64> { (*self).u_period() }
@@ -82,6 +113,13 @@ impl<S: ParametricSurface> ParametricSurface for Box<S> {
     #[inline(always)]
     fn vvder(&self, u: f64, v: f64) -> Self::Vector { (**self).vvder(u, v) }
     #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Self::Vector
+    where
+        Self::Point: control_point::ControlPoint<f64, Diff = Self::Vector>,
+        Self::Vector: VectorSpace<Scalar = f64>, {
+        (**self).der_mn(m, n, u, v)
+    }
+    #[inline(always)]
     fn parameter_range(&self) -> (ParameterRange, ParameterRange) { (**self).parameter_range() }
     #[inline(always)]
     fn u_period(&self) -> Option<f64> { (**self).u_period() }
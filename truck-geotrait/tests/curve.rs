@@ -1,4 +1,6 @@
-use truck_base::{cgmath64::*, tolerance::*};
+use std::f64::consts::PI;
+use std::ops::Bound;
+use truck_base::{assert_near, bounding_box::BoundingBox, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
 mod polynomial;
 use polynomial::PolyCurve;
@@ -26,6 +28,24 @@ fn polycurve_test() {
     }
 }
 
+#[test]
+fn polycurve_der_n() {
+    // 5t^4 + 4t^3 + 3t^2 + 2t + 1
+    let coef = (1..=5).map(|i| Vector1::new(i as f64)).collect();
+    let poly = PolyCurve::<Point1>(coef);
+    for i in 0..=10 {
+        let t = -1.0 + 0.2 * i as f64;
+        assert_eq!(poly.der_n(0, t), poly.subs(t).to_vec());
+        assert_eq!(poly.der_n(1, t), poly.der(t));
+        assert_eq!(poly.der_n(2, t), poly.der2(t));
+        let ans = [120.0 * t + 24.0, 120.0, 0.0];
+        for (n, ans) in (3..=5).zip(ans) {
+            let res = poly.der_n(n, t);
+            assert!(f64::abs(res.x - ans) < 1.0e-4 * f64::max(ans.abs(), 1.0));
+        }
+    }
+}
+
 #[test]
 fn polycurve_presearch() {
     let coef = vec![
@@ -75,6 +95,45 @@ fn polycurve_snp_on_curve() {
     assert!(count > 90, "wrong answer: {:?}", 100 - count);
 }
 
+#[test]
+fn polycurve_bounded_snp_at_end() {
+    // (t, t^2)
+    let coef = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let poly = PolyCurve::<Point2>(coef);
+    let pt = Point2::new(4.0, 0.0);
+    let t = algo::curve::search_nearest_parameter(&poly, pt, 0.5, 100).unwrap();
+    assert!(t > 1.0);
+    let t = algo::curve::search_nearest_parameter_bounded(&poly, pt, 0.5, (0.0, 1.0), 100);
+    assert_eq!(t, Some(1.0));
+    let pt = Point2::new(-4.0, 0.0);
+    let t = algo::curve::search_nearest_parameter_bounded(&poly, pt, 0.9, (0.0, 1.0), 100);
+    assert_eq!(t, Some(0.0));
+}
+
+#[test]
+fn polycurve_bounded_snp_equidistant() {
+    // (t, (t^2 - 1)^2), the origin is equidistant to the two valleys.
+    let coef = vec![
+        Vector2::new(0.0, 1.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, -2.0),
+        Vector2::new(0.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let poly = PolyCurve::<Point2>(coef);
+    let pt = Point2::origin();
+    // Newton's method stops at the local maximum.
+    let t = algo::curve::search_nearest_parameter(&poly, pt, 0.0, 100).unwrap();
+    assert_eq!(t, 0.0);
+    let t = algo::curve::search_nearest_parameter_bounded(&poly, pt, 0.0, (-2.0, 2.0), 100);
+    let ans = f64::sqrt(1.0 - f64::powf(0.25, 1.0 / 3.0));
+    assert_near!(f64::abs(t.unwrap()), ans);
+}
+
 fn exec_polycurve_division() -> bool {
     let coef: Vec<Vector3> = (0..5)
         .map(|_| {
@@ -107,3 +166,348 @@ fn polycurve_division() {
     println!("division error: {}", 100 - count);
     assert!(count > 98);
 }
+
+#[derive(Clone, Debug)]
+struct Circle(f64);
+
+impl ParametricCurve for Circle {
+    type Point = Point2;
+    type Vector = Vector2;
+    fn subs(&self, t: f64) -> Point2 { Point2::new(self.0 * f64::cos(t), self.0 * f64::sin(t)) }
+    fn der(&self, t: f64) -> Vector2 { Vector2::new(-self.0 * f64::sin(t), self.0 * f64::cos(t)) }
+    fn der2(&self, t: f64) -> Vector2 { Vector2::new(-self.0 * f64::cos(t), -self.0 * f64::sin(t)) }
+    fn parameter_range(&self) -> ParameterRange {
+        (Bound::Included(0.0), Bound::Excluded(2.0 * PI))
+    }
+    fn period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+#[test]
+fn circle_arc_length() {
+    let circle = Circle(3.0);
+    let range = (0.0, 2.0 * PI);
+    let length = algo::curve::arc_length(&circle, range, TOLERANCE);
+    assert_near!(length, 6.0 * PI);
+    let length = algo::curve::arc_length(&circle, (1.0, 2.5), TOLERANCE);
+    assert_near!(length, 4.5);
+
+    let t = algo::curve::param_at_arc_length(&circle, range, 5.0, TOLERANCE);
+    assert_near!(t, 5.0 / 3.0);
+    let t = algo::curve::param_at_arc_length(&circle, range, 100.0, TOLERANCE);
+    assert_near!(t, 2.0 * PI);
+
+    let (params, pts) = algo::curve::uniform_arc_length_division(&circle, range, 7);
+    assert_eq!(params.len(), 8);
+    params
+        .iter()
+        .zip(&pts)
+        .enumerate()
+        .for_each(|(i, (t, pt))| {
+            assert_near!(*t, 2.0 * PI * i as f64 / 7.0);
+            assert_eq!(circle.subs(*t), *pt);
+        });
+}
+
+#[test]
+fn circle_search_parameter_near_seam() {
+    let circle = Circle(2.0);
+    // the point just before the seam `t = 0`
+    let pt = Point2::new(3.0 * f64::cos(0.01), -3.0 * f64::sin(0.01));
+    let t = algo::curve::search_nearest_parameter(&circle, pt, 0.0, 100).unwrap();
+    assert!((0.0..2.0 * PI).contains(&t));
+    assert_near!(t, 2.0 * PI - 0.01);
+
+    let pt = circle.subs(-0.01);
+    let t = algo::curve::search_parameter(&circle, pt, 0.05, 100).unwrap();
+    assert_near!(t, 2.0 * PI - 0.01);
+
+    let pt = circle.subs(-0.5);
+    let t = algo::curve::presearch(&circle, pt, (-1.0, 1.0), 100);
+    assert_near!(t, 2.0 * PI - 0.5);
+
+    assert_eq!(algo::curve::normalize_parameter(&circle, 1.0), 1.0);
+    assert_near!(algo::curve::normalize_parameter(&circle, 2.0 * PI), 0.0);
+    assert_near!(algo::curve::normalize_parameter(&circle, 7.0 * PI), PI);
+}
+
+#[test]
+fn search_parameter_errors() {
+    let circle = Circle(1.0);
+    let t = algo::curve::try_search_parameter(&circle, Point2::new(0.0, 1.0), 0.5, 100).unwrap();
+    assert_near!(t, PI / 2.0);
+
+    let res = algo::curve::try_search_parameter(&circle, Point2::new(-1.0, 0.0), 0.5, 0);
+    match res {
+        Err(SearchError::NotConverged { residual, .. }) => assert!(residual > TOLERANCE),
+        _ => panic!("unexpected result: {res:?}"),
+    }
+
+    let res = algo::curve::try_search_parameter(&circle, Point2::new(0.0, 2.0), 1.0, 100);
+    match res {
+        Err(SearchError::OffGeometry { nearest, distance }) => {
+            assert_near!(nearest, PI / 2.0);
+            assert_near!(distance, 1.0);
+        }
+        _ => panic!("unexpected result: {res:?}"),
+    }
+
+    // the curve `(t^3, t^3)` stops at `t = 0`
+    let cubic = PolyCurve::<Point2>(vec![
+        Vector2::zero(),
+        Vector2::zero(),
+        Vector2::zero(),
+        Vector2::new(1.0, 1.0),
+    ]);
+    let res = algo::curve::try_search_parameter(&cubic, Point2::new(1.0, 0.0), 0.0, 100);
+    assert_eq!(res, Err(SearchError::VanishingDerivative { at: 0.0 }));
+    assert!(algo::curve::search_parameter(&cubic, Point2::new(1.0, 0.0), 0.0, 100).is_none());
+}
+
+#[test]
+fn curve_intersections() {
+    let circle0 = Circle(1.0);
+    let circle1 = Translated(Circle(1.0), Vector2::new(1.0, 0.0));
+    let range = (0.0, 2.0 * PI);
+    let hits = algo::curve::intersections(&circle0, range, &circle1, range, 0.01);
+    assert_eq!(hits.len(), 2);
+    assert_near!(hits[0].0, PI / 3.0);
+    assert_near!(hits[0].1, 2.0 * PI / 3.0);
+    assert_near!(hits[0].2, Point2::new(0.5, f64::sqrt(3.0) / 2.0));
+    assert_near!(hits[1].0, 5.0 * PI / 3.0);
+    assert_near!(hits[1].1, 4.0 * PI / 3.0);
+    assert_near!(hits[1].2, Point2::new(0.5, -f64::sqrt(3.0) / 2.0));
+
+    // the line `(4t - 2, 1)`, tangent to the circle at `(0, 1)`
+    let line = PolyCurve::<Point2>(vec![Vector2::new(-2.0, 1.0), Vector2::new(4.0, 0.0)]);
+    let hits = algo::curve::intersections(&circle0, range, &line, (0.0, 1.0), 0.01);
+    assert_eq!(hits.len(), 1);
+    assert!((hits[0].0 - PI / 2.0).abs() < 0.01);
+    assert!((hits[0].1 - 0.5).abs() < 0.01);
+    assert!(hits[0].2.distance(Point2::new(0.0, 1.0)) < 0.01);
+
+    let line = PolyCurve::<Point2>(vec![Vector2::new(-2.0, 3.0), Vector2::new(4.0, 0.0)]);
+    let hits = algo::curve::intersections(&circle0, range, &line, (0.0, 1.0), 0.01);
+    assert!(hits.is_empty());
+}
+
+#[derive(Clone, Debug)]
+struct Translated<C>(C, Vector2);
+
+impl<C: ParametricCurve<Point = Point2, Vector = Vector2>> ParametricCurve for Translated<C> {
+    type Point = Point2;
+    type Vector = Vector2;
+    fn subs(&self, t: f64) -> Point2 { self.0.subs(t) + self.1 }
+    fn der(&self, t: f64) -> Vector2 { self.0.der(t) }
+    fn der2(&self, t: f64) -> Vector2 { self.0.der2(t) }
+}
+
+// `(t, amplitude * sin(2 pi t) * exp(-(t - 0.5)^2 / (2 sigma^2)))`
+#[derive(Clone, Debug)]
+struct SineCurve {
+    amplitude: f64,
+    sigma: f64,
+    frequency: f64,
+}
+
+impl ParametricCurve for SineCurve {
+    type Point = Point2;
+    type Vector = Vector2;
+    fn subs(&self, t: f64) -> Point2 {
+        let (a, s, f) = (self.amplitude, self.sigma, self.frequency);
+        let envelope = f64::exp(-(t - 0.5) * (t - 0.5) / (2.0 * s * s));
+        Point2::new(t, a * f64::sin(2.0 * PI * f * t) * envelope)
+    }
+    fn der(&self, t: f64) -> Vector2 {
+        let (a, s, f) = (self.amplitude, self.sigma, self.frequency);
+        let envelope = f64::exp(-(t - 0.5) * (t - 0.5) / (2.0 * s * s));
+        let (sin, cos) = f64::sin_cos(2.0 * PI * f * t);
+        let y = a * envelope * (2.0 * PI * f * cos - (t - 0.5) / (s * s) * sin);
+        Vector2::new(1.0, y)
+    }
+    fn der2(&self, t: f64) -> Vector2 {
+        let eps = 1.0e-4;
+        (self.der(t + eps) - self.der(t - eps)) / (2.0 * eps)
+    }
+}
+
+fn check_division(curve: &SineCurve, (params, pts): &(Vec<f64>, Vec<Point2>), tol: f64) {
+    assert_eq!(params.len(), pts.len());
+    params.windows(2).zip(pts.windows(2)).for_each(|(t, p)| {
+        assert!(t[0] < t[1]);
+        assert_eq!(curve.subs(t[0]), p[0]);
+        assert_eq!(curve.subs(t[1]), p[1]);
+        (1..4).for_each(|i| {
+            let s = i as f64 / 4.0;
+            let chord = p[0] + (p[1] - p[0]) * s;
+            let pt = curve.subs(t[0] * (1.0 - s) + t[1] * s);
+            assert!(pt.distance(chord) < 2.0 * tol, "{t:?} {s}");
+        });
+    });
+}
+
+#[test]
+fn sine_curve_division() {
+    let tol = 0.01;
+    // the middle point is on the chord
+    let sine = SineCurve {
+        amplitude: 1.0,
+        sigma: 1.0e3,
+        frequency: 1.0,
+    };
+    let division = algo::curve::parameter_division(&sine, (0.0, 1.0), tol);
+    assert!(division.0.len() > 3);
+    check_division(&sine, &division, tol);
+
+    // the sharp peak at the middle cannot be found by the perturbed point
+    let peak = SineCurve {
+        amplitude: 1.0,
+        sigma: 0.01,
+        frequency: 0.5,
+    };
+    for seed in 0..10 {
+        let division = algo::curve::parameter_division_with_seed(&peak, (0.0, 1.0), tol, seed);
+        assert!(division.1.iter().any(|p| p.y > 0.99));
+        check_division(&peak, &division, tol);
+    }
+}
+
+#[test]
+fn division_with_seed() {
+    let sine = SineCurve {
+        amplitude: 0.3,
+        sigma: 0.2,
+        frequency: 3.0,
+    };
+    let division0 = algo::curve::parameter_division(&sine, (0.0, 1.0), 0.01);
+    let division1 = algo::curve::parameter_division_with_seed(&sine, (0.0, 1.0), 0.01, 0);
+    assert_eq!(division0, division1);
+    let division2 = algo::curve::parameter_division_with_seed(&sine, (0.0, 1.0), 0.01, 7);
+    let division3 = algo::curve::parameter_division_with_seed(&sine, (0.0, 1.0), 0.01, 7);
+    assert_eq!(division2, division3);
+    check_division(&sine, &division2, 0.01);
+}
+
+#[derive(Clone, Debug)]
+struct StepCurve;
+
+impl ParametricCurve for StepCurve {
+    type Point = Point2;
+    type Vector = Vector2;
+    fn subs(&self, t: f64) -> Point2 { Point2::new(t, f64::signum(t - 1.0 / 3.0)) }
+    fn der(&self, _: f64) -> Vector2 { Vector2::unit_x() }
+    fn der2(&self, _: f64) -> Vector2 { Vector2::zero() }
+}
+
+#[test]
+fn discontinuous_curve_division() {
+    let (params, _) = algo::curve::parameter_division(&StepCurve, (0.0, 1.0), 0.01);
+    assert!(params.len() < 1000);
+    assert!(params.windows(2).all(|t| t[0] < t[1]));
+    let i = params.partition_point(|t| *t < 1.0 / 3.0);
+    assert!(params[i] - params[i - 1] < 1.0e-10);
+}
+
+#[test]
+fn closest_pair_of_skew_lines() {
+    // the lines `(t, t, 0)` and `(1, s, 1 + s)`
+    let line0 = PolyCurve::<Point3>(vec![Vector3::zero(), Vector3::new(1.0, 1.0, 0.0)]);
+    let line1 = PolyCurve::<Point3>(vec![
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(0.0, 1.0, 1.0),
+    ]);
+    let range = (-1.0, 1.0);
+    let (t, s) = algo::curve::search_closest_pair(&line0, range, &line1, range, 100).unwrap();
+    assert_near!(t, 1.0 / 3.0);
+    assert_near!(s, -1.0 / 3.0);
+    let dist = algo::curve::distance_between_curves(&line0, range, &line1, range, 100).unwrap();
+    assert_near!(dist, 2.0 / f64::sqrt(3.0));
+}
+
+#[test]
+fn closest_pair_of_concentric_circles() {
+    let range = (0.0, 2.0 * PI);
+    let (circle0, circle1) = (Circle(1.0), Circle(2.5));
+    let (t, s) = algo::curve::search_closest_pair(&circle0, range, &circle1, range, 100).unwrap();
+    assert_near!(circle0.subs(t).distance(circle1.subs(s)), 1.5);
+    let dist = algo::curve::distance_between_curves(&circle1, range, &circle0, range, 100).unwrap();
+    assert_near!(dist, 1.5);
+}
+
+#[test]
+fn closest_pair_at_ends() {
+    // the segments `(t, 0)` and `(s + 2, 1)`
+    let line0 = PolyCurve::<Point2>(vec![Vector2::zero(), Vector2::unit_x()]);
+    let line1 = PolyCurve::<Point2>(vec![Vector2::new(2.0, 1.0), Vector2::unit_x()]);
+    let range = (0.0, 1.0);
+    let (t, s) = algo::curve::search_closest_pair(&line0, range, &line1, range, 100).unwrap();
+    assert_eq!((t, s), (1.0, 0.0));
+
+    // the parallel segments `(t, 0)` and `(s, 1)`
+    let line1 = PolyCurve::<Point2>(vec![Vector2::unit_y(), Vector2::unit_x()]);
+    let (t, s) = algo::curve::search_closest_pair(&line0, range, &line1, range, 100).unwrap();
+    assert_near!(t, s);
+    let dist = algo::curve::distance_between_curves(&line0, range, &line1, range, 100).unwrap();
+    assert_near!(dist, 1.0);
+}
+
+// the helix `(a cos t, a sin t, b t)`
+#[derive(Clone, Debug)]
+struct Helix(f64, f64);
+
+impl ParametricCurve for Helix {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 {
+        Point3::new(self.0 * f64::cos(t), self.0 * f64::sin(t), self.1 * t)
+    }
+    fn der(&self, t: f64) -> Vector3 {
+        Vector3::new(-self.0 * f64::sin(t), self.0 * f64::cos(t), self.1)
+    }
+    fn der2(&self, t: f64) -> Vector3 {
+        Vector3::new(-self.0 * f64::cos(t), -self.0 * f64::sin(t), 0.0)
+    }
+}
+
+#[test]
+fn helix_frenet_frame() {
+    let (a, b) = (2.0, 0.5);
+    let helix = Helix(a, b);
+    let c2 = a * a + b * b;
+    for i in 0..=10 {
+        let t = -3.0 + i as f64 * 0.6;
+        assert_near!(algo::curve::curvature(&helix, t), a / c2);
+        assert!(f64::abs(algo::curve::torsion(&helix, t) - b / c2) < 1.0e-5);
+        let (tangent, normal, binormal) = algo::curve::frenet_frame(&helix, t).unwrap();
+        let c = f64::sqrt(c2);
+        assert_near!(tangent, Vector3::new(-a * t.sin(), a * t.cos(), b) / c);
+        assert_near!(normal, Vector3::new(-t.cos(), -t.sin(), 0.0));
+        assert_near!(binormal, Vector3::new(b * t.sin(), -b * t.cos(), a) / c);
+    }
+
+    assert_near!(algo::curve::curvature(&Circle(2.5), 1.0), 0.4);
+    let line = PolyCurve::<Point3>(vec![
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(1.0, -1.0, 0.5),
+    ]);
+    assert_near!(algo::curve::curvature(&line, 1.0), 0.0);
+    assert_near!(algo::curve::torsion(&line, 1.0), 0.0);
+    assert!(algo::curve::frenet_frame(&line, 1.0).is_none());
+}
+
+#[test]
+fn helix_bounding_box() {
+    let helix = Helix(1.0, 0.5);
+    let (range, tol) = ((0.1, 3.0), 1.0e-3);
+    let bdb = algo::curve::bounding_box(&helix, range, tol);
+    (0..=1000).for_each(|i| {
+        let t = 0.1 + 2.9 * i as f64 / 1000.0;
+        assert!(bdb.contains(helix.subs(t)));
+    });
+
+    // the maximum of `y` at `t = PI / 2` lies between the initial samples
+    let samples = (0..=16)
+        .map(|i| helix.subs(0.1 + 2.9 * i as f64 / 16.0))
+        .collect::<BoundingBox<_>>();
+    assert!(samples.max().y < 1.0 - 1.0e-4);
+    assert!(1.0 <= bdb.max().y && bdb.max().y <= 1.0 + tol);
+}
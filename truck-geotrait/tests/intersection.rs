@@ -0,0 +1,192 @@
+use std::f64::consts::PI;
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
+use truck_geotrait::{algo::intersection::*, *};
+
+#[derive(Clone, Debug)]
+struct Line(Point3, Vector3);
+
+impl ParametricCurve for Line {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 { self.0 + self.1 * t }
+    fn der(&self, _: f64) -> Vector3 { self.1 }
+    fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+}
+
+// the circle in the xy-plane centered at the origin
+#[derive(Clone, Debug)]
+struct Circle(f64);
+
+impl ParametricCurve for Circle {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 { Point3::new(t.cos(), t.sin(), 0.0) * self.0 }
+    fn der(&self, t: f64) -> Vector3 { Vector3::new(-t.sin(), t.cos(), 0.0) * self.0 }
+    fn der2(&self, t: f64) -> Vector3 { Vector3::new(-t.cos(), -t.sin(), 0.0) * self.0 }
+}
+
+// the xy-plane
+#[derive(Clone, Debug)]
+struct Plane;
+
+impl ParametricSurface for Plane {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, 0.0) }
+    fn uder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_x() }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_y() }
+    fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+}
+
+impl ParametricSurface3D for Plane {}
+
+// the unit cylinder around the z-axis
+#[derive(Clone, Debug)]
+struct Cylinder;
+
+impl ParametricSurface for Cylinder {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u.cos(), u.sin(), v) }
+    fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.sin(), u.cos(), 0.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_z() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.cos(), -u.sin(), 0.0) }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+impl ParametricSurface3D for Cylinder {}
+
+// the unit cylinder around the x-axis
+#[derive(Clone, Debug)]
+struct XCylinder;
+
+impl ParametricSurface for XCylinder {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(v, u.cos(), u.sin()) }
+    fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(0.0, -u.sin(), u.cos()) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_x() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(0.0, -u.cos(), -u.sin()) }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+impl ParametricSurface3D for XCylinder {}
+
+// the plane z = x
+#[derive(Clone, Debug)]
+struct SlantedPlane;
+
+impl ParametricSurface for SlantedPlane {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, u) }
+    fn uder(&self, _: f64, _: f64) -> Vector3 { Vector3::new(1.0, 0.0, 1.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_y() }
+    fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+}
+
+impl ParametricSurface3D for SlantedPlane {}
+
+fn polyline_length(poly: &IntersectionPolyline) -> f64 {
+    poly.points.windows(2).map(|p| p[0].distance(p[1])).sum()
+}
+
+// the circumference of the ellipse with the semi-axes sqrt(2) and 1 by Ramanujan's formula
+fn ellipse_circumference() -> f64 {
+    let (a, b) = (f64::sqrt(2.0), 1.0);
+    let h = ((a - b) / (a + b)).powi(2);
+    PI * (a + b) * (1.0 + 3.0 * h / (10.0 + f64::sqrt(4.0 - 3.0 * h)))
+}
+
+#[test]
+fn line_piercing_plane() {
+    let line = Line(Point3::new(0.2, 0.3, -1.0), Vector3::new(0.1, 0.2, 2.0));
+    let hits = curve_surface(&line, (0.0, 1.0), &Plane, ((-1.0, 1.0), (-1.0, 1.0)), 0.01);
+    assert_eq!(hits.len(), 1);
+    match hits[0] {
+        CurveSurfaceHit::Point { t, uv, point } => {
+            assert_near!(t, 0.5);
+            assert_near!(Point2::from(uv), Point2::new(0.25, 0.4));
+            assert_near!(point, Point3::new(0.25, 0.4, 0.0));
+        }
+        _ => panic!("the line does not lie on the plane."),
+    }
+
+    let hits = curve_surface(&line, (0.0, 0.4), &Plane, ((-1.0, 1.0), (-1.0, 1.0)), 0.01);
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn line_tangent_to_cylinder() {
+    let line = Line(Point3::new(-1.0, 1.0, 0.5), Vector3::new(2.0, 0.0, 0.0));
+    let range = ((0.0, 2.0 * PI), (0.0, 1.0));
+    let hits = curve_surface(&line, (0.0, 1.0), &Cylinder, range, 0.01);
+    assert_eq!(hits.len(), 1);
+    match hits[0] {
+        CurveSurfaceHit::Point { t, uv, point } => {
+            assert!(f64::abs(t - 0.5) < 1.0e-3);
+            assert!(Cylinder.subs(uv.0, uv.1).distance(point) < 0.01);
+            assert!(point.distance(Point3::new(0.0, 1.0, 0.5)) < 1.0e-3);
+        }
+        _ => panic!("the line does not lie on the cylinder."),
+    }
+}
+
+#[test]
+fn circle_on_plane() {
+    let circle = Circle(0.5);
+    let hits = curve_surface(
+        &circle,
+        (0.0, 2.0 * PI),
+        &Plane,
+        ((-1.0, 1.0), (-1.0, 1.0)),
+        0.01,
+    );
+    assert_eq!(
+        hits,
+        vec![CurveSurfaceHit::OnSurface {
+            t_range: (0.0, 2.0 * PI)
+        }]
+    );
+}
+
+#[test]
+fn cylinder_slanted_plane() {
+    let rect0 = ((0.0, 2.0 * PI), (-2.0, 2.0));
+    let rect1 = ((-2.0, 2.0), (-2.0, 2.0));
+    let polys = surface_surface(&Cylinder, rect0, &SlantedPlane, rect1, 0.001).unwrap();
+    assert_eq!(polys.len(), 1);
+    let poly = &polys[0];
+    assert!(poly.is_closed());
+    assert!(f64::abs(polyline_length(poly) - ellipse_circumference()) < 0.05);
+    poly.points
+        .iter()
+        .zip(&poly.params0)
+        .zip(&poly.params1)
+        .for_each(|((p, uv0), uv1)| {
+            assert_near!(p.x, p.z);
+            assert_near!(Cylinder.subs(uv0.x, uv0.y), *p);
+            assert_near!(SlantedPlane.subs(uv1.x, uv1.y), *p);
+        });
+}
+
+#[test]
+fn perpendicular_cylinders() {
+    let rect = ((0.0, 2.0 * PI), (-2.0, 2.0));
+    let polys = surface_surface(&Cylinder, rect, &XCylinder, rect, 0.01).unwrap();
+    let length = polys.iter().map(polyline_length).sum::<f64>();
+    assert!(f64::abs(length - 2.0 * ellipse_circumference()) < 0.2);
+    polys.iter().flat_map(|poly| &poly.points).for_each(|p| {
+        assert!(f64::abs(p.x.abs() - p.z.abs()) < 0.01);
+        assert!(f64::abs(p.x * p.x + p.y * p.y - 1.0) < 0.01);
+        assert!(f64::abs(p.y * p.y + p.z * p.z - 1.0) < 0.01);
+    });
+}
@@ -1,8 +1,50 @@
-use truck_base::{cgmath64::*, tolerance::*};
+use std::f64::consts::PI;
+use std::ops::Bound;
+use truck_base::{assert_near, bounding_box::BoundingBox, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
 mod polynomial;
 use polynomial::{PolyCurve, PolySurface};
 
+#[test]
+fn polysurface_der_mn() {
+    let coef0 = vec![
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(3.0, 1.0, 0.0),
+        Vector3::new(2.0, 0.0, 0.0),
+    ];
+    let coef1 = vec![
+        Vector3::new(2.0, 1.0, 0.0),
+        Vector3::new(-6.0, 0.0, 1.0),
+        Vector3::new(4.0, 0.0, 0.0),
+    ];
+    let poly = PolySurface(PolyCurve(coef0), PolyCurve(coef1));
+    for i in 0..=4 {
+        let u = 0.25 * i as f64;
+        for j in 0..=4 {
+            let v = 0.25 * j as f64;
+            assert_eq!(poly.der_mn(0, 0, u, v), poly.subs(u, v).to_vec());
+            assert_eq!(poly.der_mn(1, 0, u, v), poly.uder(u, v));
+            assert_eq!(poly.der_mn(0, 1, u, v), poly.vder(u, v));
+            assert_eq!(poly.der_mn(2, 0, u, v), poly.uuder(u, v));
+            assert_eq!(poly.der_mn(1, 1, u, v), poly.uvder(u, v));
+            assert_eq!(poly.der_mn(0, 2, u, v), poly.vvder(u, v));
+            // the first components of `(2u^2 + 3u + 1)(4v^2 - 6v + 2)`
+            let ans = [
+                ((2, 1), 4.0 * (8.0 * v - 6.0)),
+                ((1, 2), (4.0 * u + 3.0) * 8.0),
+                ((2, 2), 32.0),
+                ((3, 0), 0.0),
+                ((0, 3), 0.0),
+                ((3, 1), 0.0),
+            ];
+            for ((m, n), ans) in ans {
+                let res = poly.der_mn(m, n, u, v)[0];
+                assert!(f64::abs(res - ans) < 1.0e-4 * f64::max(ans.abs(), 1.0));
+            }
+        }
+    }
+}
+
 #[test]
 fn polysurface() {
     let coef0 = vec![
@@ -168,6 +210,268 @@ fn polysurface_sp_on_surface() {
     assert!(flag, "too many failure");
 }
 
+// the unit sphere with the poles at `u = 0` and `u = PI`
+#[derive(Clone, Debug)]
+struct Sphere;
+
+impl ParametricSurface for Sphere {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        Point3::new(u.sin() * v.cos(), u.sin() * v.sin(), u.cos())
+    }
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(u.cos() * v.cos(), u.cos() * v.sin(), -u.sin())
+    }
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.sin() * v.sin(), u.sin() * v.cos(), 0.0)
+    }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { -self.subs(u, v).to_vec() }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.cos() * v.sin(), u.cos() * v.cos(), 0.0)
+    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.sin() * v.cos(), -u.sin() * v.sin(), 0.0)
+    }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Bound::Included(0.0), Bound::Included(PI)),
+            (Bound::Included(0.0), Bound::Excluded(2.0 * PI)),
+        )
+    }
+    fn v_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+impl ParametricSurface3D for Sphere {}
+
+// the cone with the apex at the origin and `v = 0`
+#[derive(Clone, Debug)]
+struct Cone;
+
+impl ParametricSurface for Cone {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(v * u.cos(), v * u.sin(), v) }
+    fn uder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(-v * u.sin(), v * u.cos(), 0.0) }
+    fn vder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(u.cos(), u.sin(), 1.0) }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(-v * u.cos(), -v * u.sin(), 0.0) }
+    fn uvder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.sin(), u.cos(), 0.0) }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Bound::Included(0.0), Bound::Excluded(2.0 * PI)),
+            (Bound::Included(0.0), Bound::Included(1.0)),
+        )
+    }
+    fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+impl ParametricSurface3D for Cone {}
+
+#[test]
+fn search_parameter_at_singularity() {
+    let pt = Sphere.subs(1.0e-4, 2.0);
+    let (u, v) = algo::surface::search_parameter3d(&Sphere, pt, (0.3, 0.5), 100).unwrap();
+    assert_near!(Sphere.subs(u, v), pt);
+
+    let pt = Point3::new(0.0, 0.0, 2.0);
+    let (u, _) = algo::surface::search_nearest_parameter(&Sphere, pt, (0.3, 0.5), 100).unwrap();
+    assert_near!(u, 0.0);
+
+    let pt = Point3::origin();
+    let (u, v) = algo::surface::search_parameter3d(&Cone, pt, (0.3, 0.5), 100).unwrap();
+    assert_near!(Cone.subs(u, v), pt);
+    assert_near!(v, 0.0);
+
+    let options = algo::surface::NewtonOptions {
+        line_search_trials: 0,
+        ..Default::default()
+    };
+    let pt = Cone.subs(1.0, 1.0e-3);
+    let res = algo::surface::search_parameter3d_with_options(&Cone, pt, (0.3, 0.5), 100, &options);
+    let (u, v) = res.unwrap();
+    assert_near!(Cone.subs(u, v), pt);
+}
+
+// the surface `(u^3, v^3)` whose derivations vanish at the origin
+#[derive(Clone, Debug)]
+struct Pinched;
+
+impl ParametricSurface for Pinched {
+    type Point = Point2;
+    type Vector = Vector2;
+    fn subs(&self, u: f64, v: f64) -> Point2 { Point2::new(u * u * u, v * v * v) }
+    fn uder(&self, u: f64, _: f64) -> Vector2 { Vector2::new(3.0 * u * u, 0.0) }
+    fn vder(&self, _: f64, v: f64) -> Vector2 { Vector2::new(0.0, 3.0 * v * v) }
+    fn uuder(&self, u: f64, _: f64) -> Vector2 { Vector2::new(6.0 * u, 0.0) }
+    fn uvder(&self, _: f64, _: f64) -> Vector2 { Vector2::zero() }
+    fn vvder(&self, _: f64, v: f64) -> Vector2 { Vector2::new(0.0, 6.0 * v) }
+}
+
+#[test]
+fn search_parameter_errors() {
+    let pt = Point2::new(1.0, 0.0);
+    let at = (0.0, 0.0);
+    let res = algo::surface::try_search_parameter2d(&Pinched, pt, at, 100);
+    assert_eq!(res, Err(SearchError::VanishingDerivative { at }));
+
+    let pt = Sphere.subs(1.0, 2.0);
+    let res = algo::surface::try_search_parameter3d(&Sphere, pt, (0.3, 0.5), 0);
+    match res {
+        Err(SearchError::NotConverged { residual, .. }) => assert!(residual > TOLERANCE),
+        _ => panic!("unexpected result: {res:?}"),
+    }
+    let res = algo::surface::try_search_parameter3d(&Sphere, pt, (0.3, 0.5), 100);
+    assert_near!(Sphere.subs(res.unwrap().0, res.unwrap().1), pt);
+
+    let pt = Point3::new(2.0, 0.0, 0.0);
+    let res = algo::surface::try_search_parameter3d(&Sphere, pt, (PI / 2.0, 0.0), 100);
+    match res {
+        Err(SearchError::OffGeometry { nearest, distance }) => {
+            let (u, v) = nearest;
+            assert_near!(Sphere.subs(u, v), Point3::new(1.0, 0.0, 0.0));
+            assert_near!(distance, 1.0);
+        }
+        _ => panic!("unexpected result: {res:?}"),
+    }
+}
+
+// the wavy patch `(u, v, sin(2 PI u) sin(2 PI v) / 5)` on `[0, 1] x [0, 1]`
+#[derive(Clone, Debug)]
+struct Wave;
+
+impl ParametricSurface for Wave {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        let (su, sv) = (f64::sin(2.0 * PI * u), f64::sin(2.0 * PI * v));
+        Point3::new(u, v, su * sv / 5.0)
+    }
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        let (cu, sv) = (f64::cos(2.0 * PI * u), f64::sin(2.0 * PI * v));
+        Vector3::new(1.0, 0.0, 2.0 * PI * cu * sv / 5.0)
+    }
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        let (su, cv) = (f64::sin(2.0 * PI * u), f64::cos(2.0 * PI * v));
+        Vector3::new(0.0, 1.0, 2.0 * PI * su * cv / 5.0)
+    }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(0.0, 0.0, -4.0 * PI * PI * self.subs(u, v).z)
+    }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        let (cu, cv) = (f64::cos(2.0 * PI * u), f64::cos(2.0 * PI * v));
+        Vector3::new(0.0, 0.0, 4.0 * PI * PI * cu * cv / 5.0)
+    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(0.0, 0.0, -4.0 * PI * PI * self.subs(u, v).z)
+    }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Bound::Included(0.0), Bound::Included(1.0)),
+            (Bound::Included(0.0), Bound::Included(1.0)),
+        )
+    }
+}
+
+impl ParametricSurface3D for Wave {}
+
+#[test]
+fn search_parameter_along_direction() {
+    let (pt, dir) = (Point3::new(0.3, 0.65, 2.0), -Vector3::unit_z());
+    let (u, v) =
+        algo::surface::search_parameter_along_direction(&Wave, pt, dir, (0.5, 0.5), 100).unwrap();
+    assert_near!(u, 0.3);
+    assert_near!(v, 0.65);
+
+    let dir = Vector3::new(0.1, -0.2, -1.0);
+    let options = Default::default();
+    let res = algo::surface::search_parameter_along_direction_with_options(
+        &Wave,
+        pt,
+        dir,
+        (0.5, 0.5),
+        100,
+        &options,
+    );
+    let (u, v, s) = res.unwrap();
+    assert!(s > 0.0);
+    assert_near!(Wave.subs(u, v), pt + dir * s);
+
+    // the ray pierces the sphere twice, and the hint is near the farther hit
+    let (pt, dir) = (Point3::new(0.3, 0.2, 2.0), -Vector3::unit_z());
+    let z = f64::sqrt(1.0 - 0.13);
+    let (u, v) =
+        algo::surface::search_parameter_along_direction(&Sphere, pt, dir, (3.0, 0.5), 100).unwrap();
+    assert_near!(Sphere.subs(u, v), Point3::new(0.3, 0.2, z));
+
+    // the point inside the sphere
+    let pt = Point3::new(0.3, 0.2, 0.5);
+    let res = algo::surface::search_parameter_along_direction_with_options(
+        &Sphere,
+        pt,
+        dir,
+        (0.3, 0.5),
+        100,
+        &options,
+    );
+    assert_near!(res.unwrap().2, 0.5 + z);
+    let options = algo::surface::DirectionalProjectionOptions {
+        allow_negative: true,
+        ..Default::default()
+    };
+    let res = algo::surface::search_parameter_along_direction_with_options(
+        &Sphere,
+        pt,
+        dir,
+        (0.3, 0.5),
+        100,
+        &options,
+    );
+    assert_near!(res.unwrap().2, 0.5 - z);
+}
+
+// the unit cylinder around the z-axis with the height 10
+#[derive(Clone, Debug)]
+struct Cylinder;
+
+impl ParametricSurface for Cylinder {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u.cos(), u.sin(), v) }
+    fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.sin(), u.cos(), 0.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_z() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.cos(), -u.sin(), 0.0) }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Bound::Included(0.0), Bound::Excluded(2.0 * PI)),
+            (Bound::Included(0.0), Bound::Included(10.0)),
+        )
+    }
+    fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+
+impl ParametricSurface3D for Cylinder {}
+
+#[test]
+fn cylinder_adaptive_division() {
+    let range = ((0.0, 2.0 * PI), (0.0, 10.0));
+    let (udiv, vdiv) = algo::surface::parameter_division_adaptive(&Cylinder, range, 0.01, 0.1);
+    assert_eq!(vdiv, vec![0.0, 10.0]);
+    assert!(udiv.len() > 30 * vdiv.len());
+    udiv.windows(2).for_each(|u| {
+        assert!(u[1] - u[0] <= 0.1);
+        let mid = Cylinder.subs((u[0] + u[1]) / 2.0, 0.0);
+        let chord = Cylinder.subs(u[0], 0.0).midpoint(Cylinder.subs(u[1], 0.0));
+        assert!(mid.distance(chord) <= 0.01);
+    });
+
+    // the chordal division refines the both directions
+    let (_, vdiv) = algo::surface::parameter_division(&Cylinder, range, 0.01);
+    assert!(vdiv.len() > 2);
+}
+
 fn exec_polysurface_division() -> bool {
     let coef0 = vec![
         Vector3::new(0.0, 1.0, 10.0 * rand::random::<f64>() - 5.0),
@@ -213,3 +517,29 @@ fn polysurface_division() {
     let count = (0..10).filter(|_| exec_polysurface_division()).count();
     assert!(count > 8, "wrong answer: {:?}", 10 - count);
 }
+
+#[test]
+fn sphere_bounding_box() {
+    let ((u0, u1), (v0, v1)) = ((0.2, 2.9), (0.1, 3.0));
+    let tol = 1.0e-3;
+    let bdb = algo::surface::bounding_box(&Sphere, ((u0, u1), (v0, v1)), tol);
+    (0..=100).for_each(|i| {
+        (0..=100).for_each(|j| {
+            let u = u0 + (u1 - u0) * i as f64 / 100.0;
+            let v = v0 + (v1 - v0) * j as f64 / 100.0;
+            assert!(bdb.contains(Sphere.subs(u, v)));
+        })
+    });
+
+    // the maximum of `y` at `(PI / 2, PI / 2)` lies between the initial samples
+    let samples = (0..=16)
+        .flat_map(|i| (0..=16).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let u = u0 + (u1 - u0) * i as f64 / 16.0;
+            let v = v0 + (v1 - v0) * j as f64 / 16.0;
+            Sphere.subs(u, v)
+        })
+        .collect::<BoundingBox<_>>();
+    assert!(samples.max().y < 1.0 - 1.0e-4);
+    assert!(1.0 <= bdb.max().y && bdb.max().y <= 1.0 + tol);
+}
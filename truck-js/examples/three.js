@@ -0,0 +1,101 @@
+import * as THREE from "three";
+import { OrbitControls } from "three/addons/controls/OrbitControls.js";
+import init, * as Truck from "./truck_js.js";
+await init();
+
+const hexagon = [...Array(6).keys()].flatMap((i) => {
+  const t = (i * Math.PI) / 3.0;
+  return [0.5 * Math.cos(t), 0.5 * Math.sin(t), 0.0];
+});
+const profile = [0.5, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.5, 0.5, 0.0, 0.5];
+
+const primitives = {
+  cuboid: () => Truck.cuboid([-0.5, -0.5, -0.5], [1.0, 1.0, 1.0]),
+  cylinder: () => Truck.cylinder([0.0, 0.0, -0.5], [0.0, 0.0, 1.0], 0.5, 1.0),
+  sphere: () => Truck.sphere([0.0, 0.0, 0.0], 0.5),
+  extrude: () => Truck.extrude_polygon(hexagon, [0.0, 0.0, 1.0]),
+  revolve: () =>
+    Truck.revolve_polygon(profile, [0.0, 0.0, 0.0], [0.0, 0.0, 1.0], 1.5 * Math.PI),
+};
+
+const renderer = new THREE.WebGLRenderer({ antialias: true });
+renderer.setSize(window.innerWidth, window.innerHeight);
+document.body.appendChild(renderer.domElement);
+
+const scene = new THREE.Scene();
+scene.background = new THREE.Color(0x303030);
+scene.add(new THREE.AmbientLight(0xffffff, 0.4));
+const light = new THREE.DirectionalLight(0xffffff, 1.5);
+light.position.set(1.0, 2.0, 3.0);
+scene.add(light);
+
+const camera = new THREE.PerspectiveCamera(
+  45,
+  window.innerWidth / window.innerHeight,
+  0.1,
+  100.0,
+);
+camera.position.set(2.0, 2.0, 2.0);
+const controls = new OrbitControls(camera, renderer.domElement);
+
+const material = new THREE.MeshStandardMaterial({ color: 0xc0a060 });
+let mesh = null;
+let tessellation = null;
+
+function rebuild() {
+  const primitive = document.getElementById("primitive").value;
+  const tol = parseFloat(document.getElementById("tolerance").value);
+  try {
+    tessellation = primitives[primitive]().tessellate(tol);
+  } catch (e) {
+    // errors of truck are thrown as `Error` with the message of the Rust error.
+    alert(e.message);
+    return;
+  }
+  const geometry = new THREE.BufferGeometry();
+  geometry.setAttribute(
+    "position",
+    new THREE.BufferAttribute(tessellation.positions(), 3),
+  );
+  geometry.setAttribute(
+    "normal",
+    new THREE.BufferAttribute(tessellation.normals(), 3),
+  );
+  geometry.setIndex(new THREE.BufferAttribute(tessellation.indices(), 1));
+  if (mesh !== null) {
+    scene.remove(mesh);
+    mesh.geometry.dispose();
+  }
+  mesh = new THREE.Mesh(geometry, material);
+  scene.add(mesh);
+}
+
+function download(data, fileName) {
+  const a = document.createElement("a");
+  a.href = URL.createObjectURL(new Blob([data]));
+  a.download = fileName;
+  a.click();
+  URL.revokeObjectURL(a.href);
+}
+
+document.getElementById("primitive").addEventListener("change", rebuild);
+document.getElementById("tolerance").addEventListener("change", rebuild);
+document.getElementById("download-obj").addEventListener(
+  "click",
+  () => download(tessellation.to_obj(), "mesh.obj"),
+);
+document.getElementById("download-stl").addEventListener(
+  "click",
+  () => download(tessellation.to_stl(Truck.StlType.Binary), "mesh.stl"),
+);
+window.addEventListener("resize", () => {
+  camera.aspect = window.innerWidth / window.innerHeight;
+  camera.updateProjectionMatrix();
+  renderer.setSize(window.innerWidth, window.innerHeight);
+});
+
+rebuild();
+renderer.setAnimationLoop(() => {
+  controls.update();
+  renderer.render(scene, camera);
+});
@@ -1,5 +1,6 @@
-use crate::{wasm_bindgen, AbstractShape, Edge, Face, IntoWasm, Vertex, Wire};
+use crate::{wasm_bindgen, AbstractShape, Edge, Face, IntoWasm, Solid, Vertex, Wire};
 use truck_modeling::*;
+use wasm_bindgen::JsError;
 
 macro_rules! intopt {
     ($type: ty, $slice: ident) => {
@@ -52,6 +53,118 @@ pub fn try_attach_plane(wire: &Wire) -> Option<Face> {
         .ok()
 }
 
+/// Converts the slice `slice` named `name` to three coordinates.
+fn coordinates(slice: &[f64], name: &str) -> Result<[f64; 3], JsError> {
+    <[f64; 3]>::try_from(slice)
+        .map_err(|_| JsError::new(&format!("{name} is not a 3-dimensional vector.")))
+}
+
+/// Returns the closed wire through the points `points`, the flat array of the coordinates.
+fn polygon_wire(points: &[f64]) -> Result<truck_modeling::Wire, JsError> {
+    if points.len() % 3 != 0 || points.len() < 9 {
+        return Err(JsError::new(
+            "points must be the coordinates of at least 3 points.",
+        ));
+    }
+    let points: Vec<Point3> = points
+        .chunks(3)
+        .map(|p| Point3::new(p[0], p[1], p[2]))
+        .collect();
+    let vertices = truck_modeling::Vertex::news(&points);
+    Ok((0..vertices.len())
+        .map(|i| builder::line(&vertices[i], &vertices[(i + 1) % vertices.len()]))
+        .collect())
+}
+
+/// Returns the box whose corner is `origin` and whose edges are parallel to the axes and have
+/// the lengths `size`.
+#[wasm_bindgen]
+pub fn cuboid(origin: &[f64], size: &[f64]) -> Result<Solid, JsError> {
+    let origin = Point3::from(coordinates(origin, "origin")?);
+    let [x, y, z] = coordinates(size, "size")?;
+    if [x, y, z]
+        .iter()
+        .any(|length| length.is_nan() || *length <= 0.0)
+    {
+        return Err(JsError::new("The lengths of the edges must be positive."));
+    }
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, x * Vector3::unit_x());
+    let f = builder::tsweep(&e, y * Vector3::unit_y());
+    Ok(builder::tsweep(&f, z * Vector3::unit_z()).into())
+}
+
+/// Returns the cylinder whose base is the disk with the center `base_center` and the radius
+/// `radius`, and whose height is `height` in the direction `axis`.
+#[wasm_bindgen]
+pub fn cylinder(
+    base_center: &[f64],
+    axis: &[f64],
+    radius: f64,
+    height: f64,
+) -> Result<Solid, JsError> {
+    let base_center = Point3::from(coordinates(base_center, "base_center")?);
+    let axis = Vector3::from(coordinates(axis, "axis")?);
+    if axis.so_small() {
+        return Err(JsError::new("axis must not be zero."));
+    }
+    if radius.is_nan() || height.is_nan() || radius <= 0.0 || height <= 0.0 {
+        return Err(JsError::new("The radius and the height must be positive."));
+    }
+    Ok(builder::frustum(base_center, axis, radius, radius, height).into())
+}
+
+/// Returns the sphere with the center `center` and the radius `radius`.
+#[wasm_bindgen]
+pub fn sphere(center: &[f64], radius: f64) -> Result<Solid, JsError> {
+    let center = Point3::from(coordinates(center, "center")?);
+    if radius.is_nan() || radius <= 0.0 {
+        return Err(JsError::new("The radius must be positive."));
+    }
+    Ok(builder::sphere(center, radius).into())
+}
+
+/// Returns the prism made by extruding the planar polygon `points` by `vector`.
+///
+/// `points` is the flat array of the coordinates of the vertices of the polygon.
+#[wasm_bindgen]
+pub fn extrude_polygon(points: &[f64], vector: &[f64]) -> Result<Solid, JsError> {
+    let vector = Vector3::from(coordinates(vector, "vector")?);
+    if vector.so_small() {
+        return Err(JsError::new("vector must not be zero."));
+    }
+    let mut face = builder::try_attach_plane(&[polygon_wire(points)?])?;
+    if face.oriented_surface().normal(0.0, 0.0).dot(vector) < 0.0 {
+        face.invert();
+    }
+    Ok(builder::tsweep(&face, vector).into())
+}
+
+/// Returns the solid made by revolving the planar polygon `points` around the axis through
+/// `origin` with the direction `axis` by `angle` radians.
+///
+/// `points` is the flat array of the coordinates of the vertices of the polygon. If the absolute
+/// value of `angle` is greater than `2π`, the polygon is revolved wholly.
+#[wasm_bindgen]
+pub fn revolve_polygon(
+    points: &[f64],
+    origin: &[f64],
+    axis: &[f64],
+    angle: f64,
+) -> Result<Solid, JsError> {
+    let origin = Point3::from(coordinates(origin, "origin")?);
+    let axis = Vector3::from(coordinates(axis, "axis")?);
+    if axis.so_small() {
+        return Err(JsError::new("axis must not be zero."));
+    }
+    if !angle.is_finite() || angle.so_small() {
+        return Err(JsError::new("angle must be a non-zero finite number."));
+    }
+    let profile = polygon_wire(points)?;
+    let range = (Rad(0.0), Rad(angle));
+    Ok(builder::try_partial_rsweep(&profile, origin, axis, range)?.into())
+}
+
 macro_rules! transform_if_chain {
     ($shape: expr, $function: expr, ($($arg: expr),*), $exception: expr, $member: ident) => {
         if let Some(entity) = AbstractShape::$member($shape) {
@@ -1,4 +1,5 @@
 use crate::*;
+use js_sys::{Float32Array, Uint32Array, Uint8Array};
 use truck_meshalgo::prelude::*;
 
 /// Wasm wrapper by Polygonmesh
@@ -49,6 +50,56 @@ pub struct PolygonBuffer {
     indices: Vec<u32>,
 }
 
+/// Tessellated mesh whose buffers are passed to javascript as typed arrays.
+///
+/// The positions and the normals are given for each vertex, and the indices for each triangle.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Tessellation {
+    mesh: truck_meshalgo::prelude::PolygonMesh,
+    positions: Vec<f32>,
+    normals: Vec<f32>,
+    indices: Vec<u32>,
+}
+
+impl From<truck_meshalgo::prelude::PolygonMesh> for Tessellation {
+    fn from(mesh: truck_meshalgo::prelude::PolygonMesh) -> Tessellation {
+        let exp = mesh.expands(|attr| {
+            let position = attr.position;
+            let normal = attr.normal.unwrap_or_else(Vector3::zero);
+            [
+                position[0] as f32,
+                position[1] as f32,
+                position[2] as f32,
+                normal[0] as f32,
+                normal[1] as f32,
+                normal[2] as f32,
+            ]
+        });
+        Tessellation {
+            positions: exp
+                .attributes()
+                .iter()
+                .flat_map(|a| &a[..3])
+                .copied()
+                .collect(),
+            normals: exp
+                .attributes()
+                .iter()
+                .flat_map(|a| &a[3..])
+                .copied()
+                .collect(),
+            indices: exp
+                .faces()
+                .triangle_iter()
+                .flatten()
+                .map(|x| x as u32)
+                .collect(),
+            mesh,
+        }
+    }
+}
+
 #[wasm_bindgen]
 impl PolygonMesh {
     /// input from obj format
@@ -132,6 +183,37 @@ impl PolygonMesh {
     pub fn merge(&mut self, other: PolygonMesh) { self.0.merge(other.0); }
 }
 
+#[wasm_bindgen]
+impl Tessellation {
+    /// positions of the vertices. Each vertex has `[f32; 3]`.
+    #[inline(always)]
+    pub fn positions(&self) -> Float32Array { Float32Array::from(self.positions.as_slice()) }
+    /// normals of the vertices. Each vertex has `[f32; 3]`.
+    #[inline(always)]
+    pub fn normals(&self) -> Float32Array { Float32Array::from(self.normals.as_slice()) }
+    /// indices of the vertices. Each triangle has `[u32; 3]`.
+    #[inline(always)]
+    pub fn indices(&self) -> Uint32Array { Uint32Array::from(self.indices.as_slice()) }
+    /// the number of the vertices
+    #[inline(always)]
+    pub fn vertex_count(&self) -> usize { self.positions.len() / 3 }
+    /// the number of the triangles
+    #[inline(always)]
+    pub fn triangle_count(&self) -> usize { self.indices.len() / 3 }
+    /// output obj format
+    pub fn to_obj(&self) -> Result<Uint8Array, JsError> {
+        let mut res = Vec::new();
+        obj::write(&self.mesh, &mut res)?;
+        Ok(Uint8Array::from(res.as_slice()))
+    }
+    /// output stl format
+    pub fn to_stl(&self, stl_type: StlType) -> Result<Uint8Array, JsError> {
+        let mut res = Vec::new();
+        stl::write(&self.mesh, &mut res, stl_type.into())?;
+        Ok(Uint8Array::from(res.as_slice()))
+    }
+}
+
 #[wasm_bindgen]
 impl PolygonBuffer {
     /// vertex buffer. One attribute contains `position: [f32; 3]`, `uv_coord: [f32; 2]` and `normal: [f32; 3]`.
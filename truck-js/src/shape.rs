@@ -154,6 +154,17 @@ macro_rules! impl_shape {
             pub fn to_polygon(&self, tol: f64) -> PolygonMesh {
                 self.triangulation(tol).to_polygon().into_wasm()
             }
+            /// Tessellates the shape within the tolerance `tol`, and returns the buffers.
+            pub fn tessellate(&self, tol: f64) -> Result<Tessellation, JsError> {
+                if tol.is_nan() || tol <= 0.0 {
+                    return Err(JsError::new("The tolerance must be positive."));
+                }
+                let triangulated = self.triangulation(tol);
+                if triangulated.face_iter().any(|face| face.surface().is_none()) {
+                    return Err(JsError::new("Failed to tessellate some faces."));
+                }
+                Ok(triangulated.to_polygon().into())
+            }
             /// read shape from json
             pub fn from_json(data: &[u8]) -> Option<$type> {
                 serde_json::from_reader::<_, truck_modeling::$type>(data)
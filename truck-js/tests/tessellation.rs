@@ -0,0 +1,84 @@
+//! Run by `wasm-pack test --node`.
+#![cfg(target_arch = "wasm32")]
+
+use truck_js::*;
+use wasm_bindgen_test::*;
+
+fn check_buffers(tessellation: &Tessellation) {
+    let positions = tessellation.positions().to_vec();
+    let normals = tessellation.normals().to_vec();
+    let indices = tessellation.indices().to_vec();
+    assert_eq!(positions.len(), 3 * tessellation.vertex_count());
+    assert_eq!(normals.len(), positions.len());
+    assert_eq!(indices.len(), 3 * tessellation.triangle_count());
+    assert!(indices
+        .iter()
+        .all(|i| (*i as usize) < tessellation.vertex_count()));
+}
+
+#[wasm_bindgen_test]
+fn tessellate_cuboid() {
+    let cube = builder::cuboid(&[0.0, 0.0, 0.0], &[1.0, 2.0, 3.0]).unwrap();
+    let tessellation = cube.tessellate(0.01).unwrap();
+    check_buffers(&tessellation);
+    assert_eq!(tessellation.triangle_count(), 12);
+    let positions = tessellation.positions().to_vec();
+    let max = positions.chunks(3).fold([0.0f32; 3], |max, p| {
+        [max[0].max(p[0]), max[1].max(p[1]), max[2].max(p[2])]
+    });
+    assert_eq!(max, [1.0, 2.0, 3.0]);
+    let normals = tessellation.normals().to_vec();
+    normals.chunks(3).for_each(|n| {
+        let len = f32::sqrt(n[0] * n[0] + n[1] * n[1] + n[2] * n[2]);
+        assert!(f32::abs(len - 1.0) < 1.0e-3, "{n:?}");
+    });
+}
+
+#[wasm_bindgen_test]
+fn tessellate_primitives() {
+    let solids = [
+        builder::cylinder(&[0.0, 0.0, 0.0], &[0.0, 0.0, 1.0], 1.0, 2.0).unwrap(),
+        builder::sphere(&[1.0, 2.0, 3.0], 0.5).unwrap(),
+        builder::extrude_polygon(
+            &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            &[0.0, 0.0, -1.0],
+        )
+        .unwrap(),
+        builder::revolve_polygon(
+            &[1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            &[0.0, 0.0, 0.0],
+            &[0.0, 0.0, 1.0],
+            std::f64::consts::PI,
+        )
+        .unwrap(),
+    ];
+    solids.iter().for_each(|solid| {
+        let tessellation = solid.tessellate(0.01).unwrap();
+        check_buffers(&tessellation);
+        assert!(tessellation.triangle_count() > 0);
+    });
+}
+
+#[wasm_bindgen_test]
+fn export_tessellation() {
+    let cube = builder::cuboid(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0]).unwrap();
+    let tessellation = cube.tessellate(0.01).unwrap();
+    let obj = String::from_utf8(tessellation.to_obj().unwrap().to_vec()).unwrap();
+    assert_eq!(
+        obj.lines().filter(|line| line.starts_with("f ")).count(),
+        12
+    );
+    let stl = tessellation.to_stl(StlType::Binary).unwrap().to_vec();
+    // header (80 bytes), the number of triangles (4 bytes), and 50 bytes for each triangle
+    assert_eq!(stl.len(), 84 + 50 * 12);
+}
+
+#[wasm_bindgen_test]
+fn invalid_arguments() {
+    assert!(builder::cuboid(&[0.0, 0.0], &[1.0, 1.0, 1.0]).is_err());
+    assert!(builder::cuboid(&[0.0, 0.0, 0.0], &[1.0, 0.0, 1.0]).is_err());
+    assert!(builder::sphere(&[0.0, 0.0, 0.0], f64::NAN).is_err());
+    assert!(builder::extrude_polygon(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0], &[0.0, 0.0, 1.0]).is_err());
+    let cube = builder::cuboid(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0]).unwrap();
+    assert!(cube.tessellate(0.0).is_err());
+}
@@ -0,0 +1,82 @@
+//! Compares `put_together_same_attrs` and `remove_unused_attrs` on a single thread and on the
+//! global thread pool of `rayon`.
+//!
+//! ```console
+//! cargo bench -p truck-meshalgo --bench put_together_same_attrs
+//! ```
+
+use std::time::{Duration, Instant};
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+const GRID_SIZE: usize = 1000;
+const ITERATIONS: u32 = 5;
+
+/// a grid of `n` x `n` quadrangles, whose positions and normals are not shared by the faces.
+fn separated_grid(n: usize) -> PolygonMesh {
+    let corners = [[0, 0], [1, 0], [1, 1], [0, 1]];
+    let positions = (0..n * n)
+        .flat_map(|k| {
+            let (i, j) = (k / n, k % n);
+            corners.map(|[di, dj]| {
+                let (x, y) = ((i + di) as f64, (j + dj) as f64);
+                Point3::new(x, y, f64::sin(x * 0.1) * f64::cos(y * 0.1))
+            })
+        })
+        .collect::<Vec<_>>();
+    let normals = vec![Vector3::unit_z(); positions.len()];
+    let faces = Faces::from_iter((0..n * n).map(|k| {
+        [0, 1, 2, 3].map(|l| StandardVertex {
+            pos: 4 * k + l,
+            uv: None,
+            nor: Some(4 * k + l),
+        })
+    }));
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+fn measure(mesh: &PolygonMesh) -> (Duration, PolygonMesh) {
+    let mut total = Duration::ZERO;
+    let mut res = mesh.clone();
+    for _ in 0..ITERATIONS {
+        res = mesh.clone();
+        let instant = Instant::now();
+        res.put_together_same_attrs(TOLERANCE).remove_unused_attrs();
+        total += instant.elapsed();
+    }
+    (total / ITERATIONS, res)
+}
+
+fn main() {
+    let mesh = separated_grid(GRID_SIZE);
+    println!(
+        "{} positions, {} faces",
+        mesh.positions().len(),
+        mesh.faces().len()
+    );
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let (serial, serial_res) = pool.install(|| measure(&mesh));
+    println!("serial:   {serial:?}");
+
+    let (parallel, parallel_res) = measure(&mesh);
+    println!(
+        "parallel: {parallel:?} ({} threads)",
+        rayon::current_num_threads()
+    );
+
+    // The results must not depend on the number of threads.
+    assert_eq!(serial_res, parallel_res);
+    let num_lattice_points = (GRID_SIZE + 1) * (GRID_SIZE + 1);
+    assert_eq!(serial_res.positions().len(), num_lattice_points);
+}
@@ -1,5 +1,6 @@
 use super::*;
 use array_macro::array;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 /// Find collisions between two polygon meshes and extract interference lines.
 ///
@@ -22,6 +23,48 @@ pub trait Collision {
     /// # Remarks
     /// The results is not arranged so that included lines make continuous maximal polyline curve.
     fn extract_interference(&self, other: &PolygonMesh) -> Vec<(Point3, Point3)>;
+    /// Extract the intersection curves between `self` and `other`.
+    ///
+    /// # Details
+    /// The pairs of intersecting triangles are found by traversing [`MeshBvh`]s of both meshes,
+    /// and the segments of each pair are calculated by Möller's triangle-triangle intersection.
+    /// The segments are chained into polylines. Closed curves are represented by the polylines
+    /// whose last points are the same as the first points.
+    ///
+    /// # Remarks
+    /// The overlaps of coplanar triangles and the intersections consisting of single points are
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let square = |positions: [Point3; 4]| {
+    ///     PolygonMesh::new(
+    ///         StandardAttributes {
+    ///             positions: positions.to_vec(),
+    ///             ..Default::default()
+    ///         },
+    ///         Faces::from_iter(&[[0, 1, 2, 3]]),
+    ///     )
+    /// };
+    /// let square0 = square([
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 2.0, 0.0),
+    ///     Point3::new(0.0, 2.0, 0.0),
+    /// ]);
+    /// let square1 = square([
+    ///     Point3::new(1.0, -1.0, -1.0),
+    ///     Point3::new(1.0, 3.0, -1.0),
+    ///     Point3::new(1.0, 3.0, 1.0),
+    ///     Point3::new(1.0, -1.0, 1.0),
+    /// ]);
+    /// let curves = square0.intersection_curves(&square1);
+    /// assert_eq!(curves.len(), 1);
+    /// let (front, back) = (curves[0][0], curves[0][curves[0].len() - 1]);
+    /// assert_near!(front.distance(back), 2.0);
+    /// ```
+    fn intersection_curves(&self, other: &PolygonMesh) -> Vec<PolylineCurve<Point3>>;
 }
 
 impl Collision for PolygonMesh {
@@ -33,6 +76,15 @@ impl Collision for PolygonMesh {
     fn extract_interference(&self, other: &PolygonMesh) -> Vec<(Point3, Point3)> {
         collision(self, other)
     }
+    fn intersection_curves(&self, other: &PolygonMesh) -> Vec<PolylineCurve<Point3>> {
+        let (bvh0, bvh1) = (MeshBvh::new(self), MeshBvh::new(other));
+        let segments = bvh0
+            .overlapping_triangles(&bvh1)
+            .into_iter()
+            .filter_map(|[tri0, tri1]| triangle_intersection(tri0, tri1))
+            .collect::<Vec<_>>();
+        chain_segments(&segments)
+    }
 }
 
 #[repr(u8)]
@@ -204,6 +256,122 @@ fn collide_triangles(tri0: [Point3; 3], tri1: [Point3; 3]) -> Option<(Point3, Po
     }
 }
 
+/// the segment where `tri` crosses the plane containing `plane`.
+/// Returns `None` if the intersection is empty, a point, or the whole triangle.
+fn section_by_plane(tri: [Point3; 3], plane: [Point3; 3]) -> Option<[Point3; 2]> {
+    let normal = (plane[1] - plane[0]).cross(plane[2] - plane[0]);
+    if normal.so_small() {
+        return None;
+    }
+    let normal = normal.normalize();
+    let dist = tri.map(|p| match (p - plane[0]).dot(normal) {
+        d if d.so_small() => 0.0,
+        d => d,
+    });
+    let zeros = dist.iter().filter(|d| **d == 0.0).count();
+    let one_side = dist.iter().all(|d| *d >= 0.0) || dist.iter().all(|d| *d <= 0.0);
+    if zeros == 3 || zeros < 2 && one_side {
+        return None;
+    }
+    let mut points = Vec::with_capacity(2);
+    (0..3).for_each(|i| {
+        let j = (i + 1) % 3;
+        if dist[i] == 0.0 {
+            points.push(tri[i]);
+        } else if dist[i] * dist[j] < 0.0 {
+            points.push(tri[i] + (tri[j] - tri[i]) * (dist[i] / (dist[i] - dist[j])));
+        }
+    });
+    Some([points[0], points[1]])
+}
+
+/// Möller's triangle-triangle intersection. Returns `None` for the coplanar triangles.
+fn triangle_intersection(tri0: [Point3; 3], tri1: [Point3; 3]) -> Option<(Point3, Point3)> {
+    let seg0 = section_by_plane(tri0, tri1)?;
+    let seg1 = section_by_plane(tri1, tri0)?;
+    let dir = seg0[1] - seg0[0];
+    let len = dir.magnitude();
+    if len.so_small() {
+        return None;
+    }
+    let dir = dir / len;
+    let (s0, s1) = (dir.dot(seg1[0] - seg0[0]), dir.dot(seg1[1] - seg0[0]));
+    let lower = f64::max(0.0, f64::min(s0, s1));
+    let upper = f64::min(len, f64::max(s0, s1));
+    match lower < upper {
+        true => Some((seg0[0] + dir * lower, seg0[0] + dir * upper)),
+        false => None,
+    }
+}
+
+/// Chains segments into polylines. The end points of the segments are identified if they are near.
+fn chain_segments(segments: &[(Point3, Point3)]) -> Vec<PolylineCurve<Point3>> {
+    let mut points = Vec::<Point3>::new();
+    let mut grid = HashMap::<[i64; 3], Vec<usize>>::default();
+    let mut point_index = |p: Point3| {
+        let cell = [p.x, p.y, p.z].map(|x| f64::floor(x / TOLERANCE) as i64);
+        let found = (0..27)
+            .map(|k| [k % 3 - 1, k / 3 % 3 - 1, k / 9 - 1])
+            .filter_map(|d| grid.get(&[cell[0] + d[0], cell[1] + d[1], cell[2] + d[2]]))
+            .find_map(|idcs| idcs.iter().copied().find(|i| points[*i].near(&p)));
+        match found {
+            Some(i) => i,
+            None => {
+                grid.entry(cell).or_default().push(points.len());
+                points.push(p);
+                points.len() - 1
+            }
+        }
+    };
+    let mut registered = HashSet::<[usize; 2]>::default();
+    let mut edges = Vec::<[usize; 2]>::new();
+    for (p, q) in segments {
+        let (i, j) = (point_index(*p), point_index(*q));
+        if i != j && registered.insert([usize::min(i, j), usize::max(i, j)]) {
+            edges.push([i, j]);
+        }
+    }
+
+    let mut adjacency = vec![Vec::<usize>::new(); points.len()];
+    edges.iter().enumerate().for_each(|(e, [i, j])| {
+        adjacency[*i].push(e);
+        adjacency[*j].push(e);
+    });
+    let mut used = vec![false; edges.len()];
+    // The end points and the branch points are the starts of open polylines.
+    // The remaining edges form closed polylines.
+    let starts = (0..points.len())
+        .filter(|i| adjacency[*i].len() != 2)
+        .chain(0..points.len())
+        .collect::<Vec<_>>();
+    let mut polylines = Vec::new();
+    for start in starts {
+        while let Some(mut e) = adjacency[start].iter().copied().find(|e| !used[*e]) {
+            let mut polyline = vec![start];
+            let mut cursor = start;
+            loop {
+                used[e] = true;
+                cursor = match edges[e] {
+                    [i, j] if i == cursor => j,
+                    [i, _] => i,
+                };
+                polyline.push(cursor);
+                if cursor == start || adjacency[cursor].len() != 2 {
+                    break;
+                }
+                match adjacency[cursor].iter().copied().find(|e| !used[*e]) {
+                    Some(next) => e = next,
+                    None => break,
+                }
+            }
+            polylines.push(PolylineCurve(
+                polyline.into_iter().map(|i| points[i]).collect(),
+            ));
+        }
+    }
+    polylines
+}
+
 fn make_pos_tri(poly: &PolygonMesh, face: [StandardVertex; 3]) -> [Point3; 3] {
     array![i => poly.positions()[face[i].pos]; 3]
 }
@@ -0,0 +1,136 @@
+use super::*;
+use std::f64::consts::PI;
+
+/// The discrete curvatures at a vertex.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VertexCurvature {
+    /// the Gaussian curvature
+    pub gaussian: f64,
+    /// the mean curvature, which is positive if the surface bends away from the normal,
+    /// e.g. a sphere with the outward normals.
+    pub mean: f64,
+}
+
+/// discrete curvatures of polygon meshes
+pub trait Curvature {
+    /// Returns the discrete curvatures at each position.
+    ///
+    /// # Details
+    /// The faces are divided into triangles in the same way as [`Faces::triangle_iter`], and
+    /// the curvatures are calculated by the method of Meyer, Desbrun, Schröder and Barr:
+    /// - the Gaussian curvature is the angle deficit divided by the mixed Voronoi area,
+    /// - the mean curvature is given by the mean curvature normal, the cotangent Laplacian of
+    /// the positions, and its direction is determined by the area-weighted vertex normal.
+    ///
+    /// The result is `None` for the positions on the boundary or the non-manifold edges, and
+    /// for the positions referenced by no faces.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // pyramid without the bottom
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(-1.0, -1.0, 0.0),
+    ///             Point3::new(1.0, -1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(-1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]]),
+    /// );
+    /// let curvatures = mesh.curvatures();
+    /// assert!(curvatures[0].is_none());
+    /// let apex = curvatures[4].unwrap();
+    /// assert!(apex.gaussian > 0.0);
+    /// assert!(apex.mean > 0.0);
+    /// ```
+    fn curvatures(&self) -> Vec<Option<VertexCurvature>>;
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CurvatureSum {
+    angle: f64,
+    area: f64,
+    laplacian: Vector3,
+    normal: Vector3,
+}
+
+impl Default for CurvatureSum {
+    fn default() -> Self {
+        Self {
+            angle: 0.0,
+            area: 0.0,
+            laplacian: Vector3::zero(),
+            normal: Vector3::zero(),
+        }
+    }
+}
+
+impl Curvature for PolygonMesh {
+    fn curvatures(&self) -> Vec<Option<VertexCurvature>> {
+        let positions = self.positions();
+        let mut sums = vec![CurvatureSum::default(); positions.len()];
+        self.faces().triangle_iter().for_each(|tri| {
+            let idcs = tri.map(|v| v.pos);
+            add_triangle_curvature(&mut sums, idcs.map(|i| positions[i]), idcs)
+        });
+        let mut boundary = vec![false; positions.len()];
+        self.faces()
+            .edge_faces()
+            .into_iter()
+            .filter(|(_, faces)| faces.len() != 2)
+            .for_each(|([i, j], _)| {
+                boundary[i] = true;
+                boundary[j] = true;
+            });
+        sums.into_iter()
+            .zip(boundary)
+            .map(|(sum, boundary)| {
+                if boundary || sum.area == 0.0 || sum.normal.so_small() {
+                    return None;
+                }
+                let mean_curvature_normal = sum.laplacian / (2.0 * sum.area);
+                Some(VertexCurvature {
+                    gaussian: (2.0 * PI - sum.angle) / sum.area,
+                    mean: mean_curvature_normal.dot(sum.normal.normalize()) / 2.0,
+                })
+            })
+            .collect()
+    }
+}
+
+fn add_triangle_curvature(sums: &mut [CurvatureSum], tri: [Point3; 3], idcs: [usize; 3]) {
+    let area_vector = (tri[1] - tri[0]).cross(tri[2] - tri[0]);
+    let area = area_vector.magnitude() / 2.0;
+    if area.so_small2() {
+        return;
+    }
+    let (angles, cots): (Vec<f64>, Vec<f64>) = (0..3)
+        .map(|k| {
+            let a = tri[(k + 1) % 3] - tri[k];
+            let b = tri[(k + 2) % 3] - tri[k];
+            let (cos, sin) = (a.dot(b), a.cross(b).magnitude());
+            (f64::atan2(sin, cos), cos / sin)
+        })
+        .unzip();
+    let obtuse = (0..3).find(|k| angles[*k] > PI / 2.0);
+    (0..3).for_each(|k| {
+        let (k1, k2) = ((k + 1) % 3, (k + 2) % 3);
+        // The edge from `k` to `k1` is opposite to the corner `k2`, and vice versa.
+        let (e1, e2) = (tri[k] - tri[k1], tri[k] - tri[k2]);
+        let sum = &mut sums[idcs[k]];
+        sum.angle += angles[k];
+        sum.laplacian += e1 * cots[k2] + e2 * cots[k1];
+        sum.normal += area_vector;
+        // mixed Voronoi area
+        sum.area += match obtuse {
+            None => (e1.magnitude2() * cots[k2] + e2.magnitude2() * cots[k1]) / 8.0,
+            Some(o) if o == k => area / 2.0,
+            Some(_) => area / 4.0,
+        };
+    });
+}
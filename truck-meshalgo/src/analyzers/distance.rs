@@ -0,0 +1,64 @@
+use super::*;
+
+/// distances between polygon meshes
+pub trait MeshDistance {
+    /// Returns the one-sided Hausdorff distance from `self` to `other`, i.e. the maximum distance
+    /// from the points on `self` to `other`.
+    ///
+    /// # Details
+    /// Each triangle of `self` is divided into a lattice so that the number of the sample points
+    /// is about `sample_density` per unit area, and the distance from each sample point to `other`
+    /// is calculated by [`MeshBvh::closest_point`]. The vertices of `self` are always sampled.
+    ///
+    /// The symmetric Hausdorff distance is the maximum of the distances in both directions.
+    ///
+    /// # Panics
+    /// Panics if `sample_density` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let square = |z: f64| {
+    ///     PolygonMesh::new(
+    ///         StandardAttributes {
+    ///             positions: vec![
+    ///                 Point3::new(0.0, 0.0, z),
+    ///                 Point3::new(1.0, 0.0, z),
+    ///                 Point3::new(1.0, 1.0, z),
+    ///                 Point3::new(0.0, 1.0, z),
+    ///             ],
+    ///             ..Default::default()
+    ///         },
+    ///         Faces::from_iter(&[[0, 1, 2, 3]]),
+    ///     )
+    /// };
+    /// let dist = square(0.0).hausdorff_distance_to(&square(0.5), 100.0);
+    /// assert_near!(dist, 0.5);
+    /// ```
+    fn hausdorff_distance_to(&self, other: &PolygonMesh, sample_density: f64) -> f64;
+}
+
+impl MeshDistance for PolygonMesh {
+    fn hausdorff_distance_to(&self, other: &PolygonMesh, sample_density: f64) -> f64 {
+        assert!(sample_density > 0.0, "sample density must be positive");
+        let bvh = MeshBvh::new(other);
+        let distance = |point: Point3| match bvh.closest_point(point) {
+            Some((_, dist, _)) => dist,
+            None => f64::INFINITY,
+        };
+        self.faces()
+            .triangle_iter()
+            .map(|tri| tri.map(|v| self.positions()[v.pos]))
+            .flat_map(|tri| sample_triangle(tri, sample_density))
+            .map(distance)
+            .fold(0.0, f64::max)
+    }
+}
+
+/// the lattice points of the triangle divided into `n * n` similar triangles
+fn sample_triangle([a, b, c]: [Point3; 3], sample_density: f64) -> impl Iterator<Item = Point3> {
+    let area = (b - a).cross(c - a).magnitude() / 2.0;
+    let n = f64::ceil(f64::sqrt(area * sample_density)).max(1.0) as usize;
+    let (u, v) = ((b - a) / n as f64, (c - a) / n as f64);
+    (0..=n).flat_map(move |i| (0..=n - i).map(move |j| a + u * i as f64 + v * j as f64))
+}
@@ -1,17 +1,25 @@
 use crate::*;
 
 mod collision;
+mod curvature;
+mod distance;
 mod in_out_judge;
 mod point_cloud;
+mod ray_casting;
+mod slicing;
 mod splitting;
 mod topology;
 mod volume;
 
 pub use collision::Collision;
+pub use curvature::{Curvature, VertexCurvature};
+pub use distance::MeshDistance;
 pub use in_out_judge::IncludingPointInDomain;
 pub use point_cloud::WithPointCloud;
+pub use ray_casting::{MeshBvh, RayCasting, RayHit};
+pub use slicing::Slicing;
 pub use splitting::ExperimentalSplitters;
 pub use splitting::Splitting;
-pub use topology::Topology;
+pub use topology::{MeshTopologyReport, Topology};
 pub use truck_topology::shell::ShellCondition;
 pub use volume::CalcVolume;
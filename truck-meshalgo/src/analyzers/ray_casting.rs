@@ -0,0 +1,445 @@
+use super::*;
+use std::ops::Range;
+
+/// An intersection point of a ray and a polygon mesh.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    /// the parameter of the ray, i.e. `point == origin + t * dir`
+    pub t: f64,
+    /// the intersection point
+    pub point: Point3,
+    /// the index of the face in the order of [`Faces::face_iter`]
+    pub face_index: usize,
+    /// the local indices, in the face, of the vertices of the triangle containing the point.
+    /// Each polygon is divided into the triangle fan from the first vertex.
+    pub corners: [usize; 3],
+    /// the barycentric coordinates of the point with respect to the triangle `corners`
+    pub barycentric: [f64; 3],
+}
+
+/// ray casting to polygon meshes
+pub trait RayCasting {
+    /// Returns all intersections of the ray and the mesh, sorted by the parameter `t`.
+    /// # Details
+    /// - Only the intersections with `t >= 0` are returned, so the ray starting inside a closed
+    /// mesh finds the exits.
+    /// - The intersections at the same point, e.g. the ray passing through the edge shared by
+    /// two faces, are reported only once.
+    /// - The faces are scanned linearly. For repeated queries, build [`MeshBvh`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let square = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    ///
+    /// let hits = square.ray_intersections(Point3::new(0.25, 0.5, 1.0), -Vector3::unit_z());
+    /// assert_eq!(hits.len(), 1);
+    /// assert_near!(hits[0].t, 1.0);
+    /// assert_near!(hits[0].point, Point3::new(0.25, 0.5, 0.0));
+    /// assert_eq!(hits[0].face_index, 0);
+    ///
+    /// // The ray passing through the diagonal, the edge shared by the two internal triangles.
+    /// let hits = square.ray_intersections(Point3::new(0.5, 0.5, 1.0), -Vector3::unit_z());
+    /// assert_eq!(hits.len(), 1);
+    /// ```
+    fn ray_intersections(&self, origin: Point3, dir: Vector3) -> Vec<RayHit>;
+}
+
+impl RayCasting for PolygonMesh {
+    fn ray_intersections(&self, origin: Point3, dir: Vector3) -> Vec<RayHit> {
+        let hits = mesh_triangles(self)
+            .filter_map(|tri| tri.intersect(origin, dir))
+            .collect();
+        sorted_unique_hits(hits)
+    }
+}
+
+/// Bounding volume hierarchy of the triangles of a polygon mesh, for fast ray casting and
+/// closest point queries.
+///
+/// # Details
+/// The hierarchy is a binary tree of axis aligned bounding boxes. Each node is divided at the median
+/// of the centers of the triangles along the longest axis, so each query takes `O(log n)` time
+/// for `n` triangles, plus the number of hits.
+///
+/// The hierarchy does not refer to the mesh after construction. Rebuild it if the mesh is modified.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let simplex = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///             Point3::new(0.0, 0.0, 1.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]),
+/// );
+/// let bvh = MeshBvh::new(&simplex);
+///
+/// // The ray starting inside finds the exit.
+/// let hits = bvh.ray_cast(Point3::new(0.1, 0.1, 0.1), Vector3::unit_x());
+/// assert_eq!(hits.len(), 1);
+/// assert_near!(hits[0].point, Point3::new(0.8, 0.1, 0.1));
+/// assert_eq!(hits[0].face_index, 3);
+///
+/// assert!(bvh.is_inside(Point3::new(0.1, 0.1, 0.1)));
+/// assert!(!bvh.is_inside(Point3::new(-0.1, 0.1, 0.1)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MeshBvh {
+    triangles: Vec<IndexedTriangle>,
+    nodes: Vec<BvhNode>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct IndexedTriangle {
+    positions: [Point3; 3],
+    face_index: usize,
+    corners: [usize; 3],
+}
+
+#[derive(Clone, Debug)]
+enum BvhContent {
+    Leaf(Range<usize>),
+    Branch([usize; 2]),
+}
+
+#[derive(Clone, Debug)]
+struct BvhNode {
+    bounding_box: BoundingBox<Point3>,
+    content: BvhContent,
+}
+
+/// the maximum number of the triangles in a leaf
+const LEAF_SIZE: usize = 4;
+
+impl MeshBvh {
+    /// Constructs the hierarchy of the triangles of `mesh`.
+    pub fn new(mesh: &PolygonMesh) -> Self {
+        let mut triangles = mesh_triangles(mesh).collect::<Vec<_>>();
+        let mut nodes = Vec::new();
+        if !triangles.is_empty() {
+            build_node(&mut triangles, 0, &mut nodes);
+        }
+        Self { triangles, nodes }
+    }
+    /// Returns all intersections of the ray and the mesh, sorted by the parameter `t`.
+    ///
+    /// The result is the same as [`RayCasting::ray_intersections`] of the original mesh.
+    pub fn ray_cast(&self, origin: Point3, dir: Vector3) -> Vec<RayHit> {
+        let mut hits = Vec::new();
+        let mut stack = match self.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![0],
+        };
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !ray_hits_box(node.bounding_box, origin, dir) {
+                continue;
+            }
+            match &node.content {
+                BvhContent::Leaf(range) => hits.extend(
+                    self.triangles[range.clone()]
+                        .iter()
+                        .filter_map(|tri| tri.intersect(origin, dir)),
+                ),
+                BvhContent::Branch(children) => stack.extend(children),
+            }
+        }
+        sorted_unique_hits(hits)
+    }
+    /// Returns whether `point` is inside the closed mesh, by the parity of the number of hits.
+    ///
+    /// The direction of the ray is determined by the hash of `point`, in order to avoid the rays
+    /// touching the mesh at vertices or edges.
+    pub fn is_inside(&self, point: Point3) -> bool {
+        let dir = hash::take_one_unit(point);
+        self.ray_cast(point, dir).len() % 2 == 1
+    }
+    /// Returns the closest point on the mesh to `point`, the distance, and the index of the face
+    /// containing the closest point. Returns `None` if the mesh has no faces.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let square = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    /// let bvh = MeshBvh::new(&square);
+    ///
+    /// // projected onto the interior
+    /// let (closest, dist, face_index) = bvh.closest_point(Point3::new(0.3, 0.4, 2.0)).unwrap();
+    /// assert_near!(closest, Point3::new(0.3, 0.4, 0.0));
+    /// assert_near!(dist, 2.0);
+    /// assert_eq!(face_index, 0);
+    ///
+    /// // projected onto the vertex
+    /// let (closest, dist, _) = bvh.closest_point(Point3::new(2.0, 2.0, 1.0)).unwrap();
+    /// assert_near!(closest, Point3::new(1.0, 1.0, 0.0));
+    /// assert_near!(dist, f64::sqrt(3.0));
+    /// ```
+    pub fn closest_point(&self, point: Point3) -> Option<(Point3, f64, usize)> {
+        let mut closest: Option<(Point3, f64, usize)> = None;
+        let mut stack = match self.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![0],
+        };
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            let box_dist2 = distance2_to_box(node.bounding_box, point);
+            if matches!(closest, Some((_, dist, _)) if box_dist2 >= dist * dist) {
+                continue;
+            }
+            match &node.content {
+                BvhContent::Leaf(range) => {
+                    for tri in &self.triangles[range.clone()] {
+                        let candidate = closest_point_on_triangle(point, tri.positions);
+                        let dist = candidate.distance(point);
+                        if !matches!(closest, Some((_, min, _)) if min <= dist) {
+                            closest = Some((candidate, dist, tri.face_index));
+                        }
+                    }
+                }
+                BvhContent::Branch([left, right]) => {
+                    // The nearer child is searched first.
+                    let dist2 = |i: usize| distance2_to_box(self.nodes[i].bounding_box, point);
+                    match dist2(*left) < dist2(*right) {
+                        true => stack.extend([*right, *left]),
+                        false => stack.extend([*left, *right]),
+                    }
+                }
+            }
+        }
+        closest
+    }
+    /// Returns the pairs of the triangles of `self` and `other` whose bounding boxes overlap.
+    ///
+    /// Only the pairs of the leaves whose bounding boxes overlap are enumerated, so the pairs of
+    /// the triangles apart from each other are not compared one by one.
+    pub fn overlapping_triangles(&self, other: &MeshBvh) -> Vec<[[Point3; 3]; 2]> {
+        let mut pairs = Vec::new();
+        let mut stack = match self.nodes.is_empty() || other.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![(0, 0)],
+        };
+        while let Some((i, j)) = stack.pop() {
+            let (node0, node1) = (&self.nodes[i], &other.nodes[j]);
+            if !boxes_overlap(node0.bounding_box, node1.bounding_box) {
+                continue;
+            }
+            match (&node0.content, &node1.content) {
+                (BvhContent::Leaf(range0), BvhContent::Leaf(range1)) => {
+                    for tri0 in &self.triangles[range0.clone()] {
+                        for tri1 in &other.triangles[range1.clone()] {
+                            pairs.push([tri0.positions, tri1.positions]);
+                        }
+                    }
+                }
+                (BvhContent::Branch(children), BvhContent::Leaf(_)) => {
+                    stack.extend(children.map(|i| (i, j)))
+                }
+                (BvhContent::Leaf(_), BvhContent::Branch(children)) => {
+                    stack.extend(children.map(|j| (i, j)))
+                }
+                (BvhContent::Branch(children0), BvhContent::Branch(children1)) => stack.extend(
+                    children0
+                        .iter()
+                        .flat_map(|i| children1.map(move |j| (*i, j))),
+                ),
+            }
+        }
+        pairs
+    }
+}
+
+fn build_node(triangles: &mut [IndexedTriangle], offset: usize, nodes: &mut Vec<BvhNode>) -> usize {
+    let bounding_box = triangles
+        .iter()
+        .flat_map(|tri| tri.positions)
+        .collect::<BoundingBox<Point3>>();
+    let idx = nodes.len();
+    nodes.push(BvhNode {
+        bounding_box,
+        content: BvhContent::Leaf(offset..offset + triangles.len()),
+    });
+    if triangles.len() > LEAF_SIZE {
+        let diagonal = triangles
+            .iter()
+            .map(IndexedTriangle::center)
+            .collect::<BoundingBox<Point3>>()
+            .diagonal();
+        let axis = (0..3)
+            .max_by(|i, j| diagonal[*i].total_cmp(&diagonal[*j]))
+            .unwrap();
+        let mid = triangles.len() / 2;
+        triangles.select_nth_unstable_by(mid, |tri0, tri1| {
+            tri0.center()[axis].total_cmp(&tri1.center()[axis])
+        });
+        let (left, right) = triangles.split_at_mut(mid);
+        let left = build_node(left, offset, nodes);
+        let right = build_node(right, offset + mid, nodes);
+        nodes[idx].content = BvhContent::Branch([left, right]);
+    }
+    idx
+}
+
+/// the slab method, with the box expanded by `TOLERANCE`
+fn ray_hits_box(bounding_box: BoundingBox<Point3>, origin: Point3, dir: Vector3) -> bool {
+    let (min, max) = (bounding_box.min(), bounding_box.max());
+    let (mut t_min, mut t_max) = (0.0, f64::INFINITY);
+    for i in 0..3 {
+        let (lower, upper) = (min[i] - TOLERANCE, max[i] + TOLERANCE);
+        if dir[i] == 0.0 {
+            if origin[i] < lower || upper < origin[i] {
+                return false;
+            }
+            continue;
+        }
+        let (t0, t1) = ((lower - origin[i]) / dir[i], (upper - origin[i]) / dir[i]);
+        t_min = f64::max(t_min, f64::min(t0, t1));
+        t_max = f64::min(t_max, f64::max(t0, t1));
+    }
+    t_min <= t_max
+}
+
+fn boxes_overlap(box0: BoundingBox<Point3>, box1: BoundingBox<Point3>) -> bool {
+    let (min0, max0, min1, max1) = (box0.min(), box0.max(), box1.min(), box1.max());
+    (0..3).all(|i| min0[i] <= max1[i] + TOLERANCE && min1[i] <= max0[i] + TOLERANCE)
+}
+
+fn distance2_to_box(bounding_box: BoundingBox<Point3>, point: Point3) -> f64 {
+    let (min, max) = (bounding_box.min(), bounding_box.max());
+    (0..3)
+        .map(|i| f64::max(f64::max(min[i] - point[i], point[i] - max[i]), 0.0))
+        .map(|d| d * d)
+        .sum()
+}
+
+fn closest_point_on_segment(point: Point3, [p, q]: [Point3; 2]) -> Point3 {
+    let dir = q - p;
+    match dir.magnitude2() > 0.0 {
+        true => p + dir * f64::clamp((point - p).dot(dir) / dir.magnitude2(), 0.0, 1.0),
+        false => p,
+    }
+}
+
+/// the algorithm in "Real-Time Collision Detection" by C. Ericson, which classifies the point by
+/// the Voronoi regions of the vertices, the edges and the interior.
+fn closest_point_on_triangle(point: Point3, [a, b, c]: [Point3; 3]) -> Point3 {
+    let (ab, ac) = (b - a, c - a);
+    if ab.cross(ac).magnitude2() == 0.0 {
+        return [[a, b], [b, c], [c, a]]
+            .into_iter()
+            .map(|segment| closest_point_on_segment(point, segment))
+            .min_by(|p, q| p.distance2(point).total_cmp(&q.distance2(point)))
+            .unwrap();
+    }
+    let ap = point - a;
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = point - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = point - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 >= d3 && d5 >= d6 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denom = va + vb + vc;
+    a + ab * (vb / denom) + ac * (vc / denom)
+}
+
+impl IndexedTriangle {
+    #[inline(always)]
+    fn center(&self) -> Point3 {
+        let [p, q, r] = self.positions;
+        Point3::from_vec((p.to_vec() + q.to_vec() + r.to_vec()) / 3.0)
+    }
+
+    /// Möller–Trumbore algorithm. The edges are included with the margin `TOLERANCE`.
+    fn intersect(&self, origin: Point3, dir: Vector3) -> Option<RayHit> {
+        let [p, q, r] = self.positions;
+        let (e1, e2) = (q - p, r - p);
+        let pvec = dir.cross(e2);
+        let det = e1.dot(pvec);
+        // parallel or degenerate
+        if det.abs() <= f64::EPSILON * e1.cross(e2).magnitude() * dir.magnitude() {
+            return None;
+        }
+        let tvec = origin - p;
+        let u = tvec.dot(pvec) / det;
+        let qvec = tvec.cross(e1);
+        let v = dir.dot(qvec) / det;
+        let t = e2.dot(qvec) / det;
+        let inside = u >= -TOLERANCE && v >= -TOLERANCE && u + v <= 1.0 + TOLERANCE;
+        match inside && t >= 0.0 {
+            true => Some(RayHit {
+                t,
+                point: origin + t * dir,
+                face_index: self.face_index,
+                corners: self.corners,
+                barycentric: [1.0 - u - v, u, v],
+            }),
+            false => None,
+        }
+    }
+}
+
+fn mesh_triangles(mesh: &PolygonMesh) -> impl Iterator<Item = IndexedTriangle> + '_ {
+    let positions = mesh.positions();
+    mesh.face_iter()
+        .enumerate()
+        .flat_map(move |(face_index, face)| {
+            (2..face.len()).map(move |k| IndexedTriangle {
+                positions: [face[0], face[k - 1], face[k]].map(|v| positions[v.pos]),
+                face_index,
+                corners: [0, k - 1, k],
+            })
+        })
+}
+
+/// Sorts hits by the parameter, and merges the hits at the same point.
+fn sorted_unique_hits(mut hits: Vec<RayHit>) -> Vec<RayHit> {
+    hits.sort_by(|hit0, hit1| hit0.t.total_cmp(&hit1.t));
+    hits.dedup_by(|hit, prev| hit.point.near(&prev.point));
+    hits
+}
@@ -0,0 +1,193 @@
+use super::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// planar cross sections of polygon meshes
+pub trait Slicing {
+    /// Returns the cross section of the mesh by the plane through `plane_point` with the normal
+    /// `plane_normal`.
+    ///
+    /// # Details
+    /// - The intersections of the plane and faces are chained into polylines. Closed loops are
+    /// represented by the polylines whose last points are the same as the first points, and
+    /// the open polylines are returned for the meshes with boundaries.
+    /// - The vertices on the plane are regarded as slightly above the plane, i.e. on the side
+    /// of `plane_normal`. Hence, the faces lying in the plane are ignored, and the loops are
+    /// never broken at the vertices on the plane.
+    /// - For consistently oriented meshes, the loops are directed counterclockwise viewed from
+    /// `plane_normal` if the faces are oriented outward.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // regular tetrahedron
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]),
+    /// );
+    /// let loops = mesh.cross_section(Point3::new(0.0, 0.0, 0.5), Vector3::unit_z());
+    /// assert_eq!(loops.len(), 1);
+    /// // the triangle with the first point repeated
+    /// assert_eq!(loops[0].len(), 4);
+    /// assert_eq!(loops[0][0], loops[0][3]);
+    /// ```
+    fn cross_section(
+        &self,
+        plane_point: Point3,
+        plane_normal: Vector3,
+    ) -> Vec<PolylineCurve<Point3>>;
+    /// Returns the cross sections by the `count` parallel planes, the `i`-th of which is through
+    /// `origin + i * spacing * normal.normalize()`.
+    ///
+    /// # Details
+    /// Each cross section is the same as [`cross_section`](Slicing::cross_section).
+    /// The heights of the vertices are calculated only once, and each level scans only the
+    /// triangles crossing it.
+    ///
+    /// # Panics
+    /// Panics if `spacing` is not positive.
+    fn cross_sections(
+        &self,
+        origin: Point3,
+        normal: Vector3,
+        spacing: f64,
+        count: usize,
+    ) -> Vec<Vec<PolylineCurve<Point3>>>;
+}
+
+impl Slicing for PolygonMesh {
+    fn cross_section(
+        &self,
+        plane_point: Point3,
+        plane_normal: Vector3,
+    ) -> Vec<PolylineCurve<Point3>> {
+        self.cross_sections(plane_point, plane_normal, 1.0, 1)
+            .pop()
+            .unwrap_or_default()
+    }
+    fn cross_sections(
+        &self,
+        origin: Point3,
+        normal: Vector3,
+        spacing: f64,
+        count: usize,
+    ) -> Vec<Vec<PolylineCurve<Point3>>> {
+        assert!(spacing > 0.0, "spacing must be positive");
+        let normal = normal.normalize();
+        let positions = self.positions();
+        let heights = positions
+            .iter()
+            .map(|p| (p - origin).dot(normal))
+            .collect::<Vec<_>>();
+        // the triangles with the ranges of heights, sorted by the lower bounds
+        let mut triangles = self
+            .faces()
+            .triangle_iter()
+            .map(|tri| {
+                let tri = tri.map(|v| v.pos);
+                let range = tri.map(|i| heights[i]);
+                let lower = range.into_iter().fold(f64::INFINITY, f64::min);
+                let upper = range.into_iter().fold(f64::NEG_INFINITY, f64::max);
+                (tri, lower, upper)
+            })
+            .collect::<Vec<_>>();
+        triangles.sort_by(|x, y| x.1.total_cmp(&y.1));
+
+        let mut next = 0;
+        let mut active = Vec::<usize>::new();
+        (0..count)
+            .map(|k| {
+                let level = spacing * k as f64;
+                while next < triangles.len() && triangles[next].1 < level {
+                    active.push(next);
+                    next += 1;
+                }
+                active.retain(|i| triangles[*i].2 >= level);
+                let segments = active
+                    .iter()
+                    .filter_map(|i| section_segment(triangles[*i].0, &heights, level))
+                    .collect::<Vec<_>>();
+                chain_segments(&segments)
+                    .into_iter()
+                    .map(|chain| {
+                        let mut points = chain
+                            .into_iter()
+                            .map(|edge| crossing_point(positions, &heights, edge, level))
+                            .collect::<Vec<_>>();
+                        points.dedup();
+                        PolylineCurve(points)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Returns the segment as the pair of edges, from the edge going down to the edge going up.
+/// The vertices on the plane are regarded as above the plane.
+fn section_segment(tri: [usize; 3], heights: &[f64], level: f64) -> Option<[[usize; 2]; 2]> {
+    let above = tri.map(|i| heights[i] >= level);
+    let edge = |k: usize| {
+        let (v, w) = (tri[k], tri[(k + 1) % 3]);
+        [usize::min(v, w), usize::max(v, w)]
+    };
+    let down = (0..3).find(|k| above[*k] && !above[(k + 1) % 3])?;
+    let up = (0..3).find(|k| !above[*k] && above[(k + 1) % 3])?;
+    Some([edge(down), edge(up)])
+}
+
+/// The point is calculated from the sorted edge, so it does not depend on the face.
+fn crossing_point(positions: &[Point3], heights: &[f64], [v, w]: [usize; 2], level: f64) -> Point3 {
+    let (h0, h1) = (heights[v] - level, heights[w] - level);
+    positions[v] + (positions[w] - positions[v]) * (h0 / (h0 - h1))
+}
+
+/// Chains the directed segments into polylines of the edges.
+fn chain_segments(segments: &[[[usize; 2]; 2]]) -> Vec<Vec<[usize; 2]>> {
+    let mut outgoing = HashMap::<[usize; 2], Vec<usize>>::default();
+    let mut incoming = HashSet::<[usize; 2]>::default();
+    segments.iter().enumerate().for_each(|(i, [from, to])| {
+        outgoing.entry(*from).or_default().push(i);
+        incoming.insert(*to);
+    });
+    let mut used = vec![false; segments.len()];
+    let next_segment = |used: &[bool], from: [usize; 2]| {
+        outgoing
+            .get(&from)
+            .and_then(|segs| segs.iter().copied().find(|i| !used[*i]))
+    };
+    // The open chains start from the edges without incoming segments.
+    let starts = segments
+        .iter()
+        .map(|[from, _]| *from)
+        .filter(|from| !incoming.contains(from))
+        .chain(segments.iter().map(|[from, _]| *from))
+        .collect::<Vec<_>>();
+    let mut chains = Vec::new();
+    for start in starts {
+        while let Some(mut i) = next_segment(&used, start) {
+            let mut chain = vec![start];
+            loop {
+                used[i] = true;
+                let to = segments[i][1];
+                chain.push(to);
+                if to == start {
+                    break;
+                }
+                match next_segment(&used, to) {
+                    Some(next) => i = next,
+                    None => break,
+                }
+            }
+            chains.push(chain);
+        }
+    }
+    chains
+}
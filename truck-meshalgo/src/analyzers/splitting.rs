@@ -1,4 +1,6 @@
 use super::*;
+use crate::filters::OptimizingFilter;
+use rustc_hash::FxHashMap as HashMap;
 use std::f64::consts::PI;
 
 /// Splitting the faces into several clusters.
@@ -128,6 +130,40 @@ pub trait Splitting {
     /// assert_eq!(components.len(), 1);
     /// ```
     fn components(&self, use_normal: bool) -> Vec<Vec<usize>>;
+    /// Splits into the connected components, and creates a mesh for each component.
+    /// # Details
+    /// If `share_edges == true`, two polygons are considered to be in the same component if they
+    /// share an edge whose vertices have the same positions. Otherwise, sharing only a position
+    /// is enough. Unused attributes are removed from each component mesh.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::analyzers::*;
+    ///
+    /// // two triangles touching at a vertex
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(-1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, -1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 3, 4]]),
+    /// );
+    ///
+    /// let components = mesh.into_components(true);
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[0].positions().len(), 3);
+    /// assert_eq!(components[1].positions().len(), 3);
+    ///
+    /// let components = mesh.into_components(false);
+    /// assert_eq!(components.len(), 1);
+    /// ```
+    fn into_components(&self, share_edges: bool) -> Vec<PolygonMesh>;
 }
 
 impl Splitting for PolygonMesh {
@@ -157,6 +193,21 @@ impl Splitting for PolygonMesh {
         let face_adjacency = self.faces().face_adjacency(use_normal);
         get_components(&face_adjacency)
     }
+
+    fn into_components(&self, share_edges: bool) -> Vec<PolygonMesh> {
+        let face_adjacency = match share_edges {
+            true => self.faces().face_adjacency(false),
+            false => vertex_face_adjacency(self.faces()),
+        };
+        get_components(&face_adjacency)
+            .into_iter()
+            .map(|component| {
+                let mut mesh = self.create_mesh_by_face_indices(&component);
+                mesh.remove_unused_attrs();
+                mesh
+            })
+            .collect()
+    }
 }
 
 #[doc(hidden)]
@@ -268,6 +319,28 @@ fn get_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
     }
 }
 
+/// the adjacency matrix of faces sharing some positions
+fn vertex_face_adjacency(faces: &Faces) -> Vec<Vec<usize>> {
+    let mut vertex_faces = HashMap::<usize, Vec<usize>>::default();
+    faces.face_iter().enumerate().for_each(|(i, face)| {
+        face.iter().for_each(|v| {
+            let entry = vertex_faces.entry(v.pos).or_default();
+            if entry.last() != Some(&i) {
+                entry.push(i);
+            }
+        })
+    });
+    let mut adjacency = vec![Vec::new(); faces.len()];
+    // It is enough for connectivity to link the faces around each vertex in a row.
+    vertex_faces.values().for_each(|idcs| {
+        idcs.windows(2).for_each(|pair| {
+            adjacency[pair[0]].push(pair[1]);
+            adjacency[pair[1]].push(pair[0]);
+        })
+    });
+    adjacency
+}
+
 fn is_in_the_plane(positions: &[Point3], normals: &[Vector3], face: &[Vertex], tol2: f64) -> bool {
     let n = FaceNormal::new(positions, face, 0).normal;
     for v in face {
@@ -1,18 +1,227 @@
 use super::*;
+use crate::errors::Error;
 use itertools::Itertools;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::fmt::{Display, Formatter};
 use truck_topology::shell::ShellCondition;
 
 /// Extracts boundaries, and check shell condition: closed or orientation.
 pub trait Topology {
     /// Returns a vector of all boundaries as line strip.
     fn extract_boundaries(&self) -> Vec<Vec<usize>>;
+    /// Returns all boundary loops consisting of the edges referenced by exactly one face.
+    /// # Details
+    /// - Each loop is a vector of position indices, ordered along the face winding.
+    /// - Each loop is implicitly closed: the first index is NOT repeated at the end.
+    /// - If a vertex has several outgoing boundary edges, the loops passing through the vertex
+    /// are separated arbitrarily.
+    /// # Errors
+    /// Returns [`Error::NonManifoldEdge`] if some edge is shared by three or more faces.
+    ///
+    /// [`Error::NonManifoldEdge`]: crate::errors::Error::NonManifoldEdge
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::{analyzers::*, errors::Error};
+    ///
+    /// let faces = Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]);
+    /// let loops = faces.extract_boundary_loops().unwrap();
+    /// assert_eq!(loops.len(), 1);
+    /// let start = loops[0].iter().position(|i| *i == 0).unwrap();
+    /// let rotated = loops[0][start..].iter().chain(&loops[0][..start]).copied().collect::<Vec<_>>();
+    /// assert_eq!(rotated, vec![0, 1, 2, 3]);
+    ///
+    /// // non-manifold edge
+    /// let faces = Faces::from_iter(&[[0, 1, 2], [1, 0, 3], [0, 1, 4]]);
+    /// assert_eq!(faces.extract_boundary_loops(), Err(Error::NonManifoldEdge([0, 1])));
+    /// ```
+    fn extract_boundary_loops(&self) -> Result<Vec<Vec<usize>>, Error>;
     /// Determines the shell conditions: non-regular, regular, oriented, or closed.  
     /// The complexity increases in proportion to the number of edges.
     ///
     /// Examples for each condition can be found on the page of
     /// [`ShellCondition`](https://docs.rs/truck-topology/0.2.0/truck_topology/shell/enum.ShellCondition.html).
     fn shell_condition(&self) -> ShellCondition;
+    /// Returns the report of all topological defects of the mesh.
+    /// # Details
+    /// Unlike [`shell_condition`](Topology::shell_condition), which returns only the summary,
+    /// the report lists the edges, vertices and faces causing each defect.
+    /// All lists in the report are sorted.
+    ///
+    /// For `Faces`, the number of positions is regarded as the maximum position index plus one.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::analyzers::*;
+    ///
+    /// // two triangles with the opposite orientations
+    /// let faces = Faces::from_iter(&[[0, 1, 2], [0, 1, 3]]);
+    /// let report = faces.shell_condition_report();
+    /// assert_eq!(report.inconsistent_face_pairs, vec![[0, 1]]);
+    /// assert_eq!(report.boundary_edges.len(), 4);
+    /// assert!(!report.is_closed_manifold());
+    /// println!("{report}");
+    /// ```
+    fn shell_condition_report(&self) -> MeshTopologyReport;
+}
+
+/// The report of the topological defects of a mesh, created by
+/// [`Topology::shell_condition_report`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeshTopologyReport {
+    /// the edges referenced by exactly one face, directed along the face
+    pub boundary_edges: Vec<[usize; 2]>,
+    /// the edges referenced by three or more faces, represented by the sorted pairs
+    pub non_manifold_edges: Vec<[usize; 2]>,
+    /// the vertices around which the faces do not form a single fan
+    pub non_manifold_vertices: Vec<usize>,
+    /// the pairs of faces sharing an edge in the same direction
+    pub inconsistent_face_pairs: Vec<[usize; 2]>,
+    /// the positions referenced by no faces
+    pub isolated_vertices: Vec<usize>,
+    /// the pairs of the first face and another face consisting of the same positions
+    pub duplicate_faces: Vec<[usize; 2]>,
+}
+
+impl MeshTopologyReport {
+    /// Returns `true` if the mesh is a closed and consistently oriented manifold.
+    /// Isolated vertices and duplicate faces are not considered.
+    pub fn is_closed_manifold(&self) -> bool {
+        self.boundary_edges.is_empty()
+            && self.non_manifold_edges.is_empty()
+            && self.non_manifold_vertices.is_empty()
+            && self.inconsistent_face_pairs.is_empty()
+    }
+}
+
+/// the maximum number of examples displayed for each defect
+const DISPLAYED_EXAMPLES: usize = 5;
+
+fn fmt_defects<T: std::fmt::Debug>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    defects: &[T],
+) -> std::fmt::Result {
+    write!(f, "{name}: {}", defects.len())?;
+    if !defects.is_empty() {
+        let examples = defects
+            .iter()
+            .take(DISPLAYED_EXAMPLES)
+            .map(|x| format!("{x:?}"));
+        write!(f, " (e.g. {}", examples.format(", "))?;
+        match defects.len() > DISPLAYED_EXAMPLES {
+            true => write!(f, ", ...)")?,
+            false => write!(f, ")")?,
+        }
+    }
+    writeln!(f)
+}
+
+impl Display for MeshTopologyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_defects(f, "boundary edges", &self.boundary_edges)?;
+        fmt_defects(f, "non-manifold edges", &self.non_manifold_edges)?;
+        fmt_defects(f, "non-manifold vertices", &self.non_manifold_vertices)?;
+        fmt_defects(f, "inconsistent face pairs", &self.inconsistent_face_pairs)?;
+        fmt_defects(f, "isolated vertices", &self.isolated_vertices)?;
+        fmt_defects(f, "duplicate faces", &self.duplicate_faces)
+    }
+}
+
+/// the uses of an edge: the number of uses, and the first two pairs of the face index and
+/// the local index of the start vertex of the edge.
+#[derive(Clone, Copy, Debug, Default)]
+struct EdgeUses {
+    count: usize,
+    uses: [(usize, usize); 2],
+}
+
+fn topology_report(faces: &Faces, num_positions: usize) -> MeshTopologyReport {
+    let mut report = MeshTopologyReport::default();
+    let mut corner_offsets = Vec::with_capacity(faces.len());
+    let mut num_corners = 0;
+    let mut edges = HashMap::<[usize; 2], EdgeUses>::default();
+    let mut first_faces = HashMap::<Vec<usize>, usize>::default();
+    let mut used = vec![false; num_positions];
+    faces.face_iter().enumerate().for_each(|(i, face)| {
+        corner_offsets.push(num_corners);
+        num_corners += face.len();
+        face.iter().for_each(|v| used[v.pos] = true);
+        (0..face.len()).for_each(|k| {
+            let (v, w) = (face[k].pos, face[(k + 1) % face.len()].pos);
+            if v == w {
+                return;
+            }
+            let edge_uses = edges
+                .entry([usize::min(v, w), usize::max(v, w)])
+                .or_default();
+            if edge_uses.count < 2 {
+                edge_uses.uses[edge_uses.count] = (i, k);
+            }
+            edge_uses.count += 1;
+        });
+        let mut key = face.iter().map(|v| v.pos).collect::<Vec<_>>();
+        key.sort_unstable();
+        match first_faces.get(&key) {
+            Some(j) => report.duplicate_faces.push([*j, i]),
+            None => {
+                first_faces.insert(key, i);
+            }
+        }
+    });
+
+    let face_vec = faces.face_iter().collect::<Vec<_>>();
+    let corner = |(i, k): (usize, usize), pos: usize| {
+        let face = face_vec[i];
+        match face[k].pos == pos {
+            true => corner_offsets[i] + k,
+            false => corner_offsets[i] + (k + 1) % face.len(),
+        }
+    };
+    // Corners are united if they are on the same vertex and on adjacent faces.
+    let mut parents = (0..num_corners).collect::<Vec<_>>();
+    for (edge, edge_uses) in &edges {
+        match edge_uses.count {
+            1 => {
+                let (i, k) = edge_uses.uses[0];
+                let face = face_vec[i];
+                report
+                    .boundary_edges
+                    .push([face[k].pos, face[(k + 1) % face.len()].pos]);
+            }
+            2 => {
+                let [(i, k), (j, l)] = edge_uses.uses;
+                if face_vec[i][k].pos == face_vec[j][l].pos {
+                    report.inconsistent_face_pairs.push([i, j]);
+                }
+                edge.iter().for_each(|pos| {
+                    let (c0, c1) = (corner((i, k), *pos), corner((j, l), *pos));
+                    union_roots(&mut parents, c0, c1);
+                });
+            }
+            _ => report.non_manifold_edges.push(*edge),
+        }
+    }
+
+    let mut fan_roots = HashMap::<usize, usize>::default();
+    let mut non_manifold_vertices = HashSet::<usize>::default();
+    face_vec.iter().enumerate().for_each(|(i, face)| {
+        face.iter().enumerate().for_each(|(k, v)| {
+            let root = find_root(&mut parents, corner_offsets[i] + k);
+            if *fan_roots.entry(v.pos).or_insert(root) != root {
+                non_manifold_vertices.insert(v.pos);
+            }
+        })
+    });
+
+    report.non_manifold_vertices = non_manifold_vertices.into_iter().collect();
+    report.isolated_vertices = (0..num_positions).filter(|i| !used[*i]).collect();
+    report.boundary_edges.sort_unstable();
+    report.non_manifold_edges.sort_unstable();
+    report.non_manifold_vertices.sort_unstable();
+    report.inconsistent_face_pairs.sort_unstable();
+    report
 }
 
 #[derive(Clone, Debug)]
@@ -103,15 +312,71 @@ impl Topology for Faces {
         }
         res
     }
+    fn extract_boundary_loops(&self) -> Result<Vec<Vec<usize>>, Error> {
+        let directed_edges = || {
+            self.face_iter()
+                .flat_map(face_edge_iter)
+                .map(|[v, w]| [v.pos, w.pos])
+                .filter(|[v, w]| v != w)
+        };
+        let mut counts = HashMap::<[usize; 2], usize>::default();
+        for [v, w] in directed_edges() {
+            let edge = [usize::min(v, w), usize::max(v, w)];
+            let count = counts.entry(edge).or_insert(0);
+            *count += 1;
+            if *count > 2 {
+                return Err(Error::NonManifoldEdge(edge));
+            }
+        }
+        let boundary_edges = directed_edges()
+            .filter(|[v, w]| counts[&[usize::min(*v, *w), usize::max(*v, *w)]] == 1)
+            .collect::<Vec<_>>();
+        let mut outgoing = HashMap::<usize, Vec<usize>>::default();
+        boundary_edges
+            .iter()
+            .rev()
+            .for_each(|[v, w]| outgoing.entry(*v).or_default().push(*w));
+        let mut loops = Vec::new();
+        for [start, _] in boundary_edges {
+            let Some(mut cursor) = outgoing.get_mut(&start).and_then(Vec::pop) else {
+                continue;
+            };
+            let mut boundary = vec![start];
+            while cursor != start {
+                boundary.push(cursor);
+                match outgoing.get_mut(&cursor).and_then(Vec::pop) {
+                    Some(next) => cursor = next,
+                    None => break,
+                }
+            }
+            loops.push(boundary);
+        }
+        Ok(loops)
+    }
     fn shell_condition(&self) -> ShellCondition {
         self.face_iter()
             .flat_map(face_edge_iter)
             .collect::<Boundaries>()
             .condition()
     }
+    fn shell_condition_report(&self) -> MeshTopologyReport {
+        let num_positions = self
+            .face_iter()
+            .flatten()
+            .map(|v| v.pos + 1)
+            .max()
+            .unwrap_or(0);
+        topology_report(self, num_positions)
+    }
 }
 
 impl Topology for PolygonMesh {
     fn extract_boundaries(&self) -> Vec<Vec<usize>> { self.faces().extract_boundaries() }
+    fn extract_boundary_loops(&self) -> Result<Vec<Vec<usize>>, Error> {
+        self.faces().extract_boundary_loops()
+    }
     fn shell_condition(&self) -> ShellCondition { self.faces().shell_condition() }
+    fn shell_condition_report(&self) -> MeshTopologyReport {
+        topology_report(self.faces(), self.positions().len())
+    }
 }
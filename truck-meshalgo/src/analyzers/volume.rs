@@ -1,15 +1,18 @@
 use super::*;
 use array_macro::array;
 
-/// Calculate the volume and the center of gravity of mesh.
+/// Calculate the volume, the surface area and the center of gravity of mesh.
 pub trait CalcVolume {
-    /// Returns the volume of the mesh if the mesh is closed.
+    /// Returns the signed volume of the mesh if the mesh is closed.
     ///
     /// # Details
     /// More strictly, it returns the integral value of `xdydz`. Because of the linearity of this operation,
     /// if multiple meshes represent a single closed geometry, the overall volume can be calculated by computing
     /// this value for each and adding them together.
     ///
+    /// The value is meaningful only for closed and consistently oriented meshes. The volume is negative
+    /// if the faces are oriented inward, which is useful to detect the inverted meshes.
+    ///
     /// # Examples
     /// ```
     /// // The regular trihedron in the unit sphere.
@@ -123,6 +126,37 @@ pub trait CalcVolume {
     /// assert_near!(homog.to_point(), Point3::new(-1.0, 2.0, 1.0));
     /// ```
     fn center_of_gravity(&self) -> Vector4;
+    /// Returns the surface area of the mesh.
+    ///
+    /// # Details
+    /// The polygons are divided into triangles in the same way as [`Faces::triangle_iter`].
+    /// The orientation of faces does not affect the area.
+    ///
+    /// # Examples
+    /// ```
+    /// // The unit square
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// ];
+    /// let attrs = StandardAttributes {
+    ///     positions,
+    ///     ..Default::default()
+    /// };
+    /// let polygon = PolygonMesh::new(attrs, Faces::from_iter(&[[0, 1, 2, 3]]));
+    /// assert_near!(polygon.surface_area(), 1.0);
+    /// ```
+    fn surface_area(&self) -> f64;
+    /// Returns the center of gravity of the mesh if the mesh is closed.
+    ///
+    /// # Details
+    /// The dehomogenized point of [`center_of_gravity`](CalcVolume::center_of_gravity).
+    /// The result is meaningless if the volume is zero.
+    #[inline(always)]
+    fn centroid(&self) -> Point3 { self.center_of_gravity().to_point() }
 }
 
 impl CalcVolume for PolygonMesh {
@@ -147,6 +181,12 @@ impl CalcVolume for PolygonMesh {
         });
         Vector4::new(arr[0] / 24.0, arr[1] / 24.0, arr[2] / 24.0, arr[3] / 6.0)
     }
+    fn surface_area(&self) -> f64 {
+        point_triangles(self)
+            .map(|[p, q, r]| (q - p).cross(r - p).magnitude())
+            .sum::<f64>()
+            / 2.0
+    }
 }
 
 fn point_triangles(poly: &PolygonMesh) -> impl Iterator<Item = [Point3; 3]> + '_ {
@@ -172,4 +212,9 @@ impl CalcVolume for truck_topology::Solid<Point3, PolylineCurve<Point3>, Polygon
             })
             .sum::<Vector4>()
     }
+    fn surface_area(&self) -> f64 {
+        self.face_iter()
+            .map(|face| face.surface().surface_area())
+            .sum::<f64>()
+    }
 }
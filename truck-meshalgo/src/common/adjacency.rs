@@ -6,6 +6,9 @@ pub trait Adjacency {
     fn vertex_adjacency(&self, num_of_vertices: usize) -> Vec<Vec<usize>>;
     /// create the adjacency list of the faces
     fn face_adjacency(&self, use_normal: bool) -> Vec<Vec<usize>>;
+    /// create the map from each edge to the faces containing the edge.
+    /// Each edge is represented by the sorted pair of the position indices.
+    fn edge_faces(&self) -> HashMap<[usize; 2], Vec<usize>>;
 }
 
 impl Adjacency for Faces {
@@ -49,6 +52,22 @@ impl Adjacency for Faces {
         }
         face_adjacency
     }
+    fn edge_faces(&self) -> HashMap<[usize; 2], Vec<usize>> {
+        let mut edge_faces = HashMap::<[usize; 2], Vec<usize>>::default();
+        for (i, face) in self.face_iter().enumerate() {
+            face.windows(2)
+                .chain(std::iter::once([face[face.len() - 1], face[0]].as_ref()))
+                .filter(|edge| edge[0].pos != edge[1].pos)
+                .for_each(|edge| {
+                    let key = match edge[0].pos < edge[1].pos {
+                        true => [edge[0].pos, edge[1].pos],
+                        false => [edge[1].pos, edge[0].pos],
+                    };
+                    edge_faces.entry(key).or_default().push(i);
+                })
+        }
+        edge_faces
+    }
 }
 
 fn signup_adjacency(
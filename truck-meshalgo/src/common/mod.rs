@@ -2,5 +2,7 @@ use crate::*;
 
 mod adjacency;
 mod face_normal;
+mod union_find;
 pub(super) use adjacency::Adjacency;
 pub(super) use face_normal::FaceNormal;
+pub(super) use union_find::{find_root, union_roots};
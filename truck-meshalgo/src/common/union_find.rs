@@ -0,0 +1,16 @@
+pub fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// The root of each set is always the least index in the set.
+pub fn union_roots(parents: &mut [usize], i: usize, j: usize) {
+    let (i, j) = (find_root(parents, i), find_root(parents, j));
+    match i < j {
+        true => parents[j] = i,
+        false => parents[i] = j,
+    }
+}
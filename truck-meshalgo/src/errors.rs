@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+/// Errors occurred by mesh algorithms
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Error {
+    /// The edge, represented by the sorted pair of position indices, is shared by three or more faces.
+    #[error("The edge {0:?} is shared by three or more faces.")]
+    NonManifoldEdge([usize; 2]),
+    /// The mesh is not orientable. The faces, in order of adjacency, form a cycle along which
+    /// the orientation is reversed.
+    #[error("The mesh is not orientable along the cycle of faces {0:?}.")]
+    NonOrientable(Vec<usize>),
+}
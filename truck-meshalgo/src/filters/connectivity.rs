@@ -0,0 +1,200 @@
+use super::*;
+
+/// The connectivity of the faces and the positions of a polygon mesh.
+///
+/// The queries are answered from flat arrays in the compressed sparse row layout, so building
+/// it once and sharing it among several filters is cheap even for large meshes.
+/// # Details
+/// - The connectivity is determined by the indices of positions, so run
+/// [`OptimizingFilter::put_together_same_attrs`] in advance if needed.
+/// - Each edge is represented by the sorted pair of the position indices, and degenerate edges,
+/// i.e. edges whose end points are the same, are skipped.
+/// - The faces are indexed in the order of [`Faces::face_iter`].
+/// - Since the connectivity does not depend on the orientations of the faces or the coordinates
+/// of the positions, it is still valid after [`StructuringFilter::heal_orientation`] or
+/// [`Smoothing::taubin_smooth`].
+///
+/// [`OptimizingFilter::put_together_same_attrs`]: ./trait.OptimizingFilter.html#tymethod.put_together_same_attrs
+/// [`StructuringFilter::heal_orientation`]: ./trait.StructuringFilter.html#method.heal_orientation
+/// [`Smoothing::taubin_smooth`]: ./trait.Smoothing.html#method.taubin_smooth
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use truck_meshalgo::filters::*;
+///
+/// // 0 - 1 - 2
+/// // |   |   |
+/// // 3 - 4 - 5
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(2.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(2.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 3, 4, 1], [1, 4, 5, 2]]),
+/// );
+/// let connectivity = MeshConnectivity::new(&mesh);
+/// assert_eq!(connectivity.faces_around_vertex(1), &[0, 1]);
+/// assert_eq!(connectivity.edge_faces(4, 1), &[0, 1]);
+/// assert_eq!(connectivity.vertex_neighbors(4), &[1, 3, 5]);
+/// assert_eq!(connectivity.boundary_edges().count(), 6);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MeshConnectivity {
+    vertex_face_offsets: Vec<usize>,
+    vertex_faces: Vec<usize>,
+    neighbor_offsets: Vec<usize>,
+    neighbors: Vec<usize>,
+    edges: Vec<[usize; 2]>,
+    edge_face_offsets: Vec<usize>,
+    edge_faces: Vec<usize>,
+    face_edge_offsets: Vec<usize>,
+    face_edges: Vec<usize>,
+}
+
+/// Returns the offsets of the compressed rows from the row index of each entry.
+fn row_offsets(rows: impl IntoIterator<Item = usize>, num_rows: usize) -> Vec<usize> {
+    let mut offsets = vec![0; num_rows + 1];
+    rows.into_iter().for_each(|row| offsets[row + 1] += 1);
+    (0..num_rows).for_each(|i| offsets[i + 1] += offsets[i]);
+    offsets
+}
+
+impl MeshConnectivity {
+    /// Builds the connectivity of `mesh`.
+    pub fn new(mesh: &PolygonMesh) -> Self {
+        let num_vertices = mesh.positions().len();
+        let num_faces = mesh.faces().len();
+
+        let mut vertex_face_pairs = mesh
+            .face_iter()
+            .enumerate()
+            .flat_map(|(i, face)| face.iter().map(move |v| [v.pos, i]))
+            .collect::<Vec<_>>();
+        vertex_face_pairs.sort_unstable();
+        vertex_face_pairs.dedup();
+        let vertex_face_offsets = row_offsets(vertex_face_pairs.iter().map(|p| p[0]), num_vertices);
+        let vertex_faces = vertex_face_pairs.into_iter().map(|p| p[1]).collect();
+
+        let mut edge_face_pairs = mesh
+            .face_iter()
+            .enumerate()
+            .flat_map(|(i, face)| {
+                let len = face.len();
+                (0..len).map(move |k| (face[k].pos, face[(k + 1) % len].pos, i))
+            })
+            .filter(|(v, w, _)| v != w)
+            .map(|(v, w, i)| (usize::min(v, w), usize::max(v, w), i))
+            .collect::<Vec<_>>();
+        edge_face_pairs.sort_unstable();
+        edge_face_pairs.dedup();
+        let mut edges = Vec::<[usize; 2]>::new();
+        let mut edge_face_offsets = vec![0];
+        let mut face_edge_pairs = Vec::with_capacity(edge_face_pairs.len());
+        let edge_faces = edge_face_pairs
+            .into_iter()
+            .map(|(v, w, i)| {
+                if edges.last() != Some(&[v, w]) {
+                    edges.push([v, w]);
+                    edge_face_offsets.push(*edge_face_offsets.last().unwrap());
+                }
+                *edge_face_offsets.last_mut().unwrap() += 1;
+                face_edge_pairs.push([i, edges.len() - 1]);
+                i
+            })
+            .collect();
+        face_edge_pairs.sort_unstable();
+        let face_edge_offsets = row_offsets(face_edge_pairs.iter().map(|p| p[0]), num_faces);
+        let face_edges = face_edge_pairs.into_iter().map(|p| p[1]).collect();
+
+        // Since the edges are sorted, the neighbors of each vertex are filled in ascending order.
+        let neighbor_offsets = row_offsets(edges.iter().flatten().copied(), num_vertices);
+        let mut cursors = neighbor_offsets.clone();
+        let mut neighbors = vec![0; 2 * edges.len()];
+        edges.iter().for_each(|&[v, w]| {
+            neighbors[cursors[v]] = w;
+            cursors[v] += 1;
+            neighbors[cursors[w]] = v;
+            cursors[w] += 1;
+        });
+
+        Self {
+            vertex_face_offsets,
+            vertex_faces,
+            neighbor_offsets,
+            neighbors,
+            edges,
+            edge_face_offsets,
+            edge_faces,
+            face_edge_offsets,
+            face_edges,
+        }
+    }
+
+    /// Returns the number of the positions.
+    #[inline(always)]
+    pub fn num_vertices(&self) -> usize { self.vertex_face_offsets.len().saturating_sub(1) }
+
+    /// Returns the number of the faces.
+    #[inline(always)]
+    pub fn num_faces(&self) -> usize { self.face_edge_offsets.len().saturating_sub(1) }
+
+    /// Returns the sorted indices of the faces containing the `vertex`-th position.
+    #[inline(always)]
+    pub fn faces_around_vertex(&self, vertex: usize) -> &[usize] {
+        let range = self.vertex_face_offsets[vertex]..self.vertex_face_offsets[vertex + 1];
+        &self.vertex_faces[range]
+    }
+
+    /// Returns the sorted indices of the faces sharing at least one edge with the `face`-th face.
+    pub fn faces_adjacent_to_face(&self, face: usize) -> Vec<usize> {
+        let range = self.face_edge_offsets[face]..self.face_edge_offsets[face + 1];
+        let mut adjacent = self.face_edges[range]
+            .iter()
+            .flat_map(|edge| self.faces_of_edge(*edge))
+            .copied()
+            .filter(|i| *i != face)
+            .collect::<Vec<_>>();
+        adjacent.sort_unstable();
+        adjacent.dedup();
+        adjacent
+    }
+
+    /// Returns the sorted indices of the faces containing the edge between `v0` and `v1`.
+    /// The order of `v0` and `v1` does not matter.
+    pub fn edge_faces(&self, v0: usize, v1: usize) -> &[usize] {
+        let key = [usize::min(v0, v1), usize::max(v0, v1)];
+        match self.edges.binary_search(&key) {
+            Ok(edge) => self.faces_of_edge(edge),
+            Err(_) => &[],
+        }
+    }
+
+    /// Returns the sorted indices of the positions connected to the `vertex`-th position by edges.
+    #[inline(always)]
+    pub fn vertex_neighbors(&self, vertex: usize) -> &[usize] {
+        let range = self.neighbor_offsets[vertex]..self.neighbor_offsets[vertex + 1];
+        &self.neighbors[range]
+    }
+
+    /// Returns the iterator over the edges contained in only one face, in ascending order.
+    pub fn boundary_edges(&self) -> impl Iterator<Item = [usize; 2]> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.faces_of_edge(*i).len() == 1)
+            .map(|(_, edge)| *edge)
+    }
+
+    #[inline(always)]
+    fn faces_of_edge(&self, edge: usize) -> &[usize] {
+        &self.edge_faces[self.edge_face_offsets[edge]..self.edge_face_offsets[edge + 1]]
+    }
+}
@@ -1,12 +1,20 @@
 use crate::*;
 use polygon_mesh::PolygonMeshEditor;
 
+mod connectivity;
 mod normal_filters;
 mod optimizing;
+mod simplification;
+mod smoothing;
 mod structuring;
 mod subdivision;
 
+pub use connectivity::MeshConnectivity;
 pub use normal_filters::NormalFilters;
-pub use optimizing::OptimizingFilter;
+pub use optimizing::{
+    AttrRemapping, AttrTargets, ClusteringStats, OptimizeOptions, OptimizingFilter,
+};
+pub use simplification::Simplification;
+pub use smoothing::Smoothing;
 pub use structuring::StructuringFilter;
 pub use subdivision::Subdivision;
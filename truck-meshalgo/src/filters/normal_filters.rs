@@ -1,4 +1,5 @@
 use super::*;
+use itertools::Itertools;
 use rustc_hash::FxHashMap as HashMap;
 
 /// Filters for adding normals
@@ -125,6 +126,48 @@ pub trait NormalFilters {
     /// assert!(mesh.normals()[v1.nor.unwrap()].near(&Vector3::new(2.0, 5.0, 0.0).normalize()));
     /// ```
     fn add_smooth_normals(&mut self, tol_ang: f64, overwrite: bool) -> &mut Self;
+    /// Replaces all normals by the ones split at the sharp edges.
+    /// # Details
+    /// Two faces sharing an edge are smoothly connected if the angle between their face normals
+    /// is less than `angle`. The corners of the faces around each vertex are grouped into the
+    /// clusters of the smoothly connected faces, and the normal of each cluster is the normalized
+    /// average of the face normals in the cluster. Every corner of faces gets a normal, which is
+    /// zero if the cluster consists of degenerate faces.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(-5.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 2.0, -2.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 2.0),
+    ///             Point3::new(5.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[0, 2, 1], &[0, 3, 2], &[1, 2, 4], &[2, 3, 4],
+    ///     ]),
+    /// );
+    ///
+    /// // The angle between the faces is about 43.6 degrees.
+    /// mesh.add_normals_with_crease_angle(PI / 3.0);
+    /// let v0: StandardVertex = mesh.faces()[0][1];
+    /// let v1: StandardVertex = mesh.faces()[3][0];
+    /// assert!(mesh.normals()[v0.nor.unwrap()].near(&Vector3::new(0.0, 1.0, 0.0)));
+    /// assert_eq!(v0.nor, v1.nor);
+    ///
+    /// // sharp edge
+    /// mesh.add_normals_with_crease_angle(PI / 6.0);
+    /// let v0: StandardVertex = mesh.faces()[0][1];
+    /// let v1: StandardVertex = mesh.faces()[3][0];
+    /// assert!(mesh.normals()[v0.nor.unwrap()].near(&Vector3::new(-2.0, 5.0, 0.0).normalize()));
+    /// assert!(mesh.normals()[v1.nor.unwrap()].near(&Vector3::new(2.0, 5.0, 0.0).normalize()));
+    /// ```
+    fn add_normals_with_crease_angle(&mut self, angle: f64) -> &mut Self;
     /// Makes the orientation of faces compatible to the normal vectors.
     /// # Examples
     /// ```
@@ -286,6 +329,73 @@ impl NormalFilters for PolygonMesh {
         self.reflect_normal_clusters(vnmap, overwrite);
         self
     }
+    fn add_normals_with_crease_angle(&mut self, angle: f64) -> &mut Self {
+        let edge_faces = self.faces().edge_faces();
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes {
+                positions, normals, ..
+            },
+            faces,
+            ..
+        } = &mut mesh;
+        let face_normals = faces
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| FaceNormal::new(positions, face, i).normal)
+            // degenerate faces have no normals
+            .map(|n| match n.magnitude2().is_finite() {
+                true => n,
+                false => Vector3::zero(),
+            })
+            .collect::<Vec<_>>();
+        // (face index, position index) -> corner index
+        let mut corners = HashMap::<(usize, usize), usize>::default();
+        let mut corner_faces = Vec::new();
+        faces.face_iter().enumerate().for_each(|(i, face)| {
+            face.iter().for_each(|v| {
+                corners.entry((i, v.pos)).or_insert_with(|| {
+                    corner_faces.push(i);
+                    corner_faces.len() - 1
+                });
+            })
+        });
+        let mut parents = (0..corner_faces.len()).collect::<Vec<_>>();
+        let inf = angle.cos();
+        edge_faces.iter().for_each(|(edge, face_ids)| {
+            face_ids.iter().tuple_combinations().for_each(|(i, j)| {
+                if face_normals[*i].dot(face_normals[*j]) > inf {
+                    edge.iter().for_each(|pos| {
+                        union_roots(&mut parents, corners[&(*i, *pos)], corners[&(*j, *pos)])
+                    });
+                }
+            })
+        });
+        let mut sums = vec![Vector3::zero(); corner_faces.len()];
+        corner_faces
+            .iter()
+            .enumerate()
+            .for_each(|(corner, face_id)| {
+                sums[find_root(&mut parents, corner)] += face_normals[*face_id];
+            });
+        let mut cluster_normals = vec![None; corner_faces.len()];
+        normals.clear();
+        faces.face_iter_mut().enumerate().for_each(|(i, face)| {
+            face.iter_mut().for_each(|v| {
+                let root = find_root(&mut parents, corners[&(i, v.pos)]);
+                let idx = *cluster_normals[root].get_or_insert_with(|| {
+                    normals.push(match sums[root].so_small() {
+                        true => Vector3::zero(),
+                        false => sums[root].normalize(),
+                    });
+                    normals.len() - 1
+                });
+                v.nor = Some(idx);
+            })
+        });
+        drop(mesh);
+        self
+    }
 }
 
 trait SubNormalFilter {
@@ -1,7 +1,94 @@
 use super::*;
-use rustc_hash::FxHashMap as HashMap;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::cmp::Ordering;
 use std::iter::Iterator;
-use std::ops::{Div, Mul};
+use std::ops::{Div, Index};
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+use rayon::prelude::*;
+
+/// The correspondence between the attribute indices before and after
+/// [`OptimizingFilter::remove_unused_attrs_with_map`].
+///
+/// For each map, the `i`-th element is the new index of the `i`-th old attribute,
+/// or `None` if the attribute has been removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AttrRemapping {
+    /// the map of the indices of positions
+    pub pos_map: Vec<Option<usize>>,
+    /// the map of the indices of texture coordinates
+    pub uv_map: Vec<Option<usize>>,
+    /// the map of the indices of normals
+    pub nor_map: Vec<Option<usize>>,
+}
+
+/// The kinds of attributes to be put together by
+/// [`OptimizingFilter::put_together_same_attrs_by`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AttrTargets {
+    /// If `true`, the positions are put together.
+    pub positions: bool,
+    /// If `true`, the texture coordinates are put together.
+    pub uv_coords: bool,
+    /// If `true`, the normals are put together.
+    pub normals: bool,
+}
+
+impl AttrTargets {
+    /// all kinds of attributes
+    pub const ALL: Self = Self {
+        positions: true,
+        uv_coords: true,
+        normals: true,
+    };
+    /// only positions
+    pub const POSITIONS: Self = Self {
+        positions: true,
+        uv_coords: false,
+        normals: false,
+    };
+}
+
+impl Default for AttrTargets {
+    #[inline(always)]
+    fn default() -> Self { Self::ALL }
+}
+
+/// The numbers of used positions before and after [`OptimizingFilter::simplify_by_clustering`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClusteringStats {
+    /// the number of positions used by faces before clustering
+    pub vertices_before: usize,
+    /// the number of positions after clustering
+    pub vertices_after: usize,
+}
+
+/// The selection of the filters run by [`OptimizingFilter::optimized`].
+///
+/// The selected filters are run in the standard order: `put_together_same_attrs`,
+/// `remove_degenerate_faces`, and `remove_unused_attrs`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OptimizeOptions {
+    /// If `Some(tol)`, `put_together_same_attrs(tol)` is run.
+    pub put_together_same_attrs: Option<f64>,
+    /// If `true`, `remove_degenerate_faces` is run.
+    pub remove_degenerate_faces: bool,
+    /// If `true`, `remove_unused_attrs` is run.
+    pub remove_unused_attrs: bool,
+}
+
+impl Default for OptimizeOptions {
+    /// all filters with `TOLERANCE`
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            put_together_same_attrs: Some(TOLERANCE),
+            remove_degenerate_faces: true,
+            remove_unused_attrs: true,
+        }
+    }
+}
 
 /// Filters for optimizing data
 pub trait OptimizingFilter {
@@ -29,6 +116,33 @@ pub trait OptimizingFilter {
     /// assert_eq!(mesh.positions().len(), 3);
     /// ```
     fn remove_unused_attrs(&mut self) -> &mut Self;
+    /// remove all unused position, texture coordinates, and normal vectors,
+    /// and returns where each old attribute has been moved.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     // 0 is not used!
+    ///     Faces::from_iter(&[&[1, 3, 2]]),
+    /// );
+    ///
+    /// let remapping = mesh.remove_unused_attrs_with_map();
+    /// assert_eq!(remapping.pos_map, vec![None, Some(0), Some(2), Some(1)]);
+    /// assert!(remapping.uv_map.is_empty());
+    /// assert!(remapping.nor_map.is_empty());
+    /// assert_eq!(mesh.positions()[2], Point3::new(0.0, 1.0, 0.0));
+    /// ```
+    fn remove_unused_attrs_with_map(&mut self) -> AttrRemapping;
     /// Removes degenerate polygons.
     /// # Examples
     /// ```
@@ -56,7 +170,47 @@ pub trait OptimizingFilter {
     /// assert_eq!(mesh.faces().len(), 2);
     /// ```
     fn remove_degenerate_faces(&mut self) -> &mut Self;
+    /// Removes degenerate polygons, including ones with little areas.
+    /// # Details
+    /// After [`remove_degenerate_faces`], the following geometric pass is run:
+    /// - For quadrangles and n-gons, the vertices at which the polygon is flat, i.e. the area of
+    /// the triangle consisting of the vertex and its neighbors is less than `area_tol`, are trimmed
+    /// as long as the polygon has more than three vertices.
+    /// - Polygons whose areas are less than `area_tol` are removed.
+    ///
+    /// [`remove_degenerate_faces`]: ./trait.OptimizingFilter.html#tymethod.remove_degenerate_faces
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 1, 2].as_ref(), // colinear triangle
+    ///         &[0, 1, 2, 3], // quadrangle with a flat corner
+    ///     ]),
+    /// );
+    ///
+    /// mesh.remove_degenerate_faces_with_tol(TOLERANCE);
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// assert_eq!(mesh.faces().tri_faces().len(), 1);
+    /// ```
+    fn remove_degenerate_faces_with_tol(&mut self, area_tol: f64) -> &mut Self;
     /// Gives the same indices to the same positions, texture coordinate, and normal vectors, respectively.
+    /// # Details
+    /// Two attributes are regarded as the same if the distance between them by max-norm is
+    /// less than or equal to `tol`, and this relation is closed transitively.
+    /// The positions are compared after normalized by the bounding box of the mesh.
+    /// The index of each attribute is replaced by the least index in its group.
     /// # Remarks
     /// No longer needed attributes are NOT autoremoved.
     /// One can remove such attributes by running [`remove_unused_attrs`] manually.
@@ -94,6 +248,246 @@ pub trait OptimizingFilter {
     /// assert_eq!(mesh.positions().len(), 4);
     /// ```
     fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self;
+    /// Removes duplicate faces, whose sets of position indices are the same.
+    /// The first one of the duplicate faces survives.
+    /// # Remarks
+    /// Faces with different numbers of vertices are not compared, e.g. a quadrangle
+    /// and the two triangles covering it are not regarded as duplicates.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 1, 2, 3].as_ref(),
+    ///         &[1, 2, 3, 0], // duplicate
+    ///         &[0, 3, 2, 1], // duplicate with opposite orientation
+    ///     ]),
+    /// );
+    ///
+    /// mesh.remove_duplicate_faces();
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// ```
+    fn remove_duplicate_faces(&mut self) -> &mut Self;
+    /// Removes duplicate faces in the same manner as [`remove_duplicate_faces`],
+    /// except that each pair of duplicate faces with the opposite orientations cancels out.
+    ///
+    /// If the numbers of duplicate faces with both orientations are the same, all of them are removed.
+    /// Otherwise, the first face with the major orientation survives.
+    ///
+    /// [`remove_duplicate_faces`]: ./trait.OptimizingFilter.html#tymethod.remove_duplicate_faces
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[0, 1, 2],
+    ///         &[2, 1, 0], // cancels out the first face
+    ///         &[0, 1, 3],
+    ///     ]),
+    /// );
+    ///
+    /// mesh.remove_cancelling_faces();
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// assert_eq!(mesh.faces()[0][2].pos, 3);
+    /// ```
+    fn remove_cancelling_faces(&mut self) -> &mut Self;
+    /// Collapses all edges shorter than `min_length`.
+    /// # Details
+    /// The end points of each short edge are merged at their midpoint, and the degenerate faces
+    /// are removed. A collapse is skipped if it flips an adjacent face.
+    /// The collapsing is repeated until no edges are collapsed, or the number of the iterations
+    /// reaches the fixed upper limit.
+    /// # Remarks
+    /// No longer needed attributes are NOT autoremoved.
+    /// One can remove such attributes by running [`remove_unused_attrs`] manually.
+    ///
+    /// [`remove_unused_attrs`]: ./trait.OptimizingFilter.html#tymethod.remove_unused_attrs
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0e-4, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    ///
+    /// mesh.collapse_short_edges(1.0e-3).remove_unused_attrs();
+    /// assert_eq!(mesh.positions().len(), 3);
+    /// assert_eq!(mesh.faces().tri_faces().len(), 1);
+    /// assert_near!(mesh.positions()[1], Point3::new(1.0, 0.5e-4, 0.0));
+    /// ```
+    fn collapse_short_edges(&mut self, min_length: f64) -> &mut Self;
+    /// Simplifies the mesh by clustering positions on the uniform grid.
+    /// # Details
+    /// The grid with the cell size `cell_size` is overlaid on the bounding box of the mesh.
+    /// All positions in the same cell are merged into their average, and the degenerate faces
+    /// and unused attributes are removed.
+    /// # Panics
+    /// `cell_size` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(0.1, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 3], [1, 2, 3]]),
+    /// );
+    ///
+    /// let stats = mesh.simplify_by_clustering(1.0);
+    /// assert_eq!(stats.vertices_before, 4);
+    /// assert_eq!(stats.vertices_after, 3);
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// assert_near!(mesh.positions()[0], Point3::new(0.05, 0.0, 0.0));
+    /// ```
+    fn simplify_by_clustering(&mut self, cell_size: f64) -> ClusteringStats;
+    /// Gives the same indices to the same attributes only of the kinds specified by `targets`.
+    ///
+    /// `put_together_same_attrs(tol)` is equivalent to `put_together_same_attrs_by(tol, AttrTargets::ALL)`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // Two uv islands sharing the positions on the seam.
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///         ],
+    ///         // the second island is mirrored, so that some coordinates coincide.
+    ///         uv_coords: vec![
+    ///             Vector2::new(0.0, 0.0),
+    ///             Vector2::new(1.0, 0.0),
+    ///             Vector2::new(0.0, 1.0),
+    ///             Vector2::new(1.0, 0.0),
+    ///             Vector2::new(0.0, 1.0),
+    ///             Vector2::new(0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[(0, Some(0), None), (1, Some(1), None), (2, Some(2), None)],
+    ///         &[(3, Some(3), None), (5, Some(5), None), (4, Some(4), None)],
+    ///     ]),
+    /// );
+    ///
+    /// mesh.put_together_same_attrs_by(TOLERANCE, AttrTargets::POSITIONS);
+    /// // positions are merged
+    /// assert_eq!(mesh.faces()[1][0].pos, 1);
+    /// assert_eq!(mesh.faces()[1][2].pos, 2);
+    /// // the indices of uv coordinates are untouched
+    /// assert_eq!(mesh.faces()[1][0].uv, Some(3));
+    /// assert_eq!(mesh.faces()[1][1].uv, Some(5));
+    /// assert_eq!(mesh.faces()[1][2].uv, Some(4));
+    /// ```
+    fn put_together_same_attrs_by(&mut self, tol: f64, targets: AttrTargets) -> &mut Self;
+    /// Gives the same indices to the same attributes in the same manner as [`put_together_same_attrs`],
+    /// and replaces the representative of each group by the average of the group.
+    /// # Details
+    /// - Each position is replaced by the centroid of the merged positions.
+    /// - Each texture coordinate is replaced by the average of the merged coordinates.
+    /// - Each normal is replaced by the normalized average of the merged normals.
+    ///
+    /// The attributes which are not used by any faces are not taken into the averages.
+    ///
+    /// [`put_together_same_attrs`]: ./trait.OptimizingFilter.html#tymethod.put_together_same_attrs
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 1.0e-4),
+    ///             Point3::new(0.0, 1.0, 1.0e-4),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [2, 1, 5], [4, 3, 5]]),
+    /// );
+    ///
+    /// mesh.put_together_same_attrs_averaged(1.0e-3);
+    /// assert_eq!(mesh.faces()[2][0].pos, 2);
+    /// assert_eq!(mesh.faces()[2][1].pos, 1);
+    /// // the merged positions are at the midpoints
+    /// assert_near!(mesh.positions()[1], Point3::new(1.0, 0.0, 0.5e-4));
+    /// assert_near!(mesh.positions()[2], Point3::new(0.0, 1.0, 0.5e-4));
+    /// ```
+    fn put_together_same_attrs_averaged(&mut self, tol: f64) -> &mut Self;
+    /// Returns the new mesh optimized by the filters selected by `options`.
+    /// The original mesh is not modified.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(5.0, 5.0, 5.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 3, 2], &[0, 1, 3]]),
+    /// );
+    ///
+    /// let optimized = mesh.optimized(OptimizeOptions::default());
+    /// assert_eq!(optimized.positions().len(), 3);
+    /// assert_eq!(optimized.faces().len(), 1);
+    /// // the original mesh is untouched
+    /// assert_eq!(mesh.positions().len(), 5);
+    /// assert_eq!(mesh.faces().len(), 2);
+    /// ```
+    fn optimized(&self, options: OptimizeOptions) -> Self;
 }
 
 fn all_pos_mut(faces: &mut Faces) -> impl Iterator<Item = &mut usize> {
@@ -114,8 +508,45 @@ fn all_nor_mut(faces: &mut Faces) -> impl Iterator<Item = &mut usize> {
         .filter_map(move |v| v.nor.as_mut())
 }
 
+/// Applies `f` to all vertices of all faces. Parallelized by `rayon` if the feature is enabled.
+fn for_each_vertex_mut(faces: &mut Faces, f: impl Fn(&mut Vertex) + Send + Sync) {
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    {
+        let f = &f;
+        faces
+            .tri_faces_mut()
+            .par_iter_mut()
+            .for_each(|face| face.iter_mut().for_each(f));
+        faces
+            .quad_faces_mut()
+            .par_iter_mut()
+            .for_each(|face| face.iter_mut().for_each(f));
+        faces
+            .other_faces_mut()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .for_each(|face| face.iter_mut().for_each(f));
+    }
+    #[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+    faces.face_iter_mut().flatten().for_each(f);
+}
+
+/// Returns the attributes picked up by `idcs`. Parallelized by `rayon` if the feature is enabled.
+fn gather_attrs<T: Copy + Send + Sync>(attrs: &[T], idcs: &[usize]) -> Vec<T> {
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    let res = idcs.par_iter().map(|i| attrs[*i]).collect();
+    #[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+    let res = idcs.iter().map(|i| attrs[*i]).collect();
+    res
+}
+
 impl OptimizingFilter for PolygonMesh {
     fn remove_unused_attrs(&mut self) -> &mut Self {
+        self.remove_unused_attrs_with_map();
+        self
+    }
+
+    fn remove_unused_attrs_with_map(&mut self) -> AttrRemapping {
         let mut mesh = self.debug_editor();
         let PolygonMeshEditor {
             attributes:
@@ -128,16 +559,20 @@ impl OptimizingFilter for PolygonMesh {
             ..
         } = &mut mesh;
         let pos_iter = all_pos_mut(faces);
-        let idcs = sub_remove_unused_attrs(pos_iter, positions.len());
-        *positions = idcs.iter().map(|i| positions[*i]).collect();
+        let (idcs, pos_map) = sub_remove_unused_attrs(pos_iter, positions.len());
+        *positions = gather_attrs(positions, &idcs);
         let uv_iter = all_uv_mut(faces);
-        let idcs = sub_remove_unused_attrs(uv_iter, uv_coords.len());
-        *uv_coords = idcs.iter().map(|i| uv_coords[*i]).collect();
+        let (idcs, uv_map) = sub_remove_unused_attrs(uv_iter, uv_coords.len());
+        *uv_coords = gather_attrs(uv_coords, &idcs);
         let nor_iter = all_nor_mut(faces);
-        let idcs = sub_remove_unused_attrs(nor_iter, normals.len());
-        *normals = idcs.iter().map(|i| normals[*i]).collect();
+        let (idcs, nor_map) = sub_remove_unused_attrs(nor_iter, normals.len());
+        *normals = gather_attrs(normals, &idcs);
         drop(mesh);
-        self
+        AttrRemapping {
+            pos_map,
+            uv_map,
+            nor_map,
+        }
     }
 
     fn remove_degenerate_faces(&mut self) -> &mut Self {
@@ -163,7 +598,98 @@ impl OptimizingFilter for PolygonMesh {
         self
     }
 
+    fn remove_degenerate_faces_with_tol(&mut self, area_tol: f64) -> &mut Self {
+        self.remove_degenerate_faces();
+        let mesh = self.debug_editor();
+        let positions = &mesh.attributes.positions;
+        let mut faces = Faces::default();
+        for face in mesh.faces.face_iter() {
+            let face = trim_flat_corners(positions, face.to_vec(), area_tol);
+            let area_vector = polygon_area_vector(face.iter().map(|v| positions[v.pos]));
+            if area_vector.magnitude() / 2.0 >= area_tol {
+                faces.push(face);
+            }
+        }
+        *mesh.faces = faces;
+        drop(mesh);
+        self
+    }
+
+    fn remove_duplicate_faces(&mut self) -> &mut Self {
+        let faces = sub_remove_duplicate_faces(self.faces(), false);
+        *self.debug_editor().faces = faces;
+        self
+    }
+
+    fn remove_cancelling_faces(&mut self) -> &mut Self {
+        let faces = sub_remove_duplicate_faces(self.faces(), true);
+        *self.debug_editor().faces = faces;
+        self
+    }
+
+    fn collapse_short_edges(&mut self, min_length: f64) -> &mut Self {
+        for _ in 0..MAX_COLLAPSING_ITERATIONS {
+            let mut mesh = self.debug_editor();
+            let PolygonMeshEditor {
+                attributes: StandardAttributes { positions, .. },
+                faces,
+                ..
+            } = &mut mesh;
+            let collapsed = collapse_short_edges_once(positions, faces, min_length);
+            drop(mesh);
+            if !collapsed {
+                break;
+            }
+            self.remove_degenerate_faces();
+        }
+        self
+    }
+
+    fn simplify_by_clustering(&mut self, cell_size: f64) -> ClusteringStats {
+        nonpositive_tolerance!(cell_size);
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { positions, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        let bnd_box: BoundingBox<_> = positions.iter().collect();
+        let origin = bnd_box.min();
+        let mut cells = HashMap::<[i64; 3], usize>::default();
+        // the sums of positions and the numbers of positions for each cell
+        let mut sums = Vec::<(Vector3, usize)>::new();
+        let mut pos_map = vec![None; positions.len()];
+        all_pos_mut(faces).for_each(|idx| {
+            let i = *idx;
+            *idx = *pos_map[i].get_or_insert_with(|| {
+                let cell = ((positions[i] - origin) / cell_size).cast_int();
+                let k = *cells.entry(cell).or_insert_with(|| {
+                    sums.push((Vector3::zero(), 0));
+                    sums.len() - 1
+                });
+                sums[k].0 += positions[i].to_vec();
+                sums[k].1 += 1;
+                k
+            });
+        });
+        let vertices_before = pos_map.into_iter().flatten().count();
+        *positions = sums
+            .into_iter()
+            .map(|(sum, n)| Point3::from_vec(sum / n as f64))
+            .collect();
+        drop(mesh);
+        self.remove_degenerate_faces().remove_unused_attrs();
+        ClusteringStats {
+            vertices_before,
+            vertices_after: self.positions().len(),
+        }
+    }
+
     fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self {
+        self.put_together_same_attrs_by(tol, AttrTargets::ALL)
+    }
+
+    fn put_together_same_attrs_by(&mut self, tol: f64, targets: AttrTargets) -> &mut Self {
         let mut mesh = self.debug_editor();
         let PolygonMeshEditor {
             attributes:
@@ -175,28 +701,82 @@ impl OptimizingFilter for PolygonMesh {
             faces,
             ..
         } = &mut mesh;
-        let bnd_box: BoundingBox<_> = positions.iter().collect();
-        let center = bnd_box.center();
-        let diag = bnd_box.diagonal().map(|a| f64::max(a.abs(), 1.0));
-        let normalized_positions = positions
-            .iter()
-            .map(move |position| 2.0 * (position - center).zip(diag, |a, b| a / b))
-            .collect::<Vec<_>>();
-        let pos_map = sub_put_together_same_attrs(&normalized_positions, tol);
-        all_pos_mut(faces).for_each(|idx| *idx = pos_map[*idx]);
+        if targets.positions {
+            let pos_map = sub_put_together_same_attrs(&normalized_positions(positions), tol);
+            for_each_vertex_mut(faces, |v| v.pos = pos_map[v.pos]);
+        }
+        if targets.uv_coords {
+            let uv_map = sub_put_together_same_attrs(uv_coords, tol);
+            for_each_vertex_mut(faces, |v| v.uv = v.uv.map(|idx| uv_map[idx]));
+        }
+        if targets.normals {
+            let nor_map = sub_put_together_same_attrs(normals, tol);
+            for_each_vertex_mut(faces, |v| v.nor = v.nor.map(|idx| nor_map[idx]));
+        }
+        drop(mesh);
+        self
+    }
+
+    fn put_together_same_attrs_averaged(&mut self, tol: f64) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes:
+                StandardAttributes {
+                    positions,
+                    uv_coords,
+                    normals,
+                },
+            faces,
+            ..
+        } = &mut mesh;
+
+        let pos_map = sub_put_together_same_attrs(&normalized_positions(positions), tol);
+        let used = used_flags(all_pos_mut(faces).map(|idx| *idx), positions.len());
+        let vectors = positions.iter().map(|p| p.to_vec()).collect::<Vec<_>>();
+        *positions = group_averages(&vectors, &pos_map, &used)
+            .into_iter()
+            .map(Point3::from_vec)
+            .collect();
+        for_each_vertex_mut(faces, |v| v.pos = pos_map[v.pos]);
+
         let uv_map = sub_put_together_same_attrs(uv_coords, tol);
-        all_uv_mut(faces).for_each(|idx| *idx = uv_map[*idx]);
+        let used = used_flags(all_uv_mut(faces).map(|idx| *idx), uv_coords.len());
+        *uv_coords = group_averages(uv_coords, &uv_map, &used);
+        for_each_vertex_mut(faces, |v| v.uv = v.uv.map(|idx| uv_map[idx]));
+
         let nor_map = sub_put_together_same_attrs(normals, tol);
-        all_nor_mut(faces).for_each(|idx| *idx = nor_map[*idx]);
+        let used = used_flags(all_nor_mut(faces).map(|idx| *idx), normals.len());
+        *normals = group_averages(normals, &nor_map, &used);
+        normals
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, normal)| nor_map[*i] == *i && used[*i] && !normal.so_small())
+            .for_each(|(_, normal)| *normal = normal.normalize());
+        for_each_vertex_mut(faces, |v| v.nor = v.nor.map(|idx| nor_map[idx]));
+
         drop(mesh);
         self
     }
+
+    fn optimized(&self, options: OptimizeOptions) -> Self {
+        let mut mesh = self.clone();
+        if let Some(tol) = options.put_together_same_attrs {
+            mesh.put_together_same_attrs(tol);
+        }
+        if options.remove_degenerate_faces {
+            mesh.remove_degenerate_faces();
+        }
+        if options.remove_unused_attrs {
+            mesh.remove_unused_attrs();
+        }
+        mesh
+    }
 }
 
 fn sub_remove_unused_attrs<'a, I: Iterator<Item = &'a mut usize>>(
     iter: I,
     old_len: usize,
-) -> Vec<usize> {
+) -> (Vec<usize>, Vec<Option<usize>>) {
     let mut new2old = Vec::new();
     let mut old2new = vec![None; old_len];
     for idx in iter {
@@ -210,19 +790,212 @@ fn sub_remove_unused_attrs<'a, I: Iterator<Item = &'a mut usize>>(
             }
         };
     }
-    new2old
+    (new2old, old2new)
 }
 
-fn sub_put_together_same_attrs<T: Copy + CastIntVector>(attrs: &[T], tol: f64) -> Vec<usize> {
-    let mut res = Vec::new();
-    let mut map = HashMap::default();
-    for (i, attr) in attrs.iter().enumerate() {
-        let v = ((*attr).add_element_wise(tol) / (tol * 2.0)).cast_int();
-        res.push(*map.entry(v).or_insert(i));
-    }
+/// Returns the positions normalized by the bounding box.
+fn normalized_positions(positions: &[Point3]) -> Vec<Vector3> {
+    let bnd_box: BoundingBox<_> = positions.iter().collect();
+    let center = bnd_box.center();
+    let diag = bnd_box.diagonal().map(|a| f64::max(a.abs(), 1.0));
+    let normalize = move |position: &Point3| 2.0 * (position - center).zip(diag, |a, b| a / b);
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    let res = positions.par_iter().map(normalize).collect();
+    #[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+    let res = positions.iter().map(normalize).collect();
     res
 }
 
+fn used_flags(idcs: impl Iterator<Item = usize>, len: usize) -> Vec<bool> {
+    let mut used = vec![false; len];
+    idcs.for_each(|i| used[i] = true);
+    used
+}
+
+/// Replaces each representative of the groups given by `map` by the average of the used
+/// attributes in the group.
+fn group_averages<V>(attrs: &[V], map: &[usize], used: &[bool]) -> Vec<V>
+where V: VectorSpace<Scalar = f64> {
+    let mut sums = vec![(V::zero(), 0_usize); attrs.len()];
+    attrs
+        .iter()
+        .zip(map)
+        .zip(used)
+        .filter(|(_, used)| **used)
+        .for_each(|((attr, root), _)| {
+            sums[*root].0 = sums[*root].0 + *attr;
+            sums[*root].1 += 1;
+        });
+    attrs
+        .iter()
+        .zip(sums)
+        .map(|(attr, (sum, n))| match n {
+            0 => *attr,
+            _ => sum / n as f64,
+        })
+        .collect()
+}
+
+fn sub_put_together_same_attrs<T: CastIntVector>(attrs: &[T], tol: f64) -> Vec<usize> {
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    let cells = attrs
+        .par_iter()
+        .map(|attr| (*attr / tol).cast_int())
+        .collect::<Vec<_>>();
+    #[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+    let cells = attrs
+        .iter()
+        .map(|attr| (*attr / tol).cast_int())
+        .collect::<Vec<_>>();
+    // The insertions are serial so that the representatives do not depend on the threads.
+    let mut parents = (0..attrs.len()).collect::<Vec<_>>();
+    let mut grid = HashMap::<T::IntVector, Vec<usize>>::default();
+    for (i, (attr, cell)) in attrs.iter().zip(cells).enumerate() {
+        for key in T::neighbor_cells(cell) {
+            let Some(idcs) = grid.get(&key) else {
+                continue;
+            };
+            for j in idcs {
+                if attr.max_norm_distance(&attrs[*j]) <= tol {
+                    union_roots(&mut parents, i, *j);
+                }
+            }
+        }
+        grid.entry(cell).or_default().push(i);
+    }
+    (0..attrs.len())
+        .map(|i| find_root(&mut parents, i))
+        .collect()
+}
+
+const MAX_COLLAPSING_ITERATIONS: usize = 64;
+
+/// Collapses the short edges which are not adjacent to each other.
+/// Returns `true` if some edges are collapsed.
+fn collapse_short_edges_once(positions: &mut [Point3], faces: &mut Faces, min_length: f64) -> bool {
+    let mut vertex_faces = vec![Vec::new(); positions.len()];
+    let mut edges = HashSet::default();
+    faces.face_iter().enumerate().for_each(|(i, face)| {
+        face.iter().for_each(|v| vertex_faces[v.pos].push(i));
+        face.iter().circular_tuple_windows().for_each(|(v, w)| {
+            if v.pos != w.pos {
+                edges.insert((usize::min(v.pos, w.pos), usize::max(v.pos, w.pos)));
+            }
+        });
+    });
+    let mut edges = edges
+        .into_iter()
+        .map(|(a, b)| (positions[a].distance(positions[b]), a, b))
+        .filter(|(len, _, _)| *len < min_length)
+        .collect::<Vec<_>>();
+    edges.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    let mut locked = vec![false; positions.len()];
+    let mut pos_map = (0..positions.len()).collect::<Vec<_>>();
+    let mut collapsed = false;
+    for (_, a, b) in edges {
+        if locked[a] || locked[b] {
+            continue;
+        }
+        let mid = positions[a].midpoint(positions[b]);
+        let incident = vertex_faces[a].iter().chain(&vertex_faces[b]);
+        let flipped = incident.clone().any(|i| {
+            let face = &faces[*i];
+            if face.iter().any(|v| v.pos == a) && face.iter().any(|v| v.pos == b) {
+                return false;
+            }
+            let before = polygon_area_vector(face.iter().map(|v| positions[v.pos]));
+            let after = polygon_area_vector(face.iter().map(|v| match v.pos == a || v.pos == b {
+                true => mid,
+                false => positions[v.pos],
+            }));
+            before.magnitude2() > 0.0 && before.dot(after) <= 0.0
+        });
+        if flipped {
+            continue;
+        }
+        // Faces around the collapsed edge are fixed in this iteration.
+        incident
+            .flat_map(|i| faces[*i].iter())
+            .for_each(|v| locked[v.pos] = true);
+        positions[a] = mid;
+        positions[b] = mid;
+        pos_map[b] = a;
+        collapsed = true;
+    }
+    all_pos_mut(faces).for_each(|idx| *idx = pos_map[*idx]);
+    collapsed
+}
+
+fn trim_flat_corners(positions: &[Point3], mut face: Vec<Vertex>, area_tol: f64) -> Vec<Vertex> {
+    let mut i = 0;
+    while face.len() > 3 && i < face.len() {
+        let len = face.len();
+        let [p, q, r] = [i + len - 1, i, i + 1].map(|k| positions[face[k % len].pos]);
+        if (q - p).cross(r - p).magnitude() / 2.0 < area_tol {
+            face.remove(i);
+            // The previous corner has to be checked again.
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    face
+}
+
+/// Returns the vector whose direction is the normal and whose length is twice the area.
+pub(super) fn polygon_area_vector(points: impl Iterator<Item = Point3>) -> Vector3 {
+    let points = points.collect::<Vec<_>>();
+    points
+        .iter()
+        .circular_tuple_windows()
+        .fold(Vector3::zero(), |sum, (p, q)| {
+            sum + (p - points[0]).cross(q - points[0])
+        })
+}
+
+fn sub_remove_duplicate_faces(faces: &Faces, cancel_opposite: bool) -> Faces {
+    // sorted position indices -> [(face index, canonical cycle)]
+    let mut groups = HashMap::<Vec<usize>, Vec<(usize, Vec<usize>)>>::default();
+    faces.face_iter().enumerate().for_each(|(i, face)| {
+        let cycle = canonical_cycle(face.iter().map(|v| v.pos));
+        let mut key = cycle.clone();
+        key.sort_unstable();
+        groups.entry(key).or_default().push((i, cycle));
+    });
+    let mut survivors = vec![false; faces.len()];
+    for group in groups.into_values() {
+        if !cancel_opposite {
+            survivors[group[0].0] = true;
+            continue;
+        }
+        let reversed = canonical_cycle(group[0].1.iter().rev().copied());
+        let (positive, negative): (Vec<_>, Vec<_>) =
+            group.into_iter().partition(|(_, cycle)| *cycle != reversed);
+        match positive.len().cmp(&negative.len()) {
+            Ordering::Greater => survivors[positive[0].0] = true,
+            Ordering::Less => survivors[negative[0].0] = true,
+            Ordering::Equal => {}
+        }
+    }
+    faces
+        .face_iter()
+        .zip(survivors)
+        .filter_map(|(face, survive)| survive.then_some(face))
+        .collect()
+}
+
+/// Returns the cycle rotated so that it starts from the least index.
+fn canonical_cycle(iter: impl Iterator<Item = usize>) -> Vec<usize> {
+    let cycle = iter.collect::<Vec<_>>();
+    let start = (0..cycle.len()).min_by_key(|i| cycle[*i]).unwrap_or(0);
+    cycle[start..]
+        .iter()
+        .chain(&cycle[..start])
+        .copied()
+        .collect()
+}
+
 fn degenerate_triangle(tri: [Vertex; 3]) -> bool {
     tri[0].pos == tri[1].pos || tri[1].pos == tri[2].pos || tri[2].pos == tri[0].pos
 }
@@ -265,21 +1038,41 @@ fn split_into_nondegenerate(poly: Vec<Vertex>) -> Vec<Vec<Vertex>> {
     vec![poly]
 }
 
-trait CastIntVector:
-    Sized + ElementWise<f64> + Mul<f64, Output = Self> + Div<f64, Output = Self> {
-    type IntVector: Copy + std::hash::Hash + Eq;
+trait CastIntVector: Copy + Send + Sync + Index<usize, Output = f64> + Div<f64, Output = Self> {
+    type IntVector: Copy + Send + std::hash::Hash + Eq;
+    /// the fixed-size array of the cells around a cell
+    type NeighborCells: IntoIterator<Item = Self::IntVector>;
+    /// Returns the lattice point by flooring each component.
     fn cast_int(&self) -> Self::IntVector;
+    /// Returns the cell itself and all cells adjacent to it.
+    fn neighbor_cells(cell: Self::IntVector) -> Self::NeighborCells;
+    fn max_norm_distance(&self, other: &Self) -> f64;
 }
 
 macro_rules! impl_cast_int {
     ($typename: ident, $n: expr) => {
         impl CastIntVector for $typename {
             type IntVector = [i64; $n];
+            type NeighborCells = [[i64; $n]; usize::pow(3, $n)];
             fn cast_int(&self) -> [i64; $n] {
-                self.cast::<i64>()
+                self.map(f64::floor)
+                    .cast::<i64>()
                     .unwrap_or_else(|| panic!("failed to cast: {self:?}"))
                     .into()
             }
+            fn neighbor_cells(cell: [i64; $n]) -> Self::NeighborCells {
+                std::array::from_fn(|mut k| {
+                    let mut res = cell;
+                    res.iter_mut().for_each(|x| {
+                        *x += (k % 3) as i64 - 1;
+                        k /= 3;
+                    });
+                    res
+                })
+            }
+            fn max_norm_distance(&self, other: &Self) -> f64 {
+                (0..$n).fold(0.0, |dist, i| f64::max(dist, (self[i] - other[i]).abs()))
+            }
         }
     };
 }
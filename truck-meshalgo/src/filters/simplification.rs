@@ -0,0 +1,322 @@
+use super::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// mesh simplification algorithms
+pub trait Simplification {
+    /// Decreases the number of the faces to `target_faces` by the quadric error metrics.
+    /// # Details
+    /// The algorithm is the one by Garland and Heckbert:
+    /// 1. triangulate all faces,
+    /// 1. for each vertex, sum up the quadrics of the planes of adjacent faces. The quadrics of
+    /// the planes perpendicular to the faces along boundary edges are added with a penalty,
+    /// 1. collapse the edge with the least quadric error in turn, until the number of faces
+    /// becomes `target_faces` or less.
+    ///
+    /// Collapses which flip some faces or break the manifold condition are skipped, so the
+    /// number of the faces may be more than `target_faces` after simplification.
+    /// # Remarks
+    /// Texture coordinates and normals are dropped, and unused positions are removed.
+    /// Run [`NormalFilters`] to re-estimate normals.
+    ///
+    /// [`NormalFilters`]: ./trait.NormalFilters.html
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// // a plane consisting of 10 x 10 quadrangles
+    /// let positions = (0..=10)
+    ///     .flat_map(|i| (0..=10).map(move |j| Point3::new(i as f64, j as f64, 0.0)))
+    ///     .collect::<Vec<_>>();
+    /// let faces = Faces::from_iter((0..10).flat_map(|i| {
+    ///     (0..10).map(move |j| [i * 11 + j, (i + 1) * 11 + j, (i + 1) * 11 + j + 1, i * 11 + j + 1])
+    /// }));
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// mesh.simplify_qem(20);
+    /// assert!(mesh.faces().len() <= 20);
+    /// // The boundary is preserved.
+    /// let bdd_box = mesh.bounding_box();
+    /// assert_near!(bdd_box.min(), Point3::new(0.0, 0.0, 0.0));
+    /// assert_near!(bdd_box.max(), Point3::new(10.0, 10.0, 0.0));
+    /// ```
+    fn simplify_qem(&mut self, target_faces: usize) -> &mut Self;
+}
+
+impl Simplification for PolygonMesh {
+    fn simplify_qem(&mut self, target_faces: usize) -> &mut Self {
+        self.triangulate();
+        let faces = self
+            .tri_faces()
+            .iter()
+            .map(|face| [face[0].pos, face[1].pos, face[2].pos])
+            .collect::<Vec<_>>();
+        let mut simplifier = QemSimplifier::new(self.positions().clone(), faces);
+        simplifier.simplify(target_faces);
+        let QemSimplifier {
+            positions,
+            faces,
+            alive,
+            ..
+        } = simplifier;
+        let tri_faces = faces
+            .into_iter()
+            .zip(alive)
+            .filter_map(|(face, alive)| alive.then(|| face.map(Vertex::from)))
+            .collect::<Vec<_>>();
+        {
+            let editor = self.debug_editor();
+            *editor.attributes = StandardAttributes {
+                positions,
+                ..Default::default()
+            };
+            *editor.faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
+        }
+        self.remove_unused_attrs()
+    }
+}
+
+/// the weight of the quadrics for boundary edges
+const BOUNDARY_PENALTY: f64 = 1.0e3;
+
+#[derive(Clone, Debug)]
+struct QemSimplifier {
+    positions: Vec<Point3>,
+    quadrics: Vec<Matrix4>,
+    faces: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    vertex_faces: Vec<Vec<usize>>,
+    // incremented for each modification of the vertex, in order to detect outdated candidates.
+    stamps: Vec<usize>,
+    num_faces: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    cost: f64,
+    position: Point3,
+    vertices: [usize; 2],
+    stamps: [usize; 2],
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Candidate {
+    // reversed in order to pop the least cost from `BinaryHeap`.
+    fn cmp(&self, other: &Self) -> Ordering { other.cost.total_cmp(&self.cost) }
+}
+
+#[inline(always)]
+fn edge_key(v0: usize, v1: usize) -> [usize; 2] { [usize::min(v0, v1), usize::max(v0, v1)] }
+
+#[inline(always)]
+fn triangle_area_vector([p, q, r]: [Point3; 3]) -> Vector3 { (q - p).cross(r - p) }
+
+/// the quadric of the squared distance from the plane multiplied by `weight`
+fn plane_quadric(normal: Vector3, point: Point3, weight: f64) -> Matrix4 {
+    let p = normal.extend(-normal.dot(point.to_vec()));
+    Matrix4::from_cols(p * p.x, p * p.y, p * p.z, p * p.w) * weight
+}
+
+#[inline(always)]
+fn quadric_error(quadric: &Matrix4, position: Point3) -> f64 {
+    let v = position.to_homogeneous();
+    v.dot(*quadric * v)
+}
+
+impl QemSimplifier {
+    fn new(positions: Vec<Point3>, faces: Vec<[usize; 3]>) -> Self {
+        let len = positions.len();
+        let mut quadrics = vec![Matrix4::zero(); len];
+        let mut vertex_faces = vec![Vec::new(); len];
+        let mut edge_count = HashMap::<[usize; 2], usize>::default();
+        faces.iter().enumerate().for_each(|(i, face)| {
+            let area_vector = triangle_area_vector(face.map(|v| positions[v]));
+            let area = area_vector.magnitude() / 2.0;
+            let quadric = match area > 0.0 {
+                true => plane_quadric(area_vector.normalize(), positions[face[0]], area),
+                false => Matrix4::zero(),
+            };
+            face.iter().for_each(|v| {
+                quadrics[*v] += quadric;
+                vertex_faces[*v].push(i);
+            });
+            (0..3).for_each(|k| {
+                *edge_count
+                    .entry(edge_key(face[k], face[(k + 1) % 3]))
+                    .or_insert(0) += 1;
+            });
+        });
+        faces.iter().for_each(|face| {
+            let normal = triangle_area_vector(face.map(|v| positions[v]));
+            (0..3).for_each(|k| {
+                let (v0, v1) = (face[k], face[(k + 1) % 3]);
+                if edge_count[&edge_key(v0, v1)] != 1 {
+                    return;
+                }
+                let dir = positions[v1] - positions[v0];
+                let perp = dir.cross(normal);
+                if perp.magnitude2() > 0.0 {
+                    let weight = dir.magnitude2() * BOUNDARY_PENALTY;
+                    let quadric = plane_quadric(perp.normalize(), positions[v0], weight);
+                    quadrics[v0] += quadric;
+                    quadrics[v1] += quadric;
+                }
+            });
+        });
+        Self {
+            quadrics,
+            alive: vec![true; faces.len()],
+            num_faces: faces.len(),
+            stamps: vec![0; len],
+            positions,
+            faces,
+            vertex_faces,
+        }
+    }
+
+    fn candidate(&self, v0: usize, v1: usize) -> Candidate {
+        let quadric = self.quadrics[v0] + self.quadrics[v1];
+        let (p0, p1) = (self.positions[v0], self.positions[v1]);
+        let mid = p0.midpoint(p1);
+        let mat = Matrix3::from_cols(
+            quadric.x.truncate(),
+            quadric.y.truncate(),
+            quadric.z.truncate(),
+        );
+        let optimal = mat
+            .invert()
+            .map(|inv| Point3::from_vec(inv * (-quadric.w.truncate())))
+            // too far points are caused by ill-conditioned quadrics.
+            .filter(|p| p.distance(mid) <= p0.distance(p1));
+        let position = optimal.unwrap_or_else(|| {
+            [p0, p1, mid]
+                .into_iter()
+                .min_by(|p, q| quadric_error(&quadric, *p).total_cmp(&quadric_error(&quadric, *q)))
+                .unwrap()
+        });
+        Candidate {
+            cost: quadric_error(&quadric, position),
+            position,
+            vertices: [v0, v1],
+            stamps: [self.stamps[v0], self.stamps[v1]],
+        }
+    }
+
+    fn neighbors(&self, v: usize) -> HashSet<usize> {
+        self.vertex_faces[v]
+            .iter()
+            .filter(|f| self.alive[**f])
+            .flat_map(|f| self.faces[*f])
+            .filter(|w| *w != v)
+            .collect()
+    }
+
+    fn simplify(&mut self, target_faces: usize) {
+        // Rejected collapses may become possible after other collapses,
+        // so the queue is rebuilt until no edges are collapsed.
+        let mut collapsed = true;
+        while collapsed && self.num_faces > target_faces {
+            collapsed = false;
+            let edges = self
+                .faces
+                .iter()
+                .zip(&self.alive)
+                .filter(|(_, alive)| **alive)
+                .flat_map(|(face, _)| (0..3).map(move |k| edge_key(face[k], face[(k + 1) % 3])))
+                .collect::<HashSet<_>>();
+            let mut heap = edges
+                .into_iter()
+                .map(|[v0, v1]| self.candidate(v0, v1))
+                .collect::<BinaryHeap<_>>();
+            while self.num_faces > target_faces {
+                let Some(candidate) = heap.pop() else {
+                    break;
+                };
+                let [v0, v1] = candidate.vertices;
+                if [self.stamps[v0], self.stamps[v1]] != candidate.stamps {
+                    continue;
+                }
+                if self.collapse(v0, v1, candidate.position) {
+                    collapsed = true;
+                    self.neighbors(v0)
+                        .into_iter()
+                        .for_each(|w| heap.push(self.candidate(v0, w)));
+                }
+            }
+        }
+    }
+
+    /// Merges `v1` into `v0`. Returns `false` if the collapse is rejected.
+    fn collapse(&mut self, v0: usize, v1: usize, position: Point3) -> bool {
+        let shared = self.vertex_faces[v0]
+            .iter()
+            .filter(|f| self.alive[**f] && self.faces[**f].contains(&v1))
+            .count();
+        if shared == 0 {
+            return false;
+        }
+        // link condition for keeping manifold
+        let common = self.neighbors(v0).intersection(&self.neighbors(v1)).count();
+        if common != shared {
+            return false;
+        }
+        let flipped = self.vertex_faces[v0]
+            .iter()
+            .chain(&self.vertex_faces[v1])
+            .filter(|f| self.alive[**f])
+            .any(|f| {
+                let face = self.faces[*f];
+                if face.contains(&v0) && face.contains(&v1) {
+                    return false;
+                }
+                let before = triangle_area_vector(face.map(|v| self.positions[v]));
+                let after = triangle_area_vector(face.map(|v| match v == v0 || v == v1 {
+                    true => position,
+                    false => self.positions[v],
+                }));
+                before.magnitude2() > 0.0 && before.dot(after) <= 0.0
+            });
+        if flipped {
+            return false;
+        }
+        for f in std::mem::take(&mut self.vertex_faces[v1]) {
+            if !self.alive[f] {
+                continue;
+            }
+            if self.faces[f].contains(&v0) {
+                self.alive[f] = false;
+                self.num_faces -= 1;
+            } else {
+                self.faces[f]
+                    .iter_mut()
+                    .filter(|v| **v == v1)
+                    .for_each(|v| *v = v0);
+                self.vertex_faces[v0].push(f);
+            }
+        }
+        self.vertex_faces[v0].retain(|f| self.alive[*f]);
+        self.positions[v0] = position;
+        self.quadrics[v0] = self.quadrics[v0] + self.quadrics[v1];
+        self.stamps[v0] += 1;
+        self.stamps[v1] += 1;
+        true
+    }
+}
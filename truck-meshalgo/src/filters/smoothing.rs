@@ -0,0 +1,150 @@
+use super::*;
+
+/// mesh smoothing algorithms
+pub trait Smoothing {
+    /// Smooths the mesh by the λ|μ algorithm by Taubin.
+    /// # Details
+    /// Each iteration consists of two Laplacian steps: the shrinking step by `lambda` and the
+    /// inflating step by `mu`. In each step, every position `p` moves to `p + factor * (q - p)`,
+    /// where `q` is the average of the positions adjacent to `p` by edges, i.e. the weights of
+    /// the one-ring are uniform. In order to prevent the mesh from shrinking, `lambda` should be
+    /// positive and `mu` should be negative with `-mu > lambda`, e.g. `lambda = 0.5` and `mu = -0.53`.
+    ///
+    /// If `preserve_boundary == true`, the positions on the boundary edges do not move.
+    /// # Remarks
+    /// The connectivity is determined by the indices of positions, so run
+    /// [`OptimizingFilter::put_together_same_attrs`] in advance if needed.
+    ///
+    /// Normals are invalidated: the normals are cleared and all `nor` indices are set to `None`.
+    /// Run [`NormalFilters`] to re-estimate normals.
+    ///
+    /// [`OptimizingFilter::put_together_same_attrs`]: ./trait.OptimizingFilter.html#tymethod.put_together_same_attrs
+    /// [`NormalFilters`]: ./trait.NormalFilters.html
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// // a plane with a spike
+    /// let positions = (0..5)
+    ///     .flat_map(|i| (0..5).map(move |j| Point3::new(i as f64, j as f64, 0.0)))
+    ///     .map(|p| match p == Point3::new(2.0, 2.0, 0.0) {
+    ///         true => Point3::new(2.0, 2.0, 1.0),
+    ///         false => p,
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let faces = Faces::from_iter((0..4).flat_map(|i| {
+    ///     (0..4).map(move |j| [i * 5 + j, (i + 1) * 5 + j, (i + 1) * 5 + j + 1, i * 5 + j + 1])
+    /// }));
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// mesh.taubin_smooth(0.5, -0.53, 10, true);
+    /// // The spike is smoothed.
+    /// assert!(mesh.positions()[12].z < 0.5);
+    /// // The boundary is preserved.
+    /// assert_eq!(mesh.positions()[0], Point3::new(0.0, 0.0, 0.0));
+    /// assert_eq!(mesh.positions()[24], Point3::new(4.0, 4.0, 0.0));
+    /// ```
+    fn taubin_smooth(
+        &mut self,
+        lambda: f64,
+        mu: f64,
+        iterations: usize,
+        preserve_boundary: bool,
+    ) -> &mut Self {
+        self.taubin_smooth_with_connectivity(lambda, mu, iterations, preserve_boundary, None)
+    }
+    /// [`taubin_smooth`] with the connectivity built in advance.
+    /// # Details
+    /// If `connectivity` is `None`, the connectivity is built from the mesh itself.
+    /// Passing the connectivity avoids rebuilding it when several filters are run in turn.
+    /// # Panics
+    /// Panics if the numbers of the positions or faces of `connectivity` are different from the mesh.
+    ///
+    /// [`taubin_smooth`]: Smoothing::taubin_smooth
+    fn taubin_smooth_with_connectivity(
+        &mut self,
+        lambda: f64,
+        mu: f64,
+        iterations: usize,
+        preserve_boundary: bool,
+        connectivity: Option<&MeshConnectivity>,
+    ) -> &mut Self;
+}
+
+impl Smoothing for PolygonMesh {
+    fn taubin_smooth_with_connectivity(
+        &mut self,
+        lambda: f64,
+        mu: f64,
+        iterations: usize,
+        preserve_boundary: bool,
+        connectivity: Option<&MeshConnectivity>,
+    ) -> &mut Self {
+        let built;
+        let connectivity = match connectivity {
+            Some(connectivity) => connectivity,
+            None => {
+                built = MeshConnectivity::new(self);
+                &built
+            }
+        };
+        assert_eq!(connectivity.num_vertices(), self.positions().len());
+        assert_eq!(connectivity.num_faces(), self.faces().len());
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes {
+                positions, normals, ..
+            },
+            faces,
+            ..
+        } = &mut mesh;
+        let mut fixed = vec![false; positions.len()];
+        if preserve_boundary {
+            connectivity
+                .boundary_edges()
+                .for_each(|edge| edge.iter().for_each(|i| fixed[*i] = true));
+        }
+        (0..iterations).for_each(|_| {
+            laplacian_step(positions, connectivity, &fixed, lambda);
+            laplacian_step(positions, connectivity, &fixed, mu);
+        });
+        normals.clear();
+        faces.face_iter_mut().flatten().for_each(|v| v.nor = None);
+        drop(mesh);
+        self
+    }
+}
+
+fn laplacian_step(
+    positions: &mut [Point3],
+    connectivity: &MeshConnectivity,
+    fixed: &[bool],
+    factor: f64,
+) {
+    let laplacians = (0..positions.len())
+        .map(|i| {
+            let ring = connectivity.vertex_neighbors(i);
+            match ring.is_empty() || fixed[i] {
+                true => Vector3::zero(),
+                false => {
+                    let sum = ring
+                        .iter()
+                        .fold(Vector3::zero(), |sum, j| sum + positions[*j].to_vec());
+                    sum / ring.len() as f64 - positions[i].to_vec()
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    positions
+        .iter_mut()
+        .zip(laplacians)
+        .for_each(|(p, laplacian)| *p += factor * laplacian);
+}
@@ -1,4 +1,8 @@
+use super::optimizing::polygon_area_vector;
 use super::*;
+use crate::errors::Error;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::collections::VecDeque;
 
 /// triangulation, quadrangulation, give a structure
 pub trait StructuringFilter {
@@ -35,6 +39,47 @@ pub trait StructuringFilter {
     /// assert_eq!(mesh.faces().len(), 12);
     /// ```
     fn triangulate(&mut self) -> &mut Self;
+    /// triangulate all quadrangles and n-gons by ear clipping
+    /// # Details
+    /// Each polygon is projected onto its best-fit plane, the plane perpendicular to the normal
+    /// given by Newell's method, and is decomposed by ear clipping there. Only convex planar
+    /// polygons are decomposed into fans. The texture coordinates and normals of each corner
+    /// are preserved.
+    ///
+    /// Unlike [`triangulate`], this filter decomposes concave polygons and non-planar quadrangles
+    /// into valid triangles.
+    ///
+    /// [`triangulate`]: ./trait.StructuringFilter.html#tymethod.triangulate
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// // L-shaped hexagon
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(2.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 2.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3, 4, 5]]),
+    /// );
+    ///
+    /// mesh.robust_triangulate_faces();
+    /// assert_eq!(mesh.faces().len(), 4);
+    /// for face in mesh.tri_faces() {
+    ///     let [p, q, r] = face.map(|v| mesh.positions()[v.pos]);
+    ///     assert!((q - p).cross(r - p).z > 0.0);
+    /// }
+    /// ```
+    fn robust_triangulate_faces(&mut self) -> &mut Self;
     /// join two triangles into one quadrangle.
     /// # Arguments
     /// * `plane_tol` - the tolerance for determining that four points are in the same plane
@@ -84,6 +129,97 @@ pub trait StructuringFilter {
     /// assert_eq!(mesh.faces().len(), 6);
     /// ```
     fn quadrangulate(&mut self, plane_tol: f64, score_tol: f64) -> &mut Self;
+    /// merge the coplanar adjacent faces into polygons
+    /// # Details
+    /// 1. The faces are grouped into the maximal connected regions, in which the distance between
+    /// the face normal of each face and the one of the first face is less than `normal_tol`.
+    /// 1. If the boundary of a region is one simple loop, the faces in the region are replaced
+    /// by one polygon along the loop. The regions with holes are kept as they are.
+    /// 1. The vertices on the loops at which the loops are straight are dropped, unless they are
+    /// used by some faces which are not merged or at which some other loop is not straight.
+    ///
+    /// The attributes of the vertices dropped from faces are NOT removed.
+    /// One can remove such attributes by running [`OptimizingFilter::remove_unused_attrs`].
+    ///
+    /// [`OptimizingFilter::remove_unused_attrs`]: ./trait.OptimizingFilter.html#tymethod.remove_unused_attrs
+    ///
+    /// # Panics
+    /// `normal_tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// // a square consisting of four triangles
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.5, 0.5, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]]),
+    /// );
+    ///
+    /// mesh.merge_coplanar_faces(TOLERANCE);
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// assert_eq!(mesh.faces().quad_faces().len(), 1);
+    /// ```
+    fn merge_coplanar_faces(&mut self, normal_tol: f64) -> &mut Self;
+    /// make the orientations of the faces consistent, and returns the number of flipped faces.
+    /// # Details
+    /// The faces are traversed by breadth first search over the edges shared by exactly two
+    /// faces, and each face is flipped if its winding disagrees with the visited neighbor.
+    /// In each connected component, the orientation of the majority of the faces is kept.
+    /// The normals of the flipped faces are negated.
+    /// # Errors
+    /// Returns [`Error::NonOrientable`] if the mesh is not orientable, e.g. a Möbius strip.
+    /// In this case, the mesh is not modified.
+    ///
+    /// [`Error::NonOrientable`]: crate::errors::Error::NonOrientable
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     // The last face is flipped.
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3], [1, 2, 4]]),
+    /// );
+    ///
+    /// assert_eq!(mesh.heal_orientation(), Ok(1));
+    /// assert_eq!(mesh.faces().tri_faces()[2], [4, 2, 1].map(StandardVertex::from));
+    /// ```
+    fn heal_orientation(&mut self) -> Result<usize, Error> {
+        self.heal_orientation_with_connectivity(None)
+    }
+    /// [`heal_orientation`] with the connectivity built in advance.
+    /// # Details
+    /// If `connectivity` is `None`, the connectivity is built from the mesh itself.
+    /// Passing the connectivity avoids rebuilding it when several filters are run in turn.
+    /// # Panics
+    /// Panics if the numbers of the positions or faces of `connectivity` are different from the mesh.
+    ///
+    /// [`heal_orientation`]: StructuringFilter::heal_orientation
+    fn heal_orientation_with_connectivity(
+        &mut self,
+        connectivity: Option<&MeshConnectivity>,
+    ) -> Result<usize, Error>;
 }
 
 impl StructuringFilter for PolygonMesh {
@@ -92,6 +228,20 @@ impl StructuringFilter for PolygonMesh {
         *self.debug_editor().faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
         self
     }
+    fn robust_triangulate_faces(&mut self) -> &mut Self {
+        let mesh = self.debug_editor();
+        let positions = &mesh.attributes.positions;
+        let mut tri_faces = mesh.faces.tri_faces().clone();
+        mesh.faces
+            .quad_faces()
+            .iter()
+            .map(|face| face.as_slice())
+            .chain(mesh.faces.other_faces().iter().map(|face| face.as_slice()))
+            .for_each(|face| ear_clipping(positions, face, &mut tri_faces));
+        *mesh.faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
+        drop(mesh);
+        self
+    }
     fn quadrangulate(&mut self, plane_tol: f64, score_tol: f64) -> &mut Self {
         nonpositive_tolerance!(plane_tol, 0.0);
         nonpositive_tolerance!(score_tol, 0.0);
@@ -99,6 +249,205 @@ impl StructuringFilter for PolygonMesh {
         self.reflect_face_edge_list(list);
         self
     }
+    fn merge_coplanar_faces(&mut self, normal_tol: f64) -> &mut Self {
+        nonpositive_tolerance!(normal_tol);
+        let edge_faces = self.faces().edge_faces();
+        let mesh = self.debug_editor();
+        let positions = &mesh.attributes.positions;
+        let face_normals = mesh
+            .faces
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| FaceNormal::new(positions, face, i).normal)
+            .collect::<Vec<_>>();
+        let regions = coplanar_regions(mesh.faces, &edge_faces, &face_normals, normal_tol);
+        let loops = regions
+            .iter()
+            .map(|region| match region.len() > 1 {
+                true => boundary_loop(mesh.faces, region),
+                false => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut merged = vec![false; mesh.faces.len()];
+        regions.iter().zip(&loops).for_each(|(region, boundary)| {
+            if boundary.is_some() {
+                region.iter().for_each(|i| merged[*i] = true);
+            }
+        });
+        let mut droppable = vec![true; positions.len()];
+        mesh.faces
+            .face_iter()
+            .zip(&merged)
+            .filter(|(_, merged)| !**merged)
+            .for_each(|(face, _)| face.iter().for_each(|v| droppable[v.pos] = false));
+        loops.iter().flatten().for_each(|boundary| {
+            let len = boundary.len();
+            (0..len).for_each(|i| {
+                let [p, q, r] = [i + len - 1, i, i + 1].map(|k| positions[boundary[k % len].pos]);
+                if !is_straight(p, q, r) {
+                    droppable[boundary[i].pos] = false;
+                }
+            })
+        });
+
+        let mut faces = Faces::default();
+        mesh.faces
+            .face_iter()
+            .zip(&merged)
+            .filter(|(_, merged)| !**merged)
+            .for_each(|(face, _)| faces.push(face));
+        loops.into_iter().flatten().for_each(|boundary| {
+            let face = boundary
+                .iter()
+                .filter(|v| !droppable[v.pos])
+                .copied()
+                .collect::<Vec<_>>();
+            match face.len() >= 3 {
+                true => faces.push(face),
+                false => faces.push(boundary),
+            }
+        });
+        *mesh.faces = faces;
+        drop(mesh);
+        self
+    }
+    fn heal_orientation_with_connectivity(
+        &mut self,
+        connectivity: Option<&MeshConnectivity>,
+    ) -> Result<usize, Error> {
+        let built;
+        let connectivity = match connectivity {
+            Some(connectivity) => connectivity,
+            None => {
+                built = MeshConnectivity::new(self);
+                &built
+            }
+        };
+        assert_eq!(connectivity.num_vertices(), self.positions().len());
+        assert_eq!(connectivity.num_faces(), self.faces().len());
+        let flipped = orientation_flips(self.faces(), connectivity)?;
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { normals, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        // The normals used only by the flipped faces are negated in place, and the others are
+        // negated as new normals.
+        let mut exclusive = vec![true; normals.len()];
+        faces
+            .face_iter()
+            .zip(&flipped)
+            .filter(|(_, flipped)| !**flipped)
+            .for_each(|(face, _)| {
+                face.iter()
+                    .filter_map(|v| v.nor)
+                    .for_each(|nor| exclusive[nor] = false)
+            });
+        let mut negated = HashMap::<usize, usize>::default();
+        let flipped_faces = faces
+            .face_iter_mut()
+            .zip(&flipped)
+            .filter(|(_, flipped)| **flipped);
+        for (face, _) in flipped_faces {
+            face.reverse();
+            for nor in face.iter_mut().filter_map(|v| v.nor.as_mut()) {
+                let idx = *nor;
+                *nor = *negated.entry(idx).or_insert_with(|| match exclusive[idx] {
+                    true => {
+                        normals[idx] = -normals[idx];
+                        idx
+                    }
+                    false => {
+                        normals.push(-normals[idx]);
+                        normals.len() - 1
+                    }
+                });
+            }
+        }
+        drop(mesh);
+        Ok(flipped.into_iter().filter(|flipped| *flipped).count())
+    }
+}
+
+/// Returns whether each face should be flipped for consistent orientation.
+fn orientation_flips(faces: &Faces, connectivity: &MeshConnectivity) -> Result<Vec<bool>, Error> {
+    let faces = faces.face_iter().collect::<Vec<_>>();
+    let face_edges = |face: &[Vertex]| {
+        let len = face.len();
+        (0..len).map(move |k| [face[k].pos, face[(k + 1) % len].pos])
+    };
+    let mut flips = vec![None; faces.len()];
+    // the parents in the trees of breadth first search; the root is the parent of itself.
+    let mut parents = (0..faces.len()).collect::<Vec<_>>();
+    for seed in 0..faces.len() {
+        if flips[seed].is_some() {
+            continue;
+        }
+        flips[seed] = Some(false);
+        let mut component = vec![seed];
+        let mut queue = VecDeque::from([seed]);
+        while let Some(i) = queue.pop_front() {
+            let flip = flips[i] == Some(true);
+            for [v, w] in face_edges(faces[i]).filter(|[v, w]| v != w) {
+                let &[f0, f1] = connectivity.edge_faces(v, w) else {
+                    continue;
+                };
+                let j = match f0 == i {
+                    true => f1,
+                    false => f0,
+                };
+                if j == i {
+                    continue;
+                }
+                let same_direction = face_edges(faces[j]).any(|edge| edge == [v, w]);
+                let required = flip ^ same_direction;
+                match flips[j] {
+                    None => {
+                        flips[j] = Some(required);
+                        parents[j] = i;
+                        component.push(j);
+                        queue.push_back(j);
+                    }
+                    Some(flip) if flip != required => {
+                        return Err(Error::NonOrientable(face_cycle(&parents, i, j)))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        let num_flips = component
+            .iter()
+            .filter(|i| flips[**i] == Some(true))
+            .count();
+        if 2 * num_flips > component.len() {
+            for i in component {
+                flips[i] = Some(flips[i] != Some(true));
+            }
+        }
+    }
+    Ok(flips.into_iter().map(|flip| flip == Some(true)).collect())
+}
+
+/// the cycle consisting of the paths from `i` and `j` to their common ancestor
+fn face_cycle(parents: &[usize], i: usize, j: usize) -> Vec<usize> {
+    let path_to_root = |mut i: usize| {
+        let mut path = vec![i];
+        while parents[i] != i {
+            i = parents[i];
+            path.push(i);
+        }
+        path
+    };
+    let (mut path0, mut path1) = (path_to_root(i), path_to_root(j));
+    while path0.len() > 1 && path1.len() > 1 && path0[path0.len() - 2] == path1[path1.len() - 2] {
+        path0.pop();
+        path1.pop();
+    }
+    path1.pop();
+    path0.extend(path1.into_iter().rev());
+    path0
 }
 
 trait SubStructureFilter {
@@ -223,6 +572,157 @@ struct FaceEdge {
     score: f64,
 }
 
+/// Decomposes `face` into triangles and appends them to `tri_faces`.
+fn ear_clipping(positions: &[Point3], face: &[Vertex], tri_faces: &mut Vec<[Vertex; 3]>) {
+    let points = face.iter().map(|v| positions[v.pos]).collect::<Vec<_>>();
+    let normal = polygon_area_vector(points.iter().copied());
+    if normal.so_small() {
+        tri_faces.extend((2..face.len()).map(|i| [face[0], face[i - 1], face[i]]));
+        return;
+    }
+    let normal = normal.normalize();
+    let axis = match normal.x.abs() < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u_axis = axis.cross(normal).normalize();
+    let v_axis = normal.cross(u_axis);
+    let origin = points[0];
+    let planar = points
+        .iter()
+        .all(|p| (p - origin).dot(normal).abs() < TOLERANCE);
+    // Since `normal` is given by Newell's method, the projected polygon is counterclockwise.
+    let uv = points
+        .iter()
+        .map(|p| Point2::new((p - origin).dot(u_axis), (p - origin).dot(v_axis)))
+        .collect::<Vec<_>>();
+    let len = uv.len();
+    let corner = |a: usize, b: usize, c: usize| (uv[b] - uv[a]).perp_dot(uv[c] - uv[b]);
+    let convex = (0..len).all(|i| corner((i + len - 1) % len, i, (i + 1) % len) > 0.0);
+    if planar && convex {
+        tri_faces.extend((2..len).map(|i| [face[0], face[i - 1], face[i]]));
+        return;
+    }
+    let mut indices = (0..len).collect::<Vec<_>>();
+    while indices.len() > 3 {
+        let n = indices.len();
+        let triangle = |i: usize| [indices[(i + n - 1) % n], indices[i], indices[(i + 1) % n]];
+        let is_ear = |i: usize| {
+            let [a, b, c] = triangle(i);
+            corner(a, b, c) > 0.0
+                && indices
+                    .iter()
+                    .filter(|j| ![a, b, c].contains(*j))
+                    .all(|j| !inside_triangle(uv[*j], [uv[a], uv[b], uv[c]]))
+        };
+        // If there is no ear, e.g. for self-intersecting polygons, the sharpest corner is clipped.
+        let ear = (0..n).find(|i| is_ear(*i)).unwrap_or_else(|| {
+            (0..n)
+                .max_by(|i, j| {
+                    let [a0, b0, c0] = triangle(*i);
+                    let [a1, b1, c1] = triangle(*j);
+                    corner(a0, b0, c0).total_cmp(&corner(a1, b1, c1))
+                })
+                .unwrap()
+        });
+        tri_faces.push(triangle(ear).map(|k| face[k]));
+        indices.remove(ear);
+    }
+    tri_faces.push([face[indices[0]], face[indices[1]], face[indices[2]]]);
+}
+
+/// Returns whether `p` is in the closed counterclockwise triangle.
+fn inside_triangle(p: Point2, [a, b, c]: [Point2; 3]) -> bool {
+    (b - a).perp_dot(p - a) >= 0.0
+        && (c - b).perp_dot(p - b) >= 0.0
+        && (a - c).perp_dot(p - c) >= 0.0
+}
+
+/// Returns the maximal connected regions of faces with the normals near to the first face.
+fn coplanar_regions(
+    faces: &Faces,
+    edge_faces: &HashMap<[usize; 2], Vec<usize>>,
+    face_normals: &[Vector3],
+    normal_tol: f64,
+) -> Vec<Vec<usize>> {
+    let mut face_adjacency = vec![Vec::new(); faces.len()];
+    edge_faces
+        .values()
+        .filter(|face_ids| face_ids.len() == 2)
+        .for_each(|face_ids| {
+            face_adjacency[face_ids[0]].push(face_ids[1]);
+            face_adjacency[face_ids[1]].push(face_ids[0]);
+        });
+    let mut unchecked = vec![true; faces.len()];
+    let mut regions = Vec::new();
+    for first in 0..faces.len() {
+        if !unchecked[first] {
+            continue;
+        }
+        unchecked[first] = false;
+        let normal = face_normals[first];
+        let mut region = vec![first];
+        let mut stack = vec![first];
+        while let Some(cursor) = stack.pop() {
+            for i in &face_adjacency[cursor] {
+                if unchecked[*i] && face_normals[*i].distance2(normal) < normal_tol * normal_tol {
+                    unchecked[*i] = false;
+                    region.push(*i);
+                    stack.push(*i);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+/// Returns the boundary of the region if it is one simple loop.
+fn boundary_loop(faces: &Faces, region: &[usize]) -> Option<Vec<Vertex>> {
+    let edges = region
+        .iter()
+        .flat_map(|i| {
+            let face = &faces[*i];
+            (0..face.len()).map(move |k| (face[k], face[(k + 1) % face.len()]))
+        })
+        .collect::<Vec<_>>();
+    let inner_edges = edges
+        .iter()
+        .map(|(v, w)| (v.pos, w.pos))
+        .collect::<HashSet<_>>();
+    let mut next = HashMap::<usize, (Vertex, usize)>::default();
+    for (v, w) in &edges {
+        if inner_edges.contains(&(w.pos, v.pos)) {
+            continue;
+        }
+        // Two outgoing boundary edges means that the boundary touches itself.
+        if next.insert(v.pos, (*v, w.pos)).is_some() {
+            return None;
+        }
+    }
+    let (&start, _) = next.iter().next()?;
+    let mut boundary = Vec::new();
+    let mut cursor = start;
+    loop {
+        let (v, w) = *next.get(&cursor)?;
+        boundary.push(v);
+        cursor = w;
+        if cursor == start || boundary.len() > next.len() {
+            break;
+        }
+    }
+    // Other loops means holes.
+    match cursor == start && boundary.len() == next.len() {
+        true => Some(boundary),
+        false => None,
+    }
+}
+
+fn is_straight(p: Point3, q: Point3, r: Point3) -> bool {
+    let (a, b) = (q - p, r - q);
+    a.dot(b) > 0.0 && a.cross(b).magnitude() <= TOLERANCE * a.magnitude() * b.magnitude()
+}
+
 #[inline(always)]
 fn calc_score(edge0: Vector3, edge1: Vector3, edge2: Vector3, edge3: Vector3) -> f64 {
     edge0.cos_angle(edge1).abs()
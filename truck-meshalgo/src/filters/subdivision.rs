@@ -12,6 +12,46 @@ pub trait Subdivision {
     /// - `self.shell_condition()` is `Oriented` or `Closed` before use.
     /// This method does NOT check these conditions.
     fn loop_subdivision(&mut self) -> &mut Self;
+    /// Applies the Loop subdivision `levels` times.
+    ///
+    /// # Details
+    /// - All faces are triangulated in advance.
+    /// - The new vertices on the edges are shared by the adjacent faces, so closed meshes stay closed.
+    /// - The boundary edges and vertices are moved by the boundary stencils.
+    /// - If all vertices have texture coordinates, the texture coordinates are subdivided by the
+    /// same stencils on the connectivity of the texture coordinates. Otherwise, texture coordinates
+    /// are dropped.
+    /// - Normals are dropped. Run [`NormalFilters`] to re-estimate normals.
+    ///
+    /// After subdivision, the number of faces becomes `4^levels` times the number of the triangles.
+    ///
+    /// [`NormalFilters`]: ./trait.NormalFilters.html
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// // tetrahedron
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(1.0, 1.0, 1.0),
+    ///             Point3::new(1.0, -1.0, -1.0),
+    ///             Point3::new(-1.0, 1.0, -1.0),
+    ///             Point3::new(-1.0, -1.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]),
+    /// );
+    ///
+    /// mesh.loop_subdivide(2);
+    /// assert_eq!(mesh.faces().len(), 4 * 16);
+    /// // Euler characteristic: V - E + F = 2
+    /// assert_eq!(mesh.positions().len(), 34);
+    /// ```
+    fn loop_subdivide(&mut self, levels: usize) -> &mut Self;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -82,6 +122,96 @@ impl Subdivision for PolygonMesh {
         }
         self
     }
+    fn loop_subdivide(&mut self, levels: usize) -> &mut Self {
+        self.triangulate();
+        (0..levels).for_each(|_| sub_loop_subdivide(self));
+        self
+    }
+}
+
+fn sub_loop_subdivide(mesh: &mut PolygonMesh) {
+    let pos_tris = mesh
+        .tri_faces()
+        .iter()
+        .map(|face| face.map(|v| v.pos))
+        .collect::<Vec<_>>();
+    let (positions, pos_tris) = subdivide_triangles(mesh.positions(), &pos_tris);
+    let uv_tris = mesh
+        .tri_faces()
+        .iter()
+        .map(|face| {
+            let [v0, v1, v2] = *face;
+            Some([v0.uv?, v1.uv?, v2.uv?])
+        })
+        .collect::<Option<Vec<_>>>();
+    let (uv_coords, uv_tris) = match uv_tris {
+        Some(uv_tris) if !mesh.uv_coords().is_empty() => {
+            let points = mesh
+                .uv_coords()
+                .iter()
+                .map(|uv| Point2::from_vec(*uv))
+                .collect::<Vec<_>>();
+            let (points, uv_tris) = subdivide_triangles(&points, &uv_tris);
+            let uv_coords = points.into_iter().map(|p| p.to_vec()).collect();
+            (uv_coords, uv_tris.into_iter().map(Some).collect())
+        }
+        _ => (Vec::new(), vec![None; pos_tris.len()]),
+    };
+    let tri_faces = pos_tris
+        .into_iter()
+        .zip(uv_tris)
+        .map(|(pos, uv)| {
+            [0, 1, 2].map(|k| StandardVertex {
+                pos: pos[k],
+                uv: uv.map(|uv| uv[k]),
+                nor: None,
+            })
+        })
+        .collect::<Vec<_>>();
+    let editor = mesh.debug_editor();
+    *editor.attributes = StandardAttributes {
+        positions,
+        uv_coords,
+        normals: Vec::new(),
+    };
+    *editor.faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
+}
+
+/// Loop subdivision of the triangles whose corners refer to `points`.
+/// Returns the subdivided points and triangles.
+fn subdivide_triangles<P>(points: &[P], tris: &[[usize; 3]]) -> (Vec<P>, Vec<[usize; 3]>)
+where P: EuclideanSpace<Scalar = f64> {
+    let mut edges = HashMap::default();
+    let mut vertex_adjacency = vec![Vec::new(); points.len()];
+    tris.iter().for_each(|face| {
+        add_vertex_edge(&mut edges, &mut vertex_adjacency, face[1], face[2], face[0]);
+        add_vertex_edge(&mut edges, &mut vertex_adjacency, face[2], face[0], face[1]);
+        add_vertex_edge(&mut edges, &mut vertex_adjacency, face[0], face[1], face[2]);
+    });
+    let new_points = vertex_adjacency
+        .iter()
+        .enumerate()
+        .map(|t| calc_new_position(t.0, t.1, &edges, points))
+        .chain(edge_positions(&edges, &vertex_adjacency, points))
+        .collect::<Vec<_>>();
+    let len = points.len();
+    let new_tris = tris
+        .iter()
+        .flat_map(|v| {
+            let e = [
+                edges[&Edge::new(v[1], v[2])].idx + len,
+                edges[&Edge::new(v[2], v[0])].idx + len,
+                edges[&Edge::new(v[0], v[1])].idx + len,
+            ];
+            [
+                [v[0], e[2], e[1]],
+                [e[0], v[2], e[1]],
+                [e[0], e[2], v[1]],
+                [e[0], e[1], e[2]],
+            ]
+        })
+        .collect::<Vec<_>>();
+    (new_points, new_tris)
 }
 
 fn add_vertex_edge(
@@ -132,17 +262,19 @@ impl VertexBoundaryCondition {
     }
 }
 
-fn calc_new_position(
+fn calc_new_position<P: EuclideanSpace<Scalar = f64>>(
     v: usize,
     adjacency: &[usize],
     edges: &HashMap<Edge, EdgeInfo>,
-    positions: &[Point3],
-) -> Point3 {
+    positions: &[P],
+) -> P {
     use VertexBoundaryCondition::*;
     let point = positions[v];
     match VertexBoundaryCondition::new(v, adjacency, edges) {
         Corner(_, _) => point,
         Boundary(w0, w1) => point + ((positions[w0] - point) + (positions[w1] - point)) / 8.0,
+        // isolated vertex
+        Inner if adjacency.is_empty() => point,
         Inner => {
             let alpha = 3.0 / 8.0 + f64::cos(2.0 * PI / adjacency.len() as f64) / 4.0;
             let alpha = (5.0 / 8.0 - alpha * alpha) / adjacency.len() as f64;
@@ -151,19 +283,19 @@ fn calc_new_position(
                     .iter()
                     .copied()
                     .map(|w| positions[w] - point)
-                    .sum::<Vector3>()
+                    .sum::<P::Diff>()
                     * alpha
         }
     }
 }
 
-fn edge_positions(
+fn edge_positions<P: EuclideanSpace<Scalar = f64>>(
     edges: &HashMap<Edge, EdgeInfo>,
     vertex_adjacency: &[Vec<usize>],
-    positions: &[Point3],
-) -> Vec<Point3> {
+    positions: &[P],
+) -> Vec<P> {
     use VertexBoundaryCondition::*;
-    let mut res = vec![Point3::origin(); edges.len()];
+    let mut res = vec![P::origin(); edges.len()];
     edges.iter().for_each(|tuple| {
         let Edge(v0, v1) = *tuple.0;
         let EdgeInfo {
@@ -0,0 +1,331 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use serde_json::{json, Value};
+use std::io::{Result, Write};
+use truck_topology::{compress::CompressedShell, Shell};
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+
+/// Trait for exporting meshes as binary glTF 2.0 (.glb).
+pub trait ToGlb {
+    /// Writes the mesh as a .glb file with one mesh primitive per face.
+    ///
+    /// `base_color(i)` returns the RGBA base color of the material of the `i`-th face.
+    /// A polygon mesh is regarded as one face, and unmeshed faces of shells are skipped
+    /// without shifting the indices of the other faces.
+    /// # Details
+    /// - Quadrangles and polygons are triangulated by fans.
+    /// - The attributes `NORMAL` and `TEXCOORD_0` are exported only if all the vertices
+    /// of the face have non-zero normals and texture coordinates, respectively.
+    /// - Since the origin of glTF texture coordinates is the upper left corner of the image,
+    /// `v` is exported as `1 - v`.
+    fn write_glb<W: Write>(
+        &self,
+        writer: W,
+        base_color: impl FnMut(usize) -> [f64; 4],
+    ) -> Result<()>;
+}
+
+/// Writes the pairs of meshes and base colors as a .glb file with one mesh primitive per pair.
+///
+/// The meshes with no triangles are skipped. See [`ToGlb::write_glb`] for details.
+/// # Examples
+/// ```
+/// use truck_meshalgo::{gltf, prelude::*};
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2, 3]]),
+/// );
+/// let mut glb = Vec::new();
+/// gltf::write_primitives([(&mesh, [1.0, 0.0, 0.0, 1.0])], &mut glb).unwrap();
+/// assert_eq!(&glb[..4], b"glTF");
+/// assert_eq!(glb.len() % 4, 0);
+/// ```
+pub fn write_primitives<'a, W: Write>(
+    primitives: impl IntoIterator<Item = (&'a PolygonMesh, [f64; 4])>,
+    mut writer: W,
+) -> Result<()> {
+    let mut builder = GlbBuilder::default();
+    primitives
+        .into_iter()
+        .for_each(|(mesh, color)| builder.push_primitive(mesh, color));
+    let (mut json, mut bin) = builder.finish();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let bin_chunk_len = match bin.is_empty() {
+        true => 0,
+        false => 8 + bin.len(),
+    };
+    let total_len = 12 + 8 + json.len() + bin_chunk_len;
+    writer.write_all(&GLB_MAGIC.to_le_bytes())?;
+    writer.write_all(&GLB_VERSION.to_le_bytes())?;
+    writer.write_all(&(total_len as u32).to_le_bytes())?;
+    writer.write_all(&(json.len() as u32).to_le_bytes())?;
+    writer.write_all(&CHUNK_JSON.to_le_bytes())?;
+    writer.write_all(&json)?;
+    if !bin.is_empty() {
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&CHUNK_BIN.to_le_bytes())?;
+        writer.write_all(&bin)?;
+    }
+    writer.flush()
+}
+
+#[derive(Debug, Default)]
+struct GlbBuilder {
+    bin: Vec<u8>,
+    buffer_views: Vec<Value>,
+    accessors: Vec<Value>,
+    materials: Vec<Value>,
+    primitives: Vec<Value>,
+}
+
+impl GlbBuilder {
+    fn push_view(&mut self, data: &[u8], target: u32) -> usize {
+        self.buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": self.bin.len(),
+            "byteLength": data.len(),
+            "target": target,
+        }));
+        self.bin.extend_from_slice(data);
+        self.buffer_views.len() - 1
+    }
+
+    fn push_vectors<const DIM: usize>(&mut self, vectors: &[[f32; DIM]], bounds: bool) -> usize {
+        let data = vectors
+            .iter()
+            .flatten()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>();
+        let view = self.push_view(&data, ARRAY_BUFFER);
+        let mut accessor = json!({
+            "bufferView": view,
+            "componentType": FLOAT,
+            "count": vectors.len(),
+            "type": format!("VEC{DIM}"),
+        });
+        if bounds {
+            let (min, max) = vectors.iter().fold(
+                ([f32::INFINITY; DIM], [f32::NEG_INFINITY; DIM]),
+                |(mut min, mut max), v| {
+                    (0..DIM).for_each(|i| {
+                        min[i] = f32::min(min[i], v[i]);
+                        max[i] = f32::max(max[i], v[i]);
+                    });
+                    (min, max)
+                },
+            );
+            accessor["min"] = json!(min.to_vec());
+            accessor["max"] = json!(max.to_vec());
+        }
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    fn push_indices(&mut self, indices: &[u32]) -> usize {
+        let data = indices
+            .iter()
+            .flat_map(|i| i.to_le_bytes())
+            .collect::<Vec<_>>();
+        let view = self.push_view(&data, ELEMENT_ARRAY_BUFFER);
+        self.accessors.push(json!({
+            "bufferView": view,
+            "componentType": UNSIGNED_INT,
+            "count": indices.len(),
+            "type": "SCALAR",
+        }));
+        self.accessors.len() - 1
+    }
+
+    fn push_primitive(&mut self, mesh: &PolygonMesh, color: [f64; 4]) {
+        let mut map = HashMap::<StandardVertex, u32>::default();
+        let mut vertices = Vec::<StandardVertex>::new();
+        let indices = mesh
+            .faces()
+            .triangle_iter()
+            .flatten()
+            .map(|v| {
+                *map.entry(v).or_insert_with(|| {
+                    vertices.push(v);
+                    vertices.len() as u32 - 1
+                })
+            })
+            .collect::<Vec<_>>();
+        if indices.is_empty() {
+            return;
+        }
+
+        let positions = vertices
+            .iter()
+            .map(|v| mesh.positions()[v.pos].cast::<f32>().unwrap().into())
+            .collect::<Vec<[f32; 3]>>();
+        let normals = vertices
+            .iter()
+            .map(|v| {
+                let n = mesh.normals()[v.nor?];
+                match n.so_small() {
+                    true => None,
+                    false => n.normalize().cast::<f32>().map(Into::into),
+                }
+            })
+            .collect::<Option<Vec<[f32; 3]>>>();
+        let uv_coords = vertices
+            .iter()
+            .map(|v| {
+                let uv = mesh.uv_coords()[v.uv?];
+                Some([uv.x as f32, 1.0 - uv.y as f32])
+            })
+            .collect::<Option<Vec<[f32; 2]>>>();
+
+        let mut attributes = json!({ "POSITION": self.push_vectors(&positions, true) });
+        if let Some(normals) = normals {
+            attributes["NORMAL"] = json!(self.push_vectors(&normals, false));
+        }
+        if let Some(uv_coords) = uv_coords {
+            attributes["TEXCOORD_0"] = json!(self.push_vectors(&uv_coords, false));
+        }
+        let indices = self.push_indices(&indices);
+        self.materials.push(json!({
+            "pbrMetallicRoughness": {
+                "baseColorFactor": color.map(|c| c.clamp(0.0, 1.0)),
+                "metallicFactor": 0.0,
+                "roughnessFactor": 0.5,
+            },
+        }));
+        self.primitives.push(json!({
+            "attributes": attributes,
+            "indices": indices,
+            "material": self.materials.len() - 1,
+            "mode": 4,
+        }));
+    }
+
+    fn finish(self) -> (Vec<u8>, Vec<u8>) {
+        let mut root = json!({
+            "asset": { "version": "2.0", "generator": "truck-meshalgo" },
+        });
+        if !self.primitives.is_empty() {
+            root["scene"] = json!(0);
+            root["scenes"] = json!([{ "nodes": [0] }]);
+            root["nodes"] = json!([{ "mesh": 0 }]);
+            root["meshes"] = json!([{ "primitives": self.primitives }]);
+            root["materials"] = json!(self.materials);
+            root["accessors"] = json!(self.accessors);
+            root["bufferViews"] = json!(self.buffer_views);
+            root["buffers"] = json!([{ "byteLength": self.bin.len() }]);
+        }
+        (root.to_string().into_bytes(), self.bin)
+    }
+}
+
+impl ToGlb for PolygonMesh {
+    fn write_glb<W: Write>(
+        &self,
+        writer: W,
+        mut base_color: impl FnMut(usize) -> [f64; 4],
+    ) -> Result<()> {
+        write_primitives([(self, base_color(0))], writer)
+    }
+}
+
+impl ToGlb for Shell<Point3, PolylineCurve, PolygonMesh> {
+    fn write_glb<W: Write>(
+        &self,
+        writer: W,
+        base_color: impl FnMut(usize) -> [f64; 4],
+    ) -> Result<()> {
+        let meshes = self
+            .face_iter()
+            .map(|face| Some(face.oriented_surface()))
+            .collect::<Vec<_>>();
+        write_meshes(&meshes, writer, base_color)
+    }
+}
+
+impl ToGlb for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
+    fn write_glb<W: Write>(
+        &self,
+        writer: W,
+        base_color: impl FnMut(usize) -> [f64; 4],
+    ) -> Result<()> {
+        let meshes = self
+            .face_iter()
+            .map(|face| {
+                let mut poly = face.surface()?;
+                if !face.orientation() {
+                    poly.invert();
+                }
+                Some(poly)
+            })
+            .collect::<Vec<_>>();
+        write_meshes(&meshes, writer, base_color)
+    }
+}
+
+impl ToGlb for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
+    fn write_glb<W: Write>(
+        &self,
+        writer: W,
+        base_color: impl FnMut(usize) -> [f64; 4],
+    ) -> Result<()> {
+        let meshes = self
+            .faces
+            .iter()
+            .map(|face| match face.orientation {
+                true => Some(face.surface.clone()),
+                false => Some(face.surface.inverse()),
+            })
+            .collect::<Vec<_>>();
+        write_meshes(&meshes, writer, base_color)
+    }
+}
+
+impl ToGlb for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
+    fn write_glb<W: Write>(
+        &self,
+        writer: W,
+        base_color: impl FnMut(usize) -> [f64; 4],
+    ) -> Result<()> {
+        let meshes = self
+            .faces
+            .iter()
+            .map(|face| match face.orientation {
+                true => face.surface.clone(),
+                false => face.surface.as_ref().map(PolygonMesh::inverse),
+            })
+            .collect::<Vec<_>>();
+        write_meshes(&meshes, writer, base_color)
+    }
+}
+
+fn write_meshes<W: Write>(
+    meshes: &[Option<PolygonMesh>],
+    writer: W,
+    mut base_color: impl FnMut(usize) -> [f64; 4],
+) -> Result<()> {
+    let primitives = meshes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, mesh)| Some((mesh.as_ref()?, base_color(i))));
+    write_primitives(primitives, writer)
+}
@@ -26,12 +26,18 @@ use truck_polymesh::{StandardVertex as Vertex, *};
 /// - determines topological properties: connectivity, boundary extraction, or shell conditions (closed or oriented)
 /// - detects collisions between two meshes and extracts interference lines
 /// - investigates positional relations between mesh and point clouds.
+/// - casts rays to mesh, accelerated by bounding volume hierarchy.
 #[cfg(feature = "analyzers")]
 pub mod analyzers;
 mod common;
+/// Errors occurred by mesh algorithms
+pub mod errors;
 /// Edits meshes. Add normals, optimizing data, and so on.
 #[cfg(feature = "filters")]
 pub mod filters;
+/// glTF Output
+#[cfg(feature = "gltf")]
+pub mod gltf;
 /// Tessellates shapes.
 #[cfg(feature = "tessellation")]
 pub mod tessellation;
@@ -47,6 +53,8 @@ pub mod prelude {
     pub use crate::analyzers::*;
     #[cfg(feature = "filters")]
     pub use crate::filters::*;
+    #[cfg(feature = "gltf")]
+    pub use crate::gltf::ToGlb;
     pub use crate::rexport_polymesh::*;
     #[cfg(feature = "tessellation")]
     pub use crate::tessellation::*;
@@ -1,5 +1,8 @@
 use crate::*;
+use rustc_hash::FxHashMap as HashMap;
 use spade::{iterators::*, *};
+use std::hash::BuildHasher;
+use truck_polymesh::{errors::Error, sink::MeshSink};
 use truck_topology::{compress::*, *};
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -47,84 +50,217 @@ type PolylineCurve = truck_polymesh::PolylineCurve<Point3>;
 /// Trait for converting tessellated shape into polygon.
 pub trait MeshedShape {
     /// Converts tessellated shape into polygon.
-    fn to_polygon(&self) -> PolygonMesh;
+    #[inline(always)]
+    fn to_polygon(&self) -> PolygonMesh { self.to_polygon_with_provenance().0 }
+    /// Converts tessellated shape into polygon, and returns the provenance of the polygon faces.
+    ///
+    /// The `i`-th element of the provenance is the index of the face of the shape from which
+    /// the `i`-th face of `PolygonMesh::face_iter` came. For a solid, the faces are indexed in
+    /// the order of `face_iter` of the solid, i.e. the faces of the shells are concatenated.
+    ///
+    /// # Remarks
+    ///
+    /// The provenance is parallel to the faces of the returned mesh. Filters which remove or
+    /// reorder faces, e.g. [`OptimizingFilter::remove_degenerate_faces`], do not update it,
+    /// so apply such filters to each face mesh beforehand, or do not apply them to the merged mesh.
+    ///
+    /// [`OptimizingFilter::remove_degenerate_faces`]: crate::filters::OptimizingFilter::remove_degenerate_faces
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    ///
+    /// // modeling a unit cube
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let (mesh, provenance) = cube.triangulation(0.01).to_polygon_with_provenance();
+    /// assert_eq!(mesh.faces().len(), provenance.len());
+    /// assert!(provenance.iter().all(|i| *i < 6));
+    /// ```
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>);
 }
 
 impl MeshedShape for Shell<Point3, PolylineCurve, PolygonMesh> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.face_iter().for_each(|face| {
-            polygon.merge(face.oriented_surface());
-        });
-        polygon
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_face_polygons(
+            self.edge_iter().flat_map(|edge| edge.curve().0),
+            self.face_iter()
+                .map(|face| face.oriented_surface())
+                .enumerate(),
+        )
     }
 }
 
 impl MeshedShape for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.face_iter().for_each(|face| {
-            if let Some(mut poly) = face.surface() {
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_face_polygons(
+            self.edge_iter().flat_map(|edge| edge.curve().0),
+            self.face_iter().enumerate().filter_map(|(i, face)| {
+                let mut poly = face.surface()?;
                 if !face.orientation() {
                     poly.invert();
                 }
-                polygon.merge(poly);
-            }
-        });
-        polygon
+                Some((i, poly))
+            }),
+        )
     }
 }
 
 impl<P, C, S> MeshedShape for Solid<P, C, S>
 where Shell<P, C, S>: MeshedShape
 {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.boundaries().iter().for_each(|shell| {
-            polygon.merge(shell.to_polygon());
-        });
-        polygon
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_shell_polygons(
+            self.boundaries()
+                .iter()
+                .map(|shell| (shell.to_polygon_with_provenance(), shell.len())),
+        )
     }
 }
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.faces.iter().for_each(|face| match face.orientation {
-            true => polygon.merge(face.surface.clone()),
-            false => polygon.merge(face.surface.inverse()),
-        });
-        polygon
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_face_polygons(
+            self.edges
+                .iter()
+                .flat_map(|edge| edge.curve.iter().copied()),
+            self.faces
+                .iter()
+                .enumerate()
+                .map(|(i, face)| match face.orientation {
+                    true => (i, face.surface.clone()),
+                    false => (i, face.surface.inverse()),
+                }),
+        )
     }
 }
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.faces.iter().for_each(|face| {
-            if let Some(surface) = &face.surface {
-                match face.orientation {
-                    true => polygon.merge(surface.clone()),
-                    false => polygon.merge(surface.inverse()),
-                }
-            }
-        });
-        polygon
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_face_polygons(
+            self.edges
+                .iter()
+                .flat_map(|edge| edge.curve.iter().copied()),
+            self.faces.iter().enumerate().filter_map(|(i, face)| {
+                let surface = face.surface.as_ref()?;
+                Some(match face.orientation {
+                    true => (i, surface.clone()),
+                    false => (i, surface.inverse()),
+                })
+            }),
+        )
     }
 }
 
 impl<P, C, S> MeshedShape for CompressedSolid<P, C, S>
 where CompressedShell<P, C, S>: MeshedShape
 {
-    fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.boundaries.iter().for_each(|shell| {
-            polygon.merge(shell.to_polygon());
-        });
-        polygon
+    fn to_polygon_with_provenance(&self) -> (PolygonMesh, Vec<usize>) {
+        merge_shell_polygons(
+            self.boundaries
+                .iter()
+                .map(|shell| (shell.to_polygon_with_provenance(), shell.faces.len())),
+        )
+    }
+}
+
+/// Index of the provenance list corresponding to the storage of a face in `Faces`.
+#[inline(always)]
+fn provenance_slot(face_len: usize) -> usize {
+    match face_len {
+        3 => 0,
+        4 => 1,
+        _ => 2,
     }
 }
 
+/// Merges the meshes of shells with their provenances.
+/// The second component of each item is the number of faces of the shell.
+fn merge_shell_polygons(
+    shells: impl IntoIterator<Item = ((PolygonMesh, Vec<usize>), usize)>,
+) -> (PolygonMesh, Vec<usize>) {
+    let mut polygon = PolygonMesh::default();
+    let mut provenance: [Vec<usize>; 3] = Default::default();
+    let mut offset = 0;
+    shells.into_iter().for_each(|((poly, prov), len)| {
+        poly.face_iter().zip(prov).for_each(|(face, i)| {
+            provenance[provenance_slot(face.len())].push(i + offset);
+        });
+        polygon.merge(poly);
+        offset += len;
+    });
+    (polygon, provenance.concat())
+}
+
+/// Merges the polygons of faces into one mesh, and returns the provenance of the merged faces.
+///
+/// The boundaries of the face polygons consist of the points of the edge polylines themselves,
+/// so the positions coinciding with the points in `boundary_points` are shared by all the faces
+/// and no welding tolerance is needed to close the mesh of a closed shell.
+fn merge_face_polygons(
+    boundary_points: impl IntoIterator<Item = Point3>,
+    polygons: impl IntoIterator<Item = (usize, PolygonMesh)>,
+) -> (PolygonMesh, Vec<usize>) {
+    let key = |p: &Point3| [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()];
+    let mut shared = HashMap::<[u64; 3], Option<usize>>::default();
+    boundary_points.into_iter().for_each(|p| {
+        shared.entry(key(&p)).or_insert(None);
+    });
+    let (mut positions, mut uv_coords, mut normals) = (Vec::new(), Vec::new(), Vec::new());
+    let mut faces = Faces::default();
+    let mut provenance: [Vec<usize>; 3] = Default::default();
+    polygons.into_iter().for_each(|(face_index, poly)| {
+        let (uv_offset, nor_offset) = (uv_coords.len(), normals.len());
+        let pos_map: Vec<usize> = poly
+            .positions()
+            .iter()
+            .map(|p| match shared.get_mut(&key(p)) {
+                Some(Some(idx)) => *idx,
+                Some(entry) => {
+                    positions.push(*p);
+                    *entry = Some(positions.len() - 1);
+                    positions.len() - 1
+                }
+                None => {
+                    positions.push(*p);
+                    positions.len() - 1
+                }
+            })
+            .collect();
+        uv_coords.extend_from_slice(poly.uv_coords());
+        normals.extend_from_slice(poly.normals());
+        poly.face_iter().for_each(|face| {
+            let face: Vec<StandardVertex> = face
+                .iter()
+                .map(|v| StandardVertex {
+                    pos: pos_map[v.pos],
+                    uv: v.uv.map(|uv| uv + uv_offset),
+                    nor: v.nor.map(|nor| nor + nor_offset),
+                })
+                .collect();
+            let degenerate =
+                (1..face.len()).any(|i| face[..i].iter().any(|v| v.pos == face[i].pos));
+            if !degenerate {
+                provenance[provenance_slot(face.len())].push(face_index);
+                faces.push(face);
+            }
+        });
+    });
+    let polygon = PolygonMesh::debug_new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    );
+    (polygon, provenance.concat())
+}
+
 /// Trait for tessellating `Shell` and `Solid`.
 pub trait MeshableShape {
     /// Shape whose edges are made polylines and faces polygon surface.
@@ -138,12 +274,11 @@ pub trait MeshableShape {
     ///
     /// # Remarks
     ///
-    /// - The tessellated mesh is not necessarily closed even if `self` is `Solid`.
-    /// If you want to get closed mesh, use [`OptimizingFilter::put_together_same_attrs`].
+    /// - Each edge is discretized only once and the faces share the points of the polyline,
+    /// so the tessellated mesh of a closed shell is closed without any welding.
+    /// The positions of the mesh are shared, however, the normals and the uv-coordinates are not.
     /// - This method requires that the curve ride strictly on a surface. If not, try [`RobustMeshableShape`].
     ///
-    /// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
-    ///
     /// # Examples
     /// ```
     /// use truck_meshalgo::prelude::*;
@@ -156,12 +291,8 @@ pub trait MeshableShape {
     /// let f = builder::tsweep(&e, Vector3::unit_y());
     /// let cube = builder::tsweep(&f, Vector3::unit_z());
     ///
-    /// // cube is Solid, however, the tessellated mesh is not closed.
-    /// let mut mesh = cube.triangulation(0.01).to_polygon();
-    /// assert_ne!(mesh.shell_condition(), ShellCondition::Closed);
-    ///
-    /// // use optimization filters!
-    /// mesh.put_together_same_attrs(TOLERANCE);
+    /// // cube is Solid, and the tessellated mesh is closed.
+    /// let mesh = cube.triangulation(0.01).to_polygon();
     /// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
     /// ```
     fn triangulation(&self, tol: f64) -> Self::MeshedShape;
@@ -253,16 +384,166 @@ pub trait RobustMeshableShape {
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape;
 }
 
+/// Trait for tessellating `Shell` and `Solid` directly into [`MeshSink`].
+pub trait StreamMeshableShape {
+    /// Tessellates the faces one by one in a single thread, and streams each polygon to `sink`
+    /// as soon as it is generated, so that the whole mesh is not kept in the memory.
+    ///
+    /// The streamed polygons are the same as the ones of `self.triangulation(tol).to_polygon()`,
+    /// however, the attributes and the faces are put face by face. [`MeshSink::finish`] is not
+    /// called.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_meshalgo::rexport_polymesh::sink::MeshSink;
+    /// use truck_modeling::builder;
+    /// use stl::{StlSink, StlType};
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let mut streamed = Vec::new();
+    /// let mut sink = StlSink::ascii(&mut streamed).unwrap();
+    /// cube.triangulation_to_sink(0.01, &mut sink).unwrap();
+    /// sink.finish().unwrap();
+    /// drop(sink);
+    ///
+    /// let mut written = Vec::new();
+    /// let polygon = cube.triangulation(0.01).to_polygon();
+    /// stl::write(&polygon, &mut written, StlType::Ascii).unwrap();
+    /// assert_eq!(streamed, written);
+    /// ```
+    ///
+    /// [`MeshSink::finish`]: truck_polymesh::sink::MeshSink::finish
+    fn triangulation_to_sink<K: MeshSink>(&self, tol: f64, sink: &mut K) -> Result<(), Error>;
+}
+
+/// Trait for tessellating `Shell` and `Solid` adaptively to the curvature of the surfaces.
+pub trait AdaptiveMeshableShape {
+    /// Shape whose edges are made polylines and faces polygon surface.
+    type MeshedShape: MeshedShape;
+    /// Tessellates shapes in the same way as [`MeshableShape::triangulation`], however, the
+    /// parameters of the surfaces are divided by [`algo::surface::parameter_division_adaptive`].
+    ///
+    /// The u- and v-directions are divided independently by the chordal error `tol` and the angle
+    /// `angle_tol` between the normals, so a nearly developable surface, e.g. a cylinder, is not
+    /// divided along its straight lines.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    /// use std::f64::consts::PI;
+    ///
+    /// // modeling a cylinder of the radius 1 and the height 10
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    /// let disk = builder::try_attach_plane(&[circle]).unwrap();
+    /// let cylinder = builder::tsweep(&disk, Vector3::new(0.0, 0.0, 10.0));
+    ///
+    /// let chordal = cylinder.triangulation(0.01).to_polygon();
+    /// let adaptive = cylinder.adaptive_triangulation(0.01, 0.1).to_polygon();
+    /// assert!(adaptive.positions().len() < chordal.positions().len());
+    /// ```
+    fn adaptive_triangulation(&self, tol: f64, angle_tol: f64) -> Self::MeshedShape;
+}
+
+/// Parameters of the tessellation of a face, used in [`ParamMeshableShape::triangulation_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MeshParam {
+    /// The tolerance of the face. If `None`, the default tolerance is used.
+    pub tol: Option<f64>,
+    /// The maximum length of the segments of the boundary polylines and of the divisions
+    /// of the surface.
+    pub max_edge_length: Option<f64>,
+    /// The fixed numbers of the divisions of the surface in the u- and v-directions.
+    /// If `Some`, `max_edge_length` is applied only to the boundaries.
+    pub division: Option<(usize, usize)>,
+}
+
+/// Trait for tessellating `Shell` and `Solid` with the parameters for each face.
+pub trait ParamMeshableShape {
+    /// Shape whose edges are made polylines and faces polygon surface.
+    type MeshedShape: MeshedShape;
+    /// The type of the surfaces of the faces.
+    type Surface;
+    /// Tessellates shapes in the same way as [`MeshableShape::triangulation`], however,
+    /// the faces whose ids are in `params` are tessellated by the given parameters.
+    ///
+    /// Each edge is divided by the finest parameters of the adjacent faces, so the faces
+    /// with different parameters still share the points on their boundaries,
+    /// and the mesh can be closed by [`OptimizingFilter::put_together_same_attrs`].
+    ///
+    /// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+    ///
+    /// # Panics
+    ///
+    /// `tol` and all tolerances in `params` must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::builder;
+    /// use truck_topology::shell::ShellCondition;
+    /// use std::collections::HashMap;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// // the bottom face is divided into 10 x 10 squares.
+    /// let param = MeshParam {
+    ///     division: Some((10, 10)),
+    ///     max_edge_length: Some(0.1),
+    ///     ..Default::default()
+    /// };
+    /// let bottom = cube.face_iter().next().unwrap();
+    /// let params = HashMap::from([(bottom.id(), param)]);
+    /// let meshed = cube.triangulation_with(0.01, &params);
+    ///
+    /// let bottom = meshed.face_iter().next().unwrap().surface().unwrap();
+    /// assert!(bottom.positions().len() >= 121);
+    ///
+    /// // the faces are still welded to each other.
+    /// let mut mesh = meshed.to_polygon();
+    /// mesh.put_together_same_attrs(TOLERANCE);
+    /// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    /// ```
+    fn triangulation_with<H: BuildHasher>(
+        &self,
+        tol: f64,
+        params: &std::collections::HashMap<FaceID<Self::Surface>, MeshParam, H>,
+    ) -> Self::MeshedShape;
+}
+
 impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Shell<Point3, C, S> {
     type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
         #[cfg(not(target_arch = "wasm32"))]
-        let res = triangulation::shell_tessellation(self, tol, triangulation::by_search_parameter);
+        let res = triangulation::shell_tessellation(
+            self,
+            tol,
+            SurfaceDivision::Chordal,
+            triangulation::by_search_parameter,
+        );
         #[cfg(target_arch = "wasm32")]
         let res = triangulation::shell_tessellation_single_thread(
             self,
             tol,
+            SurfaceDivision::Chordal,
             triangulation::by_search_parameter,
         );
         res
@@ -277,12 +558,14 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape for She
         let res = triangulation::shell_tessellation(
             self,
             tol,
+            SurfaceDivision::Chordal,
             triangulation::by_search_nearest_parameter,
         );
         #[cfg(target_arch = "wasm32")]
         let res = triangulation::shell_tessellation_single_thread(
             self,
             tol,
+            SurfaceDivision::Chordal,
             triangulation::by_search_nearest_parameter,
         );
         res
@@ -313,11 +596,114 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape for Sol
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape for Shell<Point3, C, S> {
+    type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn adaptive_triangulation(&self, tol: f64, angle_tol: f64) -> Self::MeshedShape {
+        nonpositive_tolerance!(tol);
+        let division = SurfaceDivision::Adaptive(angle_tol);
+        #[cfg(not(target_arch = "wasm32"))]
+        let res = triangulation::shell_tessellation(
+            self,
+            tol,
+            division,
+            triangulation::by_search_parameter,
+        );
+        #[cfg(target_arch = "wasm32")]
+        let res = triangulation::shell_tessellation_single_thread(
+            self,
+            tol,
+            division,
+            triangulation::by_search_parameter,
+        );
+        res
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape for Solid<Point3, C, S> {
+    type MeshedShape = Solid<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn adaptive_triangulation(&self, tol: f64, angle_tol: f64) -> Self::MeshedShape {
+        let boundaries = self
+            .boundaries()
+            .iter()
+            .map(|shell| shell.adaptive_triangulation(tol, angle_tol))
+            .collect::<Vec<_>>();
+        Solid::new(boundaries)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> ParamMeshableShape for Shell<Point3, C, S> {
+    type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
+    type Surface = S;
+    fn triangulation_with<H: BuildHasher>(
+        &self,
+        tol: f64,
+        params: &std::collections::HashMap<FaceID<S>, MeshParam, H>,
+    ) -> Self::MeshedShape {
+        let face_params = |face: &Face<Point3, C, S>| {
+            let param = params.get(&face.id()).copied().unwrap_or_default();
+            let tol = param.tol.unwrap_or(tol);
+            nonpositive_tolerance!(tol);
+            let division = match (param.division, param.max_edge_length) {
+                (Some((udiv, vdiv)), _) => SurfaceDivision::Fixed(udiv, vdiv),
+                (None, Some(len)) => SurfaceDivision::MaxLength(len),
+                (None, None) => SurfaceDivision::Chordal,
+            };
+            (tol, param.max_edge_length, division)
+        };
+        let sp = triangulation::by_search_parameter;
+        triangulation::shell_tessellation_with(self, face_params, sp)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> ParamMeshableShape for Solid<Point3, C, S> {
+    type MeshedShape = Solid<Point3, PolylineCurve, Option<PolygonMesh>>;
+    type Surface = S;
+    fn triangulation_with<H: BuildHasher>(
+        &self,
+        tol: f64,
+        params: &std::collections::HashMap<FaceID<S>, MeshParam, H>,
+    ) -> Self::MeshedShape {
+        let boundaries = self
+            .boundaries()
+            .iter()
+            .map(|shell| shell.triangulation_with(tol, params))
+            .collect::<Vec<_>>();
+        Solid::new(boundaries)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> StreamMeshableShape for Shell<Point3, C, S> {
+    fn triangulation_to_sink<K: MeshSink>(&self, tol: f64, sink: &mut K) -> Result<(), Error> {
+        nonpositive_tolerance!(tol);
+        let (division, sp) = (SurfaceDivision::Chordal, triangulation::by_search_parameter);
+        triangulation::shell_tessellation_iter(self, tol, division, sp).try_for_each(|face| {
+            match face.surface() {
+                Some(mut poly) => {
+                    if !face.orientation() {
+                        poly.invert();
+                    }
+                    poly.stream_to(sink)
+                }
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> StreamMeshableShape for Solid<Point3, C, S> {
+    fn triangulation_to_sink<K: MeshSink>(&self, tol: f64, sink: &mut K) -> Result<(), Error> {
+        self.boundaries()
+            .iter()
+            .try_for_each(|shell| shell.triangulation_to_sink(tol, sink))
+    }
+}
+
 impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for CompressedShell<Point3, C, S> {
     type MeshedShape = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
-        triangulation::cshell_tessellation(self, tol, triangulation::by_search_parameter)
+        let division = SurfaceDivision::Chordal;
+        triangulation::cshell_tessellation(self, tol, division, triangulation::by_search_parameter)
     }
 }
 
@@ -327,7 +713,9 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     type MeshedShape = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
         nonpositive_tolerance!(tol);
-        triangulation::cshell_tessellation(self, tol, triangulation::by_search_nearest_parameter)
+        let division = SurfaceDivision::Chordal;
+        let sp = triangulation::by_search_nearest_parameter;
+        triangulation::cshell_tessellation(self, tol, division, sp)
     }
 }
 
@@ -357,4 +745,30 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape
+    for CompressedShell<Point3, C, S>
+{
+    type MeshedShape = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn adaptive_triangulation(&self, tol: f64, angle_tol: f64) -> Self::MeshedShape {
+        nonpositive_tolerance!(tol);
+        let division = SurfaceDivision::Adaptive(angle_tol);
+        triangulation::cshell_tessellation(self, tol, division, triangulation::by_search_parameter)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape
+    for CompressedSolid<Point3, C, S>
+{
+    type MeshedShape = CompressedSolid<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn adaptive_triangulation(&self, tol: f64, angle_tol: f64) -> Self::MeshedShape {
+        let boundaries = self
+            .boundaries
+            .iter()
+            .map(|shell| shell.adaptive_triangulation(tol, angle_tol))
+            .collect::<Vec<_>>();
+        CompressedSolid { boundaries }
+    }
+}
+
 mod triangulation;
+use triangulation::SurfaceDivision;
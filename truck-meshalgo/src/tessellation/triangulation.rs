@@ -6,6 +6,8 @@ use crate::Point2;
 use array_macro::array;
 use itertools::Itertools;
 use rustc_hash::FxHashMap as HashMap;
+use spade::handles::FixedVertexHandle;
+use std::ops::{Deref, DerefMut};
 
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::*;
@@ -20,6 +22,20 @@ pub(super) trait SP<S>:
 }
 impl<S, F> SP<S> for F where F: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> + Parallelizable {}
 
+/// How the parameter domains of the surfaces are divided.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum SurfaceDivision {
+    /// By `ParameterDivision2D`, the chordal error only.
+    Chordal,
+    /// By `algo::surface::parameter_division_adaptive` with the angle tolerance.
+    Adaptive(f64),
+    /// By `ParameterDivision2D`, and refined so that the lengths of the divisions do not exceed
+    /// the maximum length.
+    MaxLength(f64),
+    /// Uniform divisions with the fixed numbers in the u- and v-directions.
+    Fixed(usize, usize),
+}
+
 pub(super) fn by_search_parameter<S>(
     surface: &S,
     point: Point3,
@@ -53,6 +69,7 @@ where
 pub(super) fn shell_tessellation<'a, C, S>(
     shell: &Shell<Point3, C, S>,
     tol: f64,
+    division: SurfaceDivision,
     sp: impl SP<S>,
 ) -> MeshedShell
 where
@@ -89,7 +106,9 @@ where
             .iter()
             .map(create_boundary)
             .collect();
-        shell_create_polygon(&face.surface(), wires, face.orientation(), tol, &sp)
+        let orientation = face.orientation();
+        let (surface, pcurves) = (face.surface(), face_pcurves(face));
+        shell_create_polygon(&surface, wires, pcurves, orientation, tol, division, &sp)
     };
     shell.face_par_iter().map(create_face).collect()
 }
@@ -99,8 +118,23 @@ where
 pub(super) fn shell_tessellation_single_thread<'a, C, S>(
     shell: &'a Shell<Point3, C, S>,
     tol: f64,
-    sp: impl SP<S>,
+    division: SurfaceDivision,
+    sp: impl SP<S> + 'a,
 ) -> MeshedShell
+where
+    C: PolylineableCurve + 'a,
+    S: PreMeshableSurface + 'a,
+{
+    shell_tessellation_iter(shell, tol, division, sp).collect()
+}
+
+/// Tessellates faces one by one, on the iteration.
+pub(super) fn shell_tessellation_iter<'a, C, S>(
+    shell: &'a Shell<Point3, C, S>,
+    tol: f64,
+    division: SurfaceDivision,
+    sp: impl SP<S> + 'a,
+) -> impl Iterator<Item = Face<Point3, PolylineCurve, Option<PolygonMesh>>> + 'a
 where
     C: PolylineableCurve + 'a,
     S: PreMeshableSurface + 'a,
@@ -139,15 +173,119 @@ where
             .iter()
             .map(&mut create_boundary)
             .collect();
-        shell_create_polygon(&face.surface(), wires, face.orientation(), tol, &sp)
+        let orientation = face.orientation();
+        let (surface, pcurves) = (face.surface(), face_pcurves(face));
+        shell_create_polygon(&surface, wires, pcurves, orientation, tol, division, &sp)
     };
-    shell.face_iter().map(create_face).collect()
+    shell.face_iter().map(create_face)
+}
+
+/// Tessellates faces with the tolerance, the maximum length of edges and the surface division
+/// given for each face. Each edge is divided by the finest parameters of the adjacent faces,
+/// so that the boundaries of the faces match each other.
+pub(super) fn shell_tessellation_with<C, S>(
+    shell: &Shell<Point3, C, S>,
+    params: impl Fn(&Face<Point3, C, S>) -> (f64, Option<f64>, SurfaceDivision),
+    sp: impl SP<S>,
+) -> MeshedShell
+where
+    C: PolylineableCurve,
+    S: PreMeshableSurface,
+{
+    let face_params: Vec<_> = shell.face_iter().map(params).collect();
+    let mut edge_params = HashMap::<_, (f64, Option<f64>)>::default();
+    shell
+        .face_iter()
+        .zip(&face_params)
+        .for_each(|(face, &(tol, len, _))| {
+            face.edge_iter().for_each(|edge| {
+                let param = edge_params.entry(edge.id()).or_insert((tol, len));
+                param.0 = f64::min(param.0, tol);
+                param.1 = match (param.1, len) {
+                    (Some(len0), Some(len1)) => Some(f64::min(len0, len1)),
+                    (len0, len1) => len0.or(len1),
+                };
+            });
+        });
+    let vmap: HashMap<_, _> = shell
+        .vertex_iter()
+        .map(|v| (v.id(), v.mapped(Point3::clone)))
+        .collect();
+    let mut edge_map = HashMap::default();
+    shell.edge_iter().for_each(|edge| {
+        edge_map.entry(edge.id()).or_insert_with(|| {
+            let v0 = &vmap[&edge.absolute_front().id()];
+            let v1 = &vmap[&edge.absolute_back().id()];
+            let (tol, len) = edge_params[&edge.id()];
+            Edge::debug_new(v0, v1, edge_polyline(&edge.curve(), tol, len))
+        });
+    });
+    let create_edge = |edge: &Edge<Point3, C>| -> Edge<_, _> {
+        let new_edge: &Edge<_, _> = &edge_map[&edge.id()];
+        match edge.orientation() {
+            true => new_edge.clone(),
+            false => new_edge.inverse(),
+        }
+    };
+    let create_boundary =
+        |wire: &Wire<Point3, C>| -> Wire<_, _> { wire.edge_iter().map(create_edge).collect() };
+    shell
+        .face_iter()
+        .zip(face_params)
+        .map(|(face, (tol, _, division))| {
+            let wires: Vec<_> = face
+                .absolute_boundaries()
+                .iter()
+                .map(create_boundary)
+                .collect();
+            let orientation = face.orientation();
+            let (surface, pcurves) = (face.surface(), face_pcurves(face));
+            shell_create_polygon(&surface, wires, pcurves, orientation, tol, division, &sp)
+        })
+        .collect()
+}
+
+/// Divides the curve by the tolerance, and refines the division so that the lengths of
+/// the segments do not exceed `max_len`.
+fn edge_polyline<C: PolylineableCurve>(curve: &C, tol: f64, max_len: Option<f64>) -> PolylineCurve {
+    let range = curve.range_tuple();
+    match max_len {
+        Some(len) => {
+            let (params, _) = curve.parameter_division(range, tol);
+            let dist = |t0: f64, t1: f64| curve.subs(t0).distance(curve.subs(t1));
+            let params = refine_by_length(&params, len, dist);
+            PolylineCurve(params.into_iter().map(|t| curve.subs(t)).collect())
+        }
+        None => PolylineCurve::from_curve(curve, range, tol),
+    }
+}
+
+/// Divides each interval of `div` uniformly so that `dist` of the divided intervals
+/// does not exceed `len`.
+fn refine_by_length(div: &[f64], len: f64, dist: impl Fn(f64, f64) -> f64) -> Vec<f64> {
+    let mut res: Vec<f64> = div
+        .windows(2)
+        .flat_map(|w| {
+            let n = f64::max(f64::ceil(dist(w[0], w[1]) / len), 1.0) as usize;
+            (0..n).map(move |k| w[0] + (w[1] - w[0]) * k as f64 / n as f64)
+        })
+        .collect();
+    res.extend(div.last());
+    res
+}
+
+fn uniform_division((t0, t1): (f64, f64), n: usize) -> Vec<f64> {
+    let n = usize::max(n, 1);
+    (0..=n)
+        .map(|i| t0 + (t1 - t0) * i as f64 / n as f64)
+        .collect()
 }
 
 /// Tessellates faces
 pub(super) fn cshell_tessellation<'a, C, S>(
     shell: &CompressedShell<Point3, C, S>,
     tol: f64,
+    division: SurfaceDivision,
     sp: impl SP<S>,
 ) -> MeshedCShell
 where
@@ -174,13 +312,13 @@ where
             false => Some(edges.get(edge_idx.index)?.curve.inverse()),
         };
         let create_boundary = |wire: &Vec<CompressedEdgeIndex>| {
-            let wire_iter = wire.iter().filter_map(create_edge);
+            let wire_iter = wire.iter().filter_map(create_edge).map(|poly| (poly, None));
             PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
         };
         let preboundary: Option<Vec<_>> = boundaries.iter().map(create_boundary).collect();
         let polygon: Option<PolygonMesh> = (|| {
             let boundary = PolyBoundary::new(preboundary?, &surface, tol);
-            Some(trimming_tessellation(&surface, &boundary, tol))
+            Some(trimming_tessellation(&surface, &boundary, tol, division))
         })();
         CompressedFace {
             boundaries,
@@ -199,23 +337,34 @@ where
     }
 }
 
+/// The stored pcurves of the edges in the absolute boundaries of the face.
+fn face_pcurves<C, S>(face: &Face<Point3, C, S>) -> Vec<Vec<Option<Vec<Point2>>>> {
+    face.absolute_boundaries()
+        .iter()
+        .map(|wire| wire.edge_iter().map(|edge| face.pcurve(edge)).collect())
+        .collect()
+}
+
 fn shell_create_polygon<S: PreMeshableSurface>(
     surface: &S,
     wires: Vec<Wire<Point3, PolylineCurve>>,
+    pcurves: Vec<Vec<Option<Vec<Point2>>>>,
     orientation: bool,
     tol: f64,
+    division: SurfaceDivision,
     sp: impl SP<S>,
 ) -> Face<Point3, PolylineCurve, Option<PolygonMesh>> {
     let preboundary = wires
         .iter()
-        .map(|wire: &Wire<_, _>| {
-            let wire_iter = wire.iter().map(Edge::oriented_curve);
+        .zip(pcurves)
+        .map(|(wire, pcurves): (&Wire<_, _>, _)| {
+            let wire_iter = wire.iter().map(Edge::oriented_curve).zip(pcurves);
             PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
         })
         .collect::<Option<Vec<_>>>();
     let polygon: Option<PolygonMesh> = (|| {
         let boundary = PolyBoundary::new(preboundary?, &surface, tol);
-        Some(trimming_tessellation(surface, &boundary, tol))
+        Some(trimming_tessellation(surface, &boundary, tol, division))
     })();
     let mut new_face = Face::debug_new(wires, polygon);
     if !orientation {
@@ -224,52 +373,119 @@ fn shell_create_polygon<S: PreMeshableSurface>(
     new_face
 }
 
+/// A point of the boundary in the parameter domain. If the point comes from the polyline of
+/// an edge, the exact point of the polyline is kept, so that the faces sharing the edge have
+/// exactly the same points on their boundaries.
+#[derive(Debug, Clone, Copy)]
+struct BoundaryPoint {
+    uv: Point2,
+    point: Option<Point3>,
+}
+
+impl From<Point2> for BoundaryPoint {
+    #[inline(always)]
+    fn from(uv: Point2) -> Self { Self { uv, point: None } }
+}
+
+impl Deref for BoundaryPoint {
+    type Target = Point2;
+    #[inline(always)]
+    fn deref(&self) -> &Point2 { &self.uv }
+}
+
+impl DerefMut for BoundaryPoint {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Point2 { &mut self.uv }
+}
+
 #[derive(Debug, Default, Clone)]
-struct PolyBoundaryPiece(Vec<Point2>);
+struct PolyBoundaryPiece(Vec<BoundaryPoint>);
+
+/// Returns the hints of the parameters of the points of `poly` given by the nearest vertices
+/// of `pcurve`, which are searched forward along the polylines.
+fn pcurve_hints<S: PreMeshableSurface>(
+    surface: &S,
+    poly: &PolylineCurve,
+    pcurve: Option<Vec<Point2>>,
+) -> Vec<Option<(f64, f64)>> {
+    let pcurve = match pcurve {
+        Some(pcurve) if !pcurve.is_empty() => pcurve,
+        _ => return vec![None; poly.len()],
+    };
+    let points: Vec<Point3> = pcurve.iter().map(|p| surface.subs(p.x, p.y)).collect();
+    let mut cursor = 0;
+    poly.iter()
+        .map(|pt| {
+            while cursor + 1 < points.len()
+                && pt.distance2(points[cursor + 1]) <= pt.distance2(points[cursor])
+            {
+                cursor += 1;
+            }
+            Some((pcurve[cursor].x, pcurve[cursor].y))
+        })
+        .collect()
+}
 
 impl PolyBoundaryPiece {
+    /// Creates the boundary in the parameter domain from the polylines of the edges.
+    /// If the pcurve of an edge is given, it is used for the hints of the parameter search,
+    /// which makes the parameters reliable near the seams of periodic surfaces.
     fn try_new<S: PreMeshableSurface>(
         surface: &S,
-        wire: impl Iterator<Item = PolylineCurve>,
+        wire: impl Iterator<Item = (PolylineCurve, Option<Vec<Point2>>)>,
         sp: impl SP<S>,
     ) -> Option<Self> {
         let (up, vp) = (surface.u_period(), surface.v_period());
         let (urange, vrange) = surface.try_range_tuple();
-        let mut bdry3d: Vec<Point3> = wire
-            .flat_map(|poly_edge| {
+        let mut bdry3d: Vec<(Point3, Option<(f64, f64)>)> = wire
+            .flat_map(|(poly_edge, pcurve)| {
+                let hints = pcurve_hints(surface, &poly_edge, pcurve);
                 let n = poly_edge.len() - 1;
-                poly_edge.into_iter().take(n)
+                poly_edge.into_iter().zip(hints).take(n)
             })
             .collect();
         bdry3d.push(bdry3d[0]);
+        // The parameters of the boundary are unwrapped, while the hints of the search are
+        // moved into the range of the surface.
+        let wrap = |(u, v): (f64, f64)| (wrap_period(u, up, urange), wrap_period(v, vp, vrange));
         let mut previous = None;
+        let mut previous_point = None;
         let mut vec = bdry3d
             .into_iter()
-            .flat_map(|pt| {
-                let (mut u, mut v) = match sp(surface, pt, previous) {
+            .flat_map(|(pt, pchint)| {
+                let bp = |uv, point| Some(BoundaryPoint { uv, point });
+                let hint = pchint.or(previous).map(wrap);
+                let (mut u, mut v) = match sp(surface, pt, hint) {
                     Some(hint) => hint,
                     None => return vec![None],
                 };
-                if let (Some(up), Some((u0, _))) = (up, previous) {
+                if let (Some(up), Some((u0, _))) = (up, previous.or(pchint)) {
                     u = get_mindiff(u, u0, up);
                 }
-                if let (Some(vp), Some((_, v0))) = (vp, previous) {
+                if let (Some(vp), Some((_, v0))) = (vp, previous.or(pchint)) {
                     v = get_mindiff(v, v0, vp);
                 }
                 let res = (|| {
                     if let Some((u0, v0)) = previous {
                         if !u0.near(&u) && surface.uder(u0, v0).so_small() {
-                            return vec![Some(Point2::new(u, v0)), Some(Point2::new(u, v))];
+                            return vec![
+                                bp(Point2::new(u, v0), previous_point),
+                                bp(Point2::new(u, v), Some(pt)),
+                            ];
                         } else if !v0.near(&v) && surface.vder(u0, v0).so_small() {
-                            return vec![Some(Point2::new(u0, v)), Some(Point2::new(u, v))];
+                            return vec![
+                                bp(Point2::new(u0, v), previous_point),
+                                bp(Point2::new(u, v), Some(pt)),
+                            ];
                         }
                     }
-                    vec![Some(Point2::new(u, v))]
+                    vec![bp(Point2::new(u, v), Some(pt))]
                 })();
                 previous = Some((u, v));
+                previous_point = Some(pt);
                 res
             })
-            .collect::<Option<Vec<Point2>>>()?;
+            .collect::<Option<Vec<BoundaryPoint>>>()?;
         let grav = vec.iter().fold(Point2::origin(), |g, p| g + p.to_vec()) / vec.len() as f64;
         if let (Some(up), Some((u0, _))) = (up, urange) {
             let quot = f64::floor((grav.x - u0) / up);
@@ -281,7 +497,7 @@ impl PolyBoundaryPiece {
         }
         let last = *vec.last().unwrap();
         if !vec[0].near(&last) {
-            let Point2 { x: u0, y: v0 } = last;
+            let Point2 { x: u0, y: v0 } = *last;
             if surface.uder(u0, v0).so_small() || surface.vder(u0, v0).so_small() {
                 vec.push(vec[0]);
             }
@@ -290,19 +506,22 @@ impl PolyBoundaryPiece {
     }
 }
 
-fn abs_diff(previous: f64) -> impl Fn(&f64, &f64) -> std::cmp::Ordering {
-    let f = move |x: &f64| f64::abs(x - previous);
-    move |x: &f64, y: &f64| f(x).partial_cmp(&f(y)).unwrap()
-}
-fn get_mindiff(u: f64, u0: f64, up: f64) -> f64 {
-    let closure = |i| u + i as f64 * up;
-    (-2..=2).map(closure).min_by(abs_diff(u0)).unwrap()
+/// Returns `u + n * up` nearest to `u0`, where `n` is an integer.
+fn get_mindiff(u: f64, u0: f64, up: f64) -> f64 { u - f64::round((u - u0) / up) * up }
+
+/// Returns `x + n * period` in `[x0, x0 + period)`, where `n` is an integer and `x0` is the front
+/// of `range`. If the parameter is not periodic, returns `x` itself.
+fn wrap_period(x: f64, period: Option<f64>, range: Option<(f64, f64)>) -> f64 {
+    match (period, range) {
+        (Some(period), Some((x0, _))) => x0 + f64::rem_euclid(x - x0, period),
+        _ => x,
+    }
 }
 
 #[derive(Debug, Default, Clone)]
-struct PolyBoundary(Vec<Vec<Point2>>);
+struct PolyBoundary(Vec<Vec<BoundaryPoint>>);
 
-fn normalize_range(curve: &mut Vec<Point2>, compidx: usize, (u0, u1): (f64, f64)) {
+fn normalize_range(curve: &mut Vec<BoundaryPoint>, compidx: usize, (u0, u1): (f64, f64)) {
     let p = curve[0];
     let q = curve[curve.len() - 1];
     let tmp = f64::min(p[compidx], q[compidx]) + TOLERANCE;
@@ -326,7 +545,7 @@ fn normalize_range(curve: &mut Vec<Point2>, compidx: usize, (u0, u1): (f64, f64)
     *curve = curve1;
 }
 
-fn loop_orientation(curve: &[Point2]) -> bool {
+fn loop_orientation(curve: &[BoundaryPoint]) -> bool {
     curve
         .iter()
         .circular_tuple_windows()
@@ -338,7 +557,7 @@ impl PolyBoundary {
     fn new(pieces: Vec<PolyBoundaryPiece>, surface: &impl PreMeshableSurface, tol: f64) -> Self {
         let (mut closed, mut open) = (Vec::new(), Vec::new());
         pieces.into_iter().for_each(|PolyBoundaryPiece(mut vec)| {
-            match vec[0].distance(vec[vec.len() - 1]) < 1.0e-3 {
+            match vec[0].distance(*vec[vec.len() - 1]) < 1.0e-3 {
                 true => {
                     vec.pop();
                     closed.push(vec)
@@ -346,8 +565,8 @@ impl PolyBoundary {
                 false => open.push(vec),
             }
         });
-        fn connect_edges(vecs: impl IntoIterator<Item = Vec<Point2>>) -> Vec<Point2> {
-            let closure = |vec: Vec<Point2>| {
+        fn connect_edges(vecs: impl IntoIterator<Item = Vec<BoundaryPoint>>) -> Vec<BoundaryPoint> {
+            let closure = |vec: Vec<BoundaryPoint>| {
                 let len = vec.len();
                 vec.into_iter().take(len - 1)
             };
@@ -365,9 +584,9 @@ impl PolyBoundary {
                         let q = curve[curve.len() - 1];
                         let x = Point2::new(u0, v1);
                         let y = Point2::new(u1, v1);
-                        let vec0 = polyline_on_surface(surface, q, y, tol);
+                        let vec0 = polyline_on_surface(surface, *q, y, tol);
                         let vec1 = polyline_on_surface(surface, y, x, tol);
-                        let vec2 = polyline_on_surface(surface, x, p, tol);
+                        let vec2 = polyline_on_surface(surface, x, *p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
                     } else if q.x < p.x - TOLERANCE {
                         normalize_range(&mut curve, 0, (u0, u1));
@@ -375,9 +594,9 @@ impl PolyBoundary {
                         let q = curve[curve.len() - 1];
                         let x = Point2::new(u1, v0);
                         let y = Point2::new(u0, v0);
-                        let vec0 = polyline_on_surface(surface, q, y, tol);
+                        let vec0 = polyline_on_surface(surface, *q, y, tol);
                         let vec1 = polyline_on_surface(surface, y, x, tol);
-                        let vec2 = polyline_on_surface(surface, x, p, tol);
+                        let vec2 = polyline_on_surface(surface, x, *p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
                     } else if p.y < q.y - TOLERANCE {
                         normalize_range(&mut curve, 1, (v0, v1));
@@ -385,9 +604,9 @@ impl PolyBoundary {
                         let q = curve[curve.len() - 1];
                         let x = Point2::new(u0, v0);
                         let y = Point2::new(u0, v1);
-                        let vec0 = polyline_on_surface(surface, q, y, tol);
+                        let vec0 = polyline_on_surface(surface, *q, y, tol);
                         let vec1 = polyline_on_surface(surface, y, x, tol);
-                        let vec2 = polyline_on_surface(surface, x, p, tol);
+                        let vec2 = polyline_on_surface(surface, x, *p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
                     } else if q.y < p.y - TOLERANCE {
                         normalize_range(&mut curve, 1, (v0, v1));
@@ -395,9 +614,9 @@ impl PolyBoundary {
                         let q = curve[curve.len() - 1];
                         let x = Point2::new(u1, v1);
                         let y = Point2::new(u1, v0);
-                        let vec0 = polyline_on_surface(surface, q, y, tol);
+                        let vec0 = polyline_on_surface(surface, *q, y, tol);
                         let vec1 = polyline_on_surface(surface, y, x, tol);
-                        let vec2 = polyline_on_surface(surface, x, p, tol);
+                        let vec2 = polyline_on_surface(surface, x, *p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
                     }
                 }
@@ -419,8 +638,8 @@ impl PolyBoundary {
                     }
                 }
                 let ((p0, p1), (q0, q1)) = (end_pts(&curve0), end_pts(&curve1));
-                let vec0 = polyline_on_surface(surface, p1, q0, tol);
-                let vec1 = polyline_on_surface(surface, q1, p0, tol);
+                let vec0 = polyline_on_surface(surface, *p1, *q0, tol);
+                let vec1 = polyline_on_surface(surface, *q1, *p0, tol);
                 closed.push(connect_edges([curve0, vec0, curve1, vec1]));
             }
             _ => {}
@@ -451,8 +670,8 @@ impl PolyBoundary {
             .iter()
             .flat_map(|vec| vec.iter().circular_tuple_windows())
             .try_fold(0_i32, move |counter, (p0, p1)| {
-                let a = p0 - c;
-                let b = p1 - c;
+                let a = p0.uv - c;
+                let b = p1.uv - c;
                 let s0 = r.x * a.y - r.y * a.x; // v times a
                 let s1 = r.x * b.y - r.y * b.x; // v times b
                 let s2 = a.x * b.y - a.y * b.x; // a times b
@@ -472,12 +691,20 @@ impl PolyBoundary {
     }
 
     /// Inserts points and adds constraint into triangulation.
-    fn insert_to(&self, triangulation: &mut Cdt) {
+    /// Returns the exact points of the inserted vertices which come from the polylines of edges.
+    fn insert_to(&self, triangulation: &mut Cdt) -> HashMap<FixedVertexHandle, Point3> {
+        let mut points = HashMap::default();
         let poly2tri: Vec<_> = self
             .0
             .iter()
             .flatten()
-            .filter_map(|pt| triangulation.insert(SPoint2::from([pt.x, pt.y])).ok())
+            .filter_map(|pt| {
+                let handle = triangulation.insert(SPoint2::from([pt.x, pt.y])).ok()?;
+                if let Some(point) = pt.point {
+                    points.insert(handle, point);
+                }
+                Some(handle)
+            })
             .collect();
         let mut prev: Option<usize> = None;
         let mut counter = 0;
@@ -501,20 +728,26 @@ impl PolyBoundary {
                     prev = Some(i);
                 }
             });
+        points
     }
 }
 
 /// Tessellates one surface trimmed by polyline.
-fn trimming_tessellation<S>(surface: &S, polyboundary: &PolyBoundary, tol: f64) -> PolygonMesh
-where S: PreMeshableSurface {
+fn trimming_tessellation<S: PreMeshableSurface>(
+    surface: &S,
+    polyboundary: &PolyBoundary,
+    tol: f64,
+    division: SurfaceDivision,
+) -> PolygonMesh {
     let mut triangulation = Cdt::new();
-    polyboundary.insert_to(&mut triangulation);
-    insert_surface(&mut triangulation, surface, polyboundary, tol);
+    let boundary_points = polyboundary.insert_to(&mut triangulation);
+    insert_surface(&mut triangulation, surface, polyboundary, tol, division);
     let mut mesh = triangulation_into_polymesh(
         triangulation.vertices(),
         triangulation.inner_faces(),
         surface,
         polyboundary,
+        &boundary_points,
     );
     mesh.make_face_compatible_to_normal();
     mesh
@@ -526,10 +759,34 @@ fn insert_surface(
     surface: impl PreMeshableSurface,
     polyline: &PolyBoundary,
     tol: f64,
+    division: SurfaceDivision,
 ) {
-    let bdb: BoundingBox<Point2> = polyline.0.iter().flatten().collect();
+    let bdb: BoundingBox<Point2> = polyline.0.iter().flatten().map(|p| &p.uv).collect();
     let range = ((bdb.min()[0], bdb.max()[0]), (bdb.min()[1], bdb.max()[1]));
-    let (udiv, vdiv) = surface.parameter_division(range, tol);
+    let (udiv, vdiv) = match division {
+        SurfaceDivision::Chordal => surface.parameter_division(range, tol),
+        SurfaceDivision::Adaptive(angle_tol) => {
+            algo::surface::parameter_division_adaptive(&surface, range, tol, angle_tol)
+        }
+        SurfaceDivision::MaxLength(len) => {
+            let (udiv, vdiv) = surface.parameter_division(range, tol);
+            let udiv = refine_by_length(&udiv, len, |u0, u1| {
+                vdiv.iter().fold(0.0, |dist, &v| {
+                    f64::max(dist, surface.subs(u0, v).distance(surface.subs(u1, v)))
+                })
+            });
+            let vdiv = refine_by_length(&vdiv, len, |v0, v1| {
+                udiv.iter().fold(0.0, |dist, &u| {
+                    f64::max(dist, surface.subs(u, v0).distance(surface.subs(u, v1)))
+                })
+            });
+            (udiv, vdiv)
+        }
+        SurfaceDivision::Fixed(udiv, vdiv) => (
+            uniform_division(range.0, udiv),
+            uniform_division(range.1, vdiv),
+        ),
+    };
     let insert_res: Vec<Vec<Option<_>>> = udiv
         .into_iter()
         .map(|u| {
@@ -571,6 +828,7 @@ fn triangulation_into_polymesh<'a>(
     triangles: InnerFaceIterator<'a, SPoint2, (), CdtEdge<()>, ()>,
     surface: &impl ParametricSurface3D,
     polyline: &PolyBoundary,
+    boundary_points: &HashMap<FixedVertexHandle, Point3>,
 ) -> PolygonMesh {
     let mut positions = Vec::<Point3>::new();
     let mut uv_coords = Vec::<Vector2>::new();
@@ -580,7 +838,11 @@ fn triangulation_into_polymesh<'a>(
         .map(|(i, v)| {
             let p = *v.as_ref();
             let uv = Vector2::new(p.x, p.y);
-            positions.push(surface.subs(uv[0], uv[1]));
+            let position = match boundary_points.get(&v.fix()) {
+                Some(point) => *point,
+                None => surface.subs(uv[0], uv[1]),
+            };
+            positions.push(position);
             uv_coords.push(uv);
             normals.push(surface.normal(uv[0], uv[1]));
             (v.fix(), i)
@@ -616,12 +878,12 @@ fn polyline_on_surface(
     p: Point2,
     q: Point2,
     tol: f64,
-) -> Vec<Point2> {
+) -> Vec<BoundaryPoint> {
     use truck_geometry::prelude::*;
     let line = Line(p, q);
     let pcurve = PCurve::new(line, surface);
     let (vec, _) = pcurve.parameter_division(pcurve.range_tuple(), tol);
-    vec.into_iter().map(|t| line.subs(t)).collect()
+    vec.into_iter().map(|t| line.subs(t).into()).collect()
 }
 
 #[test]
@@ -636,16 +898,17 @@ fn par_bench() {
     ));
     let solid: Solid = serde_json::from_str(JSON).unwrap();
     let shell = solid.into_boundaries().pop().unwrap();
+    let division = SurfaceDivision::Chordal;
 
     let instant = Instant::now();
     (0..100).for_each(|_| {
-        let _shell = shell_tessellation(&shell, 0.01, by_search_parameter);
+        let _shell = shell_tessellation(&shell, 0.01, division, by_search_parameter);
     });
     println!("{}ms", instant.elapsed().as_millis());
 
     let instant = Instant::now();
     (0..100).for_each(|_| {
-        let _shell = shell_tessellation_single_thread(&shell, 0.01, by_search_parameter);
+        let _shell = shell_tessellation_single_thread(&shell, 0.01, division, by_search_parameter);
     });
     println!("{}ms", instant.elapsed().as_millis());
 }
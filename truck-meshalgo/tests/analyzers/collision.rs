@@ -79,3 +79,85 @@ fn collision_sphere() {
         instant.elapsed().as_secs_f64()
     );
 }
+
+fn box_mesh(min: Point3, max: Point3) -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| {
+            Point3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        })
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [0, 1, 5, 4],
+        [1, 3, 7, 5],
+        [3, 2, 6, 7],
+        [2, 0, 4, 6],
+        [4, 5, 7, 6],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+fn polyline_length(polyline: &PolylineCurve<Point3>) -> f64 {
+    polyline.windows(2).map(|p| p[0].distance(p[1])).sum()
+}
+
+#[test]
+fn intersection_curves_of_boxes() {
+    let box0 = box_mesh(Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 1.0, 2.0));
+    let box1 = box_mesh(Point3::new(-1.0, -1.0, 1.0), Point3::new(4.0, 2.0, 3.0));
+    let curves = box0.intersection_curves(&box1);
+    assert_eq!(curves.len(), 1);
+    let curve = &curves[0];
+    assert_near!(curve[0], curve[curve.len() - 1]);
+    assert!(curve.iter().all(|p| p.z.near(&1.0)));
+    assert_near!(polyline_length(curve), 8.0);
+
+    let box1 = box_mesh(Point3::new(4.0, 4.0, 4.0), Point3::new(5.0, 5.0, 5.0));
+    assert!(box0.intersection_curves(&box1).is_empty());
+}
+
+#[test]
+fn intersection_curves_open() {
+    let cube = box_mesh(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+    let sheet = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(1.0, -1.0, 1.0),
+                Point3::new(3.0, -1.0, 1.0),
+                Point3::new(3.0, 3.0, 1.0),
+                Point3::new(1.0, 3.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2, 3]]),
+    );
+    let curves = cube.intersection_curves(&sheet);
+    assert_eq!(curves.len(), 1);
+    let curve = &curves[0];
+    let (front, back) = (curve[0], curve[curve.len() - 1]);
+    assert!(front.x.near(&1.0) && back.x.near(&1.0));
+    assert_near!(front.distance(back), 2.0);
+    assert_near!(polyline_length(curve), 4.0);
+}
+
+#[test]
+fn intersection_curves_of_spheres() {
+    let sphere0 = common::shapes::sphere(Point3::new(0.0, 0.0, -0.7), 1.0, 50, 50);
+    let sphere1 = common::shapes::sphere(Point3::new(0.0, 0.0, 0.7), 1.0, 50, 50);
+    let curves = sphere0.intersection_curves(&sphere1);
+    assert_eq!(curves.len(), 1);
+    let curve = &curves[0];
+    assert_near!(curve[0], curve[curve.len() - 1]);
+    let length = polyline_length(curve);
+    assert!(f64::abs(length - 2.0 * std::f64::consts::PI * f64::sqrt(0.51)) < 0.05);
+}
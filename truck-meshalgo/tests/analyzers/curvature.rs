@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn sphere_curvatures() {
+    let radius = 2.0;
+    let sphere = common::shapes::sphere(Point3::origin(), radius, 100, 50);
+    let curvatures = sphere.curvatures();
+    let mut counter = 0;
+    sphere
+        .positions()
+        .iter()
+        .zip(&curvatures)
+        .filter(|(p, _)| p.z.abs() < 0.95 * radius)
+        .for_each(|(p, curvature)| {
+            let curvature = curvature.unwrap();
+            assert!(
+                f64::abs(curvature.gaussian * radius * radius - 1.0) < 0.03,
+                "{p:?} {curvature:?}"
+            );
+            assert!(
+                f64::abs(curvature.mean * radius - 1.0) < 0.03,
+                "{p:?} {curvature:?}"
+            );
+            counter += 1;
+        });
+    assert!(counter > 0);
+}
+
+#[test]
+fn plane_curvatures() {
+    // 10 x 10 grid with irregular triangulation
+    let positions = (0..=10)
+        .flat_map(|i| {
+            (0..=10).map(move |j| {
+                let noise = match (i + j) % 3 {
+                    0 => 0.2,
+                    1 => -0.1,
+                    _ => 0.0,
+                };
+                Point3::new(i as f64 + noise, j as f64 - noise, 0.0)
+            })
+        })
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter((0..10).flat_map(|i| {
+        (0..10).flat_map(move |j| {
+            let (a, b) = (i * 11 + j, (i + 1) * 11 + j);
+            match (i + j) % 2 == 0 {
+                true => [[a, b, b + 1], [a, b + 1, a + 1]],
+                false => [[a, b, a + 1], [b, b + 1, a + 1]],
+            }
+        })
+    }));
+    let plane = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let curvatures = plane.curvatures();
+    (0..=10).for_each(|i| {
+        (0..=10).for_each(|j| {
+            let curvature = curvatures[i * 11 + j];
+            match i == 0 || i == 10 || j == 0 || j == 10 {
+                true => assert!(curvature.is_none()),
+                false => {
+                    let curvature = curvature.unwrap();
+                    assert!(curvature.gaussian.so_small(), "{curvature:?}");
+                    assert!(curvature.mean.so_small(), "{curvature:?}");
+                }
+            }
+        })
+    });
+}
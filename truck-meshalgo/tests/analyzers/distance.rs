@@ -0,0 +1,66 @@
+use super::*;
+
+#[test]
+fn closest_point_to_square() {
+    let square = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    );
+    let bvh = MeshBvh::new(&square);
+
+    // interior
+    let (closest, dist, face_index) = bvh.closest_point(Point3::new(0.7, 0.2, 1.5)).unwrap();
+    assert_near!(closest, Point3::new(0.7, 0.2, 0.0));
+    assert_near!(dist, 1.5);
+    assert_eq!(face_index, 0);
+    let (closest, dist, face_index) = bvh.closest_point(Point3::new(0.2, 0.7, -1.5)).unwrap();
+    assert_near!(closest, Point3::new(0.2, 0.7, 0.0));
+    assert_near!(dist, 1.5);
+    assert_eq!(face_index, 1);
+
+    // edge
+    let (closest, dist, _) = bvh.closest_point(Point3::new(2.0, 0.5, 1.0)).unwrap();
+    assert_near!(closest, Point3::new(1.0, 0.5, 0.0));
+    assert_near!(dist, f64::sqrt(2.0));
+
+    // vertex
+    let (closest, dist, _) = bvh.closest_point(Point3::new(-1.0, 2.0, 0.0)).unwrap();
+    assert_near!(closest, Point3::new(0.0, 1.0, 0.0));
+    assert_near!(dist, f64::sqrt(2.0));
+}
+
+#[test]
+fn closest_point_to_sphere() {
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 50, 50);
+    let bvh = MeshBvh::new(&sphere);
+    for _ in 0..100 {
+        let point = Point3::new(
+            4.0 * rand::random::<f64>() - 2.0,
+            4.0 * rand::random::<f64>() - 2.0,
+            4.0 * rand::random::<f64>() - 2.0,
+        );
+        let (closest, dist, _) = bvh.closest_point(point).unwrap();
+        assert_near!(closest.distance(point), dist);
+        let exact = f64::abs(point.to_vec().magnitude() - 1.0);
+        assert!(f64::abs(dist - exact) < 0.01, "{point:?} {dist} {exact}");
+    }
+}
+
+#[test]
+fn hausdorff_distance_translated_sphere() {
+    let sphere0 = common::shapes::sphere(Point3::origin(), 1.0, 50, 50);
+    let sphere1 = common::shapes::sphere(Point3::new(0.1, 0.2, 0.0), 1.0, 50, 50);
+    let dist = f64::max(
+        sphere0.hausdorff_distance_to(&sphere1, 100.0),
+        sphere1.hausdorff_distance_to(&sphere0, 100.0),
+    );
+    assert!(f64::abs(dist - f64::sqrt(0.05)) < 0.01, "{dist}");
+}
@@ -3,7 +3,11 @@ use truck_meshalgo::prelude::*;
 mod collision;
 #[path = "../common/mod.rs"]
 mod common;
+mod curvature;
+mod distance;
 mod point_cloud;
+mod ray_casting;
+mod slicing;
 mod splitting;
 mod topology;
 mod volume;
@@ -0,0 +1,104 @@
+use super::*;
+
+fn random_point(size: f64) -> Point3 {
+    Point3::new(
+        size * (2.0 * rand::random::<f64>() - 1.0),
+        size * (2.0 * rand::random::<f64>() - 1.0),
+        size * (2.0 * rand::random::<f64>() - 1.0),
+    )
+}
+
+#[test]
+fn bvh_agrees_with_linear_scan() {
+    let sphere = common::shapes::sphere(Point3::new(0.5, 0.0, -0.5), 1.0, 50, 50);
+    let bvh = MeshBvh::new(&sphere);
+    for _ in 0..100 {
+        let (origin, dir) = (random_point(2.0), random_point(1.0) - Point3::origin());
+        let hits0 = sphere.ray_intersections(origin, dir);
+        let hits1 = bvh.ray_cast(origin, dir);
+        assert_eq!(hits0.len(), hits1.len());
+        hits0.iter().zip(&hits1).for_each(|(hit0, hit1)| {
+            assert_near!(hit0.t, hit1.t);
+            assert_near!(hit0.point, hit1.point);
+        });
+    }
+}
+
+#[test]
+fn ray_cast_barycentric_coordinates() {
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 20, 20);
+    let bvh = MeshBvh::new(&sphere);
+    for _ in 0..100 {
+        let dir = random_point(1.0) - Point3::origin();
+        for hit in bvh.ray_cast(Point3::origin(), dir) {
+            let face = sphere.faces().face_iter().nth(hit.face_index).unwrap();
+            let point = hit
+                .corners
+                .iter()
+                .zip(hit.barycentric)
+                .fold(Vector3::zero(), |sum, (k, w)| {
+                    sum + sphere.positions()[face[*k].pos].to_vec() * w
+                });
+            assert_near!(Point3::from_vec(point), hit.point);
+        }
+    }
+}
+
+#[test]
+fn ray_cast_from_inside() {
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 50, 50);
+    let bvh = MeshBvh::new(&sphere);
+    for _ in 0..100 {
+        let dir = random_point(1.0) - Point3::origin();
+        let hits = bvh.ray_cast(Point3::origin(), dir);
+        assert_eq!(hits.len(), 1);
+        assert!(f64::abs(hits[0].point.to_vec().magnitude() - 1.0) < 0.01);
+    }
+}
+
+#[test]
+fn ray_cast_through_edges_and_vertices() {
+    // 4 x 4 grid, each cell divided into two triangles
+    let positions = (0..=4)
+        .flat_map(|i| (0..=4).map(move |j| Point3::new(i as f64, j as f64, 0.0)))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter((0..4).flat_map(|i| {
+        (0..4).flat_map(move |j| {
+            let (a, b) = (i * 5 + j, (i + 1) * 5 + j);
+            [[a, b, b + 1], [a, b + 1, a + 1]]
+        })
+    }));
+    let grid = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let bvh = MeshBvh::new(&grid);
+    let dir = Vector3::new(0.1, 0.2, -1.0);
+    // on the diagonal of a cell
+    let hits = bvh.ray_cast(Point3::new(1.5, 2.5, 0.0) - dir, dir);
+    assert_eq!(hits.len(), 1);
+    // on the edge shared by two cells
+    let hits = bvh.ray_cast(Point3::new(2.0, 1.3, 0.0) - dir, dir);
+    assert_eq!(hits.len(), 1);
+    // on the vertex shared by six triangles
+    let hits = bvh.ray_cast(Point3::new(2.0, 2.0, 0.0) - dir, dir);
+    assert_eq!(hits.len(), 1);
+    assert_near!(hits[0].t, 1.0);
+}
+
+#[test]
+fn bvh_is_inside() {
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 50, 50);
+    let bvh = MeshBvh::new(&sphere);
+    for _ in 0..100 {
+        let point = random_point(1.5);
+        let dist = point.to_vec().magnitude();
+        if f64::abs(dist - 1.0) < 0.05 {
+            continue;
+        }
+        assert_eq!(bvh.is_inside(point), dist < 1.0, "{point:?}");
+    }
+}
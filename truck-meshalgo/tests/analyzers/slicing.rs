@@ -0,0 +1,103 @@
+use super::*;
+use std::f64::consts::PI;
+
+fn unit_cube() -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [0, 1, 5, 4],
+        [1, 3, 7, 5],
+        [3, 2, 6, 7],
+        [2, 0, 4, 6],
+        [4, 5, 7, 6],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+fn polyline_length(polyline: &PolylineCurve<Point3>) -> f64 {
+    polyline.windows(2).map(|p| p[0].distance(p[1])).sum()
+}
+
+/// twice the signed area of the projection to the xy-plane
+fn signed_area(polyline: &PolylineCurve<Point3>) -> f64 {
+    polyline
+        .windows(2)
+        .map(|p| p[0].x * p[1].y - p[1].x * p[0].y)
+        .sum()
+}
+
+#[test]
+fn cross_section_unit_cube() {
+    let cube = unit_cube();
+    let loops = cube.cross_section(Point3::new(0.0, 0.0, 0.5), Vector3::unit_z());
+    assert_eq!(loops.len(), 1);
+    let square = &loops[0];
+    assert_eq!(square[0], square[square.len() - 1]);
+    assert!(square.iter().all(|p| p.z.near(&0.5)));
+    assert_near!(polyline_length(square), 4.0);
+    // counterclockwise
+    assert_near!(signed_area(square), 2.0);
+}
+
+#[test]
+fn cross_section_through_vertices() {
+    let cube = unit_cube();
+    // The vertices on the plane are regarded as above the plane.
+    let loops = cube.cross_section(Point3::new(0.0, 0.0, 1.0), Vector3::unit_z());
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0][0], loops[0][loops[0].len() - 1]);
+    assert_near!(polyline_length(&loops[0]), 4.0);
+    let loops = cube.cross_section(Point3::new(0.0, 0.0, 0.0), Vector3::unit_z());
+    assert!(loops.is_empty());
+
+    // The plane through the diagonal vertices
+    let normal = Vector3::new(1.0, -1.0, 0.0);
+    let loops = cube.cross_section(Point3::origin(), normal);
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0][0], loops[0][loops[0].len() - 1]);
+    assert_near!(polyline_length(&loops[0]), 2.0 + 2.0 * f64::sqrt(2.0));
+}
+
+#[test]
+fn cross_section_open_mesh() {
+    let mut cube = unit_cube();
+    // remove the face on the plane y = 0
+    let faces = cube
+        .faces()
+        .face_iter()
+        .enumerate()
+        .filter(|(i, _)| *i != 1)
+        .map(|(_, face)| face)
+        .collect::<Faces>();
+    *cube.debug_editor().faces = faces;
+    let polylines = cube.cross_section(Point3::new(0.0, 0.0, 0.5), Vector3::unit_z());
+    assert_eq!(polylines.len(), 1);
+    let polyline = &polylines[0];
+    assert!(polyline[0] != polyline[polyline.len() - 1]);
+    assert_near!(polyline_length(polyline), 3.0);
+}
+
+#[test]
+fn cross_sections_sphere() {
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 100, 50);
+    let sections = sphere.cross_sections(Point3::new(0.0, 0.0, -0.8), Vector3::unit_z(), 0.2, 9);
+    assert_eq!(sections.len(), 9);
+    sections.iter().enumerate().for_each(|(i, loops)| {
+        let z = -0.8 + 0.2 * i as f64;
+        assert_eq!(loops.len(), 1);
+        let circle = &loops[0];
+        assert_eq!(circle[0], circle[circle.len() - 1]);
+        assert!(circle.iter().all(|p| p.z.near(&z)));
+        let exact = 2.0 * PI * f64::sqrt(1.0 - z * z);
+        assert!(f64::abs(polyline_length(circle) - exact) < 0.01, "{z}");
+        assert!(signed_area(circle) > 0.0);
+    });
+}
@@ -91,3 +91,79 @@ fn into_component_test() {
     let components = mesh.components(false);
     assert_eq!(components.len(), 1);
 }
+
+fn triangulated_cube(origin: Point3, offset: usize) -> (Vec<Point3>, Vec<[usize; 3]>) {
+    let mut mesh = common::shapes::cube(origin, 1.0);
+    mesh.triangulate();
+    let faces = mesh
+        .faces()
+        .tri_faces()
+        .iter()
+        .map(|face| face.map(|v| v.pos + offset))
+        .collect();
+    (mesh.positions().clone(), faces)
+}
+
+#[test]
+fn into_components_separated_cubes() {
+    let (mut positions, mut faces) = triangulated_cube(Point3::new(0.0, 0.0, 0.0), 0);
+    let (positions1, faces1) = triangulated_cube(Point3::new(3.0, 0.0, 0.0), 8);
+    positions.extend(positions1);
+    faces.extend(faces1);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+
+    let components = mesh.into_components(true);
+    assert_eq!(components.len(), 2);
+    components.iter().for_each(|component| {
+        assert_eq!(component.tri_faces().len(), 12);
+        assert_eq!(component.positions().len(), 8);
+        assert_eq!(component.shell_condition(), ShellCondition::Closed);
+    });
+    assert_near!(
+        components[0].bounding_box().min(),
+        Point3::new(0.0, 0.0, 0.0)
+    );
+    assert_near!(
+        components[1].bounding_box().min(),
+        Point3::new(3.0, 0.0, 0.0)
+    );
+    assert_eq!(mesh.into_components(false).len(), 2);
+
+    let empty: PolygonMesh = PolygonMesh::default();
+    assert!(empty.into_components(true).is_empty());
+    assert!(empty.into_components(false).is_empty());
+}
+
+#[test]
+fn into_components_touching_at_vertex() {
+    // two cubes sharing the vertex (1, 1, 1)
+    let (mut positions, mut faces) = triangulated_cube(Point3::new(0.0, 0.0, 0.0), 0);
+    let (positions1, faces1) = triangulated_cube(Point3::new(1.0, 1.0, 1.0), 7);
+    positions.extend(positions1.into_iter().skip(1));
+    faces.extend(faces1);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+
+    let components = mesh.into_components(true);
+    assert_eq!(components.len(), 2);
+    components.iter().for_each(|component| {
+        assert_eq!(component.tri_faces().len(), 12);
+        assert_eq!(component.positions().len(), 8);
+    });
+
+    let components = mesh.into_components(false);
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].tri_faces().len(), 24);
+    assert_eq!(components[0].positions().len(), 15);
+}
@@ -69,3 +69,140 @@ fn shell_condition() {
     ]);
     assert_eq!(faces.shell_condition(), ShellCondition::Closed);
 }
+
+/// Returns the loop rotated so that it starts with the least index.
+fn normalize_loop(boundary: &[usize]) -> Vec<usize> {
+    let start = (0..boundary.len()).min_by_key(|i| boundary[*i]).unwrap();
+    boundary[start..]
+        .iter()
+        .chain(&boundary[..start])
+        .copied()
+        .collect()
+}
+
+#[test]
+fn extract_boundary_loops_cylinder() {
+    const N: usize = 16;
+    let faces = Faces::from_iter((0..N).map(|i| [i, (i + 1) % N, N + (i + 1) % N, N + i]));
+    let mut loops = faces
+        .extract_boundary_loops()
+        .unwrap()
+        .iter()
+        .map(|boundary| normalize_loop(boundary))
+        .collect::<Vec<_>>();
+    loops.sort();
+    assert_eq!(loops.len(), 2);
+    // the bottom loop is along the winding of the faces
+    assert_eq!(loops[0], (0..N).collect::<Vec<_>>());
+    // the top loop is reversed
+    let top = std::iter::once(N)
+        .chain((N + 1..2 * N).rev())
+        .collect::<Vec<_>>();
+    assert_eq!(loops[1], top);
+}
+
+#[test]
+fn extract_boundary_loops_grid() {
+    const N: usize = 5;
+    let idx = |i: usize, j: usize| i * (N + 1) + j;
+    let faces = Faces::from_iter((0..N).flat_map(|i| {
+        (0..N).map(move |j| [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1)])
+    }));
+    let loops = faces.extract_boundary_loops().unwrap();
+    assert_eq!(loops.len(), 1);
+    let expected = (0..N)
+        .map(|i| idx(i, 0))
+        .chain((0..N).map(|j| idx(N, j)))
+        .chain((0..N).map(|i| idx(N - i, N)))
+        .chain((0..N).map(|j| idx(0, N - j)))
+        .collect::<Vec<_>>();
+    assert_eq!(normalize_loop(&loops[0]), expected);
+
+    // closed mesh has no boundary
+    let faces = Faces::from_iter(&[[0, 1, 2], [0, 2, 3], [0, 3, 1], [1, 3, 2]]);
+    assert!(faces.extract_boundary_loops().unwrap().is_empty());
+}
+
+#[test]
+fn extract_boundary_loops_non_manifold() {
+    let faces = Faces::from_iter(&[[0, 1, 2], [1, 0, 3], [4, 2, 1], [2, 1, 5]]);
+    assert_eq!(
+        faces.extract_boundary_loops(),
+        Err(truck_meshalgo::errors::Error::NonManifoldEdge([1, 2])),
+    );
+}
+
+const TETRAHEDRON: [[usize; 3]; 4] = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+
+#[test]
+fn shell_condition_report_closed() {
+    let report = Faces::from_iter(TETRAHEDRON).shell_condition_report();
+    assert!(report.is_closed_manifold());
+    assert_eq!(report, MeshTopologyReport::default());
+}
+
+#[test]
+fn shell_condition_report_boundary_edges() {
+    let report = Faces::from_iter(&TETRAHEDRON[..3]).shell_condition_report();
+    assert!(!report.is_closed_manifold());
+    assert_eq!(report.boundary_edges, vec![[1, 3], [2, 1], [3, 2]]);
+    assert!(report.non_manifold_edges.is_empty());
+    assert!(report.non_manifold_vertices.is_empty());
+    assert!(report.inconsistent_face_pairs.is_empty());
+}
+
+#[test]
+fn shell_condition_report_non_manifold_edges() {
+    let faces = Faces::from_iter(&[[0, 1, 2], [1, 0, 3], [0, 1, 4]]);
+    let report = faces.shell_condition_report();
+    assert_eq!(report.non_manifold_edges, vec![[0, 1]]);
+}
+
+#[test]
+fn shell_condition_report_non_manifold_vertices() {
+    // two tetrahedra sharing only the vertex 0
+    let another = [[0, 5, 4], [0, 4, 6], [0, 6, 5], [4, 5, 6]];
+    let faces = Faces::from_iter(TETRAHEDRON.iter().chain(&another));
+    let report = faces.shell_condition_report();
+    assert_eq!(report.non_manifold_vertices, vec![0]);
+    assert!(report.boundary_edges.is_empty());
+    assert!(report.non_manifold_edges.is_empty());
+    assert!(report.inconsistent_face_pairs.is_empty());
+}
+
+#[test]
+fn shell_condition_report_inconsistent_orientation() {
+    let mut faces = TETRAHEDRON;
+    faces[3] = [1, 3, 2];
+    let faces = Faces::from_iter(faces);
+    assert_eq!(faces.shell_condition(), ShellCondition::Regular);
+    let report = faces.shell_condition_report();
+    assert_eq!(report.inconsistent_face_pairs, vec![[0, 3], [1, 3], [2, 3]]);
+    assert!(report.boundary_edges.is_empty());
+    assert!(report.non_manifold_vertices.is_empty());
+}
+
+#[test]
+fn shell_condition_report_isolated_and_duplicate() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let faces = Faces::from_iter(TETRAHEDRON.iter().chain(&[[2, 1, 0]]));
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let report = mesh.shell_condition_report();
+    assert_eq!(report.isolated_vertices, vec![4]);
+    assert_eq!(report.duplicate_faces, vec![[0, 4]]);
+    let display = report.to_string();
+    assert!(display.contains("isolated vertices: 1 (e.g. 4)"));
+    assert!(display.contains("duplicate faces: 1 (e.g. [0, 4])"));
+}
@@ -44,3 +44,58 @@ proptest! {
         assert_near!(msolid.center_of_gravity().to_point(), trans.transform_point(grav));
     }
 }
+
+fn cube(size: f64) -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64) * size)
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [0, 1, 5, 4],
+        [1, 3, 7, 5],
+        [3, 2, 6, 7],
+        [2, 0, 4, 6],
+        [4, 5, 7, 6],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn cube_mass_properties() {
+    let mesh = cube(2.0);
+    assert_near!(mesh.volume(), 8.0);
+    assert_near!(mesh.surface_area(), 24.0);
+    assert_near!(mesh.centroid(), Point3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn inverted_cube_volume() {
+    let mut mesh = cube(2.0);
+    mesh.debug_editor()
+        .faces
+        .face_iter_mut()
+        .for_each(|face| face.reverse());
+    assert_near!(mesh.volume(), -8.0);
+    assert_near!(mesh.surface_area(), 24.0);
+    assert_near!(mesh.centroid(), Point3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn sphere_mass_properties() {
+    let center = Point3::new(1.0, -2.0, 3.0);
+    let mesh = common::shapes::sphere(center, 1.0, 200, 101);
+    // The inscribed polyhedron is slightly smaller than the sphere.
+    let volume = mesh.volume();
+    assert!(volume < 4.0 * PI / 3.0);
+    assert!(4.0 * PI / 3.0 - volume < 1.0e-2, "{volume}");
+    let area = mesh.surface_area();
+    assert!(area < 4.0 * PI);
+    assert!(4.0 * PI - area < 1.0e-2, "{area}");
+    assert!(mesh.centroid().distance(center) < 1.0e-6);
+}
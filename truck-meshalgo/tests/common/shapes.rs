@@ -29,3 +29,29 @@ pub fn sphere(center: Point3, radius: f64, udiv: usize, vdiv: usize) -> PolygonM
         faces,
     )
 }
+
+/// The cube `[0, size]^3` translated by `origin`, whose `i`-th vertex is at the corner
+/// `((i & 1), (i >> 1) & 1, i >> 2)`. The faces are quadrangles oriented outward.
+pub fn cube(origin: Point3, size: f64) -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| {
+            let corner = Vector3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64);
+            origin + corner * size
+        })
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [0, 1, 5, 4],
+        [1, 3, 7, 5],
+        [3, 2, 6, 7],
+        [2, 0, 4, 6],
+        [4, 5, 7, 6],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
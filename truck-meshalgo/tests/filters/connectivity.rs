@@ -0,0 +1,101 @@
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+// 8 - 9 - 10- 11
+// | 3 | 4 | 5 |
+// 4 - 5 - 6 - 7
+// | 0 | 1 | 2 |
+// 0 - 1 - 2 - 3
+fn quad_grid() -> PolygonMesh {
+    let positions = (0..3)
+        .flat_map(|j| (0..4).map(move |i| Point3::new(i as f64, j as f64, 0.0)))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter((0..2).flat_map(|j| {
+        (0..3).map(move |i| {
+            let (v, w) = (j * 4 + i, (j + 1) * 4 + i);
+            [v, v + 1, w + 1, w]
+        })
+    }));
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn connectivity_of_quad_grid() {
+    let connectivity = MeshConnectivity::new(&quad_grid());
+    assert_eq!(connectivity.num_vertices(), 12);
+    assert_eq!(connectivity.num_faces(), 6);
+
+    assert_eq!(connectivity.faces_around_vertex(0), &[0]);
+    assert_eq!(connectivity.faces_around_vertex(3), &[2]);
+    assert_eq!(connectivity.faces_around_vertex(5), &[0, 1, 3, 4]);
+    assert_eq!(connectivity.faces_around_vertex(9), &[3, 4]);
+
+    assert_eq!(connectivity.faces_adjacent_to_face(0), vec![1, 3]);
+    assert_eq!(connectivity.faces_adjacent_to_face(1), vec![0, 2, 4]);
+    assert_eq!(connectivity.faces_adjacent_to_face(4), vec![1, 3, 5]);
+
+    assert_eq!(connectivity.edge_faces(5, 6), &[1, 4]);
+    assert_eq!(connectivity.edge_faces(6, 5), &[1, 4]);
+    assert_eq!(connectivity.edge_faces(0, 1), &[0]);
+    assert_eq!(connectivity.edge_faces(0, 5), &[] as &[usize]);
+
+    assert_eq!(connectivity.vertex_neighbors(0), &[1, 4]);
+    assert_eq!(connectivity.vertex_neighbors(5), &[1, 4, 6, 9]);
+    assert_eq!(connectivity.vertex_neighbors(11), &[7, 10]);
+
+    let boundary = connectivity.boundary_edges().collect::<Vec<_>>();
+    let expected = [
+        [0, 1],
+        [0, 4],
+        [1, 2],
+        [2, 3],
+        [3, 7],
+        [4, 8],
+        [7, 11],
+        [8, 9],
+        [9, 10],
+        [10, 11],
+    ];
+    assert_eq!(boundary, expected);
+}
+
+#[test]
+fn connectivity_with_degenerate_face() {
+    let mut mesh = quad_grid();
+    // collapse the edge [5, 6] in the face 4
+    mesh.debug_editor().faces.quad_faces_mut()[4][0] = 6.into();
+    let connectivity = MeshConnectivity::new(&mesh);
+    assert_eq!(connectivity.faces_around_vertex(5), &[0, 1, 3]);
+    assert_eq!(connectivity.faces_around_vertex(6), &[1, 2, 4, 5]);
+    assert_eq!(connectivity.edge_faces(5, 6), &[1]);
+    assert_eq!(connectivity.edge_faces(6, 10), &[4, 5]);
+    assert_eq!(connectivity.faces_adjacent_to_face(4), vec![5]);
+    assert_eq!(connectivity.vertex_neighbors(6), &[2, 5, 7, 9, 10]);
+}
+
+#[test]
+fn filters_with_prebuilt_connectivity() {
+    let mut mesh = quad_grid();
+    let editor = mesh.debug_editor();
+    editor.attributes.positions.iter_mut().for_each(|p| {
+        p.z = 0.1 * (2.0 * rand::random::<f64>() - 1.0);
+    });
+    editor.faces.quad_faces_mut()[4].reverse();
+    drop(editor);
+    let mut expected = mesh.clone();
+    assert_eq!(expected.heal_orientation(), Ok(1));
+    expected.taubin_smooth(0.5, -0.53, 5, true);
+
+    let connectivity = MeshConnectivity::new(&mesh);
+    let num_flips = mesh.heal_orientation_with_connectivity(Some(&connectivity));
+    assert_eq!(num_flips, Ok(1));
+    mesh.taubin_smooth_with_connectivity(0.5, -0.53, 5, true, Some(&connectivity));
+    assert_eq!(mesh.faces(), expected.faces());
+    assert_eq!(mesh.positions(), expected.positions());
+}
@@ -1,4 +1,7 @@
+mod connectivity;
 mod normal_filter;
 mod optimizing;
+mod simplification;
+mod smoothing;
 mod structuring;
 mod subdivision;
@@ -135,3 +135,117 @@ fn add_smooth_normals() {
         assert!(p0.distance(n0) > p1.distance(n1));
     }
 }
+
+#[test]
+fn add_normals_with_crease_angle_cube_with_boss() {
+    use std::f64::consts::PI;
+    const N: usize = 32;
+    // the circle at the bottom of the boss and its radial projection onto the top of the cube
+    let (circle, square): (Vec<_>, Vec<_>) = (0..N)
+        .map(|k| {
+            let t = 2.0 * PI * k as f64 / N as f64;
+            let (x, y) = (t.cos(), t.sin());
+            let scale = 2.0 / f64::max(x.abs(), y.abs());
+            (
+                Point3::new(x, y, 0.0),
+                Point3::new(scale * x, scale * y, 0.0),
+            )
+        })
+        .unzip();
+    let positions = circle
+        .iter()
+        .chain(&square)
+        .copied()
+        .chain(square.iter().map(|p| p - 2.0 * Vector3::unit_z()))
+        .chain(circle.iter().map(|p| p + Vector3::unit_z()))
+        .collect::<Vec<_>>();
+    let circle_idx = |k: usize| k % N;
+    let square_idx = |k: usize| N + k % N;
+    let bottom_idx = |k: usize| 2 * N + k % N;
+    let top_idx = |k: usize| 3 * N + k % N;
+
+    // pairs of a face and its expected normal, `None` means the radial direction.
+    let mut faces = Vec::<(Vec<usize>, Option<Vector3>)>::new();
+    (0..N).for_each(|k| {
+        let (k0, k1) = (k, k + 1);
+        let top = vec![
+            circle_idx(k0),
+            square_idx(k0),
+            square_idx(k1),
+            circle_idx(k1),
+        ];
+        faces.push((top, Some(Vector3::unit_z())));
+        let mid = square[k0 % N].midpoint(square[k1 % N]);
+        let normal = mid.to_vec().map(|x| match x.abs() > 2.0 - TOLERANCE {
+            true => x.signum(),
+            false => 0.0,
+        });
+        let side = vec![
+            square_idx(k0),
+            bottom_idx(k0),
+            bottom_idx(k1),
+            square_idx(k1),
+        ];
+        faces.push((side, Some(normal)));
+        let wall = vec![circle_idx(k0), circle_idx(k1), top_idx(k1), top_idx(k0)];
+        faces.push((wall, None));
+    });
+    faces.push((
+        (0..N).rev().map(bottom_idx).collect(),
+        Some(-Vector3::unit_z()),
+    ));
+    faces.push(((0..N).map(top_idx).collect(), Some(Vector3::unit_z())));
+
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(faces.iter().map(|(face, _)| face)),
+    );
+    mesh.add_normals_with_crease_angle(PI / 6.0);
+
+    // All quadrangles are before the n-gons, so the order of the faces is preserved.
+    assert_eq!(mesh.faces().len(), faces.len());
+    mesh.face_iter()
+        .zip(&faces)
+        .for_each(|(face, (_, normal))| {
+            face.iter().for_each(|v| {
+                let p = mesh.positions()[v.pos];
+                let expected = normal.unwrap_or_else(|| Vector3::new(p.x, p.y, 0.0));
+                let normal = mesh.normals()[v.nor.unwrap()];
+                assert!(normal.near(&expected), "{normal:?} {expected:?}");
+            })
+        });
+
+    // Cube edges also become smooth for the large angle.
+    mesh.add_normals_with_crease_angle(PI * 0.6);
+    let face = mesh.face_iter().next().unwrap();
+    let normal = mesh.normals()[face[0].nor.unwrap()];
+    assert!(!normal.near(&Vector3::unit_z()));
+}
+
+#[test]
+fn add_normals_with_crease_angle_degenerate_faces() {
+    use std::f64::consts::PI;
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(3.0, 0.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        // the second face is a segment
+        Faces::from_iter(&[[0, 1, 2], [1, 3, 4]]),
+    );
+    mesh.add_normals_with_crease_angle(PI / 6.0);
+    assert!(mesh.normals().iter().all(|n| n.magnitude2().is_finite()));
+    let face = mesh.face_iter().nth(1).unwrap();
+    assert_eq!(mesh.normals()[face[2].nor.unwrap()], Vector3::zero());
+    let face = mesh.face_iter().next().unwrap();
+    assert!(mesh.normals()[face[0].nor.unwrap()].near(&Vector3::unit_z()));
+}
@@ -1,5 +1,8 @@
-use truck_meshalgo::filters::*;
+use std::f64::consts::PI;
+use truck_meshalgo::{analyzers::*, filters::*};
 use truck_polymesh::*;
+#[path = "../common/mod.rs"]
+mod common;
 
 #[test]
 fn remove_unused_attrs_test() {
@@ -45,6 +48,70 @@ fn remove_unused_attrs_test() {
     assert_eq!(mesh.faces().len(), 3);
 }
 
+#[test]
+fn remove_unused_attrs_with_map_only_uv() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            uv_coords: vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(0.5, 0.5),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(0.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[&[(0, Some(0), None), (1, Some(2), None), (2, Some(3), None)]]),
+    );
+    let remapping = mesh.remove_unused_attrs_with_map();
+    assert_eq!(remapping.pos_map, vec![Some(0), Some(1), Some(2)]);
+    assert_eq!(remapping.uv_map, vec![Some(0), None, Some(1), Some(2)]);
+    assert!(remapping.nor_map.is_empty());
+    assert_eq!(mesh.positions().len(), 3);
+    assert_eq!(mesh.uv_coords().len(), 3);
+    assert_eq!(mesh.uv_coords()[1], Vector2::new(1.0, 0.0));
+    assert_eq!(mesh.faces()[0][1].uv, Some(1));
+}
+
+#[test]
+fn remove_unused_attrs_with_map_only_normals() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            normals: vec![
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 0.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[&[(0, None, Some(2)), (1, None, Some(2)), (2, None, Some(2))]]),
+    );
+    let remapping = mesh.remove_unused_attrs_with_map();
+    assert_eq!(remapping.pos_map, vec![Some(0), Some(1), Some(2)]);
+    assert!(remapping.uv_map.is_empty());
+    assert_eq!(remapping.nor_map, vec![None, None, Some(0)]);
+    assert_eq!(mesh.normals(), &vec![Vector3::new(0.0, 0.0, 1.0)]);
+    assert!(mesh.faces()[0].iter().all(|v| v.nor == Some(0)));
+}
+
+#[test]
+fn remove_unused_attrs_with_map_empty() {
+    let mut mesh: PolygonMesh = PolygonMesh::default();
+    let remapping = mesh.remove_unused_attrs_with_map();
+    assert_eq!(remapping, AttrRemapping::default());
+    assert!(mesh.positions().is_empty());
+    assert!(mesh.faces().is_empty());
+}
+
 #[test]
 fn remove_degenerate_faces_test() {
     const N: usize = 100;
@@ -190,3 +257,334 @@ fn put_together_same_attrs_test() {
     assert_eq!(mesh.uv_coords().len(), 18);
     assert_eq!(mesh.normals().len(), 17);
 }
+
+#[test]
+fn put_together_same_attrs_across_lattice_boundary() {
+    const TOL: f64 = 1.0e-3;
+    // The shifts make the pair straddle the boundary of any lattice with the cell size about `tol`.
+    for k in 0..20 {
+        let x = k as f64 * 0.37 * TOL;
+        let mut mesh = PolygonMesh::new(
+            StandardAttributes {
+                // The bounding box is [-1, 1]^3, so the normalized positions are the same as the original.
+                positions: vec![
+                    Point3::new(-1.0, -1.0, -1.0),
+                    Point3::new(1.0, 1.0, 1.0),
+                    Point3::new(x, 0.0, 0.0),
+                    Point3::new(x + TOL * 0.99, 0.0, 0.0),
+                ],
+                uv_coords: vec![Vector2::new(x, 0.0), Vector2::new(x + TOL * 0.99, 0.0)],
+                ..Default::default()
+            },
+            Faces::from_iter(&[
+                &[(0, Some(0), None), (1, Some(0), None), (2, Some(0), None)],
+                &[(0, Some(1), None), (1, Some(1), None), (3, Some(1), None)],
+            ]),
+        );
+        mesh.put_together_same_attrs(TOL);
+        assert_eq!(mesh.faces()[0][2].pos, 2, "k = {k}");
+        assert_eq!(mesh.faces()[1][2].pos, 2, "k = {k}");
+        assert_eq!(mesh.faces()[1][0].uv, Some(0), "k = {k}");
+    }
+}
+
+#[test]
+fn put_together_same_attrs_separated() {
+    const TOL: f64 = 1.0e-3;
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(-1.0, -1.0, -1.0),
+                Point3::new(1.0, 1.0, 1.0),
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(TOL * 1.01, 0.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 1, 3]]),
+    );
+    mesh.put_together_same_attrs(TOL);
+    assert_eq!(mesh.faces()[1][2].pos, 3);
+}
+
+fn duplicate_faces_mesh(faces: Faces) -> PolygonMesh {
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(0.0, 0.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn remove_duplicate_faces_same_orientation() {
+    let faces = Faces::from_iter(&[[0, 1, 2], [1, 2, 0], [0, 1, 4], [2, 0, 1], [0, 1, 2]]);
+    let mut mesh = duplicate_faces_mesh(faces.clone());
+    mesh.remove_duplicate_faces();
+    assert_eq!(mesh.faces().len(), 2);
+    assert_eq!(&mesh.faces()[0], &faces[0]);
+    assert_eq!(&mesh.faces()[1], &faces[2]);
+
+    let mut mesh = duplicate_faces_mesh(faces);
+    mesh.remove_cancelling_faces();
+    assert_eq!(mesh.faces().len(), 2);
+}
+
+#[test]
+fn remove_duplicate_faces_reversed() {
+    let faces = Faces::from_iter(&[[0, 1, 2], [0, 1, 4], [2, 1, 0], [4, 1, 0], [0, 2, 4]]);
+    let mut mesh = duplicate_faces_mesh(faces.clone());
+    mesh.remove_duplicate_faces();
+    assert_eq!(mesh.faces().len(), 3);
+    assert_eq!(&mesh.faces()[0], &faces[0]);
+    assert_eq!(&mesh.faces()[1], &faces[1]);
+    assert_eq!(&mesh.faces()[2], &faces[4]);
+
+    let mut mesh = duplicate_faces_mesh(faces.clone());
+    mesh.remove_cancelling_faces();
+    assert_eq!(mesh.faces().len(), 1);
+    assert_eq!(&mesh.faces()[0], &faces[4]);
+
+    // only one of the same orientation duplicates is cancelled.
+    let faces = Faces::from_iter(&[[1, 0, 2], [0, 1, 2], [1, 2, 0]]);
+    let mut mesh = duplicate_faces_mesh(faces.clone());
+    mesh.remove_cancelling_faces();
+    assert_eq!(mesh.faces().len(), 1);
+    assert_eq!(&mesh.faces()[0], &faces[1]);
+}
+
+#[test]
+fn remove_duplicate_faces_quads() {
+    let faces = Faces::from_iter(&[
+        [0, 1, 2, 3].as_ref(),
+        &[2, 3, 0, 1],
+        &[3, 2, 1, 0],
+        &[0, 1, 2], // a part of the quadrangle is not a duplicate.
+        &[0, 1, 2, 3, 4],
+        &[4, 3, 2, 1, 0],
+    ]);
+    let mut mesh = duplicate_faces_mesh(faces.clone());
+    mesh.remove_duplicate_faces();
+    assert_eq!(mesh.faces().tri_faces().len(), 1);
+    assert_eq!(mesh.faces().quad_faces().len(), 1);
+    assert_eq!(mesh.faces().other_faces().len(), 1);
+
+    let mut mesh = duplicate_faces_mesh(faces);
+    mesh.remove_cancelling_faces();
+    assert_eq!(mesh.faces().tri_faces().len(), 1);
+    assert_eq!(mesh.faces().quad_faces().len(), 1);
+    assert_eq!(mesh.faces().other_faces().len(), 0);
+    assert_eq!(mesh.faces().quad_faces()[0][1].pos, 1);
+}
+
+#[test]
+fn collapse_short_edges_sliver_ring() {
+    const DIV: usize = 16;
+    // open cylinder with a ring of sliver quadrangles at the bottom
+    let heights = [0.0, 1.0e-4, 0.5, 1.0];
+    let positions = heights
+        .iter()
+        .flat_map(|z| {
+            (0..DIV).map(move |i| {
+                let t = 2.0 * PI * i as f64 / DIV as f64;
+                Point3::new(f64::cos(t), f64::sin(t), *z)
+            })
+        })
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter((0..heights.len() - 1).flat_map(|j| {
+        (0..DIV).map(move |i| {
+            [
+                j * DIV + i,
+                j * DIV + (i + 1) % DIV,
+                (j + 1) * DIV + (i + 1) % DIV,
+                (j + 1) * DIV + i,
+            ]
+        })
+    }));
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+
+    mesh.collapse_short_edges(1.0e-3).remove_unused_attrs();
+    assert_eq!(mesh.positions().len(), DIV * 3);
+    assert_eq!(mesh.faces().len(), DIV * 2);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    mesh.face_iter().for_each(|face| {
+        (0..face.len()).for_each(|i| {
+            let p = mesh.positions()[face[i].pos];
+            let q = mesh.positions()[face[(i + 1) % face.len()].pos];
+            assert!(p.distance(q) >= 1.0e-3);
+        })
+    });
+}
+
+#[test]
+fn simplify_by_clustering_sphere() {
+    const CELL_SIZE: f64 = 0.25;
+    let mut mesh = common::shapes::sphere(Point3::new(0.3, -0.2, 0.1), 1.0, 128, 64);
+    let bnd_box = mesh.bounding_box();
+    let occupied = mesh
+        .positions()
+        .iter()
+        .map(|p| {
+            let v = (p - bnd_box.min()) / CELL_SIZE;
+            [v.x.floor() as i64, v.y.floor() as i64, v.z.floor() as i64]
+        })
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    let used = mesh.positions().len();
+
+    let stats = mesh.simplify_by_clustering(CELL_SIZE);
+    assert_eq!(stats.vertices_before, used);
+    assert_eq!(stats.vertices_after, mesh.positions().len());
+    // Some clusters may disappear since all faces around them are degenerate.
+    assert!(stats.vertices_after <= occupied);
+    assert!(stats.vertices_after as f64 >= occupied as f64 * 0.9);
+    mesh.positions().iter().for_each(|p| {
+        let dist = p.distance(Point3::new(0.3, -0.2, 0.1));
+        assert!(f64::abs(dist - 1.0) < CELL_SIZE * f64::sqrt(3.0), "{dist}");
+    });
+}
+
+#[test]
+fn remove_degenerate_faces_with_tol_test() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 1.0),
+                Point3::new(3.0, 3.0, 3.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 1.0e-12),
+                Point3::new(0.0, 0.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 1, 2].as_ref(),  // colinear: death
+            &[0, 4, 5],          // coincident vertices: death
+            &[0, 4, 3],          // survive
+            &[0, 4, 5, 3],       // coincident vertices: triangle
+            &[0, 1, 2, 3],       // colinear vertices: triangle
+            &[0, 4, 5, 3, 6],    // coincident vertices: quadrangle
+            &[0, 1, 2, 0, 1, 2], // index-degenerate: death
+        ]),
+    );
+    mesh.remove_degenerate_faces_with_tol(TOLERANCE);
+    assert_eq!(mesh.faces().tri_faces().len(), 3);
+    assert_eq!(mesh.faces().quad_faces().len(), 1);
+    assert_eq!(mesh.faces().other_faces().len(), 0);
+    mesh.face_iter().for_each(|face| {
+        let p = mesh.positions()[face[0].pos];
+        let q = mesh.positions()[face[1].pos];
+        let r = mesh.positions()[face[2].pos];
+        assert!((q - p).cross(r - p).magnitude() > TOLERANCE);
+    });
+}
+
+#[test]
+fn optimized_does_not_modify_original() {
+    let mut mesh = common::shapes::sphere(Point3::new(0.0, 0.0, 0.0), 1.0, 16, 8);
+    mesh.add_naive_normals(true);
+    let original = mesh.clone();
+    let optimized = mesh.optimized(OptimizeOptions::default());
+
+    assert_eq!(mesh, original);
+    let to_bits = |mesh: &PolygonMesh| {
+        mesh.positions()
+            .iter()
+            .flat_map(|p| [p.x, p.y, p.z])
+            .chain(mesh.normals().iter().flat_map(|n| [n.x, n.y, n.z]))
+            .map(f64::to_bits)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(to_bits(&mesh), to_bits(&original));
+
+    let mut expected = original.clone();
+    expected
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(optimized, expected);
+    assert!(optimized.positions().len() < mesh.positions().len());
+}
+
+#[test]
+fn put_together_same_attrs_averaged_grids() {
+    const N: usize = 4;
+    let offset = Vector3::new(2.0e-4, -1.0e-4, 3.0e-4);
+    let grid = (0..=N)
+        .flat_map(|i| {
+            (0..=N).map(move |j| Point3::new(i as f64 / N as f64, j as f64 / N as f64, 0.0))
+        })
+        .collect::<Vec<_>>();
+    let positions = grid
+        .iter()
+        .copied()
+        .chain(grid.iter().map(|p| p + offset))
+        .collect::<Vec<_>>();
+    let tilted = Vector3::new(4.0e-4, 0.0, 1.0).normalize();
+    let len = grid.len();
+    let faces = Faces::from_iter((0..2).flat_map(|k| {
+        (0..N).flat_map(move |i| {
+            (0..N).map(move |j| {
+                let idx = |i: usize, j: usize| k * len + i * (N + 1) + j;
+                [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1)]
+                    .map(|p| (p, Some(p % len), Some(k)))
+            })
+        })
+    }));
+    let uv_coords = grid.iter().map(|p| Vector2::new(p.x, p.y)).collect();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals: vec![Vector3::unit_z(), tilted],
+        },
+        faces,
+    );
+
+    mesh.put_together_same_attrs_averaged(1.0e-3)
+        .remove_unused_attrs();
+    assert_eq!(mesh.positions().len(), len);
+    mesh.positions()
+        .iter()
+        .zip(&grid)
+        .for_each(|(p, q)| assert_near!(*p, q + offset / 2.0));
+    assert_eq!(mesh.normals().len(), 1);
+    assert_near!(mesh.normals()[0], (Vector3::unit_z() + tilted).normalize());
+    assert_near!(mesh.normals()[0].magnitude(), 1.0);
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+#[test]
+fn put_together_same_attrs_independent_of_threads() {
+    let mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    let optimize = || {
+        let mut mesh = mesh.clone();
+        mesh.put_together_same_attrs(TOLERANCE)
+            .remove_unused_attrs();
+        mesh
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let serial = pool.install(optimize);
+    let parallel = optimize();
+    assert!(serial.positions().len() < mesh.positions().len());
+    assert_eq!(serial, parallel);
+}
@@ -0,0 +1,57 @@
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+macro_rules! dir ( () => { concat!(env!("CARGO_MANIFEST_DIR"), "/../resources/obj/") });
+
+fn segment_distance(p: Point3, a: Point3, b: Point3) -> f64 {
+    let dir = b - a;
+    let t = f64::clamp((p - a).dot(dir) / dir.magnitude2(), 0.0, 1.0);
+    p.distance(a + dir * t)
+}
+
+fn triangle_distance(p: Point3, [a, b, c]: [Point3; 3]) -> f64 {
+    let normal = (b - a).cross(c - a);
+    if normal.magnitude2() > 0.0 {
+        let normal = normal.normalize();
+        let h = (p - a).dot(normal);
+        let q = p - normal * h;
+        let inside = [(a, b), (b, c), (c, a)]
+            .into_iter()
+            .all(|(v, w)| (w - v).cross(q - v).dot(normal) >= 0.0);
+        if inside {
+            return h.abs();
+        }
+    }
+    [(a, b), (b, c), (c, a)]
+        .into_iter()
+        .map(|(v, w)| segment_distance(p, v, w))
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[test]
+fn simplify_qem_teapot() {
+    let file = std::fs::File::open(concat!(dir!(), "teapot.obj")).unwrap();
+    let mut mesh = obj::read(file).unwrap();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .triangulate();
+    let original = mesh.clone();
+    let original_triangles = original
+        .tri_faces()
+        .iter()
+        .map(|face| [0, 1, 2].map(|i| original.positions()[face[i].pos]))
+        .collect::<Vec<_>>();
+    let target = original.faces().len() / 10;
+    let tol = original.bounding_box().diameter() * 0.02;
+
+    mesh.simplify_qem(target);
+    assert!(mesh.faces().len() <= target);
+    assert!(mesh.uv_coords().is_empty() && mesh.normals().is_empty());
+    mesh.positions().iter().for_each(|p| {
+        let dist = original_triangles
+            .iter()
+            .map(|tri| triangle_distance(*p, *tri))
+            .fold(f64::INFINITY, f64::min);
+        assert!(dist < tol, "{p:?} is far from the original mesh: {dist}");
+    });
+}
@@ -0,0 +1,80 @@
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+#[path = "../common/mod.rs"]
+mod common;
+
+fn radial_statistics(mesh: &PolygonMesh) -> (f64, f64) {
+    let radii = mesh
+        .positions()
+        .iter()
+        .map(|p| p.to_vec().magnitude())
+        .collect::<Vec<_>>();
+    let len = radii.len() as f64;
+    let mean = radii.iter().sum::<f64>() / len;
+    let rms = f64::sqrt(radii.iter().map(|r| (r - 1.0) * (r - 1.0)).sum::<f64>() / len);
+    (mean, rms)
+}
+
+#[test]
+fn taubin_smooth_noisy_sphere() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs()
+        .add_naive_normals(true);
+    let mut editor = mesh.debug_editor();
+    editor.attributes.positions.iter_mut().for_each(|p| {
+        let noise = 1.0 + 0.05 * (2.0 * rand::random::<f64>() - 1.0);
+        *p = Point3::from_vec(p.to_vec() * noise);
+    });
+    drop(editor);
+    let (_, rms0) = radial_statistics(&mesh);
+
+    mesh.taubin_smooth(0.5, -0.53, 10, false);
+    let (mean, rms) = radial_statistics(&mesh);
+    assert!(rms < rms0 / 2.0, "{rms} {rms0}");
+    assert!((mean - 1.0).abs() < 0.01, "{mean}");
+
+    // normals are invalidated
+    assert!(mesh.normals().is_empty());
+    assert!(mesh.face_iter().flatten().all(|v| v.nor.is_none()));
+}
+
+#[test]
+fn taubin_smooth_preserve_boundary() {
+    // hemisphere
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 32, 17);
+    mesh.put_together_same_attrs(TOLERANCE);
+    let faces = mesh
+        .face_iter()
+        .filter(|face| face.iter().all(|v| mesh.positions()[v.pos].z > -TOLERANCE))
+        .collect::<Faces>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: mesh.positions().clone(),
+            ..Default::default()
+        },
+        faces,
+    );
+    mesh.remove_degenerate_faces().remove_unused_attrs();
+    let boundary = mesh
+        .positions()
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.z.abs() < TOLERANCE)
+        .map(|(i, p)| (i, *p))
+        .collect::<Vec<_>>();
+    assert_eq!(boundary.len(), 32);
+
+    let mut smoothed = mesh.clone();
+    smoothed.taubin_smooth(0.5, -0.53, 10, true);
+    boundary
+        .iter()
+        .for_each(|(i, p)| assert_eq!(smoothed.positions()[*i], *p));
+
+    let mut smoothed = mesh.clone();
+    smoothed.taubin_smooth(0.5, -0.53, 10, false);
+    assert!(boundary
+        .iter()
+        .any(|(i, p)| !smoothed.positions()[*i].near(p)));
+}
@@ -1,4 +1,4 @@
-use truck_meshalgo::filters::*;
+use truck_meshalgo::{analyzers::*, errors::Error, filters::*};
 use truck_polymesh::*;
 
 #[test]
@@ -106,3 +106,314 @@ fn quadrangulate_test() {
     tri_mesh.quadrangulate(TOLERANCE, TOLERANCE);
     assert_eq!(tri_mesh.faces(), quad_mesh.faces());
 }
+
+fn assert_valid_triangles(mesh: &PolygonMesh, normal: Vector3) {
+    mesh.tri_faces().iter().for_each(|face| {
+        let [p, q, r] = face.map(|v| mesh.positions()[v.pos]);
+        assert!((q - p).cross(r - p).dot(normal) > TOLERANCE);
+    });
+}
+
+#[test]
+fn robust_triangulate_concave_hexagon() {
+    // L-shaped hexagon, whose fan from the first vertex is invalid.
+    let positions = vec![
+        Point3::new(2.0, 1.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+    ];
+    let uv_coords = positions.iter().map(|p| Vector2::new(p.x, p.y)).collect();
+    let face = (0..6)
+        .map(|i| (i, Some(5 - i), Some(0)))
+        .collect::<Vec<_>>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals: vec![Vector3::unit_z()],
+        },
+        Faces::from_iter(&[face]),
+    );
+    mesh.robust_triangulate_faces();
+    assert_eq!(mesh.faces().len(), 4);
+    assert_valid_triangles(&mesh, Vector3::unit_z());
+    let area = mesh.tri_faces().iter().fold(0.0, |sum, face| {
+        let [p, q, r] = face.map(|v| mesh.positions()[v.pos]);
+        sum + (q - p).cross(r - p).z / 2.0
+    });
+    assert_near!(area, 3.0);
+    // the attributes of each corner are preserved
+    mesh.tri_faces().iter().flatten().for_each(|v| {
+        assert_eq!(v.uv, Some(5 - v.pos));
+        assert_eq!(v.nor, Some(0));
+    });
+}
+
+#[test]
+fn robust_triangulate_twisted_quad() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.1),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.1),
+            ],
+            uv_coords: vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(1.0, 1.0),
+                Vector2::new(0.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[
+            (0, Some(0), None),
+            (1, Some(1), None),
+            (2, Some(2), None),
+            (3, Some(3), None),
+        ]]),
+    );
+    mesh.robust_triangulate_faces();
+    assert_eq!(mesh.faces().len(), 2);
+    assert!(mesh.faces().quad_faces().is_empty());
+    assert_valid_triangles(&mesh, Vector3::unit_z());
+    mesh.tri_faces().iter().flatten().for_each(|v| {
+        assert_eq!(v.uv, Some(v.pos));
+        assert_eq!(v.nor, None);
+    });
+    // Each edge of the quadrangle is contained in some triangle.
+    (0..4).for_each(|i| {
+        let edge = [i, (i + 1) % 4];
+        assert!(mesh
+            .tri_faces()
+            .iter()
+            .any(|face| { (0..3).any(|k| [face[k].pos, face[(k + 1) % 3].pos] == edge) }));
+    });
+}
+
+/// `n` x `n` grid of triangles on the plane `z = 0`, lifted along `y > 1` if `bent == true`
+fn triangulated_grid(n: usize, bent: bool) -> (Vec<Point3>, Vec<[usize; 3]>) {
+    let positions = (0..=n)
+        .flat_map(|i| {
+            (0..=n).map(move |j| {
+                let (x, y) = (i as f64 / n as f64, 2.0 * j as f64 / n as f64);
+                match bent && y > 1.0 {
+                    true => Point3::new(x, 1.0, y - 1.0),
+                    false => Point3::new(x, y, 0.0),
+                }
+            })
+        })
+        .collect();
+    let idx = move |i: usize, j: usize| i * (n + 1) + j;
+    let faces = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .flat_map(|(i, j)| {
+            [
+                [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1)],
+                [idx(i, j), idx(i + 1, j + 1), idx(i, j + 1)],
+            ]
+        })
+        .collect();
+    (positions, faces)
+}
+
+#[test]
+fn merge_coplanar_faces_grid() {
+    let (positions, faces) = triangulated_grid(10, false);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    mesh.merge_coplanar_faces(TOLERANCE).remove_unused_attrs();
+    assert_eq!(mesh.faces().len(), 1);
+    assert_eq!(mesh.faces().quad_faces().len(), 1);
+    assert_eq!(mesh.positions().len(), 4);
+    let normal = mesh.faces().quad_faces()[0]
+        .iter()
+        .map(|v| mesh.positions()[v.pos])
+        .collect::<Vec<_>>();
+    let normal = (normal[1] - normal[0]).cross(normal[2] - normal[1]);
+    assert!(normal.z > 0.0);
+}
+
+#[test]
+fn merge_coplanar_faces_with_hole() {
+    let (positions, faces) = triangulated_grid(10, false);
+    let center = |face: &&[usize; 3]| {
+        let p = face
+            .iter()
+            .fold(Vector3::zero(), |sum, i| sum + positions[*i].to_vec())
+            / 3.0;
+        (0.4..0.6).contains(&p.x) && (0.8..1.2).contains(&p.y)
+    };
+    let faces = faces
+        .iter()
+        .filter(|face| !center(face))
+        .collect::<Vec<_>>();
+    let len = faces.len();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(faces),
+    );
+    mesh.merge_coplanar_faces(TOLERANCE);
+    assert_eq!(mesh.faces().len(), len);
+}
+
+#[test]
+fn merge_coplanar_faces_bent_grid() {
+    let (positions, faces) = triangulated_grid(10, true);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    mesh.merge_coplanar_faces(TOLERANCE).remove_unused_attrs();
+    assert_eq!(mesh.faces().len(), 2);
+    assert_eq!(mesh.faces().quad_faces().len(), 2);
+    // The vertices on the bent edge are dropped from both faces.
+    assert_eq!(mesh.positions().len(), 6);
+}
+
+#[test]
+fn merge_coplanar_faces_duplicated_face() {
+    let (positions, mut faces) = triangulated_grid(2, false);
+    faces.push(faces[0]);
+    let len = faces.len();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    // The boundary of the region is not a simple loop, so nothing is merged.
+    mesh.merge_coplanar_faces(TOLERANCE);
+    assert_eq!(mesh.faces().len(), len);
+}
+
+fn triangulated_cube() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let faces = Faces::from_iter(&[
+        &[3, 2, 1, 0],
+        &[0, 1, 5, 4],
+        &[1, 2, 6, 5],
+        &[2, 3, 7, 6],
+        &[3, 0, 4, 7],
+        &[4, 5, 6, 7],
+    ]);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    mesh.triangulate();
+    mesh
+}
+
+#[test]
+fn heal_orientation_cube() {
+    let mut mesh = triangulated_cube();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    let original = mesh.faces().clone();
+
+    let mut indices = (0..12).collect::<Vec<usize>>();
+    let flipped = (0..3)
+        .map(|_| indices.swap_remove(rand::random::<usize>() % indices.len()))
+        .collect::<Vec<_>>();
+    let mut faces = mesh.faces().clone();
+    faces
+        .face_iter_mut()
+        .enumerate()
+        .filter(|(i, _)| flipped.contains(i))
+        .for_each(|(_, face)| face.reverse());
+    *mesh.debug_editor().faces = faces;
+    assert_eq!(mesh.shell_condition(), ShellCondition::Regular);
+
+    assert_eq!(mesh.heal_orientation(), Ok(3));
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert_eq!(mesh.faces(), &original);
+}
+
+#[test]
+fn heal_orientation_negates_normals() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [1, 2, 3]]),
+    );
+    mesh.add_naive_normals(true);
+    assert_eq!(mesh.heal_orientation(), Ok(1));
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    mesh.faces().face_iter().flatten().for_each(|v| {
+        let normal = mesh.normals()[v.nor.unwrap()];
+        assert_near!(normal, Vector3::unit_z());
+    });
+}
+
+#[test]
+fn heal_orientation_mobius_strip() {
+    const N: usize = 8;
+    // the vertices `i` and `i + N` are on the opposite sides of the strip.
+    let positions = (0..2 * N)
+        .map(|i| {
+            let t = 2.0 * std::f64::consts::PI * (i % N) as f64 / N as f64;
+            let s = match i < N {
+                true => -0.3,
+                false => 0.3,
+            };
+            let r = 1.0 + s * f64::cos(t / 2.0);
+            Point3::new(r * f64::cos(t), r * f64::sin(t), s * f64::sin(t / 2.0))
+        })
+        .collect::<Vec<_>>();
+    let faces = (0..N)
+        .map(|i| match i + 1 < N {
+            true => [i, i + 1, i + N + 1, i + N],
+            false => [i, N, 0, i + N],
+        })
+        .collect::<Faces>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    let original = mesh.clone();
+    let Err(Error::NonOrientable(mut cycle)) = mesh.heal_orientation() else {
+        panic!("the Möbius strip must not be orientable.");
+    };
+    // The only cycle goes around the strip.
+    cycle.sort();
+    assert_eq!(cycle, (0..N).collect::<Vec<_>>());
+    assert_eq!(mesh.faces(), original.faces());
+}
@@ -38,3 +38,97 @@ fn loop_subdivision() {
         ],
     );
 }
+
+fn octahedron() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+    ];
+    let faces = Faces::from_iter((0..4).flat_map(|i| [[i, (i + 1) % 4, 4], [(i + 1) % 4, i, 5]]));
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+/// the ratio of the maximum and minimum distances from the origin to the vertices and the centroids of faces
+fn radius_ratio(mesh: &PolygonMesh) -> f64 {
+    let radii = mesh
+        .positions()
+        .iter()
+        .map(|p| p.to_vec().magnitude())
+        .chain(mesh.tri_faces().iter().map(|face| {
+            let sum = face.iter().fold(Vector3::zero(), |sum, v| {
+                sum + mesh.positions()[v.pos].to_vec()
+            });
+            (sum / 3.0).magnitude()
+        }))
+        .collect::<Vec<_>>();
+    let max = radii.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let min = radii.iter().copied().fold(f64::INFINITY, f64::min);
+    max / min
+}
+
+#[test]
+fn loop_subdivide_octahedron() {
+    use truck_meshalgo::analyzers::*;
+    let mut mesh = octahedron();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    let mut ratio = radius_ratio(&mesh);
+    for level in 1..=3 {
+        mesh.loop_subdivide(1);
+        assert_eq!(mesh.faces().len(), 8 * 4_usize.pow(level));
+        assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+        let next_ratio = radius_ratio(&mesh);
+        assert!(next_ratio < ratio, "level: {level}, {next_ratio} {ratio}");
+        ratio = next_ratio;
+    }
+    assert!(ratio < 1.1, "{ratio}");
+
+    let mut mesh = octahedron();
+    mesh.loop_subdivide(3);
+    assert_eq!(mesh.faces().len(), 8 * 64);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn loop_subdivide_uv_coords() {
+    // a quadrangle whose texture coordinates are the same as positions
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(0.5, 0.5, 0.0),
+            ],
+            uv_coords: vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(1.0, 1.0),
+                Vector2::new(0.0, 1.0),
+                Vector2::new(0.5, 0.5),
+            ],
+            normals: vec![Vector3::unit_z()],
+        },
+        Faces::from_iter((0..4).map(|i| [i, (i + 1) % 4, 4].map(|j| (j, Some(j), Some(0))))),
+    );
+    mesh.loop_subdivide(2);
+    assert_eq!(mesh.faces().len(), 64);
+    assert!(mesh.normals().is_empty());
+    // The stencils are affine invariant, so the texture coordinates still agree with the positions.
+    mesh.face_iter().flatten().for_each(|v| {
+        let p = mesh.positions()[v.pos];
+        let uv = mesh.uv_coords()[v.uv.unwrap()];
+        assert_near!(uv, Vector2::new(p.x, p.y));
+        assert_eq!(v.nor, None);
+    });
+}
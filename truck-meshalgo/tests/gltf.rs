@@ -0,0 +1,162 @@
+#![cfg(feature = "gltf")]
+use serde_json::Value;
+use truck_meshalgo::prelude::*;
+use truck_modeling::builder;
+
+fn parse_glb(glb: &[u8]) -> (Value, &[u8]) {
+    let u32_at = |i: usize| u32::from_le_bytes(glb[i..i + 4].try_into().unwrap()) as usize;
+    assert_eq!(&glb[0..4], b"glTF");
+    assert_eq!(u32_at(4), 2);
+    assert_eq!(u32_at(8), glb.len());
+    let json_len = u32_at(12);
+    assert_eq!(&glb[16..20], b"JSON");
+    let json = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+    let bin_start = 20 + json_len;
+    assert_eq!(u32_at(bin_start) + bin_start + 8, glb.len());
+    assert_eq!(&glb[bin_start + 4..bin_start + 8], b"BIN\0");
+    (json, &glb[bin_start + 8..])
+}
+
+fn as_f64s(value: &Value) -> Vec<f64> {
+    let array = value.as_array().unwrap();
+    array.iter().map(|x| x.as_f64().unwrap()).collect()
+}
+
+#[test]
+fn glb_polygon_mesh() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(3.0, 1.0, 0.0),
+                Point3::new(2.0, 2.0, -1.0),
+            ],
+            uv_coords: vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.25)],
+            normals: vec![Vector3::new(0.0, 0.0, 2.0)],
+        },
+        Faces::from_iter(&[
+            [
+                (0, Some(0), Some(0)),
+                (1, Some(1), Some(0)),
+                (2, Some(1), Some(0)),
+                (3, Some(0), Some(0)),
+            ]
+            .as_slice(),
+            &[
+                (1, Some(1), Some(0)),
+                (4, Some(0), Some(0)),
+                (5, Some(1), Some(0)),
+                (6, Some(0), Some(0)),
+                (2, Some(1), Some(0)),
+            ],
+        ]),
+    );
+    let mut glb = Vec::new();
+    mesh.write_glb(&mut glb, |_| [0.5, 0.25, 1.0, 1.0]).unwrap();
+    let (json, bin) = parse_glb(&glb);
+
+    assert_eq!(json["asset"]["version"], "2.0");
+    assert_eq!(
+        json["buffers"][0]["byteLength"].as_u64().unwrap() as usize,
+        bin.len()
+    );
+    let primitives = json["meshes"][0]["primitives"].as_array().unwrap();
+    assert_eq!(primitives.len(), 1);
+    let attributes = &primitives[0]["attributes"];
+    let accessor = |key: &Value| &json["accessors"][key.as_u64().unwrap() as usize];
+    let positions = accessor(&attributes["POSITION"]);
+    // the vertex (1, Some(1), Some(0)) and (2, Some(1), Some(0)) are shared by the faces.
+    assert_eq!(positions["count"], 7);
+    assert_eq!(as_f64s(&positions["min"]), [0.0, 0.0, -1.0]);
+    assert_eq!(as_f64s(&positions["max"]), [3.0, 2.0, 0.0]);
+    assert_eq!(accessor(&attributes["NORMAL"])["count"], 7);
+    assert_eq!(accessor(&attributes["TEXCOORD_0"])["count"], 7);
+    let indices = accessor(&primitives[0]["indices"]);
+    assert_eq!(indices["count"], 3 * (2 + 3));
+    assert_eq!(indices["type"], "SCALAR");
+
+    let material = &json["materials"][primitives[0]["material"].as_u64().unwrap() as usize];
+    let color = as_f64s(&material["pbrMetallicRoughness"]["baseColorFactor"]);
+    assert_eq!(color, [0.5, 0.25, 1.0, 1.0]);
+
+    // normals are normalized
+    let view = &json["bufferViews"][accessor(&attributes["NORMAL"])["bufferView"]
+        .as_u64()
+        .unwrap() as usize];
+    let offset = view["byteOffset"].as_u64().unwrap() as usize;
+    let z = f32::from_le_bytes(bin[offset + 8..offset + 12].try_into().unwrap());
+    assert_eq!(z, 1.0);
+}
+
+#[test]
+fn glb_shell() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::new(0.0, 0.0, 2.0));
+    let shell = cube.boundaries()[0].triangulation(0.01);
+    let colors = [
+        [1.0, 0.0, 0.0, 1.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, 1.0],
+        [1.0, 1.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0, 1.0],
+        [1.0, 0.0, 1.0, 1.0],
+    ];
+    let mut glb = Vec::new();
+    shell.write_glb(&mut glb, |i| colors[i]).unwrap();
+    let (json, _) = parse_glb(&glb);
+
+    let primitives = json["meshes"][0]["primitives"].as_array().unwrap();
+    assert_eq!(primitives.len(), shell.len());
+    let accessor = |key: &Value| &json["accessors"][key.as_u64().unwrap() as usize];
+    let (mut min, mut max) = ([f64::INFINITY; 3], [f64::NEG_INFINITY; 3]);
+    primitives
+        .iter()
+        .zip(shell.face_iter())
+        .enumerate()
+        .for_each(|(i, (primitive, face))| {
+            let polygon = face.surface().unwrap();
+            let attributes = &primitive["attributes"];
+            let positions = accessor(&attributes["POSITION"]);
+            let count = positions["count"].as_u64().unwrap();
+            assert!(count as usize <= polygon.faces().triangle_iter().len() * 3);
+            assert_eq!(accessor(&attributes["NORMAL"])["count"], count);
+            assert_eq!(accessor(&attributes["TEXCOORD_0"])["count"], count);
+            let indices = accessor(&primitive["indices"]);
+            let num_indices = indices["count"].as_u64().unwrap() as usize;
+            assert_eq!(num_indices, polygon.faces().triangle_iter().len() * 3);
+
+            let bdb = polygon.positions().iter().collect::<BoundingBox<Point3>>();
+            let (face_min, face_max) = (as_f64s(&positions["min"]), as_f64s(&positions["max"]));
+            (0..3).for_each(|j| {
+                assert_near!(face_min[j], bdb.min()[j]);
+                assert_near!(face_max[j], bdb.max()[j]);
+                min[j] = f64::min(min[j], face_min[j]);
+                max[j] = f64::max(max[j], face_max[j]);
+            });
+
+            let material = &json["materials"][primitive["material"].as_u64().unwrap() as usize];
+            let color = as_f64s(&material["pbrMetallicRoughness"]["baseColorFactor"]);
+            assert_eq!(color, colors[i]);
+        });
+    assert_eq!(min, [0.0, 0.0, 0.0]);
+    assert_eq!(max, [1.0, 1.0, 2.0]);
+}
+
+#[test]
+fn glb_empty() {
+    let mut glb = Vec::new();
+    PolygonMesh::default()
+        .write_glb(&mut glb, |_| [1.0; 4])
+        .unwrap();
+    let u32_at = |i: usize| u32::from_le_bytes(glb[i..i + 4].try_into().unwrap()) as usize;
+    assert_eq!(u32_at(8), glb.len());
+    assert_eq!(u32_at(12) + 20, glb.len());
+    let json: Value = serde_json::from_slice(&glb[20..]).unwrap();
+    assert!(json.get("meshes").is_none());
+}
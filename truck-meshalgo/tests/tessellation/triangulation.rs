@@ -133,3 +133,146 @@ fn special_cylinder_csolid() {
         .remove_unused_attrs();
     assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn special_cylinder_pcurves() {
+    let shell = special_cylinder_model();
+    for face in shell.face_iter() {
+        assert!(face.compute_pcurves(0.01));
+    }
+
+    // the bottom arc of `face3` crosses the seam of the cylinder
+    let face = &shell[3];
+    let surface = face.surface();
+    let edge = &face.absolute_boundaries()[0][0];
+    let pcurve = face.pcurve(edge).unwrap();
+    for p in pcurve.windows(2) {
+        assert!(p[0].distance(p[1]) < 0.5, "{p:?}");
+    }
+    let (p, q) = (pcurve[0], pcurve[pcurve.len() - 1]);
+    assert_near!(f64::abs(q.y - p.y), std::f64::consts::PI);
+    pcurve.iter().for_each(|p| {
+        let pt = surface.subs(p.x, p.y);
+        assert_near!(pt.to_vec().magnitude(), 1.0);
+        assert_near!(pt.z, 0.0);
+    });
+
+    let half: Shell = vec![face.clone()].into();
+    let area = half.triangulation(0.01).to_polygon().surface_area();
+    let exact = std::f64::consts::PI;
+    assert!(f64::abs(area - exact) < 0.01 * exact, "{area} {exact}");
+
+    let mut mesh = shell.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn partial_cylinder_across_seam() {
+    // the side of the cylinder from -165 degrees to 165 degrees, across the seam at 0 degrees
+    let t = f64::to_radians(165.0);
+    let (c, s) = (f64::cos(t), f64::sin(t));
+    let v0 = builder::vertex(Point3::new(c, -s, 0.0));
+    let v1 = builder::vertex(Point3::new(c, s, 0.0));
+    let v2 = builder::vertex(Point3::new(c, s, 1.0));
+    let v3 = builder::vertex(Point3::new(c, -s, 1.0));
+    let wire: Wire = vec![
+        builder::circle_arc(&v0, &v1, Point3::new(1.0, 0.0, 0.0)),
+        builder::line(&v1, &v2),
+        builder::circle_arc(&v2, &v3, Point3::new(1.0, 0.0, 1.0)),
+        builder::line(&v3, &v0),
+    ]
+    .into();
+    let surface = RevolutedCurve::<Curve>::by_revolution(
+        Line(Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)).into(),
+        Point3::origin(),
+        Vector3::unit_z(),
+    );
+    let face = Face::new(vec![wire], Processor::new(surface).into());
+    let shell: Shell = vec![face].into();
+
+    let mesh = shell.triangulation(0.01).to_polygon();
+    let diagonal = mesh.bounding_box().diameter();
+    let positions = mesh.positions();
+    mesh.faces().tri_faces().iter().for_each(|tri| {
+        for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+            let dist = positions[tri[a].pos].distance(positions[tri[b].pos]);
+            assert!(dist < diagonal, "{dist} {diagonal}");
+        }
+    });
+    let area = mesh.surface_area();
+    let exact = 2.0 * std::f64::consts::PI * 330.0 / 360.0;
+    assert!(f64::abs(area - exact) < 0.01 * exact, "{area} {exact}");
+}
+
+#[test]
+fn cylinder_with_finer_face() {
+    // the side face of the cylinder is the only curved face.
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder = builder::tsweep(&disk, Vector3::unit_z());
+    let side = cylinder
+        .face_iter()
+        .find(|face| !matches!(face.surface(), Surface::Plane(_)))
+        .unwrap();
+
+    let tol = 0.01;
+    let param = MeshParam {
+        tol: Some(tol / 10.0),
+        ..Default::default()
+    };
+    let params = std::collections::HashMap::from([(side.id(), param)]);
+    let meshed = cylinder.triangulation_with(tol, &params);
+    let default = cylinder.triangulation(tol);
+    type MeshedShell = truck_topology::Shell<Point3, PolylineCurve<Point3>, Option<PolygonMesh>>;
+    let count = |shell: &MeshedShell| {
+        shell
+            .face_iter()
+            .map(|face| face.surface().unwrap().positions().len())
+            .collect::<Vec<_>>()
+    };
+    let fine = count(&meshed.boundaries()[0]);
+    let coarse = count(&default.boundaries()[0]);
+    assert!(
+        fine.iter().zip(&coarse).all(|(x, y)| x > y),
+        "{fine:?} {coarse:?}"
+    );
+
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs(1.0e-12);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn closed_cylinder_without_welding() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder = builder::tsweep(&disk, Vector3::unit_z());
+
+    let mesh = cylinder.triangulation(0.01).to_polygon();
+    assert!(mesh.extract_boundaries().is_empty());
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+
+    let cshell = cylinder.boundaries()[0].compress();
+    let mesh = cshell.triangulation(0.01).to_polygon();
+    assert!(mesh.extract_boundaries().is_empty());
+}
+
+#[test]
+fn cube_face_provenance() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+
+    let (mesh, provenance) = cube.triangulation(0.01).to_polygon_with_provenance();
+    assert_eq!(mesh.tri_faces().len(), provenance.len());
+    assert!(mesh.quad_faces().is_empty() && mesh.other_faces().is_empty());
+    let mut counts = [0; 6];
+    provenance.iter().for_each(|i| counts[*i] += 1);
+    assert_eq!(counts, [2; 6]);
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use errors::Error;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 const PI: Rad<f64> = Rad(std::f64::consts::PI);
 
 /// Creates and returns a vertex by a three dimensional point.
@@ -255,6 +256,174 @@ pub fn try_wire_homotopy(wire0: &Wire, wire1: &Wire) -> Result<Shell> {
     Ok(shell)
 }
 
+/// Creates a shell by lofting the wires `sections` in order.
+///
+/// Each section is concatenated into one curve, and the sections are lofted by
+/// [`BSplineSurface::loft`] with the degree `degree` across the sections. The shell consists of
+/// the faces corresponding to the edges of the sections, and is bounded by the first and the last
+/// sections and, if the sections are open, the curves through the ends of the sections.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // circles with radii 1.0, 1.5 and 2.0 at heights 0.0, 0.5 and 1.0
+/// let sections: Vec<Wire> = [1.0, 1.5, 2.0]
+///     .iter()
+///     .map(|r| {
+///         let v = builder::vertex(Point3::new(*r, 0.0, r - 1.0));
+///         builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI))
+///     })
+///     .collect();
+/// let shell = builder::try_loft(&sections, 2).unwrap();
+/// assert_eq!(shell.len(), sections[0].len());
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// assert_eq!(shell.extract_boundaries().len(), 2);
+/// # assert!(shell.is_geometric_consistent());
+///
+/// // the shell is a cone frustum.
+/// for face in shell.face_iter() {
+///     let surface = face.oriented_surface();
+///     let ((u0, u1), (v0, v1)) = match surface.try_range_tuple() {
+///         (Some(urange), Some(vrange)) => (urange, vrange),
+///         _ => unreachable!(),
+///     };
+///     for i in 0..=10 {
+///         for j in 0..=10 {
+///             let u = u0 + (u1 - u0) * i as f64 / 10.0;
+///             let v = v0 + (v1 - v0) * j as f64 / 10.0;
+///             let pt = surface.subs(u, v);
+///             let radius = f64::sqrt(pt.x * pt.x + pt.y * pt.y);
+///             assert!(f64::abs(radius - (1.0 + pt.z)) < 1.0e-6);
+///         }
+///     }
+/// }
+/// ```
+/// # Failures
+/// - If less than two sections are given, then return `Error::TooFewSections`.
+/// - If the sections have different numbers of edges, then return `Error::NotSameNumberOfEdges`.
+/// - If closed and open sections are mixed, then return `Error::MixedClosedAndOpenSections`.
+/// - If the consecutive sections coincide, then return `Error::CoincidentSections`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let polyline = |z: f64| -> Wire {
+///     let v = builder::vertex(Point3::new(0.0, 0.0, z));
+///     let mut vertices = vec![v.clone()];
+///     vertices.push(builder::vertex(Point3::new(1.0, 0.0, z)));
+///     vertices.push(builder::vertex(Point3::new(1.0, 1.0, z)));
+///     vertices.push(builder::vertex(Point3::new(0.0, 1.0, z)));
+///     vertices.windows(2).map(|v| builder::line(&v[0], &v[1])).collect()
+/// };
+/// let triangle: Wire = {
+///     let v0 = builder::vertex(Point3::new(0.0, 0.0, 2.0));
+///     let v1 = builder::vertex(Point3::new(1.0, 0.0, 2.0));
+///     let v2 = builder::vertex(Point3::new(0.0, 1.0, 2.0));
+///     vec![
+///         builder::line(&v0, &v1),
+///         builder::line(&v1, &v2),
+///         builder::line(&v2, &v0),
+///     ]
+///     .into()
+/// };
+///
+/// assert!(matches!(
+///     builder::try_loft(&[polyline(0.0)], 1),
+///     Err(Error::TooFewSections),
+/// ));
+/// assert!(matches!(
+///     builder::try_loft(&[polyline(0.0), triangle], 1),
+///     Err(Error::MixedClosedAndOpenSections),
+/// ));
+/// let open = polyline(0.0);
+/// assert!(matches!(
+///     builder::try_loft(&[open.clone(), open, polyline(1.0)], 1),
+///     Err(Error::CoincidentSections),
+/// ));
+/// ```
+pub fn try_loft(sections: &[Wire], degree: usize) -> Result<Shell> {
+    if sections.len() < 2 {
+        return Err(Error::TooFewSections);
+    }
+    let len = sections[0].len();
+    if sections.iter().any(|wire| wire.len() != len) {
+        return Err(Error::NotSameNumberOfEdges);
+    }
+    let closed = sections[0].is_closed();
+    if sections.iter().any(|wire| wire.is_closed() != closed) {
+        return Err(Error::MixedClosedAndOpenSections);
+    }
+    if len == 0 {
+        return Ok(Shell::new());
+    }
+
+    // The parameter range of the `i`-th edge is `[i, i + 1]` before lofting, and is normalized
+    // to `[i / len, (i + 1) / len]` by lofting.
+    let curves: Vec<_> = sections
+        .iter()
+        .map(|wire| {
+            let mut curves = wire.edge_iter().enumerate().map(|(i, edge)| {
+                let mut curve = NurbsCurve::new(edge.oriented_curve().lift_up());
+                curve.knot_normalize().knot_translate(i as f64);
+                curve
+            });
+            let first = curves.next().unwrap();
+            let curve = curves.fold(first, |curve0, curve1| curve0.concat(&curve1));
+            curve.into_non_rationalized()
+        })
+        .collect();
+    let mut surface =
+        BSplineSurface::loft(&curves, degree).map_err(|_| Error::CoincidentSections)?;
+    let mut surfaces: Vec<_> = (1..len)
+        .rev()
+        .map(|i| surface.ucut(i as f64 / len as f64))
+        .collect();
+    surfaces.push(surface);
+    surfaces.reverse();
+
+    let (first, last) = (&sections[0], &sections[sections.len() - 1]);
+    let side_edge = |v0: &Vertex, v1: &Vertex, curve: BSplineCurve<Vector4>| {
+        Edge::new(v0, v1, Curve::NurbsCurve(NurbsCurve::new(curve)))
+    };
+    let mut side_edges: Vec<Edge> = first
+        .edge_iter()
+        .zip(last.edge_iter())
+        .zip(&surfaces)
+        .map(|((edge0, edge1), surface)| {
+            side_edge(edge0.front(), edge1.front(), surface.column_curve(0))
+        })
+        .collect();
+    if closed {
+        side_edges.push(side_edges[0].clone());
+    } else {
+        let surface = &surfaces[len - 1];
+        let curve = surface.column_curve(surface.control_points().len() - 1);
+        let (v0, v1) = (first.back_vertex().unwrap(), last.back_vertex().unwrap());
+        side_edges.push(side_edge(v0, v1, curve));
+    }
+
+    let shell = first
+        .edge_iter()
+        .zip(last.edge_iter())
+        .zip(surfaces)
+        .enumerate()
+        .map(|(i, ((edge0, edge1), surface))| {
+            let wire: Wire = vec![
+                edge0.clone(),
+                side_edges[i + 1].clone(),
+                edge1.inverse(),
+                side_edges[i].inverse(),
+            ]
+            .into();
+            Face::new(
+                vec![wire],
+                Surface::NurbsSurface(NurbsSurface::new(surface)),
+            )
+        })
+        .collect();
+    Ok(shell)
+}
+
 /// Creates a cone by R-sweeping.
 /// # Examples
 /// ```
@@ -421,6 +590,326 @@ pub fn try_attach_plane(wires: &[Wire]) -> Result<Face> {
     Ok(Face::try_new(wires.to_owned(), plane.into())?)
 }
 
+/// Try attaching a plane whose outer boundary is `outer` and whose holes are `inners`.
+///
+/// The plane is oriented by `outer`, and the inner wires are inverted if they are oriented in
+/// the same direction as `outer`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// // the square plate with two circular holes
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, 4.0 * Vector3::unit_x());
+/// let plate = builder::tsweep(&e, 2.0 * Vector3::unit_y());
+/// let outer = plate.boundaries()[0].clone();
+/// let inners: Vec<Wire> = [1.0, 3.0]
+///     .into_iter()
+///     .map(|x| {
+///         let v = builder::vertex(Point3::new(x + 0.5, 1.0, 0.0));
+///         builder::rsweep(&v, Point3::new(x, 1.0, 0.0), Vector3::unit_z(), Rad(7.0))
+///     })
+///     .collect();
+/// let face = builder::try_attach_plane_with_holes(&outer, &inners).unwrap();
+/// assert_eq!(face.boundaries().len(), 3);
+/// ```
+/// # Failures
+/// - If `outer` is not closed or not planar, then returns the error of
+/// [`try_attach_plane`](./fn.try_attach_plane.html).
+/// - If an inner wire is not in the plane, then returns `Error::WireNotInOnePlane`.
+/// - If an inner wire crosses the other wires or is not inside `outer`, then returns
+/// `Error::InvalidHoles`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let square = builder::tsweep(&e, Vector3::unit_y());
+/// let outer = square.boundaries()[0].clone();
+/// let v = builder::vertex(Point3::new(1.5, 0.5, 0.0));
+/// let inner = builder::rsweep(&v, Point3::new(1.0, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+/// assert_eq!(
+///     builder::try_attach_plane_with_holes(&outer, &[inner]).unwrap_err(),
+///     Error::InvalidHoles,
+/// );
+/// ```
+pub fn try_attach_plane_with_holes(outer: &Wire, inners: &[Wire]) -> Result<Face> {
+    const DIVISION: usize = 16;
+    let face = try_attach_plane(&[outer.clone()])?;
+    let plane = match face.oriented_surface() {
+        Surface::Plane(plane) => plane,
+        _ => unreachable!(),
+    };
+    let to_polygon = |wire: &Wire| -> Result<Vec<Point2>> {
+        wire.edge_iter()
+            .flat_map(|edge| {
+                let curve = edge.oriented_curve();
+                let (t0, t1) = curve.range_tuple();
+                (0..DIVISION).map(move |i| {
+                    let t = t0 + (t1 - t0) * i as f64 / DIVISION as f64;
+                    curve.subs(t)
+                })
+            })
+            .map(|pt| {
+                let prm = plane.get_parameter(pt);
+                if prm.z.so_small() {
+                    Ok(Point2::new(prm.x, prm.y))
+                } else {
+                    Err(Error::WireNotInOnePlane)
+                }
+            })
+            .collect()
+    };
+    let outer_polygon = to_polygon(outer)?;
+    let mut boundaries = vec![outer.clone()];
+    let mut polygons = Vec::<Vec<Point2>>::new();
+    for inner in inners {
+        if !inner.is_closed() {
+            return Err(truck_topology::errors::Error::NotClosedWire.into());
+        }
+        let mut inner_polygon = to_polygon(inner)?;
+        let inside = geom_impls::inside_polygon(inner_polygon[0], &outer_polygon);
+        if !inside || geom_impls::polygons_cross(&inner_polygon, &outer_polygon) {
+            return Err(Error::InvalidHoles);
+        }
+        let disjoint = polygons.iter().all(|polygon| {
+            !geom_impls::polygons_cross(&inner_polygon, polygon)
+                && !geom_impls::inside_polygon(inner_polygon[0], polygon)
+                && !geom_impls::inside_polygon(polygon[0], &inner_polygon)
+        });
+        if !disjoint {
+            return Err(Error::InvalidHoles);
+        }
+        if geom_impls::polygon_area(&inner_polygon) > 0.0 {
+            boundaries.push(inner.inverse());
+            inner_polygon.reverse();
+        } else {
+            boundaries.push(inner.clone());
+        }
+        polygons.push(inner_polygon);
+    }
+    Ok(Face::try_new(boundaries, plane.into())?)
+}
+
+/// Imprints `wire` on `face`, and returns the two faces split by the imprinted wire.
+///
+/// Each edge of `wire` is projected to the surface of `face` by the nearest points, and the
+/// projection is approximated by a cubic B-spline curve within `tol`. Both ends of `wire` must
+/// be projected to the boundary of `face` within `tol`, where the boundary edges are cut. The
+/// returned faces share the imprinted edges. Since the cut boundary edges are new ones, the
+/// returned faces do not share them with the faces adjacent to `face`. In order to imprint a
+/// face of a shell, use [`imprint_in_shell`](./fn.imprint_in_shell.html).
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let face = builder::tsweep(&e, Vector3::unit_y());
+///
+/// // the line above the face
+/// let v0 = builder::vertex(Point3::new(0.5, 0.0, 1.0));
+/// let v1 = builder::vertex(Point3::new(0.5, 1.0, 1.0));
+/// let wire: Wire = vec![builder::line(&v0, &v1)].into();
+///
+/// let faces = builder::imprint(&face, &wire, 1.0e-3).unwrap();
+/// assert_eq!(faces.len(), 2);
+/// assert_eq!(faces[0].boundaries()[0].len(), 4);
+/// assert_eq!(faces[1].boundaries()[0].len(), 4);
+/// // the faces share the imprinted edge
+/// let shell: Shell = faces.into();
+/// assert_eq!(shell.extract_boundaries()[0].len(), 6);
+/// # assert!(shell.is_geometric_consistent());
+/// ```
+/// # Failures
+/// - If `face` has holes, or `wire` is not an open continuous wire, or the projection cannot be
+/// approximated, then returns `Error::UnsupportedImprint`.
+/// - If an end of `wire` is not projected to the boundary of `face`, then returns
+/// `Error::ImprintNotOnBoundary`.
+pub fn imprint(face: &Face, wire: &Wire, tol: f64) -> Result<Vec<Face>> {
+    if face.boundaries().len() != 1 || wire.is_empty() || !wire.is_continuous() || wire.is_cyclic()
+    {
+        return Err(Error::UnsupportedImprint);
+    }
+    let surface = face.oriented_surface();
+    let mut curves = wire
+        .edge_iter()
+        .map(|edge| projected_curve(&edge.oriented_curve(), &surface, tol))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::UnsupportedImprint)?;
+    let n = curves.len();
+
+    let mut boundary = face.boundaries().pop().unwrap();
+    let front = cut_boundary(&mut boundary, curves[0].front(), tol)?;
+    let back = cut_boundary(&mut boundary, curves[n - 1].back(), tol)?;
+    if front == back {
+        return Err(Error::UnsupportedImprint);
+    }
+    *curves[0].control_point_mut(0) = front.point();
+    let last = curves[n - 1].control_points().len() - 1;
+    *curves[n - 1].control_point_mut(last) = back.point();
+    let mut vertices = vec![front.clone()];
+    vertices.extend(curves[1..].iter().map(|curve| Vertex::new(curve.front())));
+    vertices.push(back.clone());
+    let imprinted: Wire = curves
+        .into_iter()
+        .enumerate()
+        .map(|(i, curve)| Edge::new(&vertices[i], &vertices[i + 1], curve.into()))
+        .collect();
+
+    let start = boundary
+        .iter()
+        .position(|edge| edge.front() == &front)
+        .unwrap();
+    boundary.rotate_left(start);
+    let middle = boundary
+        .iter()
+        .position(|edge| edge.front() == &back)
+        .unwrap();
+    let mut wire0 = boundary;
+    let mut wire1 = wire0.split_off(middle);
+    wire0.append(&mut imprinted.inverse());
+    wire1.append(&mut imprinted.clone());
+    let face0 = Face::try_new(vec![wire0], surface.clone())?;
+    let face1 = Face::try_new(vec![wire1], surface)?;
+    Ok(vec![face0, face1])
+}
+
+/// Imprints `wire` on the face of `shell` with `face_id` by [`imprint`](./fn.imprint.html), and
+/// returns the two faces split by the imprinted wire.
+///
+/// The boundary edges at the ends of `wire` are split in all the faces of `shell`, like
+/// [`Shell::split_edge`](truck_topology::Shell::split_edge), so that the returned faces share
+/// them with the adjacent faces. The face is replaced by the first returned face, and the second
+/// one is pushed to the back of `shell`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+/// let mut shell = cube.into_boundaries().pop().unwrap();
+/// let top = shell
+///     .face_iter()
+///     .find(|face| face.vertex_iter().all(|v| v.point().z == 1.0))
+///     .unwrap()
+///     .id();
+///
+/// // the line above the top face
+/// let v0 = builder::vertex(Point3::new(0.5, 0.0, 2.0));
+/// let v1 = builder::vertex(Point3::new(0.5, 1.0, 2.0));
+/// let wire: Wire = vec![builder::line(&v0, &v1)].into();
+///
+/// let faces = builder::imprint_in_shell(&mut shell, top, &wire, 1.0e-3).unwrap();
+/// assert_eq!(faces.len(), 2);
+/// assert_eq!(shell.len(), 7);
+/// // The side faces share the split edges, so the shell is still closed.
+/// assert!(shell.extract_boundaries().is_empty());
+/// # assert!(shell.is_geometric_consistent());
+/// ```
+/// # Failures
+/// Returns the error and does not edit `shell` if:
+/// - there is no face with `face_id` in `shell`, then returns
+/// `Error::FromTopology(truck_topology::errors::Error::FaceNotFound)`, or
+/// - [`imprint`](./fn.imprint.html) fails, then returns its error.
+pub fn imprint_in_shell(
+    shell: &mut Shell,
+    face_id: FaceID,
+    wire: &Wire,
+    tol: f64,
+) -> Result<Vec<Face>> {
+    let idx = shell
+        .face_iter()
+        .position(|face| face.id() == face_id)
+        .ok_or(truck_topology::errors::Error::FaceNotFound)?;
+    let surface = shell[idx].oriented_surface();
+    let ends = [wire.front_vertex(), wire.back_vertex()]
+        .into_iter()
+        .map(|v| {
+            let pt = v.ok_or(Error::UnsupportedImprint)?.point();
+            let (u, v) = surface
+                .search_nearest_parameter(pt, None, 100)
+                .ok_or(Error::UnsupportedImprint)?;
+            Ok(surface.subs(u, v))
+        })
+        .collect::<Result<Vec<Point3>>>()?;
+
+    // The edges are split in the copy, which replaces `shell` only if the imprint succeeds.
+    let mut new_shell = shell.clone();
+    for pt in ends {
+        split_boundary(&mut new_shell, idx, pt, tol)?;
+    }
+    let faces = imprint(&new_shell[idx], wire, tol)?;
+    new_shell[idx] = faces[0].clone();
+    new_shell.push(faces[1].clone());
+    *shell = new_shell;
+    Ok(faces)
+}
+
+/// Creates a face bounded by the four-sided wire `wire` with the Coons patch.
+///
+/// The edges of `wire` are the boundaries at `v = 0`, `u = 1`, `v = 1` and `u = 0` of the
+/// surface in order. cf. [`BSplineSurface::coons_patch`]
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(1.0, 1.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(0.0, 1.0, 1.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::circle_arc(&v1, &v2, Point3::new(1.5, 0.5, 0.0)),
+///     builder::bezier(&v2, &v3, vec![Point3::new(0.5, 1.5, 0.5)]),
+///     builder::line(&v3, &v0),
+/// ]
+/// .into();
+/// let face = builder::try_coons_face(&wire).unwrap();
+/// assert_eq!(face.boundaries()[0], wire);
+/// # assert!(Shell::from(vec![face]).is_geometric_consistent());
+/// ```
+/// # Failures
+/// - If `wire` is not closed or does not consist of four edges, then return
+/// `Error::NotQuadrilateralWire`.
+/// - If the end weights of rational curves do not coincide at a corner, then return
+/// `Error::MismatchedCornerWeights`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v0),
+/// ]
+/// .into();
+/// assert!(matches!(
+///     builder::try_coons_face(&wire),
+///     Err(Error::NotQuadrilateralWire),
+/// ));
+/// ```
+pub fn try_coons_face(wire: &Wire) -> Result<Face> {
+    if wire.len() != 4 || !wire.is_closed() {
+        return Err(Error::NotQuadrilateralWire);
+    }
+    // The weights at the fronts are normalized to 1.
+    let mut curves = wire.edge_iter().map(|edge| {
+        let mut curve = edge.oriented_curve().lift_up();
+        let weight = curve.front().weight();
+        curve.transform_control_points(|pt| *pt /= weight);
+        curve
+    });
+    let c0 = curves.next().unwrap();
+    let d1 = curves.next().unwrap();
+    let mut c1 = curves.next().unwrap();
+    let mut d0 = curves.next().unwrap();
+    c1.invert();
+    d0.invert();
+    let surface = BSplineSurface::coons_patch(&c0, &c1, &d0, &d1)
+        .map_err(|_| Error::MismatchedCornerWeights)?;
+    let surface = Surface::NurbsSurface(NurbsSurface::new(surface));
+    Ok(Face::try_new(vec![wire.clone()], surface)?)
+}
+
 /// Returns another topology whose points, curves, and surfaces are cloned.
 #[inline(always)]
 pub fn clone<T: Mapped<Point3, Curve, Surface>>(elem: &T) -> T { elem.topological_clone() }
@@ -464,6 +953,122 @@ pub fn scaled<T: Mapped<Point3, Curve, Surface>>(elem: &T, origin: Point3, scala
     transformed(elem, mat2 * mat1 * mat0)
 }
 
+/// Returns a vertex, edge, wire, face, shell or solid reflected by the plane through `origin`
+/// with the normal `normal`.
+///
+/// The orientations of the faces are inverted, so the reflected solid is also oriented outward.
+/// # Panics
+/// Panics if `normal` is the zero vector.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let mirrored = builder::mirrored(&cube, Point3::new(0.0, 0.0, 2.0), Vector3::unit_z());
+/// assert!(mirrored.is_geometric_consistent());
+/// let face = mirrored
+///     .face_iter()
+///     .find(|face| face.vertex_iter().all(|v| v.point().z == 4.0))
+///     .unwrap();
+/// // the normal of the top face is directed to the outside
+/// let normal = face.oriented_surface().normal(0.5, 0.5);
+/// assert_near!(normal, Vector3::unit_z());
+/// ```
+pub fn mirrored<T: Mapped<Point3, Curve, Surface> + mapped::InvertFaces>(
+    elem: &T,
+    origin: Point3,
+    normal: Vector3,
+) -> T {
+    assert!(
+        !normal.so_small(),
+        "the normal of the mirror plane is the zero vector."
+    );
+    let n = normal.normalize();
+    let reflection = Matrix3::from_cols(
+        Vector3::unit_x() - 2.0 * n.x * n,
+        Vector3::unit_y() - 2.0 * n.y * n,
+        Vector3::unit_z() - 2.0 * n.z * n,
+    );
+    let mat0 = Matrix4::from_translation(-origin.to_vec());
+    let mat1 = Matrix4::from(reflection);
+    let mat2 = Matrix4::from_translation(origin.to_vec());
+    let mut res = transformed(elem, mat2 * mat1 * mat0);
+    res.invert_faces();
+    res
+}
+
+/// Returns `count` copies of a vertex, edge, wire, face, shell or solid arranged along `dir`
+/// at intervals of `spacing`.
+///
+/// The first copy is at the original position. Each copy has its own curves and surfaces,
+/// transformed by [`Transformed`], so the copies can be attached to different solids. The
+/// geometries wrapped by [`Processor`], e.g. the surfaces of revolution, keep their entities and
+/// only compose the transformations.
+/// # Panics
+/// Panics if `dir` is the zero vector.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let vertices = builder::linear_pattern(&v, Vector3::new(0.0, 2.0, 0.0), 0.5, 4);
+/// assert_eq!(vertices.len(), 4);
+/// assert_near!(vertices[3].point(), Point3::new(1.0, 1.5, 0.0));
+/// ```
+pub fn linear_pattern<T: Mapped<Point3, Curve, Surface>>(
+    elem: &T,
+    dir: Vector3,
+    spacing: f64,
+    count: usize,
+) -> Vec<T> {
+    assert!(
+        !dir.so_small(),
+        "the direction of the pattern is the zero vector."
+    );
+    let dir = dir.normalize();
+    (0..count)
+        .map(|i| translated(elem, dir * spacing * i as f64))
+        .collect()
+}
+
+/// Returns `count` copies of a vertex, edge, wire, face, shell or solid arranged at equal
+/// angles around the axis through `origin`.
+///
+/// The first copy is at the original position. Each copy has its own curves and surfaces,
+/// transformed by [`Transformed`], so the copies can be attached to different solids. The
+/// geometries wrapped by [`Processor`], e.g. the surfaces of revolution, keep their entities and
+/// only compose the transformations.
+/// # Panics
+/// Panics if `axis` is the zero vector.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_z());
+/// let edges = builder::circular_pattern(&e, Point3::origin(), Vector3::unit_z(), 6);
+/// assert_eq!(edges.len(), 6);
+/// let p = Point3::new(f64::cos(PI / 3.0), f64::sin(PI / 3.0), 0.0);
+/// assert_near!(edges[1].front().point(), p);
+/// ```
+pub fn circular_pattern<T: Mapped<Point3, Curve, Surface>>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    count: usize,
+) -> Vec<T> {
+    assert!(
+        !axis.so_small(),
+        "the axis of the pattern is the zero vector."
+    );
+    let axis = axis.normalize();
+    (0..count)
+        .map(|i| rotated(elem, origin, axis, PI * 2.0 * i as f64 / count as f64))
+        .collect()
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by a vector.
 /// # Examples
 /// ```
@@ -639,6 +1244,1784 @@ pub fn rsweep<T: ClosedSweep<Point3, Curve, Surface>, R: Into<Rad<f64>>>(
     }
 }
 
+/// Creates a solid by revolving the planar closed wire `profile` around the axis from the angle
+/// `angle_range.0` to `angle_range.1`.
+///
+/// The profile is rotated by `angle_range.0` and swept by the difference of the angles. If the
+/// difference is less than `2π`, the planar faces closing both ends are attached, otherwise
+/// the profile is revolved wholly. The orientation of the solid does not depend on the one of
+/// `profile`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_z());
+/// let profile = f.boundaries()[0].clone();
+///
+/// let solid = builder::try_partial_rsweep(
+///     &profile,
+///     Point3::origin(),
+///     Vector3::unit_z(),
+///     (Rad(PI / 4.0), Rad(7.0 * PI / 4.0)),
+/// )
+/// .unwrap();
+/// let shell = &solid.boundaries()[0];
+/// assert_eq!(shell.len(), 4 * 3 + 2);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # assert!(solid.is_geometric_consistent());
+///
+/// // the profile is revolved wholly if the angle is greater than `2π`.
+/// let solid = builder::try_partial_rsweep(
+///     &profile,
+///     Point3::origin(),
+///     Vector3::unit_z(),
+///     (Rad(0.0), Rad(7.0)),
+/// )
+/// .unwrap();
+/// assert_eq!(solid.boundaries()[0].len(), 4 * 3);
+/// # assert!(solid.is_geometric_consistent());
+/// ```
+/// # Failures
+/// If `profile` is not closed or not planar, then returns the error of
+/// [`try_attach_plane`](./fn.try_attach_plane.html).
+pub fn try_partial_rsweep<R: Into<Rad<f64>>>(
+    profile: &Wire,
+    origin: Point3,
+    axis: Vector3,
+    angle_range: (R, R),
+) -> Result<Solid> {
+    let (start, end) = (angle_range.0.into(), angle_range.1.into());
+    let mut face = try_attach_plane(&[profile.clone()])?;
+    // The normal of the face is directed to the sweeping direction.
+    let len = profile.len() as f64;
+    let center = profile
+        .vertex_iter()
+        .fold(Point3::origin(), |sum, v| sum + v.point().to_vec() / len);
+    let direction = axis.cross(center - origin) * f64::signum((end - start).0);
+    if face.oriented_surface().normal(0.0, 0.0).dot(direction) < 0.0 {
+        face.invert();
+    }
+    let face = rotated(&face, origin, axis, start);
+    Ok(rsweep(&face, origin, axis, end - start))
+}
+
+/// Creates a shell by sweeping the wire `profile` along the helix around the axis.
+///
+/// The profile moves by the screw motion, which rotates `turns` times around the axis through
+/// `origin` with the direction `axis` and advances `pitch` along the axis per turn. Since the
+/// Frenet frame of a helix moves by the same screw motion, the profile is fixed relative to the
+/// frame. The faces are represented by [`HelicalSurface`], and the edges drawn by the vertices are
+/// approximated by cubic B-spline curves within `tol`. If `caps` is `true`, the planar faces at
+/// both ends are also attached, so that a closed profile produces a closed shell.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // the circle with radius 0.5 centered at (2.0, 0.0, 0.0) on the xz-plane
+/// let v = builder::vertex(Point3::new(2.5, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(2.0 * PI));
+///
+/// // spring with 3 turns
+/// let spring = builder::try_helical_sweep(
+///     &circle,
+///     Point3::origin(),
+///     Vector3::unit_z(),
+///     1.5,
+///     3.0,
+///     TOLERANCE,
+///     true,
+/// )
+/// .unwrap();
+/// assert_eq!(spring.len(), circle.len() + 2);
+/// assert_eq!(spring.shell_condition(), ShellCondition::Closed);
+/// # assert!(spring.is_geometric_consistent());
+///
+/// // the end of the spring
+/// let end = spring.last().unwrap().boundaries()[0].clone();
+/// end.vertex_iter().zip(circle.vertex_iter()).for_each(|(v0, v1)| {
+///     assert_near!(v0.point(), v1.point() + 4.5 * Vector3::unit_z());
+/// });
+/// ```
+/// # Failures
+/// - If the helices drawn by the vertices cannot be interpolated, then returns
+/// [`Error::InterpolationFailed`].
+/// - If `caps` is `true` and `profile` is not closed or not planar, then returns the error of
+/// [`try_attach_plane`](./fn.try_attach_plane.html).
+pub fn try_helical_sweep(
+    profile: &Wire,
+    origin: Point3,
+    axis: Vector3,
+    pitch: f64,
+    turns: f64,
+    tol: f64,
+    caps: bool,
+) -> Result<Shell> {
+    // The negative rotation is the positive one around the opposite axis.
+    let (axis, turns) = if turns < 0.0 {
+        (-axis.normalize(), -turns)
+    } else {
+        (axis.normalize(), turns)
+    };
+    let angle = 2.0 * PI.0 * turns;
+    let screw = HelicalSurface::by_helix((), origin, axis, pitch, angle);
+    let end_profile = transformed(profile, screw.screw_matrix(angle));
+
+    let helix = move |pt: Point3| {
+        // the error of the cubic interpolation is bounded by `5 / 384 * radius * delta^4`.
+        let radius = (pt - origin).cross(axis).magnitude();
+        let delta = f64::powf(384.0 * tol / (50.0 * radius), 0.25);
+        let division = 1 + (angle / f64::min(delta, PI.0 / 8.0)).ceil() as usize;
+        let points: Vec<Point3> = (0..=division)
+            .map(|i| {
+                let v = angle * i as f64 / division as f64;
+                screw.screw_matrix(v).transform_point(pt)
+            })
+            .collect();
+        // the derivations by the normalized parameter
+        let der = |q: Point3| (axis.cross(q - origin) + axis * (pitch / (2.0 * PI.0))) * angle;
+        let ends = EndCondition::Clamped(der(points[0]), der(points[division]));
+        BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, ends)
+            .map(Curve::BSplineCurve)
+            .map_err(|_| Error::InterpolationFailed)
+    };
+    let helices = profile
+        .vertex_iter()
+        .map(|v| Ok((v.id(), helix(v.point())?)))
+        .collect::<Result<HashMap<_, _>>>()?;
+    let mut vemap = truck_base::entry_map::FxEntryMap::new(
+        |(v0, v1): (&Vertex, &Vertex)| (v0.id(), v1.id()),
+        |(v0, v1)| Edge::new(v0, v1, helices[&v0.id()].clone()),
+    );
+    let mut shell: Shell = profile
+        .edge_iter()
+        .zip(end_profile.edge_iter())
+        .map(|(edge0, edge1)| {
+            let side0 = vemap.entry_or_insert((edge0.front(), edge1.front()));
+            let side0 = side0.inverse();
+            let side1 = vemap.entry_or_insert((edge0.back(), edge1.back())).clone();
+            let wire: Wire = vec![edge0.clone(), side1, edge1.inverse(), side0].into();
+            let curve = edge0.oriented_curve();
+            let surface = HelicalSurface::by_helix(curve, origin, axis, pitch, angle);
+            Face::new(vec![wire], Surface::HelicalSurface(Processor::new(surface)))
+        })
+        .collect();
+    if caps {
+        shell.push(try_attach_plane(&[profile.inverse()])?);
+        shell.push(try_attach_plane(&[end_profile])?);
+    }
+    Ok(shell)
+}
+
+/// Creates a shell by sweeping the wire `profile` along the curve `path`.
+///
+/// The profile is placed at the start point `path.subs(range.0)` and is moved along the path
+/// over the parameter range `range` by the rotation minimizing frames, computed by the double
+/// reflection method, so the profile does not twist around the path even at the inflection
+/// points of the path. The faces are represented by NURBS surfaces whose control points are
+/// interpolated in the sweeping direction, and the sampling of the path is refined until the
+/// error from the exactly swept profile is less than `tol`. If `caps` is `true`, the planar
+/// faces at both ends are also attached, so that a closed profile produces a closed shell.
+///
+/// The path must be smooth. Sharp corners, e.g. the ones of polylines, are not mitered, and
+/// are reported as errors.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // the circle with radius 0.25 centered at (1.0, 0.0, 0.0) on the xz-plane
+/// let v = builder::vertex(Point3::new(1.25, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::new(1.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+///
+/// // a quadrant of the torus along the quarter of the unit circle
+/// let path = UnitCircle::<Point3>::new();
+/// let tol = 1.0e-3;
+/// let pipe = builder::try_path_sweep(&circle, &path, (0.0, PI / 2.0), tol, true).unwrap();
+/// assert_eq!(pipe.len(), circle.len() + 2);
+/// assert_eq!(pipe.shell_condition(), ShellCondition::Closed);
+/// # assert!(pipe.is_geometric_consistent());
+/// for face in &pipe[..circle.len()] {
+///     let surface = face.oriented_surface();
+///     for i in 0..=10 {
+///         for j in 0..=10 {
+///             let p = surface.subs(i as f64 / 10.0, j as f64 / 10.0);
+///             let radius = Vector2::new(p.x, p.y).magnitude();
+///             let dist = Vector2::new(radius - 1.0, p.z).magnitude();
+///             assert!(f64::abs(dist - 0.25) < tol);
+///         }
+///     }
+/// }
+///
+/// // the polyline is not smooth at the corner
+/// let polyline = BSplineCurve::new(
+///     KnotVec::from(vec![0.0, 0.0, 1.0, 2.0, 2.0]),
+///     vec![
+///         Point3::new(1.0, 0.0, 0.0),
+///         Point3::new(1.0, 1.0, 0.0),
+///         Point3::new(0.0, 1.0, 0.0),
+///     ],
+/// );
+/// assert_eq!(
+///     builder::try_path_sweep(&circle, &polyline, (0.0, 2.0), tol, false),
+///     Err(errors::Error::NonSmoothPath),
+/// );
+/// ```
+/// # Failures
+/// - If the tangent of `path` vanishes or turns sharply, returns [`Error::NonSmoothPath`].
+/// - If the trajectories of the control points cannot be interpolated, then returns
+/// [`Error::InterpolationFailed`].
+/// - If `caps` is `true` and `profile` is not closed or not planar, then returns the error of
+/// [`try_attach_plane`](./fn.try_attach_plane.html).
+pub fn try_path_sweep<C: ParametricCurve3D>(
+    profile: &Wire,
+    path: &C,
+    (t0, t1): (f64, f64),
+    tol: f64,
+    caps: bool,
+) -> Result<Shell> {
+    const MAX_DIVISION: usize = 1024;
+    let curves: Vec<BSplineCurve<Vector4>> = profile
+        .edge_iter()
+        .map(|edge| {
+            let mut curve = edge.oriented_curve().lift_up();
+            curve.knot_normalize();
+            curve
+        })
+        .collect();
+
+    // The even samples are interpolated, and the odd ones are used to estimate the errors.
+    let mut division = 4;
+    let (matrices, surfaces) = loop {
+        let samples = (0..=2 * division)
+            .map(|i| {
+                let t = t0 + (t1 - t0) * i as f64 / (2 * division) as f64;
+                let der = path.der(t) * (t1 - t0);
+                if der.so_small() {
+                    Err(Error::NonSmoothPath)
+                } else {
+                    Ok((path.subs(t), der.normalize()))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let smooth = samples
+            .windows(2)
+            .all(|pair| pair[0].1.dot(pair[1].1) > f64::cos(PI.0 / 8.0));
+        if smooth {
+            let matrices = rotation_minimizing_matrices(&samples);
+            let (surfaces, errors): (Vec<_>, Vec<_>) = curves
+                .iter()
+                .map(|curve| path_swept_surface(curve, &matrices))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            if errors.into_iter().all(|error| error < tol) {
+                break (matrices, surfaces);
+            }
+        }
+        if division >= MAX_DIVISION {
+            return Err(Error::NonSmoothPath);
+        }
+        division *= 2;
+    };
+    let end_profile = transformed(profile, matrices[2 * division]);
+
+    let trajectory = |pt: Point3| {
+        let points: Vec<Point3> = matrices
+            .iter()
+            .step_by(2)
+            .map(|mat| mat.transform_point(pt))
+            .collect();
+        BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, EndCondition::Natural)
+            .map(Curve::BSplineCurve)
+            .map_err(|_| Error::InterpolationFailed)
+    };
+    let trajectories = profile
+        .vertex_iter()
+        .map(|v| Ok((v.id(), trajectory(v.point())?)))
+        .collect::<Result<HashMap<_, _>>>()?;
+    let mut vemap = truck_base::entry_map::FxEntryMap::new(
+        |(v0, v1): (&Vertex, &Vertex)| (v0.id(), v1.id()),
+        |(v0, v1)| Edge::new(v0, v1, trajectories[&v0.id()].clone()),
+    );
+    let mut shell: Shell = profile
+        .edge_iter()
+        .zip(end_profile.edge_iter())
+        .zip(surfaces)
+        .map(|((edge0, edge1), surface)| {
+            let side0 = vemap.entry_or_insert((edge0.front(), edge1.front()));
+            let side0 = side0.inverse();
+            let side1 = vemap.entry_or_insert((edge0.back(), edge1.back())).clone();
+            let wire: Wire = vec![edge0.clone(), side1, edge1.inverse(), side0].into();
+            Face::new(
+                vec![wire],
+                Surface::NurbsSurface(NurbsSurface::new(surface)),
+            )
+        })
+        .collect();
+    if caps {
+        shell.push(try_attach_plane(&[profile.inverse()])?);
+        shell.push(try_attach_plane(&[end_profile])?);
+    }
+    Ok(shell)
+}
+
+/// Returns the transformations from the first sample to the others by the rotation minimizing
+/// frames. The samples are the pairs of the points and the unit tangent vectors.
+fn rotation_minimizing_matrices(samples: &[(Point3, Vector3)]) -> Vec<Matrix4> {
+    let (mut x, mut t) = samples[0];
+    let r = if t[0].abs() < 0.5 {
+        Vector3::unit_x()
+    } else {
+        Vector3::unit_y()
+    };
+    let mut r = (r - t * t.dot(r)).normalize();
+    let frame = Matrix3::from_cols(t, r, t.cross(r));
+    let inverse = Matrix4::from(frame.transpose()) * Matrix4::from_translation(-x.to_vec());
+    let mut matrices = vec![Matrix4::identity()];
+    for (x1, t1) in &samples[1..] {
+        // the reflection by the bisecting plane of the points
+        let v1 = *x1 - x;
+        let c1 = v1.dot(v1);
+        let r_l = r - v1 * (2.0 * v1.dot(r) / c1);
+        let t_l = t - v1 * (2.0 * v1.dot(t) / c1);
+        // the reflection which sends the reflected tangent to the next one
+        let v2 = *t1 - t_l;
+        r = if v2.so_small() {
+            r_l
+        } else {
+            r_l - v2 * (2.0 * v2.dot(r_l) / v2.dot(v2))
+        };
+        x = *x1;
+        t = *t1;
+        let frame = Matrix3::from_cols(t, r, t.cross(r));
+        matrices.push(Matrix4::from_translation(x.to_vec()) * Matrix4::from(frame) * inverse);
+    }
+    matrices
+}
+
+/// Returns the surface interpolating `curve` transformed by the even matrices, and the maximum
+/// distance between the control points at the odd matrices.
+fn path_swept_surface(
+    curve: &BSplineCurve<Vector4>,
+    matrices: &[Matrix4],
+) -> Result<(BSplineSurface<Vector4>, f64)> {
+    let (division, mut error) = (matrices.len() - 1, 0.0);
+    let columns = curve
+        .control_points()
+        .iter()
+        .map(|pt| {
+            let points: Vec<Vector4> = matrices.iter().step_by(2).map(|mat| *mat * *pt).collect();
+            let column =
+                BSplineCurve::interpolate(&points, 3, ParamMethod::Uniform, EndCondition::Natural)
+                    .map_err(|_| Error::InterpolationFailed)?;
+            for (i, mat) in matrices.iter().enumerate().skip(1).step_by(2) {
+                let exact = (*mat * *pt).to_point();
+                let approx = column.subs(i as f64 / division as f64).to_point();
+                error = f64::max(error, exact.distance(approx));
+            }
+            Ok(column)
+        })
+        .collect::<Result<Vec<BSplineCurve<Vector4>>>>()?;
+    let knot_vecs = (curve.knot_vec().clone(), columns[0].knot_vec().clone());
+    let control_points = columns
+        .into_iter()
+        .map(|column| column.control_points().clone())
+        .collect();
+    Ok((BSplineSurface::new(knot_vecs, control_points), error))
+}
+
+/// Returns the solid whose `edges` are rounded by the fillets with `radius`.
+///
+/// Each edge is replaced by the surface swept by the ball rolling on the two faces adjacent to
+/// the edge, and the adjacent faces and edges are trimmed. Now, only the edges satisfying the
+/// following conditions are supported.
+/// - The edge is a line between two planes, or a circle between a plane and a cylinder
+/// perpendicular to the plane, with a convex dihedral angle. The fillet is a part of a cylinder
+/// or a torus respectively.
+/// - Each end of the edge is one of the following vertices.
+///     - the corner of three faces, where the third face is a plane perpendicular to the edge.
+///     - the vertex at which another filleted edge continues the fillet smoothly, e.g. the
+///     vertex between two arcs of a circle.
+///     - the corner of three planes whose three edges are filleted, which is rounded by a sphere.
+///
+/// The normals of the planes are compared with the tolerance `tol`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the vertical edge through (1.0, 1.0, 0.0)
+/// let edge = cube
+///     .edge_iter()
+///     .find(|edge| {
+///         let (p, q) = (edge.front().point(), edge.back().point());
+///         p.x == 1.0 && p.y == 1.0 && q.x == 1.0 && q.y == 1.0
+///     })
+///     .unwrap();
+/// let filleted = builder::try_fillet(&cube, &[edge.id()], 0.25, TOLERANCE).unwrap();
+/// let shell = &filleted.boundaries()[0];
+/// assert_eq!(shell.len(), 7);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # assert!(filleted.is_geometric_consistent());
+///
+/// // the fillet touches the faces at the distance 0.25 from the edge.
+/// let vertices: Vec<Point3> = shell[6].vertex_iter().map(|v| v.point()).collect();
+/// assert!(vertices.iter().any(|p| p.near(&Point3::new(0.75, 1.0, 0.0))));
+/// assert!(vertices.iter().any(|p| p.near(&Point3::new(1.0, 0.75, 0.0))));
+///
+/// // the three edges at the corner (1.0, 1.0, 1.0) are rounded with a sphere.
+/// let corner = Point3::new(1.0, 1.0, 1.0);
+/// let edges: Vec<EdgeID> = cube
+///     .edge_iter()
+///     .filter(|edge| edge.front().point() == corner || edge.back().point() == corner)
+///     .map(|edge| edge.id())
+///     .collect();
+/// let rounded = builder::try_fillet(&cube, &edges, 0.25, TOLERANCE).unwrap();
+/// let shell = &rounded.boundaries()[0];
+/// assert_eq!(shell.len(), 10);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// ```
+/// # Failures
+/// - If some edge is not contained in `solid`, then return `Error::EdgeNotInSolid`.
+/// - If some edge does not satisfy the above conditions, then return `Error::UnsupportedFillet`.
+/// - If `radius` is too large for the adjacent edges, then return
+/// `Error::TooLargeFilletRadius`.
+pub fn try_fillet(solid: &Solid, edges: &[EdgeID], radius: f64, tol: f64) -> Result<Solid> {
+    let filleted: HashSet<EdgeID> = edges.iter().copied().collect();
+    for edge_id in &filleted {
+        if !solid.edge_iter().any(|edge| edge.id() == *edge_id) {
+            return Err(Error::EdgeNotInSolid);
+        }
+    }
+    let boundaries = solid
+        .boundaries()
+        .iter()
+        .map(|shell| fillet_shell(shell, &filleted, radius, tol))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Solid::new(boundaries))
+}
+
+/// Returns the arc from `v0` to `v1` through `transit`, which is shared with the arc from `v1`
+/// to `v0`.
+fn shared_arc(
+    arcs: &mut HashMap<(VertexID, VertexID), Edge>,
+    v0: &Vertex,
+    v1: &Vertex,
+    transit: Point3,
+) -> Edge {
+    match arcs.get(&(v1.id(), v0.id())) {
+        Some(edge) => edge.inverse(),
+        None => arcs
+            .entry((v0.id(), v1.id()))
+            .or_insert_with(|| circle_arc(v0, v1, transit))
+            .clone(),
+    }
+}
+
+/// Returns the arc from `v0` to `v1` registered by `shared_arc`.
+fn registered_arc(
+    arcs: &HashMap<(VertexID, VertexID), Edge>,
+    v0: &Vertex,
+    v1: &Vertex,
+) -> Option<Edge> {
+    match arcs.get(&(v0.id(), v1.id())) {
+        Some(edge) => Some(edge.clone()),
+        None => arcs.get(&(v1.id(), v0.id())).map(|edge| edge.inverse()),
+    }
+}
+
+/// The fillet of `edge`, on whose left side the face `faces.0` spreads.
+struct Fillet {
+    edge: Edge,
+    faces: (usize, usize),
+    surfaces: (OffsetSurface, OffsetSurface),
+    /// the origin and the direction of the axis of the torus, or `None` for the cylinder
+    axis: Option<(Point3, Vector3)>,
+}
+
+impl Fillet {
+    /// Returns the front or back vertex, and the tangent of the edge directed outward there.
+    fn end(&self, back: bool) -> (&Vertex, Vector3) {
+        let curve = self.edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        match back {
+            false => (self.edge.front(), -curve.der(t0).normalize()),
+            true => (self.edge.back(), curve.der(t1).normalize()),
+        }
+    }
+
+    /// the outward normals of the faces at `pt`
+    fn normals(&self, pt: Point3) -> (Vector3, Vector3) {
+        (self.surfaces.0.gradient(pt), self.surfaces.1.gradient(pt))
+    }
+
+    /// the center of the ball touching the faces around the point `pt` of the edge
+    fn center(&self, pt: Point3, radius: f64) -> Point3 {
+        let (n0, n1) = self.normals(pt);
+        pt - (n0 + n1) * (radius / (1.0 + n0.dot(n1)))
+    }
+
+    /// Returns the curve along the edge on which the ball touches a face, from `v0` to `v1`.
+    fn contact_curve(&self, v0: &Vertex, v1: &Vertex, tol: f64) -> Result<Edge> {
+        let Some((origin, axis)) = self.axis else {
+            let dir = self.edge.back().point() - self.edge.front().point();
+            return match (v1.point() - v0.point()).dot(dir.normalize()) < tol {
+                true => Err(Error::TooLargeFilletRadius),
+                false => Ok(line(v0, v1)),
+            };
+        };
+        // The circle of the edge is scaled around the axis and moved along the axis.
+        let height = |pt: Point3| axis.dot(pt - origin);
+        let distance = |pt: Point3| (pt - origin - axis * height(pt)).magnitude();
+        let (pt, contact) = (self.edge.front().point(), v0.point());
+        if distance(contact) < tol {
+            return Err(Error::TooLargeFilletRadius);
+        }
+        let scale = distance(contact) / distance(pt);
+        let projection = Matrix3::from_cols(axis * axis.x, axis * axis.y, axis * axis.z);
+        let mat0 = Matrix4::from_translation(-origin.to_vec());
+        let mat1 = Matrix4::from(projection + (Matrix3::identity() - projection) * scale);
+        let shift = axis * (height(contact) - height(pt));
+        let mat2 = Matrix4::from_translation(origin.to_vec() + shift);
+        let curve = self.edge.oriented_curve().transformed(mat2 * mat1 * mat0);
+        Ok(Edge::new(v0, v1, curve))
+    }
+}
+
+fn fillet_shell(shell: &Shell, filleted: &HashSet<EdgeID>, radius: f64, tol: f64) -> Result<Shell> {
+    let faces: Vec<&Face> = shell.face_iter().collect();
+    let mut edge_faces = HashMap::<EdgeID, Vec<usize>>::default();
+    for (i, face) in faces.iter().enumerate() {
+        for edge in face.edge_iter() {
+            edge_faces.entry(edge.id()).or_default().push(i);
+        }
+    }
+    let offset_surface =
+        |i: usize| OffsetSurface::new(&faces[i].oriented_surface()).ok_or(Error::UnsupportedFillet);
+    let plane_normal = |i: usize| match offset_surface(i)? {
+        OffsetSurface::Plane { normal, .. } => Ok(normal),
+        _ => Err(Error::UnsupportedFillet),
+    };
+
+    let mut fillets = Vec::<Fillet>::new();
+    let mut done = HashSet::<EdgeID>::default();
+    let filleted_edges = shell
+        .edge_iter()
+        .filter(|edge| filleted.contains(&edge.id()));
+    for edge in filleted_edges.filter(|edge| done.insert(edge.id())) {
+        let (i, j) = match edge_faces[&edge.id()][..] {
+            [i, j] if i != j => (i, j),
+            _ => return Err(Error::UnsupportedFillet),
+        };
+        let edge = faces[i].edge_iter().find(|e| e.id() == edge.id()).unwrap();
+        let surfaces = (offset_surface(i)?, offset_surface(j)?);
+        let axis = match surfaces {
+            (OffsetSurface::Plane { .. }, OffsetSurface::Plane { .. }) => match edge.curve() {
+                Curve::Line(_) => None,
+                _ => return Err(Error::UnsupportedFillet),
+            },
+            (OffsetSurface::Plane { normal, .. }, OffsetSurface::Cylinder { origin, axis, .. })
+            | (OffsetSurface::Cylinder { origin, axis, .. }, OffsetSurface::Plane { normal, .. })
+                if normal.cross(axis).magnitude() < tol =>
+            {
+                Some((origin, axis))
+            }
+            _ => return Err(Error::UnsupportedFillet),
+        };
+        fillets.push(Fillet {
+            edge,
+            faces: (i, j),
+            surfaces,
+            axis,
+        });
+    }
+    if fillets.is_empty() {
+        return Ok(shell.clone());
+    }
+
+    // the ends of the fillets at the vertices
+    let mut touched = Vec::<Vertex>::new();
+    let mut ends = HashMap::<VertexID, Vec<(usize, bool)>>::default();
+    for (k, fillet) in fillets.iter().enumerate() {
+        for back in [false, true] {
+            let (vertex, tangent) = fillet.end(back);
+            // `faces.0` spreads toward `n0.cross(dir)` from the edge.
+            let (n0, n1) = fillet.normals(vertex.point());
+            let dir = if back { tangent } else { -tangent };
+            if n0.cross(dir).dot(n1) > -tol {
+                return Err(Error::UnsupportedFillet);
+            }
+            if !touched.contains(vertex) {
+                touched.push(vertex.clone());
+            }
+            ends.entry(vertex.id()).or_default().push((k, back));
+        }
+    }
+
+    // the centers of the balls at the ends of the fillets
+    let mut centers = HashMap::<(usize, bool), Point3>::default();
+    for vertex in &touched {
+        let list = &ends[&vertex.id()];
+        let around: Vec<usize> = (0..faces.len())
+            .filter(|i| faces[*i].vertex_iter().any(|v| v == *vertex))
+            .collect();
+        let is_filleted_face = |i: &usize| {
+            let pair = |(k, _): &(usize, bool)| fillets[*k].faces;
+            list.iter().map(pair).any(|(i0, i1)| *i == i0 || *i == i1)
+        };
+        match list[..] {
+            // The fillet ends at the third face perpendicular to the edge.
+            [(k, back)] => {
+                let (_, tangent) = fillets[k].end(back);
+                let mut others = around.iter().filter(|i| !is_filleted_face(i));
+                match (around.len(), others.next()) {
+                    (3, Some(i)) if (plane_normal(*i)? - tangent).magnitude() < tol => {}
+                    _ => return Err(Error::UnsupportedFillet),
+                }
+            }
+            // The fillets continue each other if their contacts coincide, which is checked later.
+            [_, _] => {
+                if !around.iter().all(is_filleted_face) {
+                    return Err(Error::UnsupportedFillet);
+                }
+            }
+            // The corner of three planes is rounded by the ball touching all of them.
+            [_, _, _] => {
+                let normals = match around[..] {
+                    [i, j, k] => [plane_normal(i)?, plane_normal(j)?, plane_normal(k)?],
+                    _ => return Err(Error::UnsupportedFillet),
+                };
+                let matrix = Matrix3::from_cols(normals[0], normals[1], normals[2]).transpose();
+                let inverse = matrix.invert().ok_or(Error::UnsupportedFillet)?;
+                let center = vertex.point() - inverse * Vector3::new(radius, radius, radius);
+                for end in list {
+                    centers.insert(*end, center);
+                }
+                continue;
+            }
+            _ => return Err(Error::UnsupportedFillet),
+        }
+        for (k, back) in list {
+            centers.insert((*k, *back), fillets[*k].center(vertex.point(), radius));
+        }
+    }
+
+    // the contacts of the balls with `faces.0` and `faces.1`, shared at each vertex
+    let mut contacts = HashMap::<VertexID, Vec<Vertex>>::default();
+    let mut end_contacts = HashMap::<(usize, bool), (Vertex, Vertex)>::default();
+    for (k, fillet) in fillets.iter().enumerate() {
+        for back in [false, true] {
+            let (vertex, _) = fillet.end(back);
+            let center = centers[&(k, back)];
+            let (n0, n1) = fillet.normals(vertex.point());
+            let list = contacts.entry(vertex.id()).or_default();
+            let mut contact = |pt: Point3| match list.iter().find(|v| v.point().distance(pt) < tol)
+            {
+                Some(v) => v.clone(),
+                None => {
+                    list.push(Vertex::new(pt));
+                    list[list.len() - 1].clone()
+                }
+            };
+            let pair = (contact(center + n0 * radius), contact(center + n1 * radius));
+            end_contacts.insert((k, back), pair);
+        }
+    }
+    for vertex in &touched {
+        if let [end0, end1] = ends[&vertex.id()][..] {
+            let ((a0, a1), (b0, b1)) = (&end_contacts[&end0], &end_contacts[&end1]);
+            if !((a0 == b0 && a1 == b1) || (a0 == b1 && a1 == b0)) {
+                return Err(Error::UnsupportedFillet);
+            }
+        }
+    }
+
+    // the edges trimmed at the contacts, with the same orientations as the original ones
+    let mut trimmed = HashMap::<EdgeID, (Edge, Edge)>::default();
+    for edge in shell.edge_iter() {
+        let is_touched = |v: &Vertex| ends.contains_key(&v.id());
+        if filleted.contains(&edge.id())
+            || trimmed.contains_key(&edge.id())
+            || !(is_touched(edge.front()) || is_touched(edge.back()))
+        {
+            continue;
+        }
+        let mut curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        let on_edge = |vertex: &Vertex, t: f64| {
+            let Some(list) = contacts.get(&vertex.id()) else {
+                return Ok((vertex.clone(), t));
+            };
+            let found = list.iter().find_map(|v| {
+                let t = curve.search_nearest_parameter(v.point(), None, 100)?;
+                (curve.subs(t).distance(v.point()) < tol).then(|| (v.clone(), t))
+            });
+            found.ok_or(Error::UnsupportedFillet)
+        };
+        let ((v0, s0), (v1, s1)) = (on_edge(edge.front(), t0)?, on_edge(edge.back(), t1)?);
+        if !(t0 <= s0 && s0 < s1 && s1 <= t1) || v0.point().distance(v1.point()) < tol {
+            return Err(Error::TooLargeFilletRadius);
+        }
+        if v1 != *edge.back() {
+            curve.cut(s1);
+        }
+        if v0 != *edge.front() {
+            curve = curve.cut(s0);
+        }
+        trimmed.insert(edge.id(), (edge.clone(), Edge::new(&v0, &v1, curve)));
+    }
+
+    // The fillet runs from the contact with `faces.0` to the one with `faces.1` at the front.
+    let mut arcs = HashMap::<(VertexID, VertexID), Edge>::default();
+    let mut contact_edges = HashMap::<EdgeID, (usize, Edge, Edge)>::default();
+    let mut fillet_faces = Vec::<Face>::new();
+    for (k, fillet) in fillets.iter().enumerate() {
+        let (a, b) = (fillet.edge.front(), fillet.edge.back());
+        let ((a0, a1), (b0, b1)) = (&end_contacts[&(k, false)], &end_contacts[&(k, true)]);
+        let (c0, c1) = (
+            fillet.contact_curve(a0, b0, tol)?,
+            fillet.contact_curve(a1, b1, tol)?,
+        );
+        let mut arc = |v0: &Vertex, v1: &Vertex, vertex: &Vertex, back: bool| {
+            let (n0, n1) = fillet.normals(vertex.point());
+            let transit = centers[&(k, back)] + (n0 + n1).normalize() * radius;
+            shared_arc(&mut arcs, v0, v1, transit)
+        };
+        let (arc_a, arc_b) = (arc(a0, a1, a, false), arc(b1, b0, b, true));
+        let (n0, n1) = fillet.normals(a.point());
+        let surface = match fillet.axis {
+            None => {
+                let axis = n0.cross(n1).normalize();
+                let revolution =
+                    RevolutedCurve::by_revolution(c0.curve(), centers[&(k, false)], axis);
+                let mut surface = Processor::new(revolution);
+                if surface.normal(0.5, 0.0).dot(n0) < 0.0 {
+                    surface.invert();
+                }
+                surface
+            }
+            Some((origin, axis)) => {
+                // The arc at the front is revolved in the direction of the edge.
+                let (_, tangent) = fillet.end(false);
+                let axis = match axis.dot((a.point() - origin).cross(tangent)) > 0.0 {
+                    true => -axis,
+                    false => axis,
+                };
+                let curve = arc_a.oriented_curve();
+                let (u0, u1) = curve.range_tuple();
+                let mut surface =
+                    Processor::new(RevolutedCurve::by_revolution(curve, origin, axis));
+                if surface.normal((u0 + u1) / 2.0, 0.0).dot(n0 + n1) < 0.0 {
+                    surface.invert();
+                }
+                surface
+            }
+        };
+        let wire: Wire = vec![c0.inverse(), arc_a, c1.clone(), arc_b].into();
+        fillet_faces.push(Face::try_new(vec![wire], Surface::RevolutedCurve(surface))?);
+        contact_edges.insert(fillet.edge.id(), (fillet.faces.0, c0, c1));
+    }
+
+    // The rounded corners of three planes are the spheres bounded by the arcs of the fillets.
+    for vertex in &touched {
+        let list = &ends[&vertex.id()];
+        if list.len() != 3 {
+            continue;
+        }
+        let center = centers[&list[0]];
+        // The arcs run in the opposite directions to the ones in the fillets.
+        let mut boundary = list
+            .iter()
+            .map(|(k, back)| {
+                let (v0, v1) = &end_contacts[&(*k, *back)];
+                match back {
+                    false => registered_arc(&arcs, v1, v0),
+                    true => registered_arc(&arcs, v0, v1),
+                }
+                .ok_or(Error::UnsupportedFillet)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut wire = Wire::new();
+        wire.push_back(boundary.pop().unwrap());
+        while let Some(idx) = boundary
+            .iter()
+            .position(|edge| Some(edge.front()) == wire.back_vertex())
+        {
+            wire.push_back(boundary.remove(idx));
+        }
+        if !boundary.is_empty() || !wire.is_closed() {
+            return Err(Error::UnsupportedFillet);
+        }
+        let normal = wire
+            .vertex_iter()
+            .fold(Vector3::zero(), |sum, v| sum + (v.point() - center))
+            .normalize();
+        let first = wire.front_vertex().unwrap().point() - center;
+        let axis = normal.cross(first).normalize();
+        // The meridian on the opposite side is revolved, so the corner is around the parameter
+        // `PI`, apart from the seam and the poles.
+        let (p0, p1) = (
+            Vertex::new(center + axis * radius),
+            Vertex::new(center - axis * radius),
+        );
+        let meridian = circle_arc(&p0, &p1, center - normal * radius).curve();
+        let (u0, u1) = meridian.range_tuple();
+        let revolution = RevolutedCurve::by_revolution(meridian, center, axis);
+        let mut surface = Processor::new(revolution);
+        if surface.normal((u0 + u1) / 2.0, PI.0).dot(normal) < 0.0 {
+            surface.invert();
+        }
+        fillet_faces.push(Face::try_new(vec![wire], Surface::RevolutedCurve(surface))?);
+    }
+
+    let mut new_shell = Shell::new();
+    for (i, face) in faces.iter().enumerate() {
+        if !face.vertex_iter().any(|v| ends.contains_key(&v.id())) {
+            new_shell.push((*face).clone());
+            continue;
+        }
+        let mut wires = Vec::new();
+        for wire in face.boundaries() {
+            let mut edges = Vec::<Edge>::new();
+            for old in wire.edge_iter() {
+                let new = match (contact_edges.get(&old.id()), trimmed.get(&old.id())) {
+                    // In `faces.0`, the edge runs in the same direction as the fillet.
+                    (Some((i0, c0, _)), _) if *i0 == i => c0.clone(),
+                    (Some((_, _, c1)), _) => c1.inverse(),
+                    (None, Some((edge, new))) if edge.front() == old.front() => new.clone(),
+                    (None, Some((_, new))) => new.inverse(),
+                    (None, None) => old.clone(),
+                };
+                edges.push(new);
+            }
+            // The arcs at the ends of the fillets are inserted between the trimmed edges.
+            let mut new_wire = Wire::new();
+            for (k, edge) in edges.iter().enumerate() {
+                new_wire.push_back(edge.clone());
+                let next = &edges[(k + 1) % edges.len()];
+                if edge.back() != next.front() {
+                    let arc = registered_arc(&arcs, edge.back(), next.front())
+                        .ok_or(Error::UnsupportedFillet)?;
+                    new_wire.push_back(arc);
+                }
+            }
+            wires.push(new_wire);
+        }
+        new_shell.push(Face::try_new(wires, face.oriented_surface())?);
+    }
+    new_shell.extend(fillet_faces);
+    Ok(new_shell)
+}
+
+/// Returns the solid whose `edges` are replaced by the flat chamfers.
+///
+/// Each chamfer cuts the adjacent faces at the setback `distance` from the edge. If some
+/// chamfered edges meet at a vertex, the chamfers are connected by a planar corner face, e.g. a
+/// triangle at the corner of a box where three chamfered edges meet. Now, the faces around the
+/// ends of the chamfered edges must be planes, and the edges there must be lines.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // chamfer all edges
+/// let edges: Vec<EdgeID> = cube.edge_iter().map(|edge| edge.id()).collect();
+/// let chamfered = builder::try_chamfer(&cube, &edges, 0.2).unwrap();
+/// let shell = &chamfered.boundaries()[0];
+/// # assert!(chamfered.is_geometric_consistent());
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+///
+/// // 6 squares, 12 rectangles and 8 triangles
+/// assert_eq!(shell.len(), 26);
+/// let triangles = shell.iter().filter(|face| face.boundaries()[0].len() == 3);
+/// assert_eq!(triangles.count(), 8);
+/// ```
+/// # Failures
+/// - If some edge is not contained in `solid`, then return `Error::EdgeNotInSolid`.
+/// - If a face or an edge around the ends of the edges is not planar or linear, or the chamfers
+/// cannot be connected at a vertex, then return `Error::UnsupportedChamfer`.
+/// - If `distance` is too large for the adjacent edges, then return
+/// `Error::TooLargeChamferDistance`.
+pub fn try_chamfer(solid: &Solid, edges: &[EdgeID], distance: f64) -> Result<Solid> {
+    let chamfered: HashSet<EdgeID> = edges.iter().copied().collect();
+    for edge_id in &chamfered {
+        if !solid.edge_iter().any(|edge| edge.id() == *edge_id) {
+            return Err(Error::EdgeNotInSolid);
+        }
+    }
+    let boundaries = solid
+        .boundaries()
+        .iter()
+        .map(|shell| chamfer_shell(shell, &chamfered, distance))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Solid::new(boundaries))
+}
+
+/// Returns the line from `v0` to `v1`, which is shared with the line from `v1` to `v0`.
+fn shared_line(lines: &mut HashMap<(VertexID, VertexID), Edge>, v0: &Vertex, v1: &Vertex) -> Edge {
+    match lines.get(&(v1.id(), v0.id())) {
+        Some(edge) => edge.inverse(),
+        None => lines
+            .entry((v0.id(), v1.id()))
+            .or_insert_with(|| line(v0, v1))
+            .clone(),
+    }
+}
+
+fn chamfer_shell(shell: &Shell, chamfered: &HashSet<EdgeID>, distance: f64) -> Result<Shell> {
+    let is_chamfered = |edge: &Edge| chamfered.contains(&edge.id());
+    let faces: Vec<&Face> = shell.face_iter().collect();
+    // the faces containing the edges, and the incoming and outgoing edges at the corners of faces
+    let mut edge_faces = HashMap::<EdgeID, Vec<usize>>::default();
+    let mut corners = HashMap::<(usize, VertexID), (Edge, Edge)>::default();
+    for (i, face) in faces.iter().enumerate() {
+        for wire in face.boundaries() {
+            let nexts = wire.edge_iter().cycle().skip(1);
+            for (edge0, edge1) in wire.edge_iter().zip(nexts) {
+                corners.insert((i, edge0.back().id()), (edge0.clone(), edge1.clone()));
+                edge_faces.entry(edge0.id()).or_default().push(i);
+            }
+        }
+    }
+    let other_face = |edge: &Edge, i: usize| match edge_faces[&edge.id()][..] {
+        [j, k] if j == i && k != i => Ok(k),
+        [j, k] if j != i && k == i => Ok(j),
+        _ => Err(Error::UnsupportedChamfer),
+    };
+    let mut touched = Vec::<Vertex>::new();
+    for edge in shell.edge_iter().filter(is_chamfered) {
+        for vertex in [edge.front(), edge.back()] {
+            if !touched.contains(vertex) {
+                touched.push(vertex.clone());
+            }
+        }
+    }
+    if touched.is_empty() {
+        return Ok(shell.clone());
+    }
+
+    // the corners of faces moved by the offsets of the chamfered edges
+    let plane_normal = |i: usize| match faces[i].oriented_surface() {
+        Surface::Plane(plane) => Ok(plane.normal()),
+        _ => Err(Error::UnsupportedChamfer),
+    };
+    let direction = |edge: &Edge| match edge.curve() {
+        Curve::Line(_) => Ok((edge.back().point() - edge.front().point()).normalize()),
+        _ => Err(Error::UnsupportedChamfer),
+    };
+    let mut moved = HashMap::<(usize, VertexID), Point3>::default();
+    for vertex in &touched {
+        for i in 0..faces.len() {
+            let Some((incoming, outgoing)) = corners.get(&(i, vertex.id())) else {
+                continue;
+            };
+            let (normal, t0, t1) = (plane_normal(i)?, direction(incoming)?, direction(outgoing)?);
+            if !is_chamfered(incoming) && !is_chamfered(outgoing) {
+                continue;
+            }
+            let offset = |edge: &Edge, t: Vector3| {
+                if is_chamfered(edge) {
+                    normal.cross(t) * distance
+                } else {
+                    Vector3::zero()
+                }
+            };
+            let (o0, o1) = (offset(incoming, t0), offset(outgoing, t1));
+            let det = t0.cross(t1).dot(normal);
+            if det.so_small() {
+                return Err(Error::UnsupportedChamfer);
+            }
+            let s = (o1 - o0).cross(t1).dot(normal) / det;
+            moved.insert((i, vertex.id()), vertex.point() + o0 + t0 * s);
+        }
+    }
+
+    // The corner of a face is replaced by one or two vertices.
+    let mut sequences = HashMap::<(usize, VertexID), Vec<Vertex>>::default();
+    for vertex in &touched {
+        let mut new_vertices = vec![vertex.clone()];
+        for i in 0..faces.len() {
+            let Some((incoming, outgoing)) = corners.get(&(i, vertex.id())) else {
+                continue;
+            };
+            let (h, k) = (other_face(incoming, i)?, other_face(outgoing, i)?);
+            let points = match moved.get(&(i, vertex.id())) {
+                Some(pt) => {
+                    // The moved corners on a common edge must coincide.
+                    let near = |j: usize| match moved.get(&(j, vertex.id())) {
+                        Some(pt0) => pt.near(pt0),
+                        None => true,
+                    };
+                    if (!is_chamfered(incoming) && !near(h))
+                        || (!is_chamfered(outgoing) && !near(k))
+                    {
+                        return Err(Error::UnsupportedChamfer);
+                    }
+                    vec![*pt]
+                }
+                None => {
+                    let point = |j: usize| match moved.get(&(j, vertex.id())) {
+                        Some(pt) => *pt,
+                        None => vertex.point(),
+                    };
+                    vec![point(h), point(k)]
+                }
+            };
+            let mut sequence = Vec::<Vertex>::new();
+            for pt in points {
+                let found = new_vertices.iter().find(|v| v.point().near(&pt));
+                let v = match found {
+                    Some(v) => v.clone(),
+                    None => {
+                        new_vertices.push(Vertex::new(pt));
+                        new_vertices[new_vertices.len() - 1].clone()
+                    }
+                };
+                if !sequence.contains(&v) {
+                    sequence.push(v);
+                }
+            }
+            sequences.insert((i, vertex.id()), sequence);
+        }
+    }
+    let sequence = |i: usize, vertex: &Vertex| match sequences.get(&(i, vertex.id())) {
+        Some(sequence) => sequence.clone(),
+        None => vec![vertex.clone()],
+    };
+
+    let mut lines = HashMap::<(VertexID, VertexID), Edge>::default();
+    let mut new_shell = Shell::new();
+    for (i, face) in faces.iter().enumerate() {
+        if !face.vertex_iter().any(|v| touched.contains(&v)) {
+            new_shell.push((*face).clone());
+            continue;
+        }
+        let mut wires = Vec::new();
+        for wire in face.boundaries() {
+            let mut new_wire = Wire::new();
+            for edge in wire.edge_iter() {
+                let (seq0, seq1) = (sequence(i, edge.front()), sequence(i, edge.back()));
+                let (v0, v1) = (&seq0[seq0.len() - 1], &seq1[0]);
+                if v0 == edge.front() && v1 == edge.back() {
+                    new_wire.push_back(edge.clone());
+                } else {
+                    let dir = (edge.back().point() - edge.front().point()).normalize();
+                    if (v1.point() - v0.point()).dot(dir) < TOLERANCE {
+                        return Err(Error::TooLargeChamferDistance);
+                    }
+                    new_wire.push_back(shared_line(&mut lines, v0, v1));
+                }
+                if seq1.len() == 2 {
+                    new_wire.push_back(shared_line(&mut lines, &seq1[0], &seq1[1]));
+                }
+            }
+            wires.push(new_wire);
+        }
+        new_shell.push(Face::new(wires, face.oriented_surface()));
+    }
+
+    // The chamfer runs in the opposite direction to the edges in the adjacent faces.
+    let chamfered_edges = shell.edge_iter().filter(is_chamfered);
+    let mut done = HashSet::<EdgeID>::default();
+    for edge in chamfered_edges.filter(|edge| done.insert(edge.id())) {
+        let i = edge_faces[&edge.id()][0];
+        let edge = faces[i].edge_iter().find(|e| e.id() == edge.id()).unwrap();
+        let j = other_face(&edge, i)?;
+        let (a, b) = (edge.front(), edge.back());
+        let (seq_ia, seq_ib) = (sequence(i, a), sequence(i, b));
+        let (seq_ja, seq_jb) = (sequence(j, a), sequence(j, b));
+        let vertices = [
+            &seq_ib[0],
+            &seq_ia[seq_ia.len() - 1],
+            &seq_ja[0],
+            &seq_jb[seq_jb.len() - 1],
+        ];
+        let wire: Wire = (0..4)
+            .map(|k| shared_line(&mut lines, vertices[k], vertices[(k + 1) % 4]))
+            .collect();
+        let face = try_attach_plane(&[wire]).map_err(|_| Error::UnsupportedChamfer)?;
+        new_shell.push(face);
+    }
+
+    // The corner faces run around the vertices in the opposite direction to the faces.
+    for vertex in &touched {
+        let start = (0..faces.len())
+            .find(|i| corners.contains_key(&(*i, vertex.id())))
+            .unwrap();
+        let (mut i, mut cycle) = (start, Vec::<Vertex>::new());
+        loop {
+            cycle.extend(sequence(i, vertex).into_iter().rev());
+            i = other_face(&corners[&(i, vertex.id())].0, i)?;
+            if i == start {
+                break;
+            } else if cycle.len() > 2 * faces.len() {
+                return Err(Error::UnsupportedChamfer);
+            }
+        }
+        cycle.dedup();
+        if cycle.len() > 1 && cycle[0] == cycle[cycle.len() - 1] {
+            cycle.pop();
+        }
+        let len = cycle.len();
+        if len > 2 {
+            let wire: Wire = (0..len)
+                .map(|k| shared_line(&mut lines, &cycle[k], &cycle[(k + 1) % len]))
+                .collect();
+            let face = try_attach_plane(&[wire]).map_err(|_| Error::UnsupportedChamfer)?;
+            new_shell.push(face);
+        }
+    }
+    Ok(new_shell)
+}
+
+/// Returns the thin-walled solid by offsetting the faces of `solid` inward by `thickness` and
+/// opening `open_faces`.
+///
+/// Each face is offset along its normal, and the offset faces bound the cavity inside. The open
+/// faces are not offset but replaced by the rims connecting the outer faces to the offset ones.
+/// If no face is opened, the cavity is a void of the returned solid. Now, only the solids bounded
+/// by planes, and by cylinders and spheres given by revolutions, are supported. The offsets of
+/// the cylinders and the spheres are exact, and the offset surfaces around each vertex must meet
+/// at one point.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // open the top face
+/// let top = cube
+///     .face_iter()
+///     .find(|face| face.vertex_iter().all(|v| v.point().z == 1.0))
+///     .unwrap();
+/// let hollowed = builder::try_hollow(&cube, 0.1, &[top.id()]).unwrap();
+/// # assert!(hollowed.is_geometric_consistent());
+/// let shell = &hollowed.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+///
+/// // 5 outer faces, the rim, and 5 inner faces
+/// assert_eq!(shell.len(), 11);
+/// let rim = shell.iter().find(|face| face.boundaries().len() == 2).unwrap();
+/// let inner: Vec<Point3> = rim.boundaries()[1].vertex_iter().map(|v| v.point()).collect();
+/// assert!(inner.iter().any(|p| p.near(&Point3::new(0.1, 0.1, 1.0))));
+/// assert!(inner.iter().any(|p| p.near(&Point3::new(0.9, 0.9, 1.0))));
+/// ```
+/// # Failures
+/// - If some face in `open_faces` is not contained in `solid`, then return
+/// `Error::FaceNotInSolid`.
+/// - If `solid` has a face which is not a plane, a cylinder or a sphere, or the offset surfaces
+/// around a vertex or an edge do not meet, then return `Error::UnsupportedHollow`.
+/// - If an offset face is turned inside out or an offset cylinder or sphere vanishes since
+/// `thickness` is too large, then return `Error::SelfIntersectingOffset` with the id of the face.
+pub fn try_hollow(solid: &Solid, thickness: f64, open_faces: &[FaceID]) -> Result<Solid> {
+    for face_id in open_faces {
+        if !solid.face_iter().any(|face| face.id() == *face_id) {
+            return Err(Error::FaceNotInSolid);
+        }
+    }
+    let mut boundaries = Vec::new();
+    for shell in solid.boundaries() {
+        boundaries.extend(hollow_shell(shell, thickness, open_faces)?);
+    }
+    Ok(Solid::new(boundaries))
+}
+
+/// The analytic surfaces of the faces whose offsets are exact.
+#[derive(Clone, Copy, Debug)]
+enum OffsetSurface {
+    /// the plane through `origin` with the unit normal `normal`
+    Plane { origin: Point3, normal: Vector3 },
+    /// the cylinder around the axis through `origin` with the unit direction `axis`, whose
+    /// normal is directed to the outside if `sign` is `1.0` and to the axis if `-1.0`
+    Cylinder {
+        origin: Point3,
+        axis: Vector3,
+        radius: f64,
+        sign: f64,
+    },
+    /// the sphere whose normal is directed to the outside if `sign` is `1.0` and to the center
+    /// if `-1.0`
+    Sphere {
+        center: Point3,
+        radius: f64,
+        sign: f64,
+    },
+}
+
+impl OffsetSurface {
+    /// Recognizes planes, and the planes, cylinders and spheres given by revolutions.
+    fn new(surface: &Surface) -> Option<Self> {
+        const N: usize = 8;
+        let revolution = match surface {
+            Surface::Plane(plane) => {
+                return Some(Self::Plane {
+                    origin: plane.origin(),
+                    normal: plane.normal(),
+                })
+            }
+            Surface::RevolutedCurve(revolution) => revolution,
+            _ => return None,
+        };
+        let matrix = *revolution.transform();
+        let origin = matrix.transform_point(revolution.origin());
+        let axis = matrix.transform_vector(revolution.axis()).normalize();
+        let ((u0, u1), (v0, v1)) = match surface.try_range_tuple() {
+            (Some(urange), Some(vrange)) => (urange, vrange),
+            _ => return None,
+        };
+        let mut points = Vec::new();
+        for i in 0..=N {
+            for j in 0..=N {
+                let u = u0 + (u1 - u0) * i as f64 / N as f64;
+                let v = v0 + (v1 - v0) * j as f64 / N as f64;
+                points.push(surface.subs(u, v));
+            }
+        }
+        let heights: Vec<f64> = points.iter().map(|p| axis.dot(*p - origin)).collect();
+        let radii: Vec<f64> = points
+            .iter()
+            .zip(&heights)
+            .map(|(p, h)| (*p - origin - axis * *h).magnitude())
+            .collect();
+        let res = if heights.iter().all(|h| h.near(&heights[0])) {
+            Self::Plane {
+                origin: points[0],
+                normal: axis,
+            }
+        } else if radii.iter().all(|r| r.near(&radii[0])) {
+            Self::Cylinder {
+                origin,
+                axis,
+                radius: radii[0],
+                sign: 1.0,
+            }
+        } else {
+            // The center `origin + lambda * axis` is at the same distance from all points.
+            let q0 = points[0] - origin;
+            let (num, den) = points
+                .iter()
+                .zip(&heights)
+                .fold((0.0, 0.0), |(num, den), (p, h)| {
+                    let dh = h - heights[0];
+                    let dq = (*p - origin).magnitude2() - q0.magnitude2();
+                    (num + dq * dh, den + dh * dh)
+                });
+            let center = origin + axis * (num / (2.0 * den));
+            Self::Sphere {
+                center,
+                radius: points[0].distance(center),
+                sign: 1.0,
+            }
+        };
+        let (u, v) = ((u0 + u1) / 2.0, (v0 + v1) / 2.0);
+        let dot = res.gradient(surface.subs(u, v)).dot(surface.normal(u, v));
+        let res = match dot > 0.0 {
+            true => res,
+            false => res.inverse(),
+        };
+        let on_surface = points.iter().all(|p| res.distance(*p).so_small());
+        (dot.abs().near(&1.0) && on_surface).then_some(res)
+    }
+
+    fn inverse(self) -> Self {
+        match self {
+            Self::Plane { origin, normal } => Self::Plane {
+                origin,
+                normal: -normal,
+            },
+            Self::Cylinder {
+                origin,
+                axis,
+                radius,
+                sign,
+            } => Self::Cylinder {
+                origin,
+                axis,
+                radius,
+                sign: -sign,
+            },
+            Self::Sphere {
+                center,
+                radius,
+                sign,
+            } => Self::Sphere {
+                center,
+                radius,
+                sign: -sign,
+            },
+        }
+    }
+
+    /// the signed distance from the surface, positive in the direction of the normal
+    fn distance(&self, pt: Point3) -> f64 {
+        match *self {
+            Self::Plane { origin, normal } => normal.dot(pt - origin),
+            Self::Cylinder {
+                origin,
+                axis,
+                radius,
+                sign,
+            } => {
+                let vec = pt - origin;
+                sign * ((vec - axis * axis.dot(vec)).magnitude() - radius)
+            }
+            Self::Sphere {
+                center,
+                radius,
+                sign,
+            } => sign * (pt.distance(center) - radius),
+        }
+    }
+
+    /// the gradient of the signed distance
+    fn gradient(&self, pt: Point3) -> Vector3 {
+        match *self {
+            Self::Plane { normal, .. } => normal,
+            Self::Cylinder {
+                origin, axis, sign, ..
+            } => {
+                let vec = pt - origin;
+                sign * (vec - axis * axis.dot(vec)).normalize()
+            }
+            Self::Sphere { center, sign, .. } => sign * (pt - center).normalize(),
+        }
+    }
+
+    /// Returns the transformation mapping the surface to its offset by `depth` in the opposite
+    /// direction of the normal, or `None` if the offset cylinder or sphere vanishes.
+    fn offset_matrix(&self, depth: f64) -> Option<Matrix4> {
+        let (center, radius, sign, projection) = match *self {
+            Self::Plane { normal, .. } => return Some(Matrix4::from_translation(-normal * depth)),
+            Self::Cylinder {
+                origin,
+                axis,
+                radius,
+                sign,
+            } => {
+                let projection = Matrix3::from_cols(axis * axis.x, axis * axis.y, axis * axis.z);
+                (origin, radius, sign, projection)
+            }
+            Self::Sphere {
+                center,
+                radius,
+                sign,
+            } => (center, radius, sign, Matrix3::zero()),
+        };
+        let offset_radius = radius - sign * depth;
+        if offset_radius < TOLERANCE {
+            return None;
+        }
+        // scales the radius, and keeps the height along the axis of cylinder
+        let scale = offset_radius / radius;
+        let mat0 = Matrix4::from_translation(-center.to_vec());
+        let mat1 = Matrix4::from(projection + (Matrix3::identity() - projection) * scale);
+        let mat2 = Matrix4::from_translation(center.to_vec());
+        Some(mat2 * mat1 * mat0)
+    }
+}
+
+/// Returns the point near `pt` at which the surfaces offset by the depths meet, by the
+/// Gauss-Newton method damped for the vertices around which the surfaces are tangent.
+fn offset_point(mut pt: Point3, surfaces: &[(OffsetSurface, f64)]) -> Option<Point3> {
+    for _ in 0..100 {
+        let (matrix, vector) = surfaces.iter().fold(
+            (Matrix3::identity() * TOLERANCE, Vector3::zero()),
+            |(matrix, vector), (surface, depth)| {
+                let n = surface.gradient(pt);
+                let outer = Matrix3::from_cols(n * n.x, n * n.y, n * n.z);
+                (matrix + outer, vector + n * (surface.distance(pt) + depth))
+            },
+        );
+        let delta = matrix.invert()? * vector;
+        pt -= delta;
+        if delta.so_small2() {
+            break;
+        }
+    }
+    surfaces
+        .iter()
+        .all(|(surface, depth)| (surface.distance(pt) + depth).so_small())
+        .then_some(pt)
+}
+
+/// Returns the offset of `edge` from `front` to `back` lying on the offset surfaces of the faces
+/// around `edge`. The curves other than lines are mapped by the transformations of the faces
+/// around the edge or their compositions.
+fn offset_edge(
+    edge: &Edge,
+    front: &Vertex,
+    back: &Vertex,
+    around: &[(OffsetSurface, f64, Matrix4)],
+) -> Option<Edge> {
+    const N: usize = 8;
+    let curve = edge.oriented_curve();
+    let candidates = match curve {
+        Curve::Line(_) => vec![Curve::Line(Line(front.point(), back.point()))],
+        _ => {
+            let mut matrices = Vec::new();
+            for (i, (_, _, mat0)) in around.iter().enumerate() {
+                matrices.push(*mat0);
+                for (_, _, mat1) in around.iter().skip(i + 1) {
+                    matrices.push(*mat0 * *mat1);
+                    matrices.push(*mat1 * *mat0);
+                }
+            }
+            matrices
+                .into_iter()
+                .map(|mat| curve.transformed(mat))
+                .collect()
+        }
+    };
+    let on_surfaces = |curve: &Curve| {
+        let (t0, t1) = curve.range_tuple();
+        (0..=N).all(|i| {
+            let pt = curve.subs(t0 + (t1 - t0) * i as f64 / N as f64);
+            around
+                .iter()
+                .all(|(surface, depth, _)| (surface.distance(pt) + depth).so_small())
+        })
+    };
+    candidates
+        .into_iter()
+        .find(|curve| {
+            curve.front().near(&front.point())
+                && curve.back().near(&back.point())
+                && on_surfaces(curve)
+        })
+        .map(|curve| Edge::new(front, back, curve))
+}
+
+fn hollow_shell(shell: &Shell, thickness: f64, open_faces: &[FaceID]) -> Result<Vec<Shell>> {
+    let is_open = |face: &Face| open_faces.contains(&face.id());
+    // the surfaces, the depths of the offsets, and the transformations into the offset surfaces
+    let surfaces = shell
+        .face_iter()
+        .map(|face| {
+            let surface =
+                OffsetSurface::new(&face.oriented_surface()).ok_or(Error::UnsupportedHollow)?;
+            let depth = if is_open(face) { 0.0 } else { thickness };
+            let matrix = surface
+                .offset_matrix(depth)
+                .ok_or(Error::SelfIntersectingOffset(face.id()))?;
+            Ok((surface, depth, matrix))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // The offset vertex is the intersection of the offset surfaces around the vertex.
+    let mut vertices = HashMap::<VertexID, Vertex>::default();
+    for vertex in shell.vertex_iter() {
+        if vertices.contains_key(&vertex.id()) {
+            continue;
+        }
+        let around: Vec<_> = shell
+            .face_iter()
+            .zip(&surfaces)
+            .filter(|(face, _)| face.vertex_iter().any(|v| v == vertex))
+            .map(|(_, (surface, depth, _))| (*surface, *depth))
+            .collect();
+        let pt = offset_point(vertex.point(), &around).ok_or(Error::UnsupportedHollow)?;
+        vertices.insert(vertex.id(), Vertex::new(pt));
+    }
+    let mut edges = HashMap::<EdgeID, (Edge, Edge)>::default();
+    for edge in shell.edge_iter() {
+        if edges.contains_key(&edge.id()) {
+            continue;
+        }
+        let around: Vec<_> = shell
+            .face_iter()
+            .zip(&surfaces)
+            .filter(|(face, _)| face.edge_iter().any(|e| e.id() == edge.id()))
+            .map(|(_, surface)| *surface)
+            .collect();
+        let front = &vertices[&edge.front().id()];
+        let back = &vertices[&edge.back().id()];
+        let offset = offset_edge(&edge, front, back, &around).ok_or(Error::UnsupportedHollow)?;
+        edges.insert(edge.id(), (edge, offset));
+    }
+    let tangent = |edge: &Edge| {
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        curve.der((t0 + t1) / 2.0)
+    };
+    let offset_wire = |face: &Face, wire: &Wire| {
+        wire.edge_iter()
+            .map(|edge| {
+                let (original, offset) = &edges[&edge.id()];
+                let offset = if edge.front() == original.front() {
+                    offset.clone()
+                } else {
+                    offset.inverse()
+                };
+                if tangent(edge).dot(tangent(&offset)) > TOLERANCE {
+                    Ok(offset)
+                } else {
+                    Err(Error::SelfIntersectingOffset(face.id()))
+                }
+            })
+            .collect::<Result<Wire>>()
+    };
+
+    let mut outer = Shell::new();
+    let mut inner = Shell::new();
+    for (face, (_, _, matrix)) in shell.face_iter().zip(&surfaces) {
+        let wires = face.boundaries();
+        let offset_wires = wires
+            .iter()
+            .map(|wire| offset_wire(face, wire))
+            .collect::<Result<Vec<_>>>()?;
+        if is_open(face) {
+            // The rim is the open face whose holes are the offset boundaries.
+            let holes = offset_wires.iter().map(Wire::inverse);
+            let boundaries = wires.into_iter().chain(holes).collect();
+            outer.push(Face::new(boundaries, face.oriented_surface()));
+        } else {
+            outer.push(face.clone());
+            let surface = face.oriented_surface().transformed(*matrix);
+            inner.push(Face::new(offset_wires, surface).inverse());
+        }
+    }
+    if shell.face_iter().any(is_open) {
+        outer.extend(inner);
+        Ok(vec![outer])
+    } else {
+        Ok(vec![outer, inner])
+    }
+}
+
+/// Creates a sphere with the center `center` and the radius `radius`.
+///
+/// The surfaces are the revolutions of the half circle, and the poles are the apexes of the
+/// faces.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let sphere = builder::sphere(Point3::new(1.0, 2.0, 3.0), 2.0);
+/// let shell = &sphere.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # assert!(sphere.is_geometric_consistent());
+/// ```
+pub fn sphere(center: Point3, radius: f64) -> Solid {
+    let v = vertex(center + radius * Vector3::unit_z());
+    let wire: Wire = rsweep(&v, center, Vector3::unit_x(), PI);
+    let shell = cone(&wire, Vector3::unit_z(), PI * 2.0);
+    Solid::new(vec![shell])
+}
+
+/// Creates a torus whose axis is the z-axis through `center`.
+///
+/// The torus is the revolution of the circle with the radius `minor_radius` whose center is
+/// apart from the axis by `major_radius`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let torus = builder::torus(Point3::origin(), 2.0, 0.5);
+/// let shell = &torus.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # assert!(torus.is_geometric_consistent());
+/// ```
+pub fn torus(center: Point3, major_radius: f64, minor_radius: f64) -> Solid {
+    let circle_center = center + major_radius * Vector3::unit_x();
+    let v = vertex(circle_center + minor_radius * Vector3::unit_x());
+    let circle: Wire = rsweep(&v, circle_center, Vector3::unit_y(), PI * 2.0);
+    let shell = rsweep(&circle, center, Vector3::unit_z(), PI * 2.0);
+    Solid::new(vec![shell])
+}
+
+/// Creates a truncated cone whose base is the disk with the center `base_center` and the radius
+/// `base_radius`, and whose top is the disk with the radius `top_radius` apart by `height` in
+/// the direction `axis`.
+///
+/// If `top_radius` is zero, the solid is a cone whose top is an apex vertex.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let frustum = builder::frustum(Point3::origin(), Vector3::unit_z(), 1.0, 0.5, 2.0);
+/// let shell = &frustum.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # assert!(frustum.is_geometric_consistent());
+///
+/// // cone with the apex
+/// let cone = builder::frustum(Point3::origin(), Vector3::unit_z(), 1.0, 0.0, 2.0);
+/// let shell = &cone.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// // all faces are triangles without degenerate edges
+/// assert!(shell.face_iter().all(|face| face.boundaries()[0].len() == 3));
+/// # assert!(cone.is_geometric_consistent());
+/// ```
+pub fn frustum(
+    base_center: Point3,
+    axis: Vector3,
+    base_radius: f64,
+    top_radius: f64,
+    height: f64,
+) -> Solid {
+    let axis = axis.normalize();
+    let radial = if axis.x.abs() < 0.5 {
+        Vector3::unit_x().cross(axis).normalize()
+    } else {
+        Vector3::unit_y().cross(axis).normalize()
+    };
+    let top_center = base_center + height * axis;
+    let mut points = vec![top_center];
+    if !top_radius.so_small() {
+        points.push(top_center + top_radius * radial);
+    }
+    points.push(base_center + base_radius * radial);
+    points.push(base_center);
+    let vertices = Vertex::news(&points);
+    let wire: Wire = vertices
+        .windows(2)
+        .map(|pair| line(&pair[0], &pair[1]))
+        .collect();
+    let shell = cone(&wire, axis, PI * 2.0);
+    Solid::new(vec![shell])
+}
+
+/// Creates a prism by extruding the polygon `polygon` in the plane `plane` by `height`.
+///
+/// The points of `polygon` are the parameters of `plane`, and the polygon is extruded in the
+/// direction of the normal of `plane`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let polygon = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(1.0, 0.0),
+///     Point2::new(1.0, 1.0),
+///     Point2::new(0.0, 2.0),
+/// ];
+/// let plane = Plane::new(
+///     Point3::origin(),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// );
+/// let prism = builder::try_prism(&polygon, plane, 1.0).unwrap();
+/// assert_eq!(prism.boundaries()[0].len(), 6);
+/// # assert!(prism.is_geometric_consistent());
+/// ```
+/// # Failures
+/// If the polygon is degenerate, then returns the error of
+/// [`try_attach_plane`](./fn.try_attach_plane.html).
+pub fn try_prism(polygon: &[Point2], plane: Plane, height: f64) -> Result<Solid> {
+    let points: Vec<Point3> = polygon.iter().map(|p| plane.subs(p.x, p.y)).collect();
+    let vertices = Vertex::news(&points);
+    let wire: Wire = (0..vertices.len())
+        .map(|i| line(&vertices[i], &vertices[(i + 1) % vertices.len()]))
+        .collect();
+    let mut face = try_attach_plane(&[wire])?;
+    let vector = height * plane.normal();
+    if face.oriented_surface().normal(0.0, 0.0).dot(vector) < 0.0 {
+        face.invert();
+    }
+    Ok(tsweep(&face, vector))
+}
+
+/// Returns the cubic B-spline curve approximating the projection of `curve` to `surface` within
+/// `tol`.
+fn projected_curve(curve: &Curve, surface: &Surface, tol: f64) -> Option<BSplineCurve<Point3>> {
+    const MAX_DIVISION: usize = 1024;
+    let (t0, t1) = curve.range_tuple();
+    // The even samples are interpolated, and the odd ones are used to estimate the errors.
+    let mut division = 4;
+    loop {
+        let mut hint = None;
+        let points = (0..=2 * division)
+            .map(|i| {
+                let t = t0 + (t1 - t0) * i as f64 / (2 * division) as f64;
+                let (u, v) = surface.search_nearest_parameter(curve.subs(t), hint, 100)?;
+                hint = Some((u, v));
+                Some(surface.subs(u, v))
+            })
+            .collect::<Option<Vec<Point3>>>()?;
+        let even: Vec<Point3> = points.iter().step_by(2).copied().collect();
+        let approx =
+            BSplineCurve::interpolate(&even, 3, ParamMethod::Uniform, EndCondition::Natural)
+                .ok()?;
+        let error = points
+            .iter()
+            .enumerate()
+            .skip(1)
+            .step_by(2)
+            .map(|(i, pt)| pt.distance(approx.subs(i as f64 / (2 * division) as f64)))
+            .fold(0.0, f64::max);
+        if error < tol {
+            return Some(approx);
+        }
+        if division >= MAX_DIVISION {
+            return None;
+        }
+        division *= 2;
+    }
+}
+
+/// Returns the vertex of `boundary` at `pt`. If there is no such vertex, the edge through `pt`
+/// is cut by the new vertex.
+fn cut_boundary(boundary: &mut Wire, pt: Point3, tol: f64) -> Result<Vertex> {
+    if let Some(v) = boundary
+        .vertex_iter()
+        .find(|v| v.point().distance(pt) < tol)
+    {
+        return Ok(v);
+    }
+    let found = boundary.iter().enumerate().find_map(|(i, edge)| {
+        let curve = edge.curve();
+        let (t0, t1) = curve.range_tuple();
+        let t = curve.search_nearest_parameter(pt, None, 100)?;
+        let p = curve.subs(t);
+        if t0 <= t && t <= t1 && p.distance(pt) < tol {
+            Some((i, Vertex::new(p), t))
+        } else {
+            None
+        }
+    });
+    let (i, vertex, t) = found.ok_or(Error::ImprintNotOnBoundary)?;
+    let (edge0, edge1) = boundary[i]
+        .cut_with_parameter(&vertex, t)
+        .ok_or(Error::ImprintNotOnBoundary)?;
+    boundary[i] = edge1;
+    boundary.insert(i, edge0);
+    Ok(vertex)
+}
+
+/// Returns the vertex on the boundary of `shell[idx]` at `pt`. If there is no such vertex, the
+/// edge through `pt` is split by the new vertex in all the faces of `shell`.
+fn split_boundary(shell: &mut Shell, idx: usize, pt: Point3, tol: f64) -> Result<Vertex> {
+    let boundary = shell[idx].boundaries().pop().unwrap();
+    if let Some(v) = boundary
+        .vertex_iter()
+        .find(|v| v.point().distance(pt) < tol)
+    {
+        return Ok(v);
+    }
+    let found = boundary.edge_iter().find_map(|edge| {
+        let curve = edge.curve();
+        let (t0, t1) = curve.range_tuple();
+        let t = curve.search_nearest_parameter(pt, None, 100)?;
+        let p = curve.subs(t);
+        if t0 <= t && t <= t1 && p.distance(pt) < tol {
+            Some((edge.id(), t))
+        } else {
+            None
+        }
+    });
+    let (edge_id, t) = found.ok_or(Error::ImprintNotOnBoundary)?;
+    let (_, _, vertex) = shell
+        .split_edge(edge_id, t)
+        .map_err(|_| Error::ImprintNotOnBoundary)?;
+    Ok(vertex)
+}
+
 fn partial_rsweep<T: MultiSweep<Point3, Curve, Surface>>(
     elem: &T,
     origin: Point3,
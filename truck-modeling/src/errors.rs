@@ -10,10 +10,93 @@ pub enum Error {
     /// cf. [`builder::try_attach_plane`](../builder/fn.try_attach_plane.html)
     #[error("cannot attach a plane to a wire that is not on one plane.")]
     WireNotInOnePlane,
-    /// tried to create homotopy for two wires with different numbers of edges.
-    /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
+    /// tried to attach a plane with holes crossing the other boundaries or outside the face.
+    /// cf. [`builder::try_attach_plane_with_holes`](../builder/fn.try_attach_plane_with_holes.html)
+    #[error("the holes cross the other boundaries or are not inside the outer boundary.")]
+    InvalidHoles,
+    /// tried to imprint a wire which is not supported.
+    /// cf. [`builder::imprint`](../builder/fn.imprint.html)
+    #[error("the imprint of the wire on the face is not supported.")]
+    UnsupportedImprint,
+    /// tried to imprint a wire whose ends are not on the boundary of the face.
+    /// cf. [`builder::imprint`](../builder/fn.imprint.html)
+    #[error("the ends of the imprinted wire are not on the boundary of the face.")]
+    ImprintNotOnBoundary,
+    /// tried to create homotopy or loft for wires with different numbers of edges.
+    /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html),
+    /// [`builder::try_loft`](../builder/fn.try_loft.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to loft less than two sections.
+    /// cf. [`builder::try_loft`](../builder/fn.try_loft.html)
+    #[error("At least two sections are required to loft.")]
+    TooFewSections,
+    /// tried to loft both closed and open sections.
+    /// cf. [`builder::try_loft`](../builder/fn.try_loft.html)
+    #[error("The sections to loft must be all closed or all open.")]
+    MixedClosedAndOpenSections,
+    /// tried to loft sections some consecutive ones of which coincide.
+    /// cf. [`builder::try_loft`](../builder/fn.try_loft.html)
+    #[error("cannot loft the sections since some consecutive sections coincide.")]
+    CoincidentSections,
+    /// tried to create a Coons patch from a wire which is not closed or does not consist of four
+    /// edges.
+    /// cf. [`builder::try_coons_face`](../builder/fn.try_coons_face.html)
+    #[error("The wire must be closed and consist of four edges to create a Coons patch.")]
+    NotQuadrilateralWire,
+    /// tried to create a Coons patch from rational curves whose end weights are different at a
+    /// corner.
+    /// cf. [`builder::try_coons_face`](../builder/fn.try_coons_face.html)
+    #[error("The weights of the boundary curves must coincide at the corners.")]
+    MismatchedCornerWeights,
+    /// tried to sweep along a path whose tangent vanishes or turns sharply at a corner.
+    /// cf. [`builder::try_path_sweep`](../builder/fn.try_path_sweep.html)
+    #[error("The path of the sweep must be smooth, without sharp corners.")]
+    NonSmoothPath,
+    /// failed to interpolate the samples of the curves drawn by the sweep.
+    /// cf. [`builder::try_helical_sweep`](../builder/fn.try_helical_sweep.html),
+    /// [`builder::try_path_sweep`](../builder/fn.try_path_sweep.html)
+    #[error("cannot interpolate the samples of the swept curves.")]
+    InterpolationFailed,
+    /// tried to fillet or chamfer an edge which is not contained in the solid.
+    /// cf. [`builder::try_fillet`](../builder/fn.try_fillet.html),
+    /// [`builder::try_chamfer`](../builder/fn.try_chamfer.html)
+    #[error("The edge to fillet or chamfer is not contained in the solid.")]
+    EdgeNotInSolid,
+    /// tried to fillet an edge which is neither a line between planes nor a circle between a plane
+    /// and a cylinder, or whose ends cannot be connected to the faces or the other fillets.
+    /// cf. [`builder::try_fillet`](../builder/fn.try_fillet.html)
+    #[error("Only the convex lines between planes and circles between planes and cylinders can be filleted.")]
+    UnsupportedFillet,
+    /// tried to fillet with a radius too large for the adjacent edges.
+    /// cf. [`builder::try_fillet`](../builder/fn.try_fillet.html)
+    #[error("The fillet radius is too large for the adjacent edges.")]
+    TooLargeFilletRadius,
+    /// tried to chamfer an edge around whose ends there are non-planar faces or non-linear
+    /// edges, or whose chamfer cannot be connected to the others.
+    /// cf. [`builder::try_chamfer`](../builder/fn.try_chamfer.html)
+    #[error("Only the edges around which faces are planar and edges are linear can be chamfered.")]
+    UnsupportedChamfer,
+    /// tried to chamfer with a distance too large for the adjacent edges.
+    /// cf. [`builder::try_chamfer`](../builder/fn.try_chamfer.html)
+    #[error("The chamfer distance is too large for the adjacent edges.")]
+    TooLargeChamferDistance,
+    /// tried to open a face which is not contained in the solid.
+    /// cf. [`builder::try_hollow`](../builder/fn.try_hollow.html)
+    #[error("The face to open is not contained in the solid.")]
+    FaceNotInSolid,
+    /// tried to hollow a solid with a face which is not a plane, a cylinder or a sphere, or with
+    /// a vertex or an edge around which the offset surfaces do not meet.
+    /// cf. [`builder::try_hollow`](../builder/fn.try_hollow.html)
+    #[error(
+        "Only the solids bounded by planes, cylinders and spheres meeting simply can be hollowed."
+    )]
+    UnsupportedHollow,
+    /// the offset face is turned inside out or vanishes, since the thickness is larger than the
+    /// face.
+    /// cf. [`builder::try_hollow`](../builder/fn.try_hollow.html)
+    #[error("The offset of the face {0:?} intersects itself.")]
+    SelfIntersectingOffset(crate::FaceID),
 }
 
 #[test]
@@ -31,6 +114,45 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::InvalidHoles).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::UnsupportedImprint).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::ImprintNotOnBoundary).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NotSameNumberOfEdges).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::TooFewSections).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::MixedClosedAndOpenSections
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::CoincidentSections).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NotQuadrilateralWire).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::MismatchedCornerWeights
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NonSmoothPath).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::InterpolationFailed).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::EdgeNotInSolid).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::UnsupportedFillet).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::TooLargeFilletRadius).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::UnsupportedChamfer).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::TooLargeChamferDistance
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::FaceNotInSolid).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::UnsupportedHollow).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::SelfIntersectingOffset(crate::FaceID::new(std::ptr::null()))
+    )
+    .unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
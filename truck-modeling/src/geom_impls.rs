@@ -111,6 +111,45 @@ pub(super) fn attach_plane(mut pts: Vec<Vec<Point3>>) -> Option<Plane> {
     Some(plane)
 }
 
+/// twice the signed area of the closed polygon
+pub(super) fn polygon_area(polygon: &[Point2]) -> f64 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(p, q)| p.x * q.y - q.x * p.y)
+        .sum()
+}
+
+/// whether the point is inside the closed polygon or not, by the crossing number
+pub(super) fn inside_polygon(pt: Point2, polygon: &[Point2]) -> bool {
+    let count = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .filter(|(p, q)| {
+            (p.y <= pt.y) != (q.y <= pt.y) && {
+                let x = p.x + (pt.y - p.y) / (q.y - p.y) * (q.x - p.x);
+                pt.x < x
+            }
+        })
+        .count();
+    count % 2 == 1
+}
+
+/// whether the edges of two closed polygons cross or not
+pub(super) fn polygons_cross(polygon0: &[Point2], polygon1: &[Point2]) -> bool {
+    let side = |p: &Point2, q: &Point2, r: &Point2| {
+        let (u, v) = (q - p, r - p);
+        u.x * v.y - u.y * v.x
+    };
+    let mut segments0 = polygon0.iter().zip(polygon0.iter().cycle().skip(1));
+    segments0.any(|(a0, a1)| {
+        let mut segments1 = polygon1.iter().zip(polygon1.iter().cycle().skip(1));
+        segments1.any(|(b0, b1)| {
+            side(a0, a1, b0) * side(a0, a1, b1) < 0.0 && side(b0, b1, a0) * side(b0, b1, a1) < 0.0
+        })
+    })
+}
+
 #[cfg(test)]
 mod test_geom_impl {
     use super::*;
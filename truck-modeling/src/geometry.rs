@@ -171,6 +171,8 @@ pub enum Surface {
     NurbsSurface(NurbsSurface<Vector4>),
     /// revoluted curve
     RevolutedCurve(Processor<RevolutedCurve<Curve>, Matrix4>),
+    /// curve swept along a helix
+    HelicalSurface(Processor<HelicalSurface<Curve>, Matrix4>),
 }
 
 macro_rules! derive_surface_method {
@@ -180,6 +182,7 @@ macro_rules! derive_surface_method {
             Self::BSplineSurface(got) => $method(got, $($ver), *),
             Self::NurbsSurface(got) => $method(got, $($ver), *),
             Self::RevolutedCurve(got) => $method(got, $($ver), *),
+            Self::HelicalSurface(got) => $method(got, $($ver), *),
         }
     };
 }
@@ -191,6 +194,7 @@ macro_rules! derive_surface_self_method {
             Self::BSplineSurface(got) => Self::BSplineSurface($method(got, $($ver), *)),
             Self::NurbsSurface(got) => Self::NurbsSurface($method(got, $($ver), *)),
             Self::RevolutedCurve(got) => Self::RevolutedCurve($method(got, $($ver), *)),
+            Self::HelicalSurface(got) => Self::HelicalSurface($method(got, $($ver), *)),
         }
     };
 }
@@ -263,6 +267,7 @@ impl IncludeCurve<Curve> for Surface {
                 }
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
+            Surface::HelicalSurface(surface) => surface.include(curve),
         }
     }
 }
@@ -291,6 +296,9 @@ impl SearchNearestParameter<D2> for Surface {
                 };
                 algo::surface::search_nearest_parameter(rotted, point, hint, trials)
             }
+            Surface::HelicalSurface(surface) => {
+                surface.search_nearest_parameter(point, hint, trials)
+            }
         }
     }
 }
@@ -79,3 +79,43 @@ impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Solid<P, C, S> {
         )
     }
 }
+
+/// Inverts the orientations of faces. Used after mappings reversing the orientation of
+/// the space, e.g. reflections. Vertices, edges, and wires are not changed.
+pub trait InvertFaces {
+    fn invert_faces(&mut self);
+}
+
+impl<P> InvertFaces for Vertex<P> {
+    #[inline(always)]
+    fn invert_faces(&mut self) {}
+}
+
+impl<P, C> InvertFaces for Edge<P, C> {
+    #[inline(always)]
+    fn invert_faces(&mut self) {}
+}
+
+impl<P, C> InvertFaces for Wire<P, C> {
+    #[inline(always)]
+    fn invert_faces(&mut self) {}
+}
+
+impl<P, C, S> InvertFaces for Face<P, C, S> {
+    #[inline(always)]
+    fn invert_faces(&mut self) { self.invert(); }
+}
+
+impl<P, C, S> InvertFaces for Shell<P, C, S> {
+    #[inline(always)]
+    fn invert_faces(&mut self) {
+        self.face_iter_mut().for_each(|face| {
+            face.invert();
+        })
+    }
+}
+
+impl<P, C, S> InvertFaces for Solid<P, C, S> {
+    #[inline(always)]
+    fn invert_faces(&mut self) { self.not(); }
+}
@@ -0,0 +1,28 @@
+use truck_modeling::*;
+use truck_topology::adjacency::SolidAdjacency;
+
+mod common;
+
+#[test]
+fn cube_adjacency() {
+    let cube = common::unit_cube(Point3::origin());
+    let adjacency = SolidAdjacency::new(&cube);
+
+    assert_eq!(adjacency.edges().len(), 12);
+    adjacency.edges().iter().for_each(|edge| {
+        assert_eq!(adjacency.faces_of_edge(edge.id()).len(), 2);
+        assert_eq!(adjacency.edges_of_vertex(edge.front().id()).len(), 3);
+        assert_eq!(adjacency.edges_of_vertex(edge.back().id()).len(), 3);
+    });
+    cube.face_iter().for_each(|face| {
+        let adjacent = adjacency.adjacent_faces(face.id());
+        assert_eq!(adjacent.len(), 4);
+        adjacent.iter().for_each(|other| {
+            let edge = adjacency.edge_between(face.id(), other.id()).unwrap();
+            assert!(adjacency.faces_of_edge(edge.id()).contains(other));
+        });
+    });
+
+    assert_eq!(adjacency.feature_edges(Deg(30.0).into()).count(), 12);
+    assert_eq!(adjacency.feature_edges(Deg(100.0).into()).count(), 0);
+}
@@ -0,0 +1,330 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+mod common;
+
+#[test]
+fn partially_revolved_rectangle() {
+    // the rectangle `[1, 2] x [0, 1]` on the xz-plane
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_z());
+    let profile = f.boundaries()[0].clone();
+    for (start, end) in [(0.0, 1.5 * PI), (-PI, 0.5 * PI), (1.5 * PI, 0.0)] {
+        let solid = builder::try_partial_rsweep(
+            &profile,
+            Point3::origin(),
+            Vector3::unit_z(),
+            (Rad(start), Rad(end)),
+        )
+        .unwrap();
+        assert!(solid.is_geometric_consistent());
+        let mesh = solid.triangulation(0.005).to_polygon();
+        // the volume by Pappus's theorem: angle * (centroid radius) * area
+        let volume = mesh.volume();
+        let exact = 1.5 * PI * 1.5;
+        assert!(
+            f64::abs(volume - exact) < 1.0e-2 * exact,
+            "{volume} {exact}"
+        );
+    }
+}
+
+#[test]
+fn filleted_box_volume() {
+    // the box `[0, 1] x [0, 1] x [0, 2]`
+    let solid = common::cuboid(Point3::origin(), Vector3::new(1.0, 1.0, 2.0));
+    let edge = solid
+        .edge_iter()
+        .find(|edge| {
+            let (p, q) = (edge.front().point(), edge.back().point());
+            p.x == 1.0 && p.y == 1.0 && q.x == 1.0 && q.y == 1.0
+        })
+        .unwrap();
+    let radius = 0.3;
+    let filleted = builder::try_fillet(&solid, &[edge.id()], radius, TOLERANCE).unwrap();
+    assert!(filleted.is_geometric_consistent());
+    let volume = filleted.triangulation(0.001).to_polygon().volume();
+    // the removed volume is `(1 - PI / 4) r^2 L`.
+    let removed = (1.0 - PI / 4.0) * radius * radius * 2.0;
+    let exact = 2.0 - removed;
+    assert!(
+        f64::abs(volume - exact) < 0.05 * removed,
+        "{volume} {exact}"
+    );
+}
+
+#[test]
+fn filleted_cylinder_volume() {
+    // the cylinder with the radius 1 and the height 1 around the y-axis
+    let solid = builder::frustum(Point3::origin(), Vector3::unit_y(), 1.0, 1.0, 1.0);
+    // the arcs of the upper circle
+    let edges: Vec<EdgeID> = solid
+        .edge_iter()
+        .filter(|edge| {
+            let on_circle = |p: Point3| p.y.near(&1.0) && f64::hypot(p.x, p.z).near(&1.0);
+            on_circle(edge.front().point()) && on_circle(edge.back().point())
+        })
+        .map(|edge| edge.id())
+        .collect();
+    let radius = 0.25;
+    let filleted = builder::try_fillet(&solid, &edges, radius, TOLERANCE).unwrap();
+    assert!(filleted.is_geometric_consistent());
+    let volume = filleted.triangulation(0.001).to_polygon().volume();
+    // the removed section with the area `(1 - PI / 4) r^2` is revolved at the radius of the
+    // centroid `1 - r + 2r / (3 (4 - PI))`.
+    let area = (1.0 - PI / 4.0) * radius * radius;
+    let centroid = 1.0 - radius + 2.0 * radius / (3.0 * (4.0 - PI));
+    let removed = 2.0 * PI * centroid * area;
+    let exact = PI - removed;
+    assert!(
+        f64::abs(volume - exact) < 0.05 * removed,
+        "{volume} {exact}"
+    );
+}
+
+#[test]
+fn rounded_corner_volume() {
+    let cube = common::unit_cube(Point3::origin());
+    let corner = Point3::new(1.0, 1.0, 1.0);
+    let edges: Vec<EdgeID> = cube
+        .edge_iter()
+        .filter(|edge| edge.front().point() == corner || edge.back().point() == corner)
+        .map(|edge| edge.id())
+        .collect();
+    let radius = 0.3;
+    let rounded = builder::try_fillet(&cube, &edges, radius, TOLERANCE).unwrap();
+    assert!(rounded.is_geometric_consistent());
+    let volume = rounded.triangulation(0.001).to_polygon().volume();
+    // The three fillets are shortened by `r`, and the corner cube `r^3` is replaced by the
+    // eighth of the ball.
+    let r = radius;
+    let removed = 3.0 * (1.0 - PI / 4.0) * r * r * (1.0 - r) + (1.0 - PI / 6.0) * r * r * r;
+    let exact = 1.0 - removed;
+    assert!(
+        f64::abs(volume - exact) < 0.05 * removed,
+        "{volume} {exact}"
+    );
+}
+
+#[test]
+fn chamfered_cube_volume() {
+    let cube = common::unit_cube(Point3::origin());
+    let edges: Vec<EdgeID> = cube.edge_iter().map(|edge| edge.id()).collect();
+    let d = 0.2;
+    let chamfered = builder::try_chamfer(&cube, &edges, d).unwrap();
+    assert!(chamfered.is_geometric_consistent());
+    let mut mesh = chamfered.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE * 2.0)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    // 12 prisms along the edges and 8 corners are removed.
+    let exact = 1.0 - 6.0 * d * d * (1.0 - 2.0 * d) - 8.0 * 5.0 / 6.0 * d * d * d;
+    assert_near!(mesh.volume(), exact);
+}
+
+#[test]
+fn hollowed_cube_volume() {
+    let cube = common::cuboid(Point3::origin(), Vector3::new(2.0, 2.0, 2.0));
+    let top = cube
+        .face_iter()
+        .find(|face| face.vertex_iter().all(|v| v.point().z == 2.0))
+        .unwrap();
+    let hollowed = builder::try_hollow(&cube, 0.25, &[top.id()]).unwrap();
+    assert!(hollowed.is_geometric_consistent());
+    let mut mesh = hollowed.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE * 2.0)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    // the outer box minus the inner box `[0.25, 1.75] x [0.25, 1.75] x [0.25, 2]`
+    assert_near!(mesh.volume(), 8.0 - 1.5 * 1.5 * 1.75);
+}
+
+#[test]
+fn hollowed_sphere_and_cylinder_volumes() {
+    let assert_volume = |solid: Solid, exact: f64| {
+        let hollowed = builder::try_hollow(&solid, 0.25, &[]).unwrap();
+        assert!(hollowed.is_geometric_consistent());
+        let mut mesh = hollowed.triangulation(0.005).to_polygon();
+        mesh.put_together_same_attrs(TOLERANCE * 2.0)
+            .remove_degenerate_faces()
+            .remove_unused_attrs();
+        assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+        let volume = mesh.volume();
+        assert!(f64::abs(volume - exact) < 0.01 * exact, "{volume} {exact}");
+    };
+
+    // the sphere with the radius 1.5 minus the void with the radius 1.25
+    let sphere = builder::sphere(Point3::new(1.0, 2.0, 3.0), 1.5);
+    assert_volume(
+        sphere,
+        4.0 / 3.0 * PI * (1.5 * 1.5 * 1.5 - 1.25 * 1.25 * 1.25),
+    );
+
+    // the cylinder with the radius 1 and the height 2 minus the void with the radius 0.75 and
+    // the height 1.5
+    let cylinder = builder::frustum(Point3::origin(), Vector3::unit_z(), 1.0, 1.0, 2.0);
+    assert_volume(cylinder, PI * (2.0 - 0.75 * 0.75 * 1.5));
+}
+
+#[test]
+fn primitive_volumes() {
+    let assert_volume = |solid: Solid, exact: f64| {
+        assert!(solid.is_geometric_consistent());
+        let mut mesh = solid.triangulation(0.005).to_polygon();
+        mesh.put_together_same_attrs(TOLERANCE * 2.0)
+            .remove_degenerate_faces()
+            .remove_unused_attrs();
+        assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+        let volume = mesh.volume();
+        assert!(f64::abs(volume - exact) < 0.01 * exact, "{volume} {exact}");
+    };
+
+    let sphere = builder::sphere(Point3::new(1.0, 2.0, 3.0), 1.5);
+    assert_volume(sphere, 4.0 / 3.0 * PI * 1.5 * 1.5 * 1.5);
+
+    let torus = builder::torus(Point3::new(-1.0, 0.0, 2.0), 2.0, 0.5);
+    assert_volume(torus, 2.0 * PI * PI * 2.0 * 0.5 * 0.5);
+
+    let axis = Vector3::new(1.0, 1.0, 0.0);
+    let frustum = builder::frustum(Point3::new(0.0, 1.0, 0.0), axis, 1.0, 0.5, 2.0);
+    assert_volume(frustum, PI * 2.0 / 3.0 * (1.0 + 0.5 + 0.25));
+
+    let cone = builder::frustum(Point3::origin(), Vector3::unit_z(), 1.0, 0.0, 3.0);
+    assert_volume(cone, PI);
+
+    // the L-shaped hexagon, clockwise in the parameter space
+    let polygon = [
+        Point2::new(0.0, 0.0),
+        Point2::new(0.0, 2.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(2.0, 0.0),
+    ];
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, 2.0),
+    );
+    let prism = builder::try_prism(&polygon, plane, 1.5).unwrap();
+    assert_volume(prism, 3.0 * 1.5);
+}
+
+#[test]
+fn mirrored_volumes() {
+    let cube = common::cuboid(Point3::origin(), Vector3::new(1.0, 2.0, 3.0));
+    let torus = builder::torus(Point3::new(1.0, 0.0, 0.0), 2.0, 0.5);
+    let normal = Vector3::new(1.0, -2.0, 0.5);
+    for solid in [cube, torus] {
+        let volume = solid.triangulation(0.005).to_polygon().volume();
+        let mirrored = builder::mirrored(&solid, Point3::new(-1.0, 0.5, 2.0), normal);
+        assert!(mirrored.is_geometric_consistent());
+        let mesh = mirrored.triangulation(0.005).to_polygon();
+        // a positive volume means the triangles are oriented outward
+        assert!(mesh.volume() > 0.0);
+        assert!(f64::abs(mesh.volume() - volume) < 1.0e-3 * volume);
+    }
+}
+
+#[test]
+fn pattern_composes_transformations() {
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let edge = builder::bezier(&v0, &v1, vec![Point3::new(0.5, 0.0, 1.0)]);
+    let face = builder::tsweep(&edge, Vector3::unit_y());
+    let instances = builder::linear_pattern(&face, Vector3::unit_x(), 2.0, 1000);
+    assert_eq!(instances.len(), 1000);
+    let pt = instances[999].oriented_surface().subs(0.5, 0.5);
+    let expected = face.oriented_surface().subs(0.5, 0.5) + 1998.0 * Vector3::unit_x();
+    assert_near!(pt, expected);
+    assert!(instances[999].is_geometric_consistent());
+
+    // the side face of a cylinder
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_z());
+    let shell = builder::rsweep(&e, Point3::origin(), Vector3::unit_z(), Rad(1.0));
+    let Surface::RevolutedCurve(surface) = shell[0].surface() else {
+        panic!("the side face of a cylinder is not a surface of revolution");
+    };
+    let instances =
+        builder::circular_pattern(&shell[0], Point3::new(3.0, 0.0, 0.0), Vector3::unit_y(), 4);
+    instances.iter().for_each(|instance| {
+        let Surface::RevolutedCurve(processor) = instance.surface() else {
+            panic!("the instance is not a surface of revolution");
+        };
+        // the transformation is composed, and the entity is not changed.
+        let (entity0, entity1) = (surface.entity(), processor.entity());
+        assert_eq!(entity0.origin(), entity1.origin());
+        assert_eq!(entity0.axis(), entity1.axis());
+        let (curve0, curve1) = (entity0.entity_curve(), entity1.entity_curve());
+        assert_eq!(curve0.subs(0.5), curve1.subs(0.5));
+        assert!(instance.is_geometric_consistent());
+    });
+}
+
+#[test]
+fn helical_spring_is_closed() {
+    // the circle with radius 0.5 centered at (2.0, 0.0, 0.0) on the xz-plane
+    let v = builder::vertex(Point3::new(2.5, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let (origin, axis) = (Point3::origin(), Vector3::unit_z());
+    let shell =
+        builder::try_helical_sweep(&circle, origin, axis, 1.5, 3.0, TOLERANCE, true).unwrap();
+    let mut mesh = shell.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE * 2.0)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    // pitch * turns + diameter
+    let bdd_box = mesh.bounding_box();
+    let height = bdd_box.max()[2] - bdd_box.min()[2];
+    assert!(f64::abs(height - 5.5) < 0.02, "{height}");
+}
+
+#[test]
+fn plate_with_holes_area() {
+    // the square `[0, 4] x [0, 4]` with two circles with radius 0.5
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, 4.0 * Vector3::unit_x());
+    let plate = builder::tsweep(&e, 4.0 * Vector3::unit_y());
+    let outer = plate.boundaries()[0].clone();
+    let inners: Vec<Wire> = [1.0, 3.0]
+        .into_iter()
+        .map(|x| {
+            let v = builder::vertex(Point3::new(x + 0.5, 2.0, 0.0));
+            builder::rsweep(&v, Point3::new(x, 2.0, 0.0), Vector3::unit_z(), Rad(7.0))
+        })
+        .collect();
+    let face = builder::try_attach_plane_with_holes(&outer, &inners).unwrap();
+    let shell: Shell = vec![face].into();
+    let area = shell.triangulation(0.005).to_polygon().surface_area();
+    let exact = 16.0 - 2.0 * std::f64::consts::PI * 0.25;
+    assert!(f64::abs(area - exact) < 0.01 * exact, "{area} {exact}");
+}
+
+#[test]
+fn imprinted_rectangle_area() {
+    // the rectangle `[0, 2] x [0, 1]`
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, 2.0 * Vector3::unit_x());
+    let face = builder::tsweep(&e, Vector3::unit_y());
+    let v0 = builder::vertex(Point3::new(0.5, 0.0, 1.0));
+    let v1 = builder::vertex(Point3::new(1.5, 1.0, -1.0));
+    let wire: Wire = vec![builder::line(&v0, &v1)].into();
+    let faces = builder::imprint(&face, &wire, 1.0e-3).unwrap();
+    assert_eq!(faces.len(), 2);
+    let areas: Vec<f64> = faces
+        .into_iter()
+        .map(|face| {
+            let shell: Shell = vec![face].into();
+            shell.triangulation(0.01).to_polygon().surface_area()
+        })
+        .collect();
+    // both parts are trapezoids with the area 1.0
+    assert_near!(areas[0], 1.0);
+    assert_near!(areas[1], 1.0);
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use truck_modeling::*;
+
+/// the cuboid `origin + [0, diagonal.x] x [0, diagonal.y] x [0, diagonal.z]`
+pub fn cuboid(origin: Point3, diagonal: Vector3) -> Solid {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, diagonal.x * Vector3::unit_x());
+    let f = builder::tsweep(&e, diagonal.y * Vector3::unit_y());
+    builder::tsweep(&f, diagonal.z * Vector3::unit_z())
+}
+
+/// the unit cube `origin + [0, 1]^3`
+pub fn unit_cube(origin: Point3) -> Solid { cuboid(origin, Vector3::new(1.0, 1.0, 1.0)) }
@@ -0,0 +1,49 @@
+use truck_modeling::*;
+use truck_topology::compress::CompressedShell;
+
+mod common;
+
+fn cube() -> CompressedShell<Point3, Curve, Surface> {
+    let solid = common::unit_cube(Point3::origin());
+    solid.compress().boundaries.pop().unwrap()
+}
+
+#[test]
+fn permuted_cube() {
+    let cshell = cube();
+    let mut other = cshell.clone();
+    let (vlen, elen) = (other.vertices.len(), other.edges.len());
+    other.vertices.reverse();
+    other.edges.reverse();
+    other.edges.iter_mut().for_each(|edge| {
+        let (a, b) = edge.vertices;
+        edge.vertices = (vlen - 1 - a, vlen - 1 - b);
+    });
+    other.faces.reverse();
+    other
+        .faces
+        .iter_mut()
+        .flat_map(|face| face.boundaries.iter_mut().flatten())
+        .for_each(|edge| edge.index = elen - 1 - edge.index);
+
+    assert!(cshell.approx_eq(&other, TOLERANCE));
+    assert!(cshell.diff(&other, TOLERANCE).is_empty());
+}
+
+#[test]
+fn nudged_face() {
+    let cshell = cube();
+    let mut other = cshell.clone();
+    // every face of the cube is perpendicular to one of the axes
+    let nudge = Matrix4::from_translation(Vector3::new(1.0e-3, 1.0e-3, 1.0e-3));
+    other.faces[3].surface.transform_by(nudge);
+    assert!(!cshell.approx_eq(&other, TOLERANCE));
+
+    let diff = cshell.diff(&other, TOLERANCE);
+    assert!(diff.added_faces.is_empty());
+    assert!(diff.removed_faces.is_empty());
+    assert_eq!(diff.modified_faces.len(), 1);
+    let modified = diff.modified_faces[0];
+    assert_eq!((modified.original, modified.modified), (3, 3));
+    assert_near!(modified.deviation, 1.0e-3);
+}
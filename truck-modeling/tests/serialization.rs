@@ -0,0 +1,156 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use truck_modeling::*;
+use truck_polymesh::{Faces, PolygonMesh, StandardAttributes};
+
+mod common;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(object: &T) {
+    let json = serde_json::to_string(object).unwrap();
+    assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), object);
+    let bytes = bincode::serialize(object).unwrap();
+    assert_eq!(&bincode::deserialize::<T>(&bytes).unwrap(), object);
+}
+
+fn bspline_curve() -> BSplineCurve<Point3> {
+    BSplineCurve::new(
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 0.0, 0.0),
+        ],
+    )
+}
+
+fn bspline_surface() -> BSplineSurface<Point3> {
+    BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1)),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 1.0)],
+            vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+        ],
+    )
+}
+
+#[test]
+fn geometry_round_trip() {
+    round_trip(&bspline_curve());
+    round_trip(&bspline_surface());
+    round_trip(&NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Vector4::new(1.0, 0.0, 0.0, 1.0),
+            Vector4::new(1.0, 1.0, 0.0, 1.0),
+            Vector4::new(0.0, 2.0, 0.0, 2.0),
+        ],
+    )));
+    round_trip(&NurbsSurface::new(BSplineSurface::new(
+        (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+        vec![
+            vec![
+                Vector4::new(0.0, 0.0, 0.0, 1.0),
+                Vector4::new(0.0, 2.0, 0.0, 2.0),
+            ],
+            vec![
+                Vector4::new(1.0, 0.0, 0.0, 1.0),
+                Vector4::new(2.0, 2.0, 1.0, 2.0),
+            ],
+        ],
+    )));
+    round_trip(&Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ));
+    round_trip(&PolylineCurve(vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+    ]));
+    let mut processor = Processor::<_, Matrix4>::new(RevolutedCurve::by_revolution(
+        bspline_curve(),
+        Point3::origin(),
+        Vector3::unit_y(),
+    ));
+    processor.transform_by(Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)));
+    processor.invert();
+    round_trip(&processor);
+}
+
+#[test]
+fn polygon_mesh_round_trip() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 0.5, 1.0),
+    ];
+    let faces = Faces::from_iter(&[
+        [
+            (0, None, None),
+            (3, None, None),
+            (2, None, None),
+            (1, None, None),
+        ]
+        .as_ref(),
+        &[(0, None, None), (1, None, None), (4, None, None)],
+        &[(1, None, None), (2, None, None), (4, None, None)],
+        &[(2, None, None), (3, None, None), (4, None, None)],
+        &[(3, None, None), (0, None, None), (4, None, None)],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    round_trip(&mesh);
+}
+
+#[test]
+fn solid_round_trip_preserves_sharing() {
+    let cube = common::unit_cube(Point3::origin());
+
+    let check = |solid: &Solid| {
+        let edges: HashSet<_> = solid.edge_iter().map(|edge| edge.id()).collect();
+        let vertices: HashSet<_> = solid.vertex_iter().map(|vertex| vertex.id()).collect();
+        assert_eq!(solid.face_iter().count(), 6);
+        assert_eq!(edges.len(), 12);
+        assert_eq!(vertices.len(), 8);
+        assert_eq!(
+            solid.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+    };
+
+    let json = serde_json::to_string(&cube).unwrap();
+    let solid: Solid = serde_json::from_str(&json).unwrap();
+    check(&solid);
+    assert_eq!(serde_json::to_string(&solid).unwrap(), json);
+
+    let bytes = bincode::serialize(&cube).unwrap();
+    let solid: Solid = bincode::deserialize(&bytes).unwrap();
+    check(&solid);
+    assert_eq!(bincode::serialize(&solid).unwrap(), bytes);
+}
+
+#[test]
+fn reject_unsorted_knots() {
+    assert!(serde_json::from_str::<KnotVec>("[0.0, 1.0, 0.5, 2.0]").is_err());
+    let bytes = bincode::serialize(&vec![0.0, 1.0, 0.5, 2.0]).unwrap();
+    assert!(bincode::deserialize::<KnotVec>(&bytes).is_err());
+
+    let mut json = serde_json::to_value(bspline_curve()).unwrap();
+    json["knot_vec"] = serde_json::json!([0.0, 0.0, 0.0, 1.0, 0.5, 1.0, 1.0]);
+    assert!(serde_json::from_value::<BSplineCurve<Point3>>(json).is_err());
+
+    let mut json = serde_json::to_value(bspline_surface()).unwrap();
+    json["knot_vecs"][0] = serde_json::json!([0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+    assert!(serde_json::from_value::<BSplineSurface<Point3>>(json).is_err());
+}
@@ -0,0 +1,35 @@
+use truck_modeling::*;
+
+mod common;
+
+fn unit_square(origin: Point3) -> Face {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    builder::tsweep(&e, Vector3::unit_y())
+}
+
+#[test]
+fn sew_cube() {
+    let cube = common::unit_cube(Point3::origin());
+    // independent faces whose edges are not shared
+    let faces: Vec<Face> = cube.boundaries()[0]
+        .face_iter()
+        .map(|face| builder::translated(face, Vector3::new(1.0e-8, 0.0, 0.0)))
+        .collect();
+    let shell: Shell = faces.clone().into();
+    assert_eq!(shell.connected_components().len(), 6);
+
+    let shells = Shell::sew(faces, 1.0e-6);
+    assert_eq!(shells.len(), 1);
+    assert_eq!(shells[0].shell_condition(), ShellCondition::Closed);
+    Solid::new(shells);
+}
+
+#[test]
+fn gap_is_not_sewn() {
+    let tol = 1.0e-6;
+    let face0 = unit_square(Point3::origin());
+    let face1 = unit_square(Point3::new(1.0 + 2.0 * tol, 0.0, 0.0));
+    let shells = Shell::sew(vec![face0, face1], tol);
+    assert_eq!(shells.len(), 2);
+}
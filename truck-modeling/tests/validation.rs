@@ -0,0 +1,50 @@
+use truck_modeling::*;
+use truck_topology::shell::ShellCondition;
+use truck_topology::validation::ShapeValidator;
+
+mod common;
+
+#[test]
+fn valid_cube() {
+    let report = common::unit_cube(Point3::origin()).validate(TOLERANCE);
+    assert!(report.is_ok(), "{report}");
+}
+
+#[test]
+fn broken_cube() {
+    let mut shell = common::unit_cube(Point3::origin())
+        .into_boundaries()
+        .pop()
+        .unwrap();
+
+    let vertex = shell.vertex_iter().next().unwrap();
+    vertex.set_point(vertex.point() + Vector3::new(0.0, 0.0, 0.01));
+    shell[2].invert();
+    let face_edges: Vec<EdgeID> = shell[2].edge_iter().map(|edge| edge.id()).collect();
+
+    let report = shell.validate(TOLERANCE);
+    assert!(!report.is_ok());
+    assert!(report.curve_deviations().is_empty());
+    assert!(report.open_wires().is_empty());
+    assert!(report.self_intersecting_boundaries().is_empty());
+
+    let deviations = report.vertex_deviations();
+    assert_eq!(deviations.len(), 3);
+    deviations.iter().for_each(|x| {
+        assert_eq!(x.vertex_id, vertex.id());
+        assert_near!(x.distance, 0.01);
+    });
+
+    let defects = report.shell_defects();
+    assert_eq!(defects.len(), 1);
+    assert_eq!(defects[0].condition, ShellCondition::Regular);
+    assert!(defects[0].boundary_edges.is_empty());
+    assert_eq!(defects[0].misoriented_edges.len(), 4);
+    defects[0]
+        .misoriented_edges
+        .iter()
+        .for_each(|id| assert!(face_edges.contains(id)));
+
+    let message = format!("{report}");
+    assert_eq!(message.lines().count(), 4);
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use truck_base::{bounding_box::BoundingBox, tolerance::TOLERANCE};
 
 impl Ray {
     /// Returns the origin of the ray
@@ -368,6 +369,208 @@ impl Camera {
             }
         }
     }
+
+    /// Returns the distances to the near and far clip planes.
+    fn clip_distances(&self) -> (f64, f64) {
+        let p = &self.projection;
+        match self.projection_type {
+            ProjectionType::Perspective => (p[3][2] / (p[2][2] - 1.0), p[3][2] / (p[2][2] + 1.0)),
+            ProjectionType::Parallel => {
+                let near = p[3][2] / p[2][2];
+                (near, near - 1.0 / p[2][2])
+            }
+        }
+    }
+
+    /// Recreates the projection with the clip distances. The field of view is kept, and the
+    /// screen size of the parallel camera is replaced by `screen_size` if it is `Some`.
+    fn reset_projection(&mut self, screen_size: Option<f64>, near_clip: f64, far_clip: f64) {
+        let scale = self.projection[1][1];
+        *self = match self.projection_type {
+            ProjectionType::Perspective => {
+                let field_of_view = Rad(2.0 * f64::atan(1.0 / scale));
+                Camera::perspective_camera(self.matrix, field_of_view, near_clip, far_clip)
+            }
+            ProjectionType::Parallel => {
+                let screen_size = screen_size.unwrap_or(2.0 / scale);
+                Camera::parallel_camera(self.matrix, screen_size, near_clip, far_clip)
+            }
+        };
+    }
+
+    /// Moves the camera, and sets the clip distances and the screen size of the parallel camera,
+    /// so that the bounding box fills the view.
+    ///
+    /// The directions of the camera and the field of view are kept. After fitting, every point
+    /// of `bbox` is projected by `self.projection(1.0)` into the square `[-1 + margin, 1 - margin]`
+    /// of the screen, and between the near and far clip planes. The margin is based on the
+    /// vertical direction of the screen, so the horizontal one is wider if the aspect ratio is
+    /// larger than 1. `margin` must be in `[0, 1)`.
+    ///
+    /// If `bbox` is flat or degenerates to a point, the distances are computed from the size
+    /// `1.0` in the degenerate directions. Nothing is done if `bbox` is empty.
+    /// # Examples
+    /// ```
+    /// use truck_base::{bounding_box::BoundingBox, cgmath64::*};
+    /// use truck_platform::*;
+    ///
+    /// let mut camera = Camera::default();
+    /// let bbox: BoundingBox<Point3> = [Point3::new(-1.0, 0.0, 2.0), Point3::new(3.0, 1.0, 4.0)]
+    ///     .into_iter()
+    ///     .collect();
+    /// camera.fit_to(&bbox, 0.1);
+    /// for i in 0..8 {
+    ///     let corner = Point3::new([-1.0, 3.0][i & 1], [0.0, 1.0][(i >> 1) & 1], [2.0, 4.0][i >> 2]);
+    ///     let uvz = camera.projection(1.0).transform_point(corner);
+    ///     assert!(uvz.x.abs() < 0.9 + 1.0e-6 && uvz.y.abs() < 0.9 + 1.0e-6);
+    ///     assert!(-1.0 <= uvz.z && uvz.z <= 1.0);
+    /// }
+    /// ```
+    pub fn fit_to(&mut self, bbox: &BoundingBox<Point3>, margin: f64) {
+        if bbox.is_empty() {
+            return;
+        }
+        let (min, max, center) = (bbox.min(), bbox.max(), bbox.center());
+        let radius = match bbox.diameter() > TOLERANCE {
+            true => bbox.diameter() / 2.0,
+            false => 1.0,
+        };
+        let (x_axis, y_axis, dir) = (
+            self.matrix[0].truncate(),
+            self.matrix[1].truncate(),
+            self.eye_direction(),
+        );
+        // the coordinates of the corners in the camera frame at the center
+        let corners: Vec<Vector3> = (0..8)
+            .map(|i| {
+                let corner = Point3::new(
+                    [min.x, max.x][i & 1],
+                    [min.y, max.y][(i >> 1) & 1],
+                    [min.z, max.z][i >> 2],
+                );
+                let v = corner - center;
+                Vector3::new(v.dot(x_axis), v.dot(y_axis), v.dot(dir))
+            })
+            .collect();
+        let extent = 1.0 - margin;
+        let lateral = |v: &Vector3| f64::max(v.x.abs(), v.y.abs());
+        // The nearest corner is kept away from the eye for the degenerate boxes.
+        let min_depth = 0.1 * radius;
+        let (distance, screen_size) = match self.projection_type {
+            ProjectionType::Perspective => {
+                let scale = self.projection[1][1];
+                let distance = corners.iter().fold(f64::NEG_INFINITY, |distance, v| {
+                    let depth = f64::max(scale * lateral(v) / extent, min_depth);
+                    f64::max(distance, depth - v.z)
+                });
+                (distance, None)
+            }
+            ProjectionType::Parallel => {
+                let half = corners
+                    .iter()
+                    .fold(0.0, |half, v| f64::max(half, lateral(v)));
+                let half = match half > TOLERANCE {
+                    true => half / extent,
+                    false => radius,
+                };
+                let distance = corners.iter().fold(f64::NEG_INFINITY, |distance, v| {
+                    f64::max(distance, min_depth - v.z)
+                });
+                (distance, Some(2.0 * half))
+            }
+        };
+        let (near, far) = corners.iter().fold((f64::INFINITY, 0.0), |(near, far), v| {
+            (
+                f64::min(near, distance + v.z),
+                f64::max(far, distance + v.z),
+            )
+        });
+        self.matrix[3] = (center - distance * dir).to_homogeneous();
+        let padding = 0.5 * min_depth;
+        self.reset_projection(screen_size, near - padding, far + padding);
+    }
+
+    /// Rotates the camera around the point `around`.
+    ///
+    /// The camera is rotated by `yaw` radians around the head direction and `pitch` radians
+    /// around the x-axis of the camera matrix, the right direction of the screen. Both axes pass
+    /// through `around`, so the distance between the camera and `around` is kept.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    ///
+    /// let mut camera = Camera::default();
+    /// camera.matrix = Matrix4::from_translation(Vector3::new(0.0, 0.0, 3.0));
+    /// camera.orbit(Point3::origin(), PI / 2.0, 0.0);
+    /// assert_near!(camera.position(), Point3::new(3.0, 0.0, 0.0));
+    /// assert_near!(camera.eye_direction(), -Vector3::unit_x());
+    /// ```
+    pub fn orbit(&mut self, around: Point3, yaw: f64, pitch: f64) {
+        let yaw = Matrix4::from_axis_angle(self.head_direction(), Rad(yaw));
+        let pitch = Matrix4::from_axis_angle(self.matrix[0].truncate(), Rad(pitch));
+        let around = around.to_vec();
+        self.matrix = Matrix4::from_translation(around)
+            * yaw
+            * pitch
+            * Matrix4::from_translation(-around)
+            * self.matrix;
+    }
+
+    /// Moves the camera in parallel with the screen.
+    ///
+    /// `delta` is the displacement along the x-axis of the camera matrix and the head direction.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    ///
+    /// let mut camera = Camera::default();
+    /// camera.pan(Vector2::new(1.0, 2.0));
+    /// assert_near!(camera.position(), Point3::new(1.0, 2.0, 0.0));
+    /// ```
+    pub fn pan(&mut self, delta: Vector2) {
+        let displacement = self.matrix[0] * delta.x + self.matrix[1] * delta.y;
+        self.matrix[3] += displacement;
+    }
+
+    /// Zooms in by `factor`, or zooms out if `factor` is less than 1.
+    ///
+    /// The points on the plane midway between the near and far clip planes, e.g. the center of
+    /// the bounding box after [`Camera::fit_to`], are projected to the screen scaled by `factor`.
+    /// The parallel camera divides the screen size by `factor`, and does not move. The
+    /// perspective camera moves to the midway plane, and the view volume is scaled by
+    /// `1 / factor` with the center at the midway plane. `factor` must be positive.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    ///
+    /// let mut camera = Camera::parallel_camera(Matrix4::identity(), 2.0, 1.0, 3.0);
+    /// let point = Point3::new(0.5, 0.25, -2.0);
+    /// let uvz = camera.projection(1.0).transform_point(point);
+    /// camera.zoom(2.0);
+    /// assert_near!(camera.position(), Point3::origin());
+    /// let uvz1 = camera.projection(1.0).transform_point(point);
+    /// assert_near!(uvz1.x, 2.0 * uvz.x);
+    /// assert_near!(uvz1.y, 2.0 * uvz.y);
+    /// ```
+    pub fn zoom(&mut self, factor: f64) {
+        let (near, far) = self.clip_distances();
+        match self.projection_type {
+            ProjectionType::Perspective => {
+                let midway = (near + far) / 2.0;
+                let displacement = self.eye_direction() * midway * (1.0 - 1.0 / factor);
+                self.matrix[3] += displacement.extend(0.0);
+                self.reset_projection(None, near / factor, far / factor);
+            }
+            ProjectionType::Parallel => {
+                let screen_size = 2.0 / self.projection[1][1] / factor;
+                self.reset_projection(Some(screen_size), near, far);
+            }
+        }
+    }
 }
 
 impl Default for Camera {
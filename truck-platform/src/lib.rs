@@ -58,6 +58,9 @@ use web_time::Instant as TimeInstant;
 /// maximum number of light
 pub const LIGHT_MAX: usize = 255;
 
+/// maximum number of clip planes
+pub const CLIP_PLANE_MAX: usize = 4;
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Zeroable, Pod)]
 struct CameraInfo {
@@ -82,6 +85,16 @@ struct SceneInfo {
     num_of_lights: u32,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct ClipInfo {
+    planes: [[f32; 4]; CLIP_PLANE_MAX],
+    cap_color: [f32; 4],
+    num_of_planes: u32,
+    cap: u32,
+    _padding: [u32; 2],
+}
+
 /// safe handler of GPU buffer
 /// [`Buffer`](https://docs.rs/wgpu/0.10.1/wgpu/struct.Buffer.html)
 #[derive(Debug)]
@@ -140,12 +153,25 @@ pub struct PreBindGroupLayoutEntry {
 pub struct RenderObject {
     vertex_buffer: Arc<BufferHandler>,
     index_buffer: Option<Arc<BufferHandler>>,
+    instance_buffer: Option<Arc<BufferHandler>>,
     pipeline: Arc<RenderPipeline>,
     bind_group_layout: Arc<BindGroupLayout>,
     bind_group: Arc<BindGroup>,
+    overlays: Vec<RenderObject>,
+    picks: Vec<RenderObject>,
+    transparent_center: Option<Point3>,
     visible: bool,
 }
 
+/// The element under a pixel, returned by [`Scene::render_pick_buffer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PickResult {
+    /// the render id of the object added to the scene
+    pub render_id: RenderID,
+    /// the id written by the pick object of the render object
+    pub id: u32,
+}
+
 /// the projection type of camera
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ProjectionType {
@@ -199,6 +225,17 @@ pub struct Light {
     pub light_type: LightType,
 }
 
+/// Clip plane of the scene
+///
+/// The half-space to which `normal` points is cut away from the instances.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClipPlane {
+    /// a point on the plane
+    pub origin: Point3,
+    /// the normal of the plane, pointing to the removed side
+    pub normal: Vector3,
+}
+
 /// Chain that holds [`Device`], [`Queue`] and [`SurfaceConfiguration`].
 ///
 /// This struct is used for creating [`Scene`].
@@ -275,6 +312,12 @@ pub struct SceneDescriptor {
     pub backend_buffer: BackendBufferConfig,
     /// Configuration for rendering texture
     pub render_texture: RenderTextureConfig,
+    /// Clip planes of the scene. Only the first [`CLIP_PLANE_MAX`] planes are applied.
+    /// Default is empty.
+    pub clip_planes: Vec<ClipPlane>,
+    /// If this parameter is `Some`, the back faces revealed by the clip planes are painted in
+    /// this color. Default is `None`.
+    pub clip_cap_color: Option<Color>,
 }
 
 /// Configures of [`WindowScene`](./struct.WindowScene.html).
@@ -285,6 +328,12 @@ pub struct WindowSceneDescriptor {
     pub studio: StudioConfig,
     /// Configures buffer preparation, depth and MSAA.
     pub backend_buffer: BackendBufferConfig,
+    /// Clip planes of the scene. Only the first [`CLIP_PLANE_MAX`] planes are applied.
+    /// Default is empty.
+    pub clip_planes: Vec<ClipPlane>,
+    /// If this parameter is `Some`, the back faces revealed by the clip planes are painted in
+    /// this color. Default is `None`.
+    pub clip_cap_color: Option<Color>,
 }
 
 /// Wraps `wgpu` and provides an intuitive graphics API.
@@ -324,6 +373,15 @@ pub trait Rendered {
         &self,
         device_handler: &DeviceHandler,
     ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>);
+    /// Creates the per-instance vertex buffer bound to the slot 1, if `self` is drawn instanced.
+    ///
+    /// `self` is drawn once for each element of the buffer, i.e. the number of the instances is
+    /// the size of the buffer divided by its stride. The instanced objects are not pickable.
+    ///
+    /// The default implementation returns `None`, i.e. `self` is drawn once.
+    fn instance_buffer(&self, _device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        None
+    }
     /// Creates the bind group layout.
     fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout>;
     /// Creates the bind group in `set = 1`.
@@ -339,27 +397,80 @@ pub trait Rendered {
         layout: &PipelineLayout,
         scene_descriptor: &SceneDescriptor,
     ) -> Arc<RenderPipeline>;
+    /// Returns the objects rendered just after `self` in the same render pass, e.g. the edges
+    /// drawn over the faces.
+    ///
+    /// The overlays are not registered to the scene by themselves. They share the visibility of
+    /// `self`, and are synchronized together with `self` by `Scene::update_*`. The number of the
+    /// overlays must not be changed after `self` is added to the scene.
+    ///
+    /// The default implementation returns no overlays.
+    fn overlays(&self) -> Vec<Box<dyn Rendered + '_>> { Vec::new() }
+    /// Returns the point in the world coordinates by which `self` is sorted, if `self` is
+    /// transparent.
+    ///
+    /// The scene renders the opaque objects first in the order of addition, and then the
+    /// transparent objects from back to front, i.e. in the descending order of the depths of
+    /// the points from the camera. The point is synchronized by `Scene::update_*`.
+    ///
+    /// The default implementation returns `None`, i.e. `self` is opaque.
+    fn transparent_center(&self) -> Option<Point3> { None }
+    /// Returns the objects rendered into the pick buffer by [`Scene::render_pick_buffer`].
+    ///
+    /// The pipelines of the pick objects are created with the scene descriptor whose texture
+    /// format is [`Scene::PICK_FORMAT`] and whose sample count is 1. The fragment shader writes
+    /// `vec2<u32>(instance_index, id)`, where `instance_index` is the builtin of the vertex
+    /// shader, which the scene uses to identify `self`, and `id` is an arbitrary id of the element
+    /// of `self`, returned as [`PickResult::id`].
+    ///
+    /// The default implementation returns no pick objects, i.e. `self` is not pickable.
+    fn pick_objects(&self) -> Vec<Box<dyn Rendered + '_>> { Vec::new() }
     #[doc(hidden)]
     fn render_object(&self, scene: &Scene) -> RenderObject {
-        let (vertex_buffer, index_buffer) = self.vertex_buffer(scene.device_handler());
-        let bind_group_layout = self.bind_group_layout(scene.device_handler());
-        let bind_group = self.bind_group(scene.device_handler(), &bind_group_layout);
-        let pipeline_layout = scene
-            .device()
-            .create_pipeline_layout(&PipelineLayoutDescriptor {
-                bind_group_layouts: &[&scene.bind_group_layout, &bind_group_layout],
-                push_constant_ranges: &[],
-                label: None,
-            });
-        let pipeline = self.pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
-        RenderObject {
-            vertex_buffer,
-            index_buffer,
-            bind_group_layout,
-            bind_group,
-            pipeline,
-            visible: true,
-        }
+        let mut render_object = create_render_object(self, scene, &scene.scene_desc);
+        let pick_desc = scene.scene_desc.pick_descriptor();
+        render_object.picks = self
+            .pick_objects()
+            .iter()
+            .map(|pick| create_render_object(pick.as_ref(), scene, &pick_desc))
+            .collect();
+        render_object
+    }
+}
+
+fn create_render_object<R: Rendered + ?Sized>(
+    object: &R,
+    scene: &Scene,
+    scene_desc: &SceneDescriptor,
+) -> RenderObject {
+    let (vertex_buffer, index_buffer) = object.vertex_buffer(scene.device_handler());
+    let instance_buffer = object.instance_buffer(scene.device_handler());
+    let bind_group_layout = object.bind_group_layout(scene.device_handler());
+    let bind_group = object.bind_group(scene.device_handler(), &bind_group_layout);
+    let pipeline_layout = scene
+        .device()
+        .create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &[&scene.bind_group_layout, &bind_group_layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+    let pipeline = object.pipeline(scene.device_handler(), &pipeline_layout, scene_desc);
+    let overlays = object
+        .overlays()
+        .iter()
+        .map(|overlay| create_render_object(overlay.as_ref(), scene, scene_desc))
+        .collect();
+    RenderObject {
+        vertex_buffer,
+        index_buffer,
+        instance_buffer,
+        bind_group_layout,
+        bind_group,
+        pipeline,
+        overlays,
+        picks: Vec::new(),
+        transparent_center: object.transparent_center(),
+        visible: true,
     }
 }
 
@@ -1,4 +1,5 @@
 use crate::*;
+use std::ops::Range;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use winit::window::Window;
 
@@ -245,6 +246,23 @@ impl SceneDescriptor {
         };
         (foward_depth, sampling_buffer)
     }
+
+    /// The descriptor for the pipelines of the pick objects.
+    pub(crate) fn pick_descriptor(&self) -> SceneDescriptor {
+        SceneDescriptor {
+            studio: self.studio.clone(),
+            backend_buffer: BackendBufferConfig {
+                depth_test: true,
+                sample_count: 1,
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: self.render_texture.canvas_size,
+                format: Scene::PICK_FORMAT,
+            },
+            clip_planes: self.clip_planes.clone(),
+            clip_cap_color: None,
+        }
+    }
 }
 
 /// Mutable reference of `SceneDescriptor` in `Scene`.
@@ -272,7 +290,114 @@ impl<'a> Drop for SceneDescriptorMut<'a> {
     }
 }
 
+impl RenderObject {
+    fn update_vertex_buffer(&mut self, object: &dyn Rendered, handler: &DeviceHandler) {
+        self.transparent_center = object.transparent_center();
+        (self.vertex_buffer, self.index_buffer) = object.vertex_buffer(handler);
+        self.instance_buffer = object.instance_buffer(handler);
+        let overlays = object.overlays();
+        self.overlays
+            .iter_mut()
+            .zip(&overlays)
+            .for_each(|(render_object, overlay)| {
+                render_object.update_vertex_buffer(overlay.as_ref(), handler)
+            });
+        let picks = object.pick_objects();
+        self.picks
+            .iter_mut()
+            .zip(&picks)
+            .for_each(|(render_object, pick)| {
+                render_object.update_vertex_buffer(pick.as_ref(), handler)
+            });
+    }
+
+    fn update_bind_group(&mut self, object: &dyn Rendered, handler: &DeviceHandler) {
+        self.transparent_center = object.transparent_center();
+        self.bind_group = object.bind_group(handler, &self.bind_group_layout);
+        let overlays = object.overlays();
+        self.overlays
+            .iter_mut()
+            .zip(&overlays)
+            .for_each(|(render_object, overlay)| {
+                render_object.update_bind_group(overlay.as_ref(), handler)
+            });
+        let picks = object.pick_objects();
+        self.picks
+            .iter_mut()
+            .zip(&picks)
+            .for_each(|(render_object, pick)| {
+                render_object.update_bind_group(pick.as_ref(), handler)
+            });
+    }
+
+    fn update_pipeline(
+        &mut self,
+        object: &dyn Rendered,
+        handler: &DeviceHandler,
+        scene_layout: &BindGroupLayout,
+        scene_desc: &SceneDescriptor,
+    ) {
+        self.transparent_center = object.transparent_center();
+        let pipeline_layout = handler
+            .device()
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                bind_group_layouts: &[scene_layout, &self.bind_group_layout],
+                push_constant_ranges: &[],
+                label: None,
+            });
+        self.pipeline = object.pipeline(handler, &pipeline_layout, scene_desc);
+        let overlays = object.overlays();
+        self.overlays
+            .iter_mut()
+            .zip(&overlays)
+            .for_each(|(render_object, overlay)| {
+                render_object.update_pipeline(overlay.as_ref(), handler, scene_layout, scene_desc)
+            });
+        let pick_desc = scene_desc.pick_descriptor();
+        let picks = object.pick_objects();
+        self.picks
+            .iter_mut()
+            .zip(&picks)
+            .for_each(|(render_object, pick)| {
+                render_object.update_pipeline(pick.as_ref(), handler, scene_layout, &pick_desc)
+            });
+    }
+
+    fn draw<'a>(&'a self, rpass: &mut RenderPass<'a>, instances: Range<u32>) {
+        let own_instances = match self.instance_buffer {
+            Some(ref instance_buffer) => 0..(instance_buffer.size / instance_buffer.stride) as u32,
+            None => instances.clone(),
+        };
+        if own_instances.is_empty() {
+            return;
+        }
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(1, &self.bind_group, &[]);
+        rpass.set_vertex_buffer(0, self.vertex_buffer.buffer.slice(..));
+        if let Some(ref instance_buffer) = self.instance_buffer {
+            rpass.set_vertex_buffer(1, instance_buffer.buffer.slice(..));
+        }
+        match self.index_buffer {
+            Some(ref index_buffer) => {
+                rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
+                rpass.draw_indexed(0..index_size, 0, own_instances);
+            }
+            None => rpass.draw(
+                0..(self.vertex_buffer.size / self.vertex_buffer.stride) as u32,
+                own_instances,
+            ),
+        }
+        self.overlays
+            .iter()
+            .for_each(|overlay| overlay.draw(rpass, instances.clone()));
+    }
+}
+
 impl Scene {
+    /// The texture format of the pick buffer rendered by [`Scene::render_pick_buffer`].
+    pub const PICK_FORMAT: TextureFormat = TextureFormat::Rg32Uint;
+
     #[inline(always)]
     fn camera_bgl_entry() -> PreBindGroupLayoutEntry {
         PreBindGroupLayoutEntry {
@@ -312,6 +437,19 @@ impl Scene {
         }
     }
 
+    #[inline(always)]
+    fn clip_planes_bgl_entry() -> PreBindGroupLayoutEntry {
+        PreBindGroupLayoutEntry {
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }
+    }
+
     #[inline(always)]
     fn init_scene_bind_group_layout(device: &Device) -> BindGroupLayout {
         bind_group_util::create_bind_group_layout(
@@ -320,6 +458,7 @@ impl Scene {
                 Self::camera_bgl_entry(),
                 Self::lights_bgl_entry(),
                 Self::scene_bgl_entry(),
+                Self::clip_planes_bgl_entry(),
             ],
         )
     }
@@ -472,6 +611,36 @@ impl Scene {
         BufferHandler::from_slice(&[scene_info], self.device(), BufferUsages::UNIFORM)
     }
 
+    /// Creates a `UNIFORM` buffer of the clip planes.
+    ///
+    /// The bind group provides [`Scene`] holds this uniform buffer.
+    ///
+    /// # Shader Example
+    /// ```glsl
+    /// layout(set = 0, binding = 3) uniform ClipPlanes {
+    ///     vec4 planes[4];  // `vec4(n, -dot(n, o))` for the normal `n` and the origin `o`
+    ///     vec4 cap_color;  // the color of the back faces revealed by the clip planes
+    ///     uint nplanes;    // the number of clip planes
+    ///     uint cap;        // 1 if the back faces are painted by `cap_color`, otherwise 0
+    /// };
+    /// ```
+    #[inline(always)]
+    pub fn clip_planes_buffer(&self) -> BufferHandler {
+        let mut clip_info = ClipInfo::zeroed();
+        let planes = self.scene_desc.clip_planes.iter().take(CLIP_PLANE_MAX);
+        planes.enumerate().for_each(|(i, plane)| {
+            let normal = plane.normal.normalize();
+            let w = -normal.dot(plane.origin.to_vec());
+            clip_info.planes[i] = normal.extend(w).cast::<f32>().unwrap().into();
+            clip_info.num_of_planes += 1;
+        });
+        if let Some(c) = self.scene_desc.clip_cap_color {
+            clip_info.cap_color = [c.r as f32, c.g as f32, c.b as f32, c.a as f32];
+            clip_info.cap = 1;
+        }
+        BufferHandler::from_slice(&[clip_info], self.device(), BufferUsages::UNIFORM)
+    }
+
     /// Creates bind group.
     /// # Shader Examples
     /// Suppose binded as `set = 0`.
@@ -495,6 +664,13 @@ impl Scene {
     ///     float time;     // elapsed time since the scene was created.
     ///     uint nlights;   // the number of lights
     /// };
+    ///
+    /// layout(set = 0, binding = 3) uniform ClipPlanes {
+    ///     vec4 planes[4]; // the clip planes
+    ///     vec4 cap_color; // the color of the back faces revealed by the clip planes
+    ///     uint nplanes;   // the number of clip planes
+    ///     uint cap;       // 1 if the back faces are painted by `cap_color`
+    /// };
     /// ```
     #[inline(always)]
     pub fn scene_bind_group(&self) -> BindGroup {
@@ -505,6 +681,7 @@ impl Scene {
                 self.camera_buffer().binding_resource(),
                 self.lights_buffer().binding_resource(),
                 self.scene_status_buffer().binding_resource(),
+                self.clip_planes_buffer().binding_resource(),
             ],
         )
     }
@@ -579,9 +756,7 @@ impl Scene {
         match objects.get_mut(&object.render_id()) {
             None => false,
             Some(render_object) => {
-                let (vb, ib) = object.vertex_buffer(handler);
-                render_object.vertex_buffer = vb;
-                render_object.index_buffer = ib;
+                render_object.update_vertex_buffer(object, handler);
                 true
             }
         }
@@ -609,8 +784,7 @@ impl Scene {
         let (handler, objects) = (&self.device_handler, &mut self.objects);
         match objects.get_mut(&object.render_id()) {
             Some(render_object) => {
-                let bind_group = object.bind_group(handler, &render_object.bind_group_layout);
-                render_object.bind_group = bind_group;
+                render_object.update_bind_group(object, handler);
                 true
             }
             _ => false,
@@ -637,17 +811,8 @@ impl Scene {
         let (handler, objects) = (&self.device_handler, &mut self.objects);
         match objects.get_mut(&object.render_id()) {
             Some(render_object) => {
-                let device = handler.device();
-                let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
-                    bind_group_layouts: &[
-                        &self.bind_group_layout,
-                        &render_object.bind_group_layout,
-                    ],
-                    push_constant_ranges: &[],
-                    label: None,
-                });
-                render_object.pipeline =
-                    object.pipeline(handler, &pipeline_layout, &self.scene_desc);
+                let layout = &self.bind_group_layout;
+                render_object.update_pipeline(object, handler, layout, &self.scene_desc);
                 true
             }
             _ => false,
@@ -679,6 +844,24 @@ impl Scene {
         }
     }
 
+    /// Returns the visible objects: the opaque ones in the order of addition,
+    /// and then the transparent ones from back to front.
+    fn sorted_objects(&self) -> Vec<&RenderObject> {
+        let objects = self.objects.as_slice().iter().map(|(_, object)| object);
+        let (mut objects, mut transparents): (Vec<_>, Vec<_>) = objects
+            .filter(|object| object.visible)
+            .partition(|object| object.transparent_center.is_none());
+        let camera = &self.scene_desc.studio.camera;
+        let (position, direction) = (camera.position(), camera.eye_direction());
+        let depth = |object: &&RenderObject| match object.transparent_center {
+            Some(center) => (center - position).dot(direction),
+            None => 0.0,
+        };
+        transparents.sort_by(|a, b| f64::total_cmp(&depth(b), &depth(a)));
+        objects.extend(transparents);
+        objects
+    }
+
     /// Renders the scene to `view`.
     pub fn render(&self, view: &TextureView) {
         let bind_group = self.scene_bind_group();
@@ -713,26 +896,9 @@ impl Scene {
                 ..Default::default()
             });
             rpass.set_bind_group(0, &bind_group, &[]);
-            for (_, object) in &self.objects {
-                if !object.visible {
-                    continue;
-                }
-                rpass.set_pipeline(&object.pipeline);
-                rpass.set_bind_group(1, &object.bind_group, &[]);
-                rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
-                match object.index_buffer {
-                    Some(ref index_buffer) => {
-                        rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
-                        let index_size =
-                            index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
-                        rpass.draw_indexed(0..index_size, 0, 0..1);
-                    }
-                    None => rpass.draw(
-                        0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
-                        0..1,
-                    ),
-                }
-            }
+            self.sorted_objects()
+                .into_iter()
+                .for_each(|object| object.draw(&mut rpass, 0..1));
         }
         self.queue().submit(vec![encoder.finish()]);
     }
@@ -785,6 +951,125 @@ impl Scene {
             None => panic!("Asynchronous processing fails"),
         }
     }
+
+    /// Returns the element under the pixel `position`, counted from the top-left corner of the
+    /// canvas.
+    ///
+    /// The pick objects of the visible render objects are rendered into the integer texture,
+    /// and the pixel is read back. Returns `None` if no pickable object is under the pixel, or
+    /// `position` is out of the canvas.
+    ///
+    /// # Remarks
+    ///
+    /// This method waits for the GPU to finish the rendering, i.e. stalls the pipeline.
+    /// It is meant for handling clicks, not for hovering in every frame. On the web, the
+    /// GPU cannot be waited for, so this method always returns `None`.
+    pub fn render_pick_buffer(&self, position: (u32, u32)) -> Option<PickResult> {
+        let (width, height) = self.scene_desc.render_texture.canvas_size;
+        if position.0 >= width || position.1 >= height {
+            return None;
+        }
+        let (device, queue) = (self.device(), self.queue());
+        let texture_desc = |format: TextureFormat, usage: TextureUsages| TextureDescriptor {
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage,
+            view_formats: &[],
+            label: None,
+        };
+        let usage = TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC;
+        let texture = device.create_texture(&texture_desc(Self::PICK_FORMAT, usage));
+        let depth = device.create_texture(&texture_desc(
+            TextureFormat::Depth32Float,
+            TextureUsages::RENDER_ATTACHMENT,
+        ));
+        let view = texture.create_view(&Default::default());
+        let depth_view = depth.create_view(&Default::default());
+        let bind_group = self.scene_bind_group();
+        // The instance index `i + 1` identifies `objects[i]`. Zero is the background.
+        let objects: Vec<_> = self
+            .objects
+            .as_slice()
+            .iter()
+            .filter(|(_, object)| object.visible && !object.picks.is_empty())
+            .collect();
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        {
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::TRANSPARENT),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(Self::depth_stencil_attachment_descriptor(
+                    &depth_view,
+                )),
+                ..Default::default()
+            });
+            rpass.set_scissor_rect(position.0, position.1, 1, 1);
+            rpass.set_bind_group(0, &bind_group, &[]);
+            for (i, (_, object)) in objects.iter().enumerate() {
+                let instance = i as u32 + 1;
+                object
+                    .picks
+                    .iter()
+                    .for_each(|pick| pick.draw(&mut rpass, instance..instance + 1));
+            }
+        }
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: None,
+            mapped_at_creation: false,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            size: 8,
+        });
+        encoder.copy_texture_to_buffer(
+            ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: Origin3d {
+                    x: position.0,
+                    y: position.1,
+                    z: 0,
+                },
+                aspect: TextureAspect::All,
+            },
+            ImageCopyBuffer {
+                buffer: &buffer,
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: None,
+                    rows_per_image: None,
+                },
+            },
+            Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit(Some(encoder.finish()));
+        let buffer_slice = buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        buffer_slice.map_async(MapMode::Read, move |v| sender.send(v).unwrap());
+        device.poll(Maintain::Wait);
+        receiver.try_recv().ok()?.ok()?;
+        let pixel: [u32; 2] = bytemuck::pod_read_unaligned(&buffer_slice.get_mapped_range());
+        let (render_id, _) = objects.get((pixel[0] as usize).checked_sub(1)?)?;
+        Some(PickResult {
+            render_id: *render_id,
+            id: pixel[1],
+        })
+    }
 }
 
 impl WindowScene {
@@ -808,6 +1093,8 @@ impl WindowScene {
                     studio: scene_desc.studio.clone(),
                     backend_buffer: scene_desc.backend_buffer,
                     render_texture,
+                    clip_planes: scene_desc.clip_planes.clone(),
+                    clip_cap_color: scene_desc.clip_cap_color,
                 },
             ),
             window_handler,
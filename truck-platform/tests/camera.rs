@@ -0,0 +1,157 @@
+use std::f64::consts::PI;
+use truck_base::{assert_near, bounding_box::BoundingBox, cgmath64::*, tolerance::*};
+use truck_platform::*;
+
+const MARGIN: f64 = 0.1;
+
+fn cameras() -> Vec<Camera> {
+    let matrices = [
+        Matrix4::identity(),
+        Matrix4::look_at_rh(
+            Point3::new(1.0, 2.5, 2.0),
+            Point3::new(0.0, 0.0, 0.0),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Matrix4::from_translation(Vector3::new(5.0, -3.0, 1.0))
+            * Matrix4::from_axis_angle(Vector3::new(1.0, -2.0, 0.5).normalize(), Rad(2.0)),
+    ];
+    matrices
+        .into_iter()
+        .flat_map(|matrix| {
+            [
+                Camera::perspective_camera(matrix, Rad(PI / 4.0), 0.1, 10.0),
+                Camera::perspective_camera(matrix, Rad(PI / 2.0), 1.0, 2.0),
+                Camera::parallel_camera(matrix, 1.0, 0.1, 10.0),
+            ]
+        })
+        .collect()
+}
+
+fn corners(bbox: &BoundingBox<Point3>) -> Vec<Point3> {
+    let (min, max) = (bbox.min(), bbox.max());
+    (0..8)
+        .map(|i| {
+            Point3::new(
+                [min.x, max.x][i & 1],
+                [min.y, max.y][(i >> 1) & 1],
+                [min.z, max.z][i >> 2],
+            )
+        })
+        .collect()
+}
+
+fn boxes() -> Vec<(BoundingBox<Point3>, bool)> {
+    let bbox = |min: [f64; 3], max: [f64; 3]| -> BoundingBox<Point3> {
+        [Point3::from(min), Point3::from(max)].into_iter().collect()
+    };
+    vec![
+        // cube
+        (bbox([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]), true),
+        // long box
+        (bbox([-3.0, 1.0, 2.0], [8.0, 1.5, 2.1]), true),
+        // flat boxes
+        (bbox([-1.0, -1.0, 0.0], [1.0, 1.0, 0.0]), true),
+        (bbox([2.0, 0.0, -1.0], [2.0, 3.0, 1.0]), true),
+        // segment
+        (bbox([0.0, 0.0, -1.0], [0.0, 0.0, 1.0]), false),
+        // point
+        (bbox([1.0, 2.0, 3.0], [1.0, 2.0, 3.0]), false),
+    ]
+}
+
+#[test]
+fn fit_to() {
+    for camera in cameras() {
+        for (bbox, filled) in boxes() {
+            let mut camera = camera;
+            let eye_direction = camera.eye_direction();
+            camera.fit_to(&bbox, MARGIN);
+            assert_near!(camera.eye_direction(), eye_direction);
+
+            let projection = camera.projection(1.0);
+            let max = corners(&bbox).into_iter().fold(0.0, |max, corner| {
+                let uvz = projection.transform_point(corner);
+                assert!(uvz.x.abs() <= 1.0 - MARGIN + TOLERANCE, "{uvz:?}");
+                assert!(uvz.y.abs() <= 1.0 - MARGIN + TOLERANCE, "{uvz:?}");
+                assert!(-1.0 < uvz.z && uvz.z < 1.0, "{uvz:?}");
+                // The corners are in front of the camera.
+                assert!((corner - camera.position()).dot(eye_direction) > 0.0);
+                f64::max(max, f64::max(uvz.x.abs(), uvz.y.abs()))
+            });
+            // The box fills the view.
+            if filled {
+                assert_near!(max, 1.0 - MARGIN);
+            }
+        }
+    }
+}
+
+#[test]
+fn fit_to_empty() {
+    let mut camera = Camera::default();
+    camera.fit_to(&BoundingBox::new(), MARGIN);
+    assert_near!(camera.matrix, Camera::default().matrix);
+    assert_near!(camera.projection(1.0), Camera::default().projection(1.0));
+}
+
+#[test]
+fn orbit_keeps_distance() {
+    let around = Point3::new(0.5, 0.5, 0.5);
+    for mut camera in cameras() {
+        let distance = camera.position().distance(around);
+        let uvz = camera.projection(1.0).transform_point(around);
+        camera.orbit(around, 0.7, -0.3);
+        assert_near!(camera.position().distance(around), distance);
+        // `around` is fixed on the screen.
+        let uvz1 = camera.projection(1.0).transform_point(around);
+        assert_near!(uvz, uvz1);
+    }
+}
+
+#[test]
+fn pan_moves_screen() {
+    for mut camera in cameras() {
+        let point = camera.position() + camera.eye_direction() * 2.0;
+        let uvz = camera.projection(1.0).transform_point(point);
+        camera.pan(Vector2::new(0.1, -0.2));
+        let uvz1 = camera.projection(1.0).transform_point(point);
+        assert!(uvz1.x < uvz.x && uvz1.y > uvz.y);
+        assert_near!(uvz.z, uvz1.z);
+    }
+}
+
+#[test]
+fn zoom_scales_midway_plane() {
+    for mut camera in cameras() {
+        camera.fit_to(&boxes()[0].0, MARGIN);
+        let position = camera.position();
+        let point = position
+            + camera.eye_direction() * midway_distance(&camera)
+            + camera.matrix[0].truncate() * 0.3
+            - camera.head_direction() * 0.2;
+        let uvz = camera.projection(1.0).transform_point(point);
+
+        camera.zoom(1.5);
+        let uvz1 = camera.projection(1.0).transform_point(point);
+        assert_near!(uvz1.x, 1.5 * uvz.x);
+        assert_near!(uvz1.y, 1.5 * uvz.y);
+        if camera.projection_type() == ProjectionType::Parallel {
+            assert_near!(camera.position(), position);
+        }
+    }
+}
+
+// the distance between the camera and the plane midway between the clip planes
+fn midway_distance(camera: &Camera) -> f64 {
+    let near_z = match camera.projection_type() {
+        ProjectionType::Perspective => -1.0,
+        ProjectionType::Parallel => 0.0,
+    };
+    let inv = camera.projection(1.0).invert().unwrap();
+    let near = inv.transform_point(Point3::new(0.0, 0.0, near_z));
+    let far = inv.transform_point(Point3::new(0.0, 0.0, 1.0));
+    let dir = camera.eye_direction();
+    ((near - camera.position()).dot(dir) + (far - camera.position()).dot(dir)) / 2.0
+}
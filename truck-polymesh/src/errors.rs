@@ -83,6 +83,88 @@ pub enum Error<V: std::fmt::Debug = crate::StandardVertex> {
     /// Errors caused by obj files I/O.
     #[error(transparent)]
     FromIO(#[from] std::io::Error),
+    /// Syntax errors in text mesh files, i.e. obj and off files.
+    #[error(transparent)]
+    FromObj(#[from] ObjError),
+}
+
+/// Syntax errors in text mesh files, i.e. obj and off files.
+///
+/// Each variant carries the 1-based line number and the snippet of the offending line.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use errors::{Error, ObjError};
+///
+/// let obj = b"v 0.0 0.0 0.0
+/// v 1.0 0.0 0.0
+/// f 1 2 0
+/// ";
+/// match obj::read(obj.as_slice()) {
+///     Err(Error::FromObj(ObjError::InvalidFaceIndex { line, index, .. })) => {
+///         assert_eq!(line, 3);
+///         assert_eq!(index, 0);
+///     }
+///     _ => panic!("wrong result!"),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ObjError {
+    /// A number cannot be parsed.
+    #[error("line {line}: invalid number `{token}` in `{text}`")]
+    InvalidNumber {
+        /// 1-based line number
+        line: usize,
+        /// snippet of the line
+        text: String,
+        /// the token which cannot be parsed
+        token: String,
+    },
+    /// The line has too few values.
+    #[error("line {line}: missing value in `{text}`")]
+    MissingValue {
+        /// 1-based line number
+        line: usize,
+        /// snippet of the line
+        text: String,
+    },
+    /// An index of a face is zero or out of range.
+    #[error("line {line}: invalid index {index} in `{text}`")]
+    InvalidFaceIndex {
+        /// 1-based line number
+        line: usize,
+        /// snippet of the line
+        text: String,
+        /// the index written in the file
+        index: isize,
+    },
+    /// The header of the off file is invalid.
+    #[error("line {line}: invalid header `{text}`")]
+    InvalidHeader {
+        /// 1-based line number
+        line: usize,
+        /// snippet of the line
+        text: String,
+    },
+    /// The file ends before all the declared elements are read.
+    #[error("line {line}: unexpected end of file")]
+    UnexpectedEof {
+        /// 1-based line number of the last line
+        line: usize,
+    },
+}
+
+impl ObjError {
+    const SNIPPET_LENGTH: usize = 80;
+
+    /// Returns the snippet of `line` embedded in the errors.
+    pub(crate) fn snippet(line: &str) -> String {
+        let line = line.trim();
+        match line.char_indices().nth(Self::SNIPPET_LENGTH) {
+            Some((idx, _)) => format!("{}...", &line[..idx]),
+            None => line.to_string(),
+        }
+    }
 }
 
 impl From<std::num::ParseFloatError> for Error {
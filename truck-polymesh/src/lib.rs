@@ -109,10 +109,16 @@ pub mod faces;
 mod meshing_shape;
 /// wavefront obj I/O
 pub mod obj;
+/// OFF I/O
+pub mod off;
+/// PLY I/O
+pub mod ply;
 /// Defines [`PolygonMeshEditor`](./polygon_mesh/struct.PolygonMeshEditor.html).
 pub mod polygon_mesh;
 /// Defines generalized polyline curve.
 pub mod polyline_curve;
+/// Streaming output of polygon meshes
+pub mod sink;
 /// STL I/O
 pub mod stl;
 mod structured_mesh;
@@ -1,4 +1,5 @@
 use crate::*;
+use errors::ObjError;
 use std::io::{BufRead, BufReader, BufWriter, Read, Write};
 type Vertex = StandardVertex;
 type Result<T> = std::result::Result<T, errors::Error>;
@@ -50,7 +51,7 @@ type Result<T> = std::result::Result<T, errors::Error>;
 /// obj::write(&mesh, std::fs::File::create("meshdata.obj").unwrap());
 /// ```
 pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
-    sub_write(mesh, &mut BufWriter::new(writer))
+    write_with_options(mesh, writer, ObjWriteOptions::default())
 }
 
 /// Writes obj data to output stream
@@ -63,13 +64,273 @@ pub fn write_vec<W: Write>(mesh: &[PolygonMesh], writer: W) -> Result<()> {
     Ok(())
 }
 
+/// Options for [`write_with_options`] and [`write_objects`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjWriteOptions {
+    /// The name of the object, written by `o`. Ignored by [`write_objects`].
+    pub object_name: Option<String>,
+    /// The file name of the material library, written by `mtllib`.
+    pub material_library: Option<String>,
+    /// The groups of the faces, written by `g` and `usemtl`.
+    pub groups: Vec<ObjGroup>,
+    /// The number of digits after the decimal point of the coordinates. The default is `10`.
+    pub precision: usize,
+    /// If `true`, the uv coordinates are written by `vt`. The default is `true`.
+    pub write_uv_coords: bool,
+    /// If `true`, the normals are written by `vn`. The default is `true`.
+    pub write_normals: bool,
+}
+
+impl Default for ObjWriteOptions {
+    fn default() -> Self {
+        Self {
+            object_name: None,
+            material_library: None,
+            groups: Vec::new(),
+            precision: 10,
+            write_uv_coords: true,
+            write_normals: true,
+        }
+    }
+}
+
+/// A named group of the faces in the obj file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjGroup {
+    /// the name of the group
+    pub name: String,
+    /// the name of the material used by the faces in the group
+    pub material: Option<String>,
+    /// The range of the indices of the faces in the group.
+    ///
+    /// The faces are indexed in the order of [`Faces::face_iter`]. In [`write_objects`],
+    /// the faces are counted throughout all objects.
+    pub faces: std::ops::Range<usize>,
+}
+
+/// Writes obj data to output stream with options.
+/// # Remarks
+/// - The faces which are not contained in any group are written in the group `default`,
+/// unless they are at the beginning of the object.
+/// - If the uv coordinates or the normals are not written, the corresponding indices are
+/// removed from the faces.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use obj::{ObjGroup, ObjWriteOptions};
+///
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+/// ];
+/// let faces = Faces::from_iter(&[[0, 1, 2], [2, 1, 3]]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+/// let options = ObjWriteOptions {
+///     object_name: Some("square".to_string()),
+///     material_library: Some("square.mtl".to_string()),
+///     groups: vec![
+///         ObjGroup {
+///             name: "lower".to_string(),
+///             material: Some("red".to_string()),
+///             faces: 0..1,
+///         },
+///         ObjGroup {
+///             name: "upper".to_string(),
+///             material: Some("blue".to_string()),
+///             faces: 1..2,
+///         },
+///     ],
+///     precision: 3,
+///     ..Default::default()
+/// };
+/// let mut output = Vec::new();
+/// obj::write_with_options(&mesh, &mut output, options).unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.starts_with("mtllib square.mtl\no square\nv 0.000e0 0.000e0 0.000e0\n"));
+/// assert!(output.ends_with("g lower\nusemtl red\nf 1 2 3\ng upper\nusemtl blue\nf 3 2 4\n"));
+/// assert_eq!(obj::read(output.as_bytes()).unwrap(), mesh);
+/// ```
+pub fn write_with_options<W: Write>(
+    mesh: &PolygonMesh,
+    writer: W,
+    options: ObjWriteOptions,
+) -> Result<()> {
+    let name = options.object_name.clone();
+    sub_write_objects(&[(name.as_deref(), mesh)], writer, options)
+}
+
+/// Writes several named meshes to one obj file as objects.
+///
+/// The indices of the attributes in the faces are offset by the numbers of the attributes in
+/// the preceding objects. `options.object_name` is ignored.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+///
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let mut output = Vec::new();
+/// obj::write_objects(&[("first", &mesh), ("second", &mesh)], &mut output, Default::default())
+///     .unwrap();
+///
+/// let read_mesh = obj::read(output.as_slice()).unwrap();
+/// assert_eq!(read_mesh.positions().len(), 6);
+/// assert_eq!(read_mesh.tri_faces()[1], [3, 4, 5].map(StandardVertex::from));
+/// ```
+pub fn write_objects<W: Write>(
+    objects: &[(&str, &PolygonMesh)],
+    writer: W,
+    options: ObjWriteOptions,
+) -> Result<()> {
+    let objects = objects
+        .iter()
+        .map(|(name, mesh)| (Some(*name), *mesh))
+        .collect::<Vec<_>>();
+    sub_write_objects(&objects, writer, options)
+}
+
+fn sub_write_objects<W: Write>(
+    objects: &[(Option<&str>, &PolygonMesh)],
+    writer: W,
+    options: ObjWriteOptions,
+) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    let ObjWriteOptions {
+        material_library,
+        mut groups,
+        precision,
+        write_uv_coords,
+        write_normals,
+        ..
+    } = options;
+    groups.sort_by_key(|group| group.faces.start);
+    if let Some(material_library) = material_library {
+        writer.write_fmt(format_args!("mtllib {material_library}\n"))?;
+    }
+    let mut groups = GroupWriter::new(&groups);
+    let (mut pos_offset, mut uv_offset, mut nor_offset) = (0, 0, 0);
+    for (name, mesh) in objects {
+        if let Some(name) = name {
+            writer.write_fmt(format_args!("o {name}\n"))?;
+            groups.current = None;
+        }
+        write3vec(&mut writer, mesh.positions(), "v", precision)?;
+        let write_uv_coords = write_uv_coords && !mesh.uv_coords().is_empty();
+        if write_uv_coords {
+            write2vec(&mut writer, mesh.uv_coords(), "vt", precision)?;
+        }
+        let write_normals = write_normals && !mesh.normals().is_empty();
+        if write_normals {
+            write3vec(&mut writer, mesh.normals(), "vn", precision)?;
+        }
+        for face in mesh.face_iter() {
+            groups.write_header(&mut writer)?;
+            writer.write_all(b"f")?;
+            for v in face {
+                let v = Vertex {
+                    pos: v.pos + pos_offset,
+                    uv: v.uv.filter(|_| write_uv_coords).map(|uv| uv + uv_offset),
+                    nor: v.nor.filter(|_| write_normals).map(|nor| nor + nor_offset),
+                };
+                writer.write_all(b" ")?;
+                v.write(&mut writer)?;
+            }
+            writer.write_all(b"\n")?;
+        }
+        pos_offset += mesh.positions().len();
+        if write_uv_coords {
+            uv_offset += mesh.uv_coords().len();
+        }
+        if write_normals {
+            nor_offset += mesh.normals().len();
+        }
+    }
+    Ok(())
+}
+
+/// Writes `g` and `usemtl` when the group changes.
+struct GroupWriter<'a> {
+    groups: &'a [ObjGroup],
+    // the index of the next face
+    face_index: usize,
+    // the index of the first group which may contain the next face
+    group_index: usize,
+    // the group of the last face, `usize::MAX` for the default group, or `None` at the beginning
+    // of each object.
+    current: Option<usize>,
+}
+
+impl<'a> GroupWriter<'a> {
+    fn new(groups: &'a [ObjGroup]) -> Self {
+        Self {
+            groups,
+            face_index: 0,
+            group_index: 0,
+            current: None,
+        }
+    }
+
+    fn write_header<W: Write>(&mut self, writer: &mut W) -> Result<()> {
+        let groups = self.groups;
+        while self.group_index < groups.len()
+            && groups[self.group_index].faces.end <= self.face_index
+        {
+            self.group_index += 1;
+        }
+        let group_index = self.group_index;
+        let group = match groups.get(group_index) {
+            Some(group) if group.faces.contains(&self.face_index) => group_index,
+            _ => usize::MAX,
+        };
+        self.face_index += 1;
+        match (self.current, group) {
+            (Some(current), _) if current == group => {}
+            (None, usize::MAX) => self.current = Some(group),
+            _ => {
+                self.current = Some(group);
+                match groups.get(group) {
+                    Some(ObjGroup { name, material, .. }) => {
+                        writer.write_fmt(format_args!("g {name}\n"))?;
+                        if let Some(material) = material {
+                            writer.write_fmt(format_args!("usemtl {material}\n"))?;
+                        }
+                    }
+                    None => writer.write_all(b"g default\n")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 fn write2vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
     writer: &mut BufWriter<W>,
     vecs: &[V],
     prefix: &str,
+    precision: usize,
 ) -> Result<()> {
     for vec in vecs {
-        writer.write_fmt(format_args!("{} {:.10e} {:.10e}\n", prefix, vec[0], vec[1]))?;
+        writer.write_fmt(format_args!(
+            "{prefix} {:.precision$e} {:.precision$e}\n",
+            vec[0], vec[1]
+        ))?;
     }
     Ok(())
 }
@@ -78,11 +339,12 @@ fn write3vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
     writer: &mut BufWriter<W>,
     vecs: &[V],
     prefix: &str,
+    precision: usize,
 ) -> Result<()> {
     for vec in vecs {
         writer.write_fmt(format_args!(
-            "{} {:.10e} {:.10e} {:.10e}\n",
-            prefix, vec[0], vec[1], vec[2]
+            "{prefix} {:.precision$e} {:.precision$e} {:.precision$e}\n",
+            vec[0], vec[1], vec[2]
         ))?;
     }
     Ok(())
@@ -101,78 +363,144 @@ impl Vertex {
     }
 }
 
+fn write_face<W: Write>(writer: &mut W, face: &[Vertex]) -> Result<()> {
+    writer.write_all(b"f")?;
+    for v in face {
+        writer.write_all(b" ")?;
+        v.write(writer)?;
+    }
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
 impl Faces {
     fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
-        for face in self.face_iter() {
-            writer.write_all(b"f")?;
-            for v in face {
-                writer.write_all(b" ")?;
-                v.write(writer)?;
-            }
-            writer.write_all(b"\n")?;
-        }
-        Ok(())
+        self.face_iter()
+            .try_for_each(|face| write_face(writer, face))
     }
 }
 
 fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<()> {
-    write3vec(writer, mesh.positions(), "v")?;
-    write2vec(writer, mesh.uv_coords(), "vt")?;
-    write3vec(writer, mesh.normals(), "vn")?;
+    write3vec(writer, mesh.positions(), "v", 10)?;
+    write2vec(writer, mesh.uv_coords(), "vt", 10)?;
+    write3vec(writer, mesh.normals(), "vn", 10)?;
     mesh.faces.write(writer)
 }
 
+/// [`MeshSink`] writing obj data to output stream.
+///
+/// The attributes and the faces are written in the order they are put, with the same format
+/// as [`write`].
+///
+/// [`MeshSink`]: ../sink/trait.MeshSink.html
+#[derive(Debug)]
+pub struct ObjSink<W: Write> {
+    writer: BufWriter<W>,
+    counts: [usize; 3],
+}
+
+impl<W: Write> ObjSink<W> {
+    /// Creates a sink writing to `writer` with the default buffer capacity.
+    #[inline(always)]
+    pub fn new(writer: W) -> Self { Self::from_buf_writer(BufWriter::new(writer)) }
+
+    /// Creates a sink writing to `writer`, whose buffer is flushed every `capacity` bytes.
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize, writer: W) -> Self {
+        Self::from_buf_writer(BufWriter::with_capacity(capacity, writer))
+    }
+
+    fn from_buf_writer(writer: BufWriter<W>) -> Self {
+        Self {
+            writer,
+            counts: [0; 3],
+        }
+    }
+}
+
+impl<W: Write> sink::MeshSink for ObjSink<W> {
+    fn put_vertex(&mut self, position: Point3) -> Result<()> {
+        self.counts[0] += 1;
+        write3vec(&mut self.writer, &[position], "v", 10)
+    }
+    fn put_uv(&mut self, uv_coord: Vector2) -> Result<()> {
+        self.counts[1] += 1;
+        write2vec(&mut self.writer, &[uv_coord], "vt", 10)
+    }
+    fn put_normal(&mut self, normal: Vector3) -> Result<()> {
+        self.counts[2] += 1;
+        write3vec(&mut self.writer, &[normal], "vn", 10)
+    }
+    fn put_face(&mut self, face: &[Vertex]) -> Result<()> {
+        match face.len() < 3 {
+            true => Ok(()),
+            false => write_face(&mut self.writer, face),
+        }
+    }
+    fn counts(&self) -> [usize; 3] { self.counts }
+    fn finish(&mut self) -> Result<()> { Ok(self.writer.flush()?) }
+}
+
 /// Reads mesh data from wavefront obj file.
+///
+/// Negative indices in `f` statements are supported, which refer to the attributes relative to
+/// the end of the ones read so far.
+/// # Errors
+/// Returns [`ObjError`] with the line number if a statement is malformed.
+///
+/// [`ObjError`]: ../errors/enum.ObjError.html
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let obj = b"v 0.0 0.0 0.0
+/// v 1.0 0.0 0.0
+/// v 0.0 1.0 0.0
+/// f -3 -2 -1
+/// ";
+/// let mesh = obj::read(obj.as_slice()).unwrap();
+/// assert_eq!(mesh.tri_faces(), &[[0, 1, 2].map(StandardVertex::from)]);
+/// ```
 pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
     let mut positions = Vec::new();
     let mut uv_coords = Vec::new();
     let mut normals = Vec::new();
     let mut faces = Faces::default();
     let reader = BufReader::new(reader);
-    for line in reader.lines().map(|s| s.unwrap()) {
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let parser = LineParser {
+            text: &line,
+            line: idx + 1,
+        };
         let mut args = line.split_whitespace();
-        if let Some(first_str) = args.next() {
-            if first_str == "v" {
-                let x = args.next().unwrap().parse::<f64>()?;
-                let y = args.next().unwrap().parse::<f64>()?;
-                let z = args.next().unwrap().parse::<f64>()?;
+        match args.next() {
+            Some("v") => {
+                let [x, y, z] = parser.floats(&mut args)?;
                 positions.push(Point3::new(x, y, z));
-            } else if first_str == "vt" {
-                let u = args.next().unwrap().parse::<f64>()?;
-                let v = args.next().unwrap().parse::<f64>()?;
+            }
+            Some("vt") => {
+                let [u, v] = parser.floats(&mut args)?;
                 uv_coords.push(Vector2::new(u, v));
-            } else if first_str == "vn" {
-                let x = args.next().unwrap().parse::<f64>()?;
-                let y = args.next().unwrap().parse::<f64>()?;
-                let z = args.next().unwrap().parse::<f64>()?;
+            }
+            Some("vn") => {
+                let [x, y, z] = parser.floats(&mut args)?;
                 normals.push(Vector3::new(x, y, z));
-            } else if first_str == "f" {
+            }
+            Some("f") => {
                 let mut face = Vec::new();
-                for vert_str in args {
-                    if &vert_str[0..1] == "#" {
-                        break;
-                    }
+                for vert_str in args.take_while(|s| !s.starts_with('#')) {
                     let mut iter = vert_str.split('/');
-                    let pos = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let uv = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let nor = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let vert = match (pos, uv, nor) {
-                        (None, _, _) => continue,
-                        (Some(pos), uv, nor) => Vertex { pos, uv, nor },
+                    let pos = parser.index(iter.next(), positions.len())?;
+                    let uv = parser.index(iter.next(), uv_coords.len())?;
+                    let nor = parser.index(iter.next(), normals.len())?;
+                    let Some(pos) = pos else {
+                        return Err(parser.missing_value().into());
                     };
-                    face.push(vert);
+                    face.push(Vertex { pos, uv, nor });
                 }
                 faces.push(face);
             }
+            _ => {}
         }
     }
     PolygonMesh::try_new(
@@ -184,3 +512,77 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
         faces,
     )
 }
+
+/// Parser of the values in a line of text mesh files, which makes errors with the line number.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LineParser<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) line: usize,
+}
+
+impl<'a> LineParser<'a> {
+    pub(crate) fn missing_value(self) -> ObjError {
+        ObjError::MissingValue {
+            line: self.line,
+            text: ObjError::snippet(self.text),
+        }
+    }
+
+    pub(crate) fn invalid_number(self, token: &str) -> ObjError {
+        ObjError::InvalidNumber {
+            line: self.line,
+            text: ObjError::snippet(self.text),
+            token: token.to_string(),
+        }
+    }
+
+    pub(crate) fn invalid_index(self, index: isize) -> ObjError {
+        ObjError::InvalidFaceIndex {
+            line: self.line,
+            text: ObjError::snippet(self.text),
+            index,
+        }
+    }
+
+    /// Parses the next token of `args`.
+    pub(crate) fn parse<T: std::str::FromStr>(
+        self,
+        args: &mut impl Iterator<Item = &'a str>,
+    ) -> std::result::Result<T, ObjError> {
+        let token = args.next().ok_or_else(|| self.missing_value())?;
+        token.parse().map_err(|_| self.invalid_number(token))
+    }
+
+    /// Parses the next `N` floats of `args`.
+    pub(crate) fn floats<const N: usize>(
+        self,
+        args: &mut impl Iterator<Item = &'a str>,
+    ) -> std::result::Result<[f64; N], ObjError> {
+        let mut res = [0.0; N];
+        res.iter_mut().try_for_each(|x| {
+            *x = self.parse(args)?;
+            Ok(())
+        })?;
+        Ok(res)
+    }
+
+    /// Parses the 1-based index of obj files. The negative index is relative to `len`.
+    fn index(
+        self,
+        token: Option<&str>,
+        len: usize,
+    ) -> std::result::Result<Option<usize>, ObjError> {
+        let Some(token) = token.filter(|token| !token.is_empty()) else {
+            return Ok(None);
+        };
+        let index = token
+            .parse::<isize>()
+            .map_err(|_| self.invalid_number(token))?;
+        let resolved = match index.signum() {
+            1 => Some(index as usize - 1),
+            -1 => len.checked_sub(index.unsigned_abs()),
+            _ => None,
+        };
+        resolved.map(Some).ok_or_else(|| self.invalid_index(index))
+    }
+}
@@ -0,0 +1,158 @@
+use crate::*;
+use errors::ObjError;
+use obj::LineParser;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+type Vertex = StandardVertex;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+/// Writes off data to output stream.
+///
+/// Only the positions and the faces are written, since the off format has no room for the
+/// normals and the texture coordinates which are attached to the vertices of faces.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2, 3]]),
+/// );
+/// let mut off = Vec::new();
+/// off::write(&mesh, &mut off).unwrap();
+/// assert_eq!(off::read(off.as_slice()).unwrap(), mesh);
+/// ```
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    writeln!(writer, "OFF")?;
+    writeln!(
+        writer,
+        "{} {} 0",
+        mesh.positions().len(),
+        mesh.faces().len()
+    )?;
+    for p in mesh.positions() {
+        writeln!(writer, "{:.10} {:.10} {:.10}", p[0], p[1], p[2])?;
+    }
+    for face in mesh.face_iter() {
+        write!(writer, "{}", face.len())?;
+        for v in face {
+            write!(writer, " {}", v.pos)?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads mesh data from off file.
+///
+/// The headers `OFF`, `COFF`, `NOFF` and `CNOFF` are supported. The normals of `NOFF` files
+/// are attached to the vertices of faces with the same indices as the positions, and the
+/// colors are ignored.
+/// # Errors
+/// Returns [`ObjError`] with the line number if the file is malformed.
+///
+/// [`ObjError`]: ../errors/enum.ObjError.html
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    let reader = BufReader::new(reader);
+    let mut lines = reader.lines().enumerate().filter_map(|(idx, line)| {
+        let mut line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e)),
+        };
+        if let Some(pos) = line.find('#') {
+            line.truncate(pos);
+        }
+        match line.trim().is_empty() {
+            true => None,
+            false => Some(Ok((idx + 1, line))),
+        }
+    });
+    let mut last_line = 0;
+    let mut next_line = || -> Result<(usize, String)> {
+        match lines.next() {
+            Some(line) => {
+                let line = line?;
+                last_line = line.0;
+                Ok(line)
+            }
+            None => Err(ObjError::UnexpectedEof { line: last_line }.into()),
+        }
+    };
+
+    let (line, text) = next_line()?;
+    let mut args = text.split_whitespace();
+    let has_normals = match args.next() {
+        Some("OFF") | Some("COFF") => false,
+        Some("NOFF") | Some("CNOFF") => true,
+        _ => {
+            let text = ObjError::snippet(&text);
+            return Err(ObjError::InvalidHeader { line, text }.into());
+        }
+    };
+    // The counts may be written in the same line as the keyword.
+    let (line, text) = match args.next().is_some() {
+        true => (line, text),
+        false => next_line()?,
+    };
+    let parser = LineParser { text: &text, line };
+    let mut args = text.split_whitespace().skip_while(|s| s.ends_with("OFF"));
+    let num_positions: usize = parser.parse(&mut args)?;
+    let num_faces: usize = parser.parse(&mut args)?;
+
+    let mut positions = Vec::with_capacity(num_positions);
+    let mut normals = Vec::new();
+    for _ in 0..num_positions {
+        let (line, text) = next_line()?;
+        let parser = LineParser { text: &text, line };
+        let mut args = text.split_whitespace();
+        let [x, y, z] = parser.floats(&mut args)?;
+        positions.push(Point3::new(x, y, z));
+        if has_normals {
+            let [x, y, z] = parser.floats(&mut args)?;
+            normals.push(Vector3::new(x, y, z));
+        }
+    }
+
+    let mut faces = Faces::default();
+    for _ in 0..num_faces {
+        let (line, text) = next_line()?;
+        let parser = LineParser { text: &text, line };
+        let mut args = text.split_whitespace();
+        let len: usize = parser.parse(&mut args)?;
+        let face = (0..len)
+            .map(|_| {
+                let index: isize = parser.parse(&mut args)?;
+                match 0 <= index && (index as usize) < num_positions {
+                    true => Ok(index as usize),
+                    false => Err(parser.invalid_index(index)),
+                }
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        faces.push(
+            face.into_iter()
+                .map(|pos| Vertex {
+                    pos,
+                    uv: None,
+                    nor: has_normals.then_some(pos),
+                })
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    PolygonMesh::try_new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
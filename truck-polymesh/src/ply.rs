@@ -0,0 +1,510 @@
+use crate::*;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+type Vertex = StandardVertex;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+fn invalid_data(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+/// PLY format
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlyFormat {
+    /// ASCII format
+    #[default]
+    Ascii,
+    /// binary little endian format
+    BinaryLittleEndian,
+}
+
+/// The scalar types of the properties in PLY files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlyScalarType {
+    /// `char` or `int8`
+    Char,
+    /// `uchar` or `uint8`
+    UChar,
+    /// `short` or `int16`
+    Short,
+    /// `ushort` or `uint16`
+    UShort,
+    /// `int` or `int32`
+    Int,
+    /// `uint` or `uint32`
+    UInt,
+    /// `float` or `float32`
+    Float,
+    /// `double` or `float64`
+    #[default]
+    Double,
+}
+
+impl PlyScalarType {
+    fn parse(name: &str) -> Result<Self> {
+        match name {
+            "char" | "int8" => Ok(Self::Char),
+            "uchar" | "uint8" => Ok(Self::UChar),
+            "short" | "int16" => Ok(Self::Short),
+            "ushort" | "uint16" => Ok(Self::UShort),
+            "int" | "int32" => Ok(Self::Int),
+            "uint" | "uint32" => Ok(Self::UInt),
+            "float" | "float32" => Ok(Self::Float),
+            "double" | "float64" => Ok(Self::Double),
+            _ => Err(invalid_data("unknown scalar type").into()),
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Char => "char",
+            Self::UChar => "uchar",
+            Self::Short => "short",
+            Self::UShort => "ushort",
+            Self::Int => "int",
+            Self::UInt => "uint",
+            Self::Float => "float",
+            Self::Double => "double",
+        }
+    }
+
+    fn write<W: Write>(self, writer: &mut W, value: f64, format: PlyFormat) -> Result<()> {
+        match format {
+            PlyFormat::Ascii => match self {
+                Self::Float | Self::Double => writer.write_fmt(format_args!("{value}"))?,
+                _ => writer.write_fmt(format_args!("{}", value as i64))?,
+            },
+            PlyFormat::BinaryLittleEndian => match self {
+                Self::Char => writer.write_all(&(value as i8).to_le_bytes())?,
+                Self::UChar => writer.write_all(&(value as u8).to_le_bytes())?,
+                Self::Short => writer.write_all(&(value as i16).to_le_bytes())?,
+                Self::UShort => writer.write_all(&(value as u16).to_le_bytes())?,
+                Self::Int => writer.write_all(&(value as i32).to_le_bytes())?,
+                Self::UInt => writer.write_all(&(value as u32).to_le_bytes())?,
+                Self::Float => writer.write_all(&(value as f32).to_le_bytes())?,
+                Self::Double => writer.write_all(&value.to_le_bytes())?,
+            },
+        }
+        Ok(())
+    }
+}
+
+/// The per-vertex properties in PLY files other than positions, normals, and uv coordinates.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlyExtras {
+    /// The values of the properties, e.g. `red`, `green`, `blue`, or `quality`.
+    /// The length of each vector is the number of the positions.
+    pub vertex_props: HashMap<String, Vec<f64>>,
+    /// The scalar types of the properties. The properties not contained in this map are
+    /// written as `double`.
+    pub vertex_prop_types: HashMap<String, PlyScalarType>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PropertyType {
+    Scalar(PlyScalarType),
+    List(PlyScalarType, PlyScalarType),
+}
+
+#[derive(Clone, Debug)]
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<(String, PropertyType)>,
+}
+
+enum DataReader<'a, R> {
+    Ascii(std::str::SplitWhitespace<'a>),
+    Binary(&'a mut R),
+}
+
+impl<R: Read> DataReader<'_, R> {
+    fn read_scalar(&mut self, scalar_type: PlyScalarType) -> Result<f64> {
+        let reader = match self {
+            DataReader::Ascii(tokens) => {
+                let token = tokens.next().ok_or_else(|| invalid_data("too few data"))?;
+                return Ok(token.parse::<f64>()?);
+            }
+            DataReader::Binary(reader) => reader,
+        };
+        macro_rules! read_le {
+            ($ty: ty) => {{
+                let mut bytes = [0; std::mem::size_of::<$ty>()];
+                reader.read_exact(&mut bytes)?;
+                f64::from(<$ty>::from_le_bytes(bytes))
+            }};
+        }
+        Ok(match scalar_type {
+            PlyScalarType::Char => read_le!(i8),
+            PlyScalarType::UChar => read_le!(u8),
+            PlyScalarType::Short => read_le!(i16),
+            PlyScalarType::UShort => read_le!(u16),
+            PlyScalarType::Int => read_le!(i32),
+            PlyScalarType::UInt => read_le!(u32),
+            PlyScalarType::Float => read_le!(f32),
+            PlyScalarType::Double => read_le!(f64),
+        })
+    }
+
+    fn read_list(
+        &mut self,
+        count_type: PlyScalarType,
+        item_type: PlyScalarType,
+    ) -> Result<Vec<f64>> {
+        let count = self.read_scalar(count_type)?;
+        if count < 0.0 {
+            return Err(invalid_data("negative length of list").into());
+        }
+        (0..count as usize)
+            .map(|_| self.read_scalar(item_type))
+            .collect()
+    }
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<(PlyFormat, Vec<PlyElement>)> {
+    let mut lines = reader.lines();
+    let mut next_line = || {
+        lines
+            .next()
+            .unwrap_or_else(|| Err(invalid_data("unexpected end of header")))
+    };
+    if next_line()?.trim() != "ply" {
+        return Err(invalid_data("not a PLY file").into());
+    }
+    let mut format = None;
+    let mut elements = Vec::<PlyElement>::new();
+    loop {
+        let line = next_line()?;
+        let args = line.split_whitespace().collect::<Vec<_>>();
+        match args.as_slice() {
+            ["end_header"] => break,
+            ["format", "ascii", _] => format = Some(PlyFormat::Ascii),
+            ["format", "binary_little_endian", _] => format = Some(PlyFormat::BinaryLittleEndian),
+            ["format", ..] => return Err(invalid_data("unsupported format").into()),
+            ["element", name, count] => elements.push(PlyElement {
+                name: name.to_string(),
+                count: count.parse()?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count_type, item_type, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| invalid_data("property before element"))?;
+                let property_type = PropertyType::List(
+                    PlyScalarType::parse(count_type)?,
+                    PlyScalarType::parse(item_type)?,
+                );
+                element.properties.push((name.to_string(), property_type));
+            }
+            ["property", scalar_type, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| invalid_data("property before element"))?;
+                let property_type = PropertyType::Scalar(PlyScalarType::parse(scalar_type)?);
+                element.properties.push((name.to_string(), property_type));
+            }
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            _ => return Err(invalid_data("syntax error in header").into()),
+        }
+    }
+    let format = format.ok_or_else(|| invalid_data("no format"))?;
+    Ok((format, elements))
+}
+
+/// Reads PLY data in ASCII or binary little endian format.
+///
+/// # Details
+/// - The properties `x`, `y`, `z` of `vertex` are mapped to the positions, `nx`, `ny`, `nz` to
+/// the normals, and `u`, `v` (or `s`, `t`) to the uv coordinates. The other scalar properties
+/// of `vertex` are returned as [`PlyExtras`].
+/// - The list property `vertex_indices` (or `vertex_index`) of `face` is mapped to the faces.
+/// Since [`Faces`] holds triangles, quadrangles, and the other polygons separately, the order
+/// of the faces may be changed. The faces with less than three vertices are ignored.
+/// - The other elements and properties, e.g. `edge`, are skipped.
+pub fn read<R: Read>(reader: R) -> Result<(PolygonMesh, PlyExtras)> {
+    let mut reader = BufReader::new(reader);
+    let (format, elements) = read_header(&mut reader)?;
+    let text;
+    let mut data_reader = match format {
+        PlyFormat::Ascii => {
+            let mut string = String::new();
+            reader.read_to_string(&mut string)?;
+            text = string;
+            DataReader::Ascii(text.split_whitespace())
+        }
+        PlyFormat::BinaryLittleEndian => DataReader::Binary(&mut reader),
+    };
+
+    let mut columns = Vec::<(String, PlyScalarType, Vec<f64>)>::new();
+    let mut faces = Faces::default();
+    for element in &elements {
+        let is_vertex = element.name == "vertex";
+        let is_face = element.name == "face";
+        if is_vertex {
+            columns = element
+                .properties
+                .iter()
+                .filter_map(|(name, property_type)| match property_type {
+                    PropertyType::Scalar(scalar_type) => Some((
+                        name.clone(),
+                        *scalar_type,
+                        Vec::with_capacity(element.count),
+                    )),
+                    PropertyType::List(..) => None,
+                })
+                .collect();
+        }
+        for _ in 0..element.count {
+            let mut column_index = 0;
+            for (name, property_type) in &element.properties {
+                match *property_type {
+                    PropertyType::Scalar(scalar_type) => {
+                        let value = data_reader.read_scalar(scalar_type)?;
+                        if is_vertex {
+                            columns[column_index].2.push(value);
+                            column_index += 1;
+                        }
+                    }
+                    PropertyType::List(count_type, item_type) => {
+                        let list = data_reader.read_list(count_type, item_type)?;
+                        if is_face && (name == "vertex_indices" || name == "vertex_index") {
+                            let face = list
+                                .into_iter()
+                                .map(|idx| match idx >= 0.0 {
+                                    true => Ok(idx as usize),
+                                    false => Err(invalid_data("negative vertex index")),
+                                })
+                                .collect::<std::result::Result<Vec<_>, _>>()?;
+                            faces.push(face);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Removes the columns only if all of them exist.
+    let mut take_columns = |names: &[&str]| {
+        let position = |columns: &[(String, _, _)], name: &str| {
+            columns.iter().position(|(name0, _, _)| name0 == name)
+        };
+        if names.iter().any(|name| position(&columns, name).is_none()) {
+            return None;
+        }
+        let taken = names
+            .iter()
+            .map(|name| columns.remove(position(&columns, name).unwrap()).2)
+            .collect::<Vec<_>>();
+        Some(taken)
+    };
+    let positions = match take_columns(&["x", "y", "z"]) {
+        Some(xyz) => zip_columns::<Point3, 3>(xyz),
+        None if elements.iter().all(|e| e.name != "vertex" || e.count == 0) => Vec::new(),
+        None => return Err(invalid_data("no positions").into()),
+    };
+    let normals = match take_columns(&["nx", "ny", "nz"]) {
+        Some(columns) => zip_columns::<Vector3, 3>(columns),
+        None => Vec::new(),
+    };
+    let uv_coords = match take_columns(&["u", "v"]).or_else(|| take_columns(&["s", "t"])) {
+        Some(columns) => zip_columns::<Vector2, 2>(columns),
+        None => Vec::new(),
+    };
+    let (has_uv, has_normals) = (!uv_coords.is_empty(), !normals.is_empty());
+    faces.face_iter_mut().flatten().for_each(|v| {
+        v.uv = has_uv.then_some(v.pos);
+        v.nor = has_normals.then_some(v.pos);
+    });
+    let extras = PlyExtras {
+        vertex_prop_types: columns
+            .iter()
+            .map(|(name, scalar_type, _)| (name.clone(), *scalar_type))
+            .collect(),
+        vertex_props: columns
+            .into_iter()
+            .map(|(name, _, values)| (name, values))
+            .collect(),
+    };
+    let attributes = StandardAttributes {
+        positions,
+        uv_coords,
+        normals,
+    };
+    Ok((PolygonMesh::try_new(attributes, faces)?, extras))
+}
+
+fn zip_columns<T: From<[f64; N]>, const N: usize>(columns: Vec<Vec<f64>>) -> Vec<T> {
+    let len = columns[0].len();
+    (0..len)
+        .map(|i| T::from(std::array::from_fn(|j| columns[j][i])))
+        .collect()
+}
+
+/// Writes PLY data in `format`.
+///
+/// # Details
+/// - Each vertex of PLY consists of a position, and a normal and a uv coordinate if the mesh has
+/// them. Positions which are used with several pairs of a normal and a uv coordinate are
+/// duplicated.
+/// - The properties in `extras` are written in the alphabetical order of the names.
+/// - Returns an error if the length of some property in `extras` is different from the number
+/// of the positions.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use ply::{PlyExtras, PlyFormat, PlyScalarType};
+///
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let mut extras = PlyExtras::default();
+/// extras.vertex_props.insert("red".to_string(), vec![255.0, 0.0, 0.0]);
+/// extras.vertex_prop_types.insert("red".to_string(), PlyScalarType::UChar);
+///
+/// let mut bytes = Vec::new();
+/// ply::write(&mesh, &extras, &mut bytes, PlyFormat::Ascii).unwrap();
+/// let text = String::from_utf8(bytes.clone()).unwrap();
+/// assert!(text.contains("property uchar red\n"));
+///
+/// let (read_mesh, read_extras) = ply::read(bytes.as_slice()).unwrap();
+/// assert_eq!(read_mesh, mesh);
+/// assert_eq!(read_extras, extras);
+/// ```
+pub fn write<W: Write>(
+    mesh: &PolygonMesh,
+    extras: &PlyExtras,
+    writer: W,
+    format: PlyFormat,
+) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    let len = mesh.positions().len();
+    if extras
+        .vertex_props
+        .values()
+        .any(|values| values.len() != len)
+    {
+        let error = std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "the length of a property is different from the number of positions",
+        );
+        return Err(error.into());
+    }
+    let mut props = extras.vertex_props.iter().collect::<Vec<_>>();
+    props.sort_by(|(name0, _), (name1, _)| name0.cmp(name1));
+    let props = props
+        .into_iter()
+        .map(|(name, values)| {
+            let scalar_type = extras.vertex_prop_types.get(name).copied();
+            (name, scalar_type.unwrap_or_default(), values)
+        })
+        .collect::<Vec<_>>();
+
+    let (has_uv, has_normals) = (!mesh.uv_coords().is_empty(), !mesh.normals().is_empty());
+    let (vertices, faces) = ply_vertices(mesh);
+    let max_face_len = faces.iter().map(Vec::len).max().unwrap_or(0);
+    let count_type = match max_face_len <= u8::MAX as usize {
+        true => PlyScalarType::UChar,
+        false => PlyScalarType::UInt,
+    };
+
+    let format_name = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+    };
+    writer.write_fmt(format_args!("ply\nformat {format_name} 1.0\n"))?;
+    writer.write_fmt(format_args!("element vertex {}\n", vertices.len()))?;
+    let mut vertex_properties = Vec::<(&str, PlyScalarType)>::new();
+    vertex_properties.extend(["x", "y", "z"].map(|name| (name, PlyScalarType::Double)));
+    if has_normals {
+        vertex_properties.extend(["nx", "ny", "nz"].map(|name| (name, PlyScalarType::Double)));
+    }
+    if has_uv {
+        vertex_properties.extend(["u", "v"].map(|name| (name, PlyScalarType::Double)));
+    }
+    vertex_properties.extend(props.iter().map(|(name, ty, _)| (name.as_str(), *ty)));
+    for (name, scalar_type) in &vertex_properties {
+        writer.write_fmt(format_args!("property {} {name}\n", scalar_type.name()))?;
+    }
+    writer.write_fmt(format_args!("element face {}\n", faces.len()))?;
+    writer.write_fmt(format_args!(
+        "property list {} int vertex_indices\nend_header\n",
+        count_type.name()
+    ))?;
+
+    let separator = |writer: &mut BufWriter<W>, last: bool| -> Result<()> {
+        if format == PlyFormat::Ascii {
+            writer.write_all(if last { b"\n" } else { b" " })?;
+        }
+        Ok(())
+    };
+    for v in &vertices {
+        let mut values = Vec::with_capacity(vertex_properties.len());
+        values.extend(<[f64; 3]>::from(mesh.positions()[v.pos]));
+        if has_normals {
+            let normal = v
+                .nor
+                .map(|nor| mesh.normals()[nor])
+                .unwrap_or_else(Vector3::zero);
+            values.extend(<[f64; 3]>::from(normal));
+        }
+        if has_uv {
+            let uv =
+                v.uv.map(|uv| mesh.uv_coords()[uv])
+                    .unwrap_or_else(Vector2::zero);
+            values.extend(<[f64; 2]>::from(uv));
+        }
+        values.extend(props.iter().map(|(_, _, prop_values)| prop_values[v.pos]));
+        for (i, (value, (_, scalar_type))) in values.iter().zip(&vertex_properties).enumerate() {
+            scalar_type.write(&mut writer, *value, format)?;
+            separator(&mut writer, i + 1 == values.len())?;
+        }
+    }
+    for face in &faces {
+        count_type.write(&mut writer, face.len() as f64, format)?;
+        separator(&mut writer, face.is_empty())?;
+        for (i, idx) in face.iter().enumerate() {
+            PlyScalarType::Int.write(&mut writer, *idx as f64, format)?;
+            separator(&mut writer, i + 1 == face.len())?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the vertices of PLY and the faces consisting of the indices of them.
+///
+/// The `i`-th vertex of PLY is the `i`-th position, if the position is used. The positions used
+/// with several pairs of a normal and a uv coordinate are duplicated at the end.
+fn ply_vertices(mesh: &PolygonMesh) -> (Vec<Vertex>, Vec<Vec<usize>>) {
+    let mut vertices = (0..mesh.positions().len())
+        .map(Vertex::from)
+        .collect::<Vec<_>>();
+    let mut assigned = vec![false; vertices.len()];
+    let mut vertex_map = HashMap::<Vertex, usize>::new();
+    let faces = mesh
+        .face_iter()
+        .map(|face| {
+            face.iter()
+                .map(|v| {
+                    if !assigned[v.pos] {
+                        assigned[v.pos] = true;
+                        vertices[v.pos] = *v;
+                        vertex_map.insert(*v, v.pos);
+                    }
+                    *vertex_map.entry(*v).or_insert_with(|| {
+                        vertices.push(*v);
+                        vertices.len() - 1
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    (vertices, faces)
+}
@@ -0,0 +1,82 @@
+use crate::*;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+/// Receiver of the attributes and the faces of a polygon mesh, one by one.
+///
+/// The formatted data can be written to the output stream as soon as it is received,
+/// so the whole mesh does not have to be kept in the memory.
+///
+/// The indices of the vertices of the faces refer to the attributes put so far,
+/// counted from zero at the creation of the sink.
+pub trait MeshSink {
+    /// Puts the position of a vertex.
+    fn put_vertex(&mut self, position: Point3) -> Result<()>;
+    /// Puts a texture coordinate.
+    fn put_uv(&mut self, uv_coord: Vector2) -> Result<()>;
+    /// Puts a normal vector.
+    fn put_normal(&mut self, normal: Vector3) -> Result<()>;
+    /// Puts a face.
+    fn put_face(&mut self, face: &[StandardVertex]) -> Result<()>;
+    /// Returns the numbers of positions, texture coordinates, and normals put so far.
+    fn counts(&self) -> [usize; 3];
+    /// Writes the footer, if any, and flushes the output stream.
+    fn finish(&mut self) -> Result<()>;
+}
+
+impl PolygonMesh {
+    /// Streams the mesh to `sink`.
+    ///
+    /// The indices of the faces are offset by the numbers of the attributes already put to
+    /// `sink`, so several meshes can be streamed to the same sink. [`MeshSink::finish`] is
+    /// not called.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use obj::ObjSink;
+    /// use sink::MeshSink;
+    ///
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    ///
+    /// let mut streamed = Vec::new();
+    /// let mut sink = ObjSink::new(&mut streamed);
+    /// mesh.stream_to(&mut sink).unwrap();
+    /// sink.finish().unwrap();
+    /// drop(sink);
+    ///
+    /// let mut written = Vec::new();
+    /// obj::write(&mesh, &mut written).unwrap();
+    /// assert_eq!(streamed, written);
+    /// ```
+    pub fn stream_to<S: MeshSink>(&self, sink: &mut S) -> Result<()> {
+        let [pos_offset, uv_offset, nor_offset] = sink.counts();
+        self.positions()
+            .iter()
+            .try_for_each(|p| sink.put_vertex(*p))?;
+        self.uv_coords()
+            .iter()
+            .try_for_each(|uv| sink.put_uv(*uv))?;
+        self.normals()
+            .iter()
+            .try_for_each(|n| sink.put_normal(*n))?;
+        let mut buffer = Vec::new();
+        self.face_iter().try_for_each(|face| {
+            buffer.clear();
+            buffer.extend(face.iter().map(|v| StandardVertex {
+                pos: v.pos + pos_offset,
+                uv: v.uv.map(|uv| uv + uv_offset),
+                nor: v.nor.map(|nor| nor + nor_offset),
+            }));
+            sink.put_face(&buffer)
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use crate::*;
 use bytemuck::{Pod, Zeroable};
 use rustc_hash::FxHashMap as HashMap;
-use std::io::{BufRead, BufReader, Lines, Read, Write};
+use std::io::{BufRead, BufReader, BufWriter, Chain, Cursor, Lines, Read, Write};
 
 const FACESIZE: usize = std::mem::size_of::<StlFace>();
 const CHUNKSIZE: usize = FACESIZE + 2;
@@ -28,13 +28,16 @@ impl StlFace {
     fn is_empty(&self) -> bool { self == &StlFace::default() }
 }
 
+/// the stream whose bytes already read for determining the STL type are put back
+type Source<R> = Chain<Cursor<Vec<u8>>, R>;
+
 /// STL reading iterator.
 #[derive(Debug)]
 pub enum StlReader<R: Read> {
     #[doc(hidden)]
-    Ascii(Lines<BufReader<R>>),
+    Ascii(Lines<BufReader<Source<R>>>),
     #[doc(hidden)]
-    Binary(R, usize),
+    Binary(Source<R>, usize),
 }
 
 /// STL type.
@@ -44,7 +47,9 @@ pub enum StlType {
     ///
     /// # Reading
     /// If the first 5 bytes are..
-    /// - "solid" => ascii format
+    /// - "solid" => ascii format, unless the length of the data is equal to the one of the
+    /// binary format whose number of faces is written in the header. In order to check it,
+    /// the whole data is read into the memory in this case.
     /// - otherwise => binary format
     ///
     /// # Writing
@@ -59,27 +64,42 @@ pub enum StlType {
 
 impl<R: Read> StlReader<R> {
     #[inline(always)]
-    fn text_reader(reader: R) -> StlReader<R> { StlReader::Ascii(BufReader::new(reader).lines()) }
-    fn binary_reader(mut reader: R, header_judge: bool) -> Result<StlReader<R>> {
-        let mut header = [0; 5];
-        reader.read_exact(&mut header)?;
-        if header_judge && &header == b"solid" {
-            return Ok(Self::text_reader(reader));
-        }
-        let mut header = [0; 75];
+    fn text_reader(reader: Source<R>) -> StlReader<R> {
+        StlReader::Ascii(BufReader::new(reader).lines())
+    }
+    fn binary_reader(mut reader: Source<R>) -> Result<StlReader<R>> {
+        let mut header = [0; 80];
         reader.read_exact(&mut header)?;
         let mut length_bytes = [0; 4];
         reader.read_exact(&mut length_bytes)?;
         let length = u32::from_le_bytes(length_bytes) as usize;
         Ok(StlReader::Binary(reader, length))
     }
+    fn automatic_reader(mut reader: R) -> Result<StlReader<R>> {
+        let mut bytes = vec![0; 5];
+        reader.read_exact(&mut bytes)?;
+        if bytes != b"solid" {
+            return Self::binary_reader(Cursor::new(bytes).chain(reader));
+        }
+        // Some binary files also begin with "solid".
+        reader.read_to_end(&mut bytes)?;
+        let is_binary = bytes.len() >= 84 && {
+            let length = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]);
+            bytes.len() == 84 + CHUNKSIZE * length as usize
+        };
+        let reader = Cursor::new(bytes).chain(reader);
+        match is_binary {
+            true => Self::binary_reader(reader),
+            false => Ok(Self::text_reader(reader)),
+        }
+    }
     /// Creates new STL reader.
     #[inline(always)]
     pub fn new(reader: R, stl_type: StlType) -> Result<Self> {
         match stl_type {
-            StlType::Automatic => Self::binary_reader(reader, true),
-            StlType::Binary => Self::binary_reader(reader, false),
-            StlType::Ascii => Ok(Self::text_reader(reader)),
+            StlType::Automatic => Self::automatic_reader(reader),
+            StlType::Binary => Self::binary_reader(Cursor::new(Vec::new()).chain(reader)),
+            StlType::Ascii => Ok(Self::text_reader(Cursor::new(Vec::new()).chain(reader))),
         }
     }
     /// Returns the STL type.
@@ -126,19 +146,15 @@ fn ascii_one_read<R: BufRead>(lines: &mut Lines<R>) -> Result<Option<StlFace>> {
             },
         };
         let line = line.trim();
-        if line.len() < 8 {
-            continue;
-        } else if &line[0..5] == "facet" {
-            let args: Vec<_> = line.split_whitespace().collect();
-            face.normal = array![i => args[i + 2].parse::<f32>()?; 3];
-        } else if &line[0..6] == "vertex" {
-            let args: Vec<_> = line.split_whitespace().collect();
+        if line.starts_with("facet") {
+            face.normal = parse_vector(line.split_whitespace().skip(2))?;
+        } else if line.starts_with("vertex") {
             if num_ver > 2 {
                 return Err(syntax_error().into());
             }
-            face.vertices[num_ver] = array![i => args[i + 1].parse::<f32>()?; 3];
+            face.vertices[num_ver] = parse_vector(line.split_whitespace().skip(1))?;
             num_ver += 1;
-        } else if &line[0..8] == "endfacet" {
+        } else if line.starts_with("endfacet") {
             if num_ver != 3 {
                 return Err(syntax_error().into());
             }
@@ -147,16 +163,20 @@ fn ascii_one_read<R: BufRead>(lines: &mut Lines<R>) -> Result<Option<StlFace>> {
     }
 }
 
+fn parse_vector<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<[f32; 3]> {
+    let mut vector = [0.0; 3];
+    for x in &mut vector {
+        *x = args.next().ok_or_else(syntax_error)?.parse::<f32>()?;
+    }
+    Ok(vector)
+}
+
 fn binary_one_read<R: Read>(reader: &mut R) -> Result<Option<StlFace>> {
     let mut chunk = [0; CHUNKSIZE];
-    let size = reader.read(&mut chunk)?;
-    if size == CHUNKSIZE {
-        let mut buf = [0; FACESIZE];
-        buf.copy_from_slice(&chunk[..FACESIZE]);
-        Ok(Some(bytemuck::cast(buf)))
-    } else {
-        Err(syntax_error().into())
-    }
+    reader.read_exact(&mut chunk).map_err(|_| syntax_error())?;
+    let mut buf = [0; FACESIZE];
+    buf.copy_from_slice(&chunk[..FACESIZE]);
+    Ok(Some(bytemuck::cast(buf)))
 }
 
 /// Write STL file in `stl_type` format.
@@ -178,22 +198,24 @@ pub fn write<I: IntoStlIterator, W: Write>(
 fn write_ascii<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result<()> {
     let mut iter = iter.into_iter();
     writer.write_all(b"solid\n")?;
-    iter.try_for_each::<_, Result<()>>(|face| {
+    iter.try_for_each(|face| write_ascii_facet(writer, &face))?;
+    writer.write_all(b"endsolid\n")?;
+    Ok(())
+}
+
+fn write_ascii_facet<W: Write>(writer: &mut W, face: &StlFace) -> Result<()> {
+    writer.write_fmt(format_args!(
+        "  facet normal {:e} {:e} {:e}\n",
+        face.normal[0], face.normal[1], face.normal[2]
+    ))?;
+    writer.write_all(b"    outer loop\n")?;
+    face.vertices.iter().try_for_each(|pt| {
         writer.write_fmt(format_args!(
-            "  facet normal {:e} {:e} {:e}\n",
-            face.normal[0], face.normal[1], face.normal[2]
-        ))?;
-        writer.write_all(b"    outer loop\n")?;
-        face.vertices.iter().try_for_each(|pt| {
-            writer.write_fmt(format_args!(
-                "      vertex {:e} {:e} {:e}\n",
-                pt[0], pt[1], pt[2]
-            ))
-        })?;
-        writer.write_all(b"    endloop\n  endfacet\n")?;
-        Ok(())
+            "      vertex {:e} {:e} {:e}\n",
+            pt[0], pt[1], pt[2]
+        ))
     })?;
-    writer.write_all(b"endsolid\n")?;
+    writer.write_all(b"    endloop\n  endfacet\n")?;
     Ok(())
 }
 
@@ -204,11 +226,14 @@ fn write_binary<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result
     let len = iter.len() as u32;
     writer.write_all(&[0u8; 80])?;
     writer.write_all(&len.to_le_bytes())?;
-    iter.try_for_each(|face| {
-        writer.write_all(bytemuck::cast_slice(&[face]))?;
-        writer.write_all(&[0u8, 0u8])?;
-        Ok(())
-    })
+    iter.try_for_each(|face| write_binary_facet(writer, &face))
+}
+
+#[inline(always)]
+fn write_binary_facet<W: Write>(writer: &mut W, face: &StlFace) -> Result<()> {
+    writer.write_all(bytemuck::cast_slice(std::slice::from_ref(face)))?;
+    writer.write_all(&[0u8, 0u8])?;
+    Ok(())
 }
 
 /// By implementing [`IntoStlIterator`] for a type you define how it will be
@@ -223,9 +248,14 @@ pub trait IntoStlIterator {
 }
 
 /// Generate an STL faces from from a [`PolygonMesh`].
+///
+/// The polygons are triangulated on the fly. The normal of each facet is the average of the
+/// normals at the corners if all corners have normals, and otherwise, it is calculated from
+/// the positions. The normals of degenerate facets are zero vectors.
 #[derive(Debug)]
 pub struct PolygonMeshStlFaceIterator<'a> {
     positions: &'a Vec<Point3>,
+    normals: &'a Vec<Vector3>,
     faces: faces::TriangleIterator<'a, Vertex>,
     len: usize,
 }
@@ -233,13 +263,8 @@ pub struct PolygonMeshStlFaceIterator<'a> {
 impl<'a> Iterator for PolygonMeshStlFaceIterator<'a> {
     type Item = StlFace;
     fn next(&mut self) -> Option<StlFace> {
-        self.faces.next().map(|face| {
-            let p = array![i => self.positions[face[i].pos]; 3];
-            let n = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
-            let normal = n.cast().unwrap().into();
-            let vertices = array![i => p[i].cast().unwrap().into(); 3];
-            StlFace { normal, vertices }
-        })
+        let face = self.faces.next()?;
+        Some(stl_face(self.positions, self.normals, face))
     }
     #[inline(always)]
     fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
@@ -247,12 +272,172 @@ impl<'a> Iterator for PolygonMeshStlFaceIterator<'a> {
 
 impl<'a> ExactSizeIterator for PolygonMeshStlFaceIterator<'a> {}
 
+fn stl_face(positions: &[Point3], normals: &[Vector3], face: [Vertex; 3]) -> StlFace {
+    let p = array![i => positions[face[i].pos]; 3];
+    let n = match face.map(|v| v.nor) {
+        [Some(n0), Some(n1), Some(n2)] => normals[n0] + normals[n1] + normals[n2],
+        _ => (p[1] - p[0]).cross(p[2] - p[0]),
+    };
+    let n = match n.so_small() {
+        true => Vector3::zero(),
+        false => n.normalize(),
+    };
+    let normal = n.cast().unwrap().into();
+    let vertices = array![i => p[i].cast().unwrap().into(); 3];
+    StlFace { normal, vertices }
+}
+
+/// [`MeshSink`] writing STL data to output stream.
+///
+/// The polygons are triangulated and written as soon as they are put, with the same format as
+/// [`write`]. Since the facets refer to the positions and the normals, they are kept in the
+/// sink, however, the formatted facets are not.
+///
+/// [`MeshSink`]: ../sink/trait.MeshSink.html
+#[derive(Debug)]
+pub struct StlSink<W: Write> {
+    writer: BufWriter<W>,
+    stl_type: StlType,
+    positions: Vec<Point3>,
+    normals: Vec<Vector3>,
+    num_uv_coords: usize,
+    // the number of facets which are declared in the header of the binary format but not yet written
+    remaining_facets: usize,
+}
+
+impl<W: Write> StlSink<W> {
+    /// Creates a sink writing ASCII STL data, and writes the header.
+    #[inline(always)]
+    pub fn ascii(writer: W) -> Result<Self> {
+        Self::from_buf_writer(BufWriter::new(writer), StlType::Ascii, 0)
+    }
+
+    /// Creates a sink writing binary STL data, and writes the header.
+    ///
+    /// Since the header of the binary format contains the number of the facets, `num_facets`
+    /// must be the number of the triangles put to the sink, which is checked by
+    /// [`MeshSink::finish`].
+    ///
+    /// [`MeshSink::finish`]: ../sink/trait.MeshSink.html#tymethod.finish
+    #[inline(always)]
+    pub fn binary(writer: W, num_facets: usize) -> Result<Self> {
+        Self::from_buf_writer(BufWriter::new(writer), StlType::Binary, num_facets)
+    }
+
+    /// Creates a sink whose buffer is flushed every `capacity` bytes, and writes the header.
+    ///
+    /// `num_facets` is ignored if `stl_type == StlType::Ascii`. See [`StlSink::binary`].
+    #[inline(always)]
+    pub fn with_capacity(
+        capacity: usize,
+        writer: W,
+        stl_type: StlType,
+        num_facets: usize,
+    ) -> Result<Self> {
+        Self::from_buf_writer(
+            BufWriter::with_capacity(capacity, writer),
+            stl_type,
+            num_facets,
+        )
+    }
+
+    fn from_buf_writer(
+        mut writer: BufWriter<W>,
+        stl_type: StlType,
+        num_facets: usize,
+    ) -> Result<Self> {
+        let remaining_facets = match stl_type {
+            StlType::Ascii => {
+                writer.write_all(b"solid\n")?;
+                0
+            }
+            _ => {
+                let len = u32::try_from(num_facets).map_err(|_| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidInput, "too many facets")
+                })?;
+                writer.write_all(&[0u8; 80])?;
+                writer.write_all(&len.to_le_bytes())?;
+                num_facets
+            }
+        };
+        Ok(Self {
+            writer,
+            stl_type,
+            positions: Vec::new(),
+            normals: Vec::new(),
+            num_uv_coords: 0,
+            remaining_facets,
+        })
+    }
+}
+
+impl<W: Write> sink::MeshSink for StlSink<W> {
+    fn put_vertex(&mut self, position: Point3) -> Result<()> {
+        self.positions.push(position);
+        Ok(())
+    }
+    fn put_uv(&mut self, _: Vector2) -> Result<()> {
+        self.num_uv_coords += 1;
+        Ok(())
+    }
+    fn put_normal(&mut self, normal: Vector3) -> Result<()> {
+        self.normals.push(normal);
+        Ok(())
+    }
+    fn put_face(&mut self, face: &[Vertex]) -> Result<()> {
+        if let Some(v) = face.iter().find(|v| {
+            v.pos >= self.positions.len() || v.nor.is_some_and(|n| n >= self.normals.len())
+        }) {
+            return Err(errors::Error::OutOfRange(*v));
+        }
+        (2..face.len()).try_for_each(|i| {
+            let face = stl_face(
+                &self.positions,
+                &self.normals,
+                [face[0], face[i - 1], face[i]],
+            );
+            match self.stl_type {
+                StlType::Ascii => write_ascii_facet(&mut self.writer, &face),
+                _ => {
+                    if self.remaining_facets == 0 {
+                        let error = std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            "more facets than declared in the header",
+                        );
+                        return Err(error.into());
+                    }
+                    self.remaining_facets -= 1;
+                    write_binary_facet(&mut self.writer, &face)
+                }
+            }
+        })
+    }
+    fn counts(&self) -> [usize; 3] {
+        [self.positions.len(), self.num_uv_coords, self.normals.len()]
+    }
+    fn finish(&mut self) -> Result<()> {
+        match self.stl_type {
+            StlType::Ascii => self.writer.write_all(b"endsolid\n")?,
+            _ if self.remaining_facets != 0 => {
+                let error = std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "fewer facets than declared in the header",
+                );
+                return Err(error.into());
+            }
+            _ => {}
+        }
+        Ok(self.writer.flush()?)
+    }
+}
+
 impl<'a> IntoStlIterator for &'a PolygonMesh {
     type IntoIter = PolygonMeshStlFaceIterator<'a>;
     fn into_iter(self) -> Self::IntoIter {
         let iter = self.faces().triangle_iter();
         Self::IntoIter {
             positions: self.positions(),
+            normals: self.normals(),
             len: iter.len(),
             faces: iter,
         }
@@ -308,7 +493,58 @@ impl FromIterator<StlFace> for PolygonMesh {
 }
 
 /// Read STL file and parse to [`PolygonMesh`].
-#[inline(always)]
+///
+/// No vertices are welded: each facet has its own three positions and one normal, which is
+/// referred by all corners of the facet. Run `put_together_same_attrs` in `truck-meshalgo`
+/// in order to weld them. On the other hand, [`FromIterator`] for [`PolygonMesh`] welds the
+/// positions and the normals which are close to each other.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stl::StlType;
+///
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2, 3]]),
+/// );
+/// let mut bytes = Vec::new();
+/// stl::write(&mesh, &mut bytes, StlType::Binary).unwrap();
+///
+/// let read_mesh = stl::read(bytes.as_slice(), StlType::Automatic).unwrap();
+/// // The quadrangle is divided into two triangles.
+/// assert_eq!(read_mesh.positions().len(), 6);
+/// assert_eq!(read_mesh.normals(), &[Vector3::unit_z(); 2]);
+/// assert_eq!(
+///     read_mesh.tri_faces()[1],
+///     [(3, None, Some(1)), (4, None, Some(1)), (5, None, Some(1))].map(StandardVertex::from),
+/// );
+/// ```
 pub fn read<R: Read>(reader: R, stl_type: StlType) -> Result<PolygonMesh> {
-    StlReader::new(reader, stl_type)?.collect()
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut tri_faces = Vec::new();
+    for face in StlReader::new(reader, stl_type)? {
+        let StlFace { normal, vertices } = face?;
+        let len = positions.len();
+        let nor = Some(normals.len());
+        positions.extend(vertices.map(|p| Point3::from(p.map(f64::from))));
+        normals.push(Vector3::from(normal.map(f64::from)));
+        tri_faces.push(array![i => Vertex { pos: len + i, uv: None, nor }; 3]);
+    }
+    Ok(PolygonMesh::debug_new(
+        StandardAttributes {
+            positions,
+            uv_coords: Vec::new(),
+            normals,
+        },
+        Faces::from_tri_and_quad_faces(tri_faces, Vec::new()),
+    ))
 }
@@ -0,0 +1,99 @@
+use sink::MeshSink;
+use stl::{StlSink, StlType};
+use truck_polymesh::*;
+
+fn grid(n: usize) -> PolygonMesh {
+    let positions = (0..=n)
+        .flat_map(|i| (0..=n).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let (x, y) = (i as f64 / n as f64, j as f64 / n as f64);
+            Point3::new(x, y, f64::sin(x * 3.0) * f64::cos(y * 2.0))
+        })
+        .collect::<Vec<_>>();
+    let uv_coords = positions
+        .iter()
+        .map(|p| Vector2::new(p.x, p.y))
+        .collect::<Vec<_>>();
+    let faces = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let idx =
+                [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)].map(|(i, j)| i * (n + 1) + j);
+            idx.map(|k| (k, Some(k), Some(i % 2)))
+        })
+        .collect::<Faces>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals: vec![Vector3::unit_z(), Vector3::new(0.0, 0.6, 0.8)],
+        },
+        faces,
+    )
+}
+
+#[test]
+fn obj_sink_test() {
+    let mesh = grid(30);
+    let mut written = Vec::new();
+    obj::write(&mesh, &mut written).unwrap();
+
+    let mut streamed = Vec::new();
+    let mut sink = obj::ObjSink::with_capacity(64, &mut streamed);
+    mesh.stream_to(&mut sink).unwrap();
+    sink.finish().unwrap();
+    drop(sink);
+    assert_eq!(streamed, written);
+}
+
+#[test]
+fn obj_sink_several_meshes() {
+    let mesh = grid(5);
+    let mut streamed = Vec::new();
+    let mut sink = obj::ObjSink::new(&mut streamed);
+    mesh.stream_to(&mut sink).unwrap();
+    mesh.stream_to(&mut sink).unwrap();
+    assert_eq!(sink.counts(), [72, 72, 4]);
+    sink.finish().unwrap();
+    drop(sink);
+
+    let read_mesh = obj::read(streamed.as_slice()).unwrap();
+    let mut written = Vec::new();
+    obj::write(&mesh, &mut written).unwrap();
+    let mut merged = obj::read(written.as_slice()).unwrap();
+    merged.merge(merged.clone());
+    assert_eq!(read_mesh, merged);
+}
+
+#[test]
+fn stl_sink_test() {
+    let mesh = grid(30);
+    let num_facets = mesh.faces().triangle_iter().len();
+    for stl_type in [StlType::Ascii, StlType::Binary] {
+        let mut written = Vec::new();
+        stl::write(&mesh, &mut written, stl_type).unwrap();
+
+        let mut streamed = Vec::new();
+        let mut sink = StlSink::with_capacity(256, &mut streamed, stl_type, num_facets).unwrap();
+        mesh.stream_to(&mut sink).unwrap();
+        sink.finish().unwrap();
+        drop(sink);
+        assert_eq!(streamed, written);
+    }
+}
+
+#[test]
+fn stl_sink_wrong_number_of_facets() {
+    let mesh = grid(3);
+    let num_facets = mesh.faces().triangle_iter().len();
+
+    let mut sink = StlSink::binary(Vec::new(), num_facets + 1).unwrap();
+    mesh.stream_to(&mut sink).unwrap();
+    assert!(sink.finish().is_err());
+
+    let mut sink = StlSink::binary(Vec::new(), num_facets - 1).unwrap();
+    assert!(mesh.stream_to(&mut sink).is_err());
+
+    let mut sink = StlSink::ascii(Vec::new()).unwrap();
+    assert!(sink.put_face(&[0, 1, 2].map(StandardVertex::from)).is_err());
+}
@@ -270,3 +270,190 @@ fn incomplete_obj_oi_test() {
     let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
     assert_eq!(mesh, read_mesh);
 }
+
+#[test]
+fn objects_with_materials_oi_test() {
+    let cube = PolygonMesh::new(
+        StandardAttributes {
+            positions: cube::POSITIONS.to_vec(),
+            uv_coords: cube::UV_COORDS.to_vec(),
+            normals: cube::NORMALS.to_vec(),
+        },
+        Faces::from_iter(&[
+            [
+                (3, Some(0), Some(5)),
+                (2, Some(1), Some(5)),
+                (1, Some(3), Some(5)),
+            ],
+            [
+                (0, Some(0), Some(4)),
+                (1, Some(1), Some(4)),
+                (5, Some(3), Some(4)),
+            ],
+            [
+                (1, Some(0), Some(0)),
+                (4, Some(1), Some(0)),
+                (7, Some(3), Some(0)),
+            ],
+        ]),
+    );
+    let triangle = PolygonMesh::new(
+        StandardAttributes {
+            positions: cube::POSITIONS[..4].to_vec(),
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    );
+    let options = obj::ObjWriteOptions {
+        material_library: Some("materials.mtl".to_string()),
+        groups: vec![
+            obj::ObjGroup {
+                name: "bottom".to_string(),
+                material: Some("red".to_string()),
+                faces: 0..2,
+            },
+            // across the objects
+            obj::ObjGroup {
+                name: "side".to_string(),
+                material: Some("blue".to_string()),
+                faces: 2..4,
+            },
+        ],
+        ..Default::default()
+    };
+    let mut gened_obj: Vec<u8> = Vec::new();
+    let objects = [("cube", &cube), ("triangle", &triangle)];
+    obj::write_objects(&objects, &mut gened_obj, options).unwrap();
+
+    let lines = std::str::from_utf8(&gened_obj)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with('v'))
+        .collect::<Vec<_>>();
+    let expected = [
+        "mtllib materials.mtl",
+        "o cube",
+        "g bottom",
+        "usemtl red",
+        "f 4/1/6 3/2/6 2/4/6",
+        "f 1/1/5 2/2/5 6/4/5",
+        "g side",
+        "usemtl blue",
+        "f 2/1/1 5/2/1 8/4/1",
+        "o triangle",
+        "g side",
+        "usemtl blue",
+        "f 9 10 11",
+        "g default",
+        "f 9 11 12",
+    ];
+    assert_eq!(lines, expected);
+
+    let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
+    assert_eq!(read_mesh.positions().len(), 12);
+    assert_eq!(read_mesh.uv_coords().len(), 4);
+    assert_eq!(read_mesh.normals().len(), 6);
+    assert_eq!(read_mesh.faces().len(), 5);
+    let tri_faces = read_mesh.tri_faces();
+    assert_eq!(tri_faces[..3], cube.tri_faces()[..]);
+    let offset_faces = triangle
+        .tri_faces()
+        .iter()
+        .map(|face| face.map(|v| StandardVertex::from(v.pos + 8)))
+        .collect::<Vec<_>>();
+    assert_eq!(tri_faces[3..], offset_faces);
+}
+
+#[test]
+fn write_options_oi_test() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0 / 3.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            normals: vec![Vector3::unit_z()],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+    );
+    let options = obj::ObjWriteOptions {
+        object_name: Some("triangle".to_string()),
+        precision: 2,
+        write_normals: false,
+        ..Default::default()
+    };
+    let mut gened_obj: Vec<u8> = Vec::new();
+    obj::write_with_options(&mesh, &mut gened_obj, options).unwrap();
+    let expected = "o triangle
+v 0.00e0 0.00e0 0.00e0
+v 3.33e-1 0.00e0 0.00e0
+v 0.00e0 1.00e0 0.00e0
+f 1 2 3
+";
+    assert_eq!(std::str::from_utf8(&gened_obj).unwrap(), expected);
+}
+
+#[test]
+fn negative_indices_obj_test() {
+    let positive = b"v 0.0 0.0 0.0
+v 1.0 0.0 0.0
+v 0.0 1.0 0.0
+vt 0.0 0.0
+vt 1.0 0.0
+vn 0.0 0.0 1.0
+f 1/1/1 2/2/1 3/1/1
+v 1.0 1.0 0.0
+vt 1.0 1.0
+f 2/2/1 4/3/1 3/1/1
+";
+    let negative = b"v 0.0 0.0 0.0
+v 1.0 0.0 0.0
+v 0.0 1.0 0.0
+vt 0.0 0.0
+vt 1.0 0.0
+vn 0.0 0.0 1.0
+f -3/-2/-1 -2/-1/-1 -1/-2/-1
+v 1.0 1.0 0.0
+vt 1.0 1.0
+f -3/-2/-1 -1/-1/-1 -2/1/1
+";
+    let mesh0 = obj::read(positive.as_slice()).unwrap();
+    let mesh1 = obj::read(negative.as_slice()).unwrap();
+    assert_eq!(mesh0, mesh1);
+    assert_eq!(mesh0.tri_faces().len(), 2);
+}
+
+#[test]
+fn obj_error_line_test() {
+    use errors::{Error, ObjError};
+    let bad_face = b"v 0.0 0.0 0.0
+v 1.0 0.0 0.0
+f 1 2 -3
+v 0.0 1.0 0.0
+";
+    match obj::read(bad_face.as_slice()) {
+        Err(Error::FromObj(ObjError::InvalidFaceIndex { line, text, index })) => {
+            assert_eq!(line, 3);
+            assert_eq!(text, "f 1 2 -3");
+            assert_eq!(index, -3);
+        }
+        res => panic!("wrong result: {res:?}"),
+    }
+
+    let bad_number = b"# comment\n\nv 0.0 0.0 O.5\n";
+    match obj::read(bad_number.as_slice()) {
+        Err(Error::FromObj(ObjError::InvalidNumber { line, token, .. })) => {
+            assert_eq!(line, 3);
+            assert_eq!(token, "O.5");
+        }
+        res => panic!("wrong result: {res:?}"),
+    }
+
+    let missing = b"v 0.0 0.0 0.0\nvn 0.0 1.0\n";
+    match obj::read(missing.as_slice()) {
+        Err(Error::FromObj(ObjError::MissingValue { line, .. })) => assert_eq!(line, 2),
+        res => panic!("wrong result: {res:?}"),
+    }
+}
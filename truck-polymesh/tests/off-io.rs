@@ -0,0 +1,72 @@
+use errors::{Error, ObjError};
+use truck_polymesh::*;
+
+#[test]
+fn off_oi_test() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(2.0, 0.5, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(0.5, 2.0, 0.25),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter([[0, 1, 3, 4].as_slice(), &[1, 2, 3], &[4, 3, 2, 5, 0]]),
+    );
+    let mut gened_off = Vec::new();
+    off::write(&mesh, &mut gened_off).unwrap();
+    let read_mesh = off::read(gened_off.as_slice()).unwrap();
+    assert_eq!(read_mesh, mesh);
+}
+
+#[test]
+fn off_with_comments_and_normals() {
+    let off = b"NOFF 3 1 0 # counts in the header line
+# positions and normals
+
+0 0 0 0 0 1
+1 0 0 0 0 1
+0 1 0 0 0 1 # comment
+3 0 1 2 255 0 0
+";
+    let mesh = off::read(off.as_slice()).unwrap();
+    assert_eq!(mesh.positions().len(), 3);
+    assert_eq!(mesh.normals(), &[Vector3::unit_z(); 3]);
+    let face = [0, 1, 2].map(|i| StandardVertex::from((i, None, Some(i))));
+    assert_eq!(mesh.tri_faces(), &[face]);
+}
+
+#[test]
+fn off_error_line_test() {
+    let bad_face = b"OFF
+3 1 0
+0 0 0
+1 0 0
+0 1 0
+3 0 1 3
+";
+    match off::read(bad_face.as_slice()) {
+        Err(Error::FromObj(ObjError::InvalidFaceIndex { line, text, index })) => {
+            assert_eq!(line, 6);
+            assert_eq!(text, "3 0 1 3");
+            assert_eq!(index, 3);
+        }
+        res => panic!("wrong result: {res:?}"),
+    }
+
+    let truncated = b"OFF\n3 1 0\n0 0 0\n1 0 0\n";
+    match off::read(truncated.as_slice()) {
+        Err(Error::FromObj(ObjError::UnexpectedEof { line })) => assert_eq!(line, 4),
+        res => panic!("wrong result: {res:?}"),
+    }
+
+    let bad_header = b"PLY\n3 1 0\n";
+    match off::read(bad_header.as_slice()) {
+        Err(Error::FromObj(ObjError::InvalidHeader { line, .. })) => assert_eq!(line, 1),
+        res => panic!("wrong result: {res:?}"),
+    }
+}
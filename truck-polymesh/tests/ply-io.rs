@@ -0,0 +1,201 @@
+use ply::{PlyExtras, PlyFormat, PlyScalarType};
+use truck_polymesh::*;
+
+fn sample_mesh() -> (PolygonMesh, PlyExtras) {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(2.0, 0.5, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 2.0, 0.1),
+        Point3::new(-0.3, 1.5, 0.2),
+    ];
+    let normals = (0..positions.len())
+        .map(|i| Vector3::new(0.0, 0.1 * i as f64, 1.0).normalize())
+        .collect::<Vec<_>>();
+    let uv_coords = positions
+        .iter()
+        .map(|p| Vector2::new(p.x / 2.0, p.y / 2.0))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(
+        [[0, 1, 3, 4].as_slice(), &[1, 2, 3], &[4, 3, 5, 6, 0]]
+            .iter()
+            .map(|face| {
+                face.iter()
+                    .map(|i| (*i, Some(*i), Some(*i)))
+                    .collect::<Vec<_>>()
+            }),
+    );
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    );
+    let mut extras = PlyExtras::default();
+    let red = vec![255.0, 0.0, 12.0, 34.0, 56.0, 78.0, 90.0];
+    extras.vertex_props.insert("red".to_string(), red);
+    extras
+        .vertex_prop_types
+        .insert("red".to_string(), PlyScalarType::UChar);
+    let quality = vec![0.5, 0.25, -1.0, 2.0, 0.125, 3.0, 1.0e-3];
+    extras.vertex_props.insert("quality".to_string(), quality);
+    extras
+        .vertex_prop_types
+        .insert("quality".to_string(), PlyScalarType::Double);
+    (mesh, extras)
+}
+
+#[test]
+fn ply_oi_test() {
+    let (mesh, extras) = sample_mesh();
+    for format in [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian] {
+        let mut bytes = Vec::new();
+        ply::write(&mesh, &extras, &mut bytes, format).unwrap();
+        let (read_mesh, read_extras) = ply::read(bytes.as_slice()).unwrap();
+        assert_eq!(read_mesh, mesh);
+        assert_eq!(read_extras, extras);
+        assert_eq!(read_mesh.tri_faces().len(), 1);
+        assert_eq!(read_mesh.quad_faces().len(), 1);
+        assert_eq!(read_mesh.other_faces().len(), 1);
+    }
+}
+
+#[test]
+fn ply_split_normals() {
+    // a square whose triangles have different normals
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            normals: vec![Vector3::unit_z(), -Vector3::unit_z()],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))],
+            [(0, None, Some(1)), (2, None, Some(1)), (3, None, Some(1))],
+        ]),
+    );
+    let mut extras = PlyExtras::default();
+    let quality = vec![0.0, 1.0, 2.0, 3.0];
+    extras.vertex_props.insert("quality".to_string(), quality);
+
+    let mut bytes = Vec::new();
+    ply::write(&mesh, &extras, &mut bytes, PlyFormat::Ascii).unwrap();
+    let (read_mesh, read_extras) = ply::read(bytes.as_slice()).unwrap();
+    // The positions 0 and 2 are duplicated.
+    assert_eq!(read_mesh.positions().len(), 6);
+    assert_eq!(
+        read_extras.vertex_props["quality"],
+        [0.0, 1.0, 2.0, 3.0, 0.0, 2.0]
+    );
+    let expected = [[0, 1, 2], [4, 5, 3]].map(|face| face.map(StandardVertex::from));
+    let expected = expected.map(|face| {
+        face.map(|v| StandardVertex {
+            nor: Some(v.pos),
+            ..v
+        })
+    });
+    assert_eq!(read_mesh.tri_faces(), &expected);
+    read_mesh
+        .tri_faces()
+        .iter()
+        .zip(mesh.tri_faces())
+        .for_each(|(face0, face1)| {
+            face0.iter().zip(face1).for_each(|(v0, v1)| {
+                assert_eq!(read_mesh.positions()[v0.pos], mesh.positions()[v1.pos]);
+                let normal = mesh.normals()[v1.nor.unwrap()];
+                assert_eq!(read_mesh.normals()[v0.nor.unwrap()], normal);
+            })
+        });
+}
+
+#[test]
+fn ply_skip_unknown_elements() {
+    let ascii = b"ply
+format ascii 1.0
+comment unknown elements and properties are skipped
+element vertex 4
+property float x
+property float y
+property float z
+property list uchar int unknown_list
+property uchar alpha
+element edge 2
+property int vertex1
+property int vertex2
+element face 2
+property uchar flags
+property list uchar int vertex_indices
+end_header
+0 0 0 2 5 6 255
+1 0 0 0 128
+1 1 0 1 7 0
+0 1 0 0 64
+0 1
+1 2
+0 3 0 1 2
+1 3 0 2 3
+";
+    let (mesh, extras) = ply::read(ascii.as_slice()).unwrap();
+    assert_eq!(mesh.positions().len(), 4);
+    assert_eq!(mesh.positions()[2], Point3::new(1.0, 1.0, 0.0));
+    assert!(mesh.normals().is_empty() && mesh.uv_coords().is_empty());
+    let faces = [[0, 1, 2], [0, 2, 3]].map(|face| face.map(StandardVertex::from));
+    assert_eq!(mesh.tri_faces(), &faces);
+    assert_eq!(extras.vertex_props.len(), 1);
+    assert_eq!(extras.vertex_props["alpha"], [255.0, 128.0, 0.0, 64.0]);
+    assert_eq!(extras.vertex_prop_types["alpha"], PlyScalarType::UChar);
+
+    // binary
+    let mut binary = b"ply
+format binary_little_endian 1.0
+element vertex 3
+property double x
+property double y
+property double z
+element edge 1
+property list uchar int vertex_indices
+element face 1
+property list uchar int vertex_indices
+end_header
+"
+    .to_vec();
+    let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    positions
+        .iter()
+        .flatten()
+        .for_each(|x: &f64| binary.extend(x.to_le_bytes()));
+    binary.push(2);
+    [0_i32, 1]
+        .iter()
+        .for_each(|i| binary.extend(i.to_le_bytes()));
+    binary.push(3);
+    [0_i32, 1, 2]
+        .iter()
+        .for_each(|i| binary.extend(i.to_le_bytes()));
+    let (mesh, _) = ply::read(binary.as_slice()).unwrap();
+    assert_eq!(mesh.positions()[1], Point3::new(1.0, 0.0, 0.0));
+    assert_eq!(mesh.tri_faces(), &[[0, 1, 2].map(StandardVertex::from)]);
+}
+
+#[test]
+fn ply_invalid_data() {
+    let (mesh, mut extras) = sample_mesh();
+    let mut bytes = Vec::new();
+    ply::write(&mesh, &extras, &mut bytes, PlyFormat::BinaryLittleEndian).unwrap();
+    assert!(ply::read(&bytes[..bytes.len() - 3]).is_err());
+
+    let big_endian = b"ply\nformat binary_big_endian 1.0\nend_header\n";
+    assert!(ply::read(big_endian.as_slice()).is_err());
+
+    extras.vertex_props.insert("short".to_string(), vec![1.0]);
+    assert!(ply::write(&mesh, &extras, Vec::new(), PlyFormat::Ascii).is_err());
+}
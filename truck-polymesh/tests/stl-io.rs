@@ -85,3 +85,120 @@ fn through_polymesh() {
         assert!(f32::abs(face0.normal[2] - face1.normal[2]) < 5.0e-4);
     }
 }
+
+fn cube() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let faces = Faces::from_iter(&[
+        [3, 2, 1, 0],
+        [0, 1, 5, 4],
+        [1, 2, 6, 5],
+        [2, 3, 7, 6],
+        [3, 0, 4, 7],
+        [4, 5, 6, 7],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn cube_polymesh_oi_test() {
+    let mesh = cube();
+    for stl_type in [StlType::Ascii, StlType::Binary] {
+        let mut bytes = Vec::new();
+        stl::write(&mesh, &mut bytes, stl_type).unwrap();
+        let read_mesh = stl::read(bytes.as_slice(), StlType::Automatic).unwrap();
+        assert_eq!(read_mesh.positions().len(), 36);
+        assert_eq!(read_mesh.normals().len(), 12);
+        assert_eq!(read_mesh.tri_faces().len(), 12);
+        let triangles = mesh.faces().triangle_iter().zip(read_mesh.tri_faces());
+        for (i, (tri0, tri1)) in triangles.enumerate() {
+            let p = tri0.map(|v| mesh.positions()[v.pos]);
+            let q = tri1.map(|v| read_mesh.positions()[v.pos]);
+            assert_eq!(p, q);
+            assert!(tri1.iter().all(|v| v.nor == Some(i)));
+            let normal = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
+            assert_near!(read_mesh.normals()[i], normal);
+        }
+
+        // The normals of the mesh are prior to the geometric ones.
+        let mut bytes = Vec::new();
+        let inverted = read_mesh.normals().iter().map(|n| -*n).collect::<Vec<_>>();
+        let mut inverted_mesh = read_mesh.clone();
+        *inverted_mesh.debug_editor().attributes.normals = inverted.clone();
+        stl::write(&inverted_mesh, &mut bytes, stl_type).unwrap();
+        let read_mesh = stl::read(bytes.as_slice(), stl_type).unwrap();
+        assert_eq!(read_mesh.normals(), &inverted);
+    }
+}
+
+#[test]
+fn binary_beginning_with_solid() {
+    let mut bytes = Vec::new();
+    stl::write(&cube(), &mut bytes, StlType::Binary).unwrap();
+    bytes[..11].copy_from_slice(b"solid cube ");
+    let reader = StlReader::<&[u8]>::new(&bytes, StlType::Automatic).unwrap();
+    assert!(matches!(reader.stl_type(), StlType::Binary));
+    let mesh = stl::read(bytes.as_slice(), StlType::Automatic).unwrap();
+    assert_eq!(mesh.tri_faces().len(), 12);
+}
+
+#[test]
+fn corrupt_stl() {
+    let mut bytes = Vec::new();
+    stl::write(&cube(), &mut bytes, StlType::Binary).unwrap();
+    // truncated binary
+    let res = stl::read(&bytes[..bytes.len() - 10], StlType::Binary);
+    assert!(matches!(res, Err(errors::Error::FromIO(_))));
+    // too short header
+    let res = stl::read(&bytes[..40], StlType::Automatic);
+    assert!(matches!(res, Err(errors::Error::FromIO(_))));
+
+    let ascii = b"solid broken
+  facet normal 0.0 0.0
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex 1.0 0.0 0.0
+      vertex 0.0 1.0 0.0
+    endloop
+  endfacet
+endsolid
+";
+    let res = stl::read(ascii.as_slice(), StlType::Automatic);
+    assert!(matches!(res, Err(errors::Error::FromIO(_))));
+
+    let ascii = b"solid broken
+  facet normal 0.0 0.0 1.0
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex 1.0 zero 0.0
+      vertex 0.0 1.0 0.0
+    endloop
+  endfacet
+endsolid
+";
+    let res = stl::read(ascii.as_slice(), StlType::Automatic);
+    assert!(matches!(res, Err(errors::Error::FromIO(_))));
+
+    let ascii = b"solid broken
+  facet normal 0.0 0.0 1.0
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex 1.0 0.0 0.0
+";
+    let res = stl::read(ascii.as_slice(), StlType::Automatic);
+    assert!(matches!(res, Err(errors::Error::FromIO(_))));
+}
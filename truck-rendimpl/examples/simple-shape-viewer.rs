@@ -5,6 +5,7 @@
 //! - Right-click to move the light to the camera's position.
 //! - Enter "P" on the keyboard to switch between parallel projection and perspective projection of the camera.
 //! - Enter "L" on the keyboard to switch the point light source/uniform light source of the light.
+//! - Enter "Space" on the keyboard to switch the render mode, e.g. the faces with the edge overlay.
 //!
 //! A model json file can be generated by `serde_json`. See the examples of `truck-modeling`!
 
@@ -26,6 +27,7 @@ const PUNCHED_CUBE_BYTES: &[u8] = include_bytes!(concat!(
 
 enum RenderMode {
     NaiveSurface,
+    SurfaceAndEdgeOverlay,
     NaiveWireFrame,
     HiddenLineEliminate,
     SurfaceAndWireFrame,
@@ -56,8 +58,10 @@ impl MyApp {
         )
     }
     fn update_render_mode(&mut self) {
+        let edge_overlay = matches!(self.render_mode, RenderMode::SurfaceAndEdgeOverlay);
+        self.instance.instance_state_mut().edge_overlay = edge_overlay.then(EdgeOverlay::default);
         match self.render_mode {
-            RenderMode::NaiveSurface => {
+            RenderMode::NaiveSurface | RenderMode::SurfaceAndEdgeOverlay => {
                 self.instance.instance_state_mut().material = Material {
                     albedo: Vector4::new(1.0, 1.0, 1.0, 1.0),
                     reflectance: 0.5,
@@ -149,7 +153,7 @@ impl MyApp {
             ..Default::default()
         };
         (
-            creator.create_instance(&mesh_solid.to_polygon(), &polygon_state),
+            creator.create_instance_with_edges(&mesh_solid.to_polygon(), &curves, &polygon_state),
             creator.create_instance(&curves, &wire_state),
         )
     }
@@ -311,7 +315,8 @@ impl App for MyApp {
             }
             KeyCode::Space => {
                 self.render_mode = match self.render_mode {
-                    RenderMode::NaiveSurface => RenderMode::SurfaceAndWireFrame,
+                    RenderMode::NaiveSurface => RenderMode::SurfaceAndEdgeOverlay,
+                    RenderMode::SurfaceAndEdgeOverlay => RenderMode::SurfaceAndWireFrame,
                     RenderMode::SurfaceAndWireFrame => RenderMode::NaiveWireFrame,
                     RenderMode::NaiveWireFrame => RenderMode::HiddenLineEliminate,
                     RenderMode::HiddenLineEliminate => RenderMode::NaiveSurface,
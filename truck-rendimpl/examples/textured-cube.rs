@@ -82,6 +82,8 @@ impl App for MyApp {
             },
             texture: Some(std::sync::Arc::new(texture)),
             backface_culling: true,
+            edge_overlay: None,
+            clipping: true,
         };
         let mesh = Self::create_cube().triangulation(0.05).to_polygon();
         let shape: PolygonInstance = scene.instance_creator().create_instance(&mesh, &state);
@@ -0,0 +1,200 @@
+use crate::*;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct EdgeVertex {
+    position: [f32; 3],
+    other: [f32; 3],
+    side: f32,
+}
+
+impl EdgeBuffer {
+    /// Creates the vertices of the quads of the segments of `edges`.
+    ///
+    /// Returns `None` if there are no segments with positive length.
+    pub(crate) fn new(
+        device: &Device,
+        edges: &[PolylineCurve<Point3>],
+        shaders: WireShaders,
+    ) -> Option<EdgeBuffer> {
+        let vertices: Vec<EdgeVertex> = edges
+            .iter()
+            .flat_map(|edge| edge.windows(2))
+            .filter(|segment| segment[0] != segment[1])
+            .flat_map(|segment| {
+                let a: [f32; 3] = segment[0].cast().unwrap().into();
+                let b: [f32; 3] = segment[1].cast().unwrap().into();
+                let vertex = |position, other, side| EdgeVertex {
+                    position,
+                    other,
+                    side,
+                };
+                // The sign of `side` at `b` is reversed, since the direction is reversed.
+                [
+                    vertex(a, b, 1.0),
+                    vertex(a, b, -1.0),
+                    vertex(b, a, -1.0),
+                    vertex(a, b, -1.0),
+                    vertex(b, a, 1.0),
+                    vertex(b, a, -1.0),
+                ]
+            })
+            .collect();
+        match vertices.is_empty() {
+            true => None,
+            false => Some(EdgeBuffer {
+                vertices: Arc::new(BufferHandler::from_slice(
+                    &vertices,
+                    device,
+                    BufferUsages::VERTEX,
+                )),
+                shaders,
+                id: RenderID::gen(),
+            }),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn overlay<'a>(&'a self, instance: &'a PolygonInstance) -> EdgeOverlayObject<'a> {
+        EdgeOverlayObject {
+            edges: self,
+            state: &instance.state,
+        }
+    }
+}
+
+/// Render object of the edges drawn over the faces.
+pub(crate) struct EdgeOverlayObject<'a> {
+    edges: &'a EdgeBuffer,
+    state: &'a PolygonState,
+}
+
+impl Rendered for EdgeOverlayObject<'_> {
+    #[inline(always)]
+    fn render_id(&self) -> RenderID { self.edges.id }
+    #[inline(always)]
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        (Arc::clone(&self.edges.vertices), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        let entry = || PreBindGroupLayoutEntry {
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        Arc::new(bind_group_util::create_bind_group_layout(
+            handler.device(),
+            // matrix and overlay
+            &[entry(), entry()],
+        ))
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        let device = handler.device();
+        // The edges of the zero width are not rasterized.
+        let overlay = self.state.edge_overlay.unwrap_or(EdgeOverlay {
+            color: Vector4::new(0.0, 0.0, 0.0, 0.0),
+            width: 0.0,
+        });
+        let color = overlay.color.cast::<f32>().unwrap();
+        let overlay_data: [f32; 8] = [
+            color[0],
+            color[1],
+            color[2],
+            color[3],
+            overlay.width as f32,
+            0.0,
+            0.0,
+            0.0,
+        ];
+        let overlay_buffer =
+            BufferHandler::from_slice(&overlay_data, device, BufferUsages::UNIFORM);
+        Arc::new(bind_group_util::create_bind_group(
+            device,
+            layout,
+            vec![
+                self.state.matrix_buffer(device).binding_resource(),
+                overlay_buffer.binding_resource(),
+            ],
+        ))
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let device = handler.device();
+        let shaders = &self.edges.shaders;
+        // The edges are pulled to the camera, not to fight with the faces.
+        let depth_stencil = match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::LessEqual,
+                stencil: Default::default(),
+                bias: DepthBiasState {
+                    constant: -4,
+                    slope_scale: -2.0,
+                    clamp: 0.0,
+                },
+            }),
+            false => None,
+        };
+        let sample_count = scene_desc.backend_buffer.sample_count;
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &shaders.vertex_module,
+                entry_point: shaders.vertex_entry,
+                buffers: &[VertexBufferLayout {
+                    array_stride: std::mem::size_of::<EdgeVertex>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 3 * 4,
+                            shader_location: 1,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32,
+                            offset: 2 * 3 * 4,
+                            shader_location: 2,
+                        },
+                    ],
+                }],
+            },
+            fragment: Some(FragmentState {
+                module: &shaders.fragment_module,
+                entry_point: shaders.fragment_entry,
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil,
+            multisample: MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: None,
+            multiview: None,
+        });
+        Arc::new(pipeline)
+    }
+}
@@ -32,6 +32,7 @@ impl PolygonShaders {
     #[inline(always)]
     pub fn default(device: &Device) -> Self {
         let source = include_str!("shaders/microfacet-module.wgsl").to_string()
+            + include_str!("shaders/clip-module.wgsl")
             + include_str!("shaders/polygon.wgsl");
         let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
             source: ShaderSource::Wgsl(source.into()),
@@ -46,6 +47,27 @@ impl PolygonShaders {
             "tex_main",
         )
     }
+
+    /// Creates shaders for `InstancedPolygonInstance`, which does not support textures.
+    #[inline(always)]
+    fn instanced(device: &Device) -> Self {
+        let source = include_str!("shaders/microfacet-module.wgsl").to_string()
+            + include_str!("shaders/clip-module.wgsl")
+            + include_str!("shaders/polygon.wgsl")
+            + include_str!("shaders/instanced.wgsl");
+        let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(source.into()),
+            label: None,
+        }));
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_instanced",
+            Arc::clone(&shader_module),
+            "instanced_main",
+            Arc::clone(&shader_module),
+            "instanced_main",
+        )
+    }
 }
 
 impl WireShaders {
@@ -73,8 +95,27 @@ impl WireShaders {
     /// Creates default wireframe shaders
     #[inline(always)]
     fn default(device: &Device) -> Self {
+        let source = include_str!("shaders/clip-module.wgsl").to_string()
+            + include_str!("shaders/line.wgsl");
         let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
-            source: ShaderSource::Wgsl(include_str!("shaders/line.wgsl").into()),
+            source: ShaderSource::Wgsl(source.into()),
+            label: None,
+        }));
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_main",
+            shader_module,
+            "fs_main",
+        )
+    }
+
+    /// Creates shaders for the edges drawn over the faces
+    #[inline(always)]
+    fn edge_overlay(device: &Device) -> Self {
+        let source = include_str!("shaders/clip-module.wgsl").to_string()
+            + include_str!("shaders/edge_overlay.wgsl");
+        let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(source.into()),
             label: None,
         }));
         Self::new(
@@ -93,6 +134,9 @@ impl CreatorCreator for DeviceHandler {
             handler: self.clone(),
             polygon_shaders: PolygonShaders::default(self.device()),
             wire_shaders: WireShaders::default(self.device()),
+            edge_shaders: WireShaders::edge_overlay(self.device()),
+            pick_module: Arc::new(PickBuffer::shader_module(self.device())),
+            instanced_shaders: PolygonShaders::instanced(self.device()),
         }
     }
 }
@@ -111,6 +155,60 @@ impl InstanceCreator {
         I: Instance, {
         object.to_instance(&self.handler, &I::standard_shaders(self), state)
     }
+    /// Creates `PolygonInstance` from object, and attaches the edges `edges` drawn over the faces.
+    ///
+    /// The edges are drawn in the same render object as the faces, after the faces, if the
+    /// field `edge_overlay` of the state is `Some`. For shapes, `edges` are the polylines of the
+    /// tessellated edges.
+    pub fn create_instance_with_edges<T>(
+        &self,
+        object: &T,
+        edges: &[PolylineCurve<Point3>],
+        state: &PolygonState,
+    ) -> PolygonInstance
+    where
+        T: ToInstance<PolygonInstance, State = PolygonState>,
+    {
+        let mut instance: PolygonInstance = self.create_instance(object, state);
+        let shaders = self.edge_shaders.clone();
+        instance.edges = EdgeBuffer::new(self.handler.device(), edges, shaders);
+        instance
+    }
+    /// Creates `PolygonInstance` which can be picked by [`Scene::render_pick_buffer`].
+    ///
+    /// The `i`-th face of `mesh` belongs to the face `provenance[i]`, e.g. the provenance given
+    /// by `MeshedShape::to_polygon_with_provenance` of `truck-meshalgo`, which is the index of
+    /// `face_iter` of the shape. `edges` are drawn over the faces as
+    /// [`InstanceCreator::create_instance_with_edges`], and picked within several pixels.
+    /// The picked element is decoded by [`PolygonInstance::picked`].
+    pub fn create_pickable_instance(
+        &self,
+        mesh: &PolygonMesh,
+        provenance: &[usize],
+        edges: &[PolylineCurve<Point3>],
+        state: &PolygonState,
+    ) -> PolygonInstance {
+        let mut instance = self.create_instance_with_edges(mesh, edges, state);
+        let module = Arc::clone(&self.pick_module);
+        let device = self.handler.device();
+        instance.picks = Some(PickBuffer::new(device, mesh, provenance, edges, module));
+        instance
+    }
+    /// Creates `InstancedPolygonInstance`, which draws `mesh` once for each matrix of `matrices`
+    /// by one draw call.
+    ///
+    /// The albedo of every instance is initialized by the one of the material of `state`.
+    pub fn create_instanced_instance(
+        &self,
+        mesh: &PolygonMesh,
+        matrices: &[Matrix4],
+        state: &PolygonState,
+    ) -> InstancedPolygonInstance {
+        let device = self.handler.device();
+        let buffers = mesh.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, device);
+        let shaders = &self.instanced_shaders;
+        InstancedPolygonInstance::new(&self.handler, buffers, matrices, state, shaders)
+    }
     /// Creates `Texture` for attaching faces.
     #[inline(always)]
     pub fn create_texture(&self, image: &DynamicImage) -> Arc<Texture> {
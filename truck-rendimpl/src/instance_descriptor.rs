@@ -16,9 +16,24 @@ impl Default for Material {
 }
 
 impl Material {
+    #[inline(always)]
+    fn uniform_data(&self) -> [f32; 8] {
+        [
+            self.albedo[0] as f32,
+            self.albedo[1] as f32,
+            self.albedo[2] as f32,
+            self.albedo[3] as f32,
+            self.roughness as f32,
+            self.reflectance as f32,
+            self.ambient_ratio as f32,
+            self.background_ratio as f32,
+        ]
+    }
+
     /// Creates a `UNIFORM` buffer of material.
     ///
     /// The bind group provided by the instances holds this uniform buffer.
+    /// The buffer is also `COPY_DST`, so it can be rewritten by [`Material::write_buffer`].
     /// # Shader Examples
     /// ```glsl
     /// layout(set = 1, binding = 1) uniform Material {
@@ -30,17 +45,15 @@ impl Material {
     /// ```
     #[inline(always)]
     pub fn buffer(&self, device: &Device) -> BufferHandler {
-        let material_data: [f32; 8] = [
-            self.albedo[0] as f32,
-            self.albedo[1] as f32,
-            self.albedo[2] as f32,
-            self.albedo[3] as f32,
-            self.roughness as f32,
-            self.reflectance as f32,
-            self.ambient_ratio as f32,
-            self.background_ratio as f32,
-        ];
-        BufferHandler::from_slice(&material_data, device, BufferUsages::UNIFORM)
+        let usage = BufferUsages::UNIFORM | BufferUsages::COPY_DST;
+        BufferHandler::from_slice(&self.uniform_data(), device, usage)
+    }
+
+    /// Writes the material to the buffer created by [`Material::buffer`].
+    #[inline(always)]
+    pub fn write_buffer(&self, queue: &Queue, buffer: &BufferHandler) {
+        let data = self.uniform_data();
+        queue.write_buffer(buffer.buffer(), 0, bytemuck::cast_slice(&data));
     }
 
     #[doc(hidden)]
@@ -66,10 +79,33 @@ impl Default for PolygonState {
             material: Default::default(),
             texture: None,
             backface_culling: true,
+            edge_overlay: None,
+            clipping: true,
         }
     }
 }
 
+impl Default for EdgeOverlay {
+    #[inline(always)]
+    fn default() -> EdgeOverlay {
+        EdgeOverlay {
+            color: Vector4::new(0.0, 0.0, 0.0, 1.0),
+            width: 1.5,
+        }
+    }
+}
+
+impl ModelMatrix {
+    #[inline(always)]
+    pub(crate) fn buffer(matrix: Matrix4, clipping: bool, device: &Device) -> BufferHandler {
+        let model_matrix = ModelMatrix {
+            matrix: matrix.cast::<f32>().unwrap().into(),
+            clipping: [clipping as u32, 0, 0, 0],
+        };
+        BufferHandler::from_slice(&[model_matrix], device, BufferUsages::UNIFORM)
+    }
+}
+
 impl PolygonState {
     /// Creates a `UNIFORM` buffer of instance matrix.
     ///
@@ -78,12 +114,12 @@ impl PolygonState {
     /// ```glsl
     /// layout(set = 1, binding = 0) uniform ModelMatrix {
     ///     mat4 uniform_matrix;
+    ///     uint clipping;  // 1 if the instance is cut by the clip planes, otherwise 0
     /// };
     /// ```
     #[inline(always)]
     pub fn matrix_buffer(&self, device: &Device) -> BufferHandler {
-        let matrix_data: [[f32; 4]; 4] = self.matrix.cast::<f32>().unwrap().into();
-        BufferHandler::from_slice(&matrix_data, device, BufferUsages::UNIFORM)
+        ModelMatrix::buffer(self.matrix, self.clipping, device)
     }
 
     #[doc(hidden)]
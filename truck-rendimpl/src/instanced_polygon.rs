@@ -0,0 +1,258 @@
+use crate::*;
+
+impl InstanceAttributes {
+    #[inline(always)]
+    fn new(matrix: &Matrix4, albedo: Vector4) -> InstanceAttributes {
+        InstanceAttributes {
+            matrix: matrix.cast::<f32>().unwrap().into(),
+            albedo: albedo.cast::<f32>().unwrap().into(),
+        }
+    }
+}
+
+impl InstancedPolygonInstance {
+    #[inline(always)]
+    pub(crate) fn new(
+        handler: &DeviceHandler,
+        (vb, ib): (BufferHandler, BufferHandler),
+        matrices: &[Matrix4],
+        state: &PolygonState,
+        shaders: &PolygonShaders,
+    ) -> InstancedPolygonInstance {
+        let albedo = state.material.albedo;
+        let attributes: Vec<InstanceAttributes> = matrices
+            .iter()
+            .map(|matrix| InstanceAttributes::new(matrix, albedo))
+            .collect();
+        InstancedPolygonInstance {
+            polygon: (Arc::new(vb), Arc::new(ib)),
+            instance_buffer: Arc::new(Self::create_instance_buffer(&attributes, handler)),
+            attributes,
+            material_buffer: Arc::new(state.material.buffer(handler.device())),
+            state: state.clone(),
+            shaders: shaders.clone(),
+            handler: handler.clone(),
+            id: RenderID::gen(),
+        }
+    }
+
+    #[inline(always)]
+    fn create_instance_buffer(
+        attributes: &[InstanceAttributes],
+        handler: &DeviceHandler,
+    ) -> BufferHandler {
+        let usage = BufferUsages::VERTEX | BufferUsages::COPY_DST;
+        BufferHandler::from_slice(&attributes, handler.device(), usage)
+    }
+
+    #[inline(always)]
+    fn write_instance_buffer(&self) {
+        let buffer = self.instance_buffer.buffer();
+        let contents = bytemuck::cast_slice(&self.attributes);
+        self.handler.queue().write_buffer(buffer, 0, contents);
+    }
+
+    /// Returns a reference to the instance descriptor.
+    #[inline(always)]
+    pub const fn instance_state(&self) -> &PolygonState { &self.state }
+    /// Returns the mutable reference to instance descriptor.
+    #[inline(always)]
+    pub fn instance_state_mut(&mut self) -> &mut PolygonState { &mut self.state }
+
+    /// Returns the number of the drawn instances.
+    #[inline(always)]
+    pub fn number_of_instances(&self) -> usize { self.attributes.len() }
+
+    /// Sets the matrices of the instances.
+    ///
+    /// If the number of the instances is not changed, the per-instance buffer is rewritten in
+    /// place, so the change is reflected in the next rendering without updating the scene, and
+    /// returns `false`. Otherwise, the buffer is recreated, and returns `true`. In that case, the
+    /// buffer has to be updated by `Scene::update_vertex_buffer`.
+    ///
+    /// The albedo of the remaining instances is kept, and the one of the added instances is
+    /// initialized by the material of the state.
+    pub fn set_instance_matrices(&mut self, matrices: &[Matrix4]) -> bool {
+        let albedo = self.state.material.albedo;
+        let resized = self.attributes.len() != matrices.len();
+        self.attributes.resize(
+            matrices.len(),
+            InstanceAttributes::new(&Matrix4::identity(), albedo),
+        );
+        self.attributes
+            .iter_mut()
+            .zip(matrices)
+            .for_each(|(attr, matrix)| attr.matrix = matrix.cast::<f32>().unwrap().into());
+        match resized {
+            true => {
+                let buffer = Self::create_instance_buffer(&self.attributes, &self.handler);
+                self.instance_buffer = Arc::new(buffer);
+            }
+            false => self.write_instance_buffer(),
+        }
+        resized
+    }
+
+    /// Sets the albedos of the instances.
+    ///
+    /// The `i`-th instance is painted by `colors[i]`. The superfluous colors are ignored, and
+    /// the instances without colors are not changed. The per-instance buffer is rewritten in
+    /// place, so the scene does not have to be updated.
+    pub fn set_instance_colors(&mut self, colors: &[Vector4]) {
+        self.attributes
+            .iter_mut()
+            .zip(colors)
+            .for_each(|(attr, color)| attr.albedo = color.cast::<f32>().unwrap().into());
+        self.write_instance_buffer();
+    }
+
+    #[inline(always)]
+    fn non_textured_bdl(&self, device: &Device) -> BindGroupLayout {
+        bind_group_util::create_bind_group_layout(device, {
+            &[
+                PolygonState::matrix_bgl_entry(),
+                PolygonState::material_bgl_entry(),
+            ]
+        })
+    }
+
+    #[inline(always)]
+    fn non_textured_bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
+        bind_group_util::create_bind_group(
+            device,
+            layout,
+            vec![
+                self.state.matrix_buffer(device).binding_resource(),
+                self.material_buffer.binding_resource(),
+            ],
+        )
+    }
+}
+
+impl Rendered for InstancedPolygonInstance {
+    impl_render_id!(id);
+
+    #[inline(always)]
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let polygon = self.polygon.clone();
+        (polygon.0, Some(polygon.1))
+    }
+    #[inline(always)]
+    fn instance_buffer(&self, _: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        Some(Arc::clone(&self.instance_buffer))
+    }
+    #[inline(always)]
+    fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(self.non_textured_bdl(device_handler.device()))
+    }
+    #[inline(always)]
+    fn bind_group(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &BindGroupLayout,
+    ) -> Arc<BindGroup> {
+        // `instance_state_mut` may have changed the material.
+        let material = &self.state.material;
+        material.write_buffer(device_handler.queue(), &self.material_buffer);
+        Arc::new(self.non_textured_bg(device_handler.device(), layout))
+    }
+    fn pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let device = device_handler.device();
+        let cull_mode = match self.state.backface_culling {
+            true => Some(Face::Back),
+            false => None,
+        };
+        let blend = match self.state.material.alpha_blend {
+            true => Some(BlendState::ALPHA_BLENDING),
+            false => Some(BlendState::REPLACE),
+        };
+        let depth_stencil = match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            false => None,
+        };
+        // the columns of the matrix and the albedo
+        let vec4_attribute = |i: u32| VertexAttribute {
+            format: VertexFormat::Float32x4,
+            offset: i as BufferAddress * 4 * 4,
+            shader_location: 3 + i,
+        };
+        let sample_count = scene_desc.backend_buffer.sample_count;
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &self.shaders.vertex_module,
+                entry_point: self.shaders.vertex_entry,
+                buffers: &[
+                    VertexBufferLayout {
+                        array_stride: std::mem::size_of::<AttrVertex>() as BufferAddress,
+                        step_mode: VertexStepMode::Vertex,
+                        attributes: &[
+                            VertexAttribute {
+                                format: VertexFormat::Float32x3,
+                                offset: 0,
+                                shader_location: 0,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: 3 * 4,
+                                shader_location: 1,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x3,
+                                offset: 2 * 4 + 3 * 4,
+                                shader_location: 2,
+                            },
+                        ],
+                    },
+                    VertexBufferLayout {
+                        array_stride: std::mem::size_of::<InstanceAttributes>() as BufferAddress,
+                        step_mode: VertexStepMode::Instance,
+                        attributes: &[
+                            vec4_attribute(0),
+                            vec4_attribute(1),
+                            vec4_attribute(2),
+                            vec4_attribute(3),
+                            vec4_attribute(4),
+                        ],
+                    },
+                ],
+            },
+            fragment: Some(FragmentState {
+                module: &self.shaders.fragment_module,
+                entry_point: self.shaders.fragment_entry,
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                front_face: FrontFace::Ccw,
+                cull_mode,
+                polygon_mode: PolygonMode::Fill,
+                ..Default::default()
+            },
+            depth_stencil,
+            multisample: MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: sample_count > 1,
+            },
+            label: None,
+            multiview: None,
+        });
+        Arc::new(pipeline)
+    }
+}
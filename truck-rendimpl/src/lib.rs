@@ -44,6 +44,27 @@ pub struct Material {
     pub alpha_blend: bool,
 }
 
+/// Configures of the edges drawn over the faces of `PolygonInstance`.
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeOverlay {
+    /// color of the edges, [0, 1]-normalized rgba. Default is `Vector4::new(0.0, 0.0, 0.0, 1.0)`.
+    pub color: Vector4,
+    /// width of the edges in pixels. Default is 1.5.
+    pub width: f64,
+}
+
+/// The element of `PolygonInstance` under the cursor, decoded by [`PolygonInstance::picked`].
+///
+/// The indices are the ones of the faces and the edges given to
+/// [`InstanceCreator::create_pickable_instance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PickedElement {
+    /// the index of the face, i.e. the value of the provenance of the picked polygon
+    Face(usize),
+    /// the index of the edge
+    Edge(usize),
+}
+
 /// Configures of instances.
 #[derive(Clone, Debug)]
 pub struct PolygonState {
@@ -55,6 +76,12 @@ pub struct PolygonState {
     pub texture: Option<Arc<Texture>>,
     /// If this parameter is true, the backface culling will be activated.
     pub backface_culling: bool,
+    /// If this parameter is `Some`, the edges given by
+    /// [`InstanceCreator::create_instance_with_edges`] are drawn over the faces.
+    pub edge_overlay: Option<EdgeOverlay>,
+    /// If this parameter is false, the instance is not cut by the clip planes of the scene.
+    /// The caps are drawn only if `backface_culling` is false. Default is true.
+    pub clipping: bool,
 }
 
 /// Configures of `WireFrameInstance`.
@@ -64,6 +91,9 @@ pub struct WireFrameState {
     pub matrix: Matrix4,
     /// color of instance
     pub color: Vector4,
+    /// If this parameter is false, the instance is not cut by the clip planes of the scene.
+    /// Default is true.
+    pub clipping: bool,
 }
 
 /// shaders for rendering polygons
@@ -97,11 +127,51 @@ pub struct WireShaders {
 #[derive(Debug)]
 pub struct PolygonInstance {
     polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
+    center: Point3,
+    material_buffer: Arc<BufferHandler>,
     state: PolygonState,
     shaders: PolygonShaders,
+    edges: Option<EdgeBuffer>,
+    picks: Option<PickBuffer>,
+    handler: DeviceHandler,
+    id: RenderID,
+}
+
+/// Polygon instances of the same mesh drawn by one draw call
+///
+/// The mesh data is uploaded once, and each instance has its own matrix and albedo in the
+/// per-instance vertex buffer. The matrix of each instance is applied before the matrix of the
+/// state, and the albedo of each instance replaces the one of the material. The texture of
+/// the state is ignored, and the instances are not pickable.
+#[derive(Debug)]
+pub struct InstancedPolygonInstance {
+    polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
+    attributes: Vec<InstanceAttributes>,
+    instance_buffer: Arc<BufferHandler>,
+    material_buffer: Arc<BufferHandler>,
+    state: PolygonState,
+    shaders: PolygonShaders,
+    handler: DeviceHandler,
     id: RenderID,
 }
 
+/// Vertices of the edges drawn over the faces of `PolygonInstance`
+#[derive(Debug, Clone)]
+struct EdgeBuffer {
+    vertices: Arc<BufferHandler>,
+    shaders: WireShaders,
+    id: RenderID,
+}
+
+/// Vertices of `PolygonInstance` rendered into the pick buffer
+#[derive(Debug, Clone)]
+struct PickBuffer {
+    faces: Arc<BufferHandler>,
+    edges: Option<Arc<BufferHandler>>,
+    module: Arc<ShaderModule>,
+    ids: [RenderID; 2],
+}
+
 /// Wire frame rendering
 #[derive(Debug)]
 pub struct WireFrameInstance {
@@ -118,6 +188,9 @@ pub struct InstanceCreator {
     handler: DeviceHandler,
     polygon_shaders: PolygonShaders,
     wire_shaders: WireShaders,
+    edge_shaders: WireShaders,
+    pick_module: Arc<ShaderModule>,
+    instanced_shaders: PolygonShaders,
 }
 
 /// for creating `InstanceCreator`
@@ -154,6 +227,20 @@ pub trait Instance {
     fn standard_shaders(creator: &InstanceCreator) -> Self::Shaders;
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct ModelMatrix {
+    matrix: [[f32; 4]; 4],
+    clipping: [u32; 4],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct InstanceAttributes {
+    matrix: [[f32; 4]; 4],
+    albedo: [f32; 4],
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
 struct AttrVertex {
@@ -162,10 +249,13 @@ struct AttrVertex {
     pub normal: [f32; 3],
 }
 
+mod edge_overlay;
 /// utility for creating `Texture`
 pub mod image2texture;
 mod instance_creator;
 mod instance_descriptor;
+mod instanced_polygon;
+mod pick;
 mod polygon_instance;
 mod polyrend;
 mod wireframe_instance;
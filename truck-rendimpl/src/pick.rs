@@ -0,0 +1,274 @@
+use crate::*;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct PickVertex {
+    position: [f32; 3],
+    other: [f32; 3],
+    side: f32,
+    id: u32,
+}
+
+impl PickedElement {
+    #[inline(always)]
+    fn id(self) -> u32 {
+        match self {
+            PickedElement::Face(i) => 2 * i as u32,
+            PickedElement::Edge(i) => 2 * i as u32 + 1,
+        }
+    }
+
+    #[inline(always)]
+    fn from_id(id: u32) -> PickedElement {
+        match id % 2 == 0 {
+            true => PickedElement::Face(id as usize / 2),
+            false => PickedElement::Edge(id as usize / 2),
+        }
+    }
+}
+
+impl PickBuffer {
+    /// Creates the shader module for the pick buffer.
+    pub(crate) fn shader_module(device: &Device) -> ShaderModule {
+        let source = include_str!("shaders/clip-module.wgsl").to_string()
+            + include_str!("shaders/pick.wgsl");
+        device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(source.into()),
+            label: None,
+        })
+    }
+
+    /// Creates the triangles of the faces and the quads of the segments of the edges.
+    ///
+    /// The `i`-th face of `mesh` is labeled by `provenance[i]`.
+    pub(crate) fn new(
+        device: &Device,
+        mesh: &PolygonMesh,
+        provenance: &[usize],
+        edges: &[PolylineCurve<Point3>],
+        module: Arc<ShaderModule>,
+    ) -> PickBuffer {
+        let positions = mesh.positions();
+        let faces: Vec<PickVertex> = mesh
+            .faces()
+            .face_iter()
+            .zip(provenance)
+            .flat_map(|(face, face_index)| {
+                let id = PickedElement::Face(*face_index).id();
+                let vertex = move |v: &StandardVertex| {
+                    let position: [f32; 3] = positions[v.pos].cast().unwrap().into();
+                    PickVertex {
+                        position,
+                        other: position,
+                        side: 0.0,
+                        id,
+                    }
+                };
+                // fan triangulation, the same as `Faces::triangle_iter`
+                (2..face.len())
+                    .flat_map(move |i| [vertex(&face[0]), vertex(&face[i - 1]), vertex(&face[i])])
+            })
+            .collect();
+        let edges: Vec<PickVertex> = edges
+            .iter()
+            .enumerate()
+            .flat_map(|(edge_index, edge)| {
+                let id = PickedElement::Edge(edge_index).id();
+                edge.windows(2)
+                    .filter(|segment| segment[0] != segment[1])
+                    .flat_map(move |segment| {
+                        let a: [f32; 3] = segment[0].cast().unwrap().into();
+                        let b: [f32; 3] = segment[1].cast().unwrap().into();
+                        let vertex = |position, other, side| PickVertex {
+                            position,
+                            other,
+                            side,
+                            id,
+                        };
+                        [
+                            vertex(a, b, 1.0),
+                            vertex(a, b, -1.0),
+                            vertex(b, a, -1.0),
+                            vertex(a, b, -1.0),
+                            vertex(b, a, 1.0),
+                            vertex(b, a, -1.0),
+                        ]
+                    })
+            })
+            .collect();
+        let buffer = |vertices: &[PickVertex]| {
+            Arc::new(BufferHandler::from_slice(
+                vertices,
+                device,
+                BufferUsages::VERTEX,
+            ))
+        };
+        PickBuffer {
+            faces: buffer(&faces),
+            edges: match edges.is_empty() {
+                true => None,
+                false => Some(buffer(&edges)),
+            },
+            module,
+            ids: [RenderID::gen(), RenderID::gen()],
+        }
+    }
+
+    /// Returns the pick objects of the faces and the edges.
+    pub(crate) fn pick_objects<'a>(&'a self, instance: &'a PolygonInstance) -> Vec<PickObject<'a>> {
+        let faces = PickObject {
+            vertices: &self.faces,
+            module: &self.module,
+            edge: false,
+            state: &instance.state,
+            id: self.ids[0],
+        };
+        let edges = self.edges.as_ref().map(|vertices| PickObject {
+            vertices,
+            module: &self.module,
+            edge: true,
+            state: &instance.state,
+            id: self.ids[1],
+        });
+        std::iter::once(faces).chain(edges).collect()
+    }
+}
+
+impl PolygonInstance {
+    /// Returns the face or the edge of `self` picked by [`Scene::render_pick_buffer`].
+    ///
+    /// Returns `None` if `result` is not the one of `self`, or `self` is not created by
+    /// [`InstanceCreator::create_pickable_instance`].
+    #[inline(always)]
+    pub fn picked(&self, result: &PickResult) -> Option<PickedElement> {
+        match self.picks.is_some() && result.render_id == self.id {
+            true => Some(PickedElement::from_id(result.id)),
+            false => None,
+        }
+    }
+}
+
+/// Render object of the faces or the edges in the pick buffer.
+pub(crate) struct PickObject<'a> {
+    vertices: &'a Arc<BufferHandler>,
+    module: &'a Arc<ShaderModule>,
+    edge: bool,
+    state: &'a PolygonState,
+    id: RenderID,
+}
+
+impl Rendered for PickObject<'_> {
+    #[inline(always)]
+    fn render_id(&self) -> RenderID { self.id }
+    #[inline(always)]
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        (Arc::clone(self.vertices), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(bind_group_util::create_bind_group_layout(
+            handler.device(),
+            &[PolygonState::matrix_bgl_entry()],
+        ))
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        let device = handler.device();
+        Arc::new(bind_group_util::create_bind_group(
+            device,
+            layout,
+            vec![self.state.matrix_buffer(device).binding_resource()],
+        ))
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let device = handler.device();
+        let (entry_point, cull_mode, depth_compare, bias) = match self.edge {
+            // The edges are pulled to the camera in the same way as the edge overlay.
+            true => (
+                "vs_edge",
+                None,
+                CompareFunction::LessEqual,
+                DepthBiasState {
+                    constant: -4,
+                    slope_scale: -2.0,
+                    clamp: 0.0,
+                },
+            ),
+            false => (
+                "vs_face",
+                match self.state.backface_culling {
+                    true => Some(Face::Back),
+                    false => None,
+                },
+                CompareFunction::Less,
+                Default::default(),
+            ),
+        };
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: self.module,
+                entry_point,
+                buffers: &[VertexBufferLayout {
+                    array_stride: std::mem::size_of::<PickVertex>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 3 * 4,
+                            shader_location: 1,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32,
+                            offset: 2 * 3 * 4,
+                            shader_location: 2,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Uint32,
+                            offset: 2 * 3 * 4 + 4,
+                            shader_location: 3,
+                        },
+                    ],
+                }],
+            },
+            fragment: Some(FragmentState {
+                module: self.module,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                front_face: FrontFace::Ccw,
+                cull_mode,
+                ..Default::default()
+            },
+            depth_stencil: Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare,
+                stencil: Default::default(),
+                bias,
+            }),
+            multisample: MultisampleState {
+                count: scene_desc.backend_buffer.sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: None,
+            multiview: None,
+        });
+        Arc::new(pipeline)
+    }
+}
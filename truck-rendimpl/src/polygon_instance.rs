@@ -1,13 +1,40 @@
 use crate::*;
 
 impl PolygonInstance {
+    #[inline(always)]
+    pub(crate) fn new(
+        handler: &DeviceHandler,
+        (vb, ib): (BufferHandler, BufferHandler),
+        center: Point3,
+        state: &PolygonState,
+        shaders: &PolygonShaders,
+    ) -> PolygonInstance {
+        PolygonInstance {
+            polygon: (Arc::new(vb), Arc::new(ib)),
+            center,
+            material_buffer: Arc::new(state.material.buffer(handler.device())),
+            state: state.clone(),
+            shaders: shaders.clone(),
+            edges: None,
+            picks: None,
+            handler: handler.clone(),
+            id: RenderID::gen(),
+        }
+    }
+
     /// Clone the instance as another drawn element.
     #[inline(always)]
     pub fn clone_instance(&self) -> PolygonInstance {
+        let material_buffer = self.state.material.buffer(self.handler.device());
         PolygonInstance {
             polygon: self.polygon.clone(),
+            center: self.center,
+            material_buffer: Arc::new(material_buffer),
             state: self.state.clone(),
             shaders: self.shaders.clone(),
+            edges: self.edges.clone(),
+            picks: self.picks.clone(),
+            handler: self.handler.clone(),
             id: RenderID::gen(),
         }
     }
@@ -18,10 +45,53 @@ impl PolygonInstance {
     #[inline(always)]
     pub fn instance_state_mut(&mut self) -> &mut PolygonState { &mut self.state }
 
+    /// Returns whether the instance is transparent, i.e. the flag `alpha_blend` of the material
+    /// is true and the alpha of the albedo is less than 1.
+    ///
+    /// The transparent instances are rendered after the opaque ones from back to front. If
+    /// `backface_culling` is false, the back faces of a transparent instance are rendered before
+    /// its front faces in another pass. Since the number of the passes is changed, the render
+    /// object has to be recreated by `Scene::add_object` if the transparency is changed.
+    #[inline(always)]
+    pub fn is_transparent(&self) -> bool {
+        let material = &self.state.material;
+        material.alpha_blend && material.albedo[3] < 1.0
+    }
+
+    /// Sets the material of the instance.
+    ///
+    /// The material uniform buffer held by the bind group is rewritten in place, so the change is
+    /// reflected in the next rendering without updating the scene. Only if the flag `alpha_blend`
+    /// or the transparency is changed, the render object has to be recreated by
+    /// `Scene::add_object`, and returns `true`.
+    #[inline(always)]
+    pub fn set_material(&mut self, material: Material) -> bool {
+        let blend_changed = self.state.material.alpha_blend != material.alpha_blend;
+        let transparent = self.is_transparent();
+        self.state.material = material;
+        material.write_buffer(self.handler.queue(), &self.material_buffer);
+        blend_changed || transparent != self.is_transparent()
+    }
+
+    /// Sets the texture of the instance.
+    ///
+    /// The vertex buffers are not touched. Returns `true` if the presence of the texture is
+    /// changed. In that case, the shader and the bind group layout are different, so the render
+    /// object has to be recreated by `Scene::add_object`. Otherwise, it is sufficient to update
+    /// the bind group by `Scene::update_bind_group`.
+    #[inline(always)]
+    pub fn set_texture(&mut self, texture: Option<Arc<DynamicImage>>) -> bool {
+        let had_texture = self.state.texture.is_some();
+        self.state.texture =
+            texture.map(|image| Arc::new(image2texture::image2texture(&self.handler, &image)));
+        had_texture != self.state.texture.is_some()
+    }
+
     /// swap vertex buffers and index buffers
     #[inline(always)]
     pub fn swap_vertex(&mut self, other: &mut PolygonInstance) {
         std::mem::swap(&mut self.polygon, &mut other.polygon);
+        std::mem::swap(&mut self.center, &mut other.center);
     }
 
     #[inline(always)]
@@ -54,7 +124,7 @@ impl PolygonInstance {
             layout,
             vec![
                 self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
+                self.material_buffer.binding_resource(),
             ],
         )
     }
@@ -66,46 +136,19 @@ impl PolygonInstance {
             layout,
             vec![
                 self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
+                self.material_buffer.binding_resource(),
                 BindingResource::TextureView(&view),
                 BindingResource::Sampler(&sampler),
             ],
         )
     }
-}
-
-impl Rendered for PolygonInstance {
-    impl_render_id!(id);
 
-    #[inline(always)]
-    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
-        let polygon = self.polygon.clone();
-        (polygon.0, Some(polygon.1))
-    }
-    #[inline(always)]
-    fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bdl(device_handler.device()),
-            false => self.non_textured_bdl(device_handler.device()),
-        })
-    }
-    #[inline(always)]
-    fn bind_group(
-        &self,
-        device_handler: &DeviceHandler,
-        layout: &BindGroupLayout,
-    ) -> Arc<BindGroup> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bg(device_handler.device(), layout),
-            false => self.non_textured_bg(device_handler.device(), layout),
-        })
-    }
-    #[inline(always)]
-    fn pipeline(
+    fn create_pipeline(
         &self,
         device_handler: &DeviceHandler,
         layout: &PipelineLayout,
         scene_desc: &SceneDescriptor,
+        cull_mode: Option<Face>,
     ) -> Arc<RenderPipeline> {
         let device = device_handler.device();
         let (fragment_module, fragment_entry) = match self.state.texture.is_some() {
@@ -115,10 +158,6 @@ impl Rendered for PolygonInstance {
             ),
             false => (&self.shaders.fragment_module, self.shaders.fragment_entry),
         };
-        let cull_mode = match self.state.backface_culling {
-            true => Some(Face::Back),
-            false => None,
-        };
         let blend = match self.state.material.alpha_blend {
             true => Some(BlendState::ALPHA_BLENDING),
             false => Some(BlendState::REPLACE),
@@ -126,7 +165,8 @@ impl Rendered for PolygonInstance {
         let depth_stencil = match scene_desc.backend_buffer.depth_test {
             true => Some(DepthStencilState {
                 format: TextureFormat::Depth32Float,
-                depth_write_enabled: true,
+                // The transparent instances do not hide the ones behind them.
+                depth_write_enabled: !self.is_transparent(),
                 depth_compare: CompareFunction::Less,
                 stencil: Default::default(),
                 bias: Default::default(),
@@ -189,3 +229,112 @@ impl Rendered for PolygonInstance {
         Arc::new(pipeline)
     }
 }
+
+impl Rendered for PolygonInstance {
+    impl_render_id!(id);
+
+    #[inline(always)]
+    fn overlays(&self) -> Vec<Box<dyn Rendered + '_>> {
+        let front_faces = match self.is_transparent() && !self.state.backface_culling {
+            true => Some(Box::new(FrontFaces(self)) as Box<dyn Rendered + '_>),
+            false => None,
+        };
+        let edges = self
+            .edges
+            .iter()
+            .map(|edges| Box::new(edges.overlay(self)) as Box<dyn Rendered + '_>);
+        front_faces.into_iter().chain(edges).collect()
+    }
+
+    #[inline(always)]
+    fn transparent_center(&self) -> Option<Point3> {
+        match self.is_transparent() {
+            true => Some(self.state.matrix.transform_point(self.center)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    fn pick_objects(&self) -> Vec<Box<dyn Rendered + '_>> {
+        self.picks
+            .iter()
+            .flat_map(|picks| picks.pick_objects(self))
+            .map(|pick| Box::new(pick) as Box<dyn Rendered + '_>)
+            .collect()
+    }
+
+    #[inline(always)]
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let polygon = self.polygon.clone();
+        (polygon.0, Some(polygon.1))
+    }
+    #[inline(always)]
+    fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(match self.state.texture.is_some() {
+            true => self.textured_bdl(device_handler.device()),
+            false => self.non_textured_bdl(device_handler.device()),
+        })
+    }
+    #[inline(always)]
+    fn bind_group(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &BindGroupLayout,
+    ) -> Arc<BindGroup> {
+        // `instance_state_mut` may have changed the material.
+        let material = &self.state.material;
+        material.write_buffer(device_handler.queue(), &self.material_buffer);
+        Arc::new(match self.state.texture.is_some() {
+            true => self.textured_bg(device_handler.device(), layout),
+            false => self.non_textured_bg(device_handler.device(), layout),
+        })
+    }
+    #[inline(always)]
+    fn pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        // The back faces of the transparent instance are rendered first, see `FrontFaces`.
+        let cull_mode = match (self.state.backface_culling, self.is_transparent()) {
+            (true, _) => Some(Face::Back),
+            (false, true) => Some(Face::Front),
+            (false, false) => None,
+        };
+        self.create_pipeline(device_handler, layout, scene_desc, cull_mode)
+    }
+}
+
+/// The front faces of the transparent instance, rendered after the back faces.
+struct FrontFaces<'a>(&'a PolygonInstance);
+
+impl Rendered for FrontFaces<'_> {
+    #[inline(always)]
+    fn render_id(&self) -> RenderID { self.0.id }
+    #[inline(always)]
+    fn vertex_buffer(
+        &self,
+        handler: &DeviceHandler,
+    ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        self.0.vertex_buffer(handler)
+    }
+    #[inline(always)]
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        self.0.bind_group_layout(handler)
+    }
+    #[inline(always)]
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        self.0.bind_group(handler, layout)
+    }
+    #[inline(always)]
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        self.0
+            .create_pipeline(handler, layout, scene_desc, Some(Face::Back))
+    }
+}
@@ -50,12 +50,8 @@ impl ToInstance<PolygonInstance> for PolygonMesh {
         state: &PolygonState,
     ) -> PolygonInstance {
         let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
-        PolygonInstance {
-            polygon: (Arc::new(vb), Arc::new(ib)),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
-        }
+        let center = self.bounding_box().center();
+        PolygonInstance::new(handler, (vb, ib), center, state, shaders)
     }
 }
 
@@ -142,12 +138,8 @@ impl ToInstance<PolygonInstance> for StructuredMesh {
         state: &PolygonState,
     ) -> PolygonInstance {
         let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
-        PolygonInstance {
-            polygon: (Arc::new(vb), Arc::new(ib)),
-            state: state.clone(),
-            shaders: shaders.clone(),
-            id: RenderID::gen(),
-        }
+        let bdd_box: BoundingBox<Point3> = self.positions().iter().flatten().collect();
+        PolygonInstance::new(handler, (vb, ib), bdd_box.center(), state, shaders)
     }
 }
 
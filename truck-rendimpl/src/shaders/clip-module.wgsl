@@ -0,0 +1,26 @@
+struct ClipPlanes {
+    planes: array<vec4<f32>, 4>,
+    cap_color: vec4<f32>,
+    nplanes: u32,
+    cap: u32,
+}
+
+@group(0)
+@binding(3)
+var<uniform> clip_planes: ClipPlanes;
+
+// Returns true if `position` is cut away by the clip planes of the scene.
+fn is_clipped(position: vec3<f32>) -> bool {
+    for (var i: u32 = 0u; i < clip_planes.nplanes; i = i + 1u) {
+        let plane = clip_planes.planes[i];
+        if (dot(plane.xyz, position) + plane.w > 0.0) {
+            return true;
+        }
+    }
+    return false;
+}
+
+// Returns true if the back face is painted by the cap color.
+fn is_cap(front_facing: bool) -> bool {
+    return !front_facing && clip_planes.nplanes > 0u && clip_planes.cap != 0u;
+}
@@ -0,0 +1,67 @@
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct SceneInfo {
+    bk_color: vec4<f32>,
+    resolution: vec2<u32>,
+    time: f32,
+    nlights: u32,
+}
+
+@group(0)
+@binding(2)
+var<uniform> info: SceneInfo;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+    clipping: u32,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+struct EdgeOverlay {
+    color: vec4<f32>,
+    width: f32,
+}
+
+@group(1)
+@binding(1)
+var<uniform> overlay: EdgeOverlay;
+
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) position: vec3<f32>,
+}
+
+// Each segment is expanded to the quad whose width is `overlay.width` pixels.
+// `side` selects the side of the segment on the screen.
+@vertex
+fn vs_main(
+    @location(0) position: vec3<f32>,
+    @location(1) other: vec3<f32>,
+    @location(2) side: f32,
+) -> VertexOutput {
+    let world_position = model_matrix.model_matrix * vec4<f32>(position, 1.0);
+    let p = camera.projection * world_position;
+    let q = camera.projection * model_matrix.model_matrix * vec4<f32>(other, 1.0);
+    let resolution = vec2<f32>(info.resolution);
+    let dir = normalize((q.xy / q.w - p.xy / p.w) * resolution);
+    let offset = vec2<f32>(-dir.y, dir.x) * side * overlay.width / resolution;
+    return VertexOutput(p + vec4<f32>(offset * p.w, 0.0, 0.0), world_position.xyz);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (model_matrix.clipping != 0u && is_clipped(in.position)) {
+        discard;
+    }
+    return vec4<f32>(pow(overlay.color.rgb, vec3<f32>(0.4545)), overlay.color.a);
+}
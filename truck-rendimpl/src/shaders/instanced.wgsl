@@ -0,0 +1,68 @@
+struct InstanceInput {
+    @location(3) matrix0: vec4<f32>,
+    @location(4) matrix1: vec4<f32>,
+    @location(5) matrix2: vec4<f32>,
+    @location(6) matrix3: vec4<f32>,
+    @location(7) albedo: vec4<f32>,
+}
+
+struct InstancedOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) normal: vec3<f32>,
+    @location(3) albedo: vec4<f32>,
+}
+
+@vertex
+fn vs_instanced(in: VertexInput, instance: InstanceInput) -> InstancedOutput {
+    let matrix = model_matrix.model_matrix * mat4x4<f32>(
+        instance.matrix0,
+        instance.matrix1,
+        instance.matrix2,
+        instance.matrix3,
+    );
+    let world_position = matrix * vec4<f32>(in.position, 1.0);
+    let world_normal = matrix * vec4<f32>(in.normal, 0.0);
+    return InstancedOutput(
+        camera.projection * world_position,
+        world_position.xyz,
+        in.uv,
+        normalize(world_normal.xyz),
+        instance.albedo,
+    );
+}
+
+@fragment
+fn instanced_main(
+    in: InstancedOutput,
+    @builtin(front_facing) front_facing: bool,
+) -> @location(0) vec4<f32> {
+    if (model_matrix.clipping != 0u) {
+        if (is_clipped(in.position)) {
+            discard;
+        }
+        if (is_cap(front_facing)) {
+            return clip_planes.cap_color;
+        }
+    }
+    var matr: Material = material.material;
+    matr.albedo = in.albedo;
+    let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
+    let normal = normalize(in.normal);
+    var pre_color: vec3<f32> = vec3<f32>(0.0);
+    for (var i: u32 = 0u; i < info.nlights; i = i + 1u) {
+        pre_color = pre_color + microfacet_color(
+            in.position,
+            normal,
+            lights.lights[i],
+            camera_dir,
+            matr,
+        );
+    }
+    pre_color = clamp(pre_color, vec3<f32>(0.0), vec3<f32>(1.0));
+    pre_color = background_correction(pre_color, info.bk_color.xyz, matr);
+    pre_color = ambient_correction(pre_color, matr);
+
+    return vec4<f32>(pow(pre_color, vec3<f32>(0.4545)), matr.albedo.a);
+}
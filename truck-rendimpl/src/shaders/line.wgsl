@@ -9,6 +9,7 @@ var<uniform> camera: Camera;
 
 struct ModelMatrix {
     model_matrix: mat4x4<f32>,
+    clipping: u32,
 }
 
 @group(1)
@@ -23,14 +24,23 @@ struct Color {
 @binding(1)
 var<uniform> color: Color;
 
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) position: vec3<f32>,
+}
+
 @vertex
-fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
-    var res: vec4<f32> = camera.projection * model_matrix.model_matrix * vec4<f32>(position, 1.0);
+fn vs_main(@location(0) position: vec3<f32>) -> VertexOutput {
+    let world_position = model_matrix.model_matrix * vec4<f32>(position, 1.0);
+    var res: vec4<f32> = camera.projection * world_position;
     res.z = res.z - 1.0e-4;
-    return res;
+    return VertexOutput(res, world_position.xyz);
 }
 
 @fragment
-fn fs_main() -> @location(0) vec4<f32> {
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (model_matrix.clipping != 0u && is_clipped(in.position)) {
+        discard;
+    }
     return vec4<f32>(pow(color.color.rgb, vec3<f32>(0.4545)), color.color.a);
 }
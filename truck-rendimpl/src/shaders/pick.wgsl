@@ -0,0 +1,81 @@
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct SceneInfo {
+    bk_color: vec4<f32>,
+    resolution: vec2<u32>,
+    time: f32,
+    nlights: u32,
+}
+
+@group(0)
+@binding(2)
+var<uniform> info: SceneInfo;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+    clipping: u32,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+// width of the edges in the pick buffer, in pixels
+const EDGE_WIDTH: f32 = 6.0;
+
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) position: vec3<f32>,
+    @location(1) @interpolate(flat) ids: vec2<u32>,
+}
+
+@vertex
+fn vs_face(
+    @builtin(instance_index) instance: u32,
+    @location(0) position: vec3<f32>,
+    @location(3) id: u32,
+) -> VertexOutput {
+    let world_position = model_matrix.model_matrix * vec4<f32>(position, 1.0);
+    var out: VertexOutput;
+    out.gl_position = camera.projection * world_position;
+    out.position = world_position.xyz;
+    out.ids = vec2<u32>(instance, id);
+    return out;
+}
+
+// The same expansion of segments as `edge_overlay.wgsl`.
+@vertex
+fn vs_edge(
+    @builtin(instance_index) instance: u32,
+    @location(0) position: vec3<f32>,
+    @location(1) other: vec3<f32>,
+    @location(2) side: f32,
+    @location(3) id: u32,
+) -> VertexOutput {
+    let world_position = model_matrix.model_matrix * vec4<f32>(position, 1.0);
+    let p = camera.projection * world_position;
+    let q = camera.projection * model_matrix.model_matrix * vec4<f32>(other, 1.0);
+    let resolution = vec2<f32>(info.resolution);
+    let dir = normalize((q.xy / q.w - p.xy / p.w) * resolution);
+    let offset = vec2<f32>(-dir.y, dir.x) * side * EDGE_WIDTH / resolution;
+    var out: VertexOutput;
+    out.gl_position = p + vec4<f32>(offset * p.w, 0.0, 0.0);
+    out.position = world_position.xyz;
+    out.ids = vec2<u32>(instance, id);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec2<u32> {
+    if (model_matrix.clipping != 0u && is_clipped(in.position)) {
+        discard;
+    }
+    return in.ids;
+}
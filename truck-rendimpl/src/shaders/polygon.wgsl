@@ -33,6 +33,7 @@ var<uniform> info: SceneInfo;
 
 struct ModelMatrix {
     model_matrix: mat4x4<f32>,
+    clipping: u32,
 }
 
 @group(1)
@@ -77,7 +78,18 @@ fn vs_main(in: VertexInput) -> VertexOutput {
 const e: vec2<f32> = vec2<f32>(1.0, 0.0);
 
 @fragment
-fn nontex_main(in: VertexInput) -> @location(0) vec4<f32> {
+fn nontex_main(
+    in: VertexInput,
+    @builtin(front_facing) front_facing: bool,
+) -> @location(0) vec4<f32> {
+    if (model_matrix.clipping != 0u) {
+        if (is_clipped(in.position)) {
+            discard;
+        }
+        if (is_cap(front_facing)) {
+            return clip_planes.cap_color;
+        }
+    }
     let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
     let normal = normalize(in.normal);
     var pre_color: vec3<f32> = vec3<f32>(0.0);
@@ -98,10 +110,22 @@ fn nontex_main(in: VertexInput) -> @location(0) vec4<f32> {
 }
 
 @fragment
-fn tex_main(in: VertexInput) -> @location(0) vec4<f32> {
+fn tex_main(
+    in: VertexInput,
+    @builtin(front_facing) front_facing: bool,
+) -> @location(0) vec4<f32> {
     var matr: Material = material.material;
     matr.albedo = textureSample(r_color, r_sampler, in.uv);
     matr.albedo = vec4<f32>(pow(matr.albedo.rgb, vec3<f32>(2.2)), matr.albedo.a);
+    // The texture is sampled before the non-uniform control flow.
+    if (model_matrix.clipping != 0u) {
+        if (is_clipped(in.position)) {
+            discard;
+        }
+        if (is_cap(front_facing)) {
+            return clip_planes.cap_color;
+        }
+    }
     let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
     let normal = normalize(in.normal);
     var pre_color: vec3<f32> = vec3<f32>(0.0);
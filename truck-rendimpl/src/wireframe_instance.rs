@@ -6,6 +6,7 @@ impl Default for WireFrameState {
         WireFrameState {
             matrix: Matrix4::identity(),
             color: Vector4::new(1.0, 1.0, 1.0, 1.0),
+            clipping: true,
         }
     }
 }
@@ -52,7 +53,7 @@ impl Rendered for WireFrameInstance {
             &[
                 // matrix
                 PreBindGroupLayoutEntry {
-                    visibility: ShaderStages::VERTEX,
+                    visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
                     ty: BindingType::Buffer {
                         ty: BufferBindingType::Uniform,
                         has_dynamic_offset: false,
@@ -75,8 +76,7 @@ impl Rendered for WireFrameInstance {
     }
     fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
         let device = handler.device();
-        let matrix_data: [[f32; 4]; 4] = self.state.matrix.cast::<f32>().unwrap().into();
-        let matrix_buffer = BufferHandler::from_slice(&matrix_data, device, BufferUsages::UNIFORM);
+        let matrix_buffer = ModelMatrix::buffer(self.state.matrix, self.state.clipping, device);
         let color_data: [f32; 4] = self.state.color.cast::<f32>().unwrap().into();
         let color_buffer = BufferHandler::from_slice(&color_data, device, BufferUsages::UNIFORM);
         Arc::new(bind_group_util::create_bind_group(
@@ -81,6 +81,8 @@ fn nontex_inst_state() -> PolygonState {
         },
         texture: None,
         backface_culling: true,
+        edge_overlay: None,
+        clipping: true,
     }
 }
 
@@ -168,3 +168,342 @@ fn exec_tex_render_test(backend: Backends, out_dir: &str) {
 
 #[test]
 fn tex_render_test() { common::os_alt_exec_test(exec_tex_render_test) }
+
+fn cube_instance(creator: &InstanceCreator, albedo: Vector4) -> PolygonInstance {
+    creator.create_instance(
+        &obj::read(CUBE_OBJ).unwrap(),
+        &PolygonState {
+            material: Material {
+                albedo,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+}
+
+fn exec_set_material_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let white = Vector4::new(1.0, 1.0, 1.0, 1.0);
+    let red = Vector4::new(1.0, 0.2, 0.2, 1.0);
+
+    let mut cube = cube_instance(&creator, white);
+    scene.add_object(&cube);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    let material = Material {
+        albedo: red,
+        ..Default::default()
+    };
+    assert!(!cube.set_material(material));
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    scene.remove_object(&cube);
+
+    let answer = common::render_one(&mut scene, &cube_instance(&creator, red));
+    common::save_buffer(out_dir.clone() + "set-material.png", &buffer1, PICTURE_SIZE);
+    common::save_buffer(out_dir + "set-material-answer.png", &answer, PICTURE_SIZE);
+    assert!(common::count_difference(&buffer0, &buffer1) > 1000);
+    assert!(common::same_buffer(&buffer1, &answer));
+}
+
+#[test]
+fn set_material_test() { common::os_alt_exec_test(exec_set_material_test) }
+
+fn exec_set_texture_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let image = Arc::new(generate_texture(&mut scene, out_dir.clone()));
+    let white = Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+    let mut cube = cube_instance(&creator, white);
+    scene.add_object(&cube);
+    // The presence of the texture is changed, so the render object is recreated.
+    assert!(cube.set_texture(Some(Arc::clone(&image))));
+    scene.add_object(&cube);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    // Only the bind group is updated.
+    assert!(!cube.set_texture(Some(Arc::clone(&image))));
+    assert!(scene.update_bind_group(&cube));
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    assert!(cube.set_texture(None));
+    scene.add_object(&cube);
+    let buffer2 = pollster::block_on(scene.render_to_buffer());
+    scene.remove_object(&cube);
+
+    let answer = tex_polygon(&mut scene, &creator, &image);
+    let nontex_answer = common::render_one(&mut scene, &cube_instance(&creator, white));
+    common::save_buffer(out_dir + "set-texture.png", &buffer0, PICTURE_SIZE);
+    assert!(common::same_buffer(&buffer0, &answer));
+    assert!(common::same_buffer(&buffer1, &answer));
+    assert!(common::same_buffer(&buffer2, &nontex_answer));
+}
+
+#[test]
+fn set_texture_test() { common::os_alt_exec_test(exec_set_texture_test) }
+
+fn exec_edge_overlay_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::tessellation::{MeshableShape, MeshedShape};
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+    let meshed = cube.triangulation(0.01);
+    let polygon = meshed.to_polygon();
+    let edges: Vec<_> = meshed.edge_iter().map(|edge| edge.curve()).collect();
+
+    let mut state = PolygonState::default();
+    let plain: PolygonInstance = creator.create_instance(&polygon, &state);
+    let buffer0 = common::render_one(&mut scene, &plain);
+
+    let mut instance = creator.create_instance_with_edges(&polygon, &edges, &state);
+    // Without `edge_overlay`, the edges are not drawn.
+    let buffer1 = common::render_one(&mut scene, &instance);
+    assert!(common::same_buffer(&buffer0, &buffer1));
+
+    state.edge_overlay = Some(EdgeOverlay {
+        color: Vector4::new(0.0, 0.0, 0.0, 1.0),
+        width: 3.0,
+    });
+    *instance.instance_state_mut() = state;
+    let buffer2 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "edge-overlay.png", &buffer2, PICTURE_SIZE);
+
+    let diff = common::count_difference(&buffer0, &buffer2);
+    println!("{diff} pixel difference: edge overlay");
+    assert!(diff > 1000);
+    // The edges are darker than the faces.
+    buffer0
+        .chunks(4)
+        .zip(buffer2.chunks(4))
+        .for_each(|(face, edge)| assert!((0..3).all(|i| edge[i] <= face[i].saturating_add(2))));
+}
+
+#[test]
+fn edge_overlay_test() { common::os_alt_exec_test(exec_edge_overlay_test) }
+
+fn exec_pick_test(backend: Backends, _: &str) {
+    use truck_meshalgo::tessellation::{MeshableShape, MeshedShape};
+    let mut scene = test_scene(backend);
+    scene.studio_config_mut().camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(0.5, 0.5, 5.0),
+            Point3::new(0.5, 0.5, 0.5),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+    let meshed = cube.triangulation(0.01);
+    let (polygon, provenance) = meshed.to_polygon_with_provenance();
+    let edges: Vec<_> = meshed.edge_iter().map(|edge| edge.curve()).collect();
+    let state = PolygonState::default();
+    let instance = creator.create_pickable_instance(&polygon, &provenance, &edges, &state);
+    scene.add_object(&instance);
+
+    let center = (PICTURE_SIZE.0 / 2, PICTURE_SIZE.1 / 2);
+    let result = scene.render_pick_buffer(center).unwrap();
+    assert_eq!(result.render_id, instance.render_id());
+    let face_index = match instance.picked(&result) {
+        Some(PickedElement::Face(i)) => i,
+        picked => panic!("the face is not picked: {picked:?}"),
+    };
+    // The face at z = 1 faces the camera.
+    let face = meshed.face_iter().nth(face_index).unwrap();
+    assert!(face.boundaries()[0]
+        .vertex_iter()
+        .all(|v| f64::abs(v.point().z - 1.0) < 1.0e-6));
+
+    assert_eq!(scene.render_pick_buffer((0, 0)), None);
+    assert_eq!(scene.render_pick_buffer(PICTURE_SIZE), None);
+    scene.set_visibility(&instance, false);
+    assert_eq!(scene.render_pick_buffer(center), None);
+}
+
+#[test]
+fn pick_test() { common::os_alt_exec_test(exec_pick_test) }
+
+fn exec_clip_planes_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::tessellation::{MeshableShape, MeshedShape};
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+
+    let center = Point3::new(0.25, 0.25, 0.25);
+    let sphere = builder::sphere(center, 0.8);
+    let polygon = sphere.triangulation(0.01).to_polygon();
+    let mut instance: PolygonInstance = creator.create_instance(&polygon, &Default::default());
+    let count_drawn = |buffer: &[u8]| buffer.chunks(4).filter(|p| p[..3] != [0, 0, 0]).count();
+
+    let buffer0 = common::render_one(&mut scene, &instance);
+    // The plane through the center and the camera cuts the sphere in half on the screen.
+    scene.descriptor_mut().clip_planes = vec![ClipPlane {
+        origin: center,
+        normal: Vector3::new(1.75, 0.0, 1.25),
+    }];
+    let buffer1 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir.clone() + "clip-planes.png", &buffer1, PICTURE_SIZE);
+    let (count0, count1) = (count_drawn(&buffer0), count_drawn(&buffer1));
+    println!("{count0} pixels are reduced to {count1} by the clip plane");
+    assert!(0.4 * (count0 as f64) < count1 as f64);
+    assert!((count1 as f64) < 0.6 * count0 as f64);
+
+    // The back faces revealed by the cut are painted by the cap color.
+    scene.descriptor_mut().clip_planes = vec![ClipPlane {
+        origin: center,
+        normal: -Vector3::unit_x(),
+    }];
+    scene.descriptor_mut().clip_cap_color = Some(Color::RED);
+    let buffer2 = common::render_one(&mut scene, &instance);
+    assert!(buffer2.chunks(4).all(|p| p != [255, 0, 0, 255]));
+    instance.instance_state_mut().backface_culling = false;
+    let buffer3 = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "clip-planes-cap.png", &buffer3, PICTURE_SIZE);
+    assert!(count_drawn(&buffer3) > count_drawn(&buffer2));
+    assert!(buffer3.chunks(4).any(|p| p == [255, 0, 0, 255]));
+
+    // The instance can opt out of the clipping.
+    instance.instance_state_mut().clipping = false;
+    instance.instance_state_mut().backface_culling = true;
+    let buffer4 = common::render_one(&mut scene, &instance);
+    assert!(common::same_buffer(&buffer0, &buffer4));
+}
+
+#[test]
+fn clip_planes_test() { common::os_alt_exec_test(exec_clip_planes_test) }
+
+fn exec_transparency_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::tessellation::{MeshableShape, MeshedShape};
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+
+    let sphere = |center: Point3, albedo: Vector4| -> PolygonInstance {
+        let polygon = builder::sphere(center, 0.6)
+            .triangulation(0.01)
+            .to_polygon();
+        let state = PolygonState {
+            material: Material {
+                albedo,
+                alpha_blend: true,
+                ..Default::default()
+            },
+            backface_culling: false,
+            ..Default::default()
+        };
+        creator.create_instance(&polygon, &state)
+    };
+    let red = sphere(
+        Point3::new(0.0, 0.25, 0.25),
+        Vector4::new(1.0, 0.2, 0.2, 0.5),
+    );
+    let blue = sphere(
+        Point3::new(0.5, 0.25, 0.25),
+        Vector4::new(0.2, 0.2, 1.0, 0.5),
+    );
+    assert!(red.is_transparent() && blue.is_transparent());
+
+    scene.add_objects([&red, &blue]);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    scene.add_objects([&blue, &red]);
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    common::save_buffer(out_dir + "transparency.png", &buffer0, PICTURE_SIZE);
+    assert!(common::same_buffer(&buffer0, &buffer1));
+
+    // The result is different from the one of the opaque spheres.
+    let opaque = |mut instance: PolygonInstance| {
+        let mut material = instance.instance_state().material;
+        material.albedo[3] = 1.0;
+        assert!(instance.set_material(material));
+        instance
+    };
+    let (red, blue) = (opaque(red), opaque(blue));
+    scene.add_objects([&red, &blue]);
+    let buffer2 = pollster::block_on(scene.render_to_buffer());
+    assert!(common::count_difference(&buffer0, &buffer2) > 1000);
+}
+
+#[test]
+fn transparency_test() { common::os_alt_exec_test(exec_transparency_test) }
+
+fn exec_instanced_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let mesh = obj::read(CUBE_OBJ).unwrap();
+    let matrices = |offset: Vector3| -> Vec<Matrix4> {
+        (0..1000)
+            .map(|i| {
+                let position =
+                    Vector3::new((i % 10) as f64, (i / 10 % 10) as f64, (i / 100) as f64);
+                Matrix4::from_translation(position * 0.1 + offset) * Matrix4::from_scale(0.06)
+            })
+            .collect()
+    };
+    let individual = |matrices: &[Matrix4]| -> Vec<u8> {
+        let instances: Vec<PolygonInstance> = matrices
+            .iter()
+            .map(|matrix| {
+                let state = PolygonState {
+                    matrix: *matrix,
+                    ..Default::default()
+                };
+                creator.create_instance(&mesh, &state)
+            })
+            .collect();
+        scene.add_objects(&instances);
+        let buffer = pollster::block_on(scene.render_to_buffer());
+        scene.clear_objects();
+        buffer
+    };
+    let matrices0 = matrices(Vector3::new(0.0, 0.0, 0.0));
+    let matrices1 = matrices(Vector3::new(-0.2, 0.0, 0.1));
+    let answer0 = individual(&matrices0);
+    let answer1 = individual(&matrices1);
+    let answer2 = individual(&matrices0[..500]);
+
+    let mut instanced =
+        creator.create_instanced_instance(&mesh, &matrices0, &PolygonState::default());
+    assert_eq!(instanced.number_of_instances(), 1000);
+    scene.add_object(&instanced);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    assert!(!instanced.set_instance_matrices(&matrices1));
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "instanced.png", &buffer0, PICTURE_SIZE);
+    common::save_buffer(out_dir + "instanced-moved.png", &buffer1, PICTURE_SIZE);
+    assert!(common::same_buffer(&buffer0, &answer0));
+    assert!(common::same_buffer(&buffer1, &answer1));
+
+    // Changing the number of the instances requires updating the vertex buffers.
+    assert!(instanced.set_instance_matrices(&matrices0[..500]));
+    scene.update_vertex_buffer(&instanced);
+    let buffer2 = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    assert_eq!(instanced.number_of_instances(), 500);
+    assert!(common::same_buffer(&buffer2, &answer2));
+}
+
+#[test]
+fn instanced_test() { common::os_alt_exec_test(exec_instanced_test) }
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Errors occurred by set operations
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum Error {
+    /// The solids have faces which lie on the same plane and overlap each other.
+    /// Such faces are not supported yet. Faces which are apart from each other, even slightly,
+    /// are not regarded as coplanar.
+    #[error("the solids have coplanar faces overlapping each other.")]
+    CoplanarFaces,
+    /// Failed to compute the intersection curves, or to divide and classify the faces.
+    #[error("failed to compute the set operation.")]
+    OperationFailed,
+}
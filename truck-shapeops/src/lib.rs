@@ -13,8 +13,12 @@
     unused_qualifications
 )]
 
+/// Errors occurred by set operations
+pub mod errors;
 mod healing;
 pub use healing::{RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces};
 mod transversal;
-pub use transversal::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use transversal::{
+    and, difference, intersection, or, split_by_plane, union, ShapeOpsCurve, ShapeOpsSurface,
+};
 mod alternative;
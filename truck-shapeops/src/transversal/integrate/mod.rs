@@ -1,5 +1,7 @@
 use super::*;
-use truck_geometry::prelude::IntersectionCurve;
+use crate::errors::Error;
+use rustc_hash::FxHashMap as HashMap;
+use truck_geometry::prelude::{IntersectionCurve, Line, Plane};
 use truck_meshalgo::prelude::*;
 use truck_topology::*;
 
@@ -100,49 +102,345 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
 }
 
 /// AND operation between two solids.
+///
+/// The coplanar faces are not detected, cf. [`intersection`].
+/// # Failures
+/// If the intersection curves cannot be computed, returns
+/// [`Error::OperationFailed`](crate::errors::Error::OperationFailed).
 pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
-) -> Option<Solid<Point3, C, S>> {
+) -> Result<Solid<Point3, C, S>, Error> {
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [mut and_shell, _] = process_one_pair_of_shells(shell0, shell1, tol)?;
+    let [mut and_shell, _] =
+        process_one_pair_of_shells(shell0, shell1, tol).ok_or(Error::OperationFailed)?;
     for shell in iter0 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
+        let [res, _] =
+            process_one_pair_of_shells(&and_shell, shell, tol).ok_or(Error::OperationFailed)?;
         and_shell = res;
     }
     for shell in iter1 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
+        let [res, _] =
+            process_one_pair_of_shells(&and_shell, shell, tol).ok_or(Error::OperationFailed)?;
         and_shell = res;
     }
     let boundaries = and_shell.connected_components();
-    Some(Solid::new(boundaries))
+    Ok(Solid::new(boundaries))
 }
 
 /// OR operation between two solids.
+///
+/// The coplanar faces are not detected, cf. [`union`].
+/// # Failures
+/// The same as [`and`].
 pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
-) -> Option<Solid<Point3, C, S>> {
+) -> Result<Solid<Point3, C, S>, Error> {
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [_, mut or_shell] = process_one_pair_of_shells(shell0, shell1, tol)?;
+    let [_, mut or_shell] =
+        process_one_pair_of_shells(shell0, shell1, tol).ok_or(Error::OperationFailed)?;
     for shell in iter0 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
+        let [_, res] =
+            process_one_pair_of_shells(&or_shell, shell, tol).ok_or(Error::OperationFailed)?;
         or_shell = res;
     }
     for shell in iter1 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
+        let [_, res] =
+            process_one_pair_of_shells(&or_shell, shell, tol).ok_or(Error::OperationFailed)?;
         or_shell = res;
     }
     let boundaries = or_shell.connected_components();
-    Some(Solid::new(boundaries))
+    Ok(Solid::new(boundaries))
+}
+
+/// The relative error regarded as the rounding errors. Faces apart from each other more than it,
+/// e.g. perturbed by `1.0e-9`, are not coplanar and can be operated.
+const ROUNDING_ERROR: f64 = 16.0 * f64::EPSILON;
+
+/// Returns `true` if `p` is inside the triangle `tri` and apart from its edges.
+fn strictly_inside(tri: &[Point3; 3], p: Point3) -> bool {
+    let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]);
+    (0..3).all(|i| {
+        let edge = tri[(i + 1) % 3] - tri[i];
+        let signed_area = normal.dot(edge.cross(p - tri[i]));
+        signed_area > TOLERANCE * normal.magnitude() * edge.magnitude()
+    })
+}
+
+/// Returns `true` if the triangles on the same plane overlap each other.
+fn overlapping_triangles_on_plane(tri0: &[Point3; 3], tri1: &[Point3; 3]) -> bool {
+    let samples = |tri: &[Point3; 3]| {
+        let [a, b, c] = *tri;
+        let center = Point3::centroid(tri);
+        [a, b, c, a.midpoint(b), b.midpoint(c), c.midpoint(a), center]
+    };
+    samples(tri1).into_iter().any(|p| strictly_inside(tri0, p))
+        || samples(tri0).into_iter().any(|p| strictly_inside(tri1, p))
+}
+
+/// Returns `true` if the triangles lie on the same plane up to the rounding errors and overlap
+/// each other.
+fn coplanar_triangles(tri0: &[Point3; 3], tri1: &[Point3; 3]) -> bool {
+    let normal = (tri0[1] - tri0[0]).cross(tri0[2] - tri0[0]);
+    if normal.so_small() {
+        return false;
+    }
+    let normal = normal.normalize();
+    let scale = tri0
+        .iter()
+        .chain(tri1)
+        .fold(1.0, |scale, p| f64::max(scale, p.to_vec().magnitude()));
+    let on_plane = |p: &Point3| f64::abs(normal.dot(p - tri0[0])) <= ROUNDING_ERROR * scale;
+    tri1.iter().all(on_plane) && overlapping_triangles_on_plane(tri0, tri1)
+}
+
+/// Returns `true` if the planes coincide up to the rounding errors.
+fn coincident_planes(plane0: &Plane, plane1: &Plane) -> bool {
+    let (normal0, normal1) = (plane0.normal(), plane1.normal());
+    let (origin0, origin1) = (plane0.origin(), plane1.origin());
+    let scale = 1.0 + f64::max(origin0.to_vec().magnitude(), origin1.to_vec().magnitude());
+    normal0.cross(normal1).magnitude() <= ROUNDING_ERROR
+        && f64::abs(normal0.dot(origin1 - origin0)) <= ROUNDING_ERROR * scale
+}
+
+/// A face of the solid prepared for the detection of the coplanar faces.
+struct CoplanarCandidate {
+    plane: Option<Plane>,
+    bounding_box: BoundingBox<Point3>,
+    bvh: MeshBvh,
+}
+
+/// Returns `true` if a face of `solid0` and a face of `solid1` lie on the same plane and
+/// overlap each other.
+///
+/// The planes of the faces whose surfaces are [`Plane`] are compared analytically, and the
+/// triangles of the faces are compared only for the other surfaces.
+fn overlapping_coplanar_faces<C, S>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> bool
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface + TryInto<Plane>,
+{
+    let candidates = |solid: &Solid<Point3, C, S>| {
+        solid
+            .boundaries()
+            .iter()
+            .flat_map(|shell| {
+                let poly_shell = shell.triangulation(tol);
+                shell
+                    .face_iter()
+                    .zip(poly_shell.face_iter())
+                    .filter_map(|(face, poly_face)| {
+                        let mesh = poly_face.surface()?;
+                        Some(CoplanarCandidate {
+                            plane: face.surface().try_into().ok(),
+                            bounding_box: mesh.bounding_box(),
+                            bvh: MeshBvh::new(&mesh),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    let faces0 = candidates(solid0);
+    let faces1 = candidates(solid1);
+    let overlap = |bdb0: &BoundingBox<Point3>, bdb1: &BoundingBox<Point3>| {
+        let diag = (bdb0 ^ bdb1).diagonal();
+        (0..3).all(|i| diag[i] > -TOLERANCE)
+    };
+    faces0.iter().any(|face0| {
+        faces1.iter().any(|face1| {
+            if !overlap(&face0.bounding_box, &face1.bounding_box) {
+                return false;
+            }
+            let mut pairs = face0.bvh.overlapping_triangles(&face1.bvh).into_iter();
+            match (&face0.plane, &face1.plane) {
+                (Some(plane0), Some(plane1)) => {
+                    coincident_planes(plane0, plane1)
+                        && pairs.any(|[tri0, tri1]| overlapping_triangles_on_plane(&tri0, &tri1))
+                }
+                _ => pairs.any(|[tri0, tri1]| coplanar_triangles(&tri0, &tri1)),
+            }
+        })
+    })
+}
+
+/// Union of two solids.
+///
+/// If the solids are disjoint, the returned solid has the boundaries of both solids.
+/// # Failures
+/// - If the solids have coplanar faces overlapping each other, returns
+/// [`Error::CoplanarFaces`](crate::errors::Error::CoplanarFaces). The faces slightly apart from
+/// each other are not coplanar and can be united.
+/// - If the intersection curves cannot be computed, returns
+/// [`Error::OperationFailed`](crate::errors::Error::OperationFailed).
+pub fn union<C, S>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>, Error>
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface + TryInto<Plane>,
+{
+    if overlapping_coplanar_faces(solid0, solid1, tol) {
+        return Err(Error::CoplanarFaces);
+    }
+    or(solid0, solid1, tol)
+}
+
+/// Intersection of two solids.
+///
+/// If the solids are disjoint, the returned solid has no boundaries.
+/// # Failures
+/// The same as [`union`].
+pub fn intersection<C, S>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>, Error>
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface + TryInto<Plane>,
+{
+    if overlapping_coplanar_faces(solid0, solid1, tol) {
+        return Err(Error::CoplanarFaces);
+    }
+    and(solid0, solid1, tol)
+}
+
+/// Difference `solid0 - solid1`, the intersection of `solid0` and the complement of `solid1`.
+/// # Failures
+/// The same as [`union`].
+pub fn difference<C, S>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>, Error>
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface + TryInto<Plane>,
+{
+    let mut complement = solid1.clone();
+    complement.not();
+    intersection(solid0, &complement, tol)
+}
+
+/// Splits `solid` by the plane through `origin` with the normal `normal`.
+///
+/// Returns the part on the side of `normal` and the part on the opposite side. Each part is
+/// closed by the cross-section faces, and is `None` if the solid has no part on the side.
+/// # Failures
+/// - If the solid has faces on the plane, returns
+/// [`Error::CoplanarFaces`](crate::errors::Error::CoplanarFaces).
+/// - If the intersection curves cannot be computed, returns
+/// [`Error::OperationFailed`](crate::errors::Error::OperationFailed).
+pub fn split_by_plane<C, S>(
+    solid: &Solid<Point3, C, S>,
+    origin: Point3,
+    normal: Vector3,
+    tol: f64,
+) -> Result<(Option<Solid<Point3, C, S>>, Option<Solid<Point3, C, S>>), Error>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>>,
+    S: ShapeOpsSurface + TryInto<Plane> + From<Plane>,
+{
+    // The faces are assumed not to bulge out of the box of the vertices and the curves by more
+    // than the margin of the half spaces.
+    let bdb = solid.edge_iter().fold(
+        solid
+            .vertex_iter()
+            .map(|v| v.point())
+            .collect::<BoundingBox<Point3>>(),
+        |bdb, edge| {
+            let curve = edge.curve();
+            match curve.try_range_tuple() {
+                Some(range) => bdb + truck_geotrait::algo::curve::bounding_box(&curve, range, tol),
+                None => bdb,
+            }
+        },
+    );
+    let normal = normal.normalize();
+    let center = bdb.center();
+    let center = center - normal * normal.dot(center - origin);
+    let size = 2.0 * bdb.diameter() + 1.0;
+    let upper_space = half_space_box(center, normal, size);
+    if overlapping_coplanar_faces(solid, &upper_space, tol) {
+        return Err(Error::CoplanarFaces);
+    }
+    let split = |half_space: &Solid<Point3, C, S>| -> Result<Option<Solid<Point3, C, S>>, Error> {
+        let res = and(solid, half_space, tol)?;
+        match res.boundaries().is_empty() {
+            true => Ok(None),
+            false => Ok(Some(res)),
+        }
+    };
+    let lower_space = half_space_box(center, -normal, size);
+    Ok((split(&upper_space)?, split(&lower_space)?))
+}
+
+/// The box `[-size, size] x [-size, size] x [0, size]` in the frame whose origin is `center`
+/// and whose z-axis is `normal`.
+fn half_space_box<C: From<Line<Point3>>, S: From<Plane>>(
+    center: Point3,
+    normal: Vector3,
+    size: f64,
+) -> Solid<Point3, C, S> {
+    let u = if normal.x.abs() < 0.5 {
+        Vector3::unit_x().cross(normal).normalize()
+    } else {
+        Vector3::unit_y().cross(normal).normalize()
+    };
+    let v = normal.cross(u);
+    let points: Vec<Point3> = (0..8)
+        .map(|i| {
+            let a = if i & 1 == 0 { -size } else { size };
+            let b = if i & 2 == 0 { -size } else { size };
+            let h = if i & 4 == 0 { 0.0 } else { size };
+            center + a * u + b * v + h * normal
+        })
+        .collect();
+    let vertices = Vertex::news(&points);
+    let mut edges = HashMap::<(usize, usize), Edge<Point3, C>>::default();
+    let mut edge = |i: usize, j: usize| {
+        if let Some(edge) = edges.get(&(j, i)) {
+            return edge.inverse();
+        }
+        let line = Line(points[i], points[j]);
+        let edge = Edge::new(&vertices[i], &vertices[j], line.into());
+        edges.insert((i, j), edge.clone());
+        edge
+    };
+    // counter-clockwise seen from the outside
+    let faces = [
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+    ];
+    let shell: Shell<Point3, C, S> = faces
+        .iter()
+        .map(|idx| {
+            let wire: Wire<Point3, C> = (0..4).map(|k| edge(idx[k], idx[(k + 1) % 4])).collect();
+            let plane = Plane::new(points[idx[0]], points[idx[1]], points[idx[3]]);
+            Face::new(vec![wire], plane.into())
+        })
+        .collect();
+    Solid::new(vec![shell])
 }
 
 #[cfg(test)]
@@ -19,3 +19,98 @@ fn punched_cube() {
     let file = std::fs::File::create("punched-cube.obj").unwrap();
     obj::write(&poly, file).unwrap();
 }
+
+fn unit_cube(origin: Point3) -> Solid {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+fn volume(solid: &Solid) -> f64 { solid.triangulation(0.01).to_polygon().volume() }
+
+#[test]
+fn overlapping_cubes() {
+    let cube0 = unit_cube(Point3::origin());
+    let cube1 = unit_cube(Point3::new(0.5, 0.5, 0.5));
+    let union = crate::union(&cube0, &cube1, 0.05).unwrap();
+    assert_eq!(union.boundaries().len(), 1);
+    assert_near!(volume(&union), 2.0 - 0.125);
+    let intersection = crate::intersection(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(volume(&intersection), 0.125);
+    let difference = crate::difference(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(volume(&difference), 1.0 - 0.125);
+}
+
+#[test]
+fn cube_with_hole() {
+    let cube = unit_cube(Point3::origin());
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    let difference = crate::difference(&cube, &cylinder, 0.05).unwrap();
+    let exact = 1.0 - std::f64::consts::PI / 16.0;
+    assert!(f64::abs(volume(&difference) - exact) < 0.01);
+}
+
+#[test]
+fn disjoint_cubes() {
+    let cube0 = unit_cube(Point3::origin());
+    let cube1 = unit_cube(Point3::new(2.0, 0.0, 0.0));
+    let union = crate::union(&cube0, &cube1, 0.05).unwrap();
+    assert_eq!(union.boundaries().len(), 2);
+    assert_near!(volume(&union), 2.0);
+    let intersection = crate::intersection(&cube0, &cube1, 0.05).unwrap();
+    assert!(intersection.boundaries().is_empty());
+}
+
+#[test]
+fn coplanar_cubes() {
+    use crate::errors::Error;
+    let cube0 = unit_cube(Point3::origin());
+    let cube1 = unit_cube(Point3::new(0.5, 0.0, 0.0));
+    let union = crate::union(&cube0, &cube1, 0.05);
+    assert_eq!(union.err(), Some(Error::CoplanarFaces));
+    let intersection = crate::intersection(&cube0, &cube1, 0.05);
+    assert_eq!(intersection.err(), Some(Error::CoplanarFaces));
+}
+
+#[test]
+fn perturbed_coplanar_cubes() {
+    let cube0 = unit_cube(Point3::origin());
+    let cube1 = unit_cube(Point3::new(0.5, 1.0e-9, 1.0e-9));
+    let union = crate::union(&cube0, &cube1, 0.05).unwrap();
+    assert!(f64::abs(volume(&union) - 1.5) < 1.0e-6);
+}
+
+#[test]
+fn split_cube() {
+    let cube = unit_cube(Point3::origin());
+    let origin = Point3::new(0.5, 0.5, 0.3);
+    let (upper, lower) = crate::split_by_plane(&cube, origin, Vector3::unit_z(), 0.05).unwrap();
+    let (upper, lower) = (upper.unwrap(), lower.unwrap());
+    assert_near!(volume(&upper), 0.7);
+    assert_near!(volume(&lower), 0.3);
+
+    let origin = Point3::new(0.5, 0.5, 1.5);
+    let (upper, lower) = crate::split_by_plane(&cube, origin, Vector3::unit_z(), 0.05).unwrap();
+    assert!(upper.is_none());
+    assert_near!(volume(&lower.unwrap()), 1.0);
+}
+
+#[test]
+fn split_sphere() {
+    use std::f64::consts::PI;
+    let v = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+    let normal = Vector3::new(1.0, 1.0, 1.0);
+    let (part0, part1) = crate::split_by_plane(&sphere, Point3::origin(), normal, 0.01).unwrap();
+    let (volume0, volume1) = (volume(&part0.unwrap()), volume(&part1.unwrap()));
+    let exact = 4.0 * PI / 3.0;
+    let sum = volume0 + volume1;
+    assert!(f64::abs(sum - exact) < 0.05, "{sum} {exact}");
+    assert!(f64::abs(volume0 - volume1) < 0.05, "{volume0} {volume1}");
+}
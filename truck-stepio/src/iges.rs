@@ -0,0 +1,625 @@
+//! Writes curves and shells to IGES files.
+//!
+//! All curves and surfaces except lines and circular arcs are written as rational B-spline
+//! entities. Analytic surfaces are converted into NURBS surfaces, exactly for planes and
+//! surfaces of revolution, and helical surfaces are approximated by B-spline surfaces. Each face
+//! of a shell is written as a trimmed surface (type 144) whose boundaries are composite curves
+//! (type 102) in the model space.
+//!
+//! | entity | type |
+//! |:-------|:-----|
+//! | circular arc | 100 |
+//! | composite curve | 102 |
+//! | line | 110 |
+//! | rational B-spline curve | 126 |
+//! | rational B-spline surface | 128 |
+//! | curve on a parametric surface | 142 |
+//! | trimmed surface | 144 |
+
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::io::Write;
+use truck_geometry::prelude::*;
+use truck_modeling::{Curve as ModelingCurve, Surface as ModelingSurface};
+use truck_topology::Shell;
+
+/// Errors occurring in writing IGES files.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// IO error
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// A curve cannot be converted to an IGES entity.
+    #[error("The curve cannot be converted to an IGES entity.")]
+    UnsupportedCurve,
+    /// A surface cannot be converted to an IGES entity.
+    #[error("The surface cannot be converted to an IGES entity.")]
+    UnsupportedSurface,
+}
+
+/// The result with [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Curve entities of IGES.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IgesCurve {
+    /// line segment from the first point to the second point, type 110
+    Line(Point3, Point3),
+    /// circular arc in a plane parallel to the xy-plane, type 100
+    ///
+    /// The arc runs counterclockwise seen from the positive direction of the z-axis.
+    /// If `start == end`, the arc is the whole circle.
+    CircularArc {
+        /// the center of the arc
+        center: Point3,
+        /// the start point
+        start: Point3,
+        /// the end point
+        end: Point3,
+    },
+    /// rational B-spline curve, type 126
+    Nurbs(NurbsCurve<Vector4>),
+}
+
+impl IgesCurve {
+    /// Returns the curve with the opposite direction.
+    pub fn inverse(&self) -> Self {
+        match self {
+            IgesCurve::Line(p, q) => IgesCurve::Line(*q, *p),
+            IgesCurve::CircularArc { .. } => self.to_nurbs().inverse(),
+            IgesCurve::Nurbs(curve) => IgesCurve::Nurbs(curve.inverse()),
+        }
+    }
+
+    /// Converts the circular arc into a rational B-spline curve. The other curves are not changed.
+    fn to_nurbs(&self) -> Self {
+        match *self {
+            IgesCurve::CircularArc { center, start, end } => {
+                let (v0, v1) = (start - center, end - center);
+                let radius = v0.magnitude();
+                let t0 = f64::atan2(v0.y, v0.x);
+                let mut t1 = f64::atan2(v1.y, v1.x);
+                if t1 < t0 + TOLERANCE {
+                    t1 += 2.0 * PI;
+                }
+                let mut curve = unit_arc(t0, t1);
+                curve.control_points_mut().for_each(|pt| {
+                    *pt = Vector4::new(
+                        pt.x * radius + center.x * pt.w,
+                        pt.y * radius + center.y * pt.w,
+                        center.z * pt.w,
+                        pt.w,
+                    )
+                });
+                IgesCurve::Nurbs(curve)
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Points whose convex hull includes the curve.
+    fn hull_points(&self) -> Vec<Point3> {
+        match self {
+            IgesCurve::Line(p, q) => vec![*p, *q],
+            IgesCurve::CircularArc { center, start, .. } => {
+                let radius = start.distance(*center);
+                vec![
+                    center + radius * Vector3::unit_x(),
+                    center - radius * Vector3::unit_x(),
+                    center + radius * Vector3::unit_y(),
+                    center - radius * Vector3::unit_y(),
+                ]
+            }
+            IgesCurve::Nurbs(curve) => curve
+                .control_points()
+                .iter()
+                .filter(|pt| !pt.w.so_small())
+                .map(|pt| pt.to_point())
+                .collect(),
+        }
+    }
+}
+
+/// Curves which can be converted to IGES curve entities.
+pub trait ToIgesCurve {
+    /// Converts the curve to an IGES curve entity. Returns `None` if the curve is not supported.
+    fn to_iges_curve(&self) -> Option<IgesCurve>;
+}
+
+impl<T: ToIgesCurve + ?Sized> ToIgesCurve for &T {
+    #[inline(always)]
+    fn to_iges_curve(&self) -> Option<IgesCurve> { (*self).to_iges_curve() }
+}
+
+impl ToIgesCurve for Line<Point3> {
+    #[inline(always)]
+    fn to_iges_curve(&self) -> Option<IgesCurve> { Some(IgesCurve::Line(self.0, self.1)) }
+}
+
+impl ToIgesCurve for BSplineCurve<Point3> {
+    #[inline(always)]
+    fn to_iges_curve(&self) -> Option<IgesCurve> {
+        Some(IgesCurve::Nurbs(NurbsCurve::from(self.clone())))
+    }
+}
+
+impl ToIgesCurve for NurbsCurve<Vector4> {
+    #[inline(always)]
+    fn to_iges_curve(&self) -> Option<IgesCurve> { Some(IgesCurve::Nurbs(self.clone())) }
+}
+
+impl ToIgesCurve for Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4> {
+    fn to_iges_curve(&self) -> Option<IgesCurve> {
+        let mat = *self.transform();
+        let (t0, t1) = self.entity().range_tuple();
+        let (x, y) = (mat[0].truncate(), mat[1].truncate());
+        let is_xy_circle = x.z.so_small()
+            && y.z.so_small()
+            && x.magnitude().near(&y.magnitude())
+            && x.dot(y).so_small()
+            && x.cross(y).z > 0.0;
+        if self.orientation() && is_xy_circle {
+            let point = |t: f64| mat.transform_point(Point3::new(f64::cos(t), f64::sin(t), 0.0));
+            let center = mat.transform_point(Point3::origin());
+            let (start, end) = match (t1 - t0).near(&(2.0 * PI)) {
+                true => (point(t0), point(t0)),
+                false => (point(t0), point(t1)),
+            };
+            Some(IgesCurve::CircularArc { center, start, end })
+        } else {
+            let mut curve = unit_arc(t0, t1);
+            curve.transform_control_points(|pt| *pt = mat * *pt);
+            if !self.orientation() {
+                curve.invert();
+            }
+            Some(IgesCurve::Nurbs(curve))
+        }
+    }
+}
+
+impl ToIgesCurve for ModelingCurve {
+    fn to_iges_curve(&self) -> Option<IgesCurve> {
+        match self {
+            ModelingCurve::Line(curve) => curve.to_iges_curve(),
+            ModelingCurve::BSplineCurve(curve) => curve.to_iges_curve(),
+            ModelingCurve::NurbsCurve(curve) => curve.to_iges_curve(),
+            ModelingCurve::IntersectionCurve(_) => None,
+        }
+    }
+}
+
+/// Surfaces which can be converted to IGES rational B-spline surfaces.
+pub trait ToIgesSurface {
+    /// Converts the surface to a rational B-spline surface.
+    ///
+    /// `hull` is a set of points whose convex hull includes the boundary of the face.
+    /// Unbounded surfaces, e.g. planes, are restricted to the region covering `hull`.
+    /// Returns `None` if the surface is not supported.
+    fn to_iges_surface(&self, hull: &[Point3]) -> Option<NurbsSurface<Vector4>>;
+}
+
+impl ToIgesSurface for Plane {
+    fn to_iges_surface(&self, hull: &[Point3]) -> Option<NurbsSurface<Vector4>> {
+        let (mut u0, mut u1) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut v0, mut v1) = (f64::INFINITY, f64::NEG_INFINITY);
+        hull.iter().for_each(|pt| {
+            let uv = self.get_parameter(*pt);
+            (u0, u1) = (f64::min(u0, uv.x), f64::max(u1, uv.x));
+            (v0, v1) = (f64::min(v0, uv.y), f64::max(v1, uv.y));
+        });
+        if u0 + TOLERANCE > u1 || v0 + TOLERANCE > v1 {
+            return None;
+        }
+        let pt = |u: f64, v: f64| self.subs(u, v).to_homogeneous();
+        let knot_vecs = (
+            KnotVec::from(vec![u0, u0, u1, u1]),
+            KnotVec::from(vec![v0, v0, v1, v1]),
+        );
+        let control_points = vec![vec![pt(u0, v0), pt(u0, v1)], vec![pt(u1, v0), pt(u1, v1)]];
+        Some(NurbsSurface::new(BSplineSurface::new(
+            knot_vecs,
+            control_points,
+        )))
+    }
+}
+
+impl ToIgesSurface for BSplineSurface<Point3> {
+    #[inline(always)]
+    fn to_iges_surface(&self, _: &[Point3]) -> Option<NurbsSurface<Vector4>> {
+        Some(NurbsSurface::new(BSplineSurface::lift_up(self.clone())))
+    }
+}
+
+impl ToIgesSurface for NurbsSurface<Vector4> {
+    #[inline(always)]
+    fn to_iges_surface(&self, _: &[Point3]) -> Option<NurbsSurface<Vector4>> { Some(self.clone()) }
+}
+
+impl ToIgesSurface for Processor<RevolutedCurve<ModelingCurve>, Matrix4> {
+    fn to_iges_surface(&self, _: &[Point3]) -> Option<NurbsSurface<Vector4>> {
+        let revoluted = self.entity();
+        let curve = match revoluted.entity_curve() {
+            ModelingCurve::IntersectionCurve(_) => return None,
+            curve => curve.clone().lift_up(),
+        };
+        let mut surface = revolution(&curve, revoluted.origin(), revoluted.axis());
+        let mat = *self.transform();
+        surface.transform_control_points(|pt| *pt = mat * *pt);
+        if !self.orientation() {
+            surface.swap_axes();
+        }
+        Some(surface)
+    }
+}
+
+impl ToIgesSurface for ModelingSurface {
+    fn to_iges_surface(&self, hull: &[Point3]) -> Option<NurbsSurface<Vector4>> {
+        match self {
+            ModelingSurface::Plane(surface) => surface.to_iges_surface(hull),
+            ModelingSurface::BSplineSurface(surface) => surface.to_iges_surface(hull),
+            ModelingSurface::NurbsSurface(surface) => surface.to_iges_surface(hull),
+            ModelingSurface::RevolutedCurve(surface) => surface.to_iges_surface(hull),
+            ModelingSurface::HelicalSurface(surface) => {
+                let surface = surface.approximation(TOLERANCE);
+                Some(NurbsSurface::new(BSplineSurface::lift_up(surface)))
+            }
+        }
+    }
+}
+
+/// Rational quadratic arc of the unit circle on the xy-plane from the angle `t0` to `t1`.
+/// The knots are the angles at the ends of the segments, each of which is at most a quarter.
+fn unit_arc(t0: f64, t1: f64) -> NurbsCurve<Vector4> {
+    let n = f64::ceil((t1 - t0) / FRAC_PI_2 - TOLERANCE).max(1.0) as usize;
+    let dt = (t1 - t0) / n as f64;
+    let weight = f64::cos(dt / 2.0);
+    let mut knots = vec![t0; 3];
+    let mut control_points = vec![Vector4::new(f64::cos(t0), f64::sin(t0), 0.0, 1.0)];
+    (1..=n).for_each(|i| {
+        let (s0, s1) = (t0 + dt * (i - 1) as f64, t0 + dt * i as f64);
+        let mid = (s0 + s1) / 2.0;
+        control_points.push(Vector4::new(f64::cos(mid), f64::sin(mid), 0.0, weight));
+        control_points.push(Vector4::new(f64::cos(s1), f64::sin(s1), 0.0, 1.0));
+        knots.extend(std::iter::repeat(s1).take(if i == n { 3 } else { 2 }));
+    });
+    NurbsCurve::new(BSplineCurve::new(KnotVec::from(knots), control_points))
+}
+
+/// The exact NURBS representation of the surface given by revolving `curve` around the axis.
+/// The `u`-parameter is that of `curve`, and the `v`-parameter runs from `0` to `2π`.
+fn revolution(
+    curve: &BSplineCurve<Vector4>,
+    origin: Point3,
+    axis: Vector3,
+) -> NurbsSurface<Vector4> {
+    let axis = axis.normalize();
+    let arc = unit_arc(0.0, 2.0 * PI);
+    let control_points = curve
+        .control_points()
+        .iter()
+        .map(|pt| {
+            let (weight, pt) = (pt.w, pt.to_point());
+            let center = origin + (pt - origin).dot(axis) * axis;
+            let (x, y) = (pt - center, axis.cross(pt - center));
+            arc.control_points()
+                .iter()
+                .map(|a| (center.to_vec() * a.w + x * a.x + y * a.y).extend(a.w) * weight)
+                .collect()
+        })
+        .collect();
+    let knot_vecs = (curve.knot_vec().clone(), arc.knot_vec().clone());
+    NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points))
+}
+
+/// Formats real numbers in the IGES free format.
+fn real(x: f64) -> String {
+    if x == 0.0 {
+        "0.0".to_string()
+    } else if (1.0e-3..1.0e10).contains(&x.abs()) {
+        format!("{x:?}")
+    } else {
+        format!("{x:.15E}")
+    }
+}
+
+/// Hollerith string
+fn hollerith(s: &str) -> String {
+    match s.is_empty() {
+        true => String::new(),
+        false => format!("{}H{s}", s.len()),
+    }
+}
+
+/// Status numbers of the directory entries
+const INDEPENDENT: &str = "00000000";
+const DEPENDENT: &str = "00010000";
+
+#[derive(Clone, Debug)]
+struct Entity {
+    entity_type: usize,
+    form: usize,
+    status: &'static str,
+    params: Vec<String>,
+}
+
+/// Entities of an IGES file. Entities are referred by the sequence numbers of their directory entries.
+#[derive(Clone, Debug, Default)]
+struct IgesDocument {
+    entities: Vec<Entity>,
+    max_coordinate: f64,
+}
+
+impl IgesDocument {
+    fn push(&mut self, entity_type: usize, status: &'static str, params: Vec<String>) -> usize {
+        self.entities.push(Entity {
+            entity_type,
+            form: 0,
+            status,
+            params,
+        });
+        2 * self.entities.len() - 1
+    }
+
+    fn points<'a>(&mut self, points: impl IntoIterator<Item = &'a Point3>) -> Vec<String> {
+        points
+            .into_iter()
+            .flat_map(|pt| {
+                let max = pt.x.abs().max(pt.y.abs()).max(pt.z.abs());
+                self.max_coordinate = self.max_coordinate.max(max);
+                [real(pt.x), real(pt.y), real(pt.z)]
+            })
+            .collect()
+    }
+
+    fn push_curve(&mut self, curve: &IgesCurve, status: &'static str) -> usize {
+        match curve {
+            IgesCurve::Line(p, q) => {
+                let params = self.points([p, q]);
+                self.push(110, status, params)
+            }
+            IgesCurve::CircularArc { center, start, end } => {
+                self.points([center, start, end]);
+                let params = [center.z, center.x, center.y, start.x, start.y, end.x, end.y];
+                self.push(100, status, params.into_iter().map(real).collect())
+            }
+            IgesCurve::Nurbs(curve) => {
+                let (k, m) = (curve.control_points().len() - 1, curve.degree());
+                let weights: Vec<f64> = curve.control_points().iter().map(|pt| pt.w).collect();
+                let points: Vec<Point3> = curve
+                    .control_points()
+                    .iter()
+                    .map(|pt| pt.to_point())
+                    .collect();
+                let closed = points[0].near(&points[k]);
+                let polynomial = weights.iter().all(|w| w.near(&weights[0]));
+                let (t0, t1) = curve.range_tuple();
+                let mut params: Vec<String> = [k, m, 0, closed as usize, polynomial as usize, 0]
+                    .iter()
+                    .map(usize::to_string)
+                    .collect();
+                params.extend(curve.knot_vec().iter().copied().map(real));
+                params.extend(weights.into_iter().map(real));
+                params.extend(self.points(&points));
+                params.extend([t0, t1, 0.0, 0.0, 0.0].into_iter().map(real));
+                self.push(126, status, params)
+            }
+        }
+    }
+
+    fn push_surface(&mut self, surface: &NurbsSurface<Vector4>, status: &'static str) -> usize {
+        let control_points = surface.control_points();
+        let (k1, k2) = (control_points.len() - 1, control_points[0].len() - 1);
+        let (m1, m2) = surface.degrees();
+        // IGES orders the control points so that the index of `u` runs first.
+        let ordered: Vec<Vector4> = (0..=k2)
+            .flat_map(|j| control_points.iter().map(move |row| row[j]))
+            .collect();
+        let weights: Vec<f64> = ordered.iter().map(|pt| pt.w).collect();
+        let points: Vec<Point3> = ordered.iter().map(|pt| pt.to_point()).collect();
+        let polynomial = weights.iter().all(|w| w.near(&weights[0]));
+        let ((u0, u1), (v0, v1)) = surface.range_tuple();
+        let mut params: Vec<String> = [k1, k2, m1, m2, 0, 0, polynomial as usize, 0, 0]
+            .iter()
+            .map(usize::to_string)
+            .collect();
+        params.extend(surface.uknot_vec().iter().copied().map(real));
+        params.extend(surface.vknot_vec().iter().copied().map(real));
+        params.extend(weights.into_iter().map(real));
+        params.extend(self.points(&points));
+        params.extend([u0, u1, v0, v1].into_iter().map(real));
+        self.push(128, status, params)
+    }
+
+    /// Pushes a closed loop of curves as a curve on the surface `surface`,
+    /// and returns the pointer to the curve on the surface.
+    fn push_boundary(&mut self, surface: usize, curves: &[IgesCurve]) -> usize {
+        let mut params = vec![curves.len().to_string()];
+        curves.iter().for_each(|curve| {
+            let pointer = self.push_curve(curve, DEPENDENT);
+            params.push(pointer.to_string());
+        });
+        let composite = self.push(102, DEPENDENT, params);
+        let params = [0, surface, 0, composite, 2];
+        self.push(
+            142,
+            DEPENDENT,
+            params.iter().map(usize::to_string).collect(),
+        )
+    }
+
+    fn write<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let start = ["Shape data from truck".to_string()];
+        let time_stamp = chrono::Utc::now().format("%Y%m%d.%H%M%S").to_string();
+        let global = [
+            hollerith(","),
+            hollerith(";"),
+            hollerith("truck"),
+            String::new(),
+            hollerith("truck"),
+            hollerith("truck-stepio"),
+            "32".to_string(),
+            "38".to_string(),
+            "6".to_string(),
+            "308".to_string(),
+            "15".to_string(),
+            String::new(),
+            real(1.0),
+            "2".to_string(),
+            hollerith("MM"),
+            "1".to_string(),
+            real(1.0),
+            hollerith(&time_stamp),
+            real(TOLERANCE),
+            real(self.max_coordinate),
+            String::new(),
+            String::new(),
+            "11".to_string(),
+            "0".to_string(),
+            hollerith(&time_stamp),
+        ];
+        let global = split_params(&global, 72);
+
+        let mut directory = Vec::new();
+        let mut parameter = Vec::new();
+        self.entities.iter().enumerate().for_each(|(i, entity)| {
+            let de_pointer = 2 * i + 1;
+            let mut params = vec![entity.entity_type.to_string()];
+            params.extend(entity.params.iter().cloned());
+            let lines = split_params(&params, 64);
+            let (p_pointer, count) = (parameter.len() + 1, lines.len());
+            let t = entity.entity_type;
+            directory.push(format!(
+                "{t:>8}{p_pointer:>8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}",
+                0, 0, 0, 0, 0, 0, entity.status,
+            ));
+            directory.push(format!(
+                "{t:>8}{:>8}{:>8}{count:>8}{:>8}{:>8}{:>8}{:>8}{:>8}",
+                0, 0, entity.form, "", "", "", 0,
+            ));
+            parameter.extend(
+                lines
+                    .into_iter()
+                    .map(|line| format!("{line:<64}{de_pointer:>8}")),
+            );
+        });
+
+        let mut write_section = |lines: &[String], section: char| {
+            lines
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, line)| writeln!(writer, "{line:<72}{section}{:>7}", i + 1))
+        };
+        write_section(&start, 'S')?;
+        write_section(&global, 'G')?;
+        write_section(&directory, 'D')?;
+        write_section(&parameter, 'P')?;
+        let terminate = format!(
+            "S{:>7}G{:>7}D{:>7}P{:>7}",
+            start.len(),
+            global.len(),
+            directory.len(),
+            parameter.len(),
+        );
+        write_section(&[terminate], 'T')
+    }
+}
+
+/// Splits the parameters into lines with at most `width` columns. The parameters are delimited
+/// by `,` and terminated by `;`.
+fn split_params(params: &[String], width: usize) -> Vec<String> {
+    let mut lines = vec![String::new()];
+    params.iter().enumerate().for_each(|(i, param)| {
+        let delimiter = if i + 1 == params.len() { ';' } else { ',' };
+        let line = lines.last_mut().unwrap();
+        if !line.is_empty() && line.len() + param.len() + 1 > width {
+            lines.push(String::new());
+        }
+        let line = lines.last_mut().unwrap();
+        line.push_str(param);
+        line.push(delimiter);
+    });
+    lines
+}
+
+/// Writes `curves` to an IGES file.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use truck_stepio::iges::{self, ToIgesCurve};
+/// let line = Line(Point3::origin(), Point3::new(1.0, 0.0, 0.0));
+/// let bspline = BSplineCurve::new(
+///     KnotVec::bezier_knot(2),
+///     vec![Point3::origin(), Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+/// );
+/// let curves: [&dyn ToIgesCurve; 2] = [&line, &bspline];
+/// let mut buffer = Vec::new();
+/// iges::write_curves(&curves, &mut buffer).unwrap();
+/// let iges_string = String::from_utf8(buffer).unwrap();
+/// assert!(iges_string.lines().all(|line| line.len() == 80));
+/// ```
+pub fn write_curves<C: ToIgesCurve, W: Write>(curves: &[C], writer: W) -> Result<()> {
+    let mut document = IgesDocument::default();
+    curves.iter().try_for_each(|curve| {
+        let curve = curve.to_iges_curve().ok_or(Error::UnsupportedCurve)?;
+        document.push_curve(&curve, INDEPENDENT);
+        Ok::<_, Error>(())
+    })?;
+    Ok(document.write(writer)?)
+}
+
+/// Writes the faces of `shell` to an IGES file as trimmed surfaces.
+///
+/// The first boundary of each face is written as the outer boundary.
+/// The length unit is millimetre.
+pub fn write_shell<C, S, W>(shell: &Shell<Point3, C, S>, writer: W) -> Result<()>
+where
+    C: ToIgesCurve + Clone,
+    S: ToIgesSurface + Clone,
+    W: Write, {
+    let mut document = IgesDocument::default();
+    shell.face_iter().try_for_each(|face| {
+        let boundaries = face
+            .boundaries()
+            .iter()
+            .map(|wire| {
+                wire.edge_iter()
+                    .map(|edge| {
+                        let curve = edge
+                            .curve()
+                            .to_iges_curve()
+                            .ok_or(Error::UnsupportedCurve)?;
+                        Ok(match edge.orientation() {
+                            true => curve,
+                            false => curve.inverse(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let hull: Vec<Point3> = boundaries
+            .iter()
+            .flatten()
+            .flat_map(IgesCurve::hull_points)
+            .collect();
+        let mut surface = face
+            .surface()
+            .to_iges_surface(&hull)
+            .ok_or(Error::UnsupportedSurface)?;
+        if !face.orientation() {
+            surface.swap_axes();
+        }
+        let surface = document.push_surface(&surface, DEPENDENT);
+        let pointers: Vec<usize> = boundaries
+            .iter()
+            .map(|curves| document.push_boundary(surface, curves))
+            .collect();
+        let mut params = vec![surface, 1, pointers.len().saturating_sub(1)];
+        params.extend(pointers);
+        document.push(
+            144,
+            INDEPENDENT,
+            params.iter().map(usize::to_string).collect(),
+        );
+        Ok::<_, Error>(())
+    })?;
+    Ok(document.write(writer)?)
+}
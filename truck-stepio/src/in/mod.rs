@@ -66,6 +66,7 @@ pub struct Table {
     pub oriented_face: HashMap<u64, OrientedFaceHolder>,
     pub shell: HashMap<u64, ShellHolder>,
     pub oriented_shell: HashMap<u64, OrientedShellHolder>,
+    pub manifold_solid_brep: HashMap<u64, ManifoldSolidBrepHolder>,
 
     // others
     pub definitional_representation: HashMap<u64, DefinitionalRepresentationHolder>,
@@ -288,6 +289,10 @@ impl Table {
                         }
                     }
                 }
+                "MANIFOLD_SOLID_BREP" => {
+                    self.manifold_solid_brep
+                        .insert(*id, Deserialize::deserialize(&record.parameter)?);
+                }
                 "DEFINITIONAL_REPRESENTATION" => {
                     if let Parameter::List(params) = &record.parameter {
                         if params.len() == 3 {
@@ -2496,6 +2501,17 @@ pub struct OrientedShell {
     pub orientation: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = manifold_solid_brep)]
+#[holder(generate_deserialize)]
+/// `manifold_solid_brep`
+pub struct ManifoldSolidBrep {
+    pub label: String,
+    #[holder(use_place_holder)]
+    pub outer: Shell,
+}
+
 impl Table {
     fn place_holder_edge_any_to_index_and_edge_curve(
         &self,
@@ -2532,7 +2548,11 @@ impl Table {
         }
     }
 
-    fn shell_vertices(&self, shell: &ShellHolder) -> (Vec<Point3>, HashMap<u64, usize>) {
+    fn shell_vertices(
+        &self,
+        shell: &ShellHolder,
+        warnings: &mut Vec<String>,
+    ) -> (Vec<Point3>, HashMap<u64, usize>) {
         use PlaceHolder::Ref;
         let mut vidx_map = HashMap::<u64, usize>::new();
         let vertex_to_point = |v: PlaceHolder<VertexPointHolder>| {
@@ -2541,7 +2561,7 @@ impl Table {
                     let len = vidx_map.len();
                     vidx_map.insert(*idx, len);
                     let p = EntityTable::<VertexPointHolder>::get_owned(self, *idx)
-                        .map_err(|e| eprintln!("{e}"))
+                        .map_err(|e| warnings.push(format!("#{idx}: {e}")))
                         .ok()?;
                     return Some(Point3::from(&p.vertex_geometry));
                 }
@@ -2565,6 +2585,7 @@ impl Table {
         &self,
         shell: &ShellHolder,
         vidx_map: &HashMap<u64, usize>,
+        warnings: &mut Vec<String>,
     ) -> (Vec<CompressedEdge<Curve3D>>, HashMap<u64, usize>) {
         use PlaceHolder::Ref;
         let mut eidx_map = HashMap::<u64, usize>::new();
@@ -2577,11 +2598,11 @@ impl Table {
             let edge_curve = edge
                 .clone()
                 .into_owned(self)
-                .map_err(|e| eprintln!("{e}"))
+                .map_err(|e| warnings.push(format!("#{idx}: {e}")))
                 .ok()?;
             let curve = edge_curve
                 .parse_curve3d()
-                .map_err(|e| eprintln!("{e}"))
+                .map_err(|e| warnings.push(entity_warning(&edge.edge_geometry, Some(idx), e)))
                 .ok()?;
             let Ref(Name::Entity(front_idx)) = edge.edge_start else {
                 return None;
@@ -2644,27 +2665,39 @@ impl Table {
         &self,
         shell: &ShellHolder,
         eidx_map: &HashMap<u64, usize>,
+        warnings: &mut Vec<String>,
     ) -> Vec<CompressedFace<Surface>> {
         shell
-            .cfs_faces_holder(self)
-            .filter_map(|face| self.face_any_to_orientation_and_face(face))
-            .filter_map(|(orientation, face)| {
-                let step_surface: SurfaceAny = face
-                    .face_geometry
+            .cfs_faces
+            .iter()
+            .zip(shell.cfs_faces_holder(self))
+            .filter_map(|(face_ph, face)| {
+                let (orientation, face) = self.face_any_to_orientation_and_face(face)?;
+                let face_idx = entity_index(face_ph);
+                let surface_ph = &face.face_geometry;
+                let step_surface: SurfaceAny = surface_ph
                     .clone()
                     .into_owned(self)
-                    .map_err(|e| eprintln!("{e}"))
+                    .map_err(|e| warnings.push(entity_warning(surface_ph, face_idx, e)))
                     .ok()?;
                 let mut surface = Surface::try_from(&step_surface)
-                    .map_err(|e| eprintln!("{e}"))
+                    .map_err(|e| warnings.push(entity_warning(surface_ph, face_idx, e)))
                     .ok()?;
                 if !face.same_sense {
                     surface.invert()
                 }
                 let boundaries: Vec<_> = face
-                    .bounds_holder(self)
-                    .into_iter()
-                    .filter_map(|bound| self.face_bound_to_edges(bound?, eidx_map))
+                    .bounds
+                    .iter()
+                    .zip(face.bounds_holder(self))
+                    .filter_map(|(bound_ph, bound)| {
+                        let edges = bound.and_then(|b| self.face_bound_to_edges(b, eidx_map));
+                        if edges.is_none() {
+                            let msg = "the face bound could not be converted.";
+                            warnings.push(entity_warning(bound_ph, face_idx, msg));
+                        }
+                        edges
+                    })
                     .collect();
                 Some(CompressedFace {
                     surface,
@@ -2675,16 +2708,174 @@ impl Table {
             .collect()
     }
 
-    pub fn to_compressed_shell(
+    fn compress_shell(
         &self,
         shell: &ShellHolder,
-    ) -> Result<CompressedShell<Point3, Curve3D, Surface>, ExpressParseError> {
-        let (vertices, vidx_map) = self.shell_vertices(shell);
-        let (edges, eidx_map) = self.shell_edges(shell, &vidx_map);
-        Ok(CompressedShell {
+        warnings: &mut Vec<String>,
+    ) -> CompressedShell<Point3, Curve3D, Surface> {
+        let (vertices, vidx_map) = self.shell_vertices(shell, warnings);
+        let (edges, eidx_map) = self.shell_edges(shell, &vidx_map, warnings);
+        CompressedShell {
             vertices,
             edges,
-            faces: self.shell_faces(shell, &eidx_map),
+            faces: self.shell_faces(shell, &eidx_map, warnings),
+        }
+    }
+
+    /// Converts `shell` to a compressed shell.
+    ///
+    /// The entities which cannot be converted are skipped and reported to the standard error.
+    /// Use [`read`] to collect the reports instead.
+    pub fn to_compressed_shell(
+        &self,
+        shell: &ShellHolder,
+    ) -> Result<CompressedShell<Point3, Curve3D, Surface>, ExpressParseError> {
+        let mut warnings = Vec::new();
+        let shell = self.compress_shell(shell, &mut warnings);
+        warnings.iter().for_each(|warning| eprintln!("{warning}"));
+        Ok(shell)
+    }
+
+    /// Returns the shell referred by `shell` and its orientation.
+    /// The orientation is `false` if the shell is referred through an inverted `ORIENTED_CLOSED_SHELL`.
+    fn place_holder_to_shell(
+        &self,
+        shell: &PlaceHolder<ShellHolder>,
+    ) -> Option<(bool, ShellHolder)> {
+        match shell {
+            PlaceHolder::Owned(holder) => Some((true, holder.clone())),
+            PlaceHolder::Ref(Name::Entity(ref idx)) => self
+                .shell
+                .get(idx)
+                .cloned()
+                .map(|shell| (true, shell))
+                .or_else(|| {
+                    let oriented = self.oriented_shell.get(idx)?;
+                    let (orientation, shell) =
+                        self.place_holder_to_shell(&oriented.shell_element)?;
+                    Some((orientation == oriented.orientation, shell))
+                }),
+            _ => None,
+        }
+    }
+
+    fn compress_solid(
+        &self,
+        solid: &ManifoldSolidBrepHolder,
+        warnings: &mut Vec<String>,
+    ) -> Result<CompressedSolid<Point3, Curve3D, Surface>, ExpressParseError> {
+        let (orientation, shell) = self
+            .place_holder_to_shell(&solid.outer)
+            .ok_or("the outer shell is not found.")?;
+        let mut shell = self.compress_shell(&shell, warnings);
+        if !orientation {
+            shell
+                .faces
+                .iter_mut()
+                .for_each(|face| face.orientation = !face.orientation);
+        }
+        Ok(CompressedSolid {
+            boundaries: vec![shell],
         })
     }
+
+    /// Converts `solid` to a compressed solid.
+    ///
+    /// The entities which cannot be converted are skipped and reported to the standard error.
+    /// Use [`read`] to collect the reports instead.
+    pub fn to_compressed_solid(
+        &self,
+        solid: &ManifoldSolidBrepHolder,
+    ) -> Result<CompressedSolid<Point3, Curve3D, Surface>, ExpressParseError> {
+        let mut warnings = Vec::new();
+        let solid = self.compress_solid(solid, &mut warnings);
+        warnings.iter().for_each(|warning| eprintln!("{warning}"));
+        solid
+    }
+}
+
+fn entity_index<T>(entity: &PlaceHolder<T>) -> Option<u64> {
+    match entity {
+        PlaceHolder::Ref(Name::Entity(idx)) => Some(*idx),
+        _ => None,
+    }
+}
+
+/// Formats `e` as a warning about `entity`, or about `owner` if `entity` has no index.
+fn entity_warning<T>(
+    entity: &PlaceHolder<T>,
+    owner: Option<u64>,
+    e: impl std::fmt::Display,
+) -> String {
+    match entity_index(entity).or(owner) {
+        Some(idx) => format!("#{idx}: {e}"),
+        None => e.to_string(),
+    }
+}
+
+/// Shapes read from a STEP file by [`read`].
+#[derive(Clone, Debug, Default)]
+pub struct StepShapes {
+    /// solids given by `MANIFOLD_SOLID_BREP`
+    pub solids: Vec<CompressedSolid<Point3, Curve3D, Surface>>,
+    /// shells which are not the boundaries of `solids`
+    pub shells: Vec<CompressedShell<Point3, Curve3D, Surface>>,
+    /// messages about the entities which could not be converted, prefixed by the entity ids
+    pub warnings: Vec<String>,
+}
+
+/// Reads solids and shells from a STEP file.
+///
+/// The whole file fails only if it cannot be parsed.
+/// Solids, shells and faces which cannot be converted are skipped and reported in `warnings`,
+/// together with the entities causing the failures.
+pub fn read<R: std::io::Read>(mut reader: R) -> Result<StepShapes, ExpressParseError> {
+    let mut step_string = String::new();
+    reader.read_to_string(&mut step_string)?;
+    let table = Table::from_step(&step_string).ok_or("failed to parse the STEP file.")?;
+    let mut shapes = StepShapes::default();
+    let check_faces = |warnings: &mut Vec<String>, idx: u64, shell: &ShellHolder, len: usize| {
+        let lost = shell.cfs_faces.len() - len;
+        if lost > 0 {
+            warnings.push(format!("#{idx}: {lost} faces could not be converted."));
+        }
+    };
+    let mut solid_indices: Vec<u64> = table.manifold_solid_brep.keys().copied().collect();
+    solid_indices.sort();
+    let mut used_shells = std::collections::HashSet::new();
+    for idx in solid_indices {
+        let solid = &table.manifold_solid_brep[&idx];
+        let mut outer = &solid.outer;
+        while let PlaceHolder::Ref(Name::Entity(shell_idx)) = outer {
+            used_shells.insert(*shell_idx);
+            match table.oriented_shell.get(shell_idx) {
+                Some(oriented) => outer = &oriented.shell_element,
+                None => break,
+            }
+        }
+        match table.compress_solid(solid, &mut shapes.warnings) {
+            Ok(csolid) => {
+                if let Some((_, shell)) = table.place_holder_to_shell(&solid.outer) {
+                    let len = csolid.boundaries[0].faces.len();
+                    check_faces(&mut shapes.warnings, idx, &shell, len);
+                }
+                shapes.solids.push(csolid);
+            }
+            Err(e) => shapes.warnings.push(format!("#{idx}: {e}")),
+        }
+    }
+    let mut shell_indices: Vec<u64> = table
+        .shell
+        .keys()
+        .filter(|idx| !used_shells.contains(idx))
+        .copied()
+        .collect();
+    shell_indices.sort();
+    for idx in shell_indices {
+        let shell = &table.shell[&idx];
+        let cshell = table.compress_shell(shell, &mut shapes.warnings);
+        check_faces(&mut shapes.warnings, idx, shell, cshell.faces.len());
+        shapes.shells.push(cshell);
+    }
+    Ok(shapes)
 }
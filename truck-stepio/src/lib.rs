@@ -5,6 +5,7 @@
 //! It is possible to output data modeled by truck-modeling.
 //! Shapes created by set operations cannot be output yet.
 //! Input will come further down the road.
+//! Curves and shells can also be output to IGES files by the module [`iges`].
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 #![deny(clippy::all, rust_2018_idioms)]
@@ -19,6 +20,7 @@
     unused_qualifications
 )]
 
+pub mod iges;
 /// STEP input module
 #[doc(hidden)]
 #[cfg(feature = "in")]
@@ -782,6 +782,9 @@ impl DisplayByStep for ModelingSurface {
             ModelingSurface::BSplineSurface(x) => DisplayByStep::fmt(x, idx, f),
             ModelingSurface::NurbsSurface(x) => DisplayByStep::fmt(x, idx, f),
             ModelingSurface::RevolutedCurve(x) => DisplayByStep::fmt(x, idx, f),
+            ModelingSurface::HelicalSurface(x) => {
+                DisplayByStep::fmt(&x.approximation(TOLERANCE), idx, f)
+            }
         }
     }
 }
@@ -793,6 +796,7 @@ impl StepLength for ModelingSurface {
             ModelingSurface::BSplineSurface(x) => x.step_length(),
             ModelingSurface::NurbsSurface(x) => x.step_length(),
             ModelingSurface::RevolutedCurve(x) => x.entity().step_length(),
+            ModelingSurface::HelicalSurface(x) => x.approximation(TOLERANCE).step_length(),
         }
     }
 }
@@ -337,15 +337,52 @@ ENDSEC;\n",
     }
 }
 
+/// Length unit of the output STEP file.
+/// # Examples
+/// ```
+/// use truck_stepio::out::LengthUnit;
+/// assert_eq!(LengthUnit::default(), LengthUnit::Millimetre);
+/// assert_eq!(LengthUnit::Metre.to_string(), "SI_UNIT($,.METRE.)");
+/// assert_eq!(LengthUnit::Millimetre.to_string(), "SI_UNIT(.MILLI.,.METRE.)");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// micrometre
+    Micrometre,
+    /// millimetre
+    #[default]
+    Millimetre,
+    /// centimetre
+    Centimetre,
+    /// metre
+    Metre,
+}
+
+impl Display for LengthUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let prefix = match self {
+            LengthUnit::Micrometre => ".MICRO.",
+            LengthUnit::Millimetre => ".MILLI.",
+            LengthUnit::Centimetre => ".CENTI.",
+            LengthUnit::Metre => "$",
+        };
+        f.write_fmt(format_args!("SI_UNIT({prefix},.METRE.)"))
+    }
+}
+
 /// Display model with configuations
 #[derive(Clone, Debug)]
-pub struct StepModel<'a, P, C, S>(PreStepModel<'a, P, C, S>);
+pub struct StepModel<'a, P, C, S> {
+    model: PreStepModel<'a, P, C, S>,
+    length_unit: LengthUnit,
+}
 
 /// Display models with configuations
 #[derive(Clone, Debug)]
 pub struct StepModels<'a, P, C, S> {
     models: Vec<PreStepModel<'a, P, C, S>>,
     next_idx: usize,
+    length_unit: LengthUnit,
 }
 
 /// Display struct for outputting STEP file format with header.
@@ -383,6 +420,36 @@ impl<T> CompleteStepDisplay<T> {
     }
 }
 
+/// Writes `model` to `writer` as a complete STEP file with `header`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_stepio::out;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z()).compress();
+///
+/// let model = out::StepModel::from(&cube).with_length_unit(out::LengthUnit::Metre);
+/// let header = out::StepHeaderDescriptor {
+///     authors: vec!["truck".to_string()],
+///     ..Default::default()
+/// };
+/// let mut buffer = Vec::new();
+/// out::write(&mut buffer, model, header).unwrap();
+/// let step_string = String::from_utf8(buffer).unwrap();
+/// assert!(step_string.contains("MANIFOLD_SOLID_BREP"));
+/// assert!(step_string.contains("SI_UNIT($,.METRE.)"));
+/// ```
+pub fn write<W: std::io::Write, T: Display>(
+    mut writer: W,
+    model: T,
+    header: StepHeaderDescriptor,
+) -> std::io::Result<()> {
+    writer.write_fmt(format_args!("{}", CompleteStepDisplay::new(model, header)))
+}
+
 mod geometry;
 mod topology;
 pub use geometry::VectorAsDirection;
@@ -337,7 +337,12 @@ where
     C: StepLength,
     S: StepLength,
 {
-    fn from(shell: &'a CompressedShell<P, C, S>) -> Self { Self(shell.into()) }
+    fn from(shell: &'a CompressedShell<P, C, S>) -> Self {
+        Self {
+            model: shell.into(),
+            length_unit: Default::default(),
+        }
+    }
 }
 
 impl<'a, P, C, S> From<&'a CompressedSolid<P, C, S>> for StepModel<'a, P, C, S>
@@ -346,7 +351,37 @@ where
     C: StepLength,
     S: StepLength,
 {
-    fn from(solid: &'a CompressedSolid<P, C, S>) -> Self { Self(solid.into()) }
+    fn from(solid: &'a CompressedSolid<P, C, S>) -> Self {
+        Self {
+            model: solid.into(),
+            length_unit: Default::default(),
+        }
+    }
+}
+
+impl<'a, P, C, S> StepModel<'a, P, C, S> {
+    /// Sets the length unit. The default unit is millimetre.
+    #[inline(always)]
+    pub fn with_length_unit(mut self, length_unit: LengthUnit) -> Self {
+        self.length_unit = length_unit;
+        self
+    }
+}
+
+/// Outputs the representation context and the units, the entities from `#11` to `#15`.
+fn fmt_context(f: &mut Formatter<'_>, length_unit: LengthUnit) -> Result {
+    f.write_fmt(format_args!(
+        "#11 = (
+    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
+    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
+    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
+    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
+);
+#12 = ( LENGTH_UNIT() NAMED_UNIT(*) {length_unit} );
+#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
+#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
+#15 = UNCERTAINTY_MEASURE_WITH_UNIT(1.0E-6, #12, 'distance_accuracy_value','confusion accuracy');\n"
+    ))
 }
 
 impl<'a, P, C, S> Display for StepModel<'a, P, C, S>
@@ -366,19 +401,10 @@ where
 #7 = PRODUCT('','','', (#8));
 #8 = PRODUCT_CONTEXT('', #2, 'mechanical');
 #9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');
-#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', (#16), #11);
-#11 = (
-    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
-    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
-    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
-    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
-);
-#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
-#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
-#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
-#15 = UNCERTAINTY_MEASURE_WITH_UNIT(1.0E-6, #12, 'distance_accuracy_value','confusion accuracy');\n"
+#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', (#16), #11);\n"
         )?;
-        Display::fmt(&self.0, f)
+        fmt_context(f, self.length_unit)?;
+        Display::fmt(&self.model, f)
     }
 }
 
@@ -387,6 +413,7 @@ impl<'a, P, C, S> Default for StepModels<'a, P, C, S> {
         Self {
             models: Vec::new(),
             next_idx: 16,
+            length_unit: Default::default(),
         }
     }
 }
@@ -397,6 +424,12 @@ where
     C: StepLength,
     S: StepLength,
 {
+    /// Sets the length unit. The default unit is millimetre.
+    #[inline(always)]
+    pub fn with_length_unit(mut self, length_unit: LengthUnit) -> Self {
+        self.length_unit = length_unit;
+        self
+    }
     /// push a shell to step models
     pub fn push_shell(&mut self, shell: &'a CompressedShell<P, C, S>) {
         let model = PreStepModel::Shell(StepShell::new(shell, self.next_idx + 1, true));
@@ -427,7 +460,11 @@ where
                 model
             })
             .collect();
-        Self { models, next_idx }
+        Self {
+            models,
+            next_idx,
+            length_unit: Default::default(),
+        }
     }
 }
 
@@ -447,7 +484,11 @@ where
                 model
             })
             .collect();
-        Self { models, next_idx }
+        Self {
+            models,
+            next_idx,
+            length_unit: Default::default(),
+        }
     }
 }
 
@@ -475,17 +516,7 @@ where
         f.write_fmt(format_args!(
             "#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', {models_slice}, #11);\n"
         ))?;
-        f.pad("#11 = (
-    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
-    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
-    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
-    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
-);
-#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
-#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
-#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
-#15 = UNCERTAINTY_MEASURE_WITH_UNIT(1.0E-6, #12, 'distance_accuracy_value','confusion accuracy');\n"
-        )?;
+        fmt_context(f, self.length_unit)?;
         self.models
             .iter()
             .try_for_each(|model| Display::fmt(model, f))
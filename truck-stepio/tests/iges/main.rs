@@ -0,0 +1,127 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+use truck_modeling::builder;
+use truck_stepio::iges::{self, ToIgesCurve};
+
+#[derive(Debug)]
+struct Directory {
+    entity_type: usize,
+    params: Vec<String>,
+}
+
+/// Splits the IGES file into sections, checking the fixed format and the bookkeeping of
+/// the directory entries and the parameter data.
+fn parse_sections(iges_string: &str) -> Vec<Directory> {
+    let lines: Vec<&str> = iges_string.lines().collect();
+    assert!(lines.iter().all(|line| line.len() == 80), "{iges_string}");
+    let section = |letter: char| -> Vec<&str> {
+        let section: Vec<&str> = lines
+            .iter()
+            .filter(|line| line.as_bytes()[72] == letter as u8)
+            .copied()
+            .collect();
+        section.iter().enumerate().for_each(|(i, line)| {
+            assert_eq!(line[73..].trim().parse::<usize>().unwrap(), i + 1, "{line}");
+        });
+        section
+    };
+    let (start, global) = (section('S'), section('G'));
+    let (directory, parameter) = (section('D'), section('P'));
+    let terminate = section('T');
+    assert_eq!(
+        start.len() + global.len() + directory.len() + parameter.len() + terminate.len(),
+        lines.len()
+    );
+    let expected = format!(
+        "S{:>7}G{:>7}D{:>7}P{:>7}",
+        start.len(),
+        global.len(),
+        directory.len(),
+        parameter.len()
+    );
+    assert_eq!(terminate[0][..32], expected);
+
+    assert_eq!(directory.len() % 2, 0);
+    let field = |line: &str, i: usize| line[8 * i..8 * (i + 1)].trim().parse::<usize>().unwrap();
+    let mut param_lines = 0;
+    let entities = directory
+        .chunks(2)
+        .enumerate()
+        .map(|(i, lines)| {
+            let entity_type = field(lines[0], 0);
+            assert_eq!(entity_type, field(lines[1], 0));
+            let (pointer, count) = (field(lines[0], 1), field(lines[1], 3));
+            let params = &parameter[pointer - 1..pointer - 1 + count];
+            params.iter().for_each(|line| {
+                assert_eq!(line[64..72].trim().parse::<usize>().unwrap(), 2 * i + 1);
+            });
+            param_lines += count;
+            let record: String = params.iter().map(|line| line[..64].trim_end()).collect();
+            let record = record.strip_suffix(';').unwrap();
+            let mut params = record.split(',').map(str::to_string);
+            assert_eq!(params.next().unwrap(), entity_type.to_string());
+            Directory {
+                entity_type,
+                params: params.collect(),
+            }
+        })
+        .collect();
+    assert_eq!(param_lines, parameter.len());
+    entities
+}
+
+#[test]
+fn write_curves() {
+    let line = Line(Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 2.0, 3.0));
+    let arc = Processor::<_, Matrix4>::new(TrimmedCurve::new(UnitCircle::new(), (0.0, PI)));
+    let curves: [&dyn ToIgesCurve; 2] = [&line, &arc];
+    let mut buffer = Vec::new();
+    iges::write_curves(&curves, &mut buffer).unwrap();
+    let entities = parse_sections(&String::from_utf8(buffer).unwrap());
+    assert_eq!(entities.len(), 2);
+    assert_eq!(entities[0].entity_type, 110);
+    assert_eq!(
+        entities[0].params,
+        ["0.0", "0.0", "1.0", "1.0", "2.0", "3.0"]
+    );
+    assert_eq!(entities[1].entity_type, 100);
+    let arc: Vec<f64> = entities[1]
+        .params
+        .iter()
+        .map(|x| x.parse().unwrap())
+        .collect();
+    assert_near!(arc[3], 1.0);
+    assert_near!(arc[5], -1.0);
+}
+
+#[test]
+fn write_trimmed_cylinder() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let line = builder::tsweep(&v, Vector3::unit_z());
+    let cylinder = builder::rsweep(&line, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let mut buffer = Vec::new();
+    iges::write_shell(&cylinder, &mut buffer).unwrap();
+    let iges_string = String::from_utf8(buffer).unwrap();
+    let entities = parse_sections(&iges_string);
+
+    let entity = |pointer: &str| &entities[(pointer.parse::<usize>().unwrap() - 1) / 2];
+    let trimmed: Vec<&Directory> = entities.iter().filter(|e| e.entity_type == 144).collect();
+    assert_eq!(trimmed.len(), cylinder.len());
+    trimmed.iter().for_each(|trimmed| {
+        assert_eq!(entity(&trimmed.params[0]).entity_type, 128);
+        let n_inner: usize = trimmed.params[2].parse().unwrap();
+        assert_eq!(trimmed.params.len(), 4 + n_inner);
+        trimmed.params[3..].iter().for_each(|pointer| {
+            let boundary = entity(pointer);
+            assert_eq!(boundary.entity_type, 142);
+            assert_eq!(boundary.params[1], trimmed.params[0]);
+            let composite = entity(&boundary.params[3]);
+            assert_eq!(composite.entity_type, 102);
+            let n: usize = composite.params[0].parse().unwrap();
+            assert_eq!(composite.params.len(), n + 1);
+            composite.params[1..].iter().for_each(|pointer| {
+                assert!(matches!(entity(pointer).entity_type, 110 | 126));
+            });
+        });
+    });
+}
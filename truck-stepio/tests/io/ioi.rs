@@ -46,3 +46,70 @@ fn ioi() {
         });
     });
 }
+
+#[test]
+fn write_read_solids() {
+    use truck_modeling::builder;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder = builder::tsweep(&disk, Vector3::unit_z());
+
+    for solid in [cube, cylinder] {
+        let compressed = solid.compress();
+        let mut buffer = Vec::new();
+        write(
+            &mut buffer,
+            StepModel::from(&compressed),
+            Default::default(),
+        )
+        .unwrap();
+        let shapes = read(buffer.as_slice()).unwrap();
+        assert!(shapes.warnings.is_empty(), "{:?}", shapes.warnings);
+        assert!(shapes.shells.is_empty());
+        assert_eq!(shapes.solids.len(), 1);
+
+        let expected = solid.triangulation(0.01).to_polygon();
+        let mut got = shapes.solids[0].triangulation(0.01).to_polygon();
+        got.put_together_same_attrs(TOLERANCE * 50.0);
+        assert_eq!(got.shell_condition(), ShellCondition::Closed);
+        let (bdb0, bdb1) = (expected.bounding_box(), got.bounding_box());
+        assert_near!(bdb0.min(), bdb1.min());
+        assert_near!(bdb0.max(), bdb1.max());
+        assert!(f64::abs(expected.volume() - got.volume()) < 0.01 * expected.volume());
+    }
+}
+
+#[test]
+fn read_unsupported_surface() {
+    use truck_modeling::builder;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&cube.compress()), Default::default()).to_string();
+
+    // replace the first plane by an entity which is not supported
+    let line = step_string
+        .lines()
+        .find(|line| line.contains("= PLANE("))
+        .unwrap();
+    let idx = line.split(' ').next().unwrap();
+    let broken = line.replace("PLANE(", "SURFACE_REPLICA(");
+    let step_string = step_string.replace(line, &broken);
+
+    let shapes = read(step_string.as_bytes()).unwrap();
+    assert_eq!(shapes.solids.len(), 1);
+    assert_eq!(shapes.solids[0].boundaries[0].faces.len(), 5);
+    let prefix = format!("{idx}:");
+    assert!(
+        shapes.warnings.iter().any(|w| w.starts_with(&prefix)),
+        "{:?}",
+        shapes.warnings
+    );
+}
@@ -59,3 +59,50 @@ fn parse_solids() {
         panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
     });
 }
+
+#[test]
+fn write_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z()).compress();
+
+    let model = StepModel::from(&cube).with_length_unit(LengthUnit::Centimetre);
+    let header = StepHeaderDescriptor {
+        authors: vec!["truck".to_string()],
+        organization: vec!["RICOS".to_string()],
+        ..Default::default()
+    };
+    let mut buffer = Vec::new();
+    write(&mut buffer, model, header).unwrap();
+    let step_string = String::from_utf8(buffer).unwrap();
+    ruststep::parser::parse(&step_string).unwrap();
+    assert!(step_string.contains("SI_UNIT(.CENTI.,.METRE.)"));
+    assert!(step_string.contains("('truck'), ('RICOS')"));
+
+    let table = truck_stepio::r#in::Table::from_step(&step_string).unwrap();
+    assert_eq!(table.shell.len(), 1);
+    let step_shell = table.shell.values().next().unwrap();
+    let shell = table.to_compressed_shell(step_shell).unwrap();
+    assert_eq!(shell.faces.len(), 6);
+    assert_eq!(shell.edges.len(), 12);
+    assert_eq!(shell.vertices.len(), 8);
+}
+
+#[test]
+fn write_helical_spring() {
+    // the circle with radius 0.5 centered at (2.0, 0.0, 0.0) on the xz-plane
+    let v = builder::vertex(Point3::new(2.5, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let (origin, axis) = (Point3::origin(), Vector3::unit_z());
+    let shell =
+        builder::try_helical_sweep(&circle, origin, axis, 1.5, 3.0, TOLERANCE, true).unwrap();
+    let spring = Solid::new(vec![shell]).compress();
+
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&spring), Default::default()).to_string();
+    ruststep::parser::parse(&step_string).unwrap_or_else(|e| {
+        panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
+    });
+    assert!(step_string.contains("B_SPLINE_SURFACE_WITH_KNOTS"));
+}
@@ -0,0 +1,182 @@
+//! Adjacency queries on solids
+//!
+//! Walking a solid by shells, faces, wires and edges is not suitable for repeated queries
+//! such as "which faces contain this edge?". [`SolidAdjacency`] builds the reverse maps once
+//! and answers such queries by borrowing the topology.
+
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_base::cgmath64::{InnerSpace, Point3, Rad};
+
+/// Snapshot of the adjacency relations of a solid.
+/// # Examples
+/// ```
+/// use truck_topology::*;
+/// use truck_topology::adjacency::SolidAdjacency;
+/// let v = Vertex::news(&[(), (), (), ()]);
+/// let edge = [
+///     Edge::new(&v[0], &v[1], ()),
+///     Edge::new(&v[0], &v[2], ()),
+///     Edge::new(&v[0], &v[3], ()),
+///     Edge::new(&v[1], &v[2], ()),
+///     Edge::new(&v[1], &v[3], ()),
+///     Edge::new(&v[2], &v[3], ()),
+/// ];
+/// let wire = vec![
+///     Wire::from_iter(vec![&edge[0], &edge[3], &edge[1].inverse()]),
+///     Wire::from_iter(vec![&edge[1], &edge[5], &edge[2].inverse()]),
+///     Wire::from_iter(vec![&edge[2], &edge[4].inverse(), &edge[0].inverse()]),
+///     Wire::from_iter(vec![&edge[3], &edge[5], &edge[4].inverse()]),
+/// ];
+/// let mut face: Vec<Face<_, _, _>> = wire.into_iter().map(|wire| Face::new(vec![wire], ())).collect();
+/// face[3].invert();
+/// let solid = Solid::new(vec![face.into()]);
+///
+/// let adjacency = SolidAdjacency::new(&solid);
+/// assert_eq!(adjacency.faces_of_edge(edge[0].id()).len(), 2);
+/// assert_eq!(adjacency.edges_of_vertex(v[0].id()).len(), 3);
+///
+/// let faces = adjacency.faces_of_edge(edge[0].id());
+/// assert_eq!(adjacency.adjacent_faces(faces[0].id()).len(), 3);
+/// let shared = adjacency.edge_between(faces[0].id(), faces[1].id()).unwrap();
+/// assert_eq!(shared.id(), edge[0].id());
+/// ```
+#[derive(Debug)]
+pub struct SolidAdjacency<'a, P, C, S> {
+    edges: Vec<Edge<P, C>>,
+    faces: HashMap<FaceID<S>, &'a Face<P, C, S>>,
+    edge_faces: HashMap<EdgeID<C>, Vec<&'a Face<P, C, S>>>,
+    vertex_edges: HashMap<VertexID<P>, Vec<Edge<P, C>>>,
+    face_faces: HashMap<FaceID<S>, Vec<&'a Face<P, C, S>>>,
+}
+
+impl<'a, P, C, S> SolidAdjacency<'a, P, C, S> {
+    /// Builds the adjacency relations of `solid`.
+    pub fn new(solid: &'a Solid<P, C, S>) -> Self {
+        let mut edges = Vec::new();
+        let mut faces = HashMap::default();
+        let mut edge_faces = HashMap::<EdgeID<C>, Vec<&'a Face<P, C, S>>>::default();
+        let mut vertex_edges = HashMap::<VertexID<P>, Vec<Edge<P, C>>>::default();
+        for face in solid.face_iter() {
+            faces.insert(face.id(), face);
+            for edge in face.absolute_boundaries().iter().flatten() {
+                let face_list = edge_faces.entry(edge.id()).or_insert_with(|| {
+                    let edge = edge.absolute_clone();
+                    let (v0, v1) = (edge.front().id(), edge.back().id());
+                    vertex_edges.entry(v0).or_default().push(edge.clone());
+                    if v0 != v1 {
+                        vertex_edges.entry(v1).or_default().push(edge.clone());
+                    }
+                    edges.push(edge);
+                    Vec::new()
+                });
+                if face_list.iter().all(|f| f.id() != face.id()) {
+                    face_list.push(face);
+                }
+            }
+        }
+        let face_faces = faces
+            .values()
+            .map(|face| {
+                let mut set = HashSet::default();
+                let adjacent = face
+                    .absolute_boundaries()
+                    .iter()
+                    .flatten()
+                    .flat_map(|edge| &edge_faces[&edge.id()])
+                    .filter(|f| f.id() != face.id() && set.insert(f.id()))
+                    .copied()
+                    .collect();
+                (face.id(), adjacent)
+            })
+            .collect();
+        Self {
+            edges,
+            faces,
+            edge_faces,
+            vertex_edges,
+            face_faces,
+        }
+    }
+
+    /// Returns the edges of the solid without duplication. Each edge is in its absolute direction.
+    #[inline(always)]
+    pub fn edges(&self) -> &[Edge<P, C>] { &self.edges }
+
+    /// Returns the face with `face_id`.
+    #[inline(always)]
+    pub fn face(&self, face_id: FaceID<S>) -> Option<&'a Face<P, C, S>> {
+        self.faces.get(&face_id).copied()
+    }
+
+    /// Returns the faces whose boundaries include the edge with `edge_id`.
+    #[inline(always)]
+    pub fn faces_of_edge(&self, edge_id: EdgeID<C>) -> &[&'a Face<P, C, S>] {
+        self.edge_faces.get(&edge_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the edges whose ends include the vertex with `vertex_id`.
+    #[inline(always)]
+    pub fn edges_of_vertex(&self, vertex_id: VertexID<P>) -> &[Edge<P, C>] {
+        self.vertex_edges.get(&vertex_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the faces sharing at least one edge with the face with `face_id`.
+    #[inline(always)]
+    pub fn adjacent_faces(&self, face_id: FaceID<S>) -> &[&'a Face<P, C, S>] {
+        self.face_faces.get(&face_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns an edge shared by the faces with `face_id0` and `face_id1`.
+    /// The returned edge is in its absolute direction.
+    pub fn edge_between(&self, face_id0: FaceID<S>, face_id1: FaceID<S>) -> Option<Edge<P, C>> {
+        if face_id0 == face_id1 {
+            return None;
+        }
+        self.faces
+            .get(&face_id0)?
+            .absolute_boundaries()
+            .iter()
+            .flatten()
+            .find(|edge| {
+                self.faces_of_edge(edge.id())
+                    .iter()
+                    .any(|face| face.id() == face_id1)
+            })
+            .map(Edge::absolute_clone)
+    }
+}
+
+impl<'a, P, C, S> SolidAdjacency<'a, P, C, S>
+where
+    C: BoundedCurve<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3> + Clone,
+{
+    /// Returns an iterator over the feature edges, the edges at which the angle between
+    /// the normals of the two adjacent faces exceeds `threshold`.
+    ///
+    /// The normals are evaluated at the middle of the edge.
+    /// Edges which are not shared by exactly two faces, and edges at which the parameter
+    /// of a surface cannot be found, are skipped.
+    pub fn feature_edges(&self, threshold: Rad<f64>) -> impl Iterator<Item = &Edge<P, C>> + '_ {
+        self.edges.iter().filter(move |edge| {
+            let faces = self.faces_of_edge(edge.id());
+            if faces.len() != 2 {
+                return false;
+            }
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            let pt = curve.subs((t0 + t1) / 2.0);
+            let normal = |face: &Face<P, C, S>| {
+                let surface = face.surface();
+                let (u, v) = surface.search_parameter(pt, None, SEARCH_PARAMETER_TRIALS)?;
+                let normal = surface.normal(u, v);
+                Some(if face.orientation() { normal } else { -normal })
+            };
+            match (normal(faces[0]), normal(faces[1])) {
+                (Some(n0), Some(n1)) => n0.angle(n1) > threshold,
+                _ => false,
+            }
+        })
+    }
+}
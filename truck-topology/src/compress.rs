@@ -11,6 +11,10 @@
 use crate::*;
 use rustc_hash::FxHashMap as HashMap;
 use serde::{Deserialize, Serialize};
+use truck_base::cgmath64::{EuclideanSpace, MetricSpace};
+
+/// The number of division of each curve for sampling in comparison.
+const SAMPLES: usize = 8;
 
 /// Serialized compressed edge
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -96,6 +100,38 @@ pub struct CompressedSolid<P, C, S> {
     pub boundaries: Vec<CompressedShell<P, C, S>>,
 }
 
+/// A face whose geometry is modified, reported by [`CompressedShell::diff`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ModifiedFace {
+    /// the index of the face in the original shell
+    pub original: usize,
+    /// the index of the face in the other shell
+    pub modified: usize,
+    /// the maximum distance between the sampled points of the faces
+    pub deviation: f64,
+}
+
+/// The differences of faces between two compressed shells, given by [`CompressedShell::diff`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShellDiff {
+    /// the indices of the faces in the other shell which are not in the original shell
+    pub added_faces: Vec<usize>,
+    /// the indices of the faces in the original shell which are not in the other shell
+    pub removed_faces: Vec<usize>,
+    /// the faces which are in both shells but whose geometries are different
+    pub modified_faces: Vec<ModifiedFace>,
+}
+
+impl ShellDiff {
+    /// Returns whether no faces are added, removed, or modified.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.added_faces.is_empty()
+            && self.removed_faces.is_empty()
+            && self.modified_faces.is_empty()
+    }
+}
+
 struct CompressDirector<P, C> {
     vmap: HashMap<VertexID<P>, (usize, P)>,
     emap: HashMap<EdgeID<C>, (usize, CompressedEdge<C>)>,
@@ -221,6 +257,297 @@ impl<P: Clone, C: Clone, S: Clone> Solid<P, C, S> {
     }
 }
 
+impl<P, C, S> CompressedShell<P, C, S>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    C: BoundedCurve<Point = P> + SearchNearestParameter<D1, Point = P>,
+    S: ParametricSurface<Point = P> + SearchNearestParameter<D2, Point = P>,
+{
+    /// Returns whether the two shells are the same geometrically up to the order of elements.
+    ///
+    /// All vertices, edges, and faces must be matched one-to-one as in [`CompressedShell::diff`].
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        let same_lengths = self.vertices.len() == other.vertices.len()
+            && self.edges.len() == other.edges.len()
+            && self.faces.len() == other.faces.len();
+        if !same_lengths {
+            return false;
+        }
+        let samples = (self.edge_samples(), other.edge_samples());
+        let vmap = self.match_vertices(other, tol);
+        let emap = self.match_edges(other, &vmap, &samples, tol);
+        vmap.iter().all(Option::is_some)
+            && emap.iter().all(Option::is_some)
+            && self.diff_faces(other, &emap, &samples, tol).is_empty()
+    }
+
+    /// Returns the added, removed, and modified faces of `other` compared with `self`.
+    ///
+    /// The elements are matched by geometry without regard to their indices. The vertices are
+    /// matched if their distance is at most `tol`. The edges are matched if their end vertices
+    /// are matched and the sample points of each curve are within `tol` from the other curve.
+    /// Two faces are the same if their boundary edges are matched and the sample points of the
+    /// boundaries and the surfaces are within `tol` from the other face. A face which is not the
+    /// same as any face is regarded as modified if it shares a matched edge with a remaining face
+    /// of the other shell. The orientations of faces are not compared.
+    /// # Remarks
+    /// The sample points of a surface are taken in the rectangle spanned by the parameters of
+    /// the boundary, which may be outside of the face.
+    pub fn diff(&self, other: &Self, tol: f64) -> ShellDiff {
+        let samples = (self.edge_samples(), other.edge_samples());
+        let vmap = self.match_vertices(other, tol);
+        let emap = self.match_edges(other, &vmap, &samples, tol);
+        self.diff_faces(other, &emap, &samples, tol)
+    }
+
+    fn edge_samples(&self) -> Vec<Vec<P>> {
+        self.edges
+            .iter()
+            .map(|edge| {
+                let (t0, t1) = edge.curve.range_tuple();
+                (0..=SAMPLES)
+                    .map(|i| edge.curve.subs(t0 + (t1 - t0) * i as f64 / SAMPLES as f64))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn match_vertices(&self, other: &Self, tol: f64) -> Vec<Option<usize>> {
+        let mut matched = vec![false; other.vertices.len()];
+        let deviation = |i: usize, j: usize| {
+            let (p, q): (P, P) = (self.vertices[i], other.vertices[j]);
+            Some(p.distance(q))
+        };
+        greedy_match(self.vertices.len(), &mut matched, deviation, tol)
+            .into_iter()
+            .map(|x| x.map(|(j, _)| j))
+            .collect()
+    }
+
+    fn match_edges(
+        &self,
+        other: &Self,
+        vmap: &[Option<usize>],
+        (samples0, samples1): &(Vec<Vec<P>>, Vec<Vec<P>>),
+        tol: f64,
+    ) -> Vec<Option<usize>> {
+        let mut matched = vec![false; other.edges.len()];
+        let deviation = |i: usize, j: usize| {
+            let (a, b) = self.edges[i].vertices;
+            let ends = (vmap[a]?, vmap[b]?);
+            let (c, d) = other.edges[j].vertices;
+            if ends != (c, d) && ends != (d, c) {
+                return None;
+            }
+            let (curve0, curve1) = (&self.edges[i].curve, &other.edges[j].curve);
+            let dist0 = max_distance(&samples0[i], |p| curve_distance(curve1, p));
+            let dist1 = max_distance(&samples1[j], |p| curve_distance(curve0, p));
+            Some(f64::max(dist0, dist1))
+        };
+        greedy_match(self.edges.len(), &mut matched, deviation, tol)
+            .into_iter()
+            .map(|x| x.map(|(j, _)| j))
+            .collect()
+    }
+
+    fn diff_faces(
+        &self,
+        other: &Self,
+        emap: &[Option<usize>],
+        (samples0, samples1): &(Vec<Vec<P>>, Vec<Vec<P>>),
+        tol: f64,
+    ) -> ShellDiff {
+        let edge_indices = |face: &CompressedFace<S>| -> Vec<usize> {
+            let mut indices: Vec<usize> = boundary_indices(face).collect();
+            indices.sort_unstable();
+            indices.dedup();
+            indices
+        };
+        let mapped_edges: Vec<Vec<Option<usize>>> = self
+            .faces
+            .iter()
+            .map(|face| {
+                let mut mapped: Vec<_> = edge_indices(face).into_iter().map(|i| emap[i]).collect();
+                mapped.sort_unstable();
+                mapped
+            })
+            .collect();
+        let other_edges: Vec<Vec<usize>> = other.faces.iter().map(edge_indices).collect();
+        let surface_samples0 = self.surface_samples(samples0);
+        let surface_samples1 = other.surface_samples(samples1);
+        let deviation = |i: usize, j: usize| {
+            let (face0, face1) = (&self.faces[i], &other.faces[j]);
+            let (indices0, indices1) = (boundary_indices(face0), boundary_indices(face1));
+            let points0 = indices0.clone().flat_map(|e| &samples0[e]);
+            let points1 = indices1.clone().flat_map(|e| &samples1[e]);
+            let curves0 = indices0.map(|e| &self.edges[e].curve);
+            let curves1 = indices1.map(|e| &other.edges[e].curve);
+            let (surface0, surface1) = (&face0.surface, &face1.surface);
+            let dists = [
+                max_distance(points0, |p| min_curve_distance(curves1.clone(), p)),
+                max_distance(points1, |p| min_curve_distance(curves0.clone(), p)),
+                max_distance(&surface_samples0[i], |p| surface_distance(surface1, p)),
+                max_distance(&surface_samples1[j], |p| surface_distance(surface0, p)),
+            ];
+            dists.into_iter().reduce(f64::max)
+        };
+
+        let mut matched = vec![false; other.faces.len()];
+        // the faces with the matched boundaries and the same geometries
+        let same_boundary = |i: usize, j: usize| {
+            let mapped = mapped_edges[i].iter().copied();
+            mapped.eq(other_edges[j].iter().map(|e| Some(*e)))
+        };
+        let same = greedy_match(
+            self.faces.len(),
+            &mut matched,
+            |i, j| match same_boundary(i, j) {
+                true => deviation(i, j),
+                false => None,
+            },
+            tol,
+        );
+        // the remaining faces sharing matched edges
+        let modified = greedy_match(
+            self.faces.len(),
+            &mut matched,
+            |i, j| {
+                let shared = mapped_edges[i]
+                    .iter()
+                    .flatten()
+                    .any(|e| other_edges[j].binary_search(e).is_ok());
+                match same[i].is_none() && shared {
+                    true => deviation(i, j),
+                    false => None,
+                }
+            },
+            f64::INFINITY,
+        );
+
+        let mut diff = ShellDiff::default();
+        same.into_iter()
+            .zip(modified)
+            .enumerate()
+            .for_each(|(i, x)| match x {
+                (Some(_), _) => {}
+                (None, Some((j, deviation))) => diff.modified_faces.push(ModifiedFace {
+                    original: i,
+                    modified: j,
+                    deviation,
+                }),
+                (None, None) => diff.removed_faces.push(i),
+            });
+        diff.added_faces = (0..other.faces.len()).filter(|j| !matched[*j]).collect();
+        diff
+    }
+
+    fn surface_samples(&self, edge_samples: &[Vec<P>]) -> Vec<Vec<P>> {
+        self.faces
+            .iter()
+            .map(|face| {
+                let surface = &face.surface;
+                let mut hint = None;
+                let params: Vec<(f64, f64)> = face
+                    .boundaries
+                    .iter()
+                    .flatten()
+                    .flat_map(|e| &edge_samples[e.index])
+                    .filter_map(|p| {
+                        let uv =
+                            surface.search_nearest_parameter(*p, hint, SEARCH_PARAMETER_TRIALS);
+                        hint = uv.or(hint);
+                        uv
+                    })
+                    .collect();
+                let Some(&(u, v)) = params.first() else {
+                    return Vec::new();
+                };
+                let init = ((u, u), (v, v));
+                let ((u0, u1), (v0, v1)) =
+                    params.iter().fold(init, |((u0, u1), (v0, v1)), &(u, v)| {
+                        (
+                            (f64::min(u0, u), f64::max(u1, u)),
+                            (f64::min(v0, v), f64::max(v1, v)),
+                        )
+                    });
+                (0..=SAMPLES)
+                    .flat_map(|i| (0..=SAMPLES).map(move |j| (i, j)))
+                    .map(|(i, j)| {
+                        let u = u0 + (u1 - u0) * i as f64 / SAMPLES as f64;
+                        let v = v0 + (v1 - v0) * j as f64 / SAMPLES as f64;
+                        surface.subs(u, v)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Matches each index `i < len` to the index `j` with the least `deviation(i, j)` at most `tol`
+/// in the order of `i`. The indices `j` are not matched twice, recorded in `matched`.
+fn greedy_match(
+    len: usize,
+    matched: &mut [bool],
+    deviation: impl Fn(usize, usize) -> Option<f64>,
+    tol: f64,
+) -> Vec<Option<(usize, f64)>> {
+    (0..len)
+        .map(|i| {
+            let (j, dist) = (0..matched.len())
+                .filter(|j| !matched[*j])
+                .filter_map(|j| Some((j, deviation(i, j)?)))
+                .min_by(|x, y| x.1.total_cmp(&y.1))?;
+            match dist <= tol {
+                true => {
+                    matched[j] = true;
+                    Some((j, dist))
+                }
+                false => None,
+            }
+        })
+        .collect()
+}
+
+fn boundary_indices<S>(face: &CompressedFace<S>) -> impl Iterator<Item = usize> + Clone + '_ {
+    face.boundaries.iter().flatten().map(|e| e.index)
+}
+
+fn max_distance<'a, P: Copy + 'a>(
+    points: impl IntoIterator<Item = &'a P>,
+    distance: impl Fn(P) -> f64,
+) -> f64 {
+    points
+        .into_iter()
+        .fold(0.0, |max, p| f64::max(max, distance(*p)))
+}
+
+fn curve_distance<P, C>(curve: &C, p: P) -> f64
+where
+    P: MetricSpace<Metric = f64> + Copy,
+    C: ParametricCurve<Point = P> + SearchNearestParameter<D1, Point = P>, {
+    curve
+        .search_nearest_parameter(p, None, SEARCH_PARAMETER_TRIALS)
+        .map_or(f64::INFINITY, |t| curve.subs(t).distance(p))
+}
+
+fn min_curve_distance<'a, P, C>(curves: impl Iterator<Item = &'a C>, p: P) -> f64
+where
+    P: MetricSpace<Metric = f64> + Copy,
+    C: ParametricCurve<Point = P> + SearchNearestParameter<D1, Point = P> + 'a, {
+    curves.fold(f64::INFINITY, |min, curve| {
+        f64::min(min, curve_distance(curve, p))
+    })
+}
+
+fn surface_distance<P, S>(surface: &S, p: P) -> f64
+where
+    P: MetricSpace<Metric = f64> + Copy,
+    S: ParametricSurface<Point = P> + SearchNearestParameter<D2, Point = P>, {
+    surface
+        .search_nearest_parameter(p, None, SEARCH_PARAMETER_TRIALS)
+        .map_or(f64::INFINITY, |(u, v)| surface.subs(u, v).distance(p))
+}
+
 // -------------------------- test -------------------------- //
 
 #[test]
@@ -113,6 +113,39 @@ pub enum Error {
     /// ```
     #[error("This shell is not a manifold.")]
     NotManifold,
+    /// Three or more edges meet at a vertex in healing the wire. The indices of the edges
+    /// meeting at such vertices are listed.
+    /// cf. [`Wire::heal`](../struct.Wire.html#method.heal)
+    #[error("The edges {0:?} meet at branching vertices.")]
+    BranchingWire(Vec<usize>),
+    /// The edges in healing the wire are not connected.
+    /// cf. [`Wire::heal`](../struct.Wire.html#method.heal)
+    #[error("The edges are not connected.")]
+    DisconnectedEdges,
+    /// The shell does not include the edge with the given id.
+    /// cf. [`Shell::split_edge`](../struct.Shell.html#method.split_edge)
+    #[error("The edge is not included in the shell.")]
+    EdgeNotFound,
+    /// The shell does not include the face with the given id.
+    /// cf. [`Shell::merge_faces`](../struct.Shell.html#method.merge_faces)
+    #[error("The face is not included in the shell.")]
+    FaceNotFound,
+    /// The parameter is not in the interior of the range of the curve.
+    /// cf. [`Shell::split_edge`](../struct.Shell.html#method.split_edge)
+    #[error("The parameter is not in the interior of the parameter range.")]
+    InvalidCutParameter,
+    /// Two faces do not share exactly one edge with opposite orientations.
+    /// cf. [`Shell::merge_faces`](../struct.Shell.html#method.merge_faces)
+    #[error("The faces do not share exactly one edge.")]
+    NotAdjacentFaces,
+    /// The face cannot be split by the edge.
+    /// cf. [`Shell::split_face`](../struct.Shell.html#method.split_face)
+    #[error("The face cannot be split by the edge.")]
+    CannotSplitFace,
+    /// Collapsing the edge breaks the boundaries of faces.
+    /// cf. [`Shell::collapse_edge`](../struct.Shell.html#method.collapse_edge)
+    #[error("The edge cannot be collapsed.")]
+    CannotCollapseEdge,
 }
 
 #[test]
@@ -132,6 +165,19 @@ fn print_messages() {
     writeln!(&mut std::io::stderr(), "{}\n", Error::NotConnected).unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::NotClosedShell).unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::NotManifold).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::BranchingWire(vec![0, 1, 2])
+    )
+    .unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::DisconnectedEdges).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::EdgeNotFound).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::FaceNotFound).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::InvalidCutParameter).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NotAdjacentFaces).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::CannotSplitFace).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::CannotCollapseEdge).unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
@@ -62,6 +62,7 @@ impl<P, C, S> Face<P, C, S> {
             boundaries,
             orientation: true,
             surface: Arc::new(Mutex::new(surface)),
+            pcurves: Arc::new(Mutex::new(HashMap::default())),
         }
     }
 
@@ -178,6 +179,7 @@ impl<P, C, S> Face<P, C, S> {
             boundaries: self.boundaries.clone(),
             surface: Arc::clone(&self.surface),
             orientation: true,
+            pcurves: Arc::clone(&self.pcurves),
         }
     }
 
@@ -216,6 +218,8 @@ impl<P, C, S> Face<P, C, S> {
     where S: Clone {
         let surface = self.surface();
         self.surface = Arc::new(Mutex::new(surface));
+        let pcurves = self.pcurves.lock().clone();
+        self.pcurves = Arc::new(Mutex::new(pcurves));
     }
 
     /// Returns an iterator over the edges.
@@ -536,6 +540,61 @@ impl<P, C, S> Face<P, C, S> {
     #[inline(always)]
     pub fn set_surface(&self, surface: S) { *self.surface.lock() = surface; }
 
+    /// Returns the pcurve of the edge, the polyline in the parameter domain of the surface
+    /// from the front vertex to the back vertex of `edge`.
+    ///
+    /// The pcurves are attached to the edges in the absolute boundaries. If the pcurve of
+    /// `edge` is not set but the one of `edge.inverse()` is, the reversed one is returned.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// use truck_base::cgmath64::Point2;
+    /// let v = Vertex::news(&[(); 3]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let face = Face::new(vec![wire.clone()], ());
+    /// assert_eq!(face.pcurve(&wire[0]), None);
+    ///
+    /// let pcurve = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)];
+    /// face.set_pcurve(&wire[0], pcurve.clone());
+    /// assert_eq!(face.pcurve(&wire[0]), Some(pcurve));
+    /// assert_eq!(
+    ///     face.pcurve(&wire[0].inverse()),
+    ///     Some(vec![Point2::new(1.0, 0.0), Point2::new(0.0, 0.0)]),
+    /// );
+    ///
+    /// // The pcurves are shared with the cloned faces.
+    /// assert!(face.inverse().pcurve(&wire[0]).is_some());
+    /// ```
+    pub fn pcurve(&self, edge: &Edge<P, C>) -> Option<Vec<Point2>> {
+        let pcurves = self.pcurves.lock();
+        match pcurves.get(&(edge.id(), edge.orientation())) {
+            Some(pcurve) => Some(pcurve.clone()),
+            None => {
+                let pcurve = pcurves.get(&(edge.id(), !edge.orientation()))?;
+                Some(pcurve.iter().rev().copied().collect())
+            }
+        }
+    }
+
+    /// Sets the pcurve of the edge, the polyline in the parameter domain of the surface
+    /// from the front vertex to the back vertex of `edge`.
+    ///
+    /// A seam edge, appearing twice in the boundaries with the opposite orientations, can have
+    /// different pcurves for each orientation.
+    #[inline(always)]
+    pub fn set_pcurve(&self, edge: &Edge<P, C>, pcurve: Vec<Point2>) {
+        let key = (edge.id(), edge.orientation());
+        self.pcurves.lock().insert(key, pcurve);
+    }
+
+    /// Removes all pcurves of the face.
+    #[inline(always)]
+    pub fn clear_pcurves(&self) { self.pcurves.lock().clear() }
+
     /// Inverts the direction of the face.
     /// # Examples
     /// ```
@@ -798,6 +857,7 @@ impl<P, C, S> Face<P, C, S> {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
             surface: Arc::new(Mutex::new(self.surface())),
+            pcurves: Arc::new(Mutex::new(self.pcurves.lock().clone())),
         };
         let boundary = &mut face0.boundaries[0];
         let i = boundary
@@ -821,6 +881,7 @@ impl<P, C, S> Face<P, C, S> {
             boundaries: vec![new_wire],
             orientation: self.orientation,
             surface: Arc::new(Mutex::new(self.surface())),
+            pcurves: Arc::new(Mutex::new(self.pcurves.lock().clone())),
         };
         Some((face0, face1))
     }
@@ -918,10 +979,13 @@ impl<P, C, S> Face<P, C, S> {
             boundaries.push(wire);
         }
         debug_assert!(Face::try_new(boundaries.clone(), ()).is_ok());
+        let mut pcurves = self.pcurves.lock().clone();
+        pcurves.extend(other.pcurves.lock().clone());
         Some(Face {
             boundaries,
             orientation: self.orientation(),
             surface: Arc::new(Mutex::new(surface)),
+            pcurves: Arc::new(Mutex::new(pcurves)),
         })
     }
 
@@ -1022,6 +1086,72 @@ where
     }
 }
 
+impl<P, C, S> Face<P, C, S>
+where
+    C: BoundedCurve<Point = P> + ParameterDivision1D<Point = P>,
+    S: ParametricSurface<Point = P> + SearchParameter<D2, Point = P>,
+{
+    /// Computes the pcurves of all boundary edges by projecting the points of the curves
+    /// divided by `tol` onto the surface, and stores them in the face.
+    ///
+    /// Along each boundary wire, the parameters of periodic surfaces are unwrapped so that the
+    /// pcurves are continuous, even if the wire crosses the seam of the surface.
+    /// Returns `false` and does not modify the stored pcurves if some point cannot be projected.
+    pub fn compute_pcurves(&self, tol: f64) -> bool {
+        let surface = &*self.surface.lock();
+        let pcurves = self
+            .boundaries
+            .iter()
+            .map(|wire| wire_pcurves(surface, wire, tol))
+            .collect::<Option<Vec<_>>>();
+        match pcurves {
+            Some(pcurves) => {
+                self.pcurves.lock().extend(pcurves.into_iter().flatten());
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Returns the pcurves of the edges in `wire`, whose parameters are unwrapped along the wire.
+fn wire_pcurves<P, C, S>(
+    surface: &S,
+    wire: &Wire<P, C>,
+    tol: f64,
+) -> Option<Vec<((EdgeID<C>, bool), Vec<Point2>)>>
+where
+    C: BoundedCurve<Point = P> + ParameterDivision1D<Point = P>,
+    S: ParametricSurface<Point = P> + SearchParameter<D2, Point = P>,
+{
+    let (up, vp) = (surface.u_period(), surface.v_period());
+    let unwrap_period = |x: f64, x0: f64, period: Option<f64>| match period {
+        Some(p) => x - f64::round((x - x0) / p) * p,
+        None => x,
+    };
+    let mut previous: Option<(f64, f64)> = None;
+    wire.edge_iter()
+        .map(|edge| {
+            let curve = &*edge.curve.lock();
+            let (_, mut points) = curve.parameter_division(curve.range_tuple(), tol);
+            if !edge.orientation() {
+                points.reverse();
+            }
+            let project = |pt: P| {
+                let (u, v) = surface.search_parameter(pt, previous, SEARCH_PARAMETER_TRIALS)?;
+                let (u, v) = match previous {
+                    Some((u0, v0)) => (unwrap_period(u, u0, up), unwrap_period(v, v0, vp)),
+                    None => (u, v),
+                };
+                previous = Some((u, v));
+                Some(Point2::new(u, v))
+            };
+            let pcurve: Option<Vec<_>> = points.into_iter().map(project).collect();
+            Some(((edge.id(), edge.orientation()), pcurve?))
+        })
+        .collect()
+}
+
 impl<P, C, S> Clone for Face<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Face<P, C, S> {
@@ -1029,6 +1159,7 @@ impl<P, C, S> Clone for Face<P, C, S> {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
             surface: Arc::clone(&self.surface),
+            pcurves: Arc::clone(&self.pcurves),
         }
     }
 }
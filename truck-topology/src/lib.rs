@@ -81,10 +81,11 @@
 )]
 
 use parking_lot::Mutex;
+use rustc_hash::FxHashMap as HashMap;
 use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use truck_base::{id::ID, tolerance::*};
+use truck_base::{cgmath64::Point2, id::ID, tolerance::*};
 use truck_geotrait::*;
 
 #[cfg(feature = "rclite")]
@@ -152,13 +153,21 @@ pub struct Wire<P, C> {
 /// let face1 = Face::new(vec![wire], ());
 /// assert_ne!(face0.id(), face1.id());
 /// ```
+///
+/// A face may optionally hold the pcurves of its boundary edges, the polylines in the parameter
+/// domain of the surface. See [`Face::compute_pcurves`].
 #[derive(Debug)]
 pub struct Face<P, C, S> {
     boundaries: Vec<Wire<P, C>>,
     orientation: bool,
     surface: Arc<Mutex<S>>,
+    pcurves: Arc<Mutex<PCurveMap<C>>>,
 }
 
+/// The pcurves of a face, whose keys are the ids and the orientations of the edges in the
+/// absolute boundaries.
+type PCurveMap<C> = HashMap<(EdgeID<C>, bool), Vec<Point2>>;
+
 /// Shell, a connected compounded faces.
 ///
 /// The entity of this struct is `Vec<Face>` and almost methods are inherited from
@@ -383,6 +392,7 @@ pub enum SolidDisplayFormat {
     },
 }
 
+pub mod adjacency;
 pub mod compress;
 mod edge;
 /// classifies the errors that can occur in this crate.
@@ -392,6 +402,7 @@ pub mod face;
 /// classifies shell conditions and defines the face iterators.
 pub mod shell;
 mod solid;
+pub mod validation;
 mod vertex;
 /// define the edge iterators and the vertex iterator.
 pub mod wire;
@@ -1,7 +1,9 @@
+use crate::errors::Error;
 use crate::*;
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::vec::Vec;
+use truck_base::cgmath64::{EuclideanSpace, MetricSpace};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 type FaceAdjacencyMap<'a, P, C, S> = HashMap<&'a Face<P, C, S>, Vec<&'a Face<P, C, S>>>;
@@ -691,6 +693,224 @@ impl<P, C, S> Shell<P, C, S> {
         }
     }
 
+    /// Splits the edge with `edge_id` at the parameter `t` by a new vertex.
+    ///
+    /// # Returns
+    /// Returns the tuple of the two new edges and the new vertex.
+    /// The directions of the new edges are the same as the absolute direction of the split edge.
+    ///
+    /// # Failures
+    /// Returns the error and does not edit `self` if:
+    /// - there is no edge corresponding to `edge_id` in the shell, then returns
+    /// `Error::EdgeNotFound`, or
+    /// - `t` is not in the interior of the range of the curve, then returns
+    /// `Error::InvalidCutParameter`.
+    pub fn split_edge(
+        &mut self,
+        edge_id: EdgeID<C>,
+        t: f64,
+    ) -> Result<(Edge<P, C>, Edge<P, C>, Vertex<P>)>
+    where
+        P: Clone + Tolerance,
+        C: Cut<Point = P>,
+    {
+        let edge = self
+            .edge_iter()
+            .find(|edge| edge.id() == edge_id)
+            .ok_or(Error::EdgeNotFound)?
+            .absolute_clone();
+        let vertex = Vertex::new(edge.curve().subs(t));
+        let (edge0, edge1) = edge
+            .cut_with_parameter(&vertex, t)
+            .ok_or(Error::InvalidCutParameter)?;
+        self.iter_mut()
+            .flat_map(|face| face.boundaries.iter_mut())
+            .for_each(|wire| {
+                while let Some(idx) = wire.iter().position(|edge| edge.id() == edge_id) {
+                    let new_wire = if wire[idx].orientation() {
+                        Wire::from(vec![edge0.clone(), edge1.clone()])
+                    } else {
+                        Wire::from(vec![edge1.inverse(), edge0.inverse()])
+                    };
+                    let flag = wire.swap_edge_into_wire(idx, new_wire);
+                    debug_assert!(flag);
+                }
+            });
+        Ok((edge0, edge1, vertex))
+    }
+
+    /// Splits the face with `face_id` by a new edge from `v0` to `v1` whose curve is `curve`.
+    ///
+    /// # Returns
+    /// Returns the new edge. The face is replaced by the face including the new edge,
+    /// and the other face, including the inverse of the new edge, is pushed to the back of `self`.
+    ///
+    /// # Failures
+    /// Returns the error and does not edit `self` if:
+    /// - there is no face corresponding to `face_id` in the shell, then returns
+    /// `Error::FaceNotFound`,
+    /// - `v0` and `v1` are the same vertex, then returns `Error::SameVertex`, or
+    /// - the face has several boundaries or does not include `v0` and `v1`, then returns
+    /// `Error::CannotSplitFace`.
+    pub fn split_face(
+        &mut self,
+        face_id: FaceID<S>,
+        v0: &Vertex<P>,
+        v1: &Vertex<P>,
+        curve: C,
+    ) -> Result<Edge<P, C>>
+    where
+        S: Clone,
+    {
+        let idx = self
+            .iter()
+            .position(|face| face.id() == face_id)
+            .ok_or(Error::FaceNotFound)?;
+        let edge = Edge::try_new(v0, v1, curve)?;
+        let (face0, face1) = self[idx]
+            .cut_by_edge(edge.clone())
+            .ok_or(Error::CannotSplitFace)?;
+        self[idx] = face0;
+        self.push(face1);
+        Ok(edge)
+    }
+
+    /// Merges two faces sharing exactly one edge by removing the edge.
+    ///
+    /// # Returns
+    /// Returns the merged face, which replaces the face with `face_id0`.
+    /// The surface of the merged face is the one of the face with `face_id0`.
+    ///
+    /// # Failures
+    /// Returns the error and does not edit `self` if:
+    /// - there is no face corresponding to `face_id0` or `face_id1`, then returns
+    /// `Error::FaceNotFound`,
+    /// - the faces do not share exactly one edge, or share it with the same orientation,
+    /// then returns `Error::NotAdjacentFaces`, or
+    /// - the merged boundaries are not simple or not disjoint, then returns the error
+    /// of [`Face::try_new`].
+    pub fn merge_faces(
+        &mut self,
+        face_id0: FaceID<S>,
+        face_id1: FaceID<S>,
+    ) -> Result<Face<P, C, S>>
+    where
+        S: Clone,
+    {
+        let find = |face_id| self.iter().position(|face| face.id() == face_id);
+        let i0 = find(face_id0).ok_or(Error::FaceNotFound)?;
+        let i1 = find(face_id1).ok_or(Error::FaceNotFound)?;
+        if i0 == i1 {
+            return Err(Error::NotAdjacentFaces);
+        }
+        let (face0, face1) = (&self[i0], &self[i1]);
+        let ids0: HashSet<EdgeID<C>> = face0.edge_iter().map(|edge| edge.id()).collect();
+        let ids1: HashSet<EdgeID<C>> = face1.edge_iter().map(|edge| edge.id()).collect();
+        let mut shared = ids0.intersection(&ids1);
+        let edge_id = match (shared.next(), shared.next()) {
+            (Some(edge_id), None) => *edge_id,
+            _ => return Err(Error::NotAdjacentFaces),
+        };
+        let count = |face: &Face<P, C, S>| face.edge_iter().filter(|e| e.id() == edge_id).count();
+        if count(face0) != 1 || count(face1) != 1 {
+            return Err(Error::NotAdjacentFaces);
+        }
+        let take_wire = |boundaries: &mut Vec<Wire<P, C>>| {
+            let k = boundaries
+                .iter()
+                .position(|wire| wire.iter().any(|edge| edge.id() == edge_id))
+                .unwrap();
+            let mut wire = boundaries.remove(k);
+            let idx = wire.iter().position(|edge| edge.id() == edge_id).unwrap();
+            wire.rotate_left(idx);
+            let edge = wire.pop_front().unwrap();
+            (wire, edge.orientation())
+        };
+        let (mut boundaries0, mut boundaries1) = (face0.boundaries(), face1.boundaries());
+        let (mut wire0, ori0) = take_wire(&mut boundaries0);
+        let (mut wire1, ori1) = take_wire(&mut boundaries1);
+        if ori0 == ori1 {
+            return Err(Error::NotAdjacentFaces);
+        }
+        wire0.append(&mut wire1);
+        let boundaries: Vec<_> = std::iter::once(wire0)
+            .chain(boundaries0)
+            .chain(boundaries1)
+            .collect();
+        let face = if face0.orientation() {
+            Face::try_new(boundaries, face0.surface())?
+        } else {
+            let boundaries = boundaries.iter().map(Wire::inverse).collect();
+            let mut face = Face::try_new(boundaries, face0.surface())?;
+            face.invert();
+            face
+        };
+        self[i0] = face.clone();
+        self.remove(i1);
+        Ok(face)
+    }
+
+    /// Collapses the edge with `edge_id` by merging its back vertex into its front vertex.
+    ///
+    /// # Returns
+    /// Returns the remaining vertex, i.e. the absolute front vertex of the collapsed edge.
+    ///
+    /// # Remarks
+    /// The geometries of the remaining vertex and the curves are not modified.
+    /// If the edge is not degenerate, the geometry should be fixed after collapsing.
+    ///
+    /// # Failures
+    /// Returns the error and does not edit `self` if:
+    /// - there is no edge corresponding to `edge_id` in the shell, then returns
+    /// `Error::EdgeNotFound`, or
+    /// - the edge is closed, another edge also connects its ends, or some boundary would
+    /// become non-simple, then returns `Error::CannotCollapseEdge`.
+    pub fn collapse_edge(&mut self, edge_id: EdgeID<C>) -> Result<Vertex<P>>
+    where C: Clone {
+        let edge = self
+            .edge_iter()
+            .find(|edge| edge.id() == edge_id)
+            .ok_or(Error::EdgeNotFound)?;
+        let (v0, v1) = (edge.absolute_front().clone(), edge.absolute_back().clone());
+        if v0 == v1 {
+            return Err(Error::CannotCollapseEdge);
+        }
+        let mut new_edges = HashMap::<EdgeID<C>, Edge<P, C>>::default();
+        for edge in self.edge_iter() {
+            if edge.id() == edge_id || new_edges.contains_key(&edge.id()) {
+                continue;
+            }
+            let (front, back) = (edge.absolute_front(), edge.absolute_back());
+            if front != &v1 && back != &v1 {
+                continue;
+            }
+            let front = if front == &v1 { &v0 } else { front };
+            let back = if back == &v1 { &v0 } else { back };
+            if front == back {
+                return Err(Error::CannotCollapseEdge);
+            }
+            new_edges.insert(edge.id(), Edge::debug_new(front, back, edge.curve()));
+        }
+        let mut shell = self.clone();
+        for wire in shell.iter_mut().flat_map(|face| face.boundaries.iter_mut()) {
+            let new_wire: Wire<P, C> = wire
+                .iter()
+                .filter(|edge| edge.id() != edge_id)
+                .map(|edge| match new_edges.get(&edge.id()) {
+                    Some(new_edge) if edge.orientation() => new_edge.clone(),
+                    Some(new_edge) => new_edge.inverse(),
+                    None => edge.clone(),
+                })
+                .collect();
+            if new_wire.is_empty() || !new_wire.is_closed() || !new_wire.is_simple() {
+                return Err(Error::CannotCollapseEdge);
+            }
+            *wire = new_wire;
+        }
+        *self = shell;
+        Ok(v0)
+    }
+
     /// Creates display struct for debugging the shell.
     /// # Examples
     /// ```
@@ -738,6 +958,121 @@ impl<P, C, S> Shell<P, C, S> {
     }
 }
 
+impl<P, C, S> Shell<P, C, S>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    C: BoundedCurve<Point = P> + SearchNearestParameter<D1, Point = P> + Clone,
+    S: Clone,
+{
+    /// Sews the faces whose boundary edges are not shared, and returns the connected shells.
+    ///
+    /// The vertices whose distances are at most `tol` are merged. Two edges with the merged end
+    /// vertices are matched if the sample points of one edge are within `tol` from the other,
+    /// and the matched edges are replaced by one edge with the curve of one of them. Each edge
+    /// is matched at most once, and the unmatched edges remain in the boundaries of the shells.
+    pub fn sew(faces: Vec<Face<P, C, S>>, tol: f64) -> Vec<Shell<P, C, S>> {
+        const SAMPLES: usize = 8;
+        // merge vertices
+        let mut index = HashMap::<VertexID<P>, usize>::default();
+        let mut vertices = Vec::<Vertex<P>>::new();
+        faces.iter().flat_map(Face::vertex_iter).for_each(|v| {
+            index.entry(v.id()).or_insert_with(|| {
+                vertices.push(v.clone());
+                vertices.len() - 1
+            });
+        });
+        let points: Vec<P> = vertices.iter().map(Vertex::point).collect();
+        let mut cluster: Vec<usize> = (0..points.len()).collect();
+        for (i, p) in points.iter().enumerate() {
+            for (j, q) in points.iter().enumerate().skip(i + 1) {
+                if p.distance(*q) <= tol {
+                    let (a, b) = (cluster[i], cluster[j]);
+                    let (a, b) = (usize::min(a, b), usize::max(a, b));
+                    cluster.iter_mut().filter(|c| **c == b).for_each(|c| *c = a);
+                }
+            }
+        }
+        let label = |v: &Vertex<P>| cluster[index[&v.id()]];
+
+        // match edges
+        let mut edge_index = HashSet::<EdgeID<C>>::default();
+        let edges: Vec<Edge<P, C>> = faces
+            .iter()
+            .flat_map(Face::edge_iter)
+            .filter(|edge| edge_index.insert(edge.id()))
+            .map(|edge| edge.absolute_clone())
+            .collect();
+        let same_geometry = |edge0: &Edge<P, C>, edge1: &Edge<P, C>| {
+            let (curve0, curve1) = (edge0.curve(), edge1.curve());
+            let (t0, t1) = curve0.range_tuple();
+            (1..SAMPLES).all(|i| {
+                let pt = curve0.subs(t0 + (t1 - t0) * i as f64 / SAMPLES as f64);
+                let t = curve1.search_nearest_parameter(pt, None, SEARCH_PARAMETER_TRIALS);
+                t.map_or(false, |t| curve1.subs(t).distance(pt) <= tol)
+            })
+        };
+        let mut matched = vec![false; edges.len()];
+        let mut replaced = HashMap::<EdgeID<C>, Edge<P, C>>::default();
+        for (i, edge0) in edges.iter().enumerate() {
+            if matched[i] {
+                continue;
+            }
+            let (a, b) = (label(edge0.front()), label(edge0.back()));
+            let collapsed = a == b && edge0.front() != edge0.back();
+            let unchanged = edge0.front() == &vertices[a] && edge0.back() == &vertices[b];
+            let new_edge = if collapsed || unchanged {
+                edge0.clone()
+            } else {
+                Edge::debug_new(&vertices[a], &vertices[b], edge0.curve())
+            };
+            let partner = edges.iter().enumerate().skip(i + 1).find(|(j, edge1)| {
+                let (c, d) = (label(edge1.front()), label(edge1.back()));
+                let same_ends = (a, b) == (c, d) || (a, b) == (d, c);
+                !collapsed && !matched[*j] && same_ends && same_geometry(edge0, edge1)
+            });
+            if let Some((j, edge1)) = partner {
+                matched[j] = true;
+                let new_edge1 = if label(edge1.front()) == a && label(edge1.back()) == b {
+                    new_edge.clone()
+                } else {
+                    new_edge.inverse()
+                };
+                replaced.insert(edge1.id(), new_edge1);
+            }
+            replaced.insert(edge0.id(), new_edge);
+        }
+
+        // rebuild faces
+        let shell: Shell<P, C, S> = faces
+            .iter()
+            .map(|face| {
+                let boundaries = face
+                    .absolute_boundaries()
+                    .iter()
+                    .map(|wire| {
+                        wire.iter()
+                            .map(|edge| {
+                                let new_edge = &replaced[&edge.id()];
+                                if edge.orientation() {
+                                    new_edge.clone()
+                                } else {
+                                    new_edge.inverse()
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect();
+                let mut new_face = Face::debug_new(boundaries, face.surface());
+                if !face.orientation() {
+                    new_face.invert();
+                }
+                new_face
+            })
+            .collect();
+        shell.connected_components()
+    }
+}
+
 impl<P, C, S> Clone for Shell<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Shell<P, C, S> {
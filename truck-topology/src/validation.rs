@@ -0,0 +1,434 @@
+//! Validation of shapes with detailed diagnostics
+//!
+//! The constructors of topological elements check only the topological conditions,
+//! and shapes created by `new_unchecked` or imported from other formats may be broken.
+//! [`ShapeValidator`] checks both topology and geometry of shells and solids, and reports
+//! each problem with the ids of the elements and the measured magnitudes.
+
+use crate::shell::ShellCondition;
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::fmt::Display;
+use truck_base::cgmath64::{EuclideanSpace, MetricSpace, Point2};
+
+/// The number of division of each edge for sampling.
+const SAMPLES: usize = 8;
+
+/// The end of the curve of an edge is far from the point of the vertex.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VertexDeviation<P, C> {
+    /// the id of the edge
+    pub edge_id: EdgeID<C>,
+    /// the id of the vertex
+    pub vertex_id: VertexID<P>,
+    /// the distance between the end of the curve and the point of the vertex
+    pub distance: f64,
+}
+
+/// The curve of a boundary edge does not lie on the surface of the face.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurveDeviation<C, S> {
+    /// the id of the face
+    pub face_id: FaceID<S>,
+    /// the id of the edge
+    pub edge_id: EdgeID<C>,
+    /// the maximum distance between the sampled points of the curve and the surface.
+    /// If the nearest point on the surface cannot be found, this value is infinity.
+    pub deviation: f64,
+}
+
+/// A boundary wire of a face is not closed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OpenWire<S> {
+    /// the id of the face
+    pub face_id: FaceID<S>,
+    /// the index of the wire in the absolute boundaries of the face
+    pub wire_index: usize,
+}
+
+/// The boundaries of a face intersect themselves in the parameter domain of the surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelfIntersectingBoundary<S> {
+    /// the id of the face
+    pub face_id: FaceID<S>,
+    /// an intersection point in the parameter domain
+    pub parameter: (f64, f64),
+}
+
+/// A shell is not oriented, or a boundary shell of a solid is not closed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShellDefect<C> {
+    /// the index of the shell in the boundaries of the solid
+    pub shell_index: usize,
+    /// the condition of the shell
+    pub condition: ShellCondition,
+    /// the edges which are used twice or more in the same direction
+    pub misoriented_edges: Vec<EdgeID<C>>,
+    /// the edges which are used only once
+    pub boundary_edges: Vec<EdgeID<C>>,
+}
+
+/// The result of the validation of a shape.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationReport<P, C, S> {
+    vertex_deviations: Vec<VertexDeviation<P, C>>,
+    curve_deviations: Vec<CurveDeviation<C, S>>,
+    open_wires: Vec<OpenWire<S>>,
+    self_intersecting_boundaries: Vec<SelfIntersectingBoundary<S>>,
+    shell_defects: Vec<ShellDefect<C>>,
+}
+
+impl<P, C, S> Default for ValidationReport<P, C, S> {
+    fn default() -> Self {
+        Self {
+            vertex_deviations: Vec::new(),
+            curve_deviations: Vec::new(),
+            open_wires: Vec::new(),
+            self_intersecting_boundaries: Vec::new(),
+            shell_defects: Vec::new(),
+        }
+    }
+}
+
+impl<P, C, S> ValidationReport<P, C, S> {
+    /// Returns `true` if no problem is found.
+    #[inline(always)]
+    pub fn is_ok(&self) -> bool {
+        self.vertex_deviations.is_empty()
+            && self.curve_deviations.is_empty()
+            && self.open_wires.is_empty()
+            && self.self_intersecting_boundaries.is_empty()
+            && self.shell_defects.is_empty()
+    }
+    /// Returns the edges whose curve ends do not match the points of their vertices.
+    #[inline(always)]
+    pub fn vertex_deviations(&self) -> &[VertexDeviation<P, C>] { &self.vertex_deviations }
+    /// Returns the boundary curves which do not lie on the surfaces of faces.
+    #[inline(always)]
+    pub fn curve_deviations(&self) -> &[CurveDeviation<C, S>] { &self.curve_deviations }
+    /// Returns the boundary wires which are not closed.
+    #[inline(always)]
+    pub fn open_wires(&self) -> &[OpenWire<S>] { &self.open_wires }
+    /// Returns the faces whose boundaries intersect themselves in the parameter domain.
+    #[inline(always)]
+    pub fn self_intersecting_boundaries(&self) -> &[SelfIntersectingBoundary<S>] {
+        &self.self_intersecting_boundaries
+    }
+    /// Returns the shells which are not oriented or not closed.
+    #[inline(always)]
+    pub fn shell_defects(&self) -> &[ShellDefect<C>] { &self.shell_defects }
+}
+
+impl<P, C, S> Display for ValidationReport<P, C, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_ok() {
+            return f.write_str("no problems are found.");
+        }
+        for x in &self.vertex_deviations {
+            writeln!(
+                f,
+                "edge {:?}: the end of the curve is {} away from the vertex {:?}.",
+                x.edge_id, x.distance, x.vertex_id,
+            )?;
+        }
+        for x in &self.curve_deviations {
+            writeln!(
+                f,
+                "face {:?}: the curve of the edge {:?} deviates from the surface by {}.",
+                x.face_id, x.edge_id, x.deviation,
+            )?;
+        }
+        for x in &self.open_wires {
+            writeln!(
+                f,
+                "face {:?}: the boundary {} is not closed.",
+                x.face_id, x.wire_index,
+            )?;
+        }
+        for x in &self.self_intersecting_boundaries {
+            writeln!(
+                f,
+                "face {:?}: the boundaries intersect at {:?} in the parameter domain.",
+                x.face_id, x.parameter,
+            )?;
+        }
+        for x in &self.shell_defects {
+            writeln!(
+                f,
+                "shell {}: {:?}, misoriented edges: {:?}, boundary edges: {:?}.",
+                x.shell_index, x.condition, x.misoriented_edges, x.boundary_edges,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates the topology and geometry of shapes.
+pub trait ShapeValidator<P, C, S> {
+    /// Checks the shape and reports the problems whose magnitudes exceed `tol`.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// use truck_topology::validation::ShapeValidator;
+    /// use truck_geotrait::*;
+    /// use truck_base::cgmath64::*;
+    /// use std::ops::Bound;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Line(Point2, Point2);
+    /// impl ParametricCurve for Line {
+    ///     type Point = Point2;
+    ///     type Vector = Vector2;
+    ///     fn subs(&self, t: f64) -> Point2 { self.0 + (self.1 - self.0) * t }
+    ///     fn der(&self, _: f64) -> Vector2 { self.1 - self.0 }
+    ///     fn der2(&self, _: f64) -> Vector2 { Vector2::zero() }
+    ///     fn parameter_range(&self) -> ParameterRange {
+    ///         (Bound::Included(0.0), Bound::Included(1.0))
+    ///     }
+    /// }
+    /// impl BoundedCurve for Line {}
+    ///
+    /// // the whole plane
+    /// #[derive(Clone, Debug)]
+    /// struct Plane;
+    /// impl ParametricSurface for Plane {
+    ///     type Point = Point2;
+    ///     type Vector = Vector2;
+    ///     fn subs(&self, u: f64, v: f64) -> Point2 { Point2::new(u, v) }
+    ///     fn uder(&self, _: f64, _: f64) -> Vector2 { Vector2::unit_x() }
+    ///     fn vder(&self, _: f64, _: f64) -> Vector2 { Vector2::unit_y() }
+    ///     fn uuder(&self, _: f64, _: f64) -> Vector2 { Vector2::zero() }
+    ///     fn uvder(&self, _: f64, _: f64) -> Vector2 { Vector2::zero() }
+    ///     fn vvder(&self, _: f64, _: f64) -> Vector2 { Vector2::zero() }
+    /// }
+    /// impl SearchNearestParameter<D2> for Plane {
+    ///     type Point = Point2;
+    ///     fn search_nearest_parameter<H: Into<SPHint2D>>(
+    ///         &self,
+    ///         p: Point2,
+    ///         _: H,
+    ///         _: usize,
+    ///     ) -> Option<(f64, f64)> {
+    ///         Some((p.x, p.y))
+    ///     }
+    /// }
+    ///
+    /// let p = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// let v = Vertex::news(p);
+    /// let line = |i: usize, j: usize| Edge::new(&v[i], &v[j], Line(p[i], p[j]));
+    /// let wire: Wire<_, _> = vec![line(0, 1), line(1, 2), line(2, 3), line(3, 0)].into();
+    /// let shell: Shell<_, _, _> = vec![Face::new(vec![wire], Plane)].into();
+    /// assert!(shell.validate(1.0e-6).is_ok());
+    ///
+    /// // moves the vertex without modifying the curves
+    /// v[2].set_point(Point2::new(1.0, 1.1));
+    /// let report = shell.validate(1.0e-6);
+    /// assert_eq!(report.vertex_deviations().len(), 2);
+    /// assert!(report
+    ///     .vertex_deviations()
+    ///     .iter()
+    ///     .all(|x| x.vertex_id == v[2].id() && f64::abs(x.distance - 0.1) < 1.0e-6));
+    /// ```
+    fn validate(&self, tol: f64) -> ValidationReport<P, C, S>;
+}
+
+impl<P, C, S> ShapeValidator<P, C, S> for Shell<P, C, S>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    C: BoundedCurve<Point = P>,
+    S: ParametricSurface<Point = P> + SearchNearestParameter<D2, Point = P>,
+{
+    fn validate(&self, tol: f64) -> ValidationReport<P, C, S> {
+        let mut report = ValidationReport::default();
+        validate_faces(&mut report, self.face_iter(), tol);
+        let condition = self.shell_condition();
+        if condition != ShellCondition::Oriented && condition != ShellCondition::Closed {
+            report.shell_defects.push(shell_defect(self, 0, condition));
+        }
+        report
+    }
+}
+
+impl<P, C, S> ShapeValidator<P, C, S> for Solid<P, C, S>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    C: BoundedCurve<Point = P>,
+    S: ParametricSurface<Point = P> + SearchNearestParameter<D2, Point = P>,
+{
+    fn validate(&self, tol: f64) -> ValidationReport<P, C, S> {
+        let mut report = ValidationReport::default();
+        validate_faces(&mut report, self.face_iter(), tol);
+        self.boundaries().iter().enumerate().for_each(|(i, shell)| {
+            let condition = shell.shell_condition();
+            if condition != ShellCondition::Closed {
+                report.shell_defects.push(shell_defect(shell, i, condition));
+            }
+        });
+        report
+    }
+}
+
+fn validate_faces<'a, P, C, S>(
+    report: &mut ValidationReport<P, C, S>,
+    faces: impl Iterator<Item = &'a Face<P, C, S>>,
+    tol: f64,
+) where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + 'a,
+    C: BoundedCurve<Point = P> + 'a,
+    S: ParametricSurface<Point = P> + SearchNearestParameter<D2, Point = P> + 'a,
+{
+    let mut checked_edges = HashSet::default();
+    faces.for_each(|face| {
+        let surface = face.surface();
+        let periodic = surface.u_period().is_some() || surface.v_period().is_some();
+        let mut polylines = Vec::new();
+        let mut searched = true;
+        let mut all_pcurves = true;
+        for (wire_index, wire) in face.absolute_boundaries().iter().enumerate() {
+            if !wire.is_closed() {
+                report.open_wires.push(OpenWire {
+                    face_id: face.id(),
+                    wire_index,
+                });
+            }
+            let mut polyline = Vec::new();
+            let mut hint = None;
+            for edge in wire.edge_iter() {
+                let curve = edge.curve();
+                if checked_edges.insert(edge.id()) {
+                    let ends = [
+                        (curve.front(), edge.absolute_front()),
+                        (curve.back(), edge.absolute_back()),
+                    ];
+                    for (pt, vertex) in ends {
+                        let distance = pt.distance(vertex.point());
+                        if distance > tol {
+                            report.vertex_deviations.push(VertexDeviation {
+                                edge_id: edge.id(),
+                                vertex_id: vertex.id(),
+                                distance,
+                            });
+                        }
+                    }
+                }
+                let pcurve = face.pcurve(edge);
+                let (t0, t1) = curve.range_tuple();
+                let mut deviation = 0.0_f64;
+                for i in 0..=SAMPLES {
+                    let k = if edge.orientation() { i } else { SAMPLES - i };
+                    let pt = curve.subs(t0 + (t1 - t0) * k as f64 / SAMPLES as f64);
+                    let uv = surface.search_nearest_parameter(pt, hint, SEARCH_PARAMETER_TRIALS);
+                    match uv {
+                        Some((u, v)) => {
+                            deviation = deviation.max(pt.distance(surface.subs(u, v)));
+                            if i < SAMPLES && pcurve.is_none() {
+                                polyline.push(Point2::new(u, v));
+                            }
+                            hint = uv;
+                        }
+                        None => {
+                            deviation = f64::INFINITY;
+                            searched = false;
+                        }
+                    }
+                }
+                match pcurve {
+                    Some(mut pcurve) => {
+                        pcurve.pop();
+                        polyline.extend(pcurve);
+                    }
+                    None => all_pcurves = false,
+                }
+                if deviation > tol {
+                    report.curve_deviations.push(CurveDeviation {
+                        face_id: face.id(),
+                        edge_id: edge.id(),
+                        deviation,
+                    });
+                }
+            }
+            polylines.push(polyline);
+        }
+        // The stored pcurves are unwrapped, so they can be checked even on periodic surfaces.
+        if all_pcurves || (searched && !periodic) {
+            if let Some(parameter) = self_intersection(&polylines) {
+                report
+                    .self_intersecting_boundaries
+                    .push(SelfIntersectingBoundary {
+                        face_id: face.id(),
+                        parameter,
+                    });
+            }
+        }
+    });
+}
+
+/// Returns an intersection point of the segments of closed polylines.
+/// The segments which only touch at their ends are not regarded as intersecting.
+fn self_intersection(polylines: &[Vec<Point2>]) -> Option<(f64, f64)> {
+    let segments: Vec<(Point2, Point2)> = polylines
+        .iter()
+        .flat_map(|polyline| {
+            let len = polyline.len();
+            (0..len).map(move |i| (polyline[i], polyline[(i + 1) % len]))
+        })
+        .collect();
+    let cross = |o: Point2, p: Point2, q: Point2| (p - o).perp_dot(q - o);
+    segments.iter().enumerate().find_map(|(i, &(a0, a1))| {
+        segments[i + 1..].iter().find_map(|&(b0, b1)| {
+            let (d0, d1) = (cross(a0, a1, b0), cross(a0, a1, b1));
+            let (d2, d3) = (cross(b0, b1, a0), cross(b0, b1, a1));
+            if d0 * d1 < 0.0 && d2 * d3 < 0.0 {
+                let p = a0 + (a1 - a0) * (d2 / (d2 - d3));
+                Some((p.x, p.y))
+            } else {
+                None
+            }
+        })
+    })
+}
+
+fn shell_defect<P, C, S>(
+    shell: &Shell<P, C, S>,
+    shell_index: usize,
+    condition: ShellCondition,
+) -> ShellDefect<C> {
+    let mut order = Vec::new();
+    let mut counts = HashMap::<EdgeID<C>, (usize, usize)>::default();
+    shell
+        .face_iter()
+        .flat_map(Face::boundaries)
+        .flatten()
+        .for_each(|edge| {
+            let count = counts.entry(edge.id()).or_insert_with(|| {
+                order.push(edge.id());
+                (0, 0)
+            });
+            if edge.orientation() {
+                count.0 += 1;
+            } else {
+                count.1 += 1;
+            }
+        });
+    let filter = |f: fn(usize, usize) -> bool| -> Vec<EdgeID<C>> {
+        order
+            .iter()
+            .filter(|id| {
+                let (pos, neg) = counts[*id];
+                f(pos, neg)
+            })
+            .copied()
+            .collect()
+    };
+    ShellDefect {
+        shell_index,
+        condition,
+        misoriented_edges: filter(|pos, neg| pos > 1 || neg > 1),
+        boundary_edges: filter(|pos, neg| pos + neg == 1),
+    }
+}
@@ -1,8 +1,9 @@
 use crate::*;
 use rayon::prelude::*;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::collections::{vec_deque, VecDeque};
 use std::iter::Peekable;
+use truck_base::cgmath64::{EuclideanSpace, MetricSpace};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 impl<P, C> Wire<P, C> {
@@ -541,6 +542,203 @@ impl<P, C> Wire<P, C> {
     }
 }
 
+impl<P, C> Wire<P, C>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    C: Clone,
+{
+    /// Heals the wire whose edges are in arbitrary order and whose end points have small gaps,
+    /// and returns the largest gap closed.
+    ///
+    /// The vertices whose distances are at most `tol` are merged into one vertex, whose point is
+    /// set to the centroid of the points. The edges are reordered and inverted so that the wire
+    /// is continuous, and the edges whose vertices are merged are replaced by new edges.
+    /// # Examples
+    /// ```
+    /// use truck_base::cgmath64::*;
+    /// use truck_topology::*;
+    /// let p = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// let edges: Vec<Edge<Point2, ()>> = (0..4)
+    ///     .map(|i| {
+    ///         let v0 = Vertex::new(p[i] + Vector2::new(1.0e-7, 0.0));
+    ///         let v1 = Vertex::new(p[(i + 1) % 4]);
+    ///         Edge::new(&v0, &v1, ())
+    ///     })
+    ///     .collect();
+    ///
+    /// // shuffled edges with gaps
+    /// let mut wire: Wire<_, _> = vec![
+    ///     edges[2].clone(),
+    ///     edges[0].inverse(),
+    ///     edges[3].clone(),
+    ///     edges[1].inverse(),
+    /// ]
+    /// .into();
+    /// assert!(!wire.is_closed());
+    ///
+    /// let gap = wire.heal(1.0e-6).unwrap();
+    /// assert!(f64::abs(gap - 1.0e-7) < 1.0e-12);
+    /// assert_eq!(wire.len(), 4);
+    /// assert!(wire.is_closed());
+    /// ```
+    /// # Failures
+    /// - If three or more edges meet at a vertex, then returns `Error::BranchingWire`.
+    /// - If the edges are not connected, then returns `Error::DisconnectedEdges`.
+    /// - If the ends of an edge are merged, then returns `Error::SameVertex`.
+    ///
+    /// The wire is not changed in these cases.
+    /// ```
+    /// use truck_base::cgmath64::*;
+    /// use truck_topology::{errors::Error, *};
+    /// // T-junction
+    /// let v = Vertex::news([
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    /// ]);
+    /// let mut wire: Wire<Point2, ()> = vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[3], &v[1], ()),
+    /// ]
+    /// .into();
+    /// assert_eq!(wire.heal(1.0e-6), Err(Error::BranchingWire(vec![0, 1, 2])));
+    /// ```
+    pub fn heal(&mut self, tol: f64) -> Result<f64> {
+        if self.is_empty() {
+            return Ok(0.0);
+        }
+        let mut index = HashMap::<VertexID<P>, usize>::default();
+        let mut vertices = Vec::<Vertex<P>>::new();
+        self.iter()
+            .flat_map(|edge| [edge.front(), edge.back()])
+            .for_each(|v| {
+                index.entry(v.id()).or_insert_with(|| {
+                    vertices.push(v.clone());
+                    vertices.len() - 1
+                });
+            });
+        let points: Vec<P> = vertices.iter().map(Vertex::point).collect();
+
+        // merge vertices
+        let mut cluster: Vec<usize> = (0..points.len()).collect();
+        let mut gap = 0.0;
+        for (i, p) in points.iter().enumerate() {
+            for (j, q) in points.iter().enumerate().skip(i + 1) {
+                let dist = p.distance(*q);
+                if dist > tol {
+                    continue;
+                }
+                gap = f64::max(gap, dist);
+                let (a, b) = (cluster[i], cluster[j]);
+                let (a, b) = (usize::min(a, b), usize::max(a, b));
+                cluster.iter_mut().filter(|c| **c == b).for_each(|c| *c = a);
+            }
+        }
+        let label = |v: &Vertex<P>| cluster[index[&v.id()]];
+        let ends: Vec<(usize, usize)> = self
+            .iter()
+            .map(|edge| (label(edge.front()), label(edge.back())))
+            .collect();
+        let collapsed = self
+            .iter()
+            .zip(&ends)
+            .any(|(edge, (a, b))| a == b && edge.front() != edge.back());
+        if collapsed {
+            return Err(Error::SameVertex);
+        }
+
+        // check branching
+        let mut degree = vec![0; points.len()];
+        ends.iter().for_each(|&(a, b)| {
+            degree[a] += 1;
+            degree[b] += 1;
+        });
+        let branching: Vec<usize> = (0..ends.len())
+            .filter(|&i| degree[ends[i].0] > 2 || degree[ends[i].1] > 2)
+            .collect();
+        if !branching.is_empty() {
+            return Err(Error::BranchingWire(branching));
+        }
+
+        // reorder edges
+        let start = ends.iter().enumerate().find_map(|(i, &(a, b))| {
+            if degree[a] == 1 {
+                Some((i, false))
+            } else if degree[b] == 1 {
+                Some((i, true))
+            } else {
+                None
+            }
+        });
+        let (mut i, mut inverted) = start.unwrap_or((0, false));
+        let mut used = vec![false; ends.len()];
+        let mut order = Vec::with_capacity(ends.len());
+        loop {
+            used[i] = true;
+            order.push((i, inverted));
+            let back = if inverted { ends[i].0 } else { ends[i].1 };
+            let next =
+                (0..ends.len()).find(|&j| !used[j] && (ends[j].0 == back || ends[j].1 == back));
+            match next {
+                Some(j) => {
+                    i = j;
+                    inverted = ends[j].0 != back;
+                }
+                None => break,
+            }
+        }
+        if order.len() < ends.len() {
+            return Err(Error::DisconnectedEdges);
+        }
+
+        // replace vertices and edges
+        let mut members = HashMap::<usize, Vec<P>>::default();
+        points.iter().zip(&cluster).for_each(|(p, c)| {
+            members.entry(*c).or_default().push(*p);
+        });
+        members.into_iter().for_each(|(c, points)| {
+            if points.len() > 1 {
+                vertices[c].set_point(P::centroid(&points));
+            }
+        });
+        let rep = |v: &Vertex<P>| &vertices[cluster[index[&v.id()]]];
+        let edges: Vec<Edge<P, C>> = self
+            .iter()
+            .map(|edge| {
+                let (v0, v1) = edge.absolute_ends();
+                let (w0, w1) = (rep(v0), rep(v1));
+                if v0 == w0 && v1 == w1 {
+                    return edge.clone();
+                }
+                let new_edge = Edge::debug_new(w0, w1, edge.curve());
+                if edge.orientation() {
+                    new_edge
+                } else {
+                    new_edge.inverse()
+                }
+            })
+            .collect();
+        *self = order
+            .into_iter()
+            .map(|(i, inverted)| {
+                if inverted {
+                    edges[i].inverse()
+                } else {
+                    edges[i].clone()
+                }
+            })
+            .collect();
+        Ok(gap)
+    }
+}
+
 type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Option<Edge<Q, D>>, KF, KV, &'a Edge<P, C>>;
 type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
@@ -201,3 +201,105 @@ fn solid_cut_edge() {
     let count = tri.edge_iter().count();
     assert_eq!(count, 12);
 }
+
+fn cube_shell() -> Shell<Point3, Segment, ()> {
+    let p = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let v = Vertex::news(p);
+    let line = |i: usize, j: usize| Edge::new(&v[i], &v[j], Segment::new(p[i], p[j]));
+    let edge = [
+        line(0, 1),
+        line(1, 2),
+        line(2, 3),
+        line(3, 0),
+        line(0, 4),
+        line(1, 5),
+        line(2, 6),
+        line(3, 7),
+        line(4, 5),
+        line(5, 6),
+        line(6, 7),
+        line(7, 4),
+    ];
+    let e = |i: usize| edge[i].clone();
+    let ei = |i: usize| edge[i].inverse();
+    let wires = [
+        [e(0), e(1), e(2), e(3)],
+        [e(4), e(8), ei(5), ei(0)],
+        [e(5), e(9), ei(6), ei(1)],
+        [e(6), e(10), ei(7), ei(2)],
+        [e(7), e(11), ei(4), ei(3)],
+        [ei(11), ei(10), ei(9), ei(8)],
+    ];
+    wires
+        .into_iter()
+        .map(|wire| Face::new(vec![wire.into()], ()))
+        .collect()
+}
+
+#[test]
+fn shell_euler_operators() {
+    let mut shell = cube_shell();
+    let edge = shell.face_iter().next().unwrap().boundaries()[0][0].clone();
+
+    assert_eq!(
+        shell.split_edge(edge.id(), 1.0).unwrap_err(),
+        errors::Error::InvalidCutParameter,
+    );
+    let (edge0, edge1, vertex) = shell.split_edge(edge.id(), 0.5).unwrap();
+    assert_near!(vertex.point(), Point3::new(0.5, 0.0, 0.0));
+    assert_eq!(edge0.back(), &vertex);
+    assert_eq!(edge1.front(), &vertex);
+    assert_eq!(
+        shell.split_edge(edge.id(), 0.5).unwrap_err(),
+        errors::Error::EdgeNotFound,
+    );
+    assert_eq!(shell.edge_iter().count(), 26);
+    assert_eq!(shell.shell_condition(), shell::ShellCondition::Closed);
+    assert!(shell.edge_iter().all(|edge| edge.is_geometric_consistent()));
+
+    let face_id = shell[0].id();
+    let v3 = shell[0].boundaries()[0]
+        .vertex_iter()
+        .find(|v| v.point() == Point3::new(0.0, 1.0, 0.0))
+        .unwrap();
+    let curve = Segment::new(vertex.point(), v3.point());
+    let new_edge = shell.split_face(face_id, &vertex, &v3, curve).unwrap();
+    assert_eq!(shell.len(), 7);
+    assert_eq!(shell.shell_condition(), shell::ShellCondition::Closed);
+    assert!(shell.edge_iter().all(|edge| edge.is_geometric_consistent()));
+    Solid::try_new(vec![shell.clone()]).unwrap();
+
+    let face_ids: Vec<_> = shell
+        .face_iter()
+        .filter(|face| face.edge_iter().any(|edge| edge.id() == new_edge.id()))
+        .map(|face| face.id())
+        .collect();
+    assert_eq!(face_ids.len(), 2);
+    let top_id = shell[5].id();
+    assert_eq!(
+        shell.merge_faces(face_ids[0], top_id).unwrap_err(),
+        errors::Error::NotAdjacentFaces,
+    );
+    let face = shell.merge_faces(face_ids[0], face_ids[1]).unwrap();
+    assert_eq!(face.boundaries()[0].len(), 5);
+    assert_eq!(shell.len(), 6);
+    assert!(shell.edge_iter().all(|edge| edge.id() != new_edge.id()));
+    assert_eq!(shell.shell_condition(), shell::ShellCondition::Closed);
+    Solid::try_new(vec![shell.clone()]).unwrap();
+
+    let remaining = shell.collapse_edge(edge1.id()).unwrap();
+    assert_eq!(remaining, vertex);
+    assert_eq!(shell.edge_iter().count(), 24);
+    assert_eq!(shell.vertex_iter().filter(|v| v == &vertex).count(), 3);
+    assert_eq!(shell.shell_condition(), shell::ShellCondition::Closed);
+    Solid::try_new(vec![shell]).unwrap();
+}